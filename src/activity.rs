@@ -0,0 +1,190 @@
+//! Opt-in per-worktree activity log.
+//!
+//! When `[activity] enabled = true`, hook commands and `--execute` commands are
+//! recorded to `.git/wt-logs/activity/<worktree>.jsonl`, one JSON entry per line.
+//! Unlike the command log, entries are keyed by worktree so `wt list` can show
+//! what last ran in each one.
+//!
+//! # Growth control
+//!
+//! Each file is a ring buffer: a write keeps only the newest `size` entries,
+//! replacing the file through a temporary file and a rename. Writers hold
+//! `wt-logs/activity.lock` from the read to the rename, so hooks finishing at
+//! the same time in parallel don't drop each other's entries.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::state_file;
+use crate::path::sanitize_for_filename;
+
+/// Maximum command string length in entries.
+const MAX_CMD_LENGTH: usize = 500;
+
+/// One recorded command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    /// Unix timestamp (seconds) of when the command ran
+    pub ts: u64,
+    /// The expanded command
+    pub cmd: String,
+    /// `None` when the outcome is unknown (`--execute` hands off to the shell)
+    pub exit: Option<i32>,
+}
+
+/// Directory holding the per-worktree logs.
+fn activity_dir(log_dir: &Path) -> PathBuf {
+    log_dir.join("activity")
+}
+
+/// Lock serializing writers to every activity log. Kept outside the activity
+/// directory so listing it only ever shows logs.
+fn lock_path(log_dir: &Path) -> PathBuf {
+    log_dir.join("activity.lock")
+}
+
+/// Log file for a worktree, keyed by its full path so same-named directories
+/// in different places don't collide.
+fn log_path(log_dir: &Path, worktree_path: &Path) -> PathBuf {
+    let key = sanitize_for_filename(&worktree_path.to_string_lossy());
+    activity_dir(log_dir).join(format!("{key}.jsonl"))
+}
+
+/// Append an entry for a worktree, keeping the newest `size` entries.
+pub fn record(
+    log_dir: &Path,
+    worktree_path: &Path,
+    command: &str,
+    exit_code: Option<i32>,
+    size: usize,
+) -> io::Result<()> {
+    let path = log_path(log_dir, worktree_path);
+    let _lock = state_file::lock(&lock_path(log_dir))?;
+    let mut entries = read_path(&path);
+    entries.push(ActivityEntry {
+        ts: crate::utils::get_now(),
+        cmd: truncate_cmd(command),
+        exit: exit_code,
+    });
+    let skip = entries.len().saturating_sub(size);
+
+    let mut buf = String::new();
+    for entry in &entries[skip..] {
+        buf.push_str(&serde_json::to_string(entry).map_err(io::Error::other)?);
+        buf.push('\n');
+    }
+
+    state_file::write_atomic(&path, buf)
+}
+
+/// Entries for a worktree, oldest first. Missing files and unreadable lines
+/// are skipped.
+pub fn read(log_dir: &Path, worktree_path: &Path) -> Vec<ActivityEntry> {
+    read_path(&log_path(log_dir, worktree_path))
+}
+
+fn read_path(path: &Path) -> Vec<ActivityEntry> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Drop a removed worktree's log, or move it to `activity/archive/` when
+/// `archive` is set. A missing log is not an error.
+pub fn retire(log_dir: &Path, worktree_path: &Path, archive: bool) -> io::Result<()> {
+    let path = log_path(log_dir, worktree_path);
+    if !path.exists() {
+        return Ok(());
+    }
+    let _lock = state_file::lock(&lock_path(log_dir))?;
+    if archive {
+        let archive_dir = activity_dir(log_dir).join("archive");
+        fs::create_dir_all(&archive_dir)?;
+        let name = path.file_name().expect("log path has a file name");
+        fs::rename(&path, archive_dir.join(name))
+    } else {
+        fs::remove_file(&path)
+    }
+}
+
+/// Truncate a command string to `MAX_CMD_LENGTH` characters, appending `…` if truncated.
+fn truncate_cmd(command: &str) -> String {
+    match command.char_indices().nth(MAX_CMD_LENGTH) {
+        Some((byte_idx, _)) => format!("{}…", &command[..byte_idx]),
+        None => command.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_keeps_newest_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let worktree = Path::new("/repos/project.feature");
+        for i in 0..5 {
+            record(dir.path(), worktree, &format!("cmd {i}"), Some(i), 3).unwrap();
+        }
+
+        let entries = read(dir.path(), worktree);
+        let cmds: Vec<_> = entries.iter().map(|e| e.cmd.as_str()).collect();
+        assert_eq!(cmds, ["cmd 2", "cmd 3", "cmd 4"]);
+        assert_eq!(entries[2].exit, Some(4));
+
+        // Other worktrees have their own log
+        assert!(read(dir.path(), Path::new("/repos/project.other")).is_empty());
+    }
+
+    #[test]
+    fn test_retire_archives_or_deletes() {
+        let dir = tempfile::tempdir().unwrap();
+        let kept = Path::new("/repos/kept");
+        let dropped = Path::new("/repos/dropped");
+        record(dir.path(), kept, "make", None, 10).unwrap();
+        record(dir.path(), dropped, "make", None, 10).unwrap();
+
+        retire(dir.path(), kept, true).unwrap();
+        retire(dir.path(), dropped, false).unwrap();
+        // Retiring a worktree without a log is a no-op
+        retire(dir.path(), Path::new("/repos/none"), false).unwrap();
+
+        assert!(read(dir.path(), kept).is_empty());
+        assert!(read(dir.path(), dropped).is_empty());
+        let archived = fs::read_dir(dir.path().join("activity/archive"))
+            .unwrap()
+            .count();
+        assert_eq!(archived, 1);
+    }
+
+    #[test]
+    fn test_concurrent_records_keep_every_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let worktree = Path::new("/repos/project");
+        std::thread::scope(|scope| {
+            for thread in 0..8 {
+                let log_dir = dir.path();
+                scope.spawn(move || {
+                    for i in 0..10 {
+                        record(log_dir, worktree, &format!("{thread}-{i}"), None, 1000).unwrap();
+                    }
+                });
+            }
+        });
+        assert_eq!(read(dir.path(), worktree).len(), 80);
+    }
+
+    #[test]
+    fn test_command_truncation() {
+        let truncated = truncate_cmd(&"é".repeat(MAX_CMD_LENGTH + 10));
+        assert_eq!(truncated.chars().count(), MAX_CMD_LENGTH + 1);
+        assert!(truncated.ends_with('…'));
+    }
+}
@@ -0,0 +1,178 @@
+//! Command-line aliases from the `[alias]` user config section.
+//!
+//! ```toml
+//! [alias]
+//! sw = "switch --create"
+//! ```
+//!
+//! `wt sw feature` runs `wt switch --create feature`: the alias name is
+//! replaced by its expansion and the remaining arguments follow. An expansion
+//! may start with another alias. Unlike `[aliases]`, which defines shell
+//! commands for `wt step`, these expand to `wt` arguments.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+use anyhow::bail;
+
+use super::build_command;
+
+/// Longest alias chain allowed before giving up.
+const MAX_ALIAS_DEPTH: usize = 10;
+
+/// Names of the built-in top-level subcommands, including hidden ones.
+pub(crate) fn builtin_subcommands() -> Vec<String> {
+    build_command()
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect()
+}
+
+/// Spellings of the global options that take a value, read from the clap
+/// definition so new options can't be missed.
+fn global_value_options() -> Vec<String> {
+    build_command()
+        .get_arguments()
+        .filter(|arg| arg.is_global_set() && arg.get_action().takes_values())
+        .flat_map(|arg| {
+            let short = arg.get_short().map(|c| format!("-{c}"));
+            let long = arg.get_long().map(|l| format!("--{l}"));
+            short.into_iter().chain(long)
+        })
+        .collect()
+}
+
+/// Index of the subcommand word in `args`, skipping global options.
+pub(super) fn command_word_index(args: &[OsString]) -> Option<usize> {
+    let value_options = global_value_options();
+    let mut i = 1;
+    while i < args.len() {
+        let arg = args[i].to_str()?;
+        if arg == "--" {
+            return None;
+        }
+        if value_options.iter().any(|option| option == arg) {
+            i += 2;
+        } else if arg.starts_with('-') {
+            i += 1;
+        } else {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Replace a leading alias in `args` with its expansion, repeatedly.
+///
+/// Built-in subcommands always win over an alias of the same name (`wt
+/// config validate` reports those). Errors on cycles and on chains deeper
+/// than [`MAX_ALIAS_DEPTH`].
+pub(super) fn expand_aliases(
+    mut args: Vec<OsString>,
+    aliases: &BTreeMap<String, String>,
+) -> anyhow::Result<Vec<OsString>> {
+    if aliases.is_empty() {
+        return Ok(args);
+    }
+    let builtins = builtin_subcommands();
+    let mut chain: Vec<String> = Vec::new();
+
+    while let Some(index) = command_word_index(&args) {
+        let word = args[index].to_string_lossy().into_owned();
+        if builtins.contains(&word) {
+            break;
+        }
+        let Some(expansion) = aliases.get(&word) else {
+            break;
+        };
+        let seen = chain.contains(&word);
+        chain.push(word);
+        if seen {
+            bail!("Alias cycle: {}", chain.join(" → "));
+        }
+        if chain.len() > MAX_ALIAS_DEPTH {
+            bail!(
+                "Alias expansion deeper than {MAX_ALIAS_DEPTH}: {}",
+                chain.join(" → ")
+            );
+        }
+        let words: Vec<OsString> = expansion.split_whitespace().map(OsString::from).collect();
+        if words.is_empty() {
+            bail!("alias.{} in user config is empty", chain.last().unwrap());
+        }
+        args.splice(index..=index, words);
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(args: &[&str], aliases: &[(&str, &str)]) -> anyhow::Result<Vec<String>> {
+        let aliases = aliases
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let args = args.iter().map(OsString::from).collect();
+        Ok(expand_aliases(args, &aliases)?
+            .into_iter()
+            .map(|a| a.into_string().unwrap())
+            .collect())
+    }
+
+    #[test]
+    fn test_expand_aliases() {
+        let aliases = [("sw", "switch --create"), ("swb", "sw --base develop")];
+        assert_eq!(
+            expand(&["wt", "-C", "repo", "sw", "feature"], &aliases).unwrap(),
+            ["wt", "-C", "repo", "switch", "--create", "feature"]
+        );
+        // Nested aliases expand in order; trailing arguments follow
+        assert_eq!(
+            expand(&["wt", "swb", "feature", "-y"], &aliases).unwrap(),
+            [
+                "wt", "switch", "--create", "--base", "develop", "feature", "-y"
+            ]
+        );
+        // Built-ins and unknown words are left alone
+        assert_eq!(
+            expand(&["wt", "list"], &[("list", "remove")]).unwrap(),
+            ["wt", "list"]
+        );
+        assert_eq!(expand(&["wt", "nope"], &aliases).unwrap(), ["wt", "nope"]);
+        // Values of global options aren't mistaken for the command word
+        assert_eq!(
+            expand(&["wt", "--schema-version", "1", "sw", "x"], &aliases).unwrap(),
+            ["wt", "--schema-version", "1", "switch", "--create", "x"]
+        );
+    }
+
+    #[test]
+    fn test_global_value_options() {
+        let options = global_value_options();
+        for option in ["-C", "--config", "--schema-version"] {
+            assert!(options.iter().any(|o| o == option), "{option}: {options:?}");
+        }
+        assert!(!options.iter().any(|o| o == "--verbose"), "{options:?}");
+    }
+
+    #[test]
+    fn test_expand_aliases_errors() {
+        let err = expand(&["wt", "a"], &[("a", "b"), ("b", "a --x")]).unwrap_err();
+        assert_eq!(err.to_string(), "Alias cycle: a → b → a");
+
+        let chain: Vec<(String, String)> = (0..=MAX_ALIAS_DEPTH)
+            .map(|i| (format!("a{i}"), format!("a{}", i + 1)))
+            .collect();
+        let chain: Vec<(&str, &str)> = chain.iter().map(|(k, v)| (&**k, &**v)).collect();
+        let err = expand(&["wt", "a0"], &chain).unwrap_err();
+        assert!(
+            err.to_string().starts_with("Alias expansion deeper"),
+            "{err}"
+        );
+
+        let err = expand(&["wt", "e"], &[("e", " ")]).unwrap_err();
+        assert_eq!(err.to_string(), "alias.e in user config is empty");
+    }
+}
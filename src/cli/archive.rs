@@ -0,0 +1,20 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt archive`
+#[derive(Subcommand)]
+pub enum ArchiveCommand {
+    /// List archives of this repository
+    List,
+
+    /// Recreate an archived branch and its worktree
+    ///
+    /// Verifies the bundle, fetches the branch back, creates a worktree for it, and reapplies any saved uncommitted changes. The archive is deleted once the restore succeeds.
+    Restore {
+        /// Archive name (from `wt archive list`)
+        name: String,
+
+        /// Replace an existing branch of the same name
+        #[arg(long)]
+        force: bool,
+    },
+}
@@ -0,0 +1,61 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt branches`
+#[derive(Subcommand)]
+pub enum BranchesCommand {
+    /// Delete merged branches that have no worktree
+    ///
+    /// Finds local branches without a worktree whose changes are already in the default branch and that have nothing unpushed, then deletes them after confirmation.
+    #[command(after_long_help = r#"## Examples
+
+Preview which branches would go:
+
+```console
+wt branches clean --dry-run
+```
+
+Also count squash-merged branches, and delete their remote branches too:
+
+```console
+wt branches clean --squash-aware --delete-remote
+```
+
+## Which branches qualify
+
+A local branch is a candidate when all of these hold:
+
+- No worktree has it checked out.
+- It isn't the default branch.
+- It's integrated into the default branch, by the same checks as `wt remove` — or, with `--squash-aware`, the default branch already has its patches or its GitHub PR merged at the local head.
+- It has no commits its upstream lacks. Branches without an upstream qualify on integration alone.
+
+Candidates are shown in a table with the reason each qualifies and its upstream. Deleting asks for confirmation unless `--yes` is given; without a terminal and without `--yes`, nothing is deleted.
+
+With `--delete-remote`, each deleted branch's upstream branch is deleted from its remote as well (`git push <remote> --delete <branch>`). The remote branch is kept when it's the default branch or has commits of its own that aren't integrated; an upstream in the local repository (`.`) is never deleted.
+
+## See also
+
+- [`wt step prune`](@/step.md#wt-step-prune) — Also removes merged worktrees
+"#)]
+    Clean {
+        /// Show what would be deleted
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long, help_heading = "Automation")]
+        yes: bool,
+
+        /// Also delete squash-merged branches
+        ///
+        /// A branch also counts when the default branch already has its
+        /// patches, or when its GitHub PR merged at the local head. The PR
+        /// check needs an authenticated gh.
+        #[arg(long)]
+        squash_aware: bool,
+
+        /// Also delete each branch's upstream on its remote
+        #[arg(long)]
+        delete_remote: bool,
+    },
+}
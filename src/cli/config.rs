@@ -235,6 +235,10 @@ This tests:
         /// Run diagnostic checks (CI tools, commit generation, version)
         #[arg(long)]
         full: bool,
+
+        /// Show the worktree profile and path that apply to a branch
+        #[arg(long, value_name = "BRANCH")]
+        for_branch: Option<String>,
     },
 
     /// Update deprecated config settings
@@ -260,6 +264,75 @@ wt config update --yes
         yes: bool,
     },
 
+    /// Rewrite config files at the current version
+    #[command(
+        after_long_help = r#"Config files carry a `version` key; files without one are version 1. Older
+versions keep loading — renamed settings are upgraded in memory — but print
+deprecation warnings until the file is migrated.
+
+This command rewrites user config and, inside a repository, project config
+(`.config/wt.toml`) at the current version. Comments and formatting are
+preserved, and the original is kept alongside as `<file>.bak`.
+
+A config with a version newer than this build supports fails to load with a
+request to upgrade worktrunk.
+
+## Examples
+
+Migrate both config files:
+```console
+wt config migrate
+```"#
+    )]
+    Migrate,
+
+    /// Check config files for errors
+    #[command(
+        after_long_help = r#"Checks user config and, inside a repository, project config (`.config/wt.toml`)
+for TOML syntax errors, invalid values, and unknown keys.
+
+Unknown keys are warnings by default — worktrunk ignores them when loading.
+With `--strict`, they fail validation, which suits CI checks on `wt.toml`.
+A key that looks like a hook (`pre-…`, `post-…`) but names none always fails,
+listing the hook types.
+
+## Examples
+
+Check both config files:
+```console
+wt config validate
+```
+
+Fail on unknown keys:
+```console
+wt config validate --strict
+```"#
+    )]
+    Validate {
+        /// Treat unknown keys as errors
+        #[arg(long)]
+        strict: bool,
+    },
+
+    /// Print the JSON Schema for config files
+    #[command(
+        after_long_help = r#"Prints a JSON Schema generated from worktrunk's config types, for editor
+completion and validation. Editors using taplo (Even Better TOML) can map it
+to `wt.toml` with a `#:schema` comment or a `.taplo.toml` rule.
+
+## Examples
+
+Write the project config schema:
+```console
+wt config schema --project > .config/wt.schema.json
+```"#
+    )]
+    Schema {
+        /// Print the project config (`.config/wt.toml`) schema instead of user config
+        #[arg(long)]
+        project: bool,
+    },
+
     /// Manage internal data and cache
     #[command(
         after_long_help = r#"State is stored in `.git/` (config entries and log files), separate from configuration files.
@@ -526,6 +599,8 @@ wt config state hints clear NAME   # re-show specific hint
 CI cache entries show status, age, and the commit SHA they were fetched for."#)]
     Get {
         /// Output format (table, json)
+        ///
+        /// JSON follows a versioned schema; see --schema-version.
         #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
         format: super::OutputFormat,
     },
@@ -0,0 +1,73 @@
+//! Per-subcommand default flags from the `[defaults]` user config section.
+//!
+//! ```toml
+//! [defaults]
+//! list = "--branches --format json"
+//! ```
+//!
+//! Defaults are spliced in right after the subcommand name, ahead of the
+//! user's own arguments. `wt list` and `wt merge` declare `overrides_with` on
+//! each flag (itself, plus the `--no-*` counterpart), so a flag repeated on
+//! the command line replaces the default. Other commands reject repeats.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+use anyhow::bail;
+use clap::error::ErrorKind;
+
+use super::alias::command_word_index;
+use super::build_command;
+
+/// Insert the `[defaults]` flags for the invoked subcommand into `args`.
+///
+/// Returns `args` unchanged when nothing applies. Errors only when the
+/// configured flags don't parse, naming the offending key.
+pub(super) fn apply_defaults(
+    mut args: Vec<OsString>,
+    defaults: &BTreeMap<String, String>,
+) -> anyhow::Result<Vec<OsString>> {
+    let Some(index) = command_word_index(&args) else {
+        return Ok(args);
+    };
+    let Some(subcommand) = args[index].to_str().map(str::to_owned) else {
+        return Ok(args);
+    };
+    let Some(flags) = defaults.get(&subcommand) else {
+        return Ok(args);
+    };
+    let flags: Vec<&str> = flags.split_whitespace().collect();
+    if flags.is_empty() {
+        return Ok(args);
+    }
+    validate_defaults(&subcommand, &flags)?;
+
+    let insert_at = index + 1;
+    args.splice(insert_at..insert_at, flags.into_iter().map(OsString::from));
+    Ok(args)
+}
+
+/// Parse the default flags on their own so a typo is blamed on the config key
+/// rather than surfacing as a usage error for the whole command line.
+fn validate_defaults(subcommand: &str, flags: &[&str]) -> anyhow::Result<()> {
+    let args = ["wt", subcommand].into_iter().chain(flags.iter().copied());
+    let Err(err) = build_command().try_get_matches_from(args) else {
+        return Ok(());
+    };
+    // Positionals and nested subcommands come from the command line
+    if matches!(
+        err.kind(),
+        ErrorKind::MissingRequiredArgument
+            | ErrorKind::MissingSubcommand
+            | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
+    ) {
+        return Ok(());
+    }
+    let rendered = err.render().to_string();
+    let reason = rendered
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches("error: ");
+    bail!("Invalid defaults.{subcommand} in user config: {reason}");
+}
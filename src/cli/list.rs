@@ -24,6 +24,8 @@ Input fields (all optional):
 "#)]
     Statusline {
         /// Output format (table, json, claude-code)
+        ///
+        /// JSON follows a versioned schema; see --schema-version.
         #[arg(long, value_enum, default_value = "table")]
         format: OutputFormat,
 
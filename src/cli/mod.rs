@@ -1,20 +1,33 @@
+mod alias;
+mod archive;
+mod branches;
 mod config;
+mod defaults;
 mod hook;
 mod list;
+mod parent;
 mod step;
 
+pub(crate) use alias::builtin_subcommands;
+pub(crate) use archive::ArchiveCommand;
+pub(crate) use branches::BranchesCommand;
 pub(crate) use config::{
     ApprovalsCommand, CiStatusAction, ConfigCommand, ConfigShellCommand, DefaultBranchAction,
     HintsAction, LogsAction, MarkerAction, PreviousBranchAction, StateCommand,
 };
 pub(crate) use hook::HookCommand;
 pub(crate) use list::ListSubcommand;
+pub(crate) use parent::ParentCommand;
 pub(crate) use step::StepCommand;
 
 use clap::builder::styling::{AnsiColor, Color, Styles};
 use clap::{Command, CommandFactory, Parser, Subcommand, ValueEnum};
+use std::ffi::OsString;
+use std::path::PathBuf;
 use std::sync::OnceLock;
-use worktrunk::config::{DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS};
+use worktrunk::config::{
+    DEPRECATED_TEMPLATE_VARS, ListDensity, TEMPLATE_VARS, UserConfig, set_config_path,
+};
 
 use crate::commands::Shell;
 
@@ -124,6 +137,27 @@ pub(crate) fn build_command() -> Command {
     })
 }
 
+/// Expand `[alias]` entries and splice in `[defaults]` flags from the user config.
+///
+/// Returns `args` unchanged when the config fails to load — the command reports
+/// config errors, and warnings, itself later. Errors on alias cycles and
+/// invalid defaults.
+pub(crate) fn expand_config_args(args: Vec<OsString>) -> anyhow::Result<Vec<OsString>> {
+    // Lenient pass to honor `--config <path>` before loading the config
+    if let Ok(matches) = build_command()
+        .ignore_errors(true)
+        .try_get_matches_from(&args)
+        && let Ok(Some(path)) = matches.try_get_one::<PathBuf>("config")
+    {
+        set_config_path(path.clone());
+    }
+    let Ok(config) = UserConfig::load_quiet() else {
+        return Ok(args);
+    };
+    let args = alias::expand_aliases(args, &config.alias)?;
+    defaults::apply_defaults(args, &config.defaults)
+}
+
 /// Parent commands whose subcommands can be suggested for unrecognized top-level commands.
 const NESTED_COMMAND_PARENTS: &[&str] = &["step", "hook"];
 
@@ -186,6 +220,29 @@ pub(crate) enum OutputFormat {
     ClaudeCode,
 }
 
+/// Output formats for `wt list`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ListFormat {
+    /// Human-readable table format
+    Table,
+    /// JSON output
+    Json,
+    /// GitHub-flavored Markdown table
+    Markdown,
+    /// Tab-separated values with a header row
+    Tsv,
+}
+
+/// What `wt list` diffs a branch against for the `main…±` column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum BranchDiffBase {
+    /// Merge-base of the default branch and the branch (what the branch adds)
+    #[default]
+    MergeBase,
+    /// Tip of the default branch (includes changes made on it since)
+    Head,
+}
+
 #[derive(Parser)]
 #[command(name = "wt")]
 #[command(about = "Git worktree management for parallel AI agent workflows", long_about = None)]
@@ -241,6 +298,64 @@ pub(crate) struct Cli {
     )]
     pub verbose: u8,
 
+    /// Stay off the network
+    ///
+    /// Hides CI status and LLM summaries (the statusline keeps its last
+    /// cached CI status, dimmed as stale), writes commit messages without the
+    /// LLM, and skips update checks. Commands that need a remote, such as wt
+    /// switch pr:123, fail instead. Also enabled by WORKTRUNK_OFFLINE=1.
+    #[arg(
+        long,
+        global = true,
+        display_order = 103,
+        help_heading = "Global Options"
+    )]
+    pub offline: bool,
+
+    /// Report failures as JSON on stderr
+    ///
+    /// For tools that run wt: a failure prints one line of JSON with the
+    /// error kind, message, hints, exit code, and details, instead of styled
+    /// text. The exit code matches the process exit code. Other output is
+    /// unchanged.
+    #[arg(
+        long,
+        global = true,
+        display_order = 104,
+        help_heading = "Global Options"
+    )]
+    pub json_errors: bool,
+
+    /// Require a JSON schema version
+    ///
+    /// JSON output (--format=json and --json-errors) follows a versioned
+    /// schema. New fields can appear in any release; renaming, removing, or
+    /// retyping a field bumps the version. Pass the version a script was
+    /// written against, and wt fails with the supported versions instead of
+    /// printing a shape the script can't read. The current version is 1;
+    /// `wt config show` reports the version a build prints.
+    #[arg(
+        long,
+        global = true,
+        value_name = "VERSION",
+        value_parser = crate::commands::json_schema::parse_schema_version,
+        display_order = 105,
+        help_heading = "Global Options"
+    )]
+    pub schema_version: Option<u32>,
+
+    /// Snapshot-stable output for tests and generated docs
+    ///
+    /// Measures relative times from a fixed instant, breaks sort ties by name,
+    /// and fixes the terminal width unless COLUMNS is set. Also enabled by
+    /// WORKTRUNK_DETERMINISTIC=1.
+    #[arg(long, global = true, hide = true)]
+    pub deterministic: bool,
+
+    /// List command aliases from the user config
+    #[arg(long)]
+    pub list_aliases: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -288,6 +403,14 @@ wt switch --create fix --base release    # New branch from release
 wt switch --create temp --no-verify      # Skip hooks
 ```
 
+## Running commands
+
+`-x`/`--execute` runs commands in the worktree after switching, the last one with full terminal control. Matching `[[on-switch]]` entries from project config run before them, in config order; `--no-exec` skips those.
+
+```console
+wt switch feature -x 'direnv allow' -x claude
+```
+
 ## Shortcuts
 
 | Shortcut | Meaning |
@@ -306,6 +429,8 @@ wt switch pr:123                 # PR #123's branch
 wt switch mr:101                 # MR !101's branch
 ```
 
+An existing worktree can also be named by its path or directory name, as in `wt switch ../repo.feature` or `wt switch repo.feature`. A branch of the same name takes precedence.
+
 ## Interactive picker
 
 When called without arguments, `wt switch` opens an interactive picker to browse and select worktrees with live preview. The picker requires a TTY.
@@ -320,11 +445,18 @@ When called without arguments, `wt switch` opens an interactive picker to browse
 | `Enter` | Switch to selected worktree |
 | `Alt-c` | Create new worktree from query |
 | `Alt-r` | Remove selected worktree |
+| `Alt-f` | Fetch selected upstream |
+| `Alt-o` | Open in editor |
+| `Alt-y` | Copy worktree path |
 | `Esc` | Cancel |
 | `1`–`5` | Switch preview tab |
 | `Alt-p` | Toggle preview panel |
 | `Ctrl-u`/`Ctrl-d` | Scroll preview up/down |
 
+Action keys are configurable under `[keys]` in user config; `""` disables a key.
+
+When `Alt-f` finds the upstream was force-pushed — its old head is no longer in its history — the picker warns before re-opening and prints the `git rebase --onto` command that moves the branch's own commits onto the rewritten upstream.
+
 **Preview tabs** (toggle with number keys):
 
 1. **HEAD±** — Diff of uncommitted changes
@@ -366,11 +498,24 @@ Requires `glab` CLI to be installed and authenticated. The `--create` flag canno
 
 **Fork MRs:** The local branch uses the MR's branch name directly, so `git push` works normally. If a local branch with that name already exists tracking something else, rename it first.
 
+## Tags
+
+`wt switch <tag>` checks out a tag in a detached worktree — useful for inspecting a release side by side. `wt list` shows it as `⌂ <tag>`, and `wt remove <tag>` removes only the worktree. With `--branch-from-tag`, an `inspect/<tag>` branch is created instead.
+
+```console
+wt switch v2.3.1                 # Detached worktree at v2.3.1
+```
+
+When a branch and a tag share a name, the branch wins.
+
 ## When wt switch fails
 
 - **Branch doesn't exist** — Use `--create`, or check `wt list --branches`
 - **Path occupied** — Another worktree is at the target path; switch to it or remove it
 - **Stale directory** — Use `--clobber` to remove a non-worktree directory at the target path
+- **Creation interrupted** — A previous `wt` was killed before the checkout finished; interactive sessions are offered a fresh start, and `--clobber` takes it without asking. Ctrl-C during creation removes the partial worktree itself
+- **Branch already checked out** — `--new-path` can't give a branch a second worktree; add `--detach` or `--force`
+- **Name breaks naming rules** — `[branch-name-rules]` in project config rejected the new name; `--fix-name` takes the suggested fix, `--no-name-rules` skips the rules
 
 To change which branch a worktree is on, use `git switch` inside that worktree.
 
@@ -390,11 +535,11 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         branch: Option<String>,
 
         /// Include branches without worktrees
-        #[arg(long, help_heading = "Picker Options", conflicts_with_all = ["create", "base", "execute", "execute_args", "clobber"])]
+        #[arg(long, help_heading = "Picker Options", conflicts_with_all = ["create", "base", "orphan", "branch_from_tag", "execute", "execute_args", "clobber"])]
         branches: bool,
 
         /// Include remote branches
-        #[arg(long, help_heading = "Picker Options", conflicts_with_all = ["create", "base", "execute", "execute_args", "clobber"])]
+        #[arg(long, help_heading = "Picker Options", conflicts_with_all = ["create", "base", "orphan", "branch_from_tag", "execute", "execute_args", "clobber"])]
         remotes: bool,
 
         /// Create a new branch
@@ -407,12 +552,46 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(short = 'b', long, requires = "branch", add = crate::completion::branch_value_completer())]
         base: Option<String>,
 
+        /// Create an empty branch with no history
+        ///
+        /// Uses `git worktree add --orphan`. Works in repositories without
+        /// any commits yet. Requires git 2.42+.
+        #[arg(long, requires = "create", conflicts_with = "base")]
+        orphan: bool,
+
+        /// Use the fixed-up name when the new one breaks naming rules
+        ///
+        /// When the name breaks `[branch-name-rules]` in project config and a
+        /// mechanical fix passes them (`Feature/Login` → `feature/login`),
+        /// creates the branch under the fixed name instead of failing.
+        #[arg(long, requires = "create")]
+        fix_name: bool,
+
+        /// Skip the project's branch naming rules
+        #[arg(long, requires = "create", conflicts_with = "fix_name")]
+        no_name_rules: bool,
+
+        /// Check out tags on a branch
+        ///
+        /// When the argument names a tag, creates (or reuses) an
+        /// `inspect/<tag>` branch instead of a detached worktree.
+        #[arg(long, requires = "branch", conflicts_with = "create")]
+        branch_from_tag: bool,
+
         /// Command to run after switch
         ///
         /// Replaces the wt process with the command after switching, giving
         /// it full terminal control. Useful for launching editors, AI agents,
         /// or other interactive tools.
         ///
+        /// Repeat to run several commands in order:
+        /// `-x 'direnv allow' -x 'npm install'`. Matching `[[on-switch]]`
+        /// entries from project config run first. wt stops at the first
+        /// command that fails, naming it and its exit code, unless
+        /// `--keep-going` is given; the last command still gets the terminal.
+        /// With shell integration the shell runs the commands after wt exits,
+        /// joined with `&&`, or one line each with `--keep-going`.
+        ///
         /// Supports [hook template variables](@/hook.md#template-variables)
         /// (`{{ branch }}`, `{{ worktree_path }}`, etc.) and filters.
         /// `{{ base }}` and `{{ base_worktree_path }}` require `--create`.
@@ -433,23 +612,70 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         /// at the worktree, `-x 'tmux new -s {{ branch | sanitize }}'` starts
         /// a tmux session named after the branch.
         #[arg(short = 'x', long, requires = "branch")]
-        execute: Option<String>,
+        execute: Vec<String>,
 
         /// Additional arguments for --execute command (after --)
         ///
-        /// Arguments after `--` are appended to the execute command.
+        /// Arguments after `--` are appended to the last execute command.
         /// Each argument is expanded for templates, then POSIX shell-escaped.
         #[arg(last = true, requires = "execute")]
         execute_args: Vec<String>,
 
+        /// Run the remaining --execute commands after one fails
+        #[arg(long, requires = "execute")]
+        keep_going: bool,
+
+        /// Skip on-switch commands from project config
+        ///
+        /// Project config can offer commands for matching branches via
+        /// `[[on-switch]]` entries. They run before any `--execute`
+        /// commands.
+        #[arg(long, requires = "branch")]
+        no_exec: bool,
+
+        /// Worktree profile to apply
+        ///
+        /// Uses the named `[[worktree-profiles]]` entry from project config
+        /// instead of the first one whose pattern matches the branch.
+        #[arg(long, requires = "branch", value_name = "NAME")]
+        profile: Option<String>,
+
         /// Remove stale paths at target
         #[arg(long, requires = "branch")]
         clobber: bool,
 
+        /// Create the worktree at this path
+        ///
+        /// Overrides the `worktree-path` template. When the branch already has
+        /// a worktree, git allows a second one only with `--detach` or `--force`.
+        #[arg(long, requires = "branch", value_name = "PATH")]
+        new_path: Option<PathBuf>,
+
+        /// Detach HEAD in a --new-path worktree
+        ///
+        /// Checks out the branch's commit rather than the branch, so the
+        /// existing worktree keeps the branch.
+        #[arg(long, requires = "new_path", conflicts_with_all = ["create", "force"])]
+        detach: bool,
+
+        /// Check out a branch twice with --new-path
+        ///
+        /// Passes `--force` to `git worktree add`. Both worktrees then share the
+        /// branch, and a commit in one leaves the other's files out of date.
+        #[arg(long, requires = "new_path", conflicts_with = "create")]
+        force: bool,
+
+        /// Create the worktree outside allowed-worktree-roots
+        ///
+        /// Asks for confirmation at a terminal; there is no way to skip it.
+        #[arg(long, requires = "branch")]
+        allow_any_path: bool,
+
         /// Skip directory change after switching
         ///
-        /// Hooks still run normally. Useful when hooks handle navigation
-        /// (e.g., tmux workflows) or for CI/automation.
+        /// Hooks still run normally, and `--execute` commands run in the
+        /// worktree. Useful when hooks handle navigation (e.g., tmux
+        /// workflows) or for CI/automation.
         ///
         /// In picker mode (no branch argument), prints the selected branch
         /// name and exits without switching. Useful for scripting.
@@ -510,6 +736,7 @@ $ wt list --format=json
 | HEAD± | Uncommitted changes: +added -deleted lines |
 | main↕ | Commits ahead/behind default branch |
 | main…± | Line diffs since the merge-base with the default branch (`--full`) |
+| Unmerged | Commits whose changes the default branch lacks, skipping merge commits and cherry-picked patches (`--full`) |
 | Summary | LLM-generated branch summary (`--full` + `summary = true`, requires [`commit.generation`](@/config.md#commit)) (experimental) |
 | Remote⇅ | Commits ahead/behind tracking branch |
 | CI | Pipeline status (`--full`) |
@@ -519,7 +746,7 @@ $ wt list --format=json
 | Age | Time since last commit |
 | Message | Last commit message (truncated) |
 
-Note: `main↕` and `main…±` refer to the default branch (header label stays `main` for compactness). `main…±` uses a merge-base (three-dot) diff.
+Note: `main↕` and `main…±` refer to the default branch (header label stays `main` for compactness). `main…±` uses a merge-base (three-dot) diff. A branch with a recorded parent (`wt parent`) is compared with its parent instead, shown after its name as `ui (on api)`.
 
 ### CI status
 
@@ -532,11 +759,16 @@ The CI column shows GitHub/GitLab pipeline status:
 | `●` red | Checks failed |
 | `●` yellow | Merge conflicts with base |
 | `●` gray | No checks configured |
+| `✔` purple | PR merged (safe to remove) |
 | `⚠` yellow | Fetch error (rate limit, network) |
 | (blank) | No upstream or no PR/MR |
 
 CI indicators are clickable links to the PR or pipeline page. Any CI dot appears dimmed when there are unpushed local changes (stale status). PRs/MRs are checked first, then branch workflows/pipelines for branches with an upstream. Local-only branches show blank; remote-only branches (visible with `--remotes`) get CI status detection. Results are cached for 30-60 seconds; use `wt config state` to view or clear.
 
+The default branch has no PR of its own, so its row shows the checks on its latest commit (source `"branch"` in JSON). To leave it blank, set `include-default-branch = false` under `[ci]` in `.config/wt.toml`.
+
+`--ci-details` adds an indented line under each row whose CI failed or is still running, naming the checks responsible, such as `✗ lint` or `● e2e`. The names come from the same response as the status, so it costs no extra requests. GitLab pipelines aren't broken down into jobs, so their rows get no details.
+
 ### LLM summaries (experimental)
 
 With `--full`, `summary = true`, and a [`commit.generation`](@/config.md#commit) command configured, the Summary column shows an LLM-generated one-line description of each branch's changes relative to the default branch.
@@ -566,6 +798,7 @@ The Status column has multiple subcolumns. Within each, only the first matching
 | | `_` | Same commit as the default branch, clean |
 | | `–` | Same commit as the default branch, uncommitted changes |
 | | `⊂` | Content [integrated](@/remove.md#branch-cleanup) into the default branch or target |
+| | `≈` | Likely [squash-merged](@/remove.md#branch-cleanup) into the default branch |
 | | `↕` | Diverged from the default branch |
 | | `↑` | Ahead of the default branch |
 | | `↓` | Behind the default branch |
@@ -613,6 +846,7 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | Field | Type | Description |
 |-------|------|-------------|
 | `branch` | string/null | Branch name (null for detached HEAD) |
+| `branch_raw` | string | Base64 of the branch name's bytes (only when they aren't valid UTF-8) |
 | `path` | string | Worktree path (absent for branches without worktrees) |
 | `kind` | string | `"worktree"` or `"branch"` |
 | `commit` | object | Commit info (see below) |
@@ -626,12 +860,14 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `is_main` | boolean | Is the main worktree |
 | `is_current` | boolean | Is the current worktree |
 | `is_previous` | boolean | Previous worktree from wt switch |
+| `parent` | string | Branch it's stacked on, from `wt parent` (absent when none) |
 | `ci` | object | CI status (see below, absent when no CI) |
 | `url` | string | Dev server URL from project config (absent when not configured) |
 | `url_active` | boolean | Whether the URL's port is listening (absent when not configured) |
 | `summary` | string | LLM-generated branch summary (absent when not configured or no summary) |
 | `statusline` | string | Pre-formatted status with ANSI colors |
 | `symbols` | string | Raw status symbols without colors (e.g., `"!?↓"`) |
+| `incomplete` | array | Fields `--timeout` cut short, e.g. `["ci", "main"]` (absent when complete) |
 
 ### Commit object
 
@@ -639,7 +875,7 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 |-------|------|-------------|
 | `sha` | string | Full commit SHA (40 chars) |
 | `short_sha` | string | Short commit SHA (7 chars) |
-| `message` | string | Commit message (first line) |
+| `message` | string | Commit subject (first line), unsanitized |
 | `timestamp` | number | Unix timestamp |
 
 ### working_tree object
@@ -659,7 +895,12 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 |-------|------|-------------|
 | `ahead` | number | Commits ahead of the default branch |
 | `behind` | number | Commits behind the default branch |
+| `lower_bound` | boolean | Counts stop at the shallow clone boundary |
+| `unmerged` | number | Commits whose changes the default branch lacks (`--full`) |
 | `diff` | object | Lines changed vs the default branch: `{added, deleted}` |
+| `diff_base` | string | Commit the diff is taken from (`--branch-diff-base`) |
+| `conflicted_files` | number | Files that would conflict on merge (absent if clean) |
+| `against` | string | Ref compared against, if not the default (`--against` or `parent`) |
 
 ### remote object
 
@@ -686,12 +927,14 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `source` | string | `"pr"` (PR/MR) or `"branch"` (branch workflow) |
 | `stale` | boolean | Local HEAD differs from remote (unpushed changes) |
 | `url` | string | URL to the PR/MR page |
+| `merged` | boolean | PR was merged (absent otherwise) |
+| `checks` | array | Failing and pending checks as `{name, status}` |
 
 ### main_state values
 
 These values describe the relation to the default branch.
 
-`"is_main"` `"orphan"` `"would_conflict"` `"empty"` `"same_commit"` `"integrated"` `"diverged"` `"ahead"` `"behind"`
+`"is_main"` `"orphan"` `"would_conflict"` `"empty"` `"same_commit"` `"integrated"` `"squash_merged"` `"diverged"` `"ahead"` `"behind"`
 
 ### integration_reason values
 
@@ -701,8 +944,32 @@ When `main_state == "integrated"`: `"ancestor"` `"trees_match"` `"no_added_chang
 
 `"passed"` `"running"` `"failed"` `"conflicts"` `"no-ci"` `"error"`
 
+### Summary object
+
+With `--summary`, the output is an object whose `items` field holds the array above and whose `summary` field holds totals: `worktrees`, `branches`, `remote_branches`, `dirty`, `ahead`, `behind`.
+
 Missing a field that would be generally useful? Open an issue at https://github.com/max-sixty/worktrunk.
 
+## TSV output
+
+For spreadsheets, `--format=tsv` prints a header row and one tab-separated row per item, without styling or truncation:
+
+```console
+wt list --format=tsv --branches > worktrees.tsv
+```
+
+The columns are `branch`, `path`, `ahead`, `behind`, `working_added`, `working_deleted`, `age_seconds`, `state`, `ci`, `commit`, and `message`. Values are the JSON fields of the same names above (`ahead` is `main.ahead`, `state` is `main_state`, `commit` is `commit.short_sha`), and `age_seconds` counts from `commit.timestamp`. Absent values are empty cells; tabs and newlines in messages become spaces.
+
+## Long lists
+
+When the table is taller than the terminal, `wt list` hands the finished table to a pager. The pager comes from `list.pager` in user config, falling back to git's (`GIT_PAGER`, `core.pager`, `PAGER`, then `less`). Set `pager = "cat"` under `[list]` to turn this off, or pass `--no-pager` for one run. `--max-rows N` keeps the first N rows and notes how many were left out:
+
+```console
+wt list --max-rows 10
+```
+
+Piped, JSON, TSV, and `--no-align` output always include every row.
+
 ## See also
 
 - [`wt switch`](@/switch.md) — Switch worktrees or open interactive picker
@@ -717,32 +984,205 @@ Missing a field that would be generally useful? Open an issue at https://github.
         #[command(subcommand)]
         subcommand: Option<ListSubcommand>,
 
-        /// Output format (table, json)
-        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
-        format: OutputFormat,
+        /// Output format (table, json, markdown, tsv)
+        ///
+        /// JSON follows a versioned schema; see --schema-version.
+        #[arg(
+            long,
+            value_enum,
+            default_value = "table",
+            hide_possible_values = true,
+            overrides_with = "format"
+        )]
+        format: ListFormat,
 
         /// Include branches without worktrees
-        #[arg(long)]
+        #[arg(long, overrides_with = "branches")]
         branches: bool,
 
         /// Include remote branches
-        #[arg(long)]
+        #[arg(long, overrides_with = "remotes")]
         remotes: bool,
 
         /// Show CI, diff analysis, and LLM summaries
-        #[arg(long)]
+        #[arg(long, overrides_with = "full")]
         full: bool,
 
+        /// Show only the current worktree, in full detail
+        ///
+        /// Skips surveying other worktrees and branches, so it stays fast in
+        /// repositories with many worktrees. Implies --full. With --format=json,
+        /// prints a single object rather than an array.
+        #[arg(long, conflicts_with_all = ["branches", "remotes", "summary"], overrides_with = "current")]
+        current: bool,
+
+        /// Count edits to skip-worktree files as changes
+        ///
+        /// Files marked with `git update-index --skip-worktree` or
+        /// `--assume-unchanged` are hidden from `git status`, so they don't make
+        /// a worktree dirty. This shows them as modified (`!`) instead.
+        #[arg(long, overrides_with = "include_skipped")]
+        include_skipped: bool,
+
+        /// Base for the main…± line diff (merge-base, head)
+        ///
+        /// By default the diff starts at the merge-base of the default branch
+        /// and each branch, so it shows only what the branch adds. With head, it
+        /// diffs against the tip of the default branch instead, which also
+        /// counts changes made there since the branch point. Ahead/behind
+        /// counts are unaffected.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "BASE",
+            default_value = "merge-base",
+            hide_possible_values = true,
+            overrides_with = "branch_diff_base"
+        )]
+        branch_diff_base: BranchDiffBase,
+
+        /// Compute line diffs on shallow and partial clones
+        ///
+        /// Shallow and partial clones leave the working and main…± line counts
+        /// uncomputed, since diffing there can fetch missing objects on demand
+        /// and stall for minutes. This computes them anyway.
+        #[arg(long, overrides_with = "full_diffs")]
+        full_diffs: bool,
+
+        /// Add totals to the footer
+        ///
+        /// Adds counts the footer otherwise omits, such as branches behind the
+        /// default branch. With `--format=json`, the output becomes an object
+        /// with `items` and `summary` fields.
+        #[arg(long, overrides_with = "summary")]
+        summary: bool,
+
+        /// Compare against this ref instead of the default branch
+        ///
+        /// Recomputes the main↕ and main…± columns against a branch, tag, or
+        /// commit, and names it in their headers. The branch it names is left
+        /// uncompared, as the main worktree otherwise is. Defaults to
+        /// `list.compare-ref` from user config.
+        #[arg(long, value_name = "REF", overrides_with = "against")]
+        against: Option<String>,
+
+        /// Include branches hidden by `wt hide`
+        ///
+        /// Lists them with ⊘ after the branch name. Without this flag, the
+        /// footer counts the rows left out.
+        #[arg(long, overrides_with = "hidden")]
+        hidden: bool,
+
+        /// Show only rows touched since this time
+        ///
+        /// Takes a duration back from now, such as 7d, 2w, or 90m, or an ISO
+        /// date such as 2025-01-31 (midnight local time). Rows are matched on
+        /// their commit time. With activity recording on, a worktree's last
+        /// recorded command counts too, whichever is later.
+        #[arg(
+            long,
+            value_name = "WHEN",
+            conflicts_with = "current",
+            overrides_with = "since"
+        )]
+        since: Option<String>,
+
+        /// Show only rows last touched before this time
+        ///
+        /// Accepts the same forms as --since.
+        #[arg(
+            long,
+            value_name = "WHEN",
+            conflicts_with = "current",
+            overrides_with = "until"
+        )]
+        until: Option<String>,
+
+        /// Stop gathering data after this long
+        ///
+        /// Takes a duration such as 500ms or 2s. Work still running at the
+        /// deadline is abandoned and its git commands killed. The affected
+        /// cells show ~, a note on stderr names them, and the exit code is
+        /// still 0. In JSON output, each item lists its incomplete fields
+        /// under `incomplete`.
+        #[arg(long, value_name = "DURATION", overrides_with = "timeout")]
+        timeout: Option<String>,
+
+        /// Print rows as they complete, without aligning columns
+        ///
+        /// Separates columns with tabs and prints each row as its data arrives,
+        /// instead of waiting for all rows. Suits repositories with hundreds of
+        /// worktrees. Rows appear in completion order.
+        #[arg(long, conflicts_with = "progressive", overrides_with = "no_align")]
+        no_align: bool,
+
+        /// Print one labeled block per worktree
+        ///
+        /// Prints each worktree as a block of label: value lines separated by
+        /// blank lines, skipping the column layout, so nothing is truncated to
+        /// fit the terminal. Not available with JSON output, which already has
+        /// every field.
+        #[arg(short = 'l', long, conflicts_with_all = ["no_align", "progressive"], overrides_with = "long")]
+        long: bool,
+
+        /// Name the failing and pending checks under each row
+        ///
+        /// Adds an indented line under each row whose CI failed or is still
+        /// running, listing up to five checks with their states. The names
+        /// come from the same response as the CI status, so no extra requests
+        /// are made. Fetches CI as --full does. GitHub only.
+        #[arg(long, conflicts_with_all = ["no_align", "long", "progressive"], overrides_with = "ci_details")]
+        ci_details: bool,
+
+        /// Spell out symbols in Markdown output
+        ///
+        /// Replaces status symbols and arrows with words, such as
+        /// "2 ahead, 1 behind" for ↑2 ↓1. Only applies with --format=markdown.
+        #[arg(long, overrides_with = "ascii")]
+        ascii: bool,
+
+        /// Space between columns [default: normal]
+        ///
+        /// `compact` leaves one cell between columns and narrows Message,
+        /// so more columns fit a narrow terminal; `wide` leaves three. Set a
+        /// default with `density` under `[list]`.
+        #[arg(long, value_enum, value_name = "DENSITY", overrides_with = "density")]
+        density: Option<ListDensity>,
+
+        /// Print every row instead of paging a table taller than the terminal
+        #[arg(long, conflicts_with = "max_rows", overrides_with = "no_pager")]
+        no_pager: bool,
+
+        /// Show at most this many rows, then a count of the rest
+        ///
+        /// Only applies to the interactive table; piped, JSON and
+        /// --no-align output always include every row.
+        #[arg(long, value_name = "N", overrides_with = "max_rows")]
+        max_rows: Option<usize>,
+
+        /// Write the output to a file instead of stdout
+        ///
+        /// The output goes to a temporary file in the same directory, which is
+        /// then renamed over the target, so readers never see a half-written
+        /// file. An existing file keeps its permissions. Colors are left out.
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with = "progressive",
+            overrides_with = "output"
+        )]
+        output: Option<PathBuf>,
+
         /// Show fast info immediately, update with slow info
         ///
         /// Displays local data (branches, paths, status) first, then updates
         /// with remote data (CI, upstream) as it arrives. Use --no-progressive
         /// to force buffered rendering. Auto-enabled for TTY.
-        #[arg(long, overrides_with = "no_progressive")]
+        #[arg(long, overrides_with_all = ["progressive", "no_progressive"])]
         progressive: bool,
 
         /// Force buffered rendering
-        #[arg(long = "no-progressive", overrides_with = "progressive", hide = true)]
+        #[arg(long = "no-progressive", overrides_with_all = ["no_progressive", "progressive"], hide = true)]
         no_progressive: bool,
     },
 
@@ -776,6 +1216,12 @@ Force-delete an unmerged branch:
 wt remove -D experimental
 ```
 
+Delete the branch on the remote too:
+
+```console
+wt remove --delete-remote feature
+```
+
 ## Branch cleanup
 
 By default, branches are deleted when merging them would add nothing. This works with squash-merge and rebase workflows where commit history differs but file changes match.
@@ -792,6 +1238,12 @@ The 'same commit' check uses the local default branch; for other checks, 'target
 
 Branches showing `_` or `⊂` are dimmed as safe to delete.
 
+Branches showing `≈` in `wt list` pass none of these checks, yet the target already has their patches: `git cherry` matches every commit, or (with `--full`) one target commit carries the branch's whole diff. That's typical of a squash merge that later commits edited over. `wt remove` keeps these branches; `wt step prune --squash-aware` removes them after confirmation.
+
+`--delete-remote` also deletes the branch's upstream (`git push <remote> --delete <branch>`) once the local branch is deleted, after asking (`--yes` skips the question). If the remote branch has commits the local one doesn't, it's kept unless `-D` is given. A retained branch keeps its upstream too.
+
+A worktree whose branch was deleted out from under it (shown as `(deleted)` in `wt list`) has no branch left to clean up, so removing it only removes the directory. If no other branch or tag contains its last commit, `wt remove` refuses unless `-D` is given.
+
 ## Force flags
 
 Worktrunk has two force flags for different situations:
@@ -809,6 +1261,12 @@ wt remove feature --force -D    # Both
 
 Without `--force`, removal fails if the worktree contains untracked files. Without `-D`, removal keeps branches with unmerged changes. Use `--no-delete-branch` to keep the branch regardless of merge status.
 
+## Processes in the worktree
+
+With `check-cwd-users = true` in the user config, removal first looks for other processes whose working directory is inside the worktree — a second shell, a dev server — and names them ("2 processes are using this directory: node (1234), zsh (5678)") before asking whether to continue. Without a terminal to ask, removal stops instead. `--force` skips the check. `wt step prune` doesn't ask: it skips such worktrees and lists them.
+
+Detection reads `/proc` on Linux and runs `lsof` on macOS, gives up after about 100ms, and finds nothing elsewhere. The shell that ran `wt` doesn't count, since shell integration moves it out.
+
 ## Background removal
 
 Removal runs in the background by default (returns immediately). Logs are written to `.git/wt-logs/{branch}-remove.log`. Use `--foreground` to run in the foreground.
@@ -835,6 +1293,10 @@ Removal runs in the background by default (returns immediately). Logs are writte
         #[arg(short = 'D', long = "force-delete")]
         force_delete: bool,
 
+        /// Also delete the branch's upstream on its remote
+        #[arg(long)]
+        delete_remote: bool,
+
         /// Run removal in foreground (block until complete)
         #[arg(long)]
         foreground: bool,
@@ -908,6 +1370,30 @@ wt merge --no-commit
 
 Use `--no-commit` to skip committing uncommitted changes and squashing; rebase still runs by default and can rewrite commits unless `--no-rebase` is passed. Useful after preparing commits manually with `wt step`. Requires a clean working tree.
 
+## Stacked branches
+
+A branch created from the one being merged — say `feature-ui` from `feature-base` — still carries `feature-base`'s original commits after a squash or rebase, so it looks far ahead of the target. `wt merge` finds these branches and warns, with the rebase that fixes each. With `--restack`, it rebases them onto the updated target one at a time; branches stacked on those follow their parent. Parents recorded with `wt parent` or `wt switch --create --base` decide the order; other branches are matched to a parent from the commit graph.
+
+A conflict stops the restack: in a worktree, the rebase is left in progress to resolve, and a branch without a worktree is left as it was.
+
+## Merging another worktree
+
+`--branch <name>` merges a branch from its own worktree without going there, as if `wt merge` ran in that worktree: uncommitted changes there are committed, the branch is squashed and rebased, and its worktree is removed. The shell only changes directory when the removed worktree is the one it's in.
+
+```console
+wt merge --branch feature-x
+```
+
+## Tagging
+
+`--tag <name>` creates an annotated tag on the merged commit of the target branch, for release flows that tag the trunk after merging. The message defaults to the tag name; set it with `--tag-message`. `--sign` signs the tag with GPG, and `--push-tag` pushes it to the primary remote.
+
+```console
+wt merge --tag v1.4.0 --sign --push-tag
+```
+
+The name is checked before anything else runs, so an invalid or existing tag stops the merge before it starts. If creating or pushing the tag fails afterwards — say, no signing key — the merge stays in place and Worktrunk prints the `git` command to finish by hand.
+
 ## Local CI
 
 For personal projects, pre-merge hooks open up the possibility of a workflow with much faster iteration — an order of magnitude more small changes instead of fewer large ones.
@@ -936,127 +1422,639 @@ lint = "cargo clippy"
         #[arg(add = crate::completion::branch_value_completer())]
         target: Option<String>,
 
+        /// Merge this branch's worktree instead of the current one
+        #[arg(long, value_name = "BRANCH", add = crate::completion::local_branches_completer(), overrides_with = "branch")]
+        branch: Option<String>,
+
         /// Force commit squashing
-        #[arg(long, overrides_with = "no_squash", hide = true)]
+        #[arg(long, overrides_with_all = ["squash", "no_squash"], hide = true)]
         squash: bool,
 
         /// Skip commit squashing
-        #[arg(long = "no-squash", overrides_with = "squash")]
+        #[arg(long = "no-squash", overrides_with_all = ["no_squash", "squash"])]
         no_squash: bool,
 
         /// Force commit and squash
-        #[arg(long, overrides_with = "no_commit", hide = true)]
+        #[arg(long, overrides_with_all = ["commit", "no_commit"], hide = true)]
         commit: bool,
 
         /// Skip commit and squash
-        #[arg(long = "no-commit", overrides_with = "commit")]
+        #[arg(long = "no-commit", overrides_with_all = ["no_commit", "commit"])]
         no_commit: bool,
 
         /// Force rebasing onto target
-        #[arg(long, overrides_with = "no_rebase", hide = true)]
+        #[arg(long, overrides_with_all = ["rebase", "no_rebase"], hide = true)]
         rebase: bool,
 
         /// Skip rebase (fail if not already rebased)
-        #[arg(long = "no-rebase", overrides_with = "rebase")]
+        #[arg(long = "no-rebase", overrides_with_all = ["no_rebase", "rebase"])]
         no_rebase: bool,
 
         /// Force worktree removal after merge
-        #[arg(long, overrides_with = "no_remove", hide = true)]
+        #[arg(long, overrides_with_all = ["remove", "no_remove"], hide = true)]
         remove: bool,
 
         /// Keep worktree after merge
-        #[arg(long = "no-remove", overrides_with = "remove")]
+        #[arg(long = "no-remove", overrides_with_all = ["no_remove", "remove"])]
         no_remove: bool,
 
+        /// Rebase branches stacked on this one onto the target
+        #[arg(long, overrides_with = "restack")]
+        restack: bool,
+
+        /// Create an annotated tag on the merged commit
+        #[arg(long, value_name = "NAME", overrides_with = "tag")]
+        tag: Option<String>,
+
+        /// Tag message [default: the tag name]
+        #[arg(
+            long,
+            value_name = "MESSAGE",
+            requires = "tag",
+            overrides_with = "tag_message"
+        )]
+        tag_message: Option<String>,
+
+        /// GPG-sign the tag
+        #[arg(long, requires = "tag", overrides_with = "sign")]
+        sign: bool,
+
+        /// Push the tag to the primary remote
+        #[arg(long, requires = "tag", overrides_with = "push_tag")]
+        push_tag: bool,
+
         /// Skip approval prompts
-        #[arg(short, long, help_heading = "Automation")]
+        #[arg(short, long, help_heading = "Automation", overrides_with = "yes")]
         yes: bool,
 
         /// Force running hooks
-        #[arg(long, overrides_with = "no_verify", hide = true)]
+        #[arg(long, overrides_with_all = ["verify", "no_verify"], hide = true)]
         verify: bool,
 
         /// Skip hooks
         #[arg(
             long = "no-verify",
-            overrides_with = "verify",
+            overrides_with_all = ["no_verify", "verify"],
             help_heading = "Automation"
         )]
         no_verify: bool,
 
         /// What to stage before committing [default: all]
-        #[arg(long)]
+        #[arg(long, overrides_with = "stage")]
         stage: Option<crate::commands::commit::StageMode>,
     },
-    /// Deprecated: use `wt switch` instead
+
+    /// Compare two branches or worktrees
     ///
-    /// Interactive worktree picker (now integrated into `wt switch`).
-    #[command(hide = true)]
-    Select {
-        /// Include branches without worktrees
-        #[arg(long)]
-        branches: bool,
+    /// Summarizes changes in a branch since it diverged from the target, aligned like the `main…±` column of `wt list`.
+    #[command(after_long_help = r#"## Examples
 
-        /// Include remote branches
-        #[arg(long)]
-        remotes: bool,
-    },
+Changes in the current worktree since it branched from the default branch:
 
-    /// Run individual operations
-    ///
-    /// The building blocks of `wt merge` — commit, squash, rebase, push — plus standalone utilities.
-    #[command(
-        name = "step",
-        after_long_help = r#"## Examples
+```console
+wt diff
+```
 
-Commit with LLM-generated message:
+Compare a feature branch against `develop`, including its uncommitted changes:
 
 ```console
-wt step commit
+wt diff feature develop --working
 ```
 
-Manual merge workflow with review between steps:
+Open the comparison in the configured `git difftool`:
 
 ```console
-wt step commit
-wt step squash
-wt step rebase
-wt step push
+wt diff feature --tool
 ```
 
-## Operations
+Per-file counts for scripting:
 
-- `commit` — Stage and commit with [LLM-generated message](@/llm-commits.md)
-- `squash` — Squash all branch commits into one with [LLM-generated message](@/llm-commits.md)
-- `rebase` — Rebase onto target branch
-- `push` — Fast-forward target to current branch
-- `diff` — Show all changes since branching (committed, staged, unstaged, untracked)
-- `copy-ignored` — Copy gitignored files between worktrees
-- `for-each` — [experimental] Run a command in every worktree
-- `promote` — [experimental] Put a branch into the main worktree
-- `prune` — Remove worktrees and branches merged into the default branch
-- `relocate` — [experimental] Move worktrees to expected paths
-- `<alias>` — [experimental] Run a configured command alias
+```console
+wt diff feature --format=json
+```
 
-## Aliases
+## How it works
 
-Custom command templates configured in user config (`~/.config/worktrunk/config.toml`) or project config (`.config/wt.toml`). Aliases support the same [template variables](@/hook.md#template-variables) as hooks.
+Both sides resolve like `wt switch` arguments, so `@` is the current worktree and `^` is the default branch. Changes are measured from the merge-base, so commits made to the target after branching don't appear.
 
-```toml
-# .config/wt.toml
-[aliases]
-deploy = "make deploy BRANCH={{ branch }}"
-port = "echo http://localhost:{{ branch | hash_port }}"
+With `--working`, the branch's worktree directory is compared instead of its last commit, so staged and unstaged changes to tracked files are included. Untracked files are not.
+"#)]
+    Diff {
+        /// Branch or worktree to inspect
+        ///
+        /// Defaults to the current worktree.
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+
+        /// Branch to compare against
+        ///
+        /// Defaults to default branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        target: Option<String>,
+
+        /// Include uncommitted changes
+        ///
+        /// Diffs against the branch's worktree directory rather than its commit.
+        #[arg(long)]
+        working: bool,
+
+        /// Open in git difftool
+        #[arg(long, conflicts_with = "format")]
+        tool: bool,
+
+        /// Output format (table, json)
+        ///
+        /// JSON follows a versioned schema; see --schema-version.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// Print worktree changes as events
+    ///
+    /// One line per change, for status bars and scripts that want to stay current without re-running `wt list`.
+    #[command(after_long_help = r#"## Examples
+
+Print the current state and exit:
+
+```console
+wt events --once
 ```
 
+Keep printing changes, checking every two seconds:
+
 ```console
-wt step deploy                            # run the alias
-wt step deploy --dry-run                  # show expanded command
-wt step deploy --var env=staging          # pass extra template variables
-wt step deploy --yes                      # skip approval prompt
+wt events --follow --interval 2s
 ```
 
-When defined in both user and project config, user aliases take precedence. Project-config aliases require [command approval](@/hook.md#security) on first run (same as project hooks). User-config aliases are trusted.
+## Format
+
+Each line is `<event> <branch> <path>`, with `-` as the branch of a detached worktree. Branch names can't contain spaces, so everything after the second space is the path.
+
+| Event | Meaning |
+|-------|---------|
+| `worktree-created` | A worktree appeared |
+| `worktree-removed` | A worktree went away |
+| `branch-switched` | A worktree now has a different branch checked out |
+| `branch-dirty` | A worktree gained uncommitted changes |
+| `branch-clean` | A worktree's uncommitted changes are gone |
+
+The state is printed first — `worktree-created` plus `branch-dirty` or `branch-clean` for each worktree — so a reader starts from a complete picture. `--once` stops there; `--follow` goes on to print changes.
+
+## How it works
+
+Worktrees are checked every `--interval`. Changes that happen within one interval are reported together, so a burst of edits yields a single `branch-dirty`. Output is flushed after each batch, and the command exits quietly when the reader closes the pipe — noticed at the next batch — so it can run under a tmux hook or a process supervisor.
+"#)]
+    Events {
+        /// Keep running and print changes
+        #[arg(long, conflicts_with = "once")]
+        follow: bool,
+
+        /// Print the current state and exit [default]
+        #[arg(long)]
+        once: bool,
+
+        /// How often to check for changes
+        #[arg(long, default_value = "1s")]
+        interval: String,
+    },
+
+    /// Show which worktree a path belongs to
+    ///
+    /// Prints the owning worktree's branch and path, for scripts and editor plugins that would otherwise parse `git worktree list`.
+    #[command(after_long_help = r#"## Examples
+
+Which worktree is the current directory in?
+
+```console
+wt which
+```
+
+Find the worktree of a file, as JSON:
+
+```console
+wt which src/lib.rs --format=json
+```
+
+## Format
+
+One line, `<branch> <path>`, with `-` as the branch of a detached worktree. With `--format=json`, an object with `branch`, `path`, `is_primary` (the main worktree, or for bare repositories the default branch's), and `in_git_dir`.
+
+Exits non-zero when the path isn't inside any worktree of its repository.
+
+## How it works
+
+Both the path and the worktree roots are resolved through symlinks before comparing, and the deepest root containing the path wins, so a worktree nested inside another resolves to the inner one. Paths inside the shared `.git` directory belong to the primary worktree. Worktrees come from a single `git worktree list`, so the command stays fast in repositories with many worktrees.
+"#)]
+    Which {
+        /// File or directory to look up [default: current directory]
+        path: Option<PathBuf>,
+
+        /// Output format (table, json)
+        ///
+        /// JSON follows a versioned schema; see --schema-version.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// Summarize worktrees with aggregate numbers
+    ///
+    /// Counts worktrees by age and state, and totals uncommitted lines and commits behind the default branch — numbers for a retro, or to spot worktrees that need attention.
+    #[command(after_long_help = r#"## Examples
+
+Print the summary:
+
+```console
+wt stats
+```
+
+Track the numbers over time:
+
+```console
+wt stats --format=json >> stats.jsonl
+```
+
+## Measures
+
+| Measure | Meaning |
+|---------|---------|
+| Worktrees | Every worktree, including the main one |
+| Age | Time since each worktree's last commit, in fixed buckets: under 1 day, 1–7 days, 7–30 days, 30–90 days, and 90 days or more. Lower bounds are inclusive, so a commit exactly 7 days old counts toward 7–30 days |
+| Uncommitted | Added and deleted lines in tracked files, summed across worktrees, and how many worktrees have any |
+| Behind | Worktrees missing commits from the default branch, the total they're missing, and the most any one is missing |
+| States | Worktrees per `main_state`, the relationship to the default branch shown in the Status column of `wt list` |
+
+Buckets don't depend on the data, so reports taken at different times compare directly. With `--format=json`, the same numbers as an object with `worktrees`, `age` (`under_day`, `under_week`, `under_month`, `under_quarter`, `older`), `uncommitted` (`worktrees`, `added`, `deleted`), `behind` (`worktrees`, `commits`, `max`), and `states`.
+
+## How it works
+
+The numbers come from the same survey as `wt list`, so they match the table. Worktrees only: branches without a worktree aren't counted. Unlike `wt list`, no git command is cut short by `[list] timeout-ms`, so every worktree is counted.
+"#)]
+    Stats {
+        /// Output format (table, json)
+        ///
+        /// JSON follows a versioned schema; see --schema-version.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// Summarize the local usage journal
+    ///
+    /// Shows which commands and flags were used, how often, and how long they took — from the journal kept when `usage-journal = true`. Nothing is collected otherwise, and nothing leaves the machine.
+    #[command(after_long_help = r#"## Examples
+
+Print the summary:
+
+```console
+wt report
+```
+
+Share it in a survey:
+
+```console
+wt report --format=json > usage.json
+```
+
+## Recording
+
+Off by default. Enable it in the user config:
+
+```toml
+usage-journal = true
+```
+
+After each command finishes and its output is written, Worktrunk appends one line to `usage.jsonl` in the platform data directory (`~/.local/share/worktrunk/` on Linux): the subcommand, the names of the flags given, the duration, and whether it succeeded. Flag values, positional arguments, branch names and paths are never recorded; `wt step` aliases appear as `step (alias)`. Past 256KB the file is moved to `usage.jsonl.old`, so the journal stays under about 512KB.
+
+## Output
+
+| Column | Meaning |
+|--------|---------|
+| Runs | Times the command ran |
+| Failed | Runs that exited with an error |
+| Median | Median duration |
+| Flags | Runs that gave each flag, most used first |
+
+With `--format=json`, an object with `since` (Unix timestamp of the oldest entry), `runs`, and `commands`, each with `command`, `runs`, `failed`, `median_ms`, and `flags`.
+"#)]
+    Report {
+        /// Output format (table, json)
+        ///
+        /// JSON follows a versioned schema; see --schema-version.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// Explain the symbols in `wt list`
+    ///
+    /// Prints every status and CI symbol with its meaning, in the colors of the current theme.
+    #[command(after_long_help = r#"## How it works
+
+Each symbol is drawn by the same code that draws it in `wt list`, so colors follow `[theme]` and the terminal background. Within a Status subcolumn, `wt list` shows only the first matching symbol, in the order listed.
+
+See [wt list](@/list.md#status-symbols) for the full description of each column.
+"#)]
+    Legend,
+
+    /// Hide branches from `wt list`
+    ///
+    /// For long-lived worktrees you never operate on, such as a `gh-pages` checkout. Hidden rows are left out of `wt list` unless `--hidden` is given.
+    #[command(after_long_help = r#"## Examples
+
+Hide a permanent docs checkout:
+
+```console
+wt hide gh-pages
+```
+
+Show everything, with hidden rows marked:
+
+```console
+wt list --hidden
+```
+
+## How it works
+
+The hidden set is stored per repository in git config, as `worktrunk.state.<branch>.hidden`. Hiding only affects listings: `wt list` counts hidden rows in its footer, and `wt switch`, `wt remove`, and other commands that name a hidden branch treat it — and its safety checks — as usual. `wt step prune` skips hidden branches unless `--include-hidden` is given.
+"#)]
+    Hide {
+        /// Branches to hide
+        #[arg(required = true, add = crate::completion::local_branches_completer())]
+        branches: Vec<String>,
+    },
+
+    /// Show hidden branches in `wt list` again
+    Unhide {
+        /// Branches to unhide
+        #[arg(required = true, add = crate::completion::local_branches_completer())]
+        branches: Vec<String>,
+    },
+
+    /// Record which branch a branch is stacked on
+    ///
+    /// For stacked branches, such as `ui` built on top of `api`. A branch with a recorded parent is compared with its parent rather than the default branch.
+    #[command(
+        args_conflicts_with_subcommands = true,
+        after_long_help = r#"## Examples
+
+Start a branch on top of another; `--base` records the parent:
+
+```console
+wt switch --create ui --base api
+```
+
+Record a parent for an existing branch, and show it:
+
+```console
+wt parent set api --branch=ui
+wt parent --branch=ui
+```
+
+## How it works
+
+The parent is stored in git config as `branch.<branch>.worktrunkParent`, so it goes away with the branch. `wt switch --create --base <branch>` records it when the base is a local branch other than the default branch.
+
+With a parent recorded, `wt list` takes the branch's `main↕` and `main…±` against the parent and shows the parent after the branch name, as `ui (on api)`. An `--against` ref still applies to every row. In JSON, the item has a `parent` field and `main.against` names the parent. Status symbols, and what counts as integrated, are still judged against the default branch.
+
+`wt merge --restack` and `wt step prune --restack` rebase branches in the order their recorded parents give, once the branch at the bottom of a stack is finished; branches without a parent are found from the commit graph as before.
+
+A parent that would make a branch its own ancestor is rejected.
+"#
+    )]
+    Parent {
+        #[command(subcommand)]
+        action: Option<ParentCommand>,
+
+        /// Target branch (defaults to current)
+        #[arg(long, add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+    },
+
+    /// Fix repository state that has drifted from the remote
+    ///
+    /// With `--default-branch`, follows a rename of the remote's default branch, such as `master` to `main`.
+    #[command(after_long_help = r#"## Examples
+
+After the remote renamed `master` to `main`:
+
+```console
+wt repair --default-branch
+```
+
+## How it works
+
+When the local default branch tracks a remote branch that no longer exists, and the remote's `HEAD` points to another branch, `wt list` warns once and suggests this command. It:
+
+1. Fetches the remote and updates `origin/HEAD` to the remote's current default branch.
+2. Renames the local branch to the new name, tracking the new remote branch — unless a branch of that name already exists or the old branch has commits the new one lacks.
+3. Updates the cached default branch, so `wt list`, `wt merge`, and the rest compare against the new branch from then on.
+4. Re-points `compare-ref` under this project's `[projects."<id>".list]` in user config when it names the old branch, after showing a diff and asking for confirmation. A top-level `[list] compare-ref` is left alone, since it applies to every repository.
+"#)]
+    Repair {
+        /// Follow a rename of the remote's default branch
+        #[arg(long, required = true)]
+        default_branch: bool,
+
+        /// Skip the confirmation prompt for config changes
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Clean up local branches
+    Branches {
+        #[command(subcommand)]
+        action: BranchesCommand,
+    },
+
+    /// Shelve a branch as a bundle and remove it
+    ///
+    /// Saves the branch as a `git bundle` in the worktrunk data directory, then removes its worktree and deletes the branch. `wt archive restore` brings it back.
+    #[command(
+        args_conflicts_with_subcommands = true,
+        after_long_help = r#"## Examples
+
+Archive the current branch:
+
+```console
+wt archive
+```
+
+Archive another branch, keeping its uncommitted changes:
+
+```console
+wt archive feature --uncommitted -m "Paused until the API lands"
+```
+
+See what's archived, and bring a branch back:
+
+```console
+wt archive list
+wt archive restore feature-20250102-000000
+```
+
+## What an archive holds
+
+Each archive is a directory under `<data dir>/worktrunk/archives/` (`~/.local/share` on Linux), named after the branch and the time it was archived:
+
+- `branch.bundle` — the branch's commits since it diverged from the default branch
+- `changes.patch` — uncommitted changes, with `--uncommitted`
+- `meta.json` — branch, project, date, base commit, and message
+
+The bundle is verified before anything is deleted. Because it leaves out history the default branch already has, restoring needs that history to still be in the repository — `wt archive restore` checks this first.
+
+Without `--uncommitted`, a worktree with uncommitted changes isn't archived. The default branch and the main worktree can't be archived.
+
+## Restoring
+
+`wt archive restore` fetches the branch from the bundle, creates a worktree at the configured path, and applies `changes.patch` if there is one. An existing branch of the same name is left alone unless `--force` is given. The archive is deleted after a successful restore, and kept if the patch fails to apply.
+"#
+    )]
+    Archive {
+        #[command(subcommand)]
+        action: Option<ArchiveCommand>,
+
+        /// Branch to archive [default: current]
+        branch: Option<String>,
+
+        /// Save uncommitted changes as a patch
+        #[arg(long)]
+        uncommitted: bool,
+
+        /// Note to store with the archive [default: tip commit subject]
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+
+    /// Clone a repository, ready for worktrees
+    ///
+    /// With `--bare`, clones into `<dir>/.git` and checks out the default branch as a worktree beside it.
+    #[command(after_long_help = r#"## Examples
+
+Clone into `./app`, with the default branch in the primary checkout:
+
+```console
+wt clone https://github.com/org/app.git
+```
+
+Clone a bare repository, with each branch as a subdirectory:
+
+```console
+wt clone --bare https://github.com/org/app.git
+```
+
+```
+app/
+├── .git/       # bare repository
+└── main/       # default branch
+```
+
+Add a starter project config too:
+
+```console
+wt clone --bare --init https://github.com/org/app.git
+```
+
+## How it works
+
+Without `--bare`, this is `git clone`: the default branch is checked out in the primary checkout, and `wt switch --create` puts new worktrees wherever `worktree-path` says.
+
+With `--bare`, after cloning into `<dir>/.git` it:
+
+1. Sets the fetch refspec to `+refs/heads/*:refs/remotes/origin/*` and fetches, so remote branches appear as `origin/<branch>` as in a normal clone. `git clone --bare` maps them straight onto local branches.
+2. Sets `origin/HEAD`, caches the default branch, and makes the default branch track `origin/<default>`.
+3. Deletes the local branches `git clone --bare` created, except the default branch.
+4. Adds a worktree for the default branch: beside `.git` if `worktree-path` is unset, otherwise where it says. A path outside `allowed-worktree-roots` is refused.
+5. If `worktree-path` was unset, offers to save `worktree-path = "../{{ branch | sanitize }}"` for this project in user config, so later worktrees land beside `.git` too. Without a terminal, it shows the setting to add instead.
+
+`--init` writes the example `.config/wt.toml`, every value commented out, to the default branch's worktree, unless the repository already has one.
+
+Hooks don't run for the initial worktree — the project's hooks haven't been approved yet.
+"#)]
+    Clone {
+        /// Repository URL or path
+        url: String,
+
+        /// Directory to clone into [default: from the URL]
+        dir: Option<PathBuf>,
+
+        /// Clone a bare repository into `<dir>/.git`, with worktrees beside it
+        #[arg(long)]
+        bare: bool,
+
+        /// Write a starter `.config/wt.toml`
+        #[arg(long)]
+        init: bool,
+    },
+
+    /// Deprecated: use `wt switch` instead
+    ///
+    /// Interactive worktree picker (now integrated into `wt switch`).
+    #[command(hide = true)]
+    Select {
+        /// Include branches without worktrees
+        #[arg(long)]
+        branches: bool,
+
+        /// Include remote branches
+        #[arg(long)]
+        remotes: bool,
+    },
+
+    /// Run individual operations
+    ///
+    /// The building blocks of `wt merge` — commit, squash, rebase, push — plus standalone utilities.
+    #[command(
+        name = "step",
+        after_long_help = r#"## Examples
+
+Commit with LLM-generated message:
+
+```console
+wt step commit
+```
+
+Manual merge workflow with review between steps:
+
+```console
+wt step commit
+wt step squash
+wt step rebase
+wt step push
+```
+
+## Operations
+
+- `commit` — Stage and commit with [LLM-generated message](@/llm-commits.md)
+- `squash` — Squash all branch commits into one with [LLM-generated message](@/llm-commits.md)
+- `rebase` — Rebase onto target branch
+- `push` — Fast-forward target to current branch
+- `diff` — Show all changes since branching (committed, staged, unstaged, untracked)
+- `copy-ignored` — Copy gitignored files between worktrees
+- `for-each` — [experimental] Run a command in every worktree
+- `promote` — [experimental] Put a branch into the main worktree
+- `graduate` — [experimental] Put a detached worktree on a new branch
+- `prune` — Remove worktrees and branches merged into the default branch
+- `relocate` — [experimental] Move worktrees to expected paths
+- `optimize` — [experimental] Speed up git in large repos
+- `<alias>` — [experimental] Run a configured command alias
+
+## Aliases
+
+Custom command templates configured in user config (`~/.config/worktrunk/config.toml`) or project config (`.config/wt.toml`). Aliases support the same [template variables](@/hook.md#template-variables) as hooks.
+
+```toml
+# .config/wt.toml
+[aliases]
+deploy = "make deploy BRANCH={{ branch }}"
+port = "echo http://localhost:{{ branch | hash_port }}"
+```
+
+```console
+wt step deploy                            # run the alias
+wt step deploy --dry-run                  # show expanded command
+wt step deploy --var env=staging          # pass extra template variables
+wt step deploy --yes                      # skip approval prompt
+```
+
+When defined in both user and project config, user aliases take precedence. Project-config aliases require [command approval](@/hook.md#security) on first run (same as project hooks). User-config aliases are trusted.
 
 Alias names that match a built-in step command (`commit`, `squash`, etc.) are shadowed by the built-in and will never run.
 
@@ -1354,6 +2352,19 @@ if ctx['branch'].startswith('feature/') and 'backend' in ctx['repo']:
     subprocess.run(['make', 'seed-db'])
 ```
 
+The same JSON is in the `WT_HOOK_CONTEXT` environment variable, for commands that read stdin for something else:
+
+```toml
+[pre-merge]
+changelog = "git log --oneline {{ default_branch }}.. | python3 scripts/check-changelog.py"
+```
+
+```python
+import json, os, sys
+ctx = json.loads(os.environ['WT_HOOK_CONTEXT'])
+commits = sys.stdin.read().splitlines()
+```
+
 # Running Hooks Manually
 
 `wt hook <type>` runs hooks on demand — useful for testing during development, running in CI pipelines, or re-running after a failure.
@@ -1613,6 +2624,19 @@ worktree-path = "~/worktrees/{{ repo }}/{{ branch | sanitize }}"
 
 `~` expands to the home directory. Relative paths are relative to the repository root.
 
+On case-insensitive filesystems (default on macOS and Windows), `Feature/Login` and `feature/login` map to the same directory. Worktrunk treats such paths as colliding; to give each branch a lowercase directory instead, lowercase the `sanitize` output:
+
+```toml
+# "never" (default), "always", or "auto" — lowercase only where the filesystem ignores case
+slug-lowercase = "auto"
+```
+
+On shared machines, `allowed-worktree-roots` keeps worktrees in known places. `wt switch`, `wt clone --bare`, `wt step relocate` and `wt step graduate` refuse to put a worktree outside these directories, whether the path comes from the template or `--new-path`. Paths are compared after resolving symlinks and `..`; `wt switch --allow-any-path` asks before creating one elsewhere.
+
+```toml
+allowed-worktree-roots = ["~/worktrees", "/scratch"]
+```
+
 ## LLM commit messages
 
 Generate commit messages automatically during merge. Requires an external CLI tool.
@@ -1632,6 +2656,11 @@ summary = false    # Enable LLM branch summaries (requires [commit.generation])
 full = false       # Show CI, main…± diffstat, and LLM summaries (--full)
 branches = false   # Include branches without worktrees (--branches)
 remotes = false    # Include remote-only branches (--remotes)
+# max-path-width = 40  # Ellipsize longer paths in the middle (0 disables)
+# compare-ref = "release/2.4"  # Compare against this ref, not the default branch (--against)
+# layout = "drop"  # "shrink" truncates Branch, Path, URL, Activity before hiding columns
+# pager = "less"  # Page tables taller than the terminal ("cat" prints every row)
+# density = "normal"  # Column gaps: "compact" (1), "normal" (2), "wide" (3) (--density)
 ```
 
 ### Commit
@@ -1656,6 +2685,16 @@ remove = true      # Remove worktree after merge (--no-remove to keep)
 verify = true      # Run project hooks (--no-verify to skip)
 ```
 
+### Worktree limits
+
+Limits on how many worktrees `wt switch --create` makes. Per-project values go under `[projects."<id>".switch]`.
+
+```toml
+[switch]
+# max-worktrees = 20   # Refuse to create worktrees once this many exist
+# warn-worktrees = 10  # Suggest `wt step prune` once this many exist
+```
+
 ### Switch picker
 
 Configuration for `wt switch` interactive picker.
@@ -1670,6 +2709,92 @@ Configuration for `wt switch` interactive picker.
 # timeout-ms = 200
 ```
 
+### Picker keys
+
+Key bindings for `wt switch` interactive picker actions, using skim key syntax. Set a key to `""` to disable the action and hide it from the controls line.
+
+```toml
+[keys]
+# switch = "enter"
+# create = "alt-c"
+# remove = "alt-r"
+# fetch = "alt-f"       # Fetch the branch's upstream
+# open = "alt-o"        # Open in $VISUAL or $EDITOR
+# copy-path = "alt-y"   # Copy the worktree path to the clipboard
+```
+
+### Theme
+
+Severity colors for numbers in `wt list`. Each value is a color name, 256-color index, or `#rrggbb`, optionally with `bold`, `dim`, `italic` or `underline`.
+
+Unset keys come from a built-in dark or light theme, matched to the terminal background (read from `COLORFGBG`, or asked of the terminal). `base` picks one instead; `theme = "light"` on its own does the same. `wt config show` reports which is in use. The defaults below are the dark theme's.
+
+```toml
+[theme]
+# base = "auto"              # "dark", "light" or "auto"
+# ahead = "green"
+# behind = "red dim"
+# behind-warn = "yellow"     # From behind-warn-at commits behind
+# behind-critical = "red"    # From behind-critical-at commits behind
+# behind-warn-at = 10
+# behind-critical-at = 50
+# added = "green"
+# deleted = "red"
+# conflicts = "red bold"
+```
+
+### Activity log
+
+Records hook, `--execute` and `wt step for-each` commands per worktree, shown in `wt list --long` and an `Activity` column. Off by default; entries stay local, in the repository's `.git/wt-logs/activity/`.
+
+```toml
+[activity]
+# enabled = false
+# size = 10             # Entries kept per worktree
+# on-remove = "delete"  # Or "archive" to keep the log after `wt remove`
+```
+
+### Scratch directory
+
+A gitignored directory in each worktree for notes and build leftovers that outlive it. `wt remove` keeps it in `.git/wt-scratch/<branch>/`, and creating a worktree for the branch again brings it back. The directory is added to `.git/info/exclude`. Off by default; `wt list --long` notes branches with a kept directory.
+
+```toml
+[scratch]
+# enabled = false
+# dir = ".wt-scratch"
+```
+
+### Integrations
+
+Tells other tools about worktree paths. With `zoxide = true`, `wt switch` runs `zoxide add` for the worktree it lands in and `wt remove` runs `zoxide remove`; it's skipped when `zoxide` isn't on PATH. `on-path-created` runs after `wt switch` creates a worktree and `on-path-removed` after `wt remove`, with `{{ worktree_path }}`, `{{ branch }}` and `{{ repo }}`. Failures print a warning and never change the exit code.
+
+```toml
+[integrations]
+# zoxide = false
+# on-path-created = "my-index add {{ worktree_path }}"
+# on-path-removed = "my-index remove {{ worktree_path }}"
+```
+
+### Default flags
+
+Flags added to a subcommand on every run, ahead of the command-line arguments. For `wt list` and `wt merge`, a flag repeated on the command line wins, as does its `--no-*` form; other commands reject a repeated flag. Values are split on whitespace.
+
+```toml
+[defaults]
+# list = "--branches --full"
+# merge = "--no-remove"
+```
+
+### Command aliases
+
+Shortcuts for `wt` commands: with the alias below, `wt sw feature` runs `wt switch --create feature`. Arguments after the alias follow its expansion, and an expansion may start with another alias. Names of built-in commands can't be reused. `wt --list-aliases` prints the configured aliases.
+
+```toml
+[alias]
+# sw = "switch --create"
+# ls = "list --branches"
+```
+
 ### Aliases
 
 Command templates that run with `wt step <name>`. See [`wt step` aliases](@/step.md#aliases) for usage and flags.
@@ -1804,6 +2929,8 @@ Combine these commits into a single commit message.
 
 Project config (`.config/wt.toml`) defines lifecycle hooks and project-specific settings. This file is checked into version control and shared with the team. Create with `wt config create --project`.
 
+A worktree without its own `.config/wt.toml` uses the primary worktree's copy, so worktrees created before the file was committed still pick it up. `wt config show` reports which file is in use.
+
 See [`wt hook`](@/hook.md) for hook types, execution order, template variables, and examples.
 
 ### Non-hook settings
@@ -1818,11 +2945,41 @@ url = "http://localhost:{{ branch | hash_port }}"
 # Override CI platform detection for self-hosted instances
 [ci]
 platform = "github"  # or "gitlab"
+include-default-branch = true  # CI on the default branch's row (its latest commit)
 
 # Command aliases (run with wt step <name>)
 [aliases]
 deploy = "make deploy BRANCH={{ branch }}"
 test = "cargo test"
+
+# Offered after switching to a matching branch (auto = true runs it without asking)
+[[on-switch]]
+pattern = "feature/*"
+run = "npm run dev"
+
+# Per-branch worktree location and hook overrides (first matching pattern wins)
+[[worktree-profiles]]
+name = "hotfix"
+pattern = "hotfix/*"
+worktree-path = "/scratch/{{ repo }}.{{ branch | sanitize }}"
+post-create = {}
+
+# Naming rules for new branches (wt switch --create, wt step graduate)
+[branch-name-rules]
+allow = ["^(feature|fix|chore)/"]  # must match one
+deny = ["(?i)wip"]                 # must match none
+max-length = 50
+case = "lower"
+
+# Email a new worktree's git identity must match; checked before its hooks run
+[identity]
+require-email-pattern = "@example[.]com$"
+
+# Git config set in each new worktree only (`git config --worktree`), turning on
+# extensions.worktreeConfig if needed. Quote the keys; values are templates
+[git-config]
+"pull.rebase" = "true"
+"core.untrackedCache" = "true"
 ```
 
 # Shell Integration
@@ -1839,10 +2996,29 @@ Without shell integration, `wt switch` prints the target directory but cannot `c
 
 ### First-run prompts
 
-On first run without shell integration, Worktrunk offers to install it. Similarly, on first commit without LLM configuration, it offers to configure a detected tool (`claude`, `codex`). Declining sets `skip-shell-integration-prompt` or `skip-commit-generation-prompt` automatically.
+On first run without shell integration, Worktrunk explains what it does and offers to install it. Similarly, on first commit without LLM configuration, it offers to configure a detected tool (`claude`, `codex`). Declining sets `skip-shell-integration-prompt` or `skip-commit-generation-prompt` automatically.
+
+The shell integration offer only appears in a terminal, at most three times, and never again once `wt config shell install` has succeeded. To turn it off without answering:
+
+```toml
+[hints]
+disabled = ["first-run"]
+```
 
 # Other
 
+## Update check
+
+Set `update-check = true` to hear about new releases. At most once a day, after a command finishes, Worktrunk asks GitHub for the latest version in a background process, so no command waits on the network. When the answer is newer than the installed version, the next command prints a one-line notice on stderr. The check is skipped with `--offline`, in CI (`CI` set) and when output isn't a terminal. `wt config show --full` shows when it last ran and what it found.
+
+## Repository performance
+
+Set `performance-hint = true` for a reminder in large repos (about 100,000 objects or more) that have no commit-graph or don't run `git maintenance`, both of which make `wt list` noticeably slower. It appears after `wt list` at most once a week per repo; `wt step optimize` explains each fix and asks before applying it. `wt config show --full` reports the same check.
+
+## Usage journal
+
+Set `usage-journal = true` to keep a local record of which commands and flags you use. After each command, Worktrunk appends its name, the names of the flags given, its duration and whether it succeeded to `usage.jsonl` in the platform data directory. Flag values, branch names and paths are never written, and nothing is sent anywhere; `wt report` summarizes the file.
+
 ## Environment variables
 
 All user config options can be overridden with environment variables using the `WORKTRUNK_` prefix.
@@ -1880,6 +3056,7 @@ WORKTRUNK_COMMIT__GENERATION__COMMAND="echo 'test: automated commit'" wt merge
 | `WORKTRUNK_DIRECTIVE_FILE` | Internal: set by shell wrappers to enable directory changes |
 | `WORKTRUNK_SHELL` | Internal: set by shell wrappers to indicate shell type (e.g., `powershell`) |
 | `WORKTRUNK_MAX_CONCURRENT_COMMANDS` | Max parallel git commands (default: 32). Lower if hitting file descriptor limits. |
+| `WORKTRUNK_OFFLINE` | Set to `1` to forbid network access, like `--offline` |
 | `NO_COLOR` | Disable colored output ([standard](https://no-color.org/)) |
 | `CLICOLOR_FORCE` | Force colored output even when not a TTY |
 <!-- subdoc: show -->
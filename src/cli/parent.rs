@@ -0,0 +1,71 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt parent`
+#[derive(Subcommand)]
+pub enum ParentCommand {
+    /// Show the branch's recorded parent
+    #[command(after_long_help = r#"## Examples
+
+Show the current branch's parent:
+
+```console
+wt parent
+```
+
+Show another branch's parent:
+
+```console
+wt parent get --branch=ui
+```"#)]
+    Get {
+        /// Target branch (defaults to current)
+        #[arg(long, add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+    },
+
+    /// Record the branch a branch is stacked on
+    #[command(after_long_help = r#"## Examples
+
+Stack the current branch on `api`:
+
+```console
+wt parent set api
+```
+
+Stack another branch:
+
+```console
+wt parent set api --branch=ui
+```
+
+A parent that would make a branch its own ancestor, such as stacking `api` on `ui` after the above, is rejected."#)]
+    Set {
+        /// Branch it's stacked on
+        #[arg(add = crate::completion::local_branches_completer())]
+        parent: String,
+
+        /// Target branch (defaults to current)
+        #[arg(long, add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+    },
+
+    /// Forget the branch's recorded parent
+    #[command(after_long_help = r#"## Examples
+
+Compare the current branch with the default branch again:
+
+```console
+wt parent clear
+```
+
+Clear another branch's parent:
+
+```console
+wt parent clear --branch=ui
+```"#)]
+    Clear {
+        /// Target branch (defaults to current)
+        #[arg(long, add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+    },
+}
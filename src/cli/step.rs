@@ -334,6 +334,8 @@ The `.worktreeinclude` pattern is shared with [Claude Code on desktop](https://c
 
 Context JSON is piped to stdin for scripts that need structured data.
 
+Worktrees locked with `git worktree lock` are skipped and listed in the summary; `--include-locked` runs in them too.
+
 ## Template variables
 
 All variables are shell-escaped. See [`wt hook` template variables](@/hook.md#template-variables) for the complete list and filters.
@@ -371,6 +373,10 @@ Note: This command is experimental and may change in future versions.
         /// Command template (see --help for all variables)
         #[arg(required = true, last = true, num_args = 1..)]
         args: Vec<String>,
+
+        /// Also run in worktrees locked with `git worktree lock`
+        #[arg(long)]
+        include_locked: bool,
     },
 
     /// \[experimental\] Put a branch into the main worktree
@@ -424,13 +430,62 @@ Gitignored files are swapped along with the branches so each worktree keeps the
         branch: Option<String>,
     },
 
+    /// \[experimental\] Put a detached worktree on a new branch
+    ///
+    /// Keeps exploratory work started from a bare commit.
+    #[command(
+        after_long_help = r#"Creates the branch at `HEAD`, attaches the current worktree to it, and moves the worktree to the path the `worktree-path` template gives the new branch. Uncommitted changes come along.
+
+## Example
+
+```console
+# from a worktree detached at a commit
+$ wt step graduate fix-parser --push
+```
+
+## Moving
+
+The worktree stays put when `--no-move` is given, when it's the main worktree, or when something already exists at the templated path. With shell integration, the shell follows a moved worktree.
+
+## Upstream
+
+With `--push`, the new branch is pushed to the primary remote and set as its upstream.
+
+## Requirements
+
+- The worktree must have a detached `HEAD`; to rename an existing branch, use `git branch -m`
+- The branch must not exist yet
+- The name must pass `[branch-name-rules]` in project config, unless `--no-name-rules` is given
+"#
+    )]
+    Graduate {
+        /// Name for the new branch
+        branch: String,
+
+        /// Keep the worktree at its current path
+        #[arg(long)]
+        no_move: bool,
+
+        /// Push the branch and set its upstream
+        #[arg(long)]
+        push: bool,
+
+        /// Use the fixed-up name when the given one breaks naming rules
+        #[arg(long)]
+        fix_name: bool,
+
+        /// Skip the project's branch naming rules
+        #[arg(long, conflicts_with = "fix_name")]
+        no_name_rules: bool,
+    },
+
     /// \[experimental\] Remove worktrees merged into the default branch
     #[command(
         after_long_help = r#"Bulk-removes worktrees and branches that are integrated into the default branch, using the same criteria as `wt remove`'s branch cleanup. Stale worktree entries are cleaned up too.
 
-In `wt list`, candidates show `_` (same commit) or `⊂` (content integrated). Run `--dry-run` to preview. See `wt remove --help` for the full integration criteria.
+In `wt list`, candidates show `_` (same commit) or `⊂` (content integrated). Worktrees whose branch was deleted out from under them are candidates too, unless their last commit is on no other branch or tag. Run `--dry-run` to preview. See `wt remove --help` for the full integration criteria.
 
-Locked worktrees and the main worktree are always skipped. The current worktree is removed last, triggering cd to the primary worktree. Pre-remove and post-remove hooks run for each removal.
+Locked worktrees and the main worktree are always skipped, as are branches hidden by `wt hide` unless `--include-hidden` is given. Integrated worktrees skipped for being locked are listed with their lock reason; run `git worktree unlock` to let prune remove them. The current worktree is removed last, triggering cd to the primary worktree. Pre-remove and post-remove hooks run for each removal.
 
 ## Min-age guard
 
@@ -441,6 +496,17 @@ wt step prune --min-age=0s     # no age guard
 wt step prune --min-age=2d     # skip worktrees younger than 2 days
 ```
 
+## Squash merges
+
+Squash and rebase merges rewrite the branch's commits, so git can't see that it was merged. With `--squash-aware`, two more signals count, provided the worktree is clean:
+
+- **Local patches** — the default branch already has the branch's patches: `git cherry` matches every commit, or one commit has the same diff as the whole branch. `wt list` marks these branches with `≈` (the whole-branch diff comparison needs `--full`).
+- **Merged PR** — the branch's GitHub PR merged and the PR head matches the local branch (nothing unpushed). `wt list --full` marks these branches with `✔` in the CI column.
+
+Git's merged check still refuses these branches, so prune force-deletes them only after a yes at the prompt (or with `--yes`); without a terminal, they're skipped.
+
+Branches created from a pruned branch — stacked on it — still carry its original commits. Prune warns about them; with `--restack`, it rebases them onto the default branch, as [`wt merge --restack`](@/merge.md#stacked-branches) does.
+
 ## Examples
 
 Preview what would be removed:
@@ -469,9 +535,27 @@ wt step prune
         #[arg(long, default_value = "1h")]
         min_age: String,
 
+        /// Also remove squash-merged branches
+        ///
+        /// Squash and rebase merges leave a branch looking unmerged to git. With
+        /// this flag, a branch also counts when the default branch already has
+        /// its patches (by git cherry or patch-id), or when its GitHub PR merged
+        /// and the PR head matches the local branch. The PR check needs an
+        /// authenticated gh.
+        #[arg(long)]
+        squash_aware: bool,
+
         /// Run removal in foreground (block until complete)
         #[arg(long)]
         foreground: bool,
+
+        /// Rebase branches stacked on pruned ones onto the default branch
+        #[arg(long)]
+        restack: bool,
+
+        /// Also remove branches hidden by `wt hide`
+        #[arg(long)]
+        include_hidden: bool,
     },
 
     /// \[experimental\] Move worktrees to expected paths
@@ -553,6 +637,32 @@ Note: This command is experimental and may change in future versions.
         clobber: bool,
     },
 
+    /// \[experimental\] Speed up git in large repos
+    ///
+    /// Writes a commit-graph and starts git maintenance.
+    #[command(
+        after_long_help = r#"`wt list` walks the history of every branch for ahead/behind counts and merge checks. In repos with more than about 100,000 objects, that's much faster with a commit-graph file, and background maintenance keeps the file current as the repo grows.
+
+For whichever is missing, explains what it does and asks before running:
+
+- `git commit-graph write --reachable`
+- `git maintenance start` — registers the repo with the system scheduler (cron, launchd or Task Scheduler)
+
+Nothing changes without confirmation; in a non-interactive session, pass `--yes`. Set `performance-hint = true` in the user config for a weekly reminder in large repos that need it.
+
+## Example
+
+```console
+wt step optimize
+```
+"#
+    )]
+    Optimize {
+        /// Apply all fixes without asking
+        #[arg(short, long, help_heading = "Automation")]
+        yes: bool,
+    },
+
     /// Catch-all for alias lookup
     #[command(external_subcommand)]
     External(Vec<String>),
@@ -20,6 +20,7 @@ use worktrunk::styling::{
 
 use crate::commands::command_approval::approve_alias;
 use crate::commands::command_executor::{CommandContext, build_hook_context};
+use crate::commands::find_closest_match;
 use crate::commands::for_each::{CommandError, run_command_streaming};
 
 /// Built-in `wt step` subcommand names. Aliases with these names are
@@ -29,6 +30,8 @@ const BUILTIN_STEP_COMMANDS: &[&str] = &[
     "copy-ignored",
     "diff",
     "for-each",
+    "graduate",
+    "optimize",
     "promote",
     "prune",
     "push",
@@ -128,20 +131,6 @@ fn alias_needs_approval(
     Some(project_template.clone())
 }
 
-/// Find the closest match for `input` among `candidates` using Jaro similarity.
-///
-/// Returns `Some(match)` if a candidate is sufficiently similar (threshold 0.7),
-/// `None` otherwise. Uses `jaro` (not `jaro_winkler`) with the same threshold
-/// as clap — see clap GH #4660 for why.
-fn find_closest_match<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
-    candidates
-        .iter()
-        .map(|c| (*c, strsim::jaro(input, c)))
-        .filter(|(_, score)| *score > 0.7)
-        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-        .map(|(name, _)| name)
-}
-
 /// Run a configured alias by name.
 ///
 /// Looks up the alias in merged config (project config + user config),
@@ -424,20 +413,6 @@ mod tests {
         assert_snapshot!(parse(&["deploy", "--var", "=value"]).unwrap_err(), @"--var key must not be empty (got '=value')");
     }
 
-    #[test]
-    fn test_find_closest_match() {
-        assert_eq!(
-            find_closest_match("deplyo", &["deploy", "hello"]),
-            Some("deploy")
-        );
-        assert_eq!(
-            find_closest_match("comit", &["commit", "squash", "push", "rebase"]),
-            Some("commit")
-        );
-        assert_eq!(find_closest_match("zzz", &["deploy", "hello"]), None);
-        assert_eq!(find_closest_match("deploy", &[]), None);
-    }
-
     /// Verify BUILTIN_STEP_COMMANDS stays in sync with the actual StepCommand variants.
     ///
     /// If a new step subcommand is added without updating BUILTIN_STEP_COMMANDS,
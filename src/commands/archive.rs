@@ -0,0 +1,366 @@
+//! Shelving branches as bundles (`wt archive`).
+//!
+//! An archive is a directory under the worktrunk data dir holding a
+//! `git bundle` of the branch, an optional patch of uncommitted changes, and
+//! a `meta.json` describing both. Archiving removes the worktree and deletes
+//! the branch; restoring fetches the branch back from the bundle and creates a
+//! worktree for it.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use color_print::cformat;
+use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
+use serde::{Deserialize, Serialize};
+use worktrunk::config::sanitize_branch_name;
+use worktrunk::git::{AddWorktreeOpts, GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, hint_message, info_message, println, success_message};
+use worktrunk::utils::get_now;
+
+use super::table::{self, CellStyle};
+use super::worktree::compute_worktree_path;
+
+const BUNDLE_FILE: &str = "branch.bundle";
+const PATCH_FILE: &str = "changes.patch";
+const META_FILE: &str = "meta.json";
+
+/// What an archive holds, stored as `meta.json` next to the bundle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ArchiveMeta {
+    branch: String,
+    /// Project identifier of the repository the branch came from
+    project: String,
+    /// Unix timestamp of the archive
+    created_at: u64,
+    /// Branch tip when archived
+    head: String,
+    /// Merge-base with the default branch; the bundle excludes its history
+    base: Option<String>,
+    /// `--message`, or the tip commit's subject
+    message: String,
+    /// Whether `changes.patch` holds uncommitted changes
+    has_patch: bool,
+}
+
+/// Directory holding all archives (`<data dir>/worktrunk/archives`).
+fn archives_dir() -> anyhow::Result<PathBuf> {
+    let strategy = choose_base_strategy().context("Failed to find the data directory")?;
+    Ok(strategy.data_dir().join("worktrunk").join("archives"))
+}
+
+/// Archive name: the sanitized branch and the archive time, e.g. `feature-x-20260116-093000`.
+fn archive_name(branch: &str, created_at: u64) -> String {
+    let stamp = chrono::DateTime::from_timestamp(created_at as i64, 0)
+        .map(|t| t.format("%Y%m%d-%H%M%S").to_string())
+        .unwrap_or_else(|| created_at.to_string());
+    format!("{}-{stamp}", sanitize_branch_name(branch))
+}
+
+/// Archives of this project, oldest first.
+fn list_archives(project: &str) -> anyhow::Result<Vec<(String, ArchiveMeta)>> {
+    let dir = archives_dir()?;
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+    let mut archives: Vec<_> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let json = fs::read_to_string(entry.path().join(META_FILE)).ok()?;
+            let meta: ArchiveMeta = serde_json::from_str(&json).ok()?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            (meta.project == project).then_some((name, meta))
+        })
+        .collect();
+    archives.sort_by_key(|(_, meta)| meta.created_at);
+    Ok(archives)
+}
+
+/// Handle `wt archive [<branch>]`.
+pub fn handle_archive(
+    branch: Option<String>,
+    uncommitted: bool,
+    message: Option<String>,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(branch) => branch,
+        None => repo
+            .current_worktree()
+            .branch()?
+            .context("Current worktree has no branch; specify one to archive")?,
+    };
+
+    if !repo.branch(&branch).exists_locally()? {
+        anyhow::bail!(cformat!("No local branch <bold>{branch}</>"));
+    }
+    if repo.default_branch().as_deref() == Some(branch.as_str()) {
+        anyhow::bail!(cformat!(
+            "Won't archive the default branch <bold>{branch}</>"
+        ));
+    }
+
+    let worktree = repo.worktree_for_branch(&branch)?;
+    if let Some(path) = &worktree {
+        if !repo.worktree_at(path).is_linked()? {
+            return Err(GitError::CannotRemoveMainWorktree.into());
+        }
+        if !uncommitted && repo.worktree_at(path).is_dirty()? {
+            anyhow::bail!(cformat!(
+                "<bold>{branch}</> has uncommitted changes; to save them in the archive, use <underline>--uncommitted</>"
+            ));
+        }
+    }
+
+    let head = repo
+        .run_command(&["rev-parse", &format!("refs/heads/{branch}")])?
+        .trim()
+        .to_string();
+    let base = match repo.default_branch() {
+        Some(default_branch) => repo
+            .merge_base(&default_branch, &head)?
+            .filter(|base| *base != head),
+        None => None,
+    };
+    let message = match message {
+        Some(message) => message,
+        None => repo
+            .run_command(&["log", "-1", "--format=%s", &head])?
+            .trim()
+            .to_string(),
+    };
+
+    let created_at = get_now();
+    let name = archive_name(&branch, created_at);
+    let dir = archives_dir()?.join(&name);
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create {}", format_path_for_display(&dir)))?;
+
+    // Exclude history the default branch already has, so the bundle holds
+    // only the branch's own commits
+    let bundle = dir.join(BUNDLE_FILE);
+    let bundle_str = bundle.to_string_lossy();
+    let branch_ref = format!("refs/heads/{branch}");
+    let mut args: Vec<&str> = vec!["bundle", "create", &bundle_str, &branch_ref];
+    let exclude = base.as_ref().map(|base| format!("^{base}"));
+    args.extend(exclude.as_deref());
+    repo.run_command(&args).context("Failed to create bundle")?;
+    repo.run_command(&["bundle", "verify", &bundle_str])
+        .context("Failed to verify bundle")?;
+
+    let mut has_patch = false;
+    if uncommitted && let Some(path) = &worktree {
+        let wt = repo.worktree_at(path);
+        // Mark untracked files as intent-to-add so the diff includes them;
+        // the index goes away with the worktree
+        wt.run_command(&["add", "--all", "--intent-to-add"])?;
+        // Raw bytes: changed files needn't be UTF-8
+        let output = wt.run_command_output(&["diff", "HEAD", "--binary"])?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to export uncommitted changes: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        let patch = output.stdout;
+        if !patch.is_empty() {
+            fs::write(dir.join(PATCH_FILE), patch).context("Failed to write patch")?;
+            has_patch = true;
+        }
+    }
+
+    let meta = ArchiveMeta {
+        branch: branch.clone(),
+        project: repo.project_identifier()?,
+        created_at,
+        head,
+        base,
+        message,
+        has_patch,
+    };
+    fs::write(dir.join(META_FILE), serde_json::to_string_pretty(&meta)?)
+        .context("Failed to write archive metadata")?;
+
+    // The bundle has every commit, so the branch goes even if it's unmerged
+    if let Some(path) = &worktree {
+        let home = repo.home_path()?;
+        // Leaving the worktree we're about to remove
+        if repo
+            .current_worktree_info()?
+            .is_some_and(|wt| wt.path == *path)
+        {
+            crate::output::change_directory(&home)?;
+        }
+        // Use home for discovery - the worktree being removed might be cwd,
+        // and git operations after removal need a valid working directory
+        let home_repo = Repository::at(&home)?;
+        home_repo.remove_worktree(path, uncommitted)?;
+        home_repo.run_command(&["branch", "-D", "--", &branch])?;
+    } else {
+        repo.run_command(&["branch", "-D", "--", &branch])?;
+    }
+
+    eprintln!(
+        "{}",
+        success_message(cformat!("Archived <bold>{branch}</> as <bold>{name}</>"))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "To restore, run <underline>wt archive restore {name}</>"
+        ))
+    );
+    Ok(())
+}
+
+/// Handle `wt archive list`.
+pub fn handle_archive_list() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let archives = list_archives(&repo.project_identifier()?)?;
+    if archives.is_empty() {
+        eprintln!("{}", info_message("No archives for this repository"));
+        return Ok(());
+    }
+
+    for line in render_table(&archives) {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// Render archives as an aligned `Name  Date  Message` table.
+fn render_table(archives: &[(String, ArchiveMeta)]) -> Vec<String> {
+    let rows: Vec<[String; 3]> = archives
+        .iter()
+        .map(|(name, meta)| {
+            let date = chrono::DateTime::from_timestamp(meta.created_at as i64, 0)
+                .map(|t| t.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            let mut message = meta.message.clone();
+            if meta.has_patch {
+                message.push_str(" (+ uncommitted)");
+            }
+            [name.clone(), date, message]
+        })
+        .collect();
+    table::render_table(
+        ["Name", "Date", "Message"],
+        [CellStyle::Bold, CellStyle::Dim, CellStyle::Plain],
+        &rows,
+    )
+}
+
+/// Handle `wt archive restore <name>`.
+pub fn handle_archive_restore(name: &str, force: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    // Look the name up rather than joining it, so it can't point outside the
+    // archives directory or at another project's archive
+    let (_, meta) = list_archives(&repo.project_identifier()?)?
+        .into_iter()
+        .find(|(archive, _)| archive == name)
+        .ok_or_else(|| {
+            anyhow::anyhow!(cformat!(
+                "No archive named <bold>{name}</>; to see archives, run <underline>wt archive list</>"
+            ))
+        })?;
+    let dir = archives_dir()?.join(name);
+    let branch = &meta.branch;
+
+    // Verify first: a thin bundle needs the default branch's history to still be here
+    let bundle = dir.join(BUNDLE_FILE);
+    let bundle_str = bundle.to_string_lossy();
+    repo.run_command(&["bundle", "verify", &bundle_str])
+        .with_context(|| cformat!("Archive <bold>{name}</> failed verification"))?;
+
+    if repo.branch(branch).exists_locally()? {
+        if !force {
+            anyhow::bail!(cformat!(
+                "Branch <bold>{branch}</> already exists; to replace it, use <underline>--force</>"
+            ));
+        }
+        if let Some(path) = repo.worktree_for_branch(branch)? {
+            anyhow::bail!(cformat!(
+                "Branch <bold>{branch}</> is checked out at {}",
+                format_path_for_display(&path)
+            ));
+        }
+    }
+
+    let refspec = format!("+refs/heads/{branch}:refs/heads/{branch}");
+    repo.run_command(&["fetch", "--no-tags", &bundle_str, &refspec])
+        .context("Failed to fetch from bundle")?;
+
+    let path = compute_worktree_path(&repo, branch, repo.user_config())?;
+    let _ = repo.add_worktree(AddWorktreeOpts {
+        branch: Some(branch.as_str()),
+        ..AddWorktreeOpts::new(&path)
+    })?;
+
+    let patch = dir.join(PATCH_FILE);
+    if meta.has_patch {
+        let patch_str = patch.to_string_lossy();
+        repo.worktree_at(&path)
+            .run_command(&["apply", "--binary", &patch_str])
+            .with_context(|| {
+                format!(
+                    "Failed to apply uncommitted changes; the patch is at {}",
+                    format_path_for_display(&patch)
+                )
+            })?;
+    }
+
+    fs::remove_dir_all(&dir)
+        .with_context(|| format!("Failed to remove {}", format_path_for_display(&dir)))?;
+    crate::output::change_directory(&path)?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Restored <bold>{branch}</> @ {}",
+            format_path_for_display(&path)
+        ))
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_name() {
+        assert_eq!(
+            archive_name("feature/login", 1735776000),
+            "feature-login-20250102-000000"
+        );
+    }
+
+    #[test]
+    fn test_render_table_aligns_columns() {
+        let meta = |message: &str, has_patch| ArchiveMeta {
+            branch: "b".into(),
+            project: "p".into(),
+            created_at: 1735776000,
+            head: String::new(),
+            base: None,
+            message: message.into(),
+            has_patch,
+        };
+        let lines = render_table(&[
+            ("a-20250102-000000".into(), meta("Add login", false)),
+            ("longer-20250102-000000".into(), meta("WIP", true)),
+        ]);
+        let lines: Vec<String> = lines
+            .iter()
+            .map(|l| ansi_str::AnsiStr::ansi_strip(l.as_str()).into_owned())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "Name                    Date        Message",
+                "a-20250102-000000       2025-01-02  Add login",
+                "longer-20250102-000000  2025-01-02  WIP (+ uncommitted)",
+            ]
+        );
+    }
+}
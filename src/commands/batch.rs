@@ -0,0 +1,160 @@
+//! Shared policy for commands that act on many worktrees at once.
+//!
+//! `wt step prune`, `wt step relocate`, and `wt step for-each` all walk the
+//! worktree list. Worktrees locked with `git worktree lock` get the same
+//! treatment in each: skipped by default and reported with their lock reason.
+//! `--include-locked` lets commands that only run inside a worktree act on
+//! them; nothing removes or moves a locked worktree until it's unlocked.
+
+use color_print::cformat;
+use worktrunk::git::WorktreeInfo;
+use worktrunk::styling::{FormattedMessage, info_message};
+
+/// What a batch command does to each worktree it selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchAction {
+    /// Runs commands inside the worktree and leaves it in place
+    Run,
+    /// Removes or moves the worktree
+    Modify,
+}
+
+/// Decides which worktrees a batch command may act on.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchPolicy {
+    action: BatchAction,
+    include_locked: bool,
+}
+
+impl BatchPolicy {
+    pub fn new(action: BatchAction, include_locked: bool) -> Self {
+        Self {
+            action,
+            include_locked,
+        }
+    }
+
+    /// The lock reason keeping this command off `wt`, or `None` if it may act.
+    ///
+    /// The reason is empty when the worktree was locked without one.
+    /// `--include-locked` only admits locked worktrees for [`BatchAction::Run`];
+    /// git refuses to remove or move them without an explicit unlock, and so
+    /// do we.
+    pub fn locked_skip<'a>(&self, wt: &'a WorktreeInfo) -> Option<&'a str> {
+        let reason = wt.locked.as_deref()?;
+        match self.action {
+            BatchAction::Run if self.include_locked => None,
+            _ => Some(reason),
+        }
+    }
+}
+
+/// Worktrees a batch command skipped because they're locked.
+#[derive(Debug, Default)]
+pub struct LockedSkips(Vec<(String, String)>);
+
+impl LockedSkips {
+    pub fn push(&mut self, label: impl Into<String>, reason: &str) {
+        self.0.push((label.into(), reason.to_string()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Summary line naming each skipped worktree with its lock reason.
+    pub fn message(&self, policy: &BatchPolicy) -> Option<FormattedMessage> {
+        if self.0.is_empty() {
+            return None;
+        }
+        let names = self
+            .0
+            .iter()
+            .map(|(label, reason)| match reason.as_str() {
+                "" => label.clone(),
+                reason => format!("{label} ({reason})"),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(info_message(match policy.action {
+            BatchAction::Run => cformat!(
+                "Skipped locked worktrees: {names}; to include, run with <bold>--include-locked</>"
+            ),
+            BatchAction::Modify => cformat!(
+                "Skipped locked worktrees: {names}; to include, run <bold>git worktree unlock</> first"
+            ),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn worktree(locked: Option<&str>) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from("/repo.feature"),
+            head: "abc123".to_string(),
+            branch: Some("feature".to_string()),
+            branch_raw: None,
+            bare: false,
+            detached: false,
+            locked: locked.map(str::to_string),
+            prunable: None,
+        }
+    }
+
+    #[test]
+    fn test_unlocked_worktrees_always_admitted() {
+        for action in [BatchAction::Run, BatchAction::Modify] {
+            for include_locked in [false, true] {
+                let policy = BatchPolicy::new(action, include_locked);
+                assert_eq!(policy.locked_skip(&worktree(None)), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_locked_worktrees_skipped_by_default() {
+        let wt = worktree(Some("on a USB drive"));
+        for action in [BatchAction::Run, BatchAction::Modify] {
+            let policy = BatchPolicy::new(action, false);
+            assert_eq!(policy.locked_skip(&wt), Some("on a USB drive"));
+        }
+        // Locked without a reason still counts
+        let policy = BatchPolicy::new(BatchAction::Run, false);
+        assert_eq!(policy.locked_skip(&worktree(Some(""))), Some(""));
+    }
+
+    #[test]
+    fn test_include_locked_never_admits_modification() {
+        let wt = worktree(Some("wip"));
+        assert_eq!(
+            BatchPolicy::new(BatchAction::Run, true).locked_skip(&wt),
+            None
+        );
+        assert_eq!(
+            BatchPolicy::new(BatchAction::Modify, true).locked_skip(&wt),
+            Some("wip")
+        );
+    }
+
+    #[test]
+    fn test_locked_skips_message() {
+        let policy = BatchPolicy::new(BatchAction::Run, false);
+        let mut skips = LockedSkips::default();
+        assert!(skips.message(&policy).is_none());
+
+        skips.push("feature", "wip");
+        skips.push("bugfix", "");
+        let message = skips.message(&policy).unwrap().to_string();
+        assert!(message.contains("Skipped locked worktrees: feature (wip), bugfix;"));
+        assert!(message.contains("--include-locked"));
+
+        let policy = BatchPolicy::new(BatchAction::Modify, true);
+        let message = skips.message(&policy).unwrap().to_string();
+        assert!(message.contains("git worktree unlock"));
+    }
+}
@@ -4,7 +4,14 @@
 //! deleted after its worktree is removed. It checks if the branch's content has
 //! been integrated into the target branch.
 
+use std::io::{self, IsTerminal};
+
+use anyhow::Context;
+use color_print::cformat;
 use worktrunk::git::{IntegrationReason, Repository};
+use worktrunk::styling::{eprintln, format_with_gutter, hint_message};
+
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
 
 /// Outcome of a branch deletion attempt.
 pub enum BranchDeletionOutcome {
@@ -55,6 +62,117 @@ pub fn delete_branch_if_safe(
     })
 }
 
+/// A branch's upstream on its remote, deleted along with the branch by
+/// `wt remove --delete-remote`.
+///
+/// Captured before removal: deleting the local branch also drops its
+/// `branch.<name>.remote` config.
+#[derive(Debug, Clone)]
+pub struct RemoteBranch {
+    /// Remote name, e.g. `origin`
+    pub remote: String,
+    /// Branch name on the remote
+    pub name: String,
+    /// Remote-tracking branch, e.g. `origin/feature`
+    pub short: String,
+    /// Commits on the remote branch that the local branch doesn't have
+    pub unique_commits: usize,
+}
+
+impl RemoteBranch {
+    /// The upstream of `branch`, if it has one that still exists.
+    pub fn of(repo: &Repository, branch: &str) -> anyhow::Result<Option<Self>> {
+        let refs = repo.run_command(&[
+            "for-each-ref",
+            "--format=%(upstream:short)%00%(upstream:remotename)%00%(upstream:remoteref)",
+            &format!("refs/heads/{branch}"),
+        ])?;
+        let mut fields = refs.trim_end_matches('\n').split('\0');
+        let (Some(short), Some(remote), Some(remote_ref)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            return Ok(None);
+        };
+        // Upstreams on the local repository (`.`) aren't remote branches
+        if short.is_empty() || remote.is_empty() || remote == "." {
+            return Ok(None);
+        }
+        // Never offer to delete the remote's default branch
+        if repo
+            .default_branch()
+            .is_some_and(|default| remote_ref == format!("refs/heads/{default}"))
+        {
+            return Ok(None);
+        }
+        // A gone upstream has nothing left to delete. Exit code 1 means the
+        // ref doesn't exist.
+        let upstream = format!("refs/remotes/{short}");
+        let output = repo
+            .current_worktree()
+            .run_command_output(&["show-ref", "--verify", "--quiet", &upstream])?;
+        if output.status.code() == Some(1) {
+            return Ok(None);
+        }
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to look up {upstream}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        let count = repo.run_command(&[
+            "rev-list",
+            "--count",
+            &format!("refs/heads/{branch}..{upstream}"),
+        ])?;
+        let count = count.trim();
+        let unique_commits = count
+            .parse()
+            .with_context(|| format!("Failed to parse commit count for {short}: {count}"))?;
+        Ok(Some(Self {
+            remote: remote.to_string(),
+            name: remote_ref
+                .strip_prefix("refs/heads/")
+                .unwrap_or(remote_ref)
+                .to_string(),
+            short: short.to_string(),
+            unique_commits,
+        }))
+    }
+}
+
+/// Ask whether to delete `remote_branches` on their remotes. Non-interactive
+/// sessions need `--yes`.
+pub fn confirm_remote_deletion(remote_branches: &[&RemoteBranch]) -> anyhow::Result<bool> {
+    let list = remote_branches
+        .iter()
+        .map(|remote_branch| remote_branch.short.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if !io::stdin().is_terminal() {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Not deleting {list} (not interactive). To delete, use <underline>--yes</>"
+            ))
+        );
+        return Ok(false);
+    }
+    let commands = remote_branches
+        .iter()
+        .map(|remote_branch| {
+            format!(
+                "git push {} --delete {}",
+                remote_branch.remote, remote_branch.name
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let response = prompt_yes_no_preview(&cformat!("Also delete {list} on the remote?"), || {
+        eprintln!("{}", format_with_gutter(&commands, None));
+    })?;
+    Ok(response == PromptResponse::Accepted)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,349 @@
+//! Local branch cleanup (`wt branches clean`).
+//!
+//! Deletes local branches that no worktree uses, whose changes are already in
+//! the default branch, and that have nothing unpushed. Integration uses the
+//! same checks as `wt step prune`.
+
+use std::collections::HashSet;
+use std::io::{self, IsTerminal};
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, println, success_message,
+    warning_message,
+};
+
+use super::step_commands::squash_merge_description;
+use super::table::{self, CellStyle};
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
+
+/// A branch that qualifies for deletion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CleanCandidate {
+    branch: String,
+    /// Why it counts as merged, e.g. "ancestor of main"
+    reason: String,
+    upstream: Option<Upstream>,
+    /// Whether `--delete-remote` deletes the upstream too
+    delete_upstream: bool,
+}
+
+/// A branch's upstream, from `git for-each-ref`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Upstream {
+    /// Short name, e.g. `origin/feature`
+    short: String,
+    remote: String,
+    /// Ref on the remote, e.g. `refs/heads/feature`
+    remote_ref: String,
+    /// Local commits the upstream doesn't have
+    ahead: bool,
+}
+
+/// Local branch and its upstream (if any), one per `for-each-ref` line.
+fn parse_branch_upstreams(output: &str) -> Vec<(String, Option<Upstream>)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\0');
+            let branch = fields.next().filter(|b| !b.is_empty())?.to_string();
+            let short = fields.next().unwrap_or_default();
+            let remote = fields.next().unwrap_or_default();
+            let remote_ref = fields.next().unwrap_or_default();
+            let track = fields.next().unwrap_or_default();
+            // "[gone]" means the remote branch was deleted: nothing to push to or
+            // delete. Upstreams on the local repository (`.`) aren't remote branches.
+            let upstream =
+                (!short.is_empty() && !remote.is_empty() && remote != "." && track != "[gone]")
+                    .then(|| Upstream {
+                        short: short.to_string(),
+                        remote: remote.to_string(),
+                        remote_ref: remote_ref.to_string(),
+                        ahead: track.contains("ahead "),
+                    });
+            Some((branch, upstream))
+        })
+        .collect()
+}
+
+/// Render candidates as an aligned `Branch  Reason  Upstream` table.
+fn render_table(candidates: &[CleanCandidate]) -> Vec<String> {
+    let rows: Vec<[String; 3]> = candidates
+        .iter()
+        .map(|c| {
+            [
+                c.branch.clone(),
+                c.reason.clone(),
+                c.upstream
+                    .as_ref()
+                    .map_or(String::new(), |u| u.short.clone()),
+            ]
+        })
+        .collect();
+    table::render_table(
+        ["Branch", "Reason", "Upstream"],
+        [CellStyle::Bold, CellStyle::Plain, CellStyle::Dim],
+        &rows,
+    )
+}
+
+/// Why `--delete-remote` keeps `upstream`, if it does.
+///
+/// The default branch's remote counterpart is never deleted, and neither is a
+/// remote branch whose own commits aren't integrated into the target.
+fn upstream_kept_reason(
+    repo: &Repository,
+    upstream: &Upstream,
+    default_branch: Option<&str>,
+    integration_target: &str,
+) -> anyhow::Result<Option<&'static str>> {
+    if default_branch.is_some_and(|db| upstream.remote_ref == format!("refs/heads/{db}")) {
+        return Ok(Some("default branch"));
+    }
+    let remote_ref = format!("refs/remotes/{}", upstream.short);
+    let (_, reason) = repo.integration_reason(&remote_ref, integration_target)?;
+    Ok(reason.is_none().then_some("not merged"))
+}
+
+/// Ask whether to delete the candidates. Non-interactive sessions need `--yes`.
+///
+/// `?` lists the refs that would be deleted.
+fn confirm_delete(candidates: &[CleanCandidate]) -> anyhow::Result<bool> {
+    let count = candidates.len();
+    let noun = if count == 1 { "branch" } else { "branches" };
+    if !io::stdin().is_terminal() {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Not deleting {count} {noun} (not interactive). To delete, use <underline>--yes</>"
+            ))
+        );
+        return Ok(false);
+    }
+    let response = prompt_yes_no_preview(&format!("Delete {count} {noun}?"), || {
+        let refs = candidates
+            .iter()
+            .flat_map(|c| {
+                let remote = c
+                    .upstream
+                    .as_ref()
+                    .filter(|_| c.delete_upstream)
+                    .map(|u| format!("refs/remotes/{}", u.short));
+                std::iter::once(format!("refs/heads/{}", c.branch)).chain(remote)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        eprintln!("{}", format_with_gutter(&refs, None));
+    })?;
+    Ok(response == PromptResponse::Accepted)
+}
+
+/// Handle `wt branches clean`.
+pub fn handle_branches_clean(
+    dry_run: bool,
+    yes: bool,
+    squash_aware: bool,
+    delete_remote: bool,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let Some(integration_target) = repo.integration_target() else {
+        anyhow::bail!("cannot determine default branch");
+    };
+
+    // Branches checked out anywhere (stale entries included: git won't delete those)
+    let mut in_use: HashSet<String> = repo
+        .list_worktrees()?
+        .into_iter()
+        .filter_map(|wt| wt.branch)
+        .collect();
+    let default_branch = repo.default_branch();
+    in_use.extend(default_branch.clone());
+
+    let refs = repo.run_command(&[
+        "for-each-ref",
+        "--format=%(refname:short)%00%(upstream:short)%00%(upstream:remotename)%00%(upstream:remoteref)%00%(upstream:track)",
+        "refs/heads/",
+    ])?;
+
+    let mut candidates = Vec::new();
+    let mut unpushed = Vec::new();
+    let mut kept_upstreams = Vec::new();
+    for (branch, upstream) in parse_branch_upstreams(&refs) {
+        if in_use.contains(&branch) {
+            continue;
+        }
+        let (effective_target, reason) = repo.integration_reason(&branch, &integration_target)?;
+        let squash_description = if reason.is_none() && squash_aware {
+            repo.run_command(&["rev-parse", &format!("refs/heads/{branch}")])
+                .ok()
+                .and_then(|head| {
+                    squash_merge_description(&repo, &branch, head.trim(), &effective_target)
+                })
+        } else {
+            None
+        };
+        let reason = match reason {
+            Some(reason) => format!("{} {effective_target}", reason.description()),
+            None => match squash_description {
+                Some(description) => description,
+                None => continue,
+            },
+        };
+        if upstream.as_ref().is_some_and(|u| u.ahead) {
+            unpushed.push(branch);
+            continue;
+        }
+        let mut delete_upstream = false;
+        if delete_remote && let Some(upstream) = &upstream {
+            match upstream_kept_reason(
+                &repo,
+                upstream,
+                default_branch.as_deref(),
+                &integration_target,
+            )? {
+                Some(why) => kept_upstreams.push(format!("{} ({why})", upstream.short)),
+                None => delete_upstream = true,
+            }
+        }
+        candidates.push(CleanCandidate {
+            branch,
+            reason,
+            upstream,
+            delete_upstream,
+        });
+    }
+
+    if !unpushed.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(format!(
+                "Skipped {} (unpushed commits)",
+                unpushed.join(", ")
+            ))
+        );
+    }
+    if !kept_upstreams.is_empty() && !candidates.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(format!("Keeping remote {}", kept_upstreams.join(", ")))
+        );
+    }
+    if candidates.is_empty() {
+        eprintln!("{}", info_message("No merged branches to delete"));
+        return Ok(());
+    }
+
+    for line in render_table(&candidates) {
+        println!("{line}");
+    }
+
+    let count = candidates.len();
+    let noun = if count == 1 { "branch" } else { "branches" };
+    if dry_run {
+        eprintln!(
+            "{}",
+            hint_message(format!("{count} {noun} would be deleted (dry run)"))
+        );
+        return Ok(());
+    }
+    if !yes && !confirm_delete(&candidates)? {
+        return Ok(());
+    }
+
+    let mut deleted = 0;
+    for candidate in &candidates {
+        // Integration was checked above against the default branch, which
+        // `git branch -d` doesn't know about, so force the deletion
+        if let Err(e) = repo.run_command(&["branch", "-D", "--", &candidate.branch]) {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Failed to delete <bold>{}</>: {e}",
+                    candidate.branch
+                ))
+            );
+            continue;
+        }
+        deleted += 1;
+
+        if candidate.delete_upstream
+            && let Some(upstream) = &candidate.upstream
+        {
+            let remote_branch = upstream
+                .remote_ref
+                .strip_prefix("refs/heads/")
+                .unwrap_or(&upstream.remote_ref);
+            if let Err(e) =
+                repo.run_network_command(&["push", &upstream.remote, "--delete", remote_branch])
+            {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "Failed to delete <bold>{}</>: {e}",
+                        upstream.short
+                    ))
+                );
+            }
+        }
+    }
+
+    let noun = if deleted == 1 { "branch" } else { "branches" };
+    eprintln!("{}", success_message(format!("Deleted {deleted} {noun}")));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_branch_upstreams() {
+        let output = "feature\0origin/feature\0origin\0refs/heads/feature\0[ahead 2, behind 1]\n\
+                      local\0\0\0\0\n\
+                      gone\0origin/gone\0origin\0refs/heads/gone\0[gone]\n\
+                      synced\0upstream/x\0upstream\0refs/heads/x\0\n\
+                      stacked\0main\0.\0refs/heads/main\0\n";
+        let parsed = parse_branch_upstreams(output);
+        assert_eq!(parsed.len(), 5);
+        assert_eq!(parsed[4], ("stacked".to_string(), None));
+        assert!(parsed[0].1.as_ref().unwrap().ahead);
+        assert_eq!(parsed[1], ("local".to_string(), None));
+        assert_eq!(parsed[2], ("gone".to_string(), None));
+        let synced = parsed[3].1.as_ref().unwrap();
+        assert!(!synced.ahead);
+        assert_eq!(synced.remote, "upstream");
+        assert_eq!(synced.remote_ref, "refs/heads/x");
+    }
+
+    #[test]
+    fn test_render_table_aligns_columns() {
+        let candidate = |branch: &str, reason: &str, upstream: Option<&str>| CleanCandidate {
+            branch: branch.into(),
+            reason: reason.into(),
+            upstream: upstream.map(|short| Upstream {
+                short: short.into(),
+                remote: "origin".into(),
+                remote_ref: String::new(),
+                ahead: false,
+            }),
+            delete_upstream: false,
+        };
+        let lines = render_table(&[
+            candidate("a", "ancestor of main", Some("origin/a")),
+            candidate("longer-name", "PR merged", None),
+        ]);
+        let lines: Vec<String> = lines
+            .iter()
+            .map(|l| ansi_str::AnsiStr::ansi_strip(l.as_str()).into_owned())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "Branch       Reason            Upstream",
+                "a            ancestor of main  origin/a",
+                "longer-name  PR merged",
+            ]
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Cloning a repository into a worktree-first layout (`wt clone`).
+//!
+//! Without `--bare`, this is `git clone`: the primary checkout holds the
+//! default branch and linked worktrees go wherever `worktree-path` puts them.
+//! With `--bare`, the repository lives in `<dir>/.git` and every branch,
+//! including the default one, gets a worktree beside it.
+
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::Context;
+use color_print::cformat;
+use normalize_path::NormalizePath;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{AddWorktreeOpts, Repository, base_path};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+};
+
+use super::config::project_config_example;
+use super::worktree::{check_worktree_root, compute_worktree_path};
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
+
+/// Worktree path template for the bare layout, relative to `<dir>/.git`.
+const BARE_WORKTREE_PATH: &str = "../{{ branch | sanitize }}";
+
+/// Handle `wt clone`.
+pub fn handle_clone(url: &str, dir: Option<PathBuf>, bare: bool, init: bool) -> anyhow::Result<()> {
+    let dir = match dir {
+        Some(dir) => dir,
+        None => PathBuf::from(directory_for(url).with_context(|| {
+            cformat!("Can't derive a directory name from <bold>{url}</>; pass one")
+        })?),
+    };
+    let base = base_path();
+    let dir = base.join(dir);
+    if dir
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some())
+    {
+        anyhow::bail!(cformat!(
+            "Directory <bold>{}</> already exists and is not empty",
+            format_path_for_display(&dir)
+        ));
+    }
+
+    eprintln!(
+        "{}",
+        progress_message(cformat!(
+            "Cloning <bold>{url}</> into <bold>{}</>...",
+            format_path_for_display(&dir)
+        ))
+    );
+    let target = if bare { dir.join(".git") } else { dir.clone() };
+    let mut args = vec!["clone"];
+    if bare {
+        args.push("--bare");
+    }
+    let target_str = target.to_string_lossy();
+    args.extend(["--", url, target_str.as_ref()]);
+    Cmd::new("git")
+        .args(args)
+        .network()
+        .current_dir(&base)
+        .stdout(Stdio::from(std::io::stderr()))
+        .stream()
+        .with_context(|| cformat!("Failed to clone <bold>{url}</>"))?;
+
+    let repo = Repository::at(&target)?;
+    let (default_branch, worktree) = if bare {
+        setup_bare(&repo)?
+    } else {
+        let default_branch = repo
+            .default_branch()
+            .context("Cloned repository has no default branch")?;
+        (default_branch, repo.repo_path()?.to_path_buf())
+    };
+
+    if init {
+        write_project_config(&worktree)?;
+    }
+
+    let layout = if bare {
+        cformat!(
+            "bare repository in <bold>{}</>, <bold>{default_branch}</> at <bold>{}</>",
+            format_path_for_display(repo.git_common_dir()),
+            format_path_for_display(&worktree)
+        )
+    } else {
+        cformat!(
+            "<bold>{default_branch}</> at <bold>{}</>",
+            format_path_for_display(&worktree)
+        )
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!("Cloned <bold>{url}</>: {layout}"))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "To start a feature branch, run <underline>cd {} && wt switch --create <<name>></>",
+            format_path_for_display(&worktree)
+        ))
+    );
+    Ok(())
+}
+
+/// Fetch remote branches into `refs/remotes/origin` and add the default
+/// branch's worktree.
+///
+/// `git clone --bare` maps remote branches straight onto local ones and
+/// records no `origin/HEAD`, which leaves nothing for upstreams, ahead/behind
+/// counts or default-branch detection to work from. Once the remote branches
+/// are fetched, the local copies of every branch but the default are deleted.
+fn setup_bare(repo: &Repository) -> anyhow::Result<(String, PathBuf)> {
+    repo.run_command(&[
+        "config",
+        "remote.origin.fetch",
+        "+refs/heads/*:refs/remotes/origin/*",
+    ])?;
+    repo.run_network_command(&["fetch", "origin"])
+        .context("Failed to fetch origin")?;
+    repo.run_command(&["remote", "set-head", "origin", "--auto"])?;
+
+    let head = repo.run_command(&["symbolic-ref", "--short", "HEAD"])?;
+    let default_branch = head.trim().to_string();
+    repo.set_default_branch(&default_branch)?;
+    repo.run_command(&[
+        "branch",
+        "--set-upstream-to",
+        &format!("origin/{default_branch}"),
+        &default_branch,
+    ])?;
+    prune_cloned_branches(repo, &default_branch)?;
+
+    // The default template would put worktrees beside `.git`'s parent, so the
+    // default branch goes beside `.git` itself. Later worktrees follow only if
+    // the user saves the template for this project.
+    let config = UserConfig::load().context("Failed to load user config")?;
+    let project = repo.project_identifier()?;
+    let default_template =
+        config.worktree_path_for_project(&project) == UserConfig::default().worktree_path();
+    let path = if default_template {
+        let relative = config.format_path_template(
+            BARE_WORKTREE_PATH,
+            ".git",
+            &default_branch,
+            repo,
+            Some(&project),
+        )?;
+        repo.repo_path()?.join(relative).normalize()
+    } else {
+        compute_worktree_path(repo, &default_branch, &config)?
+    };
+    check_worktree_root(&path, &config)?;
+    if default_template {
+        offer_bare_worktree_path(config, &project)?;
+    }
+
+    let _ = repo
+        .add_worktree(AddWorktreeOpts {
+            branch: Some(&default_branch),
+            ..AddWorktreeOpts::new(&path)
+        })
+        .with_context(|| cformat!("Failed to create worktree for <bold>{default_branch}</>"))?;
+    Ok((default_branch, path))
+}
+
+/// Delete the local branches `git clone --bare` created, except the default.
+///
+/// They duplicate `refs/remotes/origin/*`, and would otherwise show up as
+/// local branches nobody made.
+fn prune_cloned_branches(repo: &Repository, default_branch: &str) -> anyhow::Result<()> {
+    let branches =
+        repo.run_command(&["for-each-ref", "--format=%(refname:short)", "refs/heads/"])?;
+    let extra: Vec<&str> = branches
+        .lines()
+        .filter(|branch| !branch.is_empty() && *branch != default_branch)
+        .collect();
+    if extra.is_empty() {
+        return Ok(());
+    }
+    let mut args = vec!["branch", "-D", "--"];
+    args.extend(&extra);
+    repo.run_command(&args)?;
+    Ok(())
+}
+
+/// Offer to save the bare layout's `worktree-path` for `project` in user
+/// config, so later worktrees land beside `.git` too.
+///
+/// Without a terminal, shows the setting to add instead.
+fn offer_bare_worktree_path(mut config: UserConfig, project: &str) -> anyhow::Result<()> {
+    let setting = format!("[projects.\"{project}\"]\nworktree-path = \"{BARE_WORKTREE_PATH}\"");
+    if !std::io::stdin().is_terminal() {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To put new worktrees beside <bold>.git</>, add to user config:"
+            ))
+        );
+        eprintln!("{}", format_with_gutter(&setting, None));
+        return Ok(());
+    }
+
+    let response = prompt_yes_no_preview(
+        &cformat!("Put new worktrees beside <bold>.git</> for this project?"),
+        || {
+            eprintln!("{}", format_with_gutter(&setting, None));
+        },
+    )?;
+    if response == PromptResponse::Accepted {
+        config.set_project_worktree_path(project, BARE_WORKTREE_PATH.to_string(), None)?;
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Set <bold>worktree-path = \"{BARE_WORKTREE_PATH}\"</> for this project in user config"
+            ))
+        );
+    }
+    Ok(())
+}
+
+/// Write the starter `.config/wt.toml`, unless the repository already has one.
+fn write_project_config(worktree: &Path) -> anyhow::Result<()> {
+    let path = worktree.join(".config/wt.toml");
+    if path.exists() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Project config already exists: <bold>{}</>",
+                format_path_for_display(&path)
+            ))
+        );
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create config directory")?;
+    }
+    std::fs::write(&path, project_config_example()).context("Failed to write project config")?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Created project config: <bold>{}</>",
+            format_path_for_display(&path)
+        ))
+    );
+    Ok(())
+}
+
+/// The directory `git clone` would pick for `url`: its last path component
+/// without a `.git` suffix.
+fn directory_for(url: &str) -> Option<&str> {
+    let trimmed = url.trim_end_matches('/');
+    let trimmed = trimmed.strip_suffix("/.git").unwrap_or(trimmed);
+    let name = trimmed.rsplit(['/', ':', '\\']).next()?;
+    let name = name.strip_suffix(".git").unwrap_or(name);
+    (!name.is_empty() && name != "." && name != "..").then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directory_for() {
+        assert_eq!(directory_for("https://github.com/org/app.git"), Some("app"));
+        assert_eq!(directory_for("https://github.com/org/app/"), Some("app"));
+        assert_eq!(directory_for("git@github.com:org/app.git"), Some("app"));
+        assert_eq!(directory_for("git@host:app"), Some("app"));
+        assert_eq!(directory_for("/srv/git/app/.git"), Some("app"));
+        assert_eq!(directory_for("../app.git"), Some("app"));
+        assert_eq!(directory_for("/"), None);
+        assert_eq!(directory_for(".git"), None);
+    }
+}
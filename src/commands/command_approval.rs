@@ -219,7 +219,7 @@ pub fn approve_hooks_filtered(
         return Ok(true);
     }
 
-    let project_config = match ctx.repo.load_project_config()? {
+    let project_config = match ctx.project_config()? {
         Some(cfg) => cfg,
         None => return Ok(true), // No project config = no commands to approve
     };
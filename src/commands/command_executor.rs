@@ -2,7 +2,7 @@ use anyhow::Result;
 use std::collections::HashMap;
 use std::path::Path;
 use worktrunk::HookType;
-use worktrunk::config::{Command, CommandConfig, UserConfig, expand_template};
+use worktrunk::config::{Command, CommandConfig, ProjectConfig, UserConfig, expand_template};
 use worktrunk::git::Repository;
 use worktrunk::path::to_posix_path;
 
@@ -23,6 +23,8 @@ pub struct CommandContext<'a> {
     pub branch: Option<&'a str>,
     pub worktree_path: &'a Path,
     pub yes: bool,
+    /// Worktree profile forced by `wt switch --profile`, over pattern matching.
+    pub profile: Option<&'a str>,
 }
 
 impl<'a> CommandContext<'a> {
@@ -39,9 +41,15 @@ impl<'a> CommandContext<'a> {
             branch,
             worktree_path,
             yes,
+            profile: None,
         }
     }
 
+    /// This context with `profile` forced over pattern matching.
+    pub fn with_profile(self, profile: Option<&'a str>) -> Self {
+        Self { profile, ..self }
+    }
+
     /// Get branch name, using "HEAD" as fallback for detached HEAD state.
     pub fn branch_or_head(&self) -> &str {
         self.branch.unwrap_or("HEAD")
@@ -55,6 +63,14 @@ impl<'a> CommandContext<'a> {
         self.repo.project_identifier().ok()
     }
 
+    /// Project config with the worktree profile for this context's branch applied.
+    pub fn project_config(&self) -> Result<Option<ProjectConfig>> {
+        Ok(self
+            .repo
+            .load_project_config()?
+            .map(|config| config.for_branch(self.branch, self.profile)))
+    }
+
     /// Get the commit generation config, merging project-specific settings.
     pub fn commit_generation(&self) -> worktrunk::config::CommitGenerationConfig {
         self.config.commit_generation(self.project_id().as_deref())
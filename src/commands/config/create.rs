@@ -40,6 +40,11 @@ pub(super) fn comment_out_config(content: &str) -> String {
     }
 }
 
+/// The example project config with every value commented out.
+pub(crate) fn project_config_example() -> String {
+    comment_out_config(PROJECT_CONFIG_EXAMPLE)
+}
+
 /// Handle the config create command
 pub fn handle_config_create(project: bool) -> anyhow::Result<()> {
     if project {
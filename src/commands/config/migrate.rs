@@ -0,0 +1,72 @@
+//! Config migrate command.
+//!
+//! Rewrites user and project config files at the current config version.
+
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{CONFIG_VERSION, format_migration_diff, migrate_file};
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, hint_message, info_message, success_message};
+
+use super::state::require_user_config_path;
+
+/// Handle the `wt config migrate` command.
+pub fn handle_config_migrate() -> anyhow::Result<()> {
+    migrate_config_file(&require_user_config_path()?, "User config")?;
+
+    if let Ok(repo) = Repository::current()
+        && let Ok(root) = repo.current_worktree().root()
+    {
+        migrate_config_file(&root.join(".config").join("wt.toml"), "Project config")?;
+    }
+
+    Ok(())
+}
+
+fn migrate_config_file(path: &Path, label: &str) -> anyhow::Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    let path_display = format_path_for_display(path);
+
+    let Some(result) =
+        migrate_file(path, label).with_context(|| format!("Failed to migrate {path_display}"))?
+    else {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "{label} is up to date (version {CONFIG_VERSION}) @ <bold>{path_display}</>"
+            ))
+        );
+        return Ok(());
+    };
+
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "{label} migrated from version {} to {CONFIG_VERSION} @ <bold>{path_display}</>",
+            result.from_version
+        ))
+    );
+    if let Some(diff) = format_migration_diff(&result.backup_path, path) {
+        eprintln!("{diff}");
+    }
+    if let Some(approvals_path) = &result.approvals_copied_to {
+        let approvals_display = format_path_for_display(approvals_path);
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Copied approved commands to <underline>{approvals_display}</>"
+            ))
+        );
+    }
+    let backup_display = format_path_for_display(&result.backup_path);
+    eprintln!(
+        "{}",
+        hint_message(cformat!("Original saved to <underline>{backup_display}</>"))
+    );
+    Ok(())
+}
@@ -4,19 +4,26 @@
 
 mod create;
 mod hints;
+mod migrate;
+mod schema;
 mod show;
 mod state;
 mod update;
+mod validate;
 
 // Re-export public functions
 pub use create::handle_config_create;
+pub(crate) use create::project_config_example;
 pub use hints::{handle_hints_clear, handle_hints_get};
+pub use migrate::handle_config_migrate;
+pub use schema::handle_config_schema;
 pub use show::handle_config_show;
 pub use state::{
     handle_logs_get, handle_state_clear, handle_state_clear_all, handle_state_get,
     handle_state_set, handle_state_show,
 };
 pub use update::handle_config_update;
+pub use validate::handle_config_validate;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,19 @@
+//! Config schema command.
+//!
+//! Prints the JSON Schema for user or project config.
+
+use anyhow::Context;
+use worktrunk::config::{ProjectConfig, UserConfig};
+use worktrunk::styling::println;
+
+/// Handle the `wt config schema` command.
+pub fn handle_config_schema(project: bool) -> anyhow::Result<()> {
+    let schema = if project {
+        schemars::schema_for!(ProjectConfig)
+    } else {
+        schemars::schema_for!(UserConfig)
+    };
+    let json = serde_json::to_string_pretty(&schema).context("Failed to serialize schema")?;
+    println!("{json}");
+    Ok(())
+}
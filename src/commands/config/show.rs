@@ -26,12 +26,17 @@ use super::state::require_user_config_path;
 use crate::cli::version_str;
 use crate::commands::configure_shell::{ConfigAction, scan_shell_configs};
 use crate::commands::list::ci_status::{CiPlatform, CiToolsStatus, get_platform_for_repo};
+use crate::commands::worktree::compute_worktree_path;
+use crate::display::format_relative_time_short;
 use crate::help_pager::show_help_in_pager;
 use crate::llm::test_commit_generation;
 use crate::output;
+use crate::update_check::{
+    fetch_latest_version, is_newer_version, read_state as read_update_check_state,
+};
 
 /// Handle the config show command
-pub fn handle_config_show(full: bool) -> anyhow::Result<()> {
+pub fn handle_config_show(full: bool, for_branch: Option<&str>) -> anyhow::Result<()> {
     // Build the complete output as a string
     let mut show_output = String::new();
 
@@ -49,6 +54,11 @@ pub fn handle_config_show(full: bool) -> anyhow::Result<()> {
     render_project_config(&mut show_output)?;
     show_output.push('\n');
 
+    if let Some(branch) = for_branch {
+        render_worktree_profile(&mut show_output, branch)?;
+        show_output.push('\n');
+    }
+
     // Render shell integration status
     render_shell_status(&mut show_output)?;
 
@@ -248,6 +258,16 @@ fn render_runtime_info(out: &mut String) -> anyhow::Result<()> {
         )?;
     }
 
+    // Version of the JSON output shapes, for scripts checking what they'll read
+    writeln!(
+        out,
+        "{}",
+        info_message(cformat!(
+            "JSON schema: <bold>{}</>",
+            crate::commands::json_schema::SCHEMA_VERSION
+        ))
+    )?;
+
     // Show hyperlink support status
     let hyperlinks_supported =
         worktrunk::styling::supports_hyperlinks(worktrunk::styling::Stream::Stderr);
@@ -262,6 +282,22 @@ fn render_runtime_info(out: &mut String) -> anyhow::Result<()> {
         info_message(cformat!("Hyperlinks: <bold>{status}</>"))
     )?;
 
+    // Built-in theme for `wt list` colors: configured, detected, or the dark default
+    let configured = UserConfig::load_quiet()
+        .ok()
+        .and_then(|config| config.theme)
+        .and_then(|theme| theme.background());
+    let (background, source) = worktrunk::styling::resolve_background(configured);
+    writeln!(
+        out,
+        "{}",
+        info_message(cformat!(
+            "Theme: <bold>{}</> ({})",
+            background.as_str(),
+            source.describe()
+        ))
+    )?;
+
     Ok(())
 }
 
@@ -305,11 +341,30 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
         }
     }
 
+    // Large repos without a commit-graph or maintenance make `wt list` slow
+    if let Ok(check) = crate::commands::PerfCheck::detect(&repo)
+        && check.needs_attention()
+    {
+        writeln!(
+            out,
+            "{}",
+            warning_message(cformat!(
+                "Large repo ({} objects; {}). To fix, run <underline>wt step optimize</>",
+                check.objects,
+                check.describe_missing()
+            ))
+        )?;
+    }
+
     // Check for newer version on GitHub
     render_version_check(out)?;
 
-    // Test commit generation - use effective config for current project
     let config = UserConfig::load()?;
+    if config.update_check {
+        render_last_update_check(out)?;
+    }
+
+    // Test commit generation - use effective config for current project
     let project_id = Repository::current()
         .ok()
         .and_then(|r| r.project_identifier().ok());
@@ -457,6 +512,8 @@ fn render_user_config(out: &mut String, has_system_config: bool) -> anyhow::Resu
     // Display TOML with syntax highlighting (gutter at column 0)
     writeln!(out, "{}", format_toml(&contents))?;
 
+    render_default_flags(out)?;
+
     if !has_system_config {
         render_system_config_hint(out)?;
     }
@@ -464,6 +521,27 @@ fn render_user_config(out: &mut String, has_system_config: bool) -> anyhow::Resu
     Ok(())
 }
 
+/// Show how `[defaults]` expands each subcommand (system and user config merged).
+fn render_default_flags(out: &mut String) -> anyhow::Result<()> {
+    // Quiet: the config sections above already report warnings inline
+    let Ok(config) = UserConfig::load_quiet() else {
+        return Ok(());
+    };
+    if config.defaults.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "{}", info_message("Default flags:"))?;
+    let commands = config
+        .defaults
+        .iter()
+        .map(|(subcommand, flags)| format!("wt {subcommand} {flags}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    writeln!(out, "{}", format_bash_with_gutter(&commands))?;
+    Ok(())
+}
+
 fn render_system_config_hint(out: &mut String) -> anyhow::Result<()> {
     if let Some(path) = default_system_config_path() {
         writeln!(
@@ -496,9 +574,16 @@ pub(super) fn warn_unknown_keys<C: worktrunk::config::WorktrunkConfig>(
             Some(location) => {
                 cformat!("Key <bold>{key}</> belongs in {location} (will be ignored)")
             }
+            None if worktrunk::config::is_unknown_hook_name(key) => {
+                cformat!("Unknown hook <bold>{key}</> will be ignored")
+            }
             None => cformat!("Unknown key <bold>{key}</> will be ignored"),
         };
         let _ = writeln!(out, "{}", warning_message(msg));
+        if worktrunk::config::is_unknown_hook_name(key) {
+            let hooks = worktrunk::config::hook_type_names();
+            let _ = writeln!(out, "{}", hint_message(format!("Hooks are {hooks}")));
+        }
     }
     out
 }
@@ -533,7 +618,7 @@ fn render_project_config(out: &mut String) -> anyhow::Result<()> {
             return Ok(());
         }
     };
-    let config_path = repo_root.join(".config").join("wt.toml");
+    let config_path = repo.project_config_path()?;
 
     writeln!(
         out,
@@ -544,6 +629,14 @@ fn render_project_config(out: &mut String) -> anyhow::Result<()> {
         )
     )?;
 
+    if config_path != repo_root.join(".config").join("wt.toml") {
+        writeln!(
+            out,
+            "{}",
+            hint_message("This worktree has no .config/wt.toml; using the primary worktree's")
+        )?;
+    }
+
     // Check if file exists
     if !config_path.exists() {
         writeln!(out, "{}", hint_message("Not found"))?;
@@ -599,6 +692,45 @@ fn render_project_config(out: &mut String) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Render the worktree profile and path that `wt switch --create <branch>` would use.
+fn render_worktree_profile(out: &mut String, branch: &str) -> anyhow::Result<()> {
+    writeln!(
+        out,
+        "{}",
+        format_heading("WORKTREE PROFILE", Some(&format!("for {branch}")))
+    )?;
+    let repo = Repository::current()?;
+    let project_config = repo.load_project_config()?.unwrap_or_default();
+
+    match project_config.profile_for(Some(branch), None) {
+        Some(profile) => {
+            writeln!(
+                out,
+                "{}",
+                info_message(cformat!("Profile <bold>{}</> applies", profile.name))
+            )?;
+            writeln!(out, "{}", format_toml(&toml::to_string(profile)?))?;
+        }
+        None => writeln!(
+            out,
+            "{}",
+            hint_message("No profile matches; using top-level settings")
+        )?,
+    }
+
+    let config = UserConfig::load_quiet().context("Failed to load config")?;
+    let path = compute_worktree_path(&repo, branch, &config)?;
+    writeln!(
+        out,
+        "{}",
+        info_message(cformat!(
+            "Worktree path: <bold>{}</>",
+            format_path_for_display(&path)
+        ))
+    )?;
+    Ok(())
+}
+
 fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
     writeln!(out, "{}", format_heading("SHELL INTEGRATION", None))?;
 
@@ -1039,7 +1171,7 @@ pub(super) fn render_ci_tool_status(
 
 /// Render version update check (fetches from GitHub)
 fn render_version_check(out: &mut String) -> anyhow::Result<()> {
-    match fetch_latest_version() {
+    match fetch_latest_version(5) {
         Ok(latest) => {
             let current = crate::cli::version_str();
             let current_semver = env!("CARGO_PKG_VERSION");
@@ -1067,60 +1199,26 @@ fn render_version_check(out: &mut String) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Fetch the latest release version from GitHub
-fn fetch_latest_version() -> anyhow::Result<String> {
-    // Allow tests to inject a version without network access.
-    // Set to "error" to simulate a fetch failure.
-    if let Ok(version) = std::env::var("WORKTRUNK_TEST_LATEST_VERSION") {
-        if version == "error" {
-            anyhow::bail!("simulated fetch failure");
-        }
-        return Ok(version);
-    }
-
-    let user_agent = format!(
-        "worktrunk/{} (https://worktrunk.dev)",
-        env!("CARGO_PKG_VERSION")
-    );
-    let output = Cmd::new("curl")
-        .args([
-            "--silent",
-            "--fail",
-            "--max-time",
-            "5",
-            "--header",
-            &format!("User-Agent: {user_agent}"),
-            "https://api.github.com/repos/max-sixty/worktrunk/releases/latest",
-        ])
-        .run()?;
-
-    if !output.status.success() {
-        anyhow::bail!("GitHub API request failed");
-    }
-
-    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
-    let tag = json["tag_name"]
-        .as_str()
-        .ok_or_else(|| anyhow::anyhow!("missing tag_name in response"))?;
-
-    // Strip leading 'v' prefix (e.g., "v0.23.2" -> "0.23.2")
-    Ok(tag.strip_prefix('v').unwrap_or(tag).to_string())
-}
-
-/// Compare two semver version strings (e.g., "0.24.0" > "0.23.2")
-fn is_newer_version(latest: &str, current: &str) -> bool {
-    let parse = |s: &str| -> Option<(u32, u32, u32)> {
-        let mut parts = s.splitn(3, '.');
-        Some((
-            parts.next()?.parse().ok()?,
-            parts.next()?.parse().ok()?,
-            parts.next()?.parse().ok()?,
-        ))
+/// Render the time and result of the last background update check (`update-check = true`)
+fn render_last_update_check(out: &mut String) -> anyhow::Result<()> {
+    let Some(state) = read_update_check_state() else {
+        writeln!(out, "{}", hint_message("Update check enabled; not run yet"))?;
+        return Ok(());
     };
-    match (parse(latest), parse(current)) {
-        (Some(l), Some(c)) => l > c,
-        _ => false,
-    }
+    let when = match format_relative_time_short(state.checked_at as i64).as_str() {
+        "now" => "just now".to_string(),
+        ago => format!("{ago} ago"),
+    };
+    let result = match &state.latest {
+        Some(latest) => cformat!("found <bold>{latest}</>"),
+        None => "request failed".to_string(),
+    };
+    writeln!(
+        out,
+        "{}",
+        hint_message(format!("Last update check {when}: {result}"))
+    )?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1137,24 +1235,4 @@ mod tests {
         assert!(version.chars().next().unwrap().is_ascii_digit());
         assert!(version.contains('.'));
     }
-
-    #[test]
-    fn test_is_newer_version() {
-        // Newer versions
-        assert!(is_newer_version("0.24.0", "0.23.2"));
-        assert!(is_newer_version("1.0.0", "0.99.99"));
-        assert!(is_newer_version("0.23.3", "0.23.2"));
-        assert!(is_newer_version("0.23.2", "0.23.1"));
-
-        // Same version
-        assert!(!is_newer_version("0.23.2", "0.23.2"));
-
-        // Older versions
-        assert!(!is_newer_version("0.23.1", "0.23.2"));
-        assert!(!is_newer_version("0.22.0", "0.23.2"));
-
-        // Invalid input
-        assert!(!is_newer_version("invalid", "0.23.2"));
-        assert!(!is_newer_version("0.23.2", "invalid"));
-    }
 }
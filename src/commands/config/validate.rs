@@ -0,0 +1,158 @@
+//! Config validate command.
+//!
+//! Checks user and project config files for syntax errors and unknown keys.
+//! A key that reads like a hook (`pre-…`, `post-…`) but names none fails
+//! validation outright, since its commands would silently never run.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{
+    ProjectConfig, UserConfig, WorktrunkConfig, config_version, find_unknown_project_keys,
+    find_unknown_user_keys, hook_type_names, is_unknown_hook_name,
+};
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprint, eprintln, error_message, format_with_gutter, hint_message, info_message,
+    success_message,
+};
+
+use crate::cli::builtin_subcommands;
+
+use super::show::warn_unknown_keys;
+use super::state::require_user_config_path;
+
+/// Outcome of validating one config file.
+#[derive(Default)]
+struct Validation {
+    invalid: bool,
+    unknown_keys: usize,
+}
+
+/// Handle the `wt config validate` command.
+///
+/// Invalid files always fail; unknown keys fail only with `strict`.
+pub fn handle_config_validate(strict: bool) -> anyhow::Result<()> {
+    let user_path = require_user_config_path()?;
+    let mut user = validate_file::<UserConfig>(&user_path, "User config", find_unknown_user_keys)?;
+    if !user.invalid && user_path.exists() {
+        user.invalid = !validate_aliases(&user_path)?;
+    }
+    let mut results = vec![user];
+
+    if let Ok(repo) = Repository::current()
+        && let Ok(config_path) = repo.project_config_path()
+    {
+        results.push(validate_file::<ProjectConfig>(
+            &config_path,
+            "Project config",
+            find_unknown_project_keys,
+        )?);
+    }
+
+    if results.iter().any(|r| r.invalid) {
+        anyhow::bail!("Config validation failed");
+    }
+    let unknown_keys: usize = results.iter().map(|r| r.unknown_keys).sum();
+    if strict && unknown_keys > 0 {
+        let plural = if unknown_keys == 1 { "" } else { "s" };
+        anyhow::bail!("Config validation failed: {unknown_keys} unknown key{plural} (--strict)");
+    }
+    Ok(())
+}
+
+/// Report `[alias]` entries named after built-in commands, which would never run.
+fn validate_aliases(path: &Path) -> anyhow::Result<bool> {
+    let contents = std::fs::read_to_string(path)?;
+    let config: UserConfig = toml::from_str(&contents)?;
+    let builtins = builtin_subcommands();
+    let shadowed: Vec<&String> = config
+        .alias
+        .keys()
+        .filter(|name| builtins.contains(name))
+        .collect();
+    for name in &shadowed {
+        eprintln!(
+            "{}",
+            error_message(cformat!(
+                "Alias <bold>{name}</> shadows the built-in <bold>wt {name}</> command"
+            ))
+        );
+    }
+    Ok(shadowed.is_empty())
+}
+
+fn validate_file<C: WorktrunkConfig>(
+    path: &Path,
+    label: &str,
+    find_unknown_keys: fn(&str) -> HashMap<String, toml::Value>,
+) -> anyhow::Result<Validation> {
+    let path_display = format_path_for_display(path);
+    if !path.exists() {
+        eprintln!(
+            "{}",
+            info_message(cformat!("{label} not found @ <bold>{path_display}</>"))
+        );
+        return Ok(Validation::default());
+    }
+
+    let contents =
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {path_display}"))?;
+
+    let parsed = config_version(&contents, label)
+        .map_err(|e| e.to_string())
+        .and_then(|_| toml::from_str::<C>(&contents).map_err(|e| e.to_string()));
+    if let Err(e) = parsed {
+        eprintln!(
+            "{}",
+            error_message(cformat!("{label} is invalid @ <bold>{path_display}</>"))
+        );
+        // Gutter keeps user content out of markup interpretation
+        eprintln!("{}", format_with_gutter(&e, None));
+        return Ok(Validation {
+            invalid: true,
+            unknown_keys: 0,
+        });
+    }
+
+    let (unknown_hooks, unknown_keys): (HashMap<_, _>, HashMap<_, _>) =
+        find_unknown_keys(&contents)
+            .into_iter()
+            .partition(|(key, _)| is_unknown_hook_name(key));
+    if !unknown_hooks.is_empty() {
+        let mut hooks: Vec<_> = unknown_hooks.keys().collect();
+        hooks.sort();
+        for hook in hooks {
+            eprintln!(
+                "{}",
+                error_message(cformat!("{label} has unknown hook <bold>{hook}</>"))
+            );
+        }
+        eprintln!(
+            "{}",
+            hint_message(format!("Hooks are {}", hook_type_names()))
+        );
+        if !unknown_keys.is_empty() {
+            eprint!("{}", warn_unknown_keys::<C>(&unknown_keys));
+        }
+        return Ok(Validation {
+            invalid: true,
+            unknown_keys: unknown_keys.len(),
+        });
+    }
+    if unknown_keys.is_empty() {
+        eprintln!(
+            "{}",
+            success_message(cformat!("{label} is valid @ <bold>{path_display}</>"))
+        );
+    } else {
+        eprint!("{}", warn_unknown_keys::<C>(&unknown_keys));
+    }
+    Ok(Validation {
+        invalid: false,
+        unknown_keys: unknown_keys.len(),
+    })
+}
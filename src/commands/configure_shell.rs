@@ -224,6 +224,7 @@ pub fn handle_configure_shell(
     // If nothing needs to be changed, still clean up legacy fish conf.d files
     // A user might have upgraded and have both functions/wt.fish and conf.d/wt.fish
     if !needs_shell_changes && !needs_completion_changes {
+        crate::first_run::silence();
         let legacy_cleanups = cleanup_legacy_fish_conf_d(&preview.configured, &cmd);
         return Ok(ScanResult {
             configured: preview.configured,
@@ -249,6 +250,7 @@ pub fn handle_configure_shell(
     // User confirmed (or --yes flag was used), now actually apply the changes
     let result = scan_shell_configs(shell_filter, false, &cmd)?;
     let completion_results = process_shell_completions(&shells, false, &cmd)?;
+    crate::first_run::silence();
 
     // Zsh completions require compinit to be enabled. Unlike bash/fish, zsh doesn't
     // enable its completion system by default - users must explicitly call compinit.
@@ -30,7 +30,16 @@ impl CommandEnv {
     /// `action` describes what command is running (e.g., "merge", "squash").
     /// Used in error messages when the environment can't be loaded.
     pub fn for_action(action: &str, config: UserConfig) -> anyhow::Result<Self> {
-        let repo = Repository::current()?;
+        Self::for_action_in(Repository::current()?, action, config)
+    }
+
+    /// Load the command environment for `action` in the worktree `repo` was
+    /// discovered from, as for `wt merge --branch`.
+    pub fn for_action_in(
+        repo: Repository,
+        action: &str,
+        config: UserConfig,
+    ) -> anyhow::Result<Self> {
         let worktree_path = repo.current_worktree().root()?;
         let branch = repo.require_current_branch(action)?;
 
@@ -0,0 +1,268 @@
+//! Cross-worktree diff (`wt diff`).
+//!
+//! Compares a branch (or its worktree) against a target branch using the
+//! merge-base, the same basis as the `main…±` column in `wt list`.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository, parse_numstat_line};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprintln, info_message, println};
+
+use crate::cli::OutputFormat;
+use crate::commands::list::columns::ColumnKind;
+use crate::commands::list::layout::DiffColumnConfig;
+
+/// Per-file line counts from `git diff --numstat`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct FileDiff {
+    pub path: String,
+    pub added: usize,
+    pub deleted: usize,
+    /// Binary files have no line counts
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub binary: bool,
+}
+
+/// JSON output for `wt diff --format=json`.
+#[derive(serde::Serialize)]
+pub(super) struct DiffOutput<'a> {
+    pub(super) branch: &'a str,
+    pub(super) target: &'a str,
+    pub(super) merge_base: &'a str,
+    pub(super) working: bool,
+    pub(super) files: &'a [FileDiff],
+}
+
+/// Parse `git diff --numstat` output into per-file counts.
+///
+/// Renames appear with git's `old => new` path notation.
+pub fn parse_numstat_files(output: &str) -> Vec<FileDiff> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let added = parts.next()?;
+            let deleted = parts.next()?;
+            let path = parts.next()?.to_string();
+            Some(match parse_numstat_line(line) {
+                Some((added, deleted)) => FileDiff {
+                    path,
+                    added,
+                    deleted,
+                    binary: false,
+                },
+                None if added == "-" && deleted == "-" => FileDiff {
+                    path,
+                    added: 0,
+                    deleted: 0,
+                    binary: true,
+                },
+                None => return None,
+            })
+        })
+        .collect()
+}
+
+/// Handle `wt diff [branch] [target]`.
+pub fn handle_diff(
+    branch: Option<&str>,
+    target: Option<&str>,
+    working: bool,
+    tool: bool,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+
+    // Resolve the branch being inspected (defaults to the current worktree)
+    let (branch, worktree) = match branch {
+        Some(name) => {
+            let branch = repo.resolve_worktree_name(name)?;
+            if !repo.ref_exists(&branch)? {
+                return Err(GitError::ReferenceNotFound { reference: branch }.into());
+            }
+            let worktree = repo.worktree_for_branch(&branch)?;
+            (branch, worktree)
+        }
+        None => {
+            let wt = repo.current_worktree();
+            let branch = wt.branch()?.unwrap_or_else(|| "HEAD".to_string());
+            (branch, Some(wt.root()?))
+        }
+    };
+    let target = repo.require_target_ref(target)?;
+
+    let merge_base = repo
+        .merge_base(&target, &branch)?
+        .with_context(|| format!("No common ancestor between {branch} and {target}"))?;
+
+    // --working diffs against the worktree directory, so the branch needs one
+    let working_dir: Option<PathBuf> = if working {
+        let Some(path) = worktree else {
+            return Err(GitError::WorktreeNotFound { branch }.into());
+        };
+        Some(path)
+    } else {
+        None
+    };
+
+    // Without --working, compare commits: merge-base..branch
+    let mut diff_args = vec![merge_base.clone()];
+    if working_dir.is_none() {
+        diff_args.push(branch.clone());
+    }
+
+    if tool {
+        let mut args = vec!["difftool".to_string(), "--no-prompt".to_string()];
+        args.extend(diff_args);
+        let dir = match &working_dir {
+            Some(path) => path.clone(),
+            None => repo.current_worktree().root()?,
+        };
+        return Cmd::new("git")
+            .args(&args)
+            .current_dir(&dir)
+            .context(&branch)
+            // Terminal difftools (vimdiff) read from the terminal
+            .stdin(std::process::Stdio::inherit())
+            .stream();
+    }
+
+    let mut args = vec!["diff".to_string(), "--numstat".to_string()];
+    args.extend(diff_args);
+    let numstat = match &working_dir {
+        Some(path) => repo
+            .worktree_at(path)
+            .run_command(&args.iter().map(String::as_str).collect::<Vec<_>>())?,
+        None => repo.run_command(&args.iter().map(String::as_str).collect::<Vec<_>>())?,
+    };
+    let files = parse_numstat_files(&numstat);
+
+    match format {
+        OutputFormat::Json => {
+            let output = DiffOutput {
+                branch: &branch,
+                target: &target,
+                merge_base: &merge_base,
+                working,
+                files: &files,
+            };
+            let json =
+                serde_json::to_string_pretty(&output).context("Failed to serialize to JSON")?;
+            println!("{json}");
+        }
+        OutputFormat::Table | OutputFormat::ClaudeCode => {
+            if files.is_empty() {
+                eprintln!(
+                    "{}",
+                    info_message(cformat!(
+                        "No changes in <bold>{branch}</> since <bold>{target}</>"
+                    ))
+                );
+                return Ok(());
+            }
+            for line in format_file_lines(&files) {
+                println!("{line}");
+            }
+            let (added, deleted) = files
+                .iter()
+                .fold((0, 0), |(a, d), f| (a + f.added, d + f.deleted));
+            let plural = if files.len() == 1 { "" } else { "s" };
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "<bold>{branch}</> vs <bold>{target}</>: {} file{plural}, +{added} -{deleted}",
+                    files.len()
+                ))
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Format one aligned line per file: `+added -deleted  path`.
+///
+/// Uses the list's Branch diff column rendering, sized to the widest counts.
+fn format_file_lines(files: &[FileDiff]) -> Vec<String> {
+    let digits = |n: usize| n.max(1).to_string().len();
+    let positive_digits = files.iter().map(|f| digits(f.added)).max().unwrap_or(1);
+    let negative_digits = files.iter().map(|f| digits(f.deleted)).max().unwrap_or(1);
+    let display = ColumnKind::BranchDiff
+        .diff_display_config()
+        .expect("BranchDiff is a diff column");
+    let config = DiffColumnConfig {
+        positive_digits,
+        negative_digits,
+        total_width: positive_digits + negative_digits + 3,
+        display,
+    };
+
+    files
+        .iter()
+        .map(|file| {
+            let counts = if file.binary {
+                let mut cell = worktrunk::styling::StyledLine::new();
+                cell.push_raw("bin");
+                cell.pad_to(config.total_width);
+                cell
+            } else {
+                config.render_segment(file.added, file.deleted)
+            };
+            format!("{}  {}", counts.render(), file.path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numstat_files() {
+        let output = "12\t3\tsrc/lib.rs\n0\t7\tREADME.md\n-\t-\tassets/logo.png\n";
+        let files = parse_numstat_files(output);
+        assert_eq!(
+            files,
+            vec![
+                FileDiff {
+                    path: "src/lib.rs".into(),
+                    added: 12,
+                    deleted: 3,
+                    binary: false,
+                },
+                FileDiff {
+                    path: "README.md".into(),
+                    added: 0,
+                    deleted: 7,
+                    binary: false,
+                },
+                FileDiff {
+                    path: "assets/logo.png".into(),
+                    added: 0,
+                    deleted: 0,
+                    binary: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_numstat_files_renames_and_invalid() {
+        let files = parse_numstat_files("1\t1\told.rs => new.rs\n");
+        assert_eq!(files[0].path, "old.rs => new.rs");
+        assert!(parse_numstat_files("garbage\n").is_empty());
+    }
+
+    #[test]
+    fn test_format_file_lines_aligns_counts() {
+        let files = parse_numstat_files("120\t3\ta.rs\n4\t56\tb.rs\n");
+        let lines: Vec<String> = format_file_lines(&files)
+            .iter()
+            .map(|l| ansi_str::AnsiStr::ansi_strip(l.as_str()).into_owned())
+            .collect();
+        assert_eq!(lines, vec!["+120 - 3  a.rs", "+  4 -56  b.rs"]);
+    }
+}
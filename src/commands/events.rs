@@ -0,0 +1,218 @@
+//! Worktree change events (`wt events`).
+//!
+//! Prints one line per change so status bars (tmux, prompts) can stay current
+//! without re-running `wt list`. The tree has no file watcher, so the
+//! repository is polled every `--interval`; changes within one interval are
+//! coalesced, which debounces bursts of edits into a single event.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use worktrunk::git::Repository;
+
+/// State of one worktree, as far as events are concerned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WorktreeState {
+    branch: Option<String>,
+    dirty: bool,
+}
+
+/// All worktrees, keyed by path so output order is stable.
+type Snapshot = BTreeMap<PathBuf, WorktreeState>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventKind {
+    WorktreeCreated,
+    WorktreeRemoved,
+    BranchSwitched,
+    BranchDirty,
+    BranchClean,
+}
+
+impl EventKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::WorktreeCreated => "worktree-created",
+            Self::WorktreeRemoved => "worktree-removed",
+            Self::BranchSwitched => "branch-switched",
+            Self::BranchDirty => "branch-dirty",
+            Self::BranchClean => "branch-clean",
+        }
+    }
+}
+
+/// One output line: `<kind> <branch> <path>`.
+///
+/// Detached worktrees print `-` as the branch. Branch names can't contain
+/// spaces, so splitting on the first two spaces recovers a path that does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Event {
+    kind: EventKind,
+    branch: Option<String>,
+    path: PathBuf,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.kind.as_str(),
+            self.branch.as_deref().unwrap_or("-"),
+            self.path.display()
+        )
+    }
+}
+
+/// Read the current state of every worktree.
+///
+/// Prunable worktrees (directory gone) are left out, so deleting one by hand
+/// reports it removed. A worktree whose status can't be read — typically one
+/// being removed mid-poll — is skipped until the next poll.
+fn snapshot(repo: &Repository) -> anyhow::Result<Snapshot> {
+    Ok(repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| wt.prunable.is_none())
+        .filter_map(|wt| {
+            let dirty = repo
+                .worktree_at(&wt.path)
+                .is_dirty()
+                .inspect_err(|e| log::debug!("Skipping {}: {e}", wt.path.display()))
+                .ok()?;
+            Some((
+                wt.path,
+                WorktreeState {
+                    branch: wt.branch,
+                    dirty,
+                },
+            ))
+        })
+        .collect())
+}
+
+/// Events that turn `old` into `new`: removals first, then per-path changes.
+///
+/// A new worktree reports its dirtiness right away, so diffing against an
+/// empty snapshot describes the full state.
+fn diff_snapshots(old: &Snapshot, new: &Snapshot) -> Vec<Event> {
+    let event = |kind, path: &PathBuf, state: &WorktreeState| Event {
+        kind,
+        branch: state.branch.clone(),
+        path: path.clone(),
+    };
+    let dirtiness = |state: &WorktreeState| {
+        if state.dirty {
+            EventKind::BranchDirty
+        } else {
+            EventKind::BranchClean
+        }
+    };
+
+    let mut events: Vec<Event> = old
+        .iter()
+        .filter(|(path, _)| !new.contains_key(*path))
+        .map(|(path, state)| event(EventKind::WorktreeRemoved, path, state))
+        .collect();
+
+    for (path, state) in new {
+        match old.get(path) {
+            None => {
+                events.push(event(EventKind::WorktreeCreated, path, state));
+                events.push(event(dirtiness(state), path, state));
+            }
+            Some(prev) => {
+                if prev.branch != state.branch {
+                    events.push(event(EventKind::BranchSwitched, path, state));
+                }
+                if prev.dirty != state.dirty {
+                    events.push(event(dirtiness(state), path, state));
+                }
+            }
+        }
+    }
+    events
+}
+
+/// Write events to stdout, flushing so line-buffered readers see them at once.
+///
+/// Returns `Ok(false)` once the reader has gone away.
+fn emit(events: &[Event]) -> anyhow::Result<bool> {
+    let mut stdout = io::stdout().lock();
+    let result = events
+        .iter()
+        .try_for_each(|event| writeln!(stdout, "{event}"))
+        .and_then(|()| stdout.flush());
+    match result {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(false),
+        Err(e) => Err(e).context("Failed to write events"),
+    }
+}
+
+/// Handle `wt events`.
+pub fn handle_events(follow: bool, interval: &str) -> anyhow::Result<()> {
+    let interval: Duration =
+        humantime::parse_duration(interval).context("Invalid --interval duration")?;
+    let repo = Repository::current()?;
+
+    let mut state = snapshot(&repo)?;
+    if !emit(&diff_snapshots(&Snapshot::new(), &state))? || !follow {
+        return Ok(());
+    }
+
+    loop {
+        std::thread::sleep(interval);
+        let next = snapshot(&repo)?;
+        if !emit(&diff_snapshots(&state, &next))? {
+            return Ok(());
+        }
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(branch: Option<&str>, dirty: bool) -> WorktreeState {
+        WorktreeState {
+            branch: branch.map(str::to_string),
+            dirty,
+        }
+    }
+
+    fn lines(events: &[Event]) -> Vec<String> {
+        events.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let old = Snapshot::from([
+            ("/repo".into(), state(Some("main"), false)),
+            ("/repo.a".into(), state(Some("a"), false)),
+            ("/repo.b".into(), state(Some("b"), true)),
+        ]);
+        let new = Snapshot::from([
+            ("/repo".into(), state(Some("main"), true)),
+            ("/repo.b".into(), state(None, true)),
+            ("/repo.c d".into(), state(Some("c"), false)),
+        ]);
+
+        assert_eq!(
+            lines(&diff_snapshots(&old, &new)),
+            [
+                "worktree-removed a /repo.a",
+                "branch-dirty main /repo",
+                "branch-switched - /repo.b",
+                "worktree-created c /repo.c d",
+                "branch-clean c /repo.c d",
+            ]
+        );
+        assert!(diff_snapshots(&new, &new).is_empty());
+    }
+}
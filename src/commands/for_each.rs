@@ -22,19 +22,19 @@
 //! For now, we keep `for-each` under `step` as a pragmatic choice.
 
 use std::collections::HashMap;
-use std::io::Write;
 use std::process::Stdio;
 
 use color_print::cformat;
 use worktrunk::config::{UserConfig, expand_template};
-use worktrunk::git::Repository;
-use worktrunk::git::WorktrunkError;
-use worktrunk::shell_exec::ShellConfig;
+use worktrunk::git::{GitError, Repository, WorktrunkError};
+use worktrunk::shell_exec::{Cmd, interrupted};
 use worktrunk::styling::{
     eprintln, error_message, format_with_gutter, progress_message, success_message, warning_message,
 };
 
+use crate::commands::batch::{BatchAction, BatchPolicy, LockedSkips};
 use crate::commands::command_executor::{CommandContext, build_hook_context};
+use crate::commands::hooks::record_activity;
 use crate::commands::worktree_display_name;
 
 /// Run a command in each worktree sequentially.
@@ -43,15 +43,25 @@ use crate::commands::worktree_display_name;
 /// in real-time. Continues on errors and reports a summary at the end.
 ///
 /// All template variables from hooks are available, and context JSON is piped to stdin.
-pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
+/// Locked worktrees are skipped unless `include_locked`.
+pub fn step_for_each(args: Vec<String>, include_locked: bool) -> anyhow::Result<()> {
     let repo = Repository::current()?;
+    let config = UserConfig::load()?;
+    let policy = BatchPolicy::new(BatchAction::Run, include_locked);
+    let mut skipped_locked = LockedSkips::default();
     // Filter out prunable worktrees (directory deleted) - can't run commands there
     let worktrees: Vec<_> = repo
         .list_worktrees()?
         .into_iter()
         .filter(|wt| !wt.is_prunable())
+        .filter(|wt| match policy.locked_skip(wt) {
+            Some(reason) => {
+                skipped_locked.push(worktree_display_name(wt, &repo, &config), reason);
+                false
+            }
+            None => true,
+        })
         .collect();
-    let config = UserConfig::load()?;
 
     let mut failed: Vec<String> = Vec::new();
     let total = worktrees.len();
@@ -59,7 +69,7 @@ pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
     // Join args into a template string (will be expanded per-worktree)
     let command_template = args.join(" ");
 
-    for wt in &worktrees {
+    for (completed, wt) in worktrees.iter().enumerate() {
         let display_name = worktree_display_name(wt, &repo, &config);
         eprintln!(
             "{}",
@@ -86,7 +96,32 @@ pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
 
         // Execute command: stream both stdout and stderr in real-time
         // Pipe context JSON to stdin for scripts that want structured data
-        match run_command_streaming(&command, &wt.path, Some(&context_json)) {
+        let result = run_command_streaming(&command, &wt.path, Some(&context_json));
+        let exit_code = match &result {
+            Ok(()) => Some(0),
+            Err(CommandError::SpawnFailed(_)) => None,
+            Err(CommandError::ExitCode(code)) => *code,
+        };
+        record_activity(&ctx, &command, exit_code);
+        if let Some(signal) = interrupted() {
+            eprintln!();
+            let failures = match failed.len() {
+                0 => String::new(),
+                n => format!(", {n} failed"),
+            };
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Interrupted in <bold>{display_name}</> after {completed} of {total} worktree{}{failures}",
+                    if total == 1 { "" } else { "s" }
+                ))
+            );
+            return Err(WorktrunkError::AlreadyDisplayed {
+                exit_code: 128 + signal,
+            }
+            .into());
+        }
+        match result {
             Ok(()) => {}
             Err(CommandError::SpawnFailed(err)) => {
                 eprintln!(
@@ -112,6 +147,9 @@ pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
 
     // Summary
     eprintln!();
+    if let Some(message) = skipped_locked.message(&policy) {
+        eprintln!("{message}");
+    }
     if failed.is_empty() {
         eprintln!(
             "{}",
@@ -151,6 +189,9 @@ pub(crate) enum CommandError {
 /// Both stdout and stderr stream to the terminal (stderr) in real-time.
 /// If `stdin_content` is provided, it's piped to the command's stdin.
 ///
+/// The command runs in its own process group; Ctrl-C is forwarded to the whole
+/// group so nothing it spawned outlives it (see [`worktrunk::shell_exec::interrupted`]).
+///
 /// # TODO: Streaming vs Gutter Tradeoff
 ///
 /// Currently stderr streams directly without gutter formatting, same as hooks.
@@ -164,46 +205,24 @@ pub(crate) fn run_command_streaming(
     working_dir: &std::path::Path,
     stdin_content: Option<&str>,
 ) -> Result<(), CommandError> {
-    let shell = ShellConfig::get().map_err(|e| CommandError::SpawnFailed(e.to_string()))?;
-
-    log::debug!("$ {} (streaming)", command);
-
-    let stdin_mode = if stdin_content.is_some() {
-        Stdio::piped()
-    } else {
-        Stdio::inherit() // Allow interactive commands when no stdin content
-    };
-
-    let mut child = shell
-        .command(command)
+    let mut cmd = Cmd::shell(command)
         .current_dir(working_dir)
-        .stdin(stdin_mode)
         // Redirect stdout to stderr to keep stdout reserved for data output
-        // Note: Stdio::from(Stderr) works since Rust 1.74 (impl From<Stderr> for Stdio)
         .stdout(Stdio::from(std::io::stderr()))
-        // Stream stderr to terminal in real-time
-        .stderr(Stdio::inherit())
-        // Prevent subprocesses from writing to the directive file
-        .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
-        .spawn()
-        .map_err(|e| CommandError::SpawnFailed(e.to_string()))?;
-
-    // Write stdin content if provided (JSON context for scripts)
-    if let Some(content) = stdin_content
-        && let Some(mut stdin) = child.stdin.take()
-    {
-        // Ignore write errors - command may not read stdin
-        let _ = stdin.write_all(content.as_bytes());
-        // stdin is dropped here, closing the pipe
+        // Allow interactive commands when no stdin content
+        .stdin(Stdio::inherit())
+        .forward_signals();
+    if let Some(content) = stdin_content {
+        cmd = cmd.stdin_bytes(content);
     }
 
-    let status = child
-        .wait()
-        .map_err(|e| CommandError::SpawnFailed(e.to_string()))?;
-
-    if status.success() {
-        Ok(())
-    } else {
-        Err(CommandError::ExitCode(status.code()))
-    }
+    cmd.stream().map_err(|err| {
+        if let Some(WorktrunkError::ChildProcessExited { code, .. }) = err.downcast_ref() {
+            CommandError::ExitCode(Some(*code))
+        } else if let Some(GitError::Other { message }) = err.downcast_ref() {
+            CommandError::SpawnFailed(message.clone())
+        } else {
+            CommandError::SpawnFailed(err.to_string())
+        }
+    })
 }
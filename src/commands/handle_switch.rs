@@ -1,22 +1,28 @@
 //! Switch command handler.
 
 use std::collections::HashMap;
+use std::io::{self, IsTerminal};
 use std::path::Path;
 
 use anyhow::Context;
+use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::{UserConfig, expand_template};
+use worktrunk::config::{Approvals, Command, UserConfig, expand_template};
 use worktrunk::git::{GitError, Repository, SwitchSuggestionCtx, current_or_recover};
-use worktrunk::styling::{eprintln, info_message};
+use worktrunk::integrations::PathEvent;
+use worktrunk::styling::{eprintln, format_bash_with_gutter, hint_message, info_message};
 
-use super::command_approval::approve_hooks;
+use super::command_approval::{approve_command_batch, approve_hooks};
 use super::command_executor::{CommandContext, build_hook_context};
-use super::hooks::{HookFailureStrategy, execute_hook};
+use super::hooks::{HookFailureStrategy, execute_hook, record_activity};
+use super::project_config::{ApprovableCommand, Phase};
 use super::worktree::{
-    SwitchBranchInfo, SwitchPlan, SwitchResult, execute_switch, get_path_mismatch, plan_switch,
+    SharedCheckout, SwitchBranchInfo, SwitchPlan, SwitchResult, SwitchTargetFlags,
+    check_new_branch_name, execute_switch, get_path_mismatch, plan_switch,
 };
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
 use crate::output::{
-    execute_user_command, handle_switch_output, is_shell_integration_active,
+    execute_user_commands, handle_switch_output, is_shell_integration_active,
     prompt_shell_integration,
 };
 
@@ -25,15 +31,60 @@ pub struct SwitchOptions<'a> {
     pub branch: &'a str,
     pub create: bool,
     pub base: Option<&'a str>,
-    pub execute: Option<&'a str>,
+    /// Create the branch as an orphan (no history)
+    pub orphan: bool,
+    /// Create a name that breaks `[branch-name-rules]` under its suggested fix
+    pub fix_name: bool,
+    /// Skip `[branch-name-rules]` from project config
+    pub no_name_rules: bool,
+    /// Check out tags on an `inspect/<tag>` branch rather than detached
+    pub branch_from_tag: bool,
+    /// `--execute` commands, run in order after any `[[on-switch]]` commands
+    pub execute: &'a [String],
+    /// Appended to the last `--execute` command
     pub execute_args: &'a [String],
+    /// Run the remaining commands after one fails
+    pub keep_going: bool,
+    /// Skip `[[on-switch]]` commands from project config
+    pub no_exec: bool,
+    /// Worktree profile forced over pattern matching
+    pub profile: Option<&'a str>,
     pub yes: bool,
     pub clobber: bool,
+    /// Create the worktree here instead of at the template path
+    pub new_path: Option<&'a Path>,
+    /// Detach HEAD in the `new_path` worktree
+    pub detach: bool,
+    /// Check out the branch in `new_path` even if another worktree has it
+    pub force: bool,
+    /// Allow a path outside `allowed-worktree-roots`, after confirmation
+    pub allow_any_path: bool,
     /// Whether to change directory after switching (default: true)
     pub change_dir: bool,
     pub verify: bool,
 }
 
+/// Suggest cleaning up once the worktree count reaches `[switch] warn-worktrees`.
+fn warn_worktree_count(repo: &Repository, config: &UserConfig) -> anyhow::Result<()> {
+    let project = repo.project_identifier().ok();
+    let Some(threshold) = config
+        .switch(project.as_deref())
+        .and_then(|s| s.warn_worktrees)
+    else {
+        return Ok(());
+    };
+    let count = repo.list_worktrees()?.len();
+    if count >= threshold {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "{count} worktrees exist — consider <underline>wt step prune</>"
+            ))
+        );
+    }
+    Ok(())
+}
+
 /// Run pre-switch hooks before branch validation or worktree creation.
 ///
 /// Uses current worktree context since the destination is unknown at this point.
@@ -78,7 +129,8 @@ pub(crate) fn approve_switch_hooks(
         return Ok(false);
     }
 
-    let ctx = CommandContext::new(repo, config, Some(plan.branch()), plan.worktree_path(), yes);
+    let ctx = CommandContext::new(repo, config, Some(plan.branch()), plan.worktree_path(), yes)
+        .with_profile(plan.profile());
     let approved = if plan.is_create() {
         approve_hooks(
             &ctx,
@@ -129,16 +181,19 @@ pub(crate) fn switch_extra_vars(result: &SwitchResult) -> Vec<(&str, &str)> {
 }
 
 /// Spawn post-switch (and post-start for creates) background hooks.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn spawn_switch_background_hooks(
     repo: &Repository,
     config: &UserConfig,
     result: &SwitchResult,
     branch: &str,
+    profile: Option<&str>,
     yes: bool,
     extra_vars: &[(&str, &str)],
     hooks_display_path: Option<&Path>,
 ) -> anyhow::Result<()> {
-    let ctx = CommandContext::new(repo, config, Some(branch), result.path(), yes);
+    let ctx =
+        CommandContext::new(repo, config, Some(branch), result.path(), yes).with_profile(profile);
 
     let mut hooks = super::hooks::prepare_background_hooks(
         &ctx,
@@ -167,16 +222,38 @@ pub fn handle_switch(
         branch,
         create,
         base,
+        orphan,
+        fix_name,
+        no_name_rules,
+        branch_from_tag,
         execute,
         execute_args,
+        keep_going,
+        no_exec,
+        profile,
         yes,
         clobber,
+        new_path,
+        detach,
+        force,
+        allow_any_path,
         change_dir,
         verify,
     } = opts;
 
     let (repo, is_recovered) = current_or_recover().context("Failed to switch worktree")?;
 
+    if let Some(name) = profile {
+        let known = repo
+            .load_project_config()?
+            .is_some_and(|c| c.profile_named(name).is_some());
+        if !known {
+            anyhow::bail!(cformat!(
+                "No worktree profile named <bold>{name}</> in project config"
+            ));
+        }
+    }
+
     // Run pre-switch hooks before anything else (before branch validation, planning, etc.)
     // Skip when recovered — the source worktree is gone, nothing to run hooks against.
     if verify && !is_recovered {
@@ -186,17 +263,47 @@ pub fn handle_switch(
     // Build switch suggestion context for enriching error hints with --execute/trailing args.
     // Without this, errors like "branch already exists" would suggest `wt switch <branch>`
     // instead of the full `wt switch <branch> --execute=<cmd> -- <args>`.
-    let suggestion_ctx = execute.map(|exec| {
-        let escaped = shell_escape::escape(exec.into());
+    let suggestion_ctx = (!execute.is_empty()).then(|| {
+        let mut extra_flags: Vec<String> = execute
+            .iter()
+            .map(|exec| format!("--execute={}", shell_escape::escape(exec.into())))
+            .collect();
+        if keep_going {
+            extra_flags.push("--keep-going".to_string());
+        }
         SwitchSuggestionCtx {
-            extra_flags: vec![format!("--execute={escaped}")],
+            extra_flags,
             trailing_args: execute_args.to_vec(),
         }
     });
 
+    // A new branch's name must pass the project's naming rules
+    let checked_branch = if create && !no_name_rules {
+        check_new_branch_name(&repo, branch, fix_name)?
+    } else {
+        branch.to_string()
+    };
+    let branch = checked_branch.as_str();
+
     // Validate FIRST (before approval) - fails fast if branch doesn't exist, etc.
-    let plan = plan_switch(&repo, branch, create, base, clobber, config).map_err(|err| {
-        match suggestion_ctx {
+    let flags = SwitchTargetFlags {
+        create,
+        base,
+        orphan,
+        branch_from_tag,
+        new_path,
+        shared_checkout: if detach {
+            Some(SharedCheckout::Detach)
+        } else if force {
+            Some(SharedCheckout::Force)
+        } else {
+            None
+        },
+        allow_any_path,
+        profile,
+    };
+    let plan =
+        plan_switch(&repo, branch, flags, clobber, config).map_err(|err| match suggestion_ctx {
             Some(ref ctx) => match err.downcast::<GitError>() {
                 Ok(git_err) => GitError::WithSwitchSuggestion {
                     source: Box::new(git_err),
@@ -206,8 +313,7 @@ pub fn handle_switch(
                 Err(err) => err,
             },
             None => err,
-        }
-    })?;
+        })?;
 
     // "Approve at the Gate": collect and approve hooks upfront
     // This ensures approval happens once at the command entry point
@@ -246,12 +352,33 @@ pub fn handle_switch(
     let hooks_display_path =
         handle_switch_output(&result, &branch_info, change_dir, Some(&source_root), &cwd)?;
 
+    if matches!(result, SwitchResult::Created { .. }) {
+        warn_worktree_count(&repo, config)?;
+    }
+
+    // Register the worktree with zoxide and `on-path-created`
+    let event = match result {
+        SwitchResult::Created { .. } => Some(PathEvent::Created),
+        SwitchResult::Existing { .. } => Some(PathEvent::Switched),
+        SwitchResult::AlreadyAt(_) => None,
+    };
+    if let Some(event) = event {
+        worktrunk::integrations::notify(
+            &repo,
+            config.integrations.as_ref(),
+            event,
+            result.path(),
+            &branch_info.branch,
+            result.path(),
+        );
+    }
+
     // Offer shell integration if not already installed/active
     // (only shows prompt/hint when shell integration isn't working)
     // With --execute: show hints only (don't interrupt with prompt)
     // Best-effort: don't fail switch if offer fails
     if !is_shell_integration_active() {
-        let skip_prompt = execute.is_some();
+        let skip_prompt = !execute.is_empty();
         let _ = prompt_shell_integration(config, binary_name, skip_prompt);
     }
 
@@ -270,15 +397,30 @@ pub fn handle_switch(
             config,
             &result,
             &branch_info.branch,
+            profile,
             yes,
             &extra_vars,
             hooks_display_path.as_deref(),
         )?;
     }
 
-    // Execute user command after post-start hooks have been spawned
+    // Execute user commands after post-start hooks have been spawned:
+    // matching [[on-switch]] entries first, then --execute commands in the order given
+    let mut commands = if no_exec {
+        Vec::new()
+    } else {
+        on_switch_commands(
+            &repo,
+            config,
+            &branch_info.branch,
+            result.path(),
+            yes,
+            &extra_vars,
+        )?
+    };
+
     // Note: execute_args requires execute via clap's `requires` attribute
-    if let Some(cmd) = execute {
+    if let Some((last, _)) = execute.split_last() {
         // Build template context for expansion (includes base vars when creating)
         let ctx = CommandContext::new(&repo, config, Some(&branch_info.branch), result.path(), yes);
         let template_vars = build_hook_context(&ctx, &extra_vars)?;
@@ -287,26 +429,141 @@ pub fn handle_switch(
             .map(|(k, v)| (k.as_str(), v.as_str()))
             .collect();
 
-        // Expand template variables in command (shell_escape: true for safety)
-        let expanded_cmd = expand_template(cmd, &vars, true, &repo, "--execute command")?;
+        // Each arg after -- is expanded, then shell-escaped
+        let expanded_args: Vec<String> = execute_args
+            .iter()
+            .map(|arg| expand_template(arg, &vars, false, &repo, "--execute argument"))
+            .collect::<Result<_, _>>()?;
+        let escaped_args: Vec<_> = expanded_args
+            .iter()
+            .map(|arg| shell_escape::escape(arg.into()).into_owned())
+            .collect();
+
+        for cmd in execute {
+            // Expand template variables in command (shell_escape: true for safety)
+            let expanded_cmd = expand_template(cmd, &vars, true, &repo, "--execute command")?;
+            // Trailing args (after --) go to the last command
+            let full_cmd = if std::ptr::eq(cmd, last) && !escaped_args.is_empty() {
+                format!("{} {}", expanded_cmd, escaped_args.join(" "))
+            } else {
+                expanded_cmd
+            };
+            // Runs after we exit (or is handed to the shell), so the outcome is unknown
+            record_activity(&ctx, &full_cmd, None);
+            commands.push(full_cmd);
+        }
+    }
+
+    if !commands.is_empty() {
+        // Without a cd (--no-cd), the commands still run in the worktree
+        let exec_dir = (!change_dir).then(|| result.path().as_path());
+        execute_user_commands(
+            &commands,
+            hooks_display_path.as_deref(),
+            exec_dir,
+            keep_going,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Offer `[[on-switch]]` commands from project config that match the switched-to branch.
+///
+/// Entries with `auto = true` go through the usual project-command approval and then
+/// run; the others are confirmed one at a time (accepted by `--yes`, skipped when stdin
+/// isn't a terminal). Returns the accepted commands, expanded, in config order.
+fn on_switch_commands(
+    repo: &Repository,
+    config: &UserConfig,
+    branch: &str,
+    worktree_path: &Path,
+    yes: bool,
+    extra_vars: &[(&str, &str)],
+) -> anyhow::Result<Vec<String>> {
+    let Some(project_config) = repo.load_project_config()? else {
+        return Ok(Vec::new());
+    };
+    let entries: Vec<_> = project_config.on_switch_for(branch).collect();
+    if entries.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let auto_commands: Vec<ApprovableCommand> = entries
+        .iter()
+        .filter(|entry| entry.auto)
+        .map(|entry| ApprovableCommand {
+            phase: Phase::OnSwitch,
+            command: Command::new(None, entry.run.clone()),
+        })
+        .collect();
+    let auto_approved = auto_commands.is_empty()
+        || {
+            let project_id = repo.project_identifier()?;
+            let approvals = Approvals::load().context("Failed to load approvals")?;
+            // The switch already happened, so a missing approval skips the commands
+            // rather than failing the whole command
+            match approve_command_batch(&auto_commands, &project_id, &approvals, yes, false) {
+                Err(e)
+                    if matches!(e.downcast_ref::<GitError>(), Some(GitError::NotInteractive)) =>
+                {
+                    eprintln!(
+                        "{}",
+                        hint_message(cformat!(
+                            "Skipped unapproved on-switch commands (not interactive). To approve them, use <underline>--yes</>"
+                        ))
+                    );
+                    false
+                }
+                result => result?,
+            }
+        };
 
-        // Append any trailing args (after --) to the execute command
-        // Each arg is also expanded, then shell-escaped
-        let full_cmd = if execute_args.is_empty() {
-            expanded_cmd
+    let mut accepted = Vec::new();
+    for entry in entries {
+        let run = if entry.auto {
+            auto_approved
         } else {
-            let expanded_args: Result<Vec<_>, _> = execute_args
-                .iter()
-                .map(|arg| expand_template(arg, &vars, false, &repo, "--execute argument"))
-                .collect();
-            let escaped_args: Vec<_> = expanded_args?
-                .iter()
-                .map(|arg| shell_escape::escape(arg.into()).into_owned())
-                .collect();
-            format!("{} {}", expanded_cmd, escaped_args.join(" "))
+            confirm_on_switch_command(&entry.run, yes)?
         };
-        execute_user_command(&full_cmd, hooks_display_path.as_deref())?;
+        if run {
+            accepted.push(entry.run.as_str());
+        }
+    }
+    if accepted.is_empty() {
+        return Ok(Vec::new());
     }
 
-    Ok(())
+    let ctx = CommandContext::new(repo, config, Some(branch), worktree_path, yes);
+    let template_vars = build_hook_context(&ctx, extra_vars)?;
+    let vars: HashMap<&str, &str> = template_vars
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    accepted
+        .into_iter()
+        .map(|run| expand_template(run, &vars, true, repo, "on-switch command"))
+        .collect::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+/// Ask whether to run an offered `[[on-switch]]` command.
+fn confirm_on_switch_command(template: &str, yes: bool) -> anyhow::Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    if !io::stdin().is_terminal() {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Skipped on-switch command <bold>{template}</> (not interactive). To run it, use <underline>--yes</> or set <underline>auto = true</>"
+            ))
+        );
+        return Ok(false);
+    }
+
+    eprintln!("{}", info_message("Project config suggests running:"));
+    eprintln!("{}", format_bash_with_gutter(template));
+    let response = prompt_yes_no_preview("Run it?", || {})?;
+    Ok(response == PromptResponse::Accepted)
 }
@@ -0,0 +1,52 @@
+//! Hiding branches from `wt list` (`wt hide` / `wt unhide`).
+//!
+//! The hidden set is per-repository state in git config
+//! (`worktrunk.state.<branch>.hidden`). It only affects listings: commands
+//! that name a hidden branch treat it like any other, and `wt step prune`
+//! leaves hidden branches alone unless `--include-hidden` is given.
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{eprintln, info_message, success_message};
+
+/// Handle `wt hide <branch>...`.
+pub fn handle_hide(branches: &[String]) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    for branch in branches {
+        if !repo.branch(branch).exists_locally()? {
+            return Err(GitError::BranchNotFound {
+                branch: branch.clone(),
+                show_create_hint: false,
+                closest: None,
+            }
+            .into());
+        }
+    }
+
+    for branch in branches {
+        let message = if repo.set_branch_hidden(branch, true)? {
+            success_message(cformat!("Hid <bold>{branch}</> from <bold>wt list</>"))
+        } else {
+            info_message(cformat!("<bold>{branch}</> is already hidden"))
+        };
+        eprintln!("{message}");
+    }
+    Ok(())
+}
+
+/// Handle `wt unhide <branch>...`.
+///
+/// Doesn't require the branch to exist, so entries for deleted branches can
+/// be cleared.
+pub fn handle_unhide(branches: &[String]) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    for branch in branches {
+        let message = if repo.set_branch_hidden(branch, false)? {
+            success_message(cformat!("Unhid <bold>{branch}</>"))
+        } else {
+            info_message(cformat!("<bold>{branch}</> isn't hidden"))
+        };
+        eprintln!("{message}");
+    }
+    Ok(())
+}
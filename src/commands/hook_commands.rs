@@ -13,7 +13,9 @@ use anyhow::Context;
 use color_print::cformat;
 use strum::IntoEnumIterator;
 use worktrunk::HookType;
-use worktrunk::config::{Approvals, CommandConfig, ProjectConfig, UserConfig};
+use worktrunk::config::{
+    Approvals, CommandConfig, ProjectConfig, UserConfig, deprecated_var_replacement,
+};
 use worktrunk::git::{GitError, Repository};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
@@ -52,7 +54,8 @@ fn run_filtered_hook(
             display_path: crate::output::pre_hook_display_path(ctx.worktree_path),
         },
         failure_strategy,
-    )
+    )?;
+    Ok(())
 }
 
 fn run_post_hook(
@@ -143,10 +146,15 @@ pub fn run_hook(
         }
     }
 
-    // Build extra vars from command-line --var flags
+    // Build extra vars from command-line --var flags. Older configs are upgraded
+    // in memory to the new variable names, so a deprecated alias also sets its
+    // replacement.
     let custom_vars_refs: Vec<(&str, &str)> = custom_vars
         .iter()
-        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .flat_map(|(k, v)| {
+            std::iter::once((k.as_str(), v.as_str()))
+                .chain(deprecated_var_replacement(k).map(|new| (new, v.as_str())))
+        })
         .collect();
 
     /// Helper to require at least one hook is configured (for standalone `wt hook` command)
@@ -260,11 +268,7 @@ pub fn add_approvals(show_all: bool) -> anyhow::Result<()> {
     let approvals = Approvals::load().context("Failed to load approvals")?;
 
     // Load project config (error if missing - this command requires it)
-    let config_path = repo
-        .current_worktree()
-        .root()?
-        .join(".config")
-        .join("wt.toml");
+    let config_path = repo.project_config_path()?;
     let project_config = repo
         .load_project_config()?
         .ok_or(GitError::ProjectConfigNotFound { config_path })?;
@@ -501,8 +505,7 @@ fn render_project_hooks(
     filter: Option<HookType>,
     ctx: Option<&CommandContext>,
 ) -> anyhow::Result<()> {
-    let repo_root = repo.current_worktree().root()?;
-    let config_path = repo_root.join(".config").join("wt.toml");
+    let config_path = repo.project_config_path()?;
 
     writeln!(
         out,
@@ -72,6 +72,15 @@ pub enum HookFailureStrategy {
     Warn,
 }
 
+/// Counts from a foreground hook run, for summaries after the fact.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HookRunSummary {
+    /// Commands that ran
+    pub ran: usize,
+    /// Commands that exited non-zero (only nonzero under `Warn`)
+    pub failed: usize,
+}
+
 // Re-export for backward compatibility with existing imports
 pub use super::hook_filter::{HookSource, ParsedFilter};
 
@@ -253,6 +262,7 @@ pub fn spawn_background_hooks(
         } else {
             // Background: outcome unknown, log with null exit/duration
             worktrunk::command_log::log_command(&log_label, &cmd.prepared.expanded, None, None);
+            record_activity(ctx, &cmd.prepared.expanded, None);
         }
     }
 
@@ -319,11 +329,31 @@ pub(crate) fn check_name_filter_matched(
     Ok(())
 }
 
+/// Record a command in the worktree's activity log, when `[activity]` is enabled.
+///
+/// `exit_code` is `None` for commands whose outcome isn't known here (background
+/// hooks, `--execute`). Failures to write are logged and otherwise ignored.
+pub fn record_activity(ctx: &CommandContext, command: &str, exit_code: Option<i32>) {
+    let Some(activity) = ctx.config.activity.as_ref().filter(|a| a.enabled()) else {
+        return;
+    };
+    if let Err(err) = worktrunk::activity::record(
+        &ctx.repo.wt_logs_dir(),
+        ctx.worktree_path,
+        command,
+        exit_code,
+        activity.size(),
+    ) {
+        log::debug!("Failed to record activity: {err}");
+    }
+}
+
 /// Run user and project hooks for a given hook type.
 ///
 /// This is the canonical implementation for running hooks from both sources.
 /// Runs user hooks first, then project hooks sequentially. Handles name filtering
 /// and returns an error if a name filter was provided but no matching command found.
+/// On success, returns how many commands ran and how many failed.
 ///
 /// `display_path`: Pass `ctx.hooks_display_path()` for automatic detection, or
 /// explicit `Some(path)` when hooks run somewhere the user won't be cd'd to.
@@ -331,7 +361,7 @@ pub fn run_hook_with_filter(
     ctx: &CommandContext,
     spec: HookCommandSpec<'_, '_, '_, '_>,
     failure_strategy: HookFailureStrategy,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<HookRunSummary> {
     let commands = prepare_hook_commands(ctx, spec)?;
     let HookCommandSpec {
         user_config,
@@ -343,8 +373,9 @@ pub fn run_hook_with_filter(
 
     check_name_filter_matched(name_filter, commands.len(), user_config, project_config)?;
 
+    let mut summary = HookRunSummary::default();
     if commands.is_empty() {
-        return Ok(());
+        return Ok(summary);
     }
 
     // Track first failure's exit code for Warn strategy (to propagate after all commands run)
@@ -352,14 +383,26 @@ pub fn run_hook_with_filter(
 
     for cmd in commands {
         cmd.announce()?;
+        summary.ran += 1;
 
         let log_label = format!("{} {}", cmd.hook_type, cmd.summary_name());
-        if let Err(err) = execute_command_in_worktree(
+        let result = execute_command_in_worktree(
             ctx.worktree_path,
             &cmd.prepared.expanded,
             Some(&cmd.prepared.context_json),
             Some(&log_label),
-        ) {
+        );
+        let exit_code = match &result {
+            Ok(()) => Some(0),
+            Err(err) => worktrunk::git::exit_code(err),
+        };
+        record_activity(ctx, &cmd.prepared.expanded, exit_code);
+
+        if let Err(err) = result {
+            // Ctrl-C stops the whole hook, even under the warn strategy
+            if worktrunk::shell_exec::interrupted().is_some() {
+                return Err(err);
+            }
             // Extract raw message and exit code from error
             let (err_msg, exit_code) = if let Some(wt_err) = err.downcast_ref::<WorktrunkError>() {
                 match wt_err {
@@ -383,6 +426,7 @@ pub fn run_hook_with_filter(
                     .into());
                 }
                 HookFailureStrategy::Warn => {
+                    summary.failed += 1;
                     let message = match &cmd.prepared.name {
                         Some(name) => cformat!("Command <bold>{name}</> failed: {err_msg}"),
                         None => format!("Command failed: {err_msg}"),
@@ -405,7 +449,7 @@ pub fn run_hook_with_filter(
         return Err(WorktrunkError::AlreadyDisplayed { exit_code }.into());
     }
 
-    Ok(())
+    Ok(summary)
 }
 
 /// Look up user and project configs for a given hook type.
@@ -436,8 +480,8 @@ pub fn execute_hook(
     failure_strategy: HookFailureStrategy,
     name_filter: Option<&str>,
     display_path: Option<&Path>,
-) -> anyhow::Result<()> {
-    let project_config = ctx.repo.load_project_config()?;
+) -> anyhow::Result<HookRunSummary> {
+    let project_config = ctx.project_config()?;
     let user_hooks = ctx.config.hooks(ctx.project_id().as_deref());
     let (user_config, proj_config) =
         lookup_hook_configs(&user_hooks, project_config.as_ref(), hook_type);
@@ -472,7 +516,7 @@ pub(crate) fn prepare_background_hooks(
     extra_vars: &[(&str, &str)],
     display_path: Option<&Path>,
 ) -> anyhow::Result<Vec<SourcedCommand>> {
-    let project_config = ctx.repo.load_project_config()?;
+    let project_config = ctx.project_config()?;
     let user_hooks = ctx.config.hooks(ctx.project_id().as_deref());
     let (user_config, proj_config) =
         lookup_hook_configs(&user_hooks, project_config.as_ref(), hook_type);
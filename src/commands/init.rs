@@ -30,54 +30,90 @@ pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
 /// - Include shell integration (cd-on-switch functionality)
 /// - Register dynamic completions
 ///
-/// TODO(completions): We output static completions because that's the package manager
-/// convention, but dynamic completions (one-liner that calls the binary at tab-time)
-/// might be better—users would get branch name completion. See the fish example in
-/// `~/.config/fish/completions/wt.fish` which calls `COMPLETE=fish wt` at runtime.
-/// Other tools like gh/kubectl also call their binaries at runtime.
+/// The scripts are generated from the clap command tree at runtime, so new flags and
+/// subcommands appear without touching any shell code. Branch names and other
+/// custom values come from the dynamic protocol instead (`COMPLETE=$SHELL wt` or the
+/// hidden `wt __complete <shell> -- <words>`), which walks the same command tree.
 pub fn handle_completions(shell: shell::Shell) -> anyhow::Result<()> {
-    let mut cmd = Cli::command();
     let cmd_name = crate::binary_name();
-    let mut stdout = io::stdout();
+    write_completions(shell, &cmd_name, &mut io::stdout())?;
+    Ok(())
+}
+
+/// Write the static completion script for `shell` to `out`.
+fn write_completions(shell: shell::Shell, cmd_name: &str, out: &mut dyn Write) -> io::Result<()> {
+    let mut cmd = Cli::command();
 
     match shell {
-        shell::Shell::Bash => {
-            generate(
-                clap_complete::shells::Bash,
-                &mut cmd,
-                &cmd_name,
-                &mut stdout,
-            );
-        }
-        shell::Shell::Fish => {
-            generate(
-                clap_complete::shells::Fish,
-                &mut cmd,
-                &cmd_name,
-                &mut stdout,
-            );
-        }
-        shell::Shell::Zsh => {
-            generate(clap_complete::shells::Zsh, &mut cmd, &cmd_name, &mut stdout);
-        }
+        shell::Shell::Bash => generate(clap_complete::shells::Bash, &mut cmd, cmd_name, out),
+        shell::Shell::Fish => generate(clap_complete::shells::Fish, &mut cmd, cmd_name, out),
+        shell::Shell::Zsh => generate(clap_complete::shells::Zsh, &mut cmd, cmd_name, out),
         shell::Shell::Nushell => {
             // Nushell uses template-based integration (shell wrapper + completions in one)
             // Unlike other shells, it doesn't use clap_complete
-            let init = shell::ShellInit::with_prefix(shell, cmd_name.clone());
+            let init = shell::ShellInit::with_prefix(shell, cmd_name.to_string());
             let code = init
                 .generate()
                 .expect("Failed to generate nushell integration");
-            write!(stdout, "{}", code).expect("Failed to write to stdout");
+            write!(out, "{}", code)?;
         }
         shell::Shell::PowerShell => {
-            generate(
-                clap_complete::shells::PowerShell,
-                &mut cmd,
-                &cmd_name,
-                &mut stdout,
-            );
+            generate(clap_complete::shells::PowerShell, &mut cmd, cmd_name, out)
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Command;
+
+    use super::*;
+
+    /// Collect `(path, long flag)` pairs for every visible subcommand and option.
+    fn visible_commands_and_flags(
+        cmd: &Command,
+        path: &str,
+        out: &mut Vec<(String, Option<String>)>,
+    ) {
+        for arg in cmd.get_arguments().filter(|a| !a.is_hide_set()) {
+            if let Some(long) = arg.get_long() {
+                out.push((path.to_string(), Some(format!("--{long}"))));
+            }
+        }
+        for sub in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+            let sub_path = format!("{path} {}", sub.get_name());
+            out.push((sub_path.clone(), None));
+            visible_commands_and_flags(sub, &sub_path, out);
+        }
+    }
+
+    #[test]
+    fn test_zsh_completions_cover_command_tree() {
+        let mut script = Vec::new();
+        write_completions(shell::Shell::Zsh, "wt", &mut script).unwrap();
+        let script = String::from_utf8(script).unwrap();
+
+        let mut cmd = Cli::command();
+        cmd.build();
+        let mut expected = Vec::new();
+        visible_commands_and_flags(&cmd, "wt", &mut expected);
+        assert!(expected.len() > 50, "command tree looks empty");
+
+        let missing: Vec<_> = expected
+            .iter()
+            .filter(|(path, flag)| match flag {
+                Some(flag) => !script.contains(flag.as_str()),
+                None => {
+                    let name = path.rsplit(' ').next().unwrap();
+                    !script.contains(&format!("'{name}:")) && !script.contains(&format!("({name})"))
+                }
+            })
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "zsh completions are missing entries: {missing:?}"
+        );
+    }
+}
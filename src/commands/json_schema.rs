@@ -0,0 +1,248 @@
+//! Versioning for JSON output.
+//!
+//! Everything wt prints as JSON — `--format=json` on `wt list`, `wt list
+//! statusline`, `wt diff`, `wt which`, `wt stats`, `wt report`, and `wt
+//! config state show`, plus `--json-errors` — shares one schema version.
+//! Adding a field doesn't change it; renaming, removing, or retyping one
+//! does. Scripts pin the version they were written against with
+//! `--schema-version`, and wt refuses to run rather than print a shape they
+//! can't read. `wt config show` reports the version this build prints.
+//!
+//! The tests below snapshot the serialized defaults of each typed output, so
+//! a field rename fails here before it reaches a release (`wt config state
+//! show` builds its JSON inline; its integration snapshots cover it). When one
+//! of these snapshots changes, either the change is additive or
+//! [`SCHEMA_VERSION`] needs a bump.
+
+/// Version of the JSON shapes this build prints.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Versions a `--schema-version` request may name.
+///
+/// A bump that keeps the old shapes renderable lists both; until then this is
+/// just the current version.
+pub const SUPPORTED_SCHEMA_VERSIONS: &[u32] = &[SCHEMA_VERSION];
+
+/// Parse `--schema-version`, rejecting versions this build can't produce.
+pub fn parse_schema_version(s: &str) -> Result<u32, String> {
+    let supported = SUPPORTED_SCHEMA_VERSIONS
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let version: u32 = s
+        .parse()
+        .map_err(|_| format!("expected a schema version number; supported: {supported}"))?;
+    if !SUPPORTED_SCHEMA_VERSIONS.contains(&version) {
+        return Err(format!(
+            "schema version {version} is not supported by this wt; supported: {supported}"
+        ));
+    }
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use insta::assert_snapshot;
+    use worktrunk::git::{GitError, error_json};
+
+    use super::*;
+    use crate::commands::diff::{DiffOutput, FileDiff};
+    use crate::commands::list::json_output::{JsonItem, JsonListWithSummary, JsonSummary};
+    use crate::commands::list::model::ListItem;
+    use crate::commands::report::{CommandUsage, Report};
+    use crate::commands::stats::Stats;
+    use crate::commands::which::WhichOutput;
+
+    fn json(value: &impl serde::Serialize) -> String {
+        serde_json::to_string_pretty(value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_schema_version() {
+        assert_eq!(parse_schema_version("1"), Ok(1));
+        assert_eq!(
+            parse_schema_version("2").unwrap_err(),
+            "schema version 2 is not supported by this wt; supported: 1"
+        );
+        assert_eq!(
+            parse_schema_version("v1").unwrap_err(),
+            "expected a schema version number; supported: 1"
+        );
+    }
+
+    #[test]
+    fn test_schema_list_item() {
+        let item = ListItem::new_branch("abc123".into(), "feature".into());
+        assert_snapshot!(json(&JsonItem::from_list_item(&item)), @r#"
+        {
+          "branch": "feature",
+          "kind": "branch",
+          "commit": {
+            "sha": "abc123",
+            "short_sha": "abc123",
+            "message": "",
+            "timestamp": 0
+          },
+          "is_main": false,
+          "is_current": false,
+          "is_previous": false
+        }
+        "#);
+    }
+
+    #[test]
+    fn test_schema_list_summary() {
+        let items = [ListItem::new_branch("abc123".into(), "feature".into())];
+        let output = JsonListWithSummary {
+            items: vec![],
+            summary: JsonSummary::from_items(&items),
+        };
+        assert_snapshot!(json(&output), @r#"
+        {
+          "items": [],
+          "summary": {
+            "worktrees": 0,
+            "branches": 1,
+            "remote_branches": 0,
+            "dirty": 0,
+            "ahead": 0,
+            "behind": 0
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn test_schema_which() {
+        let output = WhichOutput {
+            branch: None,
+            path: Path::new("/repo"),
+            is_primary: false,
+            in_git_dir: false,
+        };
+        assert_snapshot!(json(&output), @r#"
+        {
+          "branch": null,
+          "path": "/repo",
+          "is_primary": false,
+          "in_git_dir": false
+        }
+        "#);
+    }
+
+    #[test]
+    fn test_schema_diff() {
+        let files = [FileDiff {
+            path: "src/lib.rs".into(),
+            added: 0,
+            deleted: 0,
+            binary: false,
+        }];
+        let output = DiffOutput {
+            branch: "feature",
+            target: "main",
+            merge_base: "abc123",
+            working: false,
+            files: &files,
+        };
+        assert_snapshot!(json(&output), @r#"
+        {
+          "branch": "feature",
+          "target": "main",
+          "merge_base": "abc123",
+          "working": false,
+          "files": [
+            {
+              "path": "src/lib.rs",
+              "added": 0,
+              "deleted": 0
+            }
+          ]
+        }
+        "#);
+    }
+
+    #[test]
+    fn test_schema_stats() {
+        assert_snapshot!(json(&Stats::default()), @r#"
+        {
+          "worktrees": 0,
+          "age": {
+            "under_day": 0,
+            "under_week": 0,
+            "under_month": 0,
+            "under_quarter": 0,
+            "older": 0
+          },
+          "uncommitted": {
+            "worktrees": 0,
+            "added": 0,
+            "deleted": 0
+          },
+          "behind": {
+            "worktrees": 0,
+            "commits": 0,
+            "max": 0
+          },
+          "states": {}
+        }
+        "#);
+    }
+
+    #[test]
+    fn test_schema_report() {
+        let output = Report {
+            since: None,
+            runs: 0,
+            commands: vec![CommandUsage::default()],
+        };
+        assert_snapshot!(json(&output), @r#"
+        {
+          "since": null,
+          "runs": 0,
+          "commands": [
+            {
+              "command": "",
+              "runs": 0,
+              "failed": 0,
+              "median_ms": 0,
+              "flags": {}
+            }
+          ]
+        }
+        "#);
+    }
+
+    #[test]
+    fn test_schema_error() {
+        let err = GitError::Other {
+            message: String::new(),
+        };
+        assert_snapshot!(json(&error_json(&err.into(), 1)), @r#"
+        {
+          "error": "Other",
+          "message": "",
+          "hints": [],
+          "exit_code": 1,
+          "details": {
+            "message": ""
+          }
+        }
+        "#);
+        let err = anyhow::anyhow!("failed");
+        assert_snapshot!(json(&error_json(&err, 1)), @r#"
+        {
+          "error": "Error",
+          "message": "failed",
+          "hints": [],
+          "exit_code": 1,
+          "details": {
+            "causes": []
+          }
+        }
+        "#);
+    }
+}
@@ -9,6 +9,7 @@ use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
+use worktrunk::config::state_file;
 use worktrunk::git::Repository;
 use worktrunk::path::sanitize_for_filename;
 
@@ -92,35 +93,13 @@ impl CachedCiStatus {
     pub(super) fn write(&self, repo: &Repository, branch: &str) {
         let path = Self::cache_file(repo, branch);
 
-        // Create cache directory if needed
-        if let Some(parent) = path.parent()
-            && let Err(e) = fs::create_dir_all(parent)
-        {
-            log::debug!("Failed to create cache dir for {}: {}", branch, e);
-            return;
-        }
-
         let Ok(json) = serde_json::to_string(self) else {
             log::debug!("Failed to serialize CI cache for {}", branch);
             return;
         };
 
-        // Write to temp file first, then rename for atomic update
-        let temp_path = path.with_extension("json.tmp");
-        if let Err(e) = fs::write(&temp_path, &json) {
-            log::debug!("Failed to write CI cache temp file for {}: {}", branch, e);
-            return;
-        }
-
-        // On Windows, fs::rename may fail if target exists (depending on Windows version
-        // and filesystem). Remove target first to ensure rename succeeds.
-        #[cfg(windows)]
-        let _ = fs::remove_file(&path);
-
-        if let Err(e) = fs::rename(&temp_path, &path) {
-            log::debug!("Failed to rename CI cache file for {}: {}", branch, e);
-            // Clean up temp file on failure
-            let _ = fs::remove_file(&temp_path);
+        if let Err(e) = state_file::write_atomic(&path, json) {
+            log::debug!("Failed to write CI cache for {}: {}", branch, e);
         }
     }
 
@@ -138,7 +117,7 @@ impl CachedCiStatus {
                 let entry = entry.ok()?;
                 let path = entry.path();
 
-                // Only process .json files (skip .json.tmp)
+                // Only process .json files (skip in-flight temp files)
                 if path.extension()?.to_str()? != "json" {
                     return None;
                 }
@@ -6,7 +6,7 @@ use serde::Deserialize;
 use worktrunk::git::{GitRemoteUrl, Repository, parse_remote_owner};
 
 use super::{
-    CiBranchName, CiSource, CiStatus, MAX_PRS_TO_FETCH, PrStatus, is_retriable_error,
+    CiBranchName, CiCheck, CiSource, CiStatus, MAX_PRS_TO_FETCH, PrStatus, is_retriable_error,
     non_interactive_cmd, parse_json,
 };
 
@@ -34,10 +34,13 @@ fn get_github_owner_repo(repo: &Repository) -> Option<(String, String)> {
 /// to filter by source repository.
 ///
 /// Since `gh pr list --head` doesn't support `owner:branch` format, we:
-/// 1. Fetch all open PRs with matching branch name (up to 20)
+/// 1. Fetch all PRs with matching branch name (up to 20)
 /// 2. Include `headRepositoryOwner` in the JSON output
 /// 3. Filter client-side by comparing `headRepositoryOwner.login` to the branch's push remote owner
 ///
+/// An open PR wins; otherwise a merged PR reports `merged`, the safe-to-delete
+/// signal that survives squash merges. Closed, unmerged PRs are ignored.
+///
 /// This correctly handles:
 /// - Fork workflows (PRs from your fork to upstream)
 /// - Organization repos (PRs from org branches)
@@ -88,11 +91,11 @@ pub(super) fn detect_github(
             "--head",
             &branch.name, // Use bare branch name, not "origin/feature"
             "--state",
-            "open",
+            "all",
             "--limit",
             &MAX_PRS_TO_FETCH.to_string(),
             "--json",
-            "headRefOid,mergeStateStatus,statusCheckRollup,url,headRepositoryOwner",
+            "headRefOid,mergeStateStatus,statusCheckRollup,url,headRepositoryOwner,state",
         ])
         .current_dir(&repo_root)
         .run()
@@ -122,12 +125,17 @@ pub(super) fn detect_github(
     // Filter to PRs from our origin (case-insensitive comparison for GitHub usernames).
     // If headRepositoryOwner is missing (older GH CLI, Enterprise, or permissions),
     // treat it as a potential match to avoid false negatives.
-    let pr_info = pr_list.iter().find(|pr| {
+    let ours = |pr: &&GitHubPrInfo| {
         pr.head_repository_owner
             .as_ref()
             .map(|h| h.login.eq_ignore_ascii_case(&branch_owner))
             .unwrap_or(true) // Missing owner field = potential match
-    });
+    };
+    let pr_info = pr_list
+        .iter()
+        .filter(ours)
+        .find(|pr| pr.is_open())
+        .or_else(|| pr_list.iter().filter(ours).find(|pr| pr.is_merged()));
     if pr_info.is_none() && !pr_list.is_empty() {
         log::debug!(
             "Found {} PRs for branch {} but none from owner {}",
@@ -139,7 +147,8 @@ pub(super) fn detect_github(
     let pr_info = pr_info?;
 
     // Determine CI status using priority: conflicts > running > failed > passed > no_ci
-    let ci_status = if pr_info.merge_state_status.as_deref() == Some("DIRTY") {
+    let merged = pr_info.is_merged();
+    let ci_status = if !merged && pr_info.merge_state_status.as_deref() == Some("DIRTY") {
         CiStatus::Conflicts
     } else {
         pr_info.ci_status()
@@ -156,6 +165,12 @@ pub(super) fn detect_github(
         source: CiSource::PullRequest,
         is_stale,
         url: pr_info.url.clone(),
+        merged,
+        checks: pr_info
+            .status_check_rollup
+            .as_deref()
+            .map(unfinished_github_checks)
+            .unwrap_or_default(),
     })
 }
 
@@ -174,7 +189,7 @@ pub(super) fn detect_github_commit_checks(repo: &Repository, local_head: &str) -
             "api",
             &format!("repos/{owner}/{repo_name}/commits/{local_head}/check-runs"),
             "--jq",
-            ".check_runs | map({status, conclusion})",
+            ".check_runs | map({name, status, conclusion})",
         ])
         .current_dir(&repo_root)
         .run()
@@ -212,6 +227,8 @@ pub(super) fn detect_github_commit_checks(repo: &Repository, local_head: &str) -
         source: CiSource::Branch,
         is_stale: false, // We're querying by SHA, so always current
         url: None,
+        merged: false,
+        checks: unfinished_github_checks(&checks),
     })
 }
 
@@ -220,7 +237,8 @@ pub(super) fn detect_github_commit_checks(repo: &Repository, local_head: &str) -
 /// Note: We include `headRepositoryOwner` for client-side filtering by source fork.
 /// See [`parse_remote_owner`] for why this is necessary.
 ///
-/// Note: We don't include `state` because we already filter with `--state open`.
+/// `state` is `OPEN`, `MERGED`, or `CLOSED`. When it's missing (older GH CLI),
+/// the PR is treated as open.
 #[derive(Debug, Deserialize)]
 pub(super) struct GitHubPrInfo {
     #[serde(rename = "headRefOid")]
@@ -234,6 +252,7 @@ pub(super) struct GitHubPrInfo {
     /// Used to filter PRs by source fork (see [`parse_remote_owner`]).
     #[serde(rename = "headRepositoryOwner")]
     pub head_repository_owner: Option<HeadRepositoryOwner>,
+    pub state: Option<String>,
 }
 
 /// Owner info for the head repository of a PR.
@@ -258,6 +277,10 @@ pub(super) struct HeadRepositoryOwner {
 /// here is simpler overall.
 #[derive(Debug, Deserialize)]
 pub(super) struct GitHubCheck {
+    /// CheckRun only: the check's name (e.g. "build-linux")
+    pub name: Option<String>,
+    /// StatusContext only: the status's name (e.g. "pre-commit.ci - pr")
+    pub context: Option<String>,
     /// CheckRun only: "COMPLETED", "IN_PROGRESS", "QUEUED", etc.
     pub status: Option<String>,
     /// CheckRun only: "SUCCESS", "FAILURE", "CANCELLED", "SKIPPED", etc.
@@ -274,69 +297,100 @@ impl GitHubPrInfo {
             Some(checks) => aggregate_github_checks(checks),
         }
     }
-}
 
-/// Aggregate CI status from multiple GitHub checks (case-insensitive).
-///
-/// Priority: running > failed > passed > no-ci.
-/// Handles both `statusCheckRollup` (uppercase) and check-runs API (lowercase).
-/// Skipped/neutral checks don't contribute to pass/fail.
-pub(super) fn aggregate_github_checks(checks: &[GitHubCheck]) -> CiStatus {
-    let mut has_running = false;
-    let mut has_failure = false;
-    let mut has_success = false;
+    fn is_open(&self) -> bool {
+        self.state.as_deref().is_none_or(|s| s == "OPEN")
+    }
+
+    fn is_merged(&self) -> bool {
+        self.state.as_deref() == Some("MERGED")
+    }
+}
 
-    for check in checks {
+impl GitHubCheck {
+    /// This check's own status (case-insensitive), or `None` when it doesn't
+    /// count toward pass/fail (skipped, neutral).
+    ///
+    /// Handles both `statusCheckRollup` (uppercase) and check-runs API (lowercase).
+    fn ci_status(&self) -> Option<CiStatus> {
         // CheckRun: status field indicates in-progress states
-        if let Some(status) = &check.status {
+        if let Some(status) = &self.status {
             let s = status.to_ascii_lowercase();
             if matches!(
                 s.as_str(),
                 "in_progress" | "queued" | "pending" | "expected"
             ) {
-                has_running = true;
+                return Some(CiStatus::Running);
             }
         }
 
         // StatusContext: state field indicates pending
-        if let Some(state) = &check.state {
+        if let Some(state) = &self.state {
             let s = state.to_ascii_lowercase();
             if s == "pending" {
-                has_running = true;
+                return Some(CiStatus::Running);
             } else if matches!(s.as_str(), "failure" | "error") {
-                has_failure = true;
+                return Some(CiStatus::Failed);
             } else if s == "success" {
-                has_success = true;
+                return Some(CiStatus::Passed);
             }
         }
 
         // CheckRun: conclusion field indicates final result
-        if let Some(conclusion) = &check.conclusion {
-            let c = conclusion.to_ascii_lowercase();
-            match c.as_str() {
-                "failure" | "error" | "cancelled" | "timed_out" | "action_required" => {
-                    has_failure = true;
-                }
-                "success" => {
-                    has_success = true;
-                }
-                // "skipped", "neutral" - ignored
-                _ => {}
+        match self
+            .conclusion
+            .as_deref()
+            .map(str::to_ascii_lowercase)?
+            .as_str()
+        {
+            "failure" | "error" | "cancelled" | "timed_out" | "action_required" => {
+                Some(CiStatus::Failed)
             }
+            "success" => Some(CiStatus::Passed),
+            // "skipped", "neutral" - ignored
+            _ => None,
         }
     }
 
-    if has_running {
-        CiStatus::Running
-    } else if has_failure {
-        CiStatus::Failed
-    } else if has_success {
-        CiStatus::Passed
-    } else {
-        CiStatus::NoCI
+    /// CheckRun `name`, or StatusContext `context`
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref().or(self.context.as_deref())
     }
 }
 
+/// Aggregate CI status from multiple GitHub checks.
+///
+/// Priority: running > failed > passed > no-ci.
+/// Skipped/neutral checks don't contribute to pass/fail.
+pub(super) fn aggregate_github_checks(checks: &[GitHubCheck]) -> CiStatus {
+    let statuses: Vec<CiStatus> = checks.iter().filter_map(GitHubCheck::ci_status).collect();
+    [CiStatus::Running, CiStatus::Failed, CiStatus::Passed]
+        .into_iter()
+        .find(|s| statuses.contains(s))
+        .unwrap_or(CiStatus::NoCI)
+}
+
+/// Named checks that failed or are still running, failures first.
+///
+/// Comes from the same response as [`aggregate_github_checks`], so
+/// `--ci-details` costs no extra requests.
+pub(super) fn unfinished_github_checks(checks: &[GitHubCheck]) -> Vec<CiCheck> {
+    let mut unfinished: Vec<CiCheck> = checks
+        .iter()
+        .filter_map(|check| {
+            let status = check.ci_status()?;
+            let name = check.name()?;
+            matches!(status, CiStatus::Failed | CiStatus::Running).then(|| CiCheck {
+                name: name.to_string(),
+                status,
+            })
+        })
+        .collect();
+    // Stable sort keeps the platform's order within each group
+    unfinished.sort_by_key(|check| check.status != CiStatus::Failed);
+    unfinished
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -350,6 +404,7 @@ mod tests {
             status_check_rollup: None,
             url: None,
             head_repository_owner: None,
+            state: None,
         };
         assert_eq!(pr.ci_status(), CiStatus::NoCI);
 
@@ -360,6 +415,7 @@ mod tests {
             status_check_rollup: Some(vec![]),
             url: None,
             head_repository_owner: None,
+            state: None,
         };
         assert_eq!(pr.ci_status(), CiStatus::NoCI);
 
@@ -369,12 +425,15 @@ mod tests {
                 head_ref_oid: None,
                 merge_state_status: None,
                 status_check_rollup: Some(vec![GitHubCheck {
+                    name: None,
+                    context: None,
                     status: Some(status.into()),
                     conclusion: None,
                     state: None,
                 }]),
                 url: None,
                 head_repository_owner: None,
+                state: None,
             };
             assert_eq!(pr.ci_status(), CiStatus::Running, "status={status}");
         }
@@ -384,12 +443,15 @@ mod tests {
             head_ref_oid: None,
             merge_state_status: None,
             status_check_rollup: Some(vec![GitHubCheck {
+                name: None,
+                context: None,
                 status: None,
                 conclusion: None,
                 state: Some("PENDING".into()),
             }]),
             url: None,
             head_repository_owner: None,
+            state: None,
         };
         assert_eq!(pr.ci_status(), CiStatus::Running);
 
@@ -399,12 +461,15 @@ mod tests {
                 head_ref_oid: None,
                 merge_state_status: None,
                 status_check_rollup: Some(vec![GitHubCheck {
+                    name: None,
+                    context: None,
                     status: Some("COMPLETED".into()),
                     conclusion: Some(conclusion.into()),
                     state: None,
                 }]),
                 url: None,
                 head_repository_owner: None,
+                state: None,
             };
             assert_eq!(pr.ci_status(), CiStatus::Failed, "conclusion={conclusion}");
         }
@@ -415,12 +480,15 @@ mod tests {
                 head_ref_oid: None,
                 merge_state_status: None,
                 status_check_rollup: Some(vec![GitHubCheck {
+                    name: None,
+                    context: None,
                     status: None,
                     conclusion: None,
                     state: Some(state.into()),
                 }]),
                 url: None,
                 head_repository_owner: None,
+                state: None,
             };
             assert_eq!(pr.ci_status(), CiStatus::Failed, "state={state}");
         }
@@ -430,12 +498,15 @@ mod tests {
             head_ref_oid: None,
             merge_state_status: None,
             status_check_rollup: Some(vec![GitHubCheck {
+                name: None,
+                context: None,
                 status: Some("COMPLETED".into()),
                 conclusion: Some("SUCCESS".into()),
                 state: None,
             }]),
             url: None,
             head_repository_owner: None,
+            state: None,
         };
         assert_eq!(pr.ci_status(), CiStatus::Passed);
     }
@@ -445,6 +516,8 @@ mod tests {
         // Helper to create a check without state field (like check-runs API)
         fn check(status: &str, conclusion: Option<&str>) -> GitHubCheck {
             GitHubCheck {
+                name: None,
+                context: None,
                 status: Some(status.into()),
                 conclusion: conclusion.map(|c| c.into()),
                 state: None,
@@ -511,6 +584,8 @@ mod tests {
 
         // StatusContext via state field (used by external CI like pre-commit.ci)
         let checks = vec![GitHubCheck {
+            name: None,
+            context: None,
             status: None,
             conclusion: None,
             state: Some("PENDING".into()),
@@ -518,10 +593,55 @@ mod tests {
         assert_eq!(aggregate_github_checks(&checks), CiStatus::Running);
 
         let checks = vec![GitHubCheck {
+            name: None,
+            context: None,
             status: None,
             conclusion: None,
             state: Some("failure".into()),
         }];
         assert_eq!(aggregate_github_checks(&checks), CiStatus::Failed);
     }
+
+    #[test]
+    fn test_unfinished_github_checks() {
+        fn run(name: &str, status: &str, conclusion: Option<&str>) -> GitHubCheck {
+            GitHubCheck {
+                name: Some(name.into()),
+                context: None,
+                status: Some(status.into()),
+                conclusion: conclusion.map(|c| c.into()),
+                state: None,
+            }
+        }
+
+        let checks = vec![
+            run("build-linux", "completed", Some("success")),
+            run("e2e", "in_progress", None),
+            run("lint", "completed", Some("failure")),
+            run("docs", "completed", Some("skipped")),
+            GitHubCheck {
+                name: None,
+                context: Some("pre-commit.ci - pr".into()),
+                status: None,
+                conclusion: None,
+                state: Some("FAILURE".into()),
+            },
+        ];
+
+        // Failures first, platform order within each group; passed and
+        // skipped checks are left out
+        let unfinished = unfinished_github_checks(&checks);
+        let names: Vec<(&str, CiStatus)> = unfinished
+            .iter()
+            .map(|c| (c.name.as_str(), c.status))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("lint", CiStatus::Failed),
+                ("pre-commit.ci - pr", CiStatus::Failed),
+                ("e2e", CiStatus::Running),
+            ]
+        );
+    }
 }
@@ -187,6 +187,8 @@ pub(super) fn detect_gitlab(
         source: CiSource::PullRequest,
         is_stale,
         url: mr_entry.web_url.clone(),
+        merged: false,
+        checks: Vec::new(),
     })
 }
 
@@ -244,6 +246,8 @@ pub(super) fn detect_gitlab_pipeline(branch: &str, local_head: &str) -> Option<P
         source: CiSource::Branch,
         is_stale,
         url: pipeline.web_url.clone(),
+        merged: false,
+        checks: Vec::new(),
     })
 }
 
@@ -124,6 +124,7 @@ const MAX_PRS_TO_FETCH: u8 = 20;
 /// - Opening browsers for authentication
 fn non_interactive_cmd(program: &str) -> Cmd {
     Cmd::new(program)
+        .network()
         .env_remove("CLICOLOR_FORCE")
         .env_remove("GH_FORCE_TTY")
         .env("NO_COLOR", "1")
@@ -136,11 +137,14 @@ fn non_interactive_cmd(program: &str) -> Cmd {
 /// On Windows, CreateProcessW (via Cmd) searches PATH for .exe files.
 /// We provide .exe mocks in tests via mock-stub, so this works consistently.
 fn tool_available(tool: &str, args: &[&str]) -> bool {
-    Cmd::new(tool)
-        .args(args.iter().copied())
-        .run()
-        .map(|o| o.status.success())
-        .unwrap_or(false)
+    let cmd = Cmd::new(tool).args(args.iter().copied());
+    // `auth status` asks the forge whether the token is still valid
+    let cmd = if args.first() == Some(&"auth") {
+        cmd.network()
+    } else {
+        cmd
+    };
+    cmd.run().map(|o| o.status.success()).unwrap_or(false)
 }
 
 /// Parse JSON output from CLI tools
@@ -212,7 +216,9 @@ impl CiToolsStatus {
 /// - Conflicts: Yellow (merge conflicts)
 /// - NoCI: Gray (no PR/checks)
 /// - Error: Yellow (CI fetch failed, e.g., rate limit)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::IntoStaticStr)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::IntoStaticStr, strum::EnumIter,
+)]
 #[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub enum CiStatus {
@@ -252,6 +258,24 @@ pub struct PrStatus {
     /// URL to the PR/MR (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// True if the PR was merged (GitHub only). Survives squash merges, which
+    /// leave no ancestry for git to find.
+    #[serde(default)]
+    pub merged: bool,
+    /// Failing and pending checks behind `ci_status`, failures first
+    /// (`--ci-details`). GitHub only: GitLab's MR and pipeline responses
+    /// don't name their jobs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<CiCheck>,
+}
+
+/// A single named check that hasn't passed, from the same response as the
+/// aggregate status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CiCheck {
+    pub name: String,
+    /// `Failed` or `Running`
+    pub status: CiStatus,
 }
 
 impl CiStatus {
@@ -276,17 +300,27 @@ impl CiStatus {
 
 impl PrStatus {
     /// Get the style for this PR status (color + optional dimming for stale)
+    ///
+    /// Merged PRs are magenta regardless of their final checks.
     pub fn style(&self) -> Style {
-        let style = Style::new().fg_color(Some(Color::Ansi(self.ci_status.color())));
+        let color = if self.merged {
+            AnsiColor::Magenta
+        } else {
+            self.ci_status.color()
+        };
+        let style = Style::new().fg_color(Some(Color::Ansi(color)));
         if self.is_stale { style.dimmed() } else { style }
     }
 
     /// Get the indicator symbol for this status
     ///
+    /// - Merged: ✔ (PR merged, safe to remove)
     /// - Error: ⚠ (warning indicator)
     /// - All others: ● (filled circle)
     pub fn indicator(&self) -> &'static str {
-        if matches!(self.ci_status, CiStatus::Error) {
+        if self.merged {
+            "✔"
+        } else if matches!(self.ci_status, CiStatus::Error) {
             "⚠"
         } else {
             "●"
@@ -322,6 +356,8 @@ impl PrStatus {
             source: CiSource::Branch,
             is_stale: false,
             url: None,
+            merged: false,
+            checks: Vec::new(),
         }
     }
 
@@ -335,6 +371,10 @@ impl PrStatus {
     /// based on repo path to spread cache expirations across concurrent statuslines. Invalidated
     /// when HEAD changes.
     ///
+    /// # Offline
+    /// Under `--offline`, returns the last cached status of any age, marked
+    /// stale, and never fetches.
+    ///
     /// # Fork Support
     /// Runs gh commands from the repository directory to enable auto-detection of
     /// upstream repositories for forks. This ensures PRs opened against upstream
@@ -351,7 +391,8 @@ impl PrStatus {
         // Use full_name as cache key to distinguish local "feature" from remote "origin/feature"
         let now_secs = get_now();
 
-        if let Some(cached) = CachedCiStatus::read(repo, &branch.full_name) {
+        let cached = CachedCiStatus::read(repo, &branch.full_name);
+        if let Some(cached) = &cached {
             if cached.is_valid(local_head, now_secs, &repo_path) {
                 log::debug!(
                     "Using cached CI status for {} (age={}s, ttl={}s, status={:?})",
@@ -360,7 +401,7 @@ impl PrStatus {
                     CachedCiStatus::ttl_for_repo(&repo_path),
                     cached.status.as_ref().map(|s| &s.ci_status)
                 );
-                return cached.status;
+                return cached.status.clone();
             }
             log::debug!(
                 "Cache expired for {} (age={}s, ttl={}s, head_match={})",
@@ -371,6 +412,15 @@ impl PrStatus {
             );
         }
 
+        // Offline: the last cached status, if any, marked stale since it can't
+        // be refreshed
+        if worktrunk::utils::is_offline() {
+            return cached.and_then(|cached| cached.status).map(|status| Self {
+                is_stale: true,
+                ..status
+            });
+        }
+
         // Cache miss or expired - fetch fresh status
         let status = Self::detect_uncached(repo, branch, local_head, has_upstream);
 
@@ -392,6 +442,9 @@ impl PrStatus {
     /// Returns `None` if the platform cannot be determined (user should set
     /// `ci.platform` in project config for non-standard hostnames).
     /// PR/MR detection always runs. Workflow/pipeline fallback only runs if `has_upstream`.
+    ///
+    /// The default branch has no PR of its own, so it skips straight to the
+    /// checks on its head commit, unless `ci.include-default-branch = false`.
     fn detect_uncached(
         repo: &Repository,
         branch: &CiBranchName,
@@ -402,11 +455,22 @@ impl PrStatus {
         let project_config = repo.load_project_config().ok().flatten();
         let platform_override = project_config.as_ref().and_then(|c| c.ci_platform());
 
+        let is_default_branch =
+            !branch.is_remote() && repo.default_branch().as_deref() == Some(branch.name.as_str());
+        if is_default_branch
+            && !project_config
+                .as_ref()
+                .is_none_or(|c| c.ci_include_default_branch())
+        {
+            return None;
+        }
+
         // Determine platform (config override, branch's remote, or any remote URL)
         // For remote branches, use their specific remote to get the correct platform
         let platform = get_platform_for_repo(repo, platform_override, branch.remote.as_deref());
 
         match platform {
+            Some(p) if is_default_branch => p.detect_default_branch(repo, branch, local_head),
             Some(p) => p.detect_ci(repo, branch, local_head, has_upstream),
             None => {
                 // Unknown platform - user should set ci.platform in project config
@@ -467,6 +531,8 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            merged: false,
+            checks: Vec::new(),
         };
         assert_eq!(pr_passed.indicator(), "●");
 
@@ -475,6 +541,8 @@ mod tests {
             source: CiSource::Branch,
             is_stale: false,
             url: None,
+            merged: false,
+            checks: Vec::new(),
         };
         assert_eq!(branch_running.indicator(), "●");
 
@@ -483,6 +551,8 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            merged: false,
+            checks: Vec::new(),
         };
         assert_eq!(error_status.indicator(), "⚠");
     }
@@ -496,12 +566,16 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: Some("https://github.com/owner/repo/pull/123".to_string()),
+            merged: false,
+            checks: Vec::new(),
         };
         let no_url = PrStatus {
             ci_status: CiStatus::Passed,
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            merged: false,
+            checks: Vec::new(),
         };
 
         // With URL + include_link=true → has OSC 8 hyperlink
@@ -512,6 +586,23 @@ mod tests {
         assert_snapshot!(no_url.format_indicator(true), @"[32m●[0m");
     }
 
+    #[test]
+    fn test_merged_pr_indicator() {
+        use insta::assert_snapshot;
+
+        // Merged wins over the PR's final check state
+        let merged = PrStatus {
+            ci_status: CiStatus::Failed,
+            source: CiSource::PullRequest,
+            is_stale: false,
+            url: None,
+            merged: true,
+            checks: Vec::new(),
+        };
+        assert_eq!(merged.indicator(), "✔");
+        assert_snapshot!(merged.format_indicator(false), @"[35m✔[0m");
+    }
+
     #[test]
     fn test_pr_status_error_constructor() {
         let error = PrStatus::error();
@@ -529,6 +620,8 @@ mod tests {
             source: CiSource::Branch,
             is_stale: true,
             url: None,
+            merged: false,
+            checks: Vec::new(),
         };
         let style = stale.style();
         // Just verify it doesn't panic and returns a style
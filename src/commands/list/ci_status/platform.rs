@@ -98,6 +98,22 @@ impl CiPlatform {
         }
         None
     }
+
+    /// Detect CI status for the default branch from its head commit's checks.
+    ///
+    /// Skips the PR/MR lookup: a PR whose head is the default branch (say, from
+    /// a fork's `main`) says nothing about the branch's own health.
+    pub(super) fn detect_default_branch(
+        self,
+        repo: &Repository,
+        branch: &CiBranchName,
+        local_head: &str,
+    ) -> Option<PrStatus> {
+        if !self.is_tool_available() {
+            return None;
+        }
+        self.detect_branch(repo, branch, local_head)
+    }
 }
 
 /// Detect the CI platform from a remote URL.
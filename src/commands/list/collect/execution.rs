@@ -7,18 +7,20 @@
 //! - `ExpectedResults` - track expected results for timeout diagnostics
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use crossbeam_channel as chan;
 use worktrunk::git::{BranchRef, Repository, WorktreeInfo};
 
 use super::CollectOptions;
 use super::tasks::{
-    AheadBehindTask, BranchDiffTask, CiStatusTask, CommitDetailsTask, CommittedTreesMatchTask,
-    GitOperationTask, HasFileChangesTask, IsAncestorTask, MergeTreeConflictsTask,
-    SummaryGenerateTask, Task, TaskContext, UpstreamTask, UrlStatusTask, UserMarkerTask,
-    WorkingTreeConflictsTask, WorkingTreeDiffTask, WouldMergeAddTask,
+    ActivityTask, AheadBehindTask, BranchDiffTask, CiStatusTask, CommitDetailsTask,
+    CommittedTreesMatchTask, GitOperationTask, HasFileChangesTask, IsAncestorTask,
+    MergeTreeConflictsTask, SquashMergedTask, SummaryGenerateTask, Task, TaskContext,
+    UnmergedCommitsTask, UpstreamTask, UrlStatusTask, UserMarkerTask, WorkingTreeConflictsTask,
+    WorkingTreeDiffTask, WouldMergeAddTask,
 };
-use super::types::{TaskError, TaskKind, TaskResult};
+use super::types::{ErrorCause, TaskError, TaskKind, TaskResult};
 
 // Tasks that are expensive because they require merge-base computation or merge simulation.
 // These are skipped for branches that are far behind the default branch (in `wt switch` interactive picker).
@@ -30,6 +32,8 @@ const EXPENSIVE_TASKS: &[TaskKind] = &[
     TaskKind::WouldMergeAdd,      // git merge-tree simulation
     TaskKind::BranchDiff,         // git diff with three-dot range
     TaskKind::MergeTreeConflicts, // git merge-tree simulation
+    TaskKind::SquashMerged,       // git cherry + patch-id over target's new commits
+    TaskKind::UnmergedCommits,    // git cherry
 ];
 
 /// Tasks that require a valid commit SHA. Skipped for unborn branches (no commits yet).
@@ -43,6 +47,8 @@ const COMMIT_TASKS: &[TaskKind] = &[
     TaskKind::BranchDiff,
     TaskKind::MergeTreeConflicts,
     TaskKind::WouldMergeAdd,
+    TaskKind::SquashMerged,
+    TaskKind::UnmergedCommits,
     TaskKind::CiStatus,
     TaskKind::Upstream,
 ];
@@ -64,8 +70,29 @@ pub struct WorkItem {
 
 impl WorkItem {
     /// Execute this work item, returning the task result.
-    pub fn execute(self) -> Result<TaskResult, TaskError> {
-        let result = dispatch_task(self.kind, self.ctx);
+    ///
+    /// With a `deadline` (`wt list --timeout`), a task whose turn comes after
+    /// it doesn't run, and a task whose commands it cut short reports
+    /// [`ErrorCause::Deadline`] rather than an ordinary timeout.
+    pub fn execute(self, deadline: Option<Instant>) -> Result<TaskResult, TaskError> {
+        let passed = || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+        if passed() {
+            return Err(TaskError::new(
+                self.ctx.item_idx,
+                self.kind,
+                "deadline passed",
+                ErrorCause::Deadline,
+            ));
+        }
+        let result = match dispatch_task(self.kind, self.ctx) {
+            Err(error) if error.is_timeout() && passed() => Err(TaskError::new(
+                error.item_idx,
+                error.kind,
+                error.message,
+                ErrorCause::Deadline,
+            )),
+            result => result,
+        };
         if let Ok(ref task_result) = result {
             debug_assert_eq!(TaskKind::from(task_result), self.kind);
         }
@@ -82,7 +109,9 @@ fn dispatch_task(kind: TaskKind, ctx: TaskContext) -> Result<TaskResult, TaskErr
         TaskKind::HasFileChanges => HasFileChangesTask::compute(ctx),
         TaskKind::WouldMergeAdd => WouldMergeAddTask::compute(ctx),
         TaskKind::IsAncestor => IsAncestorTask::compute(ctx),
+        TaskKind::SquashMerged => SquashMergedTask::compute(ctx),
         TaskKind::BranchDiff => BranchDiffTask::compute(ctx),
+        TaskKind::UnmergedCommits => UnmergedCommitsTask::compute(ctx),
         TaskKind::WorkingTreeDiff => WorkingTreeDiffTask::compute(ctx),
         TaskKind::MergeTreeConflicts => MergeTreeConflictsTask::compute(ctx),
         TaskKind::WorkingTreeConflicts => WorkingTreeConflictsTask::compute(ctx),
@@ -91,6 +120,7 @@ fn dispatch_task(kind: TaskKind, ctx: TaskContext) -> Result<TaskResult, TaskErr
         TaskKind::Upstream => UpstreamTask::compute(ctx),
         TaskKind::CiStatus => CiStatusTask::compute(ctx),
         TaskKind::UrlStatus => UrlStatusTask::compute(ctx),
+        TaskKind::Activity => ActivityTask::compute(ctx),
         TaskKind::SummaryGenerate => SummaryGenerateTask::compute(ctx),
     }
 }
@@ -106,9 +136,27 @@ fn dispatch_task(kind: TaskKind, ctx: TaskContext) -> Result<TaskResult, TaskErr
 #[derive(Default)]
 pub(crate) struct ExpectedResults {
     inner: std::sync::Mutex<Vec<Vec<TaskKind>>>,
+    /// Set once every work item has been generated, so no more expectations
+    /// will be added
+    registered: (std::sync::Mutex<bool>, std::sync::Condvar),
 }
 
 impl ExpectedResults {
+    /// Record that work item generation is done.
+    pub fn finish_registration(&self) {
+        let (done, cvar) = &self.registered;
+        *done.lock().unwrap() = true;
+        cvar.notify_all();
+    }
+
+    /// Wait up to `timeout` for [`finish_registration`](Self::finish_registration),
+    /// so the expectations are final.
+    pub fn wait_for_registration(&self, timeout: std::time::Duration) {
+        let (done, cvar) = &self.registered;
+        let guard = done.lock().unwrap();
+        let _ = cvar.wait_timeout_while(guard, timeout, |done| !*done);
+    }
+
     /// Record that we expect a result of the given kind for the given item.
     /// Called internally by `TaskSpawner::spawn()`.
     pub fn expect(&self, item_idx: usize, kind: TaskKind) {
@@ -181,23 +229,22 @@ pub fn work_items_for_worktree(
         None
     };
 
-    // Send URL immediately (before health check) so it appears right away.
-    // The UrlStatusTask will later update with active status.
-    if include_url && let Some(ref url) = item_url {
-        expected_results.expect(item_idx, TaskKind::UrlStatus);
-        let _ = tx.send(Ok(TaskResult::UrlStatus {
-            item_idx,
-            url: Some(url.clone()),
-            active: None,
-        }));
-    }
-
     let ctx = TaskContext {
         repo: repo.clone(),
         branch_ref: BranchRef::from(wt),
         item_idx,
         item_url,
         llm_command: options.llm_command.clone(),
+        include_skipped: options.include_skipped,
+        branch_diff_base: options.branch_diff_base,
+        compare_ref: options.compare_ref.clone(),
+        parent: wt
+            .branch
+            .as_deref()
+            .and_then(|b| options.branch_parents.get(b))
+            .cloned(),
+        skip_line_diffs: options.skip_line_diffs,
+        squash_patch_ids: options.squash_patch_ids,
     };
 
     // Check if this branch is stale and should skip expensive tasks.
@@ -207,6 +254,9 @@ pub fn work_items_for_worktree(
         .is_some_and(|b| options.stale_branches.contains(b));
 
     let has_commits = wt.has_commits();
+    // `git status` compares against HEAD, which no longer resolves once the
+    // branch is deleted, so it reports every tracked file as added
+    let branch_deleted = !has_commits && repo.deleted_branch_commit(wt).is_some();
 
     let mut items = Vec::with_capacity(15);
 
@@ -229,11 +279,14 @@ pub fn work_items_for_worktree(
         TaskKind::WorkingTreeDiff,
         TaskKind::GitOperation,
         TaskKind::UserMarker,
+        TaskKind::Activity,
         TaskKind::WorkingTreeConflicts,
         TaskKind::BranchDiff,
+        TaskKind::UnmergedCommits,
         TaskKind::MergeTreeConflicts,
         TaskKind::CiStatus,
         TaskKind::WouldMergeAdd,
+        TaskKind::SquashMerged,
         TaskKind::SummaryGenerate,
     ] {
         if skip.contains(&kind) {
@@ -247,22 +300,32 @@ pub fn work_items_for_worktree(
         if !has_commits && COMMIT_TASKS.contains(&kind) {
             continue;
         }
+        if branch_deleted && kind == TaskKind::WorkingTreeDiff {
+            continue;
+        }
         // Skip SummaryGenerate when no LLM command is configured
         if kind == TaskKind::SummaryGenerate && options.llm_command.is_none() {
             continue;
         }
         add_item(kind);
     }
-    // URL status health check task (if we have a URL).
-    // Note: We already registered and sent an immediate UrlStatus above with url + active=None.
-    // This work item will send a second UrlStatus with active=Some(bool) after health check.
-    // Both results must be registered and expected.
-    if include_url && ctx.item_url.is_some() {
+    // URL status health check task (if we have a URL), plus an immediate
+    // UrlStatus with url + active=None. Both results must be registered.
+    if let Some(url) = &ctx.item_url {
         expected_results.expect(item_idx, TaskKind::UrlStatus);
         items.push(WorkItem {
             ctx: ctx.clone(),
             kind: TaskKind::UrlStatus,
         });
+
+        // Send URL now (before the health check) so it appears right away. Sent
+        // last, so the row's expected results are final by the time any arrive.
+        expected_results.expect(item_idx, TaskKind::UrlStatus);
+        let _ = tx.send(Ok(TaskResult::UrlStatus {
+            item_idx,
+            url: Some(url.clone()),
+            active: None,
+        }));
     }
 
     items
@@ -301,6 +364,16 @@ pub fn work_items_for_branch(
         item_idx,
         item_url: None, // Branches without worktrees don't have URLs
         llm_command: options.llm_command.clone(),
+        include_skipped: options.include_skipped,
+        branch_diff_base: options.branch_diff_base,
+        compare_ref: options.compare_ref.clone(),
+        parent: if is_remote {
+            None
+        } else {
+            options.branch_parents.get(branch_name).cloned()
+        },
+        skip_line_diffs: options.skip_line_diffs,
+        squash_patch_ids: options.squash_patch_ids,
     };
 
     // Check if this branch is stale and should skip expensive tasks.
@@ -325,9 +398,11 @@ pub fn work_items_for_branch(
         TaskKind::IsAncestor,
         TaskKind::Upstream,
         TaskKind::BranchDiff,
+        TaskKind::UnmergedCommits,
         TaskKind::MergeTreeConflicts,
         TaskKind::CiStatus,
         TaskKind::WouldMergeAdd,
+        TaskKind::SquashMerged,
         TaskKind::SummaryGenerate,
     ] {
         if skip.contains(&kind) {
@@ -367,6 +442,7 @@ mod tests {
             path: dir.path().to_path_buf(),
             head: "deadbeef".to_string(),
             branch: Some("main".to_string()),
+            branch_raw: None,
             bare: false,
             detached: false,
             locked: None,
@@ -379,6 +455,12 @@ mod tests {
             url_template: Some("http://localhost/{{ branch }}".to_string()),
             llm_command: None,
             stale_branches: HashSet::new(),
+            include_skipped: false,
+            branch_diff_base: Default::default(),
+            compare_ref: None,
+            branch_parents: Default::default(),
+            skip_line_diffs: false,
+            squash_patch_ids: false,
         };
 
         let expected_results = Arc::new(ExpectedResults::default());
@@ -414,6 +496,7 @@ mod tests {
             path: dir.path().to_path_buf(),
             head: "deadbeef".to_string(),
             branch: Some("main".to_string()),
+            branch_raw: None,
             bare: false,
             detached: false,
             locked: None,
@@ -99,8 +99,9 @@ mod tasks;
 mod types;
 
 use anyhow::Context;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Instant;
 
 use anstyle::Style;
 use color_print::cformat;
@@ -108,14 +109,17 @@ use crossbeam_channel as chan;
 use dunce::canonicalize;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
-use worktrunk::git::{Repository, WorktreeInfo};
+use worktrunk::config::{ListConfig, ListDensity};
+use worktrunk::git::{GitError, IncompleteHistory, Repository, WorktreeInfo};
 use worktrunk::styling::{
     INFO_SYMBOL, eprintln, format_with_gutter, hint_message, warning_message,
 };
 
+use crate::cli::BranchDiffBase;
 use crate::commands::is_worktree_at_expected_path;
 
 use super::model::{DisplayFields, ItemKind, ListItem, WorktreeData};
+use super::progressive_table::Overflow;
 
 // Re-exports for sibling modules (columns.rs, render.rs, layout.rs)
 pub(crate) use tasks::parse_port_from_url;
@@ -190,6 +194,25 @@ pub struct CollectOptions {
     /// TODO: Consider adding a visible indicator in Status column when integration
     /// checks are skipped, so users know the `⊂` symbol may be incomplete.
     pub stale_branches: std::collections::HashSet<String>,
+
+    /// Count edits to skip-worktree/assume-unchanged files as dirty (`--include-skipped`).
+    pub include_skipped: bool,
+
+    /// What branch line diffs are taken against (`--branch-diff-base`).
+    pub branch_diff_base: BranchDiffBase,
+
+    /// Ref to compare against instead of the default branch (`--against`).
+    pub compare_ref: Option<String>,
+
+    /// Recorded stack parent of each branch that has one (`wt parent`).
+    pub branch_parents: HashMap<String, String>,
+
+    /// Leave line counts uncomputed on a shallow or partial clone.
+    pub skip_line_diffs: bool,
+
+    /// Match squash merges by cumulative patch-id, reading target's history
+    /// since each branch forked (`--full`). Otherwise only `git cherry` runs.
+    pub squash_patch_ids: bool,
 }
 
 fn worktree_branch_set(worktrees: &[WorktreeInfo]) -> HashSet<&str> {
@@ -199,6 +222,36 @@ fn worktree_branch_set(worktrees: &[WorktreeInfo]) -> HashSet<&str> {
         .collect()
 }
 
+/// Which worktrees [`collect`] surveys.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WorktreeScope {
+    /// Every worktree
+    #[default]
+    All,
+    /// Only the worktree containing the current directory (`--current`).
+    /// Branches are skipped and the row gets full detail.
+    Current,
+}
+
+/// Time bounds on the rows [`collect`] keeps (`--since`/`--until`), as
+/// inclusive Unix timestamps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimeWindow {
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+}
+
+impl TimeWindow {
+    fn is_unbounded(&self) -> bool {
+        self.since.is_none() && self.until.is_none()
+    }
+
+    fn contains(&self, timestamp: i64) -> bool {
+        self.since.is_none_or(|since| timestamp >= since)
+            && self.until.is_none_or(|until| timestamp <= until)
+    }
+}
+
 /// Controls how show flags (branches/remotes/full) are determined in [`collect`].
 #[cfg_attr(not(unix), allow(dead_code))]
 pub enum ShowConfig {
@@ -215,6 +268,34 @@ pub enum ShowConfig {
         cli_branches: bool,
         cli_remotes: bool,
         cli_full: bool,
+        include_skipped: bool,
+        branch_diff_base: BranchDiffBase,
+        /// Compute line diffs even on shallow and partial clones (`--full-diffs`)
+        full_diffs: bool,
+        /// Add totals (e.g. branches behind) to the footer (`--summary`)
+        summary: bool,
+        /// Ref to compare against instead of the default branch (`--against`)
+        against: Option<String>,
+        /// Which worktrees to survey
+        scope: WorktreeScope,
+        /// Keep only rows touched within this window (`--since`/`--until`)
+        time_window: TimeWindow,
+        /// List branches hidden by `wt hide`, marked (`--hidden`)
+        show_hidden: bool,
+        /// Print each row, tab-separated, once its data is complete (`--no-align`)
+        stream: bool,
+        /// Fetch CI and name failing and pending checks under their rows
+        /// (`--ci-details`)
+        ci_details: bool,
+        /// Build the rendered table into `ListData::captured` instead of
+        /// printing it (`--output`)
+        capture: bool,
+        /// Stop gathering at this point and mark what's missing (`--timeout`)
+        deadline: Option<Instant>,
+        /// What the live table does with more rows than fit the terminal
+        overflow: Overflow,
+        /// Space between columns, overriding `list.density` (`--density`)
+        density: Option<ListDensity>,
     },
 }
 
@@ -250,6 +331,10 @@ pub fn collect(
             show_remotes,
             ..
         } => (*show_branches, *show_remotes),
+        ShowConfig::DeferredToParallel {
+            scope: WorktreeScope::Current,
+            ..
+        } => (false, false),
         ShowConfig::DeferredToParallel { cli_remotes, .. } => {
             // Always fetch local branches: ~7ms hidden by parallelism, needed if
             // config says branches=true (which we won't know until after this phase).
@@ -292,6 +377,10 @@ pub fn collect(
             // Populate is_bare cache (value used later via repo_path)
             let _ = repo.is_bare();
         });
+        s.spawn(|_| {
+            // Populate shallow/partial clone cache (decides whether to skip line diffs)
+            let _ = repo.incomplete_history();
+        });
         s.spawn(|_| {
             let _ = url_template_cell.set(repo.url_template());
         });
@@ -325,27 +414,85 @@ pub fn collect(
     let url_template = url_template_cell.into_inner().unwrap();
 
     // Resolve show flags: merge CLI overrides with config (warmed in parallel phase)
-    let (show_branches, show_remotes, skip_tasks, command_timeout) = match show_config {
+    let (
+        show_branches,
+        show_remotes,
+        skip_tasks,
+        command_timeout,
+        include_skipped,
+        branch_diff_base,
+        full_diffs,
+        show_summary,
+        compare_ref,
+        scope,
+        time_window,
+        show_hidden,
+        stream,
+        ci_details,
+        capture,
+        deadline,
+        overflow,
+        density,
+        squash_patch_ids,
+    ) = match show_config {
         ShowConfig::Resolved {
             show_branches,
             show_remotes,
             skip_tasks,
             command_timeout,
-        } => (show_branches, show_remotes, skip_tasks, command_timeout),
+        } => (
+            show_branches,
+            show_remotes,
+            skip_tasks,
+            command_timeout,
+            false,
+            BranchDiffBase::default(),
+            false,
+            false,
+            None,
+            WorktreeScope::All,
+            TimeWindow::default(),
+            // The picker lists everything
+            true,
+            false,
+            false,
+            false,
+            None,
+            Overflow::Scroll,
+            None,
+            false,
+        ),
         ShowConfig::DeferredToParallel {
             cli_branches,
             cli_remotes,
             cli_full,
+            include_skipped,
+            branch_diff_base,
+            full_diffs,
+            summary,
+            against,
+            scope,
+            time_window,
+            show_hidden,
+            stream,
+            ci_details,
+            capture,
+            deadline,
+            overflow,
+            density,
         } => {
             let config = repo.config();
-            let show_branches = cli_branches || config.list.branches();
-            let show_remotes = cli_remotes || config.list.remotes();
-            let show_full = cli_full || config.list.full();
-            let skip_tasks: HashSet<TaskKind> = if show_full {
+            // A single row is cheap to fill in completely
+            let current_only = scope == WorktreeScope::Current;
+            let show_branches = !current_only && (cli_branches || config.list.branches());
+            let show_remotes = !current_only && (cli_remotes || config.list.remotes());
+            let show_full = current_only || cli_full || config.list.full();
+            let mut skip_tasks: HashSet<TaskKind> = if show_full {
                 HashSet::new()
             } else {
                 [
                     TaskKind::BranchDiff,
+                    TaskKind::UnmergedCommits,
                     TaskKind::CiStatus,
                     TaskKind::WorkingTreeConflicts,
                     TaskKind::SummaryGenerate,
@@ -353,6 +500,9 @@ pub fn collect(
                 .into_iter()
                 .collect()
             };
+            if ci_details {
+                skip_tasks.remove(&TaskKind::CiStatus);
+            }
             // Resolve timeout from merged config (--full disables timeout)
             let command_timeout = if show_full {
                 None
@@ -363,10 +513,40 @@ pub fn collect(
                     .filter(|&ms| ms > 0) // 0 means "no timeout" (explicit disable)
                     .map(std::time::Duration::from_millis)
             };
-            (show_branches, show_remotes, skip_tasks, command_timeout)
+            (
+                show_branches,
+                show_remotes,
+                skip_tasks,
+                command_timeout,
+                include_skipped,
+                branch_diff_base,
+                full_diffs,
+                summary,
+                against.or_else(|| config.list.compare_ref().map(str::to_string)),
+                scope,
+                time_window,
+                show_hidden,
+                stream,
+                ci_details,
+                capture,
+                deadline,
+                overflow,
+                density,
+                show_full,
+            )
         }
     };
 
+    // Validate --against up front, before the skeleton renders
+    if let Some(reference) = &compare_ref
+        && !repo.ref_exists(reference)?
+    {
+        return Err(GitError::ReferenceNotFound {
+            reference: reference.clone(),
+        }
+        .into());
+    }
+
     // Filter local branches to those without worktrees (CPU-only, no git commands)
     let branches_without_worktrees = if show_branches {
         let all_local = if let Some(result) = local_branches_cell.into_inner() {
@@ -413,6 +593,45 @@ pub fn collect(
         eprintln!("{}", hint_message(hint));
     }
 
+    // Once per repo: the remote renamed its default branch and rows still
+    // compare against the old one
+    if !repo.has_shown_hint("default-branch-renamed")
+        && let Some((old, new)) = repo.renamed_default_branch()
+    {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "The remote renamed <bold>{old}</> to <bold>{new}</>; rows still compare against <bold>{old}</>"
+            ))
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To follow the rename, run <underline>wt repair --default-branch</>"
+            ))
+        );
+        let _ = repo.mark_hint_shown("default-branch-renamed");
+    }
+
+    // Diffs on shallow and partial clones can fetch missing objects one at a
+    // time, so line counts stay uncomputed unless --full-diffs asks for them
+    let incomplete_history = repo.incomplete_history().filter(|_| !full_diffs);
+    if let Some(kind) = incomplete_history
+        && !repo.has_shown_hint("incomplete-history")
+    {
+        let clone = match kind {
+            IncompleteHistory::Shallow => "shallow",
+            IncompleteHistory::Partial => "partial",
+        };
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Skipped line diffs: this is a {clone} clone, where diffs can fetch missing objects. To compute them, run <underline>wt list --full-diffs</>"
+            ))
+        );
+        let _ = repo.mark_hint_shown("incomplete-history");
+    }
+
     // Main worktree is the primary worktree (for sorting and is_main display).
     // - Normal repos: the main worktree (repo root)
     // - Bare repos: the default branch's worktree
@@ -425,6 +644,23 @@ pub fn collect(
         .cloned()
         .ok_or_else(|| anyhow::anyhow!("No worktrees found"))?;
 
+    // Narrow the survey once main is known (it anchors relative paths)
+    let worktrees = match scope {
+        WorktreeScope::All => worktrees,
+        WorktreeScope::Current => {
+            let Some(current) = &current_worktree_path else {
+                return Err(GitError::NotInWorktree {
+                    action: Some("use --current".into()),
+                }
+                .into());
+            };
+            worktrees
+                .into_iter()
+                .filter(|wt| &wt.path == current)
+                .collect()
+        }
+    };
+
     // Defer previous_branch lookup until after skeleton - set is_previous later
     // (skeleton shows placeholder gutter, actual symbols appear when data loads)
 
@@ -444,8 +680,15 @@ pub fn collect(
         .collect();
     let timestamps = repo.commit_timestamps(&all_shas).unwrap_or_default();
 
+    // Activity is only read when recording is enabled
+    let activity_enabled = repo
+        .user_config()
+        .activity
+        .as_ref()
+        .is_some_and(|activity| activity.enabled());
+
     // Sort worktrees: current first, main second, then by timestamp descending
-    let sorted_worktrees = sort_worktrees_with_cache(
+    let mut sorted_worktrees = sort_worktrees_with_cache(
         worktrees.clone(),
         &main_worktree,
         current_worktree_path.as_ref(),
@@ -453,17 +696,77 @@ pub fn collect(
     );
 
     // Sort branches by timestamp (most recent first)
-    let branches_without_worktrees =
-        sort_by_timestamp_desc_with_cache(branches_without_worktrees, &timestamps, |(_, sha)| {
-            sha.as_str()
+    let mut branches_without_worktrees = sort_by_timestamp_desc_with_cache(
+        branches_without_worktrees,
+        &timestamps,
+        |(_, sha)| sha.as_str(),
+        |(name, _)| name.as_str(),
+    );
+    let mut remote_branches = sort_by_timestamp_desc_with_cache(
+        remote_branches,
+        &timestamps,
+        |(_, sha)| sha.as_str(),
+        |(name, _)| name.as_str(),
+    );
+
+    // Drop rows outside --since/--until before anything is sized or rendered
+    if !time_window.is_unbounded() {
+        let commit_time = |sha: &str| timestamps.get(sha).copied().unwrap_or(0);
+        let log_dir = activity_enabled.then(|| repo.wt_logs_dir());
+        sorted_worktrees.retain(|wt| {
+            let last_activity = log_dir
+                .as_deref()
+                .and_then(|dir| {
+                    worktrunk::activity::read(dir, &wt.path)
+                        .last()
+                        .map(|e| e.ts)
+                })
+                .and_then(|ts| i64::try_from(ts).ok());
+            let touched = commit_time(&wt.head).max(last_activity.unwrap_or(0));
+            time_window.contains(touched)
         });
-    let remote_branches =
-        sort_by_timestamp_desc_with_cache(remote_branches, &timestamps, |(_, sha)| sha.as_str());
+        branches_without_worktrees.retain(|(_, sha)| time_window.contains(commit_time(sha)));
+        remote_branches.retain(|(_, sha)| time_window.contains(commit_time(sha)));
+    }
+
+    // Branches hidden by `wt hide` are left out and counted in the footer, or
+    // listed with a marker under --hidden. --current always shows its row.
+    let hidden_branches = if scope == WorktreeScope::All {
+        repo.hidden_branches()
+    } else {
+        HashSet::new()
+    };
+    let is_hidden = |branch: Option<&str>| branch.is_some_and(|b| hidden_branches.contains(b));
+    let mut hidden_row_count = 0;
+    if !show_hidden && !hidden_branches.is_empty() {
+        let before = sorted_worktrees.len() + branches_without_worktrees.len();
+        sorted_worktrees.retain(|wt| !is_hidden(wt.branch.as_deref()));
+        branches_without_worktrees.retain(|(name, _)| !is_hidden(Some(name)));
+        hidden_row_count = before - sorted_worktrees.len() - branches_without_worktrees.len();
+    }
+
+    // Stack parents recorded by `wt parent` or `wt switch --base`. Rows with
+    // one compare with it instead of the default branch, unless the parent
+    // branch has since been deleted.
+    let branch_parents: HashMap<String, String> = repo
+        .branch_parents()
+        .into_iter()
+        .filter(|(_, parent)| repo.branch(parent).exists_locally().unwrap_or(false))
+        .collect();
+    let parent_of = |branch: Option<&str>| branch.and_then(|b| branch_parents.get(b)).cloned();
 
     // Pre-canonicalize main_worktree.path for is_main comparison
     // (paths from git worktree list may differ based on symlinks or working directory)
     let main_worktree_canonical = canonicalize(&main_worktree.path).ok();
 
+    // Detached worktrees checked out at a tag show the tag in the Branch column.
+    // Only list tags when there's a detached worktree to label.
+    let tags_by_commit = if worktrees.iter().any(|wt| wt.detached) {
+        repo.tags_by_commit().unwrap_or_default()
+    } else {
+        HashMap::new()
+    };
+
     // URL template already fetched in parallel join (layout needs to know if column is needed)
     // Initialize worktree items with identity fields and None for computed fields
     let mut all_items: Vec<ListItem> = sorted_worktrees
@@ -490,6 +793,10 @@ pub fn collect(
             let mut worktree_data =
                 WorktreeData::from_worktree(wt, is_main, is_current, is_previous);
             worktree_data.branch_worktree_mismatch = branch_worktree_mismatch;
+            if wt.detached {
+                worktree_data.tag = tags_by_commit.get(&wt.head).cloned();
+            }
+            worktree_data.branch_deleted = repo.deleted_branch_commit(wt).is_some();
 
             // URL expanded post-skeleton to minimize time-to-skeleton
             ListItem {
@@ -498,11 +805,18 @@ pub fn collect(
                 commit: None,
                 counts: None,
                 branch_diff: None,
+                unmerged_commits: None,
+                conflicted_files: None,
                 committed_trees_match: None,
                 has_file_changes: None,
                 would_merge_add: None,
                 is_ancestor: None,
+                squash_merged: None,
                 is_orphan: None,
+                is_compare_base: is_main,
+                hidden: is_hidden(wt.branch.as_deref()),
+                parent: parent_of(wt.branch.as_deref()),
+                incomplete: Default::default(),
                 upstream: None,
                 pr_status: None,
                 url: None,
@@ -517,11 +831,12 @@ pub fn collect(
 
     // Initialize branch items (local and remote) - URLs expanded post-skeleton
     let branch_start_idx = all_items.len();
-    all_items.extend(
-        branches_without_worktrees
-            .iter()
-            .map(|(name, sha)| ListItem::new_branch(sha.clone(), name.clone())),
-    );
+    all_items.extend(branches_without_worktrees.iter().map(|(name, sha)| {
+        let mut item = ListItem::new_branch(sha.clone(), name.clone());
+        item.hidden = is_hidden(Some(name));
+        item.parent = parent_of(Some(name));
+        item
+    }));
 
     let remote_start_idx = all_items.len();
     all_items.extend(
@@ -530,12 +845,25 @@ pub fn collect(
             .map(|(name, sha)| ListItem::new_branch(sha.clone(), name.clone())),
     );
 
+    // With --against, the branch the ref names takes the main worktree's place
+    // as the row that isn't compared to itself
+    if let Some(reference) = &compare_ref {
+        let compare_branch = reference.strip_prefix("refs/heads/").unwrap_or(reference);
+        for item in &mut all_items {
+            item.is_compare_base = item.branch.as_deref() == Some(compare_branch);
+        }
+    }
+
     // If no URL template configured, add UrlStatus to skip_tasks
     let mut effective_skip_tasks = skip_tasks.clone();
     if url_template.is_none() {
         effective_skip_tasks.insert(TaskKind::UrlStatus);
     }
 
+    if !activity_enabled {
+        effective_skip_tasks.insert(TaskKind::Activity);
+    }
+
     // Skip SummaryGenerate unless summary is enabled and an LLM command is configured
     let config = repo.config();
     let llm_command = config.commit_generation.command.clone();
@@ -543,13 +871,40 @@ pub fn collect(
         effective_skip_tasks.insert(TaskKind::SummaryGenerate);
     }
 
-    // Calculate layout from items (worktrees, local branches, and remote branches)
-    let layout = super::layout::calculate_layout_from_basics(
-        &all_items,
-        &effective_skip_tasks,
-        &main_worktree.path,
-        url_template.as_deref(),
-    );
+    // Offline, CI and summaries would only fail; hide their columns instead
+    if worktrunk::utils::is_offline() {
+        effective_skip_tasks.insert(TaskKind::CiStatus);
+        effective_skip_tasks.insert(TaskKind::SummaryGenerate);
+    }
+
+    if incomplete_history.is_some() {
+        effective_skip_tasks.insert(TaskKind::BranchDiff);
+        effective_skip_tasks.insert(TaskKind::UnmergedCommits);
+    }
+
+    // Calculate layout from items (worktrees, local branches, and remote branches).
+    // Streamed rows aren't aligned, so they skip sizing columns to the items.
+    let stream = stream && render_table;
+    let layout = if stream {
+        super::layout::calculate_unaligned_layout(
+            &effective_skip_tasks,
+            &main_worktree.path,
+            url_template.as_deref(),
+            compare_ref.as_deref(),
+        )
+    } else {
+        super::layout::calculate_layout_from_basics(
+            &all_items,
+            &effective_skip_tasks,
+            &main_worktree.path,
+            url_template.as_deref(),
+            &ListConfig {
+                density: density.or(config.list.density),
+                ..config.list.clone()
+            },
+            compare_ref.as_deref(),
+        )
+    };
 
     // Single-line invariant: use safe width to prevent line wrapping
     let max_width = crate::display::get_terminal_width();
@@ -560,6 +915,12 @@ pub fn collect(
         skip_tasks: effective_skip_tasks,
         url_template: url_template.clone(),
         llm_command,
+        include_skipped,
+        branch_diff_base,
+        compare_ref: compare_ref.clone(),
+        branch_parents,
+        skip_line_diffs: incomplete_history.is_some(),
+        squash_patch_ids,
         ..Default::default()
     };
 
@@ -606,6 +967,7 @@ pub fn collect(
             skeletons,
             initial_footer,
             max_width,
+            overflow,
         );
         table.render_skeleton()?;
         worktrunk::shell_exec::trace_instant("Skeleton rendered");
@@ -717,6 +1079,14 @@ pub fn collect(
     // Cache last rendered (unclamped) message per row to avoid redundant updates.
     let mut last_rendered_lines: Vec<String> = vec![String::new(); all_items.len()];
 
+    // Streaming: the header prints now, each row once all its results are in
+    let mut received_per_item = vec![0usize; all_items.len()];
+    let mut streamed = vec![false; all_items.len()];
+    let mut captured = capture.then(String::new);
+    if stream {
+        emit(&mut captured, &layout.format_unaligned_header());
+    }
+
     // Create channel for task results
     let (tx, rx) = chan::unbounded::<Result<TaskResult, TaskError>>();
 
@@ -787,6 +1157,9 @@ pub fn collect(
             ));
         }
 
+        // Every expectation is in: a --timeout deadline can name what's missing
+        expected_results_clone.finish_registration();
+
         // Sort work items: network tasks last to avoid blocking local operations
         all_work_items.sort_by_key(|item| item.kind.is_network());
 
@@ -794,7 +1167,8 @@ pub fn collect(
         worktrunk::shell_exec::trace_instant("Parallel execution started");
         all_work_items.into_par_iter().for_each(|item| {
             worktrunk::shell_exec::set_command_timeout(command_timeout);
-            let result = item.execute();
+            worktrunk::shell_exec::set_command_deadline(deadline);
+            let result = item.execute(deadline);
             let _ = tx_worker.send(result);
         });
     });
@@ -813,6 +1187,7 @@ pub fn collect(
         &mut all_items,
         &mut errors,
         &expected_results,
+        deadline,
         |item_idx, item, ctx| {
             // Trace first result arrival
             if !first_result_traced {
@@ -826,6 +1201,17 @@ pub fn collect(
                 ctx.apply_to(item, target.as_str());
             }
 
+            // Streaming mode only: print the row once its last result arrives.
+            // A row's expectations are all registered before its first result
+            // is sent, so this compares against the row's final total.
+            if stream {
+                received_per_item[item_idx] += 1;
+                if received_per_item[item_idx] == expected_results.results_for(item_idx).len() {
+                    streamed[item_idx] = true;
+                    emit(&mut captured, &layout.format_unaligned_line(item));
+                }
+            }
+
             // Progressive mode only: update UI
             if let Some(ref mut table) = progressive_table {
                 let dim = Style::new().dimmed();
@@ -867,11 +1253,28 @@ pub fn collect(
     );
     worktrunk::shell_exec::trace_instant("All results drained");
 
-    // Handle timeout if it occurred
-    if let DrainOutcome::TimedOut {
-        received_count,
-        items_with_missing,
-    } = drain_outcome
+    // Tasks the --timeout deadline left waiting are incomplete rather than
+    // failed, like those it cut short (marked as they arrived): their cells show `~`
+    let deadline_passed = deadline.is_some_and(|deadline| Instant::now() >= deadline);
+    if deadline_passed
+        && let DrainOutcome::TimedOut {
+            items_with_missing, ..
+        } = &drain_outcome
+    {
+        for result in items_with_missing {
+            all_items[result.item_idx]
+                .incomplete
+                .extend(result.missing_kinds.iter().copied());
+        }
+    }
+
+    // Handle timeout if it occurred (a --timeout deadline is reported below,
+    // with the incomplete cells)
+    if !deadline_passed
+        && let DrainOutcome::TimedOut {
+            received_count,
+            items_with_missing,
+        } = drain_outcome
     {
         // Warning: what happened + gutter showing which results are missing
         let mut diag = format!(
@@ -882,6 +1285,7 @@ pub fn collect(
         if !items_with_missing.is_empty() {
             let missing_lines: Vec<String> = items_with_missing
                 .iter()
+                .take(5)
                 .map(|result| {
                     let missing_names: Vec<&str> =
                         result.missing_kinds.iter().map(|k| k.into()).collect();
@@ -919,8 +1323,8 @@ pub fn collect(
         }
     }
 
-    // Count errors for summary
-    let error_count = errors.len();
+    // Count errors for summary (cells the deadline left incomplete didn't fail)
+    let error_count = errors.iter().filter(|e| !e.is_deadline()).count();
     let timed_out_count = errors.iter().filter(|e| e.is_timeout()).count();
 
     // Finalize progressive table or render buffered output
@@ -929,7 +1333,9 @@ pub fn collect(
         let final_msg = super::format_summary_message(
             &all_items,
             show_branches || show_remotes,
+            show_summary,
             layout.hidden_column_count,
+            hidden_row_count,
             error_count,
             timed_out_count,
         );
@@ -944,36 +1350,91 @@ pub fn collect(
         } else {
             // Non-TTY: output to stdout (same as buffered mode)
             // Progressive skeleton was suppressed; now output the final table
-            println!("{}", layout.format_header_line());
+            emit(&mut captured, &layout.format_header_line());
             for item in &all_items {
-                println!("{}", layout.format_list_item_line(item));
+                emit(&mut captured, &layout.format_list_item_line(item));
+            }
+            emit(&mut captured, "");
+            emit(&mut captured, &final_msg);
+        }
+    } else if stream {
+        // Streaming mode: print rows that never completed (prunable worktrees
+        // run no tasks; timed-out rows are missing results), then the footer
+        for (item, done) in all_items.iter().zip(&streamed) {
+            if !done {
+                emit(&mut captured, &layout.format_unaligned_line(item));
             }
-            println!();
-            println!("{}", final_msg);
         }
+        let final_msg = super::format_summary_message(
+            &all_items,
+            show_branches || show_remotes,
+            show_summary,
+            layout.hidden_column_count,
+            hidden_row_count,
+            error_count,
+            timed_out_count,
+        );
+        emit(&mut captured, "");
+        emit(&mut captured, &final_msg);
     } else if render_table {
         // Buffered mode: render final table
         let final_msg = super::format_summary_message(
             &all_items,
             show_branches || show_remotes,
+            show_summary,
             layout.hidden_column_count,
+            hidden_row_count,
             error_count,
             timed_out_count,
         );
 
-        println!("{}", layout.format_header_line());
+        emit(&mut captured, &layout.format_header_line());
         for item in &all_items {
-            println!("{}", layout.format_list_item_line(item));
+            emit(&mut captured, &layout.format_list_item_line(item));
+            if ci_details && let Some(details) = super::render::format_ci_details_line(item) {
+                emit(&mut captured, &details);
+            }
         }
-        println!();
-        println!("{}", final_msg);
+        emit(&mut captured, "");
+        emit(&mut captured, &final_msg);
     }
 
     // Status symbols are now computed during data collection (both modes), no fallback needed
 
+    // Name what the --timeout deadline left incomplete (after table rendering)
+    let incomplete_lines: Vec<String> = all_items
+        .iter()
+        .filter(|item| !item.incomplete.is_empty())
+        .map(|item| {
+            let kinds: Vec<&str> = item.incomplete.iter().map(|k| k.into()).collect();
+            let name = item
+                .branch
+                .clone()
+                .unwrap_or_else(|| item.head[..8.min(item.head.len())].to_string());
+            cformat!("<bold>{}</>: {}", name, kinds.join(", "))
+        })
+        .collect();
+    if !incomplete_lines.is_empty() {
+        let mut lines: Vec<String> = incomplete_lines.iter().take(5).cloned().collect();
+        if incomplete_lines.len() > 5 {
+            lines.push(format!("and {} more", incomplete_lines.len() - 5));
+        }
+        eprintln!(
+            "{}",
+            warning_message(format!(
+                "Stopped at the --timeout deadline; cells marked ~ are incomplete\n{}",
+                format_with_gutter(&lines.join("\n"), None)
+            ))
+        );
+    }
+
     // Display collection errors/warnings (after table rendering)
-    // Filter out timeout errors - they're shown in the summary footer
-    let non_timeout_errors: Vec<_> = errors.iter().filter(|e| !e.is_timeout()).collect();
+    // Filter out timeout errors - they're shown in the summary footer - and
+    // deadline errors, reported above
+    let non_timeout_errors: Vec<_> = errors
+        .iter()
+        .filter(|e| !e.is_timeout() && !e.is_deadline())
+        .collect();
 
     if !non_timeout_errors.is_empty() || progress_overflow {
         let mut warning_parts = Vec::new();
@@ -1023,6 +1484,7 @@ pub fn collect(
     // - Progressive + TTY: rows morphed in place, footer became summary
     // - Progressive + Non-TTY: rendered final table (no intermediate output)
     // - Buffered: rendered final table
+    // - Streaming: printed each row as it completed
     // JSON mode (render_table=false): no rendering, data returned for serialization
     worktrunk::shell_exec::trace_instant("List collect complete");
 
@@ -1030,21 +1492,38 @@ pub fn collect(
         items,
         main_worktree_path: main_worktree.path.clone(),
         skip_tasks: returned_skip_tasks,
+        compare_ref,
+        captured,
     }))
 }
 
+/// Print a line of table output, or add it to the capture buffer.
+fn emit(captured: &mut Option<String>, line: &str) {
+    match captured {
+        Some(buffer) => {
+            buffer.push_str(line);
+            buffer.push('\n');
+        }
+        None => println!("{line}"),
+    }
+}
+
 // ============================================================================
 // Sorting Helpers
 // ============================================================================
 
 /// Sort items by timestamp descending using pre-fetched timestamps.
-fn sort_by_timestamp_desc_with_cache<T, F>(
+///
+/// Under `--deterministic`, ties sort by `get_name`.
+fn sort_by_timestamp_desc_with_cache<T, F, N>(
     items: Vec<T>,
     timestamps: &std::collections::HashMap<String, i64>,
     get_sha: F,
+    get_name: N,
 ) -> Vec<T>
 where
     F: Fn(&T) -> &str,
+    N: Fn(&T) -> &str,
 {
     // Embed timestamp in tuple to avoid parallel Vec and index lookups
     let mut with_ts: Vec<_> = items
@@ -1054,12 +1533,20 @@ where
             (item, ts)
         })
         .collect();
-    with_ts.sort_by_key(|(_, ts)| std::cmp::Reverse(*ts));
+    if worktrunk::utils::is_deterministic() {
+        // Break timestamp ties by name rather than git's enumeration order
+        with_ts.sort_by(|(a, a_ts), (b, b_ts)| {
+            b_ts.cmp(a_ts).then_with(|| get_name(a).cmp(get_name(b)))
+        });
+    } else {
+        with_ts.sort_by_key(|(_, ts)| std::cmp::Reverse(*ts));
+    }
     with_ts.into_iter().map(|(item, _)| item).collect()
 }
 
 /// Sort worktrees: current first, main second, then by timestamp descending.
-/// Uses pre-fetched timestamps for efficiency.
+/// Uses pre-fetched timestamps for efficiency. Under `--deterministic`, ties
+/// sort by path.
 fn sort_worktrees_with_cache(
     worktrees: Vec<WorktreeInfo>,
     main_worktree: &WorktreeInfo,
@@ -1082,7 +1569,16 @@ fn sort_worktrees_with_cache(
         })
         .collect();
 
-    with_sort_key.sort_by_key(|(_, priority, ts)| (*priority, std::cmp::Reverse(*ts)));
+    if worktrunk::utils::is_deterministic() {
+        // Break timestamp ties by path rather than git's enumeration order
+        with_sort_key.sort_by(|(a, a_priority, a_ts), (b, b_priority, b_ts)| {
+            (a_priority, b_ts)
+                .cmp(&(b_priority, a_ts))
+                .then_with(|| a.path.cmp(&b.path))
+        });
+    } else {
+        with_sort_key.sort_by_key(|(_, priority, ts)| (*priority, std::cmp::Reverse(*ts)));
+    }
     with_sort_key.into_iter().map(|(wt, _, _)| wt).collect()
 }
 
@@ -1106,11 +1602,18 @@ pub fn build_worktree_item(
         commit: None,
         counts: None,
         branch_diff: None,
+        unmerged_commits: None,
+        conflicted_files: None,
         committed_trees_match: None,
         has_file_changes: None,
         would_merge_add: None,
         is_ancestor: None,
+        squash_merged: None,
         is_orphan: None,
+        is_compare_base: is_main,
+        hidden: false,
+        parent: None,
+        incomplete: Default::default(),
         upstream: None,
         pr_status: None,
         url: None,
@@ -1165,6 +1668,7 @@ pub fn populate_item(
         path: data.path.clone(),
         head: item.head.clone(),
         branch: item.branch.clone(),
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: None,
@@ -1190,7 +1694,7 @@ pub fn populate_item(
 
         // Execute all tasks in parallel
         work_items.into_par_iter().for_each(|item| {
-            let result = item.execute();
+            let result = item.execute(None);
             let _ = tx.send(result);
         });
     });
@@ -1201,6 +1705,7 @@ pub fn populate_item(
         std::slice::from_mut(item),
         &mut errors,
         &expected_results,
+        None,
         |_item_idx, item, ctx| {
             if let Some(ref t) = target {
                 ctx.apply_to(item, t);
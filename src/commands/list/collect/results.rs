@@ -13,6 +13,11 @@ use worktrunk::git::LineDiff;
 /// under CI load where process spawning for ~70 work items can be slow.
 pub(super) const DRAIN_TIMEOUT: Duration = Duration::from_secs(120);
 
+/// How long past the `--timeout` deadline to keep draining. Tasks refused or
+/// cut short by the deadline report in almost at once; only a task stuck
+/// outside a subprocess is still missing after this.
+const DEADLINE_GRACE: Duration = Duration::from_millis(100);
+
 use super::super::model::{CommitDetails, ItemKind, ListItem, UpstreamStatus, WorkingTreeStatus};
 use super::execution::ExpectedResults;
 use super::types::{DrainOutcome, MissingResult, StatusContext, TaskError, TaskKind, TaskResult};
@@ -52,7 +57,11 @@ pub(super) fn apply_default(
             // Conservative: don't claim merged if we couldn't check
             items[idx].is_ancestor = Some(false);
         }
-        TaskKind::BranchDiff => {
+        TaskKind::SquashMerged => {
+            // Conservative: don't claim squash-merged if we couldn't check
+            items[idx].squash_merged = Some(false);
+        }
+        TaskKind::BranchDiff | TaskKind::UnmergedCommits => {
             // Leave as None — UI shows `…` for skipped/failed tasks
         }
         TaskKind::WorkingTreeDiff => {
@@ -91,6 +100,9 @@ pub(super) fn apply_default(
             // URL is set at item creation, only default url_active
             items[idx].url_active = None;
         }
+        TaskKind::Activity => {
+            // Already defaults to empty in WorktreeData
+        }
         TaskKind::SummaryGenerate => {
             // Leave as None — no summary available
         }
@@ -104,8 +116,10 @@ pub(super) fn apply_default(
 /// item index and a reference to the updated item, allowing progressive mode
 /// to update the live table while buffered mode does nothing.
 ///
-/// Uses [`DRAIN_TIMEOUT`] to prevent infinite hangs if git commands stall.
-/// When timeout occurs, returns `DrainOutcome::TimedOut` with diagnostic info.
+/// Uses [`DRAIN_TIMEOUT`] to prevent infinite hangs if git commands stall,
+/// or `deadline` (`wt list --timeout`, plus [`DEADLINE_GRACE`]) when that
+/// comes first. When timeout occurs, returns `DrainOutcome::TimedOut` with
+/// diagnostic info.
 ///
 /// Errors are collected in the `errors` vec for display after rendering.
 /// Default values are applied for failed tasks so the UI can still render.
@@ -118,9 +132,13 @@ pub(super) fn drain_results(
     items: &mut [ListItem],
     errors: &mut Vec<TaskError>,
     expected_results: &ExpectedResults,
+    deadline: Option<Instant>,
     mut on_result: impl FnMut(usize, &mut ListItem, &StatusContext),
 ) -> DrainOutcome {
-    let deadline = Instant::now() + DRAIN_TIMEOUT;
+    let drain_deadline = Instant::now() + DRAIN_TIMEOUT;
+    let deadline = deadline.map_or(drain_deadline, |deadline| {
+        (deadline + DEADLINE_GRACE).min(drain_deadline)
+    });
 
     // Track which result kinds we've received per item (for timeout diagnostics)
     let mut received_by_item: Vec<Vec<TaskKind>> = vec![Vec::new(); items.len()];
@@ -132,6 +150,14 @@ pub(super) fn drain_results(
     loop {
         let remaining = deadline.saturating_duration_since(Instant::now());
         if remaining.is_zero() {
+            // A --timeout deadline reports every task that didn't finish, so
+            // wait for the expectations of items still being set up
+            if deadline < drain_deadline {
+                expected_results.wait_for_registration(
+                    drain_deadline.saturating_duration_since(Instant::now()),
+                );
+            }
+
             // Deadline exceeded - build diagnostic info showing MISSING results
             let received_count: usize = received_by_item.iter().map(|v| v.len()).sum();
 
@@ -165,9 +191,7 @@ pub(super) fn drain_results(
                 }
             }
 
-            // Sort by item index and limit to first 5
             items_with_missing.sort_by_key(|result| result.item_idx);
-            items_with_missing.truncate(5);
 
             return DrainOutcome::TimedOut {
                 received_count,
@@ -190,8 +214,13 @@ pub(super) fn drain_results(
         // Track this result for diagnostics (both success and error count as "received")
         received_by_item[item_idx].push(kind);
 
-        // Handle error case: apply defaults and collect error
+        // Handle error case: apply defaults and collect error. A task the
+        // --timeout deadline refused or cut short is incomplete, marked before
+        // its row can render
         if let Err(error) = outcome {
+            if error.is_deadline() {
+                items[item_idx].incomplete.insert(error.kind);
+            }
             apply_default(items, &mut status_contexts, &error);
             errors.push(error);
             let item = &mut items[item_idx];
@@ -234,9 +263,15 @@ pub(super) fn drain_results(
             TaskResult::IsAncestor { is_ancestor, .. } => {
                 item.is_ancestor = Some(is_ancestor);
             }
+            TaskResult::SquashMerged { squash_merged, .. } => {
+                item.squash_merged = Some(squash_merged);
+            }
             TaskResult::BranchDiff { branch_diff, .. } => {
                 item.branch_diff = Some(branch_diff);
             }
+            TaskResult::UnmergedCommits { count, .. } => {
+                item.unmerged_commits = Some(count);
+            }
             TaskResult::WorkingTreeDiff {
                 working_tree_diff,
                 working_tree_status,
@@ -244,7 +279,7 @@ pub(super) fn drain_results(
                 ..
             } => {
                 if let ItemKind::Worktree(data) = &mut item.kind {
-                    data.working_tree_diff = Some(working_tree_diff);
+                    data.working_tree_diff = working_tree_diff;
                 } else {
                     debug_assert!(false, "WorkingTreeDiff result for non-worktree item");
                 }
@@ -253,11 +288,11 @@ pub(super) fn drain_results(
                 status_ctx.has_conflicts = has_conflicts;
             }
             TaskResult::MergeTreeConflicts {
-                has_merge_tree_conflicts,
-                ..
+                conflicted_files, ..
             } => {
                 // Store for status_symbols computation
-                status_ctx.has_merge_tree_conflicts = has_merge_tree_conflicts;
+                status_ctx.has_merge_tree_conflicts = conflicted_files.is_some();
+                item.conflicted_files = conflicted_files;
             }
             TaskResult::WorkingTreeConflicts {
                 has_working_tree_conflicts,
@@ -296,6 +331,13 @@ pub(super) fn drain_results(
                     item.url_active = active;
                 }
             }
+            TaskResult::Activity { entries, .. } => {
+                if let ItemKind::Worktree(data) = &mut item.kind {
+                    data.activity = entries;
+                } else {
+                    debug_assert!(false, "Activity result for non-worktree item");
+                }
+            }
             TaskResult::SummaryGenerate { summary, .. } => {
                 item.summary = Some(summary);
             }
@@ -345,7 +387,7 @@ mod tests {
         .unwrap();
         drop(tx);
 
-        let outcome = drain_results(rx, &mut items, &mut errors, &expected, |_, _, _| {});
+        let outcome = drain_results(rx, &mut items, &mut errors, &expected, None, |_, _, _| {});
         assert!(matches!(outcome, DrainOutcome::Complete));
         assert_eq!(items[0].summary, Some(Some("Add feature".into())));
     }
@@ -1,12 +1,14 @@
 //! Task trait and implementations.
 //!
-//! Contains the `Task` trait interface and all 16 task implementations that
+//! Contains the `Task` trait interface and all 19 task implementations that
 //! compute various git operations for worktrees and branches.
 
 use std::net::{SocketAddr, TcpStream};
 use std::time::Duration;
 
-use worktrunk::git::{LineDiff, Repository};
+use worktrunk::git::{IncompleteHistory, LineDiff, Repository};
+
+use crate::cli::BranchDiffBase;
 
 use super::super::ci_status::{CiBranchName, PrStatus};
 use super::super::model::{
@@ -41,6 +43,18 @@ pub struct TaskContext {
     pub item_url: Option<String>,
     /// LLM command for summary generation (from commit.generation config).
     pub llm_command: Option<String>,
+    /// Count edits to skip-worktree/assume-unchanged files as dirty (`--include-skipped`).
+    pub include_skipped: bool,
+    /// What branch line diffs are taken against (`--branch-diff-base`).
+    pub branch_diff_base: BranchDiffBase,
+    /// Ref to compare against instead of the default branch (`--against`).
+    pub compare_ref: Option<String>,
+    /// Recorded stack parent of this branch (`wt parent`).
+    pub parent: Option<String>,
+    /// Leave working tree line counts uncomputed (shallow or partial clone).
+    pub skip_line_diffs: bool,
+    /// Also match squash merges by the branch's cumulative patch-id (`--full`).
+    pub squash_patch_ids: bool,
 }
 
 impl TaskContext {
@@ -72,6 +86,15 @@ impl TaskContext {
         self.repo.default_branch()
     }
 
+    /// Ref for ahead/behind and branch diff: `--against` if given, then the
+    /// branch's recorded stack parent, otherwise the default branch.
+    pub(super) fn compare_base(&self) -> Option<String> {
+        self.compare_ref
+            .clone()
+            .or_else(|| self.parent.clone())
+            .or_else(|| self.default_branch())
+    }
+
     /// Get the integration target (cached in Repository).
     ///
     /// Used for integration checks (status symbols, safe deletion).
@@ -114,20 +137,26 @@ impl Task for CommitDetailsTask {
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         let repo = &ctx.repo;
-        let (timestamp, commit_message) = repo
+        // Unborn branches have no commit to describe; fall back to empty details
+        let commit = repo
             .commit_details(&ctx.branch_ref.commit_sha)
-            .map_err(|e| ctx.error(Self::KIND, &e))?;
-        Ok(TaskResult::CommitDetails {
-            item_idx: ctx.item_idx,
-            commit: CommitDetails {
+            .map_err(|e| ctx.error(Self::KIND, &e))?
+            .map(|(timestamp, commit_message)| CommitDetails {
                 timestamp,
                 commit_message,
-            },
+            })
+            .unwrap_or_default();
+        Ok(TaskResult::CommitDetails {
+            item_idx: ctx.item_idx,
+            commit,
         })
     }
 }
 
 /// Task 2: Ahead/behind counts vs local default branch (informational stats)
+///
+/// Counts against the `--against` ref instead when one is given, or else
+/// against the branch's recorded stack parent.
 pub struct AheadBehindTask;
 
 impl Task for AheadBehindTask {
@@ -135,7 +164,7 @@ impl Task for AheadBehindTask {
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         // When default_branch is None, return zero counts (cells show empty)
-        let Some(base) = ctx.default_branch() else {
+        let Some(base) = ctx.compare_base() else {
             return Ok(TaskResult::AheadBehind {
                 item_idx: ctx.item_idx,
                 counts: AheadBehind::default(),
@@ -152,6 +181,27 @@ impl Task for AheadBehindTask {
             .is_none();
 
         if is_orphan {
+            // A shallow clone cuts history at the grafted boundary, so a missing
+            // merge-base doesn't mean an orphan. Count what history we have and
+            // report it as a lower bound.
+            if repo.incomplete_history() == Some(IncompleteHistory::Shallow) {
+                let head = &ctx.branch_ref.commit_sha;
+                let ahead = repo
+                    .count_commits(&base, head)
+                    .map_err(|e| ctx.error(Self::KIND, &e))?;
+                let behind = repo
+                    .count_commits(head, &base)
+                    .map_err(|e| ctx.error(Self::KIND, &e))?;
+                return Ok(TaskResult::AheadBehind {
+                    item_idx: ctx.item_idx,
+                    counts: AheadBehind {
+                        ahead,
+                        behind,
+                        lower_bound: true,
+                    },
+                    is_orphan: false,
+                });
+            }
             return Ok(TaskResult::AheadBehind {
                 item_idx: ctx.item_idx,
                 counts: AheadBehind::default(),
@@ -176,7 +226,11 @@ impl Task for AheadBehindTask {
 
         Ok(TaskResult::AheadBehind {
             item_idx: ctx.item_idx,
-            counts: AheadBehind { ahead, behind },
+            counts: AheadBehind {
+                ahead,
+                behind,
+                lower_bound: false,
+            },
             is_orphan: false,
         })
     }
@@ -329,7 +383,50 @@ impl Task for IsAncestorTask {
     }
 }
 
+/// Task 3d: Squash-merge heuristic (does target already carry the branch's patches?)
+///
+/// Runs `git cherry` (cached with the Unmerged column) and, with `--full`,
+/// compares the patch-id of the branch's cumulative diff against target's
+/// commits since the fork point. Catches squash merges after target has
+/// edited the same lines again, which the merge simulation in Task 3b can't
+/// see. Ancestors short-circuit to false.
+///
+/// Uses commit SHA so the repo cache keys on (branch SHA, target SHA).
+pub struct SquashMergedTask;
+
+impl Task for SquashMergedTask {
+    const KIND: TaskKind = TaskKind::SquashMerged;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        // When integration_target is None, return false (conservative: don't mark as merged)
+        let Some(base) = ctx.integration_target() else {
+            return Ok(TaskResult::SquashMerged {
+                item_idx: ctx.item_idx,
+                squash_merged: false,
+            });
+        };
+        let repo = &ctx.repo;
+        let sha = &ctx.branch_ref.commit_sha;
+        let squash_merged = if ctx.squash_patch_ids {
+            repo.is_squash_merged(sha, &base)
+        } else {
+            repo.is_cherry_merged(sha, &base)
+        }
+        .map_err(|e| ctx.error(Self::KIND, &e))?;
+
+        Ok(TaskResult::SquashMerged {
+            item_idx: ctx.item_idx,
+            squash_merged,
+        })
+    }
+}
+
 /// Task 4: Branch diff stats vs local default branch (informational stats)
+///
+/// Diffs from the merge-base by default, so the totals only cover what the
+/// branch adds; `--branch-diff-base=head` diffs against the default branch tip.
+/// `--against`, or a recorded stack parent, swaps the default branch for
+/// another ref.
 pub struct BranchDiffTask;
 
 impl Task for BranchDiffTask {
@@ -337,20 +434,66 @@ impl Task for BranchDiffTask {
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         // When default_branch is None, return empty diff (cells show empty)
-        let Some(base) = ctx.default_branch() else {
+        let Some(base) = ctx.compare_base() else {
             return Ok(TaskResult::BranchDiff {
                 item_idx: ctx.item_idx,
                 branch_diff: BranchDiffTotals::default(),
             });
         };
         let repo = &ctx.repo;
-        let diff = repo
-            .branch_diff_stats(&base, &ctx.branch_ref.commit_sha)
-            .map_err(|e| ctx.error(Self::KIND, &e))?;
+        let head = &ctx.branch_ref.commit_sha;
+        let (base, diff) = match ctx.branch_diff_base {
+            BranchDiffBase::MergeBase => {
+                let diff = repo
+                    .branch_diff_stats(&base, head)
+                    .map_err(|e| ctx.error(Self::KIND, &e))?;
+                // Cached by branch_diff_stats, so this doesn't spawn git again
+                let merge_base = repo
+                    .merge_base(&base, head)
+                    .map_err(|e| ctx.error(Self::KIND, &e))?;
+                (merge_base, diff)
+            }
+            BranchDiffBase::Head => {
+                let (tip, diff) = repo
+                    .tip_diff_stats(&base, head)
+                    .map_err(|e| ctx.error(Self::KIND, &e))?;
+                (Some(tip), diff)
+            }
+        };
 
         Ok(TaskResult::BranchDiff {
             item_idx: ctx.item_idx,
-            branch_diff: BranchDiffTotals { diff },
+            branch_diff: BranchDiffTotals { diff, base },
+        })
+    }
+}
+
+/// Task 4b: Commits the default branch doesn't have yet, by change (`git cherry`)
+///
+/// Unlike ahead/behind, skips merge commits and commits whose patch is
+/// already on the default branch. `--against` swaps the default branch for
+/// another ref. Shares the repo's `git cherry` cache with Task 3d.
+pub struct UnmergedCommitsTask;
+
+impl Task for UnmergedCommitsTask {
+    const KIND: TaskKind = TaskKind::UnmergedCommits;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        // When default_branch is None, report nothing unmerged (cells show empty)
+        let Some(base) = ctx.compare_base() else {
+            return Ok(TaskResult::UnmergedCommits {
+                item_idx: ctx.item_idx,
+                count: 0,
+            });
+        };
+        let count = ctx
+            .repo
+            .unmerged_commit_count(&ctx.branch_ref.commit_sha, &base)
+            .map_err(|e| ctx.error(Self::KIND, &e))?;
+
+        Ok(TaskResult::UnmergedCommits {
+            item_idx: ctx.item_idx,
+            count,
         })
     }
 }
@@ -370,20 +513,35 @@ impl Task for WorkingTreeDiffTask {
             .working_tree(&ctx.repo)
             .ok_or_else(|| ctx.error(Self::KIND, &anyhow::anyhow!("requires a worktree")))?;
 
-        // Use --no-optional-locks to avoid index lock contention with WorkingTreeConflictsTask's
-        // `git stash create` which needs the index lock.
+        // status_porcelain uses --no-optional-locks to avoid index lock contention with
+        // WorkingTreeConflictsTask's `git stash create` which needs the index lock.
         let status_output = wt
-            .run_command(&["--no-optional-locks", "status", "--porcelain"])
+            .status_porcelain()
             .map_err(|e| ctx.error(Self::KIND, &e))?;
 
-        let (working_tree_status, is_dirty, has_conflicts) =
+        let (mut working_tree_status, mut is_dirty, has_conflicts) =
             parse_working_tree_status(&status_output);
 
-        let working_tree_diff = if is_dirty {
-            wt.working_tree_diff_stats()
+        // --include-skipped: count skip-worktree/assume-unchanged edits as modifications
+        if ctx.include_skipped
+            && !wt
+                .hidden_changes()
                 .map_err(|e| ctx.error(Self::KIND, &e))?
+                .is_empty()
+        {
+            working_tree_status.modified = true;
+            is_dirty = true;
+        }
+
+        let working_tree_diff = if ctx.skip_line_diffs {
+            None
+        } else if is_dirty {
+            Some(
+                wt.working_tree_diff_stats()
+                    .map_err(|e| ctx.error(Self::KIND, &e))?,
+            )
         } else {
-            LineDiff::default()
+            Some(LineDiff::default())
         };
 
         Ok(TaskResult::WorkingTreeDiff {
@@ -409,16 +567,17 @@ impl Task for MergeTreeConflictsTask {
         let Some(base) = ctx.default_branch() else {
             return Ok(TaskResult::MergeTreeConflicts {
                 item_idx: ctx.item_idx,
-                has_merge_tree_conflicts: false,
+                conflicted_files: None,
             });
         };
         let repo = &ctx.repo;
-        let has_merge_tree_conflicts = repo
-            .has_merge_conflicts(&base, &ctx.branch_ref.commit_sha)
-            .map_err(|e| ctx.error(Self::KIND, &e))?;
+        let conflicted_files = repo
+            .merge_conflict_files(&base, &ctx.branch_ref.commit_sha)
+            .map_err(|e| ctx.error(Self::KIND, &e))?
+            .map(|files| files.len());
         Ok(TaskResult::MergeTreeConflicts {
             item_idx: ctx.item_idx,
-            has_merge_tree_conflicts,
+            conflicted_files,
         })
     }
 }
@@ -447,10 +606,11 @@ impl Task for WorkingTreeConflictsTask {
             .working_tree(&ctx.repo)
             .ok_or_else(|| ctx.error(Self::KIND, &anyhow::anyhow!("requires a worktree")))?;
 
-        // Use --no-optional-locks to avoid index lock contention with WorkingTreeDiffTask.
-        // Both tasks run in parallel, and `git stash create` below needs the index lock.
+        // status_porcelain uses --no-optional-locks to avoid index lock contention with
+        // WorkingTreeDiffTask. Both tasks run in parallel, and `git stash create` below
+        // needs the index lock.
         let status_output = wt
-            .run_command(&["--no-optional-locks", "status", "--porcelain"])
+            .status_porcelain()
             .map_err(|e| ctx.error(Self::KIND, &e))?;
 
         let is_dirty = !status_output.trim().is_empty();
@@ -696,6 +856,27 @@ impl Task for SummaryGenerateTask {
     }
 }
 
+/// Task 15 (worktree only): Recent commands from the activity log
+pub struct ActivityTask;
+
+impl Task for ActivityTask {
+    const KIND: TaskKind = TaskKind::Activity;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        // This task is only spawned for worktree items, so worktree path is always present.
+        let path = ctx
+            .branch_ref
+            .worktree_path
+            .as_deref()
+            .ok_or_else(|| ctx.error(Self::KIND, &anyhow::anyhow!("requires a worktree")))?;
+        let entries = worktrunk::activity::read(&ctx.repo.wt_logs_dir(), path);
+        Ok(TaskResult::Activity {
+            item_idx: ctx.item_idx,
+            entries,
+        })
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
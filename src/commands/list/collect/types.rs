@@ -6,6 +6,7 @@
 //! - `StatusContext` - context for status symbol computation
 //! - `DrainOutcome` and `MissingResult` - timeout diagnostic info
 
+use worktrunk::activity::ActivityEntry;
 use worktrunk::git::LineDiff;
 
 use super::super::ci_status::PrStatus;
@@ -96,15 +97,23 @@ pub(crate) enum TaskResult {
     },
     /// Whether branch HEAD is ancestor of integration target (same commit or already merged)
     IsAncestor { item_idx: usize, is_ancestor: bool },
+    /// Whether integration target has the branch's patches (squash merge or cherry-picks)
+    SquashMerged {
+        item_idx: usize,
+        squash_merged: bool,
+    },
     /// Line diff vs default branch
     BranchDiff {
         item_idx: usize,
         branch_diff: BranchDiffTotals,
     },
+    /// Branch commits whose changes the default branch lacks (`git cherry`)
+    UnmergedCommits { item_idx: usize, count: usize },
     /// Working tree diff and status
     WorkingTreeDiff {
         item_idx: usize,
-        working_tree_diff: LineDiff,
+        /// None when line counts were skipped (shallow or partial clone)
+        working_tree_diff: Option<LineDiff>,
         /// Working tree change flags
         working_tree_status: WorkingTreeStatus,
         has_conflicts: bool,
@@ -112,7 +121,8 @@ pub(crate) enum TaskResult {
     /// Potential merge conflicts with default branch (merge-tree simulation on committed HEAD)
     MergeTreeConflicts {
         item_idx: usize,
-        has_merge_tree_conflicts: bool,
+        /// None = clean merge, Some(n) = n files would conflict
+        conflicted_files: Option<usize>,
     },
     /// Potential merge conflicts including working tree changes (--full only)
     ///
@@ -154,6 +164,11 @@ pub(crate) enum TaskResult {
         /// Whether the port is listening (None if no URL or couldn't parse port)
         active: Option<bool>,
     },
+    /// Recorded commands from the activity log (`[activity] enabled = true`)
+    Activity {
+        item_idx: usize,
+        entries: Vec<ActivityEntry>,
+    },
     /// LLM-generated branch summary (`--full` + `[list] summary = true` + LLM command)
     SummaryGenerate {
         item_idx: usize,
@@ -171,7 +186,9 @@ impl TaskResult {
             | TaskResult::HasFileChanges { item_idx, .. }
             | TaskResult::WouldMergeAdd { item_idx, .. }
             | TaskResult::IsAncestor { item_idx, .. }
+            | TaskResult::SquashMerged { item_idx, .. }
             | TaskResult::BranchDiff { item_idx, .. }
+            | TaskResult::UnmergedCommits { item_idx, .. }
             | TaskResult::WorkingTreeDiff { item_idx, .. }
             | TaskResult::MergeTreeConflicts { item_idx, .. }
             | TaskResult::WorkingTreeConflicts { item_idx, .. }
@@ -180,6 +197,7 @@ impl TaskResult {
             | TaskResult::Upstream { item_idx, .. }
             | TaskResult::CiStatus { item_idx, .. }
             | TaskResult::UrlStatus { item_idx, .. }
+            | TaskResult::Activity { item_idx, .. }
             | TaskResult::SummaryGenerate { item_idx, .. } => *item_idx,
         }
     }
@@ -207,7 +225,7 @@ pub(super) enum DrainOutcome {
     TimedOut {
         /// Number of task results received before timeout
         received_count: usize,
-        /// Items with missing results
+        /// Items with missing results, in item order
         items_with_missing: Vec<MissingResult>,
     },
 }
@@ -225,6 +243,8 @@ pub(super) struct MissingResult {
 pub enum ErrorCause {
     /// Command exceeded the configured timeout.
     Timeout,
+    /// The `--timeout` deadline passed before the task finished.
+    Deadline,
     /// Any other error (permission denied, git error, etc.).
     Other,
 }
@@ -261,6 +281,12 @@ impl TaskError {
     pub fn is_timeout(&self) -> bool {
         self.cause == ErrorCause::Timeout
     }
+
+    /// Whether the `--timeout` deadline cut this task short. Its data is
+    /// incomplete rather than failed.
+    pub fn is_deadline(&self) -> bool {
+        self.cause == ErrorCause::Deadline
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,12 @@
+use std::borrow::Cow;
+
+use unicode_width::UnicodeWidthStr;
+
 use super::collect::TaskKind;
 
+/// Widest ref name shown in `--against` headers before truncating.
+const MAX_COMPARE_LABEL_WIDTH: usize = 12;
+
 /// Logical identifier for each column rendered by `wt list`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ColumnKind {
@@ -9,11 +16,13 @@ pub enum ColumnKind {
     WorkingDiff,
     AheadBehind,
     BranchDiff,
+    Unmerged, // Commits the default branch lacks by change (`git cherry`, `--full`)
     Summary,
     Upstream,
     CiStatus,
     Path,
-    Url, // Dev server URL from project config template
+    Url,      // Dev server URL from project config template
+    Activity, // Last command from the activity log (`[activity] enabled = true`)
     Commit,
     Time,
     Message,
@@ -28,9 +37,11 @@ impl ColumnKind {
             ColumnKind::WorkingDiff => "HEAD±",
             ColumnKind::AheadBehind => "main↕",
             ColumnKind::BranchDiff => "main…±",
+            ColumnKind::Unmerged => "Unmerged",
             ColumnKind::Path => "Path",
             ColumnKind::Upstream => "Remote⇅",
             ColumnKind::Url => "URL",
+            ColumnKind::Activity => "Activity",
             ColumnKind::Time => "Age",
             ColumnKind::CiStatus => "CI",
             ColumnKind::Commit => "Commit",
@@ -39,6 +50,63 @@ impl ColumnKind {
         }
     }
 
+    /// Header naming `compare_ref` in place of `main` for the default-branch
+    /// columns (`wt list --against`). Long refs keep their tail, since that is
+    /// usually the distinguishing part (`…release/2.4`).
+    pub fn header_against(self, compare_ref: Option<&str>) -> Cow<'static, str> {
+        let Some(reference) = compare_ref else {
+            return Cow::Borrowed(self.header());
+        };
+        let suffix = match self {
+            ColumnKind::AheadBehind => "↕",
+            ColumnKind::BranchDiff => "…±",
+            _ => return Cow::Borrowed(self.header()),
+        };
+        let label = if reference.width() > MAX_COMPARE_LABEL_WIDTH {
+            let chars: Vec<char> = reference.chars().collect();
+            let tail: String = chars[chars.len() - (MAX_COMPARE_LABEL_WIDTH - 1)..]
+                .iter()
+                .collect();
+            format!("…{tail}")
+        } else {
+            reference.to_string()
+        };
+        Cow::Owned(format!("{label}{suffix}"))
+    }
+
+    /// Tasks whose results this column shows. When the `wt list --timeout`
+    /// deadline cuts any of them short, the cell shows `~`.
+    pub const fn fed_by(self) -> &'static [TaskKind] {
+        match self {
+            ColumnKind::Gutter | ColumnKind::Branch | ColumnKind::Path | ColumnKind::Commit => &[],
+            // The URL itself comes from the template; the task only dims it
+            ColumnKind::Url => &[],
+            ColumnKind::Status => &[
+                TaskKind::WorkingTreeDiff,
+                TaskKind::WorkingTreeConflicts,
+                TaskKind::MergeTreeConflicts,
+                TaskKind::GitOperation,
+                TaskKind::UserMarker,
+                TaskKind::Upstream,
+                TaskKind::AheadBehind,
+                TaskKind::IsAncestor,
+                TaskKind::CommittedTreesMatch,
+                TaskKind::HasFileChanges,
+                TaskKind::WouldMergeAdd,
+                TaskKind::SquashMerged,
+            ],
+            ColumnKind::WorkingDiff => &[TaskKind::WorkingTreeDiff],
+            ColumnKind::AheadBehind => &[TaskKind::AheadBehind],
+            ColumnKind::BranchDiff => &[TaskKind::BranchDiff],
+            ColumnKind::Unmerged => &[TaskKind::UnmergedCommits],
+            ColumnKind::Summary => &[TaskKind::SummaryGenerate],
+            ColumnKind::Upstream => &[TaskKind::Upstream],
+            ColumnKind::CiStatus => &[TaskKind::CiStatus],
+            ColumnKind::Activity => &[TaskKind::Activity],
+            ColumnKind::Time | ColumnKind::Message => &[TaskKind::CommitDetails],
+        }
+    }
+
     /// Get the base priority for this column (lower = more important).
     ///
     /// Used by both `wt list` layout and statusline truncation to ensure
@@ -92,11 +160,13 @@ pub const COLUMN_SPECS: &[ColumnSpec] = &[
     ColumnSpec::new(ColumnKind::WorkingDiff, 3, None),
     ColumnSpec::new(ColumnKind::AheadBehind, 4, None),
     ColumnSpec::new(ColumnKind::BranchDiff, 6, Some(TaskKind::BranchDiff)),
+    ColumnSpec::new(ColumnKind::Unmerged, 15, Some(TaskKind::UnmergedCommits)),
     ColumnSpec::new(ColumnKind::Summary, 10, Some(TaskKind::SummaryGenerate)),
     ColumnSpec::new(ColumnKind::Upstream, 8, None),
     ColumnSpec::new(ColumnKind::CiStatus, 5, Some(TaskKind::CiStatus)),
     ColumnSpec::new(ColumnKind::Path, 7, None),
     ColumnSpec::new(ColumnKind::Url, 9, Some(TaskKind::UrlStatus)),
+    ColumnSpec::new(ColumnKind::Activity, 14, Some(TaskKind::Activity)),
     ColumnSpec::new(ColumnKind::Commit, 11, None),
     ColumnSpec::new(ColumnKind::Time, 12, None),
     ColumnSpec::new(ColumnKind::Message, 13, None),
@@ -124,11 +194,13 @@ mod tests {
             ColumnKind::WorkingDiff,
             ColumnKind::AheadBehind,
             ColumnKind::BranchDiff,
+            ColumnKind::Unmerged,
             ColumnKind::Summary,
             ColumnKind::Upstream,
             ColumnKind::CiStatus,
             ColumnKind::Path,
             ColumnKind::Url,
+            ColumnKind::Activity,
             ColumnKind::Commit,
             ColumnKind::Time,
             ColumnKind::Message,
@@ -144,6 +216,12 @@ mod tests {
             .unwrap();
         assert_eq!(branch_diff.requires_task, Some(TaskKind::BranchDiff));
 
+        let unmerged = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Unmerged)
+            .unwrap();
+        assert_eq!(unmerged.requires_task, Some(TaskKind::UnmergedCommits));
+
         let url = COLUMN_SPECS
             .iter()
             .find(|c| c.kind == ColumnKind::Url)
@@ -162,12 +240,20 @@ mod tests {
             .unwrap();
         assert_eq!(summary.requires_task, Some(TaskKind::SummaryGenerate));
 
+        let activity = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Activity)
+            .unwrap();
+        assert_eq!(activity.requires_task, Some(TaskKind::Activity));
+
         // All other columns should not require a background task to render
         for spec in COLUMN_SPECS {
             if spec.kind != ColumnKind::BranchDiff
+                && spec.kind != ColumnKind::Unmerged
                 && spec.kind != ColumnKind::Url
                 && spec.kind != ColumnKind::CiStatus
                 && spec.kind != ColumnKind::Summary
+                && spec.kind != ColumnKind::Activity
             {
                 assert!(
                     spec.requires_task.is_none(),
@@ -204,6 +290,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_header_against() {
+        assert_eq!(ColumnKind::AheadBehind.header_against(None), "main↕");
+        assert_eq!(
+            ColumnKind::AheadBehind.header_against(Some("release/2.4")),
+            "release/2.4↕"
+        );
+        assert_eq!(
+            ColumnKind::BranchDiff.header_against(Some("release/2.4")),
+            "release/2.4…±"
+        );
+        // Long refs keep their tail
+        assert_eq!(
+            ColumnKind::AheadBehind.header_against(Some("origin/release/2025.10")),
+            "…ase/2025.10↕"
+        );
+        // Other columns are unaffected
+        assert_eq!(ColumnKind::Branch.header_against(Some("v1")), "Branch");
+    }
+
     #[test]
     fn test_all_column_kinds_have_priority() {
         // Every ColumnKind variant must be in COLUMN_SPECS so priority() works correctly.
@@ -215,9 +321,11 @@ mod tests {
             ColumnKind::WorkingDiff,
             ColumnKind::AheadBehind,
             ColumnKind::BranchDiff,
+            ColumnKind::Unmerged,
             ColumnKind::Path,
             ColumnKind::Upstream,
             ColumnKind::Url,
+            ColumnKind::Activity,
             ColumnKind::CiStatus,
             ColumnKind::Commit,
             ColumnKind::Time,
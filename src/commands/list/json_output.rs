@@ -9,7 +9,7 @@
 //!
 //! Fields are organized by concept, matching the status display subcolumns:
 //! - `working_tree`: staged/modified/untracked changes
-//! - `main_state`: relationship to the default branch (would_conflict, same_commit, integrated, diverged, ahead, behind)
+//! - `main_state`: relationship to the default branch (would_conflict, same_commit, integrated, squash_merged, diverged, ahead, behind)
 //! - `operation_state`: git operations in progress (conflicts, rebase, merge)
 //! - `main`: relationship to the default branch (ahead/behind/diff counts)
 //! - `remote`: relationship to tracking branch
@@ -22,6 +22,7 @@ use serde::Serialize;
 use worktrunk::git::LineDiff;
 
 use super::ci_status::{CiSource, PrStatus};
+use super::collect::TaskKind;
 use super::model::{ItemKind, ListItem, UpstreamStatus};
 
 /// JSON output for a single list item
@@ -30,8 +31,16 @@ pub struct JsonItem {
     /// Branch name, null for detached HEAD
     pub branch: Option<String>,
 
-    /// Filesystem path to the worktree
+    /// Base64 of the branch name's bytes, present only when they aren't valid
+    /// UTF-8 (`branch` then has U+FFFD in their place)
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_raw: Option<String>,
+
+    /// Filesystem path to the worktree
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_path_lossy"
+    )]
     pub path: Option<PathBuf>,
 
     /// Item kind: "worktree" or "branch"
@@ -44,7 +53,7 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_tree: Option<JsonWorkingTree>,
 
-    /// Default branch relationship: would_conflict, same_commit, integrated, diverged, ahead, behind
+    /// Default branch relationship: would_conflict, same_commit, integrated, squash_merged, diverged, ahead, behind
     /// (null for default branch itself)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub main_state: Option<&'static str>,
@@ -79,6 +88,15 @@ pub struct JsonItem {
     /// This was the previous worktree (from `worktrunk.history`)
     pub is_previous: bool,
 
+    /// Hidden by `wt hide` (only listed with `--hidden`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_hidden: bool,
+
+    /// Branch this one is stacked on, recorded by `wt parent` or
+    /// `wt switch --base` (absent when none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+
     /// CI status from PR or branch workflow
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ci: Option<JsonCi>,
@@ -102,6 +120,11 @@ pub struct JsonItem {
     /// Raw status symbols without ANSI colors (e.g., "+! ✖ ↑")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symbols: Option<String>,
+
+    /// Fields that `--timeout` stopped gathering before they were complete.
+    /// They are absent or hold partial values; they are not null or zero.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub incomplete: Vec<&'static str>,
 }
 
 /// Commit information
@@ -168,9 +191,34 @@ pub struct JsonMain {
     /// Commits behind default branch
     pub behind: usize,
 
+    /// Counts stop at a shallow clone's boundary, so they're minimums
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub lower_bound: bool,
+
+    /// Commits whose changes the default branch doesn't have yet: like
+    /// `ahead`, but without merge commits and cherry-picked patches
+    /// (`--full` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmerged: Option<usize>,
+
     /// Lines added/deleted vs default branch
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff: Option<JsonDiff>,
+
+    /// Commit the diff was taken from (merge-base, or default branch tip
+    /// with `--branch-diff-base=head`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff_base: Option<String>,
+
+    /// Files that would conflict when merging into the default branch
+    /// (absent when the merge is clean)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflicted_files: Option<usize>,
+
+    /// Ref the counts and diff are taken against, when not the default
+    /// branch (`--against`, or the item's `parent`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub against: Option<String>,
 }
 
 /// Relationship to remote tracking branch
@@ -192,7 +240,7 @@ pub struct JsonRemote {
 /// Worktree-specific state
 #[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct JsonWorktree {
-    /// Worktree state: "branch_worktree_mismatch", "prunable", "locked" (absent when normal)
+    /// Worktree state: "branch_worktree_mismatch", "prunable", "locked", "branch_deleted" (absent when normal)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<&'static str>,
 
@@ -219,6 +267,24 @@ pub struct JsonCi {
     /// URL to the PR/MR (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+
+    /// True if the PR was merged (absent otherwise)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub merged: bool,
+
+    /// Failing and pending checks, failures first (GitHub only; absent when none)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<JsonCiCheck>,
+}
+
+/// A named check that failed or is still running
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JsonCiCheck {
+    /// Check name, e.g. "build-linux"
+    pub name: String,
+
+    /// "failed" or "running"
+    pub status: &'static str,
 }
 
 impl JsonItem {
@@ -284,14 +350,19 @@ impl JsonItem {
             .as_ref()
             .and_then(|symbols| symbols.operation_state.as_json_str());
 
-        // Main relationship (absent when is_main)
-        let main = if is_main {
+        // Main relationship (absent for the comparison base itself)
+        let main = if item.is_compare_base() {
             None
         } else {
             item.counts.map(|counts| JsonMain {
                 ahead: counts.ahead,
                 behind: counts.behind,
-                diff: item.branch_diff.map(|bd| JsonDiff::from(bd.diff)),
+                lower_bound: counts.lower_bound,
+                unmerged: item.unmerged_commits,
+                diff: item.branch_diff.as_ref().map(|bd| JsonDiff::from(bd.diff)),
+                diff_base: item.branch_diff.as_ref().and_then(|bd| bd.base.clone()),
+                conflicted_files: item.conflicted_files,
+                against: None,
             })
         };
 
@@ -332,8 +403,19 @@ impl JsonItem {
         // Summary: flatten Option<Option<String>> → Option<String>
         let summary = item.summary.as_ref().and_then(|s| s.clone());
 
+        let mut incomplete: Vec<&'static str> = item
+            .incomplete
+            .iter()
+            .filter_map(|&kind| incomplete_field(kind))
+            .collect();
+        incomplete.sort_unstable();
+        incomplete.dedup();
+
         JsonItem {
             branch: item.branch.clone(),
+            branch_raw: worktree_data
+                .and_then(|data| data.branch_raw.as_deref())
+                .map(base64_encode),
             path,
             kind: kind_str,
             commit,
@@ -347,16 +429,42 @@ impl JsonItem {
             is_main,
             is_current,
             is_previous,
+            is_hidden: item.hidden,
+            parent: item.parent.clone(),
             ci,
             url: item.url.clone(),
             url_active: item.url_active,
             summary,
             statusline,
             symbols,
+            incomplete,
         }
     }
 }
 
+/// The JSON field a task fills, named in `incomplete` when `--timeout` cuts
+/// the task short. The activity log isn't in JSON output.
+fn incomplete_field(kind: TaskKind) -> Option<&'static str> {
+    Some(match kind {
+        TaskKind::CommitDetails => "commit",
+        TaskKind::WorkingTreeDiff => "working_tree",
+        TaskKind::AheadBehind | TaskKind::BranchDiff | TaskKind::UnmergedCommits => "main",
+        TaskKind::IsAncestor
+        | TaskKind::CommittedTreesMatch
+        | TaskKind::HasFileChanges
+        | TaskKind::WouldMergeAdd
+        | TaskKind::SquashMerged
+        | TaskKind::MergeTreeConflicts => "main_state",
+        TaskKind::WorkingTreeConflicts | TaskKind::GitOperation => "operation_state",
+        TaskKind::UserMarker => "symbols",
+        TaskKind::Upstream => "remote",
+        TaskKind::CiStatus => "ci",
+        TaskKind::UrlStatus => "url_active",
+        TaskKind::SummaryGenerate => "summary",
+        TaskKind::Activity => return None,
+    })
+}
+
 /// Convert UpstreamStatus to JsonRemote
 fn upstream_to_json(upstream: &UpstreamStatus, branch: &Option<String>) -> Option<JsonRemote> {
     upstream.active().map(|active| {
@@ -378,6 +486,10 @@ fn worktree_state_to_json(
 ) -> (Option<&'static str>, Option<String>) {
     use super::model::WorktreeState;
 
+    if data.branch_deleted {
+        return (Some("branch_deleted"), None);
+    }
+
     // Check status symbols for worktree state
     if let Some(symbols) = status_symbols {
         match symbols.worktree_state {
@@ -410,6 +522,15 @@ impl From<&PrStatus> for JsonCi {
             source: pr.source,
             stale: pr.is_stale,
             url: pr.url.clone(),
+            merged: pr.merged,
+            checks: pr
+                .checks
+                .iter()
+                .map(|check| JsonCiCheck {
+                    name: check.name.clone(),
+                    status: check.status.into(),
+                })
+                .collect(),
         }
     }
 }
@@ -455,9 +576,97 @@ fn format_raw_symbols(symbols: &super::model::StatusSymbols) -> String {
     result
 }
 
+/// JSON output for `wt list --format=json --summary`
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JsonListWithSummary {
+    /// The listed worktrees and branches
+    pub items: Vec<JsonItem>,
+    /// Totals across `items`
+    pub summary: JsonSummary,
+}
+
+/// Totals across listed items, matching the table footer
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JsonSummary {
+    /// Number of worktrees
+    pub worktrees: usize,
+    /// Number of local branches without a worktree
+    pub branches: usize,
+    /// Number of remote branches
+    pub remote_branches: usize,
+    /// Worktrees with uncommitted changes (including untracked files)
+    pub dirty: usize,
+    /// Items with commits ahead of the default branch
+    pub ahead: usize,
+    /// Items with commits behind the default branch
+    pub behind: usize,
+}
+
+impl JsonSummary {
+    pub fn from_items(items: &[ListItem]) -> Self {
+        let metrics = super::SummaryMetrics::from_items(items);
+        Self {
+            worktrees: metrics.worktrees,
+            branches: metrics.local_branches,
+            remote_branches: metrics.remote_branches,
+            dirty: metrics.dirty_worktrees,
+            ahead: metrics.ahead_items,
+            behind: metrics.behind_items,
+        }
+    }
+}
+
 /// Convert a list of ListItems to JSON output
-pub fn to_json_items(items: &[ListItem]) -> Vec<JsonItem> {
-    items.iter().map(JsonItem::from_list_item).collect()
+///
+/// `compare_ref` is recorded on each `main` object when counts were taken
+/// against something other than the default branch; without one, a stacked
+/// item's counts were taken against its parent.
+pub fn to_json_items(items: &[ListItem], compare_ref: Option<&str>) -> Vec<JsonItem> {
+    items
+        .iter()
+        .map(|item| {
+            let mut json = JsonItem::from_list_item(item);
+            if let Some(main) = &mut json.main {
+                main.against = compare_ref
+                    .map(str::to_string)
+                    .or_else(|| item.parent.clone());
+            }
+            json
+        })
+        .collect()
+}
+
+/// Serialize a path as a string, replacing non-UTF-8 bytes rather than failing.
+fn serialize_path_lossy<S: serde::Serializer>(
+    path: &Option<PathBuf>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match path {
+        Some(path) => serializer.serialize_str(&path.to_string_lossy()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Standard base64 with padding, for raw bytes that aren't valid UTF-8.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for (i, shift) in [18, 12, 6, 0].into_iter().enumerate() {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((n >> shift) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
 }
 
 #[cfg(test)]
@@ -465,7 +674,7 @@ mod tests {
     use insta::assert_snapshot;
 
     use super::*;
-    use crate::commands::list::ci_status::CiStatus;
+    use crate::commands::list::ci_status::{CiCheck, CiStatus};
     use crate::commands::list::model::{
         ActiveGitOperation, Divergence, MainState, OperationState, StatusSymbols,
         WorkingTreeStatus, WorktreeData, WorktreeState,
@@ -504,6 +713,8 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: Some("https://github.com/org/repo/pull/123".to_string()),
+            merged: false,
+            checks: Vec::new(),
         });
         assert_eq!(passed.status, "passed");
         assert_eq!(passed.source, CiSource::PullRequest);
@@ -519,11 +730,51 @@ mod tests {
             source: CiSource::Branch,
             is_stale: true,
             url: None,
+            merged: false,
+            checks: Vec::new(),
         });
         assert_eq!(failed.status, "failed");
         assert_eq!(failed.source, CiSource::Branch);
         assert!(failed.stale);
         assert!(failed.url.is_none());
+        assert!(!failed.merged);
+
+        // Merged PR
+        let merged = JsonCi::from(&PrStatus {
+            ci_status: CiStatus::Passed,
+            source: CiSource::PullRequest,
+            is_stale: false,
+            url: None,
+            merged: true,
+            checks: Vec::new(),
+        });
+        assert!(merged.merged);
+        assert!(merged.checks.is_empty());
+
+        // Unfinished checks carry through in order
+        let with_checks = JsonCi::from(&PrStatus {
+            ci_status: CiStatus::Running,
+            source: CiSource::PullRequest,
+            is_stale: false,
+            url: None,
+            merged: false,
+            checks: vec![
+                CiCheck {
+                    name: "lint".to_string(),
+                    status: CiStatus::Failed,
+                },
+                CiCheck {
+                    name: "e2e".to_string(),
+                    status: CiStatus::Running,
+                },
+            ],
+        });
+        let checks: Vec<_> = with_checks
+            .checks
+            .iter()
+            .map(|c| (c.name.as_str(), c.status))
+            .collect();
+        assert_eq!(checks, [("lint", "failed"), ("e2e", "running")]);
 
         // All status string mappings
         let status_mappings = [
@@ -538,6 +789,8 @@ mod tests {
                 source: CiSource::Branch,
                 is_stale: false,
                 url: None,
+                merged: false,
+                checks: Vec::new(),
             });
             assert_eq!(json.status, expected);
         }
@@ -601,12 +854,16 @@ mod tests {
             is_current: false,
             is_previous: false,
             detached: false,
+            tag: None,
             locked: None,
             prunable: None,
             working_tree_diff: None,
             git_operation: ActiveGitOperation::None,
             branch_worktree_mismatch: false,
             working_diff_display: None,
+            branch_deleted: false,
+            branch_raw: None,
+            activity: Vec::new(),
         }
     }
 
@@ -800,20 +1057,29 @@ mod tests {
         let main = serde_json::to_string_pretty(&JsonMain {
             ahead: 3,
             behind: 1,
+            lower_bound: false,
+            unmerged: Some(2),
             diff: Some(JsonDiff {
                 added: 50,
                 deleted: 20,
             }),
+            diff_base: Some("abc123def456".to_string()),
+            conflicted_files: Some(2),
+            against: Some("release/2.4".to_string()),
         })
         .unwrap();
         assert_snapshot!(main, @r#"
         {
           "ahead": 3,
           "behind": 1,
+          "unmerged": 2,
           "diff": {
             "added": 50,
             "deleted": 20
-          }
+          },
+          "diff_base": "abc123def456",
+          "conflicted_files": 2,
+          "against": "release/2.4"
         }
         "#);
 
@@ -873,6 +1139,8 @@ mod tests {
             source: CiSource::PullRequest,
             stale: false,
             url: Some("https://example.com".to_string()),
+            merged: false,
+            checks: Vec::new(),
         })
         .unwrap();
         assert_snapshot!(json, @r#"
@@ -884,4 +1152,13 @@ mod tests {
         }
         "#);
     }
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"caf\xe9"), "Y2Fm6Q==");
+    }
 }
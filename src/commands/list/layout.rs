@@ -96,7 +96,7 @@
 //!
 //! Exceptions that we can compute instantly from items:
 //! - `path`: true only if any worktree has `branch_worktree_mismatch` (computed from items)
-//! - `branch_diff`/`ci_status`: false if their required task is skipped
+//! - `branch_diff`/`unmerged`/`ci_status`: false if their required task is skipped
 //!
 //! Other columns (status, working_diff, ahead_behind, upstream) require expensive git operations,
 //! so we assume they have data until proven otherwise.
@@ -105,12 +105,12 @@
 //!
 //! Some columns have non-standard behavior that extends beyond the basic two-tier model:
 //!
-//! 1. **BranchDiff** and **CiStatus** - Visibility gate (`show_full` flag)
-//!    - Both require `show_full=true` (hidden by default as too noisy for typical usage)
+//! 1. **BranchDiff**, **Unmerged** and **CiStatus** - Visibility gate (`show_full` flag)
+//!    - All require `show_full=true` (hidden by default as too noisy for typical usage)
 //!    - Gated via `skip_tasks`: when `show_full=false`, their `TaskKind` is in `skip_tasks`
 //!      and the column is filtered out entirely (bypasses the tier system)
 //!    - Within the visibility gate, follows normal two-tier priority
-//!      (BranchDiff: 6/16, CiStatus: 5/15)
+//!      (BranchDiff: 6/16, CiStatus: 5/15, Unmerged: 15/25)
 //!
 //! 2. **Summary** - Flexible sizing with post-allocation expansion
 //!    - Allocated at priority 10 with minimum width 10
@@ -123,6 +123,13 @@
 //!      all flexible space and Message is dropped (its space reclaimed for Summary)
 //!    - After Summary expansion, expands up to max 100 using remaining leftover space
 //!
+//! 4. **`layout = "shrink"`** - Truncate before dropping
+//!    - Branch (12), Path (15), URL and Activity (10) are reserved at their minimum
+//!      width, so a lower-priority column is only dropped if it doesn't fit even then
+//!    - Leftover space goes back to them in proportion to how much each was cut
+//!    - Summary and Message only get space the truncated columns don't need
+//!    - The default, `layout = "drop"`, reserves every column at its ideal width
+//!
 //! ## Implementation
 //!
 //! The code implements this using a centralized registry and priority-based allocation:
@@ -182,7 +189,8 @@ use std::path::{Path, PathBuf};
 
 use anstyle::Style;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{ADDITION, DELETION, Stream, supports_hyperlinks};
+use worktrunk::config::{ListConfig, ListDensity, ListLayout};
+use worktrunk::styling::{ADDITION, DELETION, Stream, supports_hyperlinks, theme};
 
 use crate::display::{get_terminal_width, shorten_path};
 
@@ -195,6 +203,10 @@ pub use super::columns::DiffVariant;
 /// Width of short commit hash display (first 8 hex characters)
 const COMMIT_HASH_WIDTH: usize = 8;
 
+/// Width budget for text columns in unaligned (`--no-align`) output, where
+/// cells aren't padded, so this only bounds truncation
+const UNALIGNED_TEXT_WIDTH: usize = 256;
+
 /// Ensures a column width is at least as wide as its header.
 ///
 /// This is the general solution for preventing header overflow: pass the header
@@ -248,6 +260,8 @@ pub struct ColumnWidths {
     pub status: usize, // Includes both git status symbols and user-defined status
     pub time: usize,
     pub url: usize,
+    pub activity: usize,
+    pub unmerged: usize,
     pub ci_status: usize,
     pub ahead_behind: DiffWidths,
     pub working_diff: DiffWidths,
@@ -264,6 +278,8 @@ pub struct ColumnDataFlags {
     pub branch_diff: bool,
     pub upstream: bool,
     pub url: bool,
+    pub activity: bool,
+    pub unmerged: bool,
     pub ci_status: bool,
     pub path: bool, // True if any worktree has branch_worktree_mismatch
 }
@@ -287,6 +303,19 @@ pub struct DiffDisplayConfig {
 }
 
 impl DiffDisplayConfig {
+    /// Style for the negative value.
+    ///
+    /// Commit counts behind the default branch escalate to the theme's warn and
+    /// critical styles past their thresholds; everything else uses `negative_style`.
+    pub fn negative_style_for(&self, negative: usize) -> Style {
+        match self.variant {
+            DiffVariant::Arrows => theme()
+                .escalated_behind(negative)
+                .unwrap_or(self.negative_style),
+            DiffVariant::Signs | DiffVariant::UpstreamArrows => self.negative_style,
+        }
+    }
+
     /// Format diff values with fixed-width alignment for tabular display.
     ///
     /// Numbers are right-aligned within a 3-digit column width.
@@ -331,12 +360,13 @@ impl DiffDisplayConfig {
         }
 
         if negative > 0 || self.always_show_zeros {
+            let negative_style = self.negative_style_for(negative);
             parts.push(format!(
                 "{}{}{}{}",
-                self.negative_style,
+                negative_style,
                 symbols.negative,
                 negative,
-                self.negative_style.render_reset()
+                negative_style.render_reset()
             ));
         }
 
@@ -378,14 +408,14 @@ impl ColumnKind {
         match self {
             ColumnKind::WorkingDiff | ColumnKind::BranchDiff => Some(DiffDisplayConfig {
                 variant: DiffVariant::Signs,
-                positive_style: ADDITION,
-                negative_style: DELETION,
+                positive_style: theme().added,
+                negative_style: theme().deleted,
                 always_show_zeros: false,
             }),
             ColumnKind::AheadBehind => Some(DiffDisplayConfig {
                 variant: DiffVariant::Arrows,
-                positive_style: ADDITION,
-                negative_style: DELETION.dimmed(),
+                positive_style: theme().ahead,
+                negative_style: theme().behind,
                 always_show_zeros: false,
             }),
             ColumnKind::Upstream => Some(DiffDisplayConfig {
@@ -412,9 +442,11 @@ impl ColumnKind {
             ColumnKind::WorkingDiff => flags.working_diff,
             ColumnKind::AheadBehind => flags.ahead_behind,
             ColumnKind::BranchDiff => flags.branch_diff,
+            ColumnKind::Unmerged => flags.unmerged,
             ColumnKind::Path => flags.path,
             ColumnKind::Upstream => flags.upstream,
             ColumnKind::Url => flags.url,
+            ColumnKind::Activity => flags.activity,
             ColumnKind::Time => true,
             ColumnKind::CiStatus => flags.ci_status,
             ColumnKind::Commit => true,
@@ -454,6 +486,8 @@ impl ColumnKind {
             ColumnKind::Path => text(max_path_width),
             ColumnKind::Time => text(widths.time),
             ColumnKind::Url => text(widths.url),
+            ColumnKind::Activity => text(widths.activity),
+            ColumnKind::Unmerged => text(widths.unmerged),
             ColumnKind::CiStatus => text(widths.ci_status),
             ColumnKind::Commit => text(commit_width),
             ColumnKind::Summary => None, // Flexible: handled specially in allocation loop
@@ -464,6 +498,19 @@ impl ColumnKind {
             ColumnKind::Upstream => diff(widths.upstream),
         }
     }
+
+    /// Narrowest width this column may be truncated to under `layout = "shrink"`.
+    ///
+    /// None for columns that only ever show at their ideal width: symbols,
+    /// numbers, and hashes can't be cut without losing their meaning.
+    fn min_width(self) -> Option<usize> {
+        match self {
+            ColumnKind::Branch => Some(12),
+            ColumnKind::Path => Some(15),
+            ColumnKind::Url | ColumnKind::Activity => Some(10),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -483,7 +530,7 @@ pub struct DiffColumnConfig {
 #[derive(Clone, Debug)]
 pub struct ColumnLayout {
     pub kind: ColumnKind,
-    pub header: &'static str,
+    pub header: String,
     pub start: usize,
     pub width: usize,
     pub format: ColumnFormat,
@@ -508,9 +555,48 @@ struct ColumnCandidate<'a> {
 struct PendingColumn<'a> {
     spec: &'a ColumnSpec,
     width: usize,
+    /// Width the column would like; above `width` only when it was reserved
+    /// at its minimum (`layout = "shrink"`)
+    ideal: usize,
     format: ColumnFormat,
 }
 
+/// Grow columns reserved at their minimum width back toward their ideal.
+///
+/// When `remaining` covers every shortfall, each column gets its ideal width.
+/// Otherwise the space is shared in proportion to each column's shortfall, so
+/// the columns are truncated alike; rounding leftovers go one at a time to the
+/// highest-priority columns (`pending` is still in priority order).
+fn grow_toward_ideal(pending: &mut [PendingColumn], remaining: &mut usize) {
+    let shortfall: usize = pending.iter().map(|col| col.ideal - col.width).sum();
+    if shortfall == 0 {
+        return;
+    }
+    if shortfall <= *remaining {
+        for col in pending.iter_mut() {
+            col.width = col.ideal;
+        }
+        *remaining -= shortfall;
+        return;
+    }
+
+    let budget = *remaining;
+    for col in pending.iter_mut() {
+        let share = budget * (col.ideal - col.width) / shortfall;
+        col.width += share;
+        *remaining -= share;
+    }
+    for col in pending.iter_mut() {
+        if *remaining == 0 {
+            break;
+        }
+        if col.width < col.ideal {
+            col.width += 1;
+            *remaining -= 1;
+        }
+    }
+}
+
 /// Estimate URL column width using heuristics.
 ///
 /// When hyperlinks are supported, URLs display as `:PORT` (6 chars for 5-digit ports).
@@ -550,6 +636,7 @@ fn build_estimated_widths(
     skip_tasks: &HashSet<TaskKind>,
     has_branch_worktree_mismatch: bool,
     url_width: usize,
+    compare_ref: Option<&str>,
 ) -> LayoutMetadata {
     // Fixed widths for slow columns (require expensive git operations)
     // Values exceeding these widths use compact notation (K suffix)
@@ -558,11 +645,13 @@ fn build_estimated_widths(
     // PositionMask::FULL allocates: 1+1+1+1+1+1+2 = 8 chars (7 positions)
     let status_fixed = fit_header(ColumnKind::Status.header(), 8);
     let working_diff_fixed = fit_header(ColumnKind::WorkingDiff.header(), 9); // "+999 -999"
-    let ahead_behind_fixed = fit_header(ColumnKind::AheadBehind.header(), 7); // "↑99 ↓99"
-    let branch_diff_fixed = fit_header(ColumnKind::BranchDiff.header(), 9); // "+999 -999"
+    let ahead_behind_fixed = fit_header(&ColumnKind::AheadBehind.header_against(compare_ref), 7); // "↑99 ↓99"
+    let branch_diff_fixed = fit_header(&ColumnKind::BranchDiff.header_against(compare_ref), 9); // "+999 -999"
     let upstream_fixed = fit_header(ColumnKind::Upstream.header(), 7); // "↑99 ↓99"
     let age_estimate = 4; // "11mo" (short format)
     let ci_estimate = fit_header(ColumnKind::CiStatus.header(), 1); // Single indicator symbol
+    let activity_estimate = 20; // Last command, truncated
+    let unmerged_fixed = fit_header(ColumnKind::Unmerged.header(), 3); // "999"
 
     // Assume columns will have data (better to show and hide than to not show).
     // This is a limitation of progressive mode - we can't know which columns have data
@@ -578,6 +667,8 @@ fn build_estimated_widths(
         branch_diff: !skip_tasks.contains(&TaskKind::BranchDiff),
         upstream: true,
         url: !skip_tasks.contains(&TaskKind::UrlStatus),
+        activity: !skip_tasks.contains(&TaskKind::Activity),
+        unmerged: !skip_tasks.contains(&TaskKind::UnmergedCommits),
         ci_status: !skip_tasks.contains(&TaskKind::CiStatus),
         path: has_branch_worktree_mismatch,
     };
@@ -595,6 +686,8 @@ fn build_estimated_widths(
         status: status_fixed,
         time: age_estimate,
         url: url_estimate,
+        activity: activity_estimate,
+        unmerged: unmerged_fixed,
         ci_status: ci_estimate,
         // Commit counts (Arrows): compact notation, 2 digits covers up to 99
         ahead_behind: DiffWidths {
@@ -628,10 +721,84 @@ fn build_estimated_widths(
     }
 }
 
+/// Column facts measured from the items themselves, gathered in one pass.
+///
+/// Only cheap, already-known data is measured here; expensive columns use the
+/// fixed estimates from [`build_estimated_widths`]. A new data-driven column
+/// gets a field, a line in [`ColumnStats::observe`], and its use in
+/// [`ColumnStats::finish`].
+#[derive(Debug)]
+struct ColumnStats<'a> {
+    main_worktree_path: &'a Path,
+    /// Widest Branch label (branch name or `⌂ <tag>`)
+    branch: usize,
+    /// Widest worktree path, shortened relative to the main worktree
+    path: usize,
+    /// Any worktree sits at a path that doesn't match its branch
+    branch_worktree_mismatch: bool,
+}
+
+impl<'a> ColumnStats<'a> {
+    fn new(main_worktree_path: &'a Path) -> Self {
+        Self {
+            main_worktree_path,
+            branch: 0,
+            path: 0,
+            branch_worktree_mismatch: false,
+        }
+    }
+
+    fn observe(&mut self, item: &super::model::ListItem) {
+        // Includes standalone branches, which have a label but no worktree
+        if let Some(label) = item.branch_label() {
+            self.branch = self.branch.max(label.width());
+        }
+        if let Some(data) = item.worktree_data() {
+            let path = shorten_path(data.path.as_path(), self.main_worktree_path);
+            self.path = self.path.max(path.width());
+            self.branch_worktree_mismatch |= data.branch_worktree_mismatch;
+        }
+    }
+
+    /// Path column width. `cap` budgets the data width so unrelated worktree
+    /// roots don't crowd out other columns.
+    fn path_width(&self, cap: Option<usize>) -> usize {
+        let data_width = cap.map_or(self.path, |cap| self.path.min(cap));
+        fit_header(ColumnKind::Path.header(), data_width)
+    }
+
+    /// Combine the measurements with the fixed estimates for everything else.
+    fn finish(
+        self,
+        skip_tasks: &HashSet<TaskKind>,
+        url_width: usize,
+        compare_ref: Option<&str>,
+    ) -> LayoutMetadata {
+        // Path column is only useful when there's a mismatch; otherwise it's
+        // redundant with branch
+        build_estimated_widths(
+            fit_header(ColumnKind::Branch.header(), self.branch),
+            skip_tasks,
+            self.branch_worktree_mismatch,
+            url_width,
+            compare_ref,
+        )
+    }
+}
+
 /// Allocate columns using priority-based allocation logic.
 ///
 /// This is the core allocation algorithm used by `calculate_layout_from_basics()`
 /// with pre-allocated width estimates for expensive-to-compute columns.
+///
+/// With [`ListLayout::Shrink`], columns with a [`ColumnKind::min_width`] are
+/// reserved at that minimum, so a column is only dropped when it doesn't fit
+/// even with the others truncated; [`grow_toward_ideal`] then hands back
+/// whatever space is left.
+///
+/// `density` sets the gap between columns; [`ListDensity::Compact`] also caps
+/// Message lower, leaving more room for the columns after it.
+#[allow(clippy::too_many_arguments)]
 fn allocate_columns_with_priority(
     metadata: &LayoutMetadata,
     skip_tasks: &HashSet<TaskKind>,
@@ -639,8 +806,11 @@ fn allocate_columns_with_priority(
     commit_width: usize,
     terminal_width: usize,
     main_worktree_path: PathBuf,
+    compare_ref: Option<&str>,
+    list_layout: ListLayout,
+    density: ListDensity,
 ) -> LayoutConfig {
-    let spacing = 2;
+    let spacing = density.gap();
     let mut remaining = terminal_width;
 
     // Build candidates with priorities
@@ -673,6 +843,7 @@ fn allocate_columns_with_priority(
     const MAX_SUMMARY: usize = 70;
     const MIN_MESSAGE: usize = 10;
     const MAX_MESSAGE: usize = 100;
+    const MAX_MESSAGE_COMPACT: usize = 80;
     // Message is only shown when Summary reaches this width — below this,
     // Summary needs all the flexible space to be readable.
     const SUMMARY_THRESHOLD_FOR_MESSAGE: usize = 40;
@@ -702,13 +873,17 @@ fn allocate_columns_with_priority(
                 _ => MIN_MESSAGE,
             };
             let spacing_cost = if needs_spacing(&pending) { spacing } else { 0 };
+            // Under `layout = "shrink"`, only space the truncated data columns
+            // don't need is free; the flexible columns never cause truncation
+            let shortfall: usize = pending.iter().map(|col| col.ideal - col.width).sum();
             if remaining > spacing_cost {
                 let available = remaining - spacing_cost;
-                if available >= min_width {
+                if available >= min_width + shortfall {
                     remaining = remaining.saturating_sub(min_width + spacing_cost);
                     pending.push(PendingColumn {
                         spec,
                         width: min_width,
+                        ideal: min_width,
                         format: ColumnFormat::Text,
                     });
                 }
@@ -724,17 +899,29 @@ fn allocate_columns_with_priority(
             continue;
         };
 
+        let reserved = match list_layout {
+            ListLayout::Drop => ideal_width,
+            ListLayout::Shrink => spec
+                .kind
+                .min_width()
+                .map_or(ideal_width, |min| min.min(ideal_width)),
+        };
+
         let is_first = !needs_spacing(&pending);
-        let allocated = try_allocate(&mut remaining, ideal_width, spacing, is_first);
+        let allocated = try_allocate(&mut remaining, reserved, spacing, is_first);
         if allocated > 0 {
             pending.push(PendingColumn {
                 spec,
                 width: allocated,
+                ideal: ideal_width,
                 format,
             });
         }
     }
 
+    // Truncated columns take back their width before Summary and Message expand
+    grow_toward_ideal(&mut pending, &mut remaining);
+
     // Post-allocation expansion: Summary first, then Message with leftovers.
     // Message is only kept if Summary reaches SUMMARY_THRESHOLD_FOR_MESSAGE (40);
     // below that, Summary needs all the flexible space to be readable.
@@ -780,8 +967,12 @@ fn allocate_columns_with_priority(
         .iter_mut()
         .find(|col| col.spec.kind == ColumnKind::Message)
     {
-        if message_col.width < MAX_MESSAGE && remaining > 0 {
-            let expansion = remaining.min(MAX_MESSAGE - message_col.width);
+        let max_message = match density {
+            ListDensity::Compact => MAX_MESSAGE_COMPACT,
+            ListDensity::Normal | ListDensity::Wide => MAX_MESSAGE,
+        };
+        if message_col.width < max_message && remaining > 0 {
+            let expansion = remaining.min(max_message - message_col.width);
             message_col.width += expansion;
         }
         max_message_len = message_col.width;
@@ -791,7 +982,7 @@ fn allocate_columns_with_priority(
     pending.sort_by_key(|col| column_display_index(col.spec.kind));
 
     // Build final column layouts with positions
-    let gap = 2;
+    let gap = spacing;
     let mut position = 0;
     let mut columns = Vec::new();
 
@@ -814,7 +1005,7 @@ fn allocate_columns_with_priority(
 
         columns.push(ColumnLayout {
             kind: col.spec.kind,
-            header: col.spec.kind.header(),
+            header: col.spec.kind.header_against(compare_ref).into_owned(),
             start,
             width: col.width,
             format: col.format,
@@ -860,11 +1051,19 @@ fn allocate_columns_with_priority(
 /// - CI: 1 char (indicator symbol)
 /// - Message: flexible (20-100 chars)
 /// - URL: estimated from template + longest branch
+/// - Path: longest shortened path, capped at `max-path-width` (longer paths are
+///   ellipsized in the middle when rendered)
+///
+/// `list_config` also picks how columns give way when they don't all fit
+/// (`layout`) and how far apart they sit (`density`). `compare_ref` renames the default-branch column headers
+/// (`--against`).
 pub fn calculate_layout_from_basics(
     items: &[super::model::ListItem],
     skip_tasks: &HashSet<TaskKind>,
     main_worktree_path: &Path,
     url_template: Option<&str>,
+    list_config: &ListConfig,
+    compare_ref: Option<&str>,
 ) -> LayoutConfig {
     calculate_layout_with_width(
         items,
@@ -872,6 +1071,8 @@ pub fn calculate_layout_from_basics(
         get_terminal_width(),
         main_worktree_path,
         url_template,
+        list_config,
+        compare_ref,
     )
 }
 
@@ -882,42 +1083,20 @@ pub fn calculate_layout_with_width(
     terminal_width: usize,
     main_worktree_path: &Path,
     url_template: Option<&str>,
+    list_config: &ListConfig,
+    compare_ref: Option<&str>,
 ) -> LayoutConfig {
-    // Calculate actual widths for things we know
-    // Include branch names from both worktrees and standalone branches
-    let longest_branch = items
-        .iter()
-        .filter_map(|item| item.branch.as_deref())
-        .max_by_key(|b| b.width());
-
-    let max_branch = longest_branch.map(|b| b.width()).unwrap_or(0);
-    let max_branch = fit_header(ColumnKind::Branch.header(), max_branch);
-
-    let path_data_width = items
-        .iter()
-        .filter_map(|item| item.worktree_path())
-        .map(|path| shorten_path(path.as_path(), main_worktree_path).width())
-        .max()
-        .unwrap_or(0);
-    let max_path_width = fit_header(ColumnKind::Path.header(), path_data_width);
-
-    // Check if any worktree has a branch-worktree mismatch.
-    // Path column is only useful when there's a mismatch; otherwise it's redundant with branch.
-    let has_branch_worktree_mismatch = items
-        .iter()
-        .filter_map(|item| item.worktree_data())
-        .any(|data| data.branch_worktree_mismatch);
+    let mut stats = ColumnStats::new(main_worktree_path);
+    for item in items {
+        stats.observe(item);
+    }
+    let max_path_width = stats.path_width(list_config.max_path_width());
 
     // Estimate URL width from template (heuristic, no expansion needed)
     let url_width = estimate_url_width(url_template, supports_hyperlinks(Stream::Stdout));
 
     // Build pre-allocated width estimates (same as buffered mode)
-    let metadata = build_estimated_widths(
-        max_branch,
-        skip_tasks,
-        has_branch_worktree_mismatch,
-        url_width,
-    );
+    let metadata = stats.finish(skip_tasks, url_width, compare_ref);
 
     let commit_width = fit_header(ColumnKind::Commit.header(), COMMIT_HASH_WIDTH);
 
@@ -928,6 +1107,46 @@ pub fn calculate_layout_with_width(
         commit_width,
         terminal_width,
         main_worktree_path.to_path_buf(),
+        compare_ref,
+        list_config.layout(),
+        list_config.density(),
+    )
+}
+
+/// Calculate the layout for unaligned (`--no-align`) output.
+///
+/// Skips scanning items entirely: every column whose task runs is included,
+/// with text columns sized generously so cells are rarely truncated. Rows are
+/// rendered tab-separated via [`LayoutConfig::format_unaligned_line`].
+pub fn calculate_unaligned_layout(
+    skip_tasks: &HashSet<TaskKind>,
+    main_worktree_path: &Path,
+    url_template: Option<&str>,
+    compare_ref: Option<&str>,
+) -> LayoutConfig {
+    let url_width = if url_template.is_some() {
+        UNALIGNED_TEXT_WIDTH
+    } else {
+        0
+    };
+    let metadata = build_estimated_widths(
+        UNALIGNED_TEXT_WIDTH,
+        skip_tasks,
+        true,
+        url_width,
+        compare_ref,
+    );
+
+    allocate_columns_with_priority(
+        &metadata,
+        skip_tasks,
+        UNALIGNED_TEXT_WIDTH,
+        COMMIT_HASH_WIDTH,
+        usize::MAX / 2,
+        main_worktree_path.to_path_buf(),
+        compare_ref,
+        ListLayout::Drop,
+        ListDensity::Normal,
     )
 }
 
@@ -984,6 +1203,8 @@ mod tests {
             branch_diff: true,
             upstream: true,
             url: true,
+            activity: true,
+            unmerged: true,
             ci_status: true,
             path: true,
         };
@@ -994,6 +1215,8 @@ mod tests {
             branch_diff: false,
             upstream: false,
             url: false,
+            activity: false,
+            unmerged: false,
             ci_status: false,
             path: false,
         };
@@ -1018,6 +1241,10 @@ mod tests {
         assert!(!ColumnKind::Upstream.has_data(&all_false));
         assert!(ColumnKind::Url.has_data(&all_true));
         assert!(!ColumnKind::Url.has_data(&all_false));
+        assert!(ColumnKind::Activity.has_data(&all_true));
+        assert!(!ColumnKind::Activity.has_data(&all_false));
+        assert!(ColumnKind::Unmerged.has_data(&all_true));
+        assert!(!ColumnKind::Unmerged.has_data(&all_false));
         assert!(ColumnKind::CiStatus.has_data(&all_true));
         assert!(!ColumnKind::CiStatus.has_data(&all_false));
         assert!(ColumnKind::Path.has_data(&all_true));
@@ -1059,6 +1286,8 @@ mod tests {
             status: 8,
             time: 4,
             url: 0,
+            activity: 20,
+            unmerged: 8,
             ci_status: 2,
             ahead_behind: DiffWidths {
                 total: 7,
@@ -1126,6 +1355,8 @@ mod tests {
             status: 0,
             time: 0,
             url: 0,
+            activity: 0,
+            unmerged: 0,
             ci_status: 0,
             ahead_behind: DiffWidths {
                 total: 0,
@@ -1158,7 +1389,7 @@ mod tests {
         // Empty skip set means all tasks are computed (equivalent to --full)
         // has_branch_worktree_mismatch=true to test the path flag is passed through
         // url_width=0 since we're not testing URL column here
-        let metadata = build_estimated_widths(20, &HashSet::new(), true, 0);
+        let metadata = build_estimated_widths(20, &HashSet::new(), true, 0, None);
         let widths = metadata.widths;
 
         // Line diffs (Signs variant: +/-) allocate 3 digits for 100-999 range
@@ -1240,15 +1471,24 @@ mod tests {
             counts: Some(AheadBehind {
                 ahead: 5,
                 behind: 10,
+                ..Default::default()
             }),
             branch_diff: Some(BranchDiffTotals {
                 diff: LineDiff::from((200, 30)),
+                base: None,
             }),
+            unmerged_commits: None,
+            conflicted_files: None,
             committed_trees_match: Some(false),
             has_file_changes: Some(true),
             would_merge_add: None,
             is_ancestor: None,
+            squash_merged: None,
             is_orphan: None,
+            is_compare_base: false,
+            hidden: false,
+            parent: None,
+            incomplete: Default::default(),
             upstream: Some(UpstreamStatus {
                 remote: Some("origin".to_string()),
                 ahead: 4,
@@ -1263,6 +1503,7 @@ mod tests {
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test/path"),
                 detached: false,
+                tag: None,
                 locked: None,
                 prunable: None,
                 working_tree_diff: Some(LineDiff::from((100, 50))),
@@ -1272,6 +1513,9 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 working_diff_display: None,
+                branch_deleted: false,
+                branch_raw: None,
+                activity: Vec::new(),
             })),
         };
 
@@ -1280,7 +1524,14 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/test");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout = calculate_layout_from_basics(
+            &items,
+            &skip_tasks,
+            &main_worktree_path,
+            None,
+            &ListConfig::default(),
+            None,
+        );
 
         assert!(
             !layout.columns.is_empty(),
@@ -1343,15 +1594,24 @@ mod tests {
             counts: Some(AheadBehind {
                 ahead: 0,
                 behind: 0,
+                ..Default::default()
             }),
             branch_diff: Some(BranchDiffTotals {
                 diff: LineDiff::default(),
+                base: None,
             }),
+            unmerged_commits: None,
+            conflicted_files: None,
             committed_trees_match: Some(false),
             has_file_changes: Some(true),
             would_merge_add: None,
             is_ancestor: None,
+            squash_merged: None,
             is_orphan: None,
+            is_compare_base: true,
+            hidden: false,
+            parent: None,
+            incomplete: Default::default(),
             upstream: Some(UpstreamStatus::default()),
             pr_status: None,
             url: None,
@@ -1362,6 +1622,7 @@ mod tests {
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test"),
                 detached: false,
+                tag: None,
                 locked: None,
                 prunable: None,
                 working_tree_diff: Some(LineDiff::default()),
@@ -1371,6 +1632,9 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 working_diff_display: None,
+                branch_deleted: false,
+                branch_raw: None,
+                activity: Vec::new(),
             })),
         };
 
@@ -1379,7 +1643,14 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/home/user/project");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout = calculate_layout_from_basics(
+            &items,
+            &skip_tasks,
+            &main_worktree_path,
+            None,
+            &ListConfig::default(),
+            None,
+        );
 
         assert!(
             layout
@@ -1460,11 +1731,18 @@ mod tests {
             commit: None,
             counts: None,
             branch_diff: None,
+            unmerged_commits: None,
+            conflicted_files: None,
             committed_trees_match: None,
             has_file_changes: None,
             would_merge_add: None,
             is_ancestor: None,
+            squash_merged: None,
             is_orphan: None,
+            is_compare_base: false,
+            hidden: false,
+            parent: None,
+            incomplete: Default::default(),
             upstream: None,
             pr_status: None,
             url: None,
@@ -1475,6 +1753,7 @@ mod tests {
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test/wt"),
                 detached: false,
+                tag: None,
                 locked: None,
                 prunable: None,
                 working_tree_diff: None,
@@ -1484,6 +1763,9 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 working_diff_display: None,
+                branch_deleted: false,
+                branch_raw: None,
+                activity: Vec::new(),
             })),
         }
     }
@@ -1491,16 +1773,26 @@ mod tests {
     /// Helper: compute layout with explicit terminal width and skip_tasks.
     fn layout_at_width(width: usize, skip_tasks: &HashSet<TaskKind>) -> LayoutConfig {
         let items = vec![make_test_item("feature-branch")];
-        calculate_layout_with_width(&items, skip_tasks, width, Path::new("/test"), None)
+        calculate_layout_with_width(
+            &items,
+            skip_tasks,
+            width,
+            Path::new("/test"),
+            None,
+            &ListConfig::default(),
+            None,
+        )
     }
 
-    /// Default skip_tasks for non-full mode (Summary, BranchDiff, CI, WorkingTreeConflicts skipped).
+    /// Default skip_tasks for non-full mode (Summary, BranchDiff, Unmerged, CI, WorkingTreeConflicts skipped).
     fn non_full_skip_tasks() -> HashSet<TaskKind> {
         [
             TaskKind::BranchDiff,
+            TaskKind::UnmergedCommits,
             TaskKind::CiStatus,
             TaskKind::WorkingTreeConflicts,
             TaskKind::SummaryGenerate,
+            TaskKind::Activity,
         ]
         .into_iter()
         .collect()
@@ -1700,4 +1992,192 @@ mod tests {
             "Message should still appear"
         );
     }
+
+    #[test]
+    fn test_unaligned_layout_keeps_every_column() {
+        // Without a URL template, collection skips the URL task
+        let mut skip_tasks = non_full_skip_tasks();
+        skip_tasks.insert(TaskKind::UrlStatus);
+        let layout = calculate_unaligned_layout(&skip_tasks, Path::new("/test"), None, None);
+
+        assert_eq!(layout.hidden_column_count, 0);
+        // Path shows even without a branch-worktree mismatch
+        assert!(find_column(&layout, ColumnKind::Path).is_some());
+        assert!(find_column(&layout, ColumnKind::Message).is_some());
+        // Columns for skipped tasks or a missing URL template stay out
+        assert!(find_column(&layout, ColumnKind::BranchDiff).is_none());
+        assert!(find_column(&layout, ColumnKind::Url).is_none());
+
+        let item = make_test_item("feature-branch");
+        let line = layout.format_unaligned_line(&item);
+        assert_eq!(line.split('\t').count(), layout.columns.len());
+        assert!(line.contains("\tfeature-branch\t"), "{line:?}");
+    }
+
+    #[test]
+    fn test_grow_toward_ideal_shares_shortfall() {
+        let spec = |kind| COLUMN_SPECS.iter().find(|spec| spec.kind == kind).unwrap();
+        let column = |kind, width, ideal| PendingColumn {
+            spec: spec(kind),
+            width,
+            ideal,
+            format: ColumnFormat::Text,
+        };
+        let widths = |pending: &[PendingColumn]| -> Vec<usize> {
+            pending.iter().map(|col| col.width).collect()
+        };
+        let mut pending = [
+            column(ColumnKind::Branch, 12, 33),
+            column(ColumnKind::Status, 8, 8),
+            column(ColumnKind::Path, 15, 35),
+        ];
+
+        // Shortfalls of 21 and 20 split 14 columns as 7 + 6, the odd one to Branch
+        let mut remaining = 14;
+        grow_toward_ideal(&mut pending, &mut remaining);
+        assert_eq!(widths(&pending), [20, 8, 21]);
+        assert_eq!(remaining, 0);
+
+        // Enough space restores every ideal width and leaves the rest
+        let mut remaining = 30;
+        grow_toward_ideal(&mut pending, &mut remaining);
+        assert_eq!(widths(&pending), [33, 8, 35]);
+        assert_eq!(remaining, 3);
+    }
+
+    #[test]
+    fn test_shrink_layout_truncates_before_dropping() {
+        let mut item = make_test_item("billing-export-to-parquet-for-finance");
+        if let Some(data) = item.worktree_data_mut() {
+            data.path = PathBuf::from("/elsewhere/checkouts/billing-export");
+            data.branch_worktree_mismatch = true;
+        }
+        let layout_with = |layout| {
+            let list_config = ListConfig {
+                layout: Some(layout),
+                ..Default::default()
+            };
+            calculate_layout_with_width(
+                std::slice::from_ref(&item),
+                &non_full_skip_tasks(),
+                100,
+                Path::new("/test"),
+                None,
+                &list_config,
+                None,
+            )
+        };
+
+        // Drop: Branch at full width, Path hidden
+        let drop = layout_with(ListLayout::Drop);
+        assert_eq!(find_column(&drop, ColumnKind::Branch).unwrap().width, 37);
+        assert!(find_column(&drop, ColumnKind::Path).is_none());
+
+        // Shrink: both truncated, neither below its minimum; Message, being
+        // flexible, doesn't get in at their expense
+        let shrink = layout_with(ListLayout::Shrink);
+        let branch = find_column(&shrink, ColumnKind::Branch).unwrap().width;
+        let path = find_column(&shrink, ColumnKind::Path).unwrap().width;
+        assert!((12..37).contains(&branch), "{branch}");
+        assert!(path >= 15, "{path}");
+        assert!(find_column(&shrink, ColumnKind::Message).is_none());
+        assert!(shrink.hidden_column_count < drop.hidden_column_count);
+    }
+
+    #[test]
+    fn test_compact_density_narrows_gaps() {
+        let item = make_test_item("feature");
+        let layout_with = |density| {
+            let list_config = ListConfig {
+                density: Some(density),
+                ..Default::default()
+            };
+            calculate_layout_with_width(
+                std::slice::from_ref(&item),
+                &non_full_skip_tasks(),
+                200,
+                Path::new("/test"),
+                None,
+                &list_config,
+                None,
+            )
+        };
+
+        for (density, gap) in [
+            (ListDensity::Compact, 1),
+            (ListDensity::Normal, 2),
+            (ListDensity::Wide, 3),
+        ] {
+            let layout = layout_with(density);
+            for pair in layout.columns.windows(2) {
+                if pair[0].kind != ColumnKind::Gutter {
+                    assert_eq!(pair[1].start, pair[0].start + pair[0].width + gap);
+                }
+            }
+        }
+
+        // Compact also caps Message lower
+        let message = |density| {
+            find_column(&layout_with(density), ColumnKind::Message)
+                .unwrap()
+                .width
+        };
+        assert_eq!(message(ListDensity::Normal), 100);
+        assert_eq!(message(ListDensity::Compact), 80);
+    }
+
+    #[test]
+    fn test_column_stats_observe() {
+        use crate::commands::list::model::ListItem;
+
+        let main = Path::new("/repo");
+        let mut stats = ColumnStats::new(main);
+        // Nothing observed: headers set the minimum widths
+        assert_eq!(stats.path_width(None), "Path".len());
+
+        let mut worktree = make_test_item("feature");
+        if let Some(data) = worktree.worktree_data_mut() {
+            data.path = PathBuf::from("/repo.a-long-feature");
+        }
+        let branch = ListItem::new_branch("abc12345".into(), "a-much-longer-branch-name".into());
+        stats.observe(&worktree);
+        stats.observe(&branch);
+
+        // Branch width counts standalone branches; path width counts worktrees only
+        assert_eq!(stats.branch, "a-much-longer-branch-name".len());
+        let path_width = shorten_path(Path::new("/repo.a-long-feature"), main).len();
+        assert_eq!(stats.path, path_width);
+        assert_eq!(stats.path_width(None), path_width);
+        assert_eq!(stats.path_width(Some(8)), "Path".len().max(8));
+        assert!(!stats.branch_worktree_mismatch);
+
+        let mut mismatched = make_test_item("other");
+        if let Some(data) = mismatched.worktree_data_mut() {
+            data.branch_worktree_mismatch = true;
+        }
+        stats.observe(&mismatched);
+        assert!(stats.branch_worktree_mismatch);
+
+        let metadata = stats.finish(&HashSet::new(), 0, None);
+        assert_eq!(metadata.widths.branch, "a-much-longer-branch-name".len());
+        assert!(metadata.data_flags.path);
+        assert_eq!(metadata.widths.url, 0);
+    }
+
+    #[test]
+    fn test_column_stats_detached_tag_label() {
+        let mut item = make_test_item("unused");
+        item.branch = None;
+        if let Some(data) = item.worktree_data_mut() {
+            data.tag = Some("v1.2.3-release".into());
+        }
+        let mut stats = ColumnStats::new(Path::new("/test"));
+        stats.observe(&item);
+        // "⌂ v1.2.3-release": the house symbol is one column wide
+        assert_eq!(stats.branch, "v1.2.3-release".len() + 2);
+
+        let metadata = stats.finish(&HashSet::new(), 0, None);
+        assert_eq!(metadata.widths.branch, "v1.2.3-release".len() + 2);
+        assert!(!metadata.data_flags.path);
+    }
 }
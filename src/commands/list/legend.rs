@@ -0,0 +1,277 @@
+//! Symbol legend for `wt legend`.
+//!
+//! Every entry is rendered by the same code as the `wt list` cell it explains
+//! — a [`StatusSymbols`] or [`PrStatus`] holding just that state — so colors
+//! follow the active theme. States come from the enums' iterators and
+//! descriptions from exhaustive matches, so a new state can't be added
+//! without a legend line.
+
+use strum::IntoEnumIterator;
+use worktrunk::styling::{format_heading, println};
+
+use super::ci_status::{CiSource, CiStatus, PrStatus};
+use super::model::{
+    Divergence, HIDDEN_MARKER, MainState, OperationState, StatusSymbols, WorkingTreeStatus,
+    WorktreeState,
+};
+
+/// One group of the legend: a heading and its `(symbol, meaning)` lines.
+struct Group {
+    heading: &'static str,
+    /// Status subcolumn the symbols appear in
+    subcolumn: Option<&'static str>,
+    entries: Vec<(String, &'static str)>,
+}
+
+/// Handle `wt legend`.
+pub(crate) fn handle_legend() -> anyhow::Result<()> {
+    println!("{}", format_legend());
+    Ok(())
+}
+
+/// Render the legend, one line per symbol, grouped by column.
+fn format_legend() -> String {
+    legend_groups()
+        .iter()
+        .map(|group| {
+            let mut out = format_heading(group.heading, group.subcolumn);
+            for (symbol, meaning) in &group.entries {
+                // Symbols are one column wide; pad by hand since they're styled
+                out.push_str(&format!("\n  {symbol}  {meaning}"));
+            }
+            out
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn legend_groups() -> Vec<Group> {
+    let status = |symbols: StatusSymbols| symbols.format_compact();
+
+    let working_tree = [
+        (
+            WorkingTreeStatus {
+                staged: true,
+                ..Default::default()
+            },
+            "Staged files",
+        ),
+        (
+            WorkingTreeStatus {
+                modified: true,
+                ..Default::default()
+            },
+            "Modified files (unstaged)",
+        ),
+        (
+            WorkingTreeStatus {
+                untracked: true,
+                ..Default::default()
+            },
+            "Untracked files",
+        ),
+    ]
+    .into_iter()
+    .map(|(working_tree, meaning)| {
+        let symbols = StatusSymbols {
+            working_tree,
+            ..Default::default()
+        };
+        (status(symbols), meaning)
+    })
+    .collect();
+
+    let operations = OperationState::iter().filter_map(|state| {
+        let symbols = StatusSymbols {
+            operation_state: state,
+            ..Default::default()
+        };
+        Some((status(symbols), operation_meaning(state)?))
+    });
+    let locations = WorktreeState::iter().filter_map(|state| {
+        let symbols = StatusSymbols {
+            worktree_state: state,
+            ..Default::default()
+        };
+        Some((status(symbols), worktree_meaning(state)?))
+    });
+
+    let main = MainState::iter()
+        .filter_map(|state| {
+            let symbols = StatusSymbols {
+                main_state: state,
+                ..Default::default()
+            };
+            Some((status(symbols), main_meaning(state)?))
+        })
+        .collect();
+
+    let remote = Divergence::iter()
+        .filter_map(|divergence| {
+            let symbols = StatusSymbols {
+                upstream_divergence: divergence,
+                ..Default::default()
+            };
+            Some((status(symbols), divergence_meaning(divergence)?))
+        })
+        .collect();
+
+    let pr = |ci_status: CiStatus, merged: bool, is_stale: bool| PrStatus {
+        ci_status,
+        source: CiSource::PullRequest,
+        is_stale,
+        url: None,
+        merged,
+        checks: Vec::new(),
+    };
+    let mut ci: Vec<(String, &'static str)> = CiStatus::iter()
+        .map(|status| {
+            (
+                pr(status, false, false).format_indicator(false),
+                ci_meaning(status),
+            )
+        })
+        .collect();
+    ci.push((
+        pr(CiStatus::Passed, true, false).format_indicator(false),
+        "PR merged (safe to remove)",
+    ));
+    ci.push((
+        pr(CiStatus::Passed, false, true).format_indicator(false),
+        "Dimmed: local commits not pushed, so the status may be stale",
+    ));
+
+    vec![
+        Group {
+            heading: "BRANCH",
+            subcolumn: None,
+            entries: vec![(
+                HIDDEN_MARKER.to_string(),
+                "Hidden by wt hide (listed with --hidden)",
+            )],
+        },
+        Group {
+            heading: "STATUS",
+            subcolumn: Some("working tree"),
+            entries: working_tree,
+        },
+        Group {
+            heading: "STATUS",
+            subcolumn: Some("worktree"),
+            entries: operations.chain(locations).collect(),
+        },
+        Group {
+            heading: "STATUS",
+            subcolumn: Some("default branch"),
+            entries: main,
+        },
+        Group {
+            heading: "STATUS",
+            subcolumn: Some("remote"),
+            entries: remote,
+        },
+        Group {
+            heading: "CI",
+            subcolumn: None,
+            entries: ci,
+        },
+    ]
+}
+
+fn operation_meaning(state: OperationState) -> Option<&'static str> {
+    Some(match state {
+        OperationState::None => return None,
+        OperationState::Conflicts => "Merge conflicts",
+        OperationState::Rebase => "Rebase in progress",
+        OperationState::Merge => "Merge in progress",
+    })
+}
+
+fn worktree_meaning(state: WorktreeState) -> Option<&'static str> {
+    Some(match state {
+        WorktreeState::None => return None,
+        WorktreeState::Branch => "Branch without worktree",
+        WorktreeState::BranchWorktreeMismatch => "Worktree path doesn't match its branch",
+        WorktreeState::Prunable => "Prunable (directory missing)",
+        WorktreeState::Locked => "Locked worktree",
+    })
+}
+
+fn main_meaning(state: MainState) -> Option<&'static str> {
+    Some(match state {
+        MainState::None => return None,
+        MainState::IsMain => "Is the default branch",
+        MainState::Orphan => "Orphan branch (no common ancestor with the default branch)",
+        MainState::WouldConflict => "Would conflict if merged to the default branch",
+        MainState::Empty => "Same commit as the default branch, clean",
+        MainState::SameCommit => "Same commit as the default branch, uncommitted changes",
+        MainState::Integrated(_) => "Content integrated into the default branch",
+        MainState::SquashMerged => "Likely squash-merged into the default branch",
+        MainState::Diverged => "Diverged from the default branch",
+        MainState::Ahead => "Ahead of the default branch",
+        MainState::Behind => "Behind the default branch",
+    })
+}
+
+fn divergence_meaning(divergence: Divergence) -> Option<&'static str> {
+    Some(match divergence {
+        Divergence::None => return None,
+        Divergence::InSync => "In sync with remote",
+        Divergence::Diverged => "Diverged from remote",
+        Divergence::Ahead => "Ahead of remote",
+        Divergence::Behind => "Behind remote",
+    })
+}
+
+fn ci_meaning(status: CiStatus) -> &'static str {
+    match status {
+        CiStatus::Passed => "Green: all checks passed",
+        CiStatus::Running => "Blue: checks running",
+        CiStatus::Failed => "Red: checks failed",
+        CiStatus::Conflicts => "Yellow: merge conflicts with base",
+        CiStatus::NoCI => "Gray: no checks configured",
+        CiStatus::Error => "Fetch error (rate limit, network)",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ansi_str::AnsiStr;
+
+    use super::*;
+
+    #[test]
+    fn test_legend_covers_every_state() {
+        let legend = format_legend().ansi_strip().into_owned();
+        let has_line = |symbol: &str| {
+            legend
+                .lines()
+                .any(|line| line.trim_start().starts_with(&format!("{symbol}  ")))
+        };
+
+        for state in OperationState::iter().filter(|s| *s != OperationState::None) {
+            assert!(has_line(&state.to_string()), "{state:?} missing:\n{legend}");
+        }
+        for state in WorktreeState::iter().filter(|s| *s != WorktreeState::None) {
+            assert!(has_line(&state.to_string()), "{state:?} missing:\n{legend}");
+        }
+        for state in MainState::iter().filter(|s| *s != MainState::None) {
+            assert!(has_line(&state.to_string()), "{state:?} missing:\n{legend}");
+        }
+        for divergence in Divergence::iter().filter(|d| *d != Divergence::None) {
+            assert!(
+                has_line(divergence.symbol()),
+                "{divergence:?} missing:\n{legend}"
+            );
+        }
+        for status in CiStatus::iter() {
+            assert!(
+                legend.contains(ci_meaning(status)),
+                "{status:?} missing:\n{legend}"
+            );
+        }
+        for symbol in ["+", "!", "?", "✔", HIDDEN_MARKER] {
+            assert!(has_line(symbol), "{symbol} missing:\n{legend}");
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Long-format output for `wt list --long`.
+//!
+//! Each item prints as a block of `Label: value` lines, with blocks separated
+//! by blank lines. Values use the same styles as the table cells, but there's
+//! no width budget: nothing is truncated and empty fields are left out.
+//! Multi-line values continue under the first value column.
+
+use crate::display::format_relative_time_short;
+use anstyle::Style;
+use color_print::cformat;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use unicode_width::UnicodeWidthStr;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{Stream, supports_hyperlinks};
+
+use super::model::ListItem;
+
+/// Render all items as labeled blocks separated by blank lines.
+///
+/// `compare_ref` names the `--against` ref in the ahead/behind and line diff
+/// labels, as the table headers do. `scratch_store` is the git common dir when
+/// `[scratch]` is enabled, for noting branches with a preserved scratch
+/// directory. `worktree_config` holds each worktree's own git config, from
+/// `config.worktree`.
+pub(super) fn format_long(
+    items: &[ListItem],
+    compare_ref: Option<&str>,
+    scratch_store: Option<&Path>,
+    worktree_config: &HashMap<PathBuf, Vec<(String, String)>>,
+) -> String {
+    items
+        .iter()
+        .map(|item| format_block(item, compare_ref, scratch_store, worktree_config))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render one item, aligning values after the widest label in the block.
+fn format_block(
+    item: &ListItem,
+    compare_ref: Option<&str>,
+    scratch_store: Option<&Path>,
+    worktree_config: &HashMap<PathBuf, Vec<(String, String)>>,
+) -> String {
+    let fields = block_fields(item, compare_ref, scratch_store, worktree_config);
+    let label_width = fields
+        .iter()
+        .map(|(label, _)| label.width())
+        .max()
+        .unwrap_or(0);
+    let bold = Style::new().bold();
+    let continuation = format!("\n{}", " ".repeat(label_width + 2));
+
+    fields
+        .into_iter()
+        .map(|(label, value)| {
+            let padding = " ".repeat(label_width - label.width());
+            let value = value.replace('\n', &continuation);
+            format!("{bold}{label}:{bold:#}{padding} {value}\n")
+        })
+        .collect()
+}
+
+/// Activity entries shown per worktree, newest first.
+const ACTIVITY_ENTRIES: usize = 3;
+
+/// Collect the `(label, value)` pairs for an item, skipping fields without data.
+fn block_fields(
+    item: &ListItem,
+    compare_ref: Option<&str>,
+    scratch_store: Option<&Path>,
+    worktree_config: &HashMap<PathBuf, Vec<(String, String)>>,
+) -> Vec<(String, String)> {
+    let dim = Style::new().dimmed();
+    let data = item.worktree_data();
+    let mut fields = Vec::new();
+
+    let branch = match item.branch_label() {
+        Some(label) => cformat!("<bold>{label}</>"),
+        None => format!("{dim}(detached){dim:#}"),
+    };
+    fields.push(("Branch".to_string(), branch));
+
+    if let Some(data) = data {
+        fields.push(("Path".to_string(), format_path_for_display(&data.path)));
+    }
+
+    // Unborn branch: no commit to describe
+    if item.head() != worktrunk::git::NULL_OID {
+        fields.push(("Head".to_string(), format!("{dim}{}{dim:#}", item.head())));
+        if let Some(commit) = &item.commit {
+            fields.push((
+                "Age".to_string(),
+                format_relative_time_short(commit.timestamp),
+            ));
+            fields.push(("Message".to_string(), commit.display_message()));
+        }
+    }
+
+    if let Some(working) = data.and_then(|data| data.working_diff_display.clone()) {
+        fields.push(("Working ±".to_string(), working));
+    }
+
+    if !item.is_compare_base() {
+        let target = compare_ref.unwrap_or("Main");
+        if let Some(commits) = &item.display.commits_display {
+            fields.push((format!("{target} ↕"), commits.clone()));
+        }
+        if let Some(branch_diff) = &item.display.branch_diff_display {
+            fields.push((format!("{target} ±"), branch_diff.clone()));
+        }
+        if let Some(unmerged) = item.unmerged_commits.filter(|&count| count > 0) {
+            fields.push(("Unmerged".to_string(), unmerged.to_string()));
+        }
+    }
+
+    if let Some(active) = item.upstream().active() {
+        let tracking = match &item.branch {
+            Some(branch) => format!("{}/{branch}", active.remote),
+            None => active.remote.to_string(),
+        };
+        let divergence = item
+            .display
+            .upstream_display
+            .clone()
+            .unwrap_or_else(|| format!("{dim}in sync{dim:#}"));
+        fields.push(("Remote".to_string(), format!("{tracking} {divergence}")));
+    }
+
+    if let Some(symbols) = &item.status_symbols {
+        let compact = symbols.format_compact();
+        let state = symbols
+            .main_state
+            .as_json_str()
+            .map(|state| format!("{dim}{}{dim:#}", state.replace('_', " ")));
+        let value = match (compact.is_empty(), state) {
+            (false, Some(state)) => format!("{compact} {state}"),
+            (false, None) => compact,
+            (true, Some(state)) => state,
+            (true, None) => String::new(),
+        };
+        if !value.is_empty() {
+            fields.push(("State".to_string(), value));
+        }
+    }
+
+    if let Some(Some(pr_status)) = &item.pr_status {
+        let status: &'static str = pr_status.ci_status.into();
+        let mut ci = format!(
+            "{} {status}",
+            pr_status.format_indicator(supports_hyperlinks(Stream::Stdout))
+        );
+        if let Some(url) = &pr_status.url {
+            ci.push_str(&format!(" {dim}{url}{dim:#}"));
+        }
+        fields.push(("CI".to_string(), ci));
+    }
+
+    if let Some(locked) = data.and_then(|data| data.locked.as_deref()) {
+        let reason = if locked.is_empty() {
+            format!("{dim}(none given){dim:#}")
+        } else {
+            locked.to_string()
+        };
+        fields.push(("Lock reason".to_string(), reason));
+    }
+
+    if let Some(data) = data
+        && !data.activity.is_empty()
+    {
+        let lines: Vec<String> = data
+            .activity
+            .iter()
+            .rev()
+            .take(ACTIVITY_ENTRIES)
+            .map(|entry| {
+                let age = format_relative_time_short(entry.ts as i64);
+                let command = entry.cmd.lines().next().unwrap_or_default();
+                let outcome = match entry.exit {
+                    Some(0) => cformat!("<green>✓</>"),
+                    Some(code) => cformat!("<red>✗{code}</>"),
+                    None => format!("{dim}·{dim:#}"),
+                };
+                format!("{dim}{age:>4}{dim:#} {outcome} {command}")
+            })
+            .collect();
+        fields.push(("Activity".to_string(), lines.join("\n")));
+    }
+
+    if let Some(entries) = data
+        .and_then(|data| worktree_config.get(&data.path))
+        .filter(|entries| !entries.is_empty())
+    {
+        let lines: Vec<String> = entries
+            .iter()
+            .map(|(key, value)| format!("{key} {dim}={dim:#} {value}"))
+            .collect();
+        fields.push(("Git config".to_string(), lines.join("\n")));
+    }
+
+    if let (Some(git_common_dir), Some(branch)) = (scratch_store, &item.branch)
+        && worktrunk::scratch::is_preserved(git_common_dir, branch)
+    {
+        let path = worktrunk::scratch::store_path(git_common_dir, branch);
+        fields.push((
+            "Scratch".to_string(),
+            format!(
+                "kept from a removed worktree {dim}{}{dim:#}",
+                format_path_for_display(&path)
+            ),
+        ));
+    }
+
+    fields
+}
@@ -0,0 +1,327 @@
+//! Markdown output for `wt list --format=markdown`.
+//!
+//! Renders a GitHub-flavored Markdown table for pasting into PR descriptions
+//! and notes. Columns are chosen as for the table — skipped tasks hide their
+//! columns, and so does a column no row has data for — but nothing is hidden
+//! for width, since Markdown reflows. Cells are plain text with pipes and
+//! backticks escaped; paths render as inline code. `--ascii` spells out
+//! status symbols and arrows as words.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use ansi_str::AnsiStr;
+
+use crate::display::{format_relative_time_short, shorten_path};
+use worktrunk::styling::strip_osc8_hyperlinks;
+
+use super::collect::TaskKind;
+use super::columns::{COLUMN_SPECS, ColumnKind};
+use super::model::{Divergence, ListItem, OperationState, StatusSymbols, WorktreeState};
+
+/// Render `items` as a Markdown table, ending in a newline.
+pub(super) fn format_markdown(
+    items: &[ListItem],
+    skip_tasks: &HashSet<TaskKind>,
+    main_worktree_path: &Path,
+    compare_ref: Option<&str>,
+    ascii: bool,
+) -> String {
+    // Path only earns a column when some worktree isn't where its branch
+    // would put it, as in the table
+    let show_path = items
+        .iter()
+        .filter_map(|item| item.worktree_data())
+        .any(|data| data.branch_worktree_mismatch);
+
+    let columns: Vec<(ColumnKind, Vec<String>)> = COLUMN_SPECS
+        .iter()
+        .filter(|spec| {
+            spec.requires_task
+                .is_none_or(|task| !skip_tasks.contains(&task))
+        })
+        .filter(|spec| spec.kind != ColumnKind::Path || show_path)
+        .map(|spec| {
+            let cells: Vec<String> = items
+                .iter()
+                .map(|item| format_cell(spec.kind, item, main_worktree_path, ascii))
+                .collect();
+            (spec.kind, cells)
+        })
+        .filter(|(kind, cells)| {
+            matches!(kind, ColumnKind::Gutter | ColumnKind::Branch)
+                || cells.iter().any(|cell| !cell.is_empty())
+        })
+        .collect();
+
+    let row = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+
+    let mut out = row(columns
+        .iter()
+        .map(|(kind, _)| escape(&kind.header_against(compare_ref)))
+        .collect());
+    out.push_str(&row(columns
+        .iter()
+        .map(|(kind, _)| if is_numeric(*kind) { "---:" } else { "---" }.to_string())
+        .collect()));
+    for index in 0..items.len() {
+        out.push_str(&row(columns
+            .iter()
+            .map(|(_, cells)| cells[index].clone())
+            .collect()));
+    }
+    out
+}
+
+/// Columns of counts, right-aligned as in the table.
+fn is_numeric(kind: ColumnKind) -> bool {
+    matches!(
+        kind,
+        ColumnKind::WorkingDiff
+            | ColumnKind::AheadBehind
+            | ColumnKind::BranchDiff
+            | ColumnKind::Unmerged
+            | ColumnKind::Upstream
+    )
+}
+
+/// One cell, already escaped. Empty when the item has nothing to show.
+fn format_cell(
+    kind: ColumnKind,
+    item: &ListItem,
+    main_worktree_path: &Path,
+    ascii: bool,
+) -> String {
+    let data = item.worktree_data();
+    let has_commit = item.head() != worktrunk::git::NULL_OID;
+
+    match kind {
+        ColumnKind::Gutter => {
+            let (symbol, word) = match data {
+                Some(data) if data.is_current => ("@", "current"),
+                Some(data) if data.is_main => ("^", "main"),
+                Some(_) => ("+", "worktree"),
+                None => ("", "branch"),
+            };
+            if ascii { word } else { symbol }.to_string()
+        }
+        ColumnKind::Branch => escape(item.branch_label().as_deref().unwrap_or("-")),
+        ColumnKind::Status => item
+            .status_symbols
+            .as_ref()
+            .map(|symbols| {
+                if ascii {
+                    escape(&status_words(symbols).join(", "))
+                } else {
+                    escape(&plain(&symbols.format_compact()))
+                }
+            })
+            .unwrap_or_default(),
+        ColumnKind::WorkingDiff => data
+            .and_then(|data| data.working_tree_diff.as_ref())
+            .and_then(|diff| ColumnKind::WorkingDiff.format_diff_plain(diff.added, diff.deleted))
+            .map(|text| plain(&text))
+            .unwrap_or_default(),
+        ColumnKind::AheadBehind => match item.counts {
+            Some(counts) if !item.is_compare_base() && ascii => {
+                let bound = if counts.lower_bound { "at least " } else { "" };
+                arrows_in_words(counts.ahead, counts.behind)
+                    .map(|words| format!("{bound}{words}"))
+                    .unwrap_or_default()
+            }
+            Some(_) if !item.is_compare_base() => item
+                .display
+                .commits_display
+                .as_deref()
+                .map(plain)
+                .unwrap_or_default(),
+            _ => String::new(),
+        },
+        ColumnKind::BranchDiff if item.is_compare_base() => String::new(),
+        ColumnKind::BranchDiff => item
+            .display
+            .branch_diff_display
+            .as_deref()
+            .map(plain)
+            .unwrap_or_default(),
+        ColumnKind::Unmerged if item.is_compare_base() => String::new(),
+        ColumnKind::Unmerged => item
+            .unmerged_commits
+            .filter(|&count| count > 0)
+            .map(|count| count.to_string())
+            .unwrap_or_default(),
+        ColumnKind::Path => data
+            .map(|data| code(&shorten_path(&data.path, main_worktree_path)))
+            .unwrap_or_default(),
+        ColumnKind::Upstream => {
+            let upstream = item.upstream();
+            let Some(active) = upstream.active() else {
+                return String::new();
+            };
+            match (active.ahead, active.behind, ascii) {
+                (0, 0, true) => "in sync".to_string(),
+                (0, 0, false) => escape("|"),
+                (ahead, behind, true) => arrows_in_words(ahead, behind).unwrap_or_default(),
+                (_, _, false) => item
+                    .display
+                    .upstream_display
+                    .as_deref()
+                    .map(plain)
+                    .unwrap_or_default(),
+            }
+        }
+        ColumnKind::CiStatus => match &item.pr_status {
+            Some(Some(pr_status)) => {
+                let text = if ascii {
+                    let status: &'static str = pr_status.ci_status.into();
+                    status.to_string()
+                } else {
+                    plain(&pr_status.format_indicator(false))
+                };
+                match &pr_status.url {
+                    Some(url) => format!("[{}]({})", escape(&text), url.replace(' ', "%20")),
+                    None => escape(&text),
+                }
+            }
+            _ => String::new(),
+        },
+        ColumnKind::Url => item.url.as_deref().map(escape).unwrap_or_default(),
+        ColumnKind::Activity => data
+            .and_then(|data| data.activity.last())
+            .map(|entry| escape(entry.cmd.lines().next().unwrap_or_default()))
+            .unwrap_or_default(),
+        ColumnKind::Commit if has_commit => {
+            let head = item.head();
+            escape(&head[..8.min(head.len())])
+        }
+        ColumnKind::Commit => String::new(),
+        ColumnKind::Time => match &item.commit {
+            Some(commit) if has_commit => format_relative_time_short(commit.timestamp),
+            _ => String::new(),
+        },
+        ColumnKind::Summary => match &item.summary {
+            Some(Some(summary)) => escape(summary),
+            _ => String::new(),
+        },
+        ColumnKind::Message if item.is_branch_deleted() => String::new(),
+        ColumnKind::Message if !has_commit => "(no commits yet)".to_string(),
+        ColumnKind::Message => item
+            .commit
+            .as_ref()
+            .map(|commit| escape(&commit.display_message()))
+            .unwrap_or_default(),
+    }
+}
+
+/// `2 ahead, 1 behind`, leaving out zero counts.
+fn arrows_in_words(ahead: usize, behind: usize) -> Option<String> {
+    let parts: Vec<String> = [(ahead, "ahead"), (behind, "behind")]
+        .into_iter()
+        .filter(|&(count, _)| count > 0)
+        .map(|(count, word)| format!("{count} {word}"))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Status symbols as words, in the order the table shows them.
+fn status_words(symbols: &StatusSymbols) -> Vec<String> {
+    let working = &symbols.working_tree;
+    let mut words: Vec<String> = [
+        (working.staged, "staged"),
+        (working.modified, "modified"),
+        (working.untracked, "untracked"),
+        (working.renamed, "renamed"),
+        (working.deleted, "deleted"),
+    ]
+    .into_iter()
+    .filter(|&(present, _)| present)
+    .map(|(_, word)| word.to_string())
+    .collect();
+
+    let worktree = if symbols.operation_state != OperationState::None {
+        symbols.operation_state.as_json_str()
+    } else {
+        match symbols.worktree_state {
+            WorktreeState::None => None,
+            WorktreeState::BranchWorktreeMismatch => Some("path mismatch"),
+            WorktreeState::Prunable => Some("prunable"),
+            WorktreeState::Locked => Some("locked"),
+            WorktreeState::Branch => Some("no worktree"),
+        }
+    };
+    words.extend(worktree.map(str::to_string));
+
+    words.extend(symbols.main_state.as_json_str().map(|state| match state {
+        "is_main" => "main".to_string(),
+        state => state.replace('_', " "),
+    }));
+
+    words.extend(
+        match symbols.upstream_divergence {
+            Divergence::None => None,
+            Divergence::InSync => Some("in sync with remote"),
+            Divergence::Ahead => Some("ahead of remote"),
+            Divergence::Behind => Some("behind remote"),
+            Divergence::Diverged => Some("diverged from remote"),
+        }
+        .map(str::to_string),
+    );
+
+    words.extend(symbols.user_marker.clone());
+    words
+}
+
+/// Strip colors and hyperlinks from a styled cell.
+fn plain(styled: &str) -> String {
+    strip_osc8_hyperlinks(&styled.ansi_strip())
+}
+
+/// Escape text for a table cell: pipes would end the cell, backticks would
+/// open a code span, and newlines would end the row.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('`', "\\`")
+        .replace('\n', " ")
+}
+
+/// Wrap text in a code span, with a fence longer than any backtick run
+/// inside it. Pipes still need escaping inside code spans in tables.
+fn code(text: &str) -> String {
+    let longest_run = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+    let pad = if text.starts_with('`') || text.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    format!("{fence}{pad}{}{pad}{fence}", text.replace('|', "\\|"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a|b"), "a\\|b");
+        assert_eq!(escape("run `make`"), "run \\`make\\`");
+        assert_eq!(escape("one\ntwo"), "one two");
+        assert_eq!(escape("C:\\path"), "C:\\\\path");
+    }
+
+    #[test]
+    fn test_code() {
+        assert_eq!(code("../repo.feature"), "`../repo.feature`");
+        assert_eq!(code("a`b"), "``a`b``");
+        assert_eq!(code("`a"), "`` `a ``");
+        assert_eq!(code("a|b"), "`a\\|b`");
+    }
+
+    #[test]
+    fn test_arrows_in_words() {
+        assert_eq!(arrows_in_words(2, 1).as_deref(), Some("2 ahead, 1 behind"));
+        assert_eq!(arrows_in_words(0, 3).as_deref(), Some("3 behind"));
+        assert_eq!(arrows_in_words(0, 0), None);
+    }
+}
@@ -123,47 +123,200 @@ pub(crate) mod collect;
 pub(crate) mod columns;
 pub mod json_output;
 pub(crate) mod layout;
+pub(crate) mod legend;
+mod long;
+mod markdown;
 pub mod model;
 pub mod progressive;
 mod progressive_table;
 pub(crate) mod render;
+mod tsv;
 
 #[cfg(test)]
 mod spacing_test;
 
 // Layout is calculated in collect.rs
+use crate::cli::{BranchDiffBase, ListFormat};
 use anstyle::Style;
 use anyhow::Context;
+use color_print::cformat;
 use model::{ListData, ListItem};
 use progressive::RenderMode;
+use progressive_table::Overflow;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use worktrunk::config::ListDensity;
 use worktrunk::git::Repository;
-use worktrunk::styling::INFO_SYMBOL;
+use worktrunk::styling::{INFO_SYMBOL, eprintln, warning_message};
 
 // Re-export for statusline and other consumers
 pub use collect::{CollectOptions, build_worktree_item, populate_item};
 pub use model::StatuslineSegment;
 
-pub fn handle_list(
-    repo: Repository,
-    format: crate::OutputFormat,
-    cli_branches: bool,
-    cli_remotes: bool,
-    cli_full: bool,
-    render_mode: RenderMode,
-) -> anyhow::Result<()> {
+/// Install the `[theme]` severity colors before anything renders.
+///
+/// Unset keys come from the dark or light built-in theme, detected from the
+/// terminal background unless `base` picks one. An invalid style warns and
+/// keeps the default theme.
+fn install_theme(repo: &Repository) {
+    let config = repo.user_config().theme.clone().unwrap_or_default();
+    let (background, _) = worktrunk::styling::resolve_background(config.background());
+    match config.resolve(background) {
+        Ok(theme) => worktrunk::styling::set_theme(theme),
+        Err(e) => eprintln!(
+            "{}",
+            warning_message(cformat!("Ignoring <bold>[theme]</>: {e}"))
+        ),
+    }
+}
+
+/// Flags for [`handle_list`], resolved from the command line.
+pub struct ListOptions {
+    pub format: ListFormat,
+    pub branches: bool,
+    pub remotes: bool,
+    pub full: bool,
+    /// Count edits to skip-worktree files as changes
+    pub include_skipped: bool,
+    pub branch_diff_base: BranchDiffBase,
+    /// Compute line diffs even on shallow and partial clones
+    pub full_diffs: bool,
+    /// Add totals to the footer, or a `summary` object to JSON output
+    pub summary: bool,
+    /// Ref to compare against instead of the default branch
+    pub against: Option<String>,
+    /// List rows hidden by `wt hide`, marked
+    pub hidden: bool,
+    /// Keep rows touched at or after this time (duration or date)
+    pub since: Option<String>,
+    /// Keep rows touched at or before this time (duration or date)
+    pub until: Option<String>,
+    /// Stop gathering after this long, marking what's missing
+    pub timeout: Option<String>,
+    /// Show only the current worktree, in full detail
+    pub current: bool,
+    /// Print tab-separated rows as each one completes
+    pub no_align: bool,
+    /// One labeled block per item instead of the table
+    pub long: bool,
+    /// Name failing and pending checks on a line under their rows
+    pub ci_details: bool,
+    /// Spell out symbols as words in Markdown output
+    pub ascii: bool,
+    /// Space between columns, overriding `list.density`
+    pub density: Option<ListDensity>,
+    /// Print every row even when the table is taller than the terminal
+    pub no_pager: bool,
+    /// Show at most this many rows in the interactive table
+    pub max_rows: Option<usize>,
+    /// Write the output to this file, atomically, instead of stdout
+    pub output: Option<PathBuf>,
+    pub render_mode: RenderMode,
+}
+
+pub fn handle_list(repo: Repository, opts: ListOptions) -> anyhow::Result<()> {
+    let ListOptions {
+        format,
+        branches: cli_branches,
+        remotes: cli_remotes,
+        full: cli_full,
+        include_skipped,
+        branch_diff_base,
+        full_diffs,
+        summary,
+        against,
+        hidden,
+        since,
+        until,
+        timeout,
+        current,
+        no_align,
+        long,
+        ci_details,
+        ascii,
+        density,
+        no_pager,
+        max_rows,
+        output,
+        render_mode,
+    } = opts;
+
+    let now = worktrunk::utils::get_now();
+    let time_point = |flag: &str, value: Option<String>| {
+        value
+            .map(|value| {
+                worktrunk::duration::parse_time_point(&value, now)
+                    .with_context(|| cformat!("Invalid <bold>--{flag}</> value"))
+            })
+            .transpose()
+    };
+    let time_window = collect::TimeWindow {
+        since: time_point("since", since)?,
+        until: time_point("until", until)?,
+    };
+    let deadline = timeout
+        .map(|value| {
+            humantime::parse_duration(&value)
+                .with_context(|| cformat!("Invalid <bold>--timeout</> value"))
+        })
+        .transpose()?
+        .map(|timeout| std::time::Instant::now() + timeout);
+
+    if long && matches!(format, ListFormat::Json) {
+        anyhow::bail!(cformat!(
+            "<bold>--long</> doesn't apply to JSON output, which already has every field"
+        ));
+    }
+    if long && matches!(format, ListFormat::Markdown) {
+        anyhow::bail!(cformat!(
+            "<bold>--long</> doesn't apply to Markdown output, which is always a table"
+        ));
+    }
+    if long && matches!(format, ListFormat::Tsv) {
+        anyhow::bail!(cformat!(
+            "<bold>--long</> doesn't apply to TSV output, which is always one row per item"
+        ));
+    }
+    if ascii && !matches!(format, ListFormat::Markdown) {
+        anyhow::bail!(cformat!(
+            "<bold>--ascii</> only applies to <bold>--format=markdown</>"
+        ));
+    }
+
     // Progressive rendering only for table format with Progressive mode
+    // (streamed rows print once each, so there's nothing to update in place;
+    // long blocks print after the survey; CI detail lines would shift the
+    // rows being updated in place; a file is written once, at the end)
     let show_progress = match format {
-        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode => {
+        ListFormat::Table => {
             render_mode == RenderMode::Progressive
+                && !no_align
+                && !long
+                && !ci_details
+                && output.is_none()
         }
-        crate::OutputFormat::Json => false, // JSON never shows progress
+        // JSON, Markdown, and TSV print once, after everything is collected
+        ListFormat::Json | ListFormat::Markdown | ListFormat::Tsv => false,
     };
 
     // Render table in collect() for all table modes (progressive + buffered)
-    let render_table = matches!(
-        format,
-        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode
-    );
+    let render_table = !long && matches!(format, ListFormat::Table);
+
+    // Only the progressive table ever overflows the terminal, so these never
+    // touch piped, JSON, or --no-align output
+    let overflow = match (no_pager, max_rows) {
+        (true, _) => Overflow::Scroll,
+        (false, Some(rows)) => Overflow::Truncate(rows),
+        (false, None) if !show_progress => Overflow::Scroll,
+        (false, None) => match repo.config().list.pager() {
+            Some(value) => crate::pager::parse_pager_value(value),
+            None => crate::pager::detect_pager(),
+        }
+        .map_or(Overflow::Scroll, Overflow::Page),
+    };
+
+    install_theme(&repo);
 
     // For testing: allow enabling skip_expensive_for_stale via env var
     let skip_expensive_for_stale = std::env::var("WORKTRUNK_TEST_SKIP_EXPENSIVE_THRESHOLD").is_ok();
@@ -174,33 +327,157 @@ pub fn handle_list(
             cli_branches,
             cli_remotes,
             cli_full,
+            include_skipped,
+            branch_diff_base,
+            full_diffs,
+            summary,
+            against,
+            scope: if current {
+                collect::WorktreeScope::Current
+            } else {
+                collect::WorktreeScope::All
+            },
+            time_window,
+            show_hidden: hidden,
+            stream: no_align,
+            ci_details,
+            capture: output.is_some(),
+            deadline,
+            overflow,
+            density,
         },
         show_progress,
         render_table,
         skip_expensive_for_stale,
     )?;
 
-    let Some(ListData { items, .. }) = list_data else {
+    let Some(ListData {
+        items,
+        main_worktree_path,
+        skip_tasks,
+        compare_ref,
+        captured,
+    }) = list_data
+    else {
         return Ok(());
     };
 
-    match format {
-        crate::OutputFormat::Json => {
+    let rendered = match format {
+        ListFormat::Json => {
             // Convert to new JSON structure
-            let json_items = json_output::to_json_items(&items);
-            let json =
-                serde_json::to_string_pretty(&json_items).context("Failed to serialize to JSON")?;
-            println!("{}", json);
+            let json_items = json_output::to_json_items(&items, compare_ref.as_deref());
+            let json = if current && let [item] = json_items.as_slice() {
+                // A single object rather than a one-element array
+                serde_json::to_string_pretty(item)
+            } else if summary {
+                let output = json_output::JsonListWithSummary {
+                    items: json_items,
+                    summary: json_output::JsonSummary::from_items(&items),
+                };
+                serde_json::to_string_pretty(&output)
+            } else {
+                serde_json::to_string_pretty(&json_items)
+            }
+            .context("Failed to serialize to JSON")?;
+            Some(format!("{json}\n"))
         }
-        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode => {
-            // Table and summary already rendered in collect() for all modes
-            // Nothing to do here - collect() handles the complete table rendering
+        ListFormat::Markdown => Some(markdown::format_markdown(
+            &items,
+            &skip_tasks,
+            &main_worktree_path,
+            compare_ref.as_deref(),
+            ascii,
+        )),
+        ListFormat::Tsv => {
+            let json_items = json_output::to_json_items(&items, compare_ref.as_deref());
+            let now = worktrunk::utils::display_clock().now() as i64;
+            Some(tsv::format_tsv(&json_items, now).context("Failed to serialize to TSV")?)
         }
+        ListFormat::Table if long => {
+            let scratch_store = repo
+                .user_config()
+                .scratch
+                .as_ref()
+                .is_some_and(|scratch| scratch.enabled())
+                .then(|| repo.git_common_dir());
+            // Per-worktree git config only takes effect with the extension on
+            let worktree_config: HashMap<PathBuf, Vec<(String, String)>> =
+                if repo.worktree_config_enabled() {
+                    items
+                        .iter()
+                        .filter_map(|item| item.worktree_data())
+                        .filter_map(|data| {
+                            let entries = repo.worktree_at(&data.path).worktree_config().ok()?;
+                            Some((data.path.clone(), entries))
+                        })
+                        .collect()
+                } else {
+                    HashMap::new()
+                };
+            Some(long::format_long(
+                &items,
+                compare_ref.as_deref(),
+                scratch_store,
+                &worktree_config,
+            ))
+        }
+        // Table and summary were rendered in collect(), to stdout unless
+        // captured for --output
+        ListFormat::Table => captured,
+    };
+
+    match (rendered, output) {
+        (Some(text), Some(path)) => write_output_file(&path, &plain_text(&text))?,
+        (Some(text), None) => worktrunk::styling::print!("{text}"),
+        (None, _) => {}
+    }
+
+    if !matches!(format, ListFormat::Json | ListFormat::Tsv) {
+        crate::commands::nudge_if_due(&repo);
     }
 
     Ok(())
 }
 
+/// Write `--output` atomically: into a temp file beside the target, then
+/// renamed over it, so a reader sees the old file or the new one and never a
+/// partial write. The temp file is removed if anything fails.
+fn write_output_file(path: &Path, contents: &str) -> anyhow::Result<()> {
+    let display = worktrunk::path::format_path_for_display(path);
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let mut builder = tempfile::Builder::new();
+    builder.prefix(".wt-list-").suffix(".tmp");
+    // A new file gets the mode a plain write would give it, less the umask,
+    // rather than the temp file's private 0600
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(std::fs::Permissions::from_mode(0o666));
+    }
+
+    let write = || -> std::io::Result<()> {
+        let mut temp = builder.tempfile_in(dir)?;
+        temp.write_all(contents.as_bytes())?;
+        if let Ok(existing) = std::fs::metadata(path) {
+            temp.as_file().set_permissions(existing.permissions())?;
+        }
+        temp.as_file().sync_all()?;
+        temp.persist(path).map_err(|e| e.error)?;
+        Ok(())
+    };
+    write().with_context(|| cformat!("Failed to write <bold>{display}</>"))
+}
+
+/// Strip colors and hyperlinks, which only make sense on a terminal.
+fn plain_text(text: &str) -> String {
+    use ansi_str::AnsiStr;
+    worktrunk::styling::strip_osc8_hyperlinks(&text.ansi_strip())
+}
+
 #[derive(Default)]
 pub(super) struct SummaryMetrics {
     worktrees: usize,
@@ -208,6 +485,7 @@ pub(super) struct SummaryMetrics {
     remote_branches: usize,
     dirty_worktrees: usize,
     ahead_items: usize,
+    behind_items: usize,
 }
 
 impl SummaryMetrics {
@@ -244,11 +522,15 @@ impl SummaryMetrics {
         if item.counts.is_some_and(|c| c.ahead > 0) {
             self.ahead_items += 1;
         }
+        if item.counts.is_some_and(|c| c.behind > 0) {
+            self.behind_items += 1;
+        }
     }
 
     pub(super) fn summary_parts(
         &self,
         include_branches: bool,
+        include_behind: bool,
         hidden_columns: usize,
     ) -> Vec<String> {
         let mut parts = Vec::new();
@@ -274,6 +556,10 @@ impl SummaryMetrics {
             parts.push(format!("{} ahead", self.ahead_items));
         }
 
+        if include_behind && self.behind_items > 0 {
+            parts.push(format!("{} behind", self.behind_items));
+        }
+
         if hidden_columns > 0 {
             let plural = if hidden_columns == 1 {
                 "column"
@@ -291,15 +577,19 @@ impl SummaryMetrics {
 pub(crate) fn format_summary_message(
     items: &[ListItem],
     show_branches: bool,
+    show_behind: bool,
     hidden_column_count: usize,
+    hidden_row_count: usize,
     error_count: usize,
     timed_out_count: usize,
 ) -> String {
     let metrics = SummaryMetrics::from_items(items);
     let dim = Style::new().dimmed();
-    let summary = metrics
-        .summary_parts(show_branches, hidden_column_count)
-        .join(", ");
+    let mut parts = metrics.summary_parts(show_branches, show_behind, hidden_column_count);
+    if hidden_row_count > 0 {
+        parts.push(format!("{hidden_row_count} hidden (use --hidden)"));
+    }
+    let summary = parts.join(", ");
 
     if error_count > 0 {
         let failure_msg = if error_count == timed_out_count {
@@ -333,6 +623,7 @@ mod tests {
         assert_eq!(metrics.remote_branches, 0);
         assert_eq!(metrics.dirty_worktrees, 0);
         assert_eq!(metrics.ahead_items, 0);
+        assert_eq!(metrics.behind_items, 0);
     }
 
     #[test]
@@ -343,8 +634,9 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            behind_items: 0,
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, false, 0);
         assert_eq!(parts, vec!["1 worktree"]);
     }
 
@@ -356,8 +648,9 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            behind_items: 0,
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, false, 0);
         assert_eq!(parts, vec!["3 worktrees"]);
     }
 
@@ -369,8 +662,9 @@ mod tests {
             remote_branches: 10,
             dirty_worktrees: 0,
             ahead_items: 0,
+            behind_items: 0,
         };
-        let parts = metrics.summary_parts(true, 0);
+        let parts = metrics.summary_parts(true, false, 0);
         assert_eq!(
             parts,
             vec!["2 worktrees", "5 branches", "10 remote branches"]
@@ -385,8 +679,9 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 2,
             ahead_items: 0,
+            behind_items: 0,
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, false, 0);
         assert_eq!(parts, vec!["3 worktrees", "2 with changes"]);
     }
 
@@ -398,8 +693,9 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 1,
+            behind_items: 0,
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, false, 0);
         assert_eq!(parts, vec!["2 worktrees", "1 ahead"]);
     }
 
@@ -411,11 +707,12 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            behind_items: 0,
         };
-        let parts = metrics.summary_parts(false, 1);
+        let parts = metrics.summary_parts(false, false, 1);
         assert_eq!(parts, vec!["1 worktree", "1 column hidden"]);
 
-        let parts = metrics.summary_parts(false, 3);
+        let parts = metrics.summary_parts(false, false, 3);
         assert_eq!(parts, vec!["1 worktree", "3 columns hidden"]);
     }
 
@@ -427,8 +724,9 @@ mod tests {
             remote_branches: 5,
             dirty_worktrees: 0,
             ahead_items: 0,
+            behind_items: 0,
         };
-        let parts = metrics.summary_parts(true, 0);
+        let parts = metrics.summary_parts(true, false, 0);
         assert_eq!(parts, vec!["2 worktrees", "5 remote branches"]);
     }
 
@@ -440,8 +738,9 @@ mod tests {
             remote_branches: 8,
             dirty_worktrees: 2,
             ahead_items: 4,
+            behind_items: 0,
         };
-        let parts = metrics.summary_parts(true, 2);
+        let parts = metrics.summary_parts(true, false, 2);
         assert_eq!(
             parts,
             vec![
@@ -455,21 +754,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_summary_metrics_summary_parts_behind() {
+        let metrics = SummaryMetrics {
+            worktrees: 3,
+            local_branches: 0,
+            remote_branches: 0,
+            dirty_worktrees: 0,
+            ahead_items: 1,
+            behind_items: 2,
+        };
+        // Behind counts only appear with --summary
+        assert_eq!(
+            metrics.summary_parts(false, false, 0),
+            vec!["3 worktrees", "1 ahead"]
+        );
+        assert_eq!(
+            metrics.summary_parts(false, true, 0),
+            vec!["3 worktrees", "1 ahead", "2 behind"]
+        );
+    }
+
     #[test]
     fn test_format_summary_message_error_variants() {
         use insta::assert_snapshot;
 
         // No errors
-        assert_snapshot!(format_summary_message(&[], false, 0, 0, 0), @"[2m○[22m [2mShowing 0 worktrees[0m");
+        assert_snapshot!(format_summary_message(&[], false, false, 0, 0, 0, 0), @"[2m○[22m [2mShowing 0 worktrees[0m");
         // All timeouts
-        assert_snapshot!(format_summary_message(&[], false, 0, 3, 3), @"[2m○[22m [2mShowing 0 worktrees. 3 tasks timed out[0m");
+        assert_snapshot!(format_summary_message(&[], false, false, 0, 0, 3, 3), @"[2m○[22m [2mShowing 0 worktrees. 3 tasks timed out[0m");
         // Mixed errors and timeouts
-        assert_snapshot!(format_summary_message(&[], false, 0, 5, 3), @"[2m○[22m [2mShowing 0 worktrees. 5 tasks failed (3 timed out)[0m");
+        assert_snapshot!(format_summary_message(&[], false, false, 0, 0, 5, 3), @"[2m○[22m [2mShowing 0 worktrees. 5 tasks failed (3 timed out)[0m");
         // Only failures, no timeouts
-        assert_snapshot!(format_summary_message(&[], false, 0, 2, 0), @"[2m○[22m [2mShowing 0 worktrees. 2 tasks failed[0m");
+        assert_snapshot!(format_summary_message(&[], false, false, 0, 0, 2, 0), @"[2m○[22m [2mShowing 0 worktrees. 2 tasks failed[0m");
         // Single error
-        assert_snapshot!(format_summary_message(&[], false, 0, 1, 0), @"[2m○[22m [2mShowing 0 worktrees. 1 task failed[0m");
+        assert_snapshot!(format_summary_message(&[], false, false, 0, 0, 1, 0), @"[2m○[22m [2mShowing 0 worktrees. 1 task failed[0m");
         // Single timeout
-        assert_snapshot!(format_summary_message(&[], false, 0, 1, 1), @"[2m○[22m [2mShowing 0 worktrees. 1 task timed out[0m");
+        assert_snapshot!(format_summary_message(&[], false, false, 0, 0, 1, 1), @"[2m○[22m [2mShowing 0 worktrees. 1 task timed out[0m");
     }
 }
@@ -3,7 +3,10 @@
 //! This module contains the main data structures used to represent
 //! worktrees and branches in `wt list` output.
 
+use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
+use worktrunk::activity::ActivityEntry;
 
 use worktrunk::git::{IntegrationReason, IntegrationSignals, LineDiff, check_integration};
 
@@ -11,8 +14,12 @@ use super::state::{ActiveGitOperation, Divergence, MainState, OperationState, Wo
 use super::stats::{AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus};
 use super::status_symbols::{StatusSymbols, WorkingTreeStatus};
 use crate::commands::list::ci_status::PrStatus;
+use crate::commands::list::collect::TaskKind;
 use crate::commands::list::columns::ColumnKind;
 
+/// Follows the branch name of rows hidden by `wt hide` (`wt list --hidden`).
+pub const HIDDEN_MARKER: &str = "⊘";
+
 /// Display fields shared between WorktreeInfo and BranchInfo
 /// These contain formatted strings with ANSI colors for json-pretty output
 #[derive(Clone, serde::Serialize, Default)]
@@ -41,9 +48,7 @@ impl DisplayFields {
         branch_diff: &Option<BranchDiffTotals>,
         upstream: &Option<UpstreamStatus>,
     ) -> Self {
-        let commits_display = counts
-            .as_ref()
-            .and_then(|c| ColumnKind::AheadBehind.format_diff_plain(c.ahead, c.behind));
+        let commits_display = counts.as_ref().and_then(format_commits_plain);
 
         let branch_diff_display = branch_diff.as_ref().and_then(|bd| {
             ColumnKind::BranchDiff.format_diff_plain(bd.diff.added, bd.diff.deleted)
@@ -67,11 +72,24 @@ impl DisplayFields {
     }
 }
 
+/// Plain-text ahead/behind, prefixed with `≥` when the counts are lower bounds.
+fn format_commits_plain(counts: &AheadBehind) -> Option<String> {
+    let formatted = ColumnKind::AheadBehind.format_diff_plain(counts.ahead, counts.behind)?;
+    Some(if counts.lower_bound {
+        format!("≥{formatted}")
+    } else {
+        formatted
+    })
+}
+
 /// Type-specific data for worktrees
 #[derive(Clone, serde::Serialize, Default)]
 pub struct WorktreeData {
     pub path: PathBuf,
     pub detached: bool,
+    /// Tag at HEAD for detached worktrees (e.g., created by `wt switch <tag>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locked: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -94,6 +112,16 @@ pub struct WorktreeData {
     pub branch_worktree_mismatch: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_diff_display: Option<String>,
+    /// The branch ref was deleted while checked out here (see
+    /// `Repository::deleted_branch_commit`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub branch_deleted: bool,
+    /// Raw bytes of the branch name when it isn't valid UTF-8 (see `WorktreeInfo::branch_raw`)
+    #[serde(skip)]
+    pub branch_raw: Option<Vec<u8>>,
+    /// Recent commands from the activity log, oldest first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub activity: Vec<ActivityEntry>,
 }
 
 impl WorktreeData {
@@ -115,6 +143,7 @@ impl WorktreeData {
             detached: wt.detached,
             locked: wt.locked.clone(),
             prunable: wt.prunable.clone(),
+            branch_raw: wt.branch_raw.clone(),
             is_main,
             is_current,
             is_previous,
@@ -156,6 +185,15 @@ pub struct ListItem {
     pub counts: Option<AheadBehind>,
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
     pub branch_diff: Option<BranchDiffTotals>,
+    /// Branch commits whose changes the default branch lacks (`git cherry`).
+    /// Unlike `counts.ahead`, excludes merge commits and cherry-picked patches.
+    /// None until the `--full` task runs.
+    #[serde(skip)]
+    pub unmerged_commits: Option<usize>,
+    /// Files that would conflict when merging into the default branch.
+    /// None when the merge is clean or the check didn't run.
+    #[serde(skip)]
+    pub conflicted_files: Option<usize>,
     /// Whether HEAD's tree SHA matches the integration target's tree SHA.
     /// True when committed content is identical regardless of commit history.
     /// Internal field used to compute `BranchState::Integrated(TreesMatch)`.
@@ -176,10 +214,30 @@ pub struct ListItem {
     /// This is the cheapest integration check (~1ms).
     #[serde(skip)]
     pub is_ancestor: Option<bool>,
+    /// Whether the integration target carries the branch's patches without its commits
+    /// (`git cherry` or a matching cumulative patch-id). Catches squash merges that later
+    /// commits on the integration target have since edited over.
+    #[serde(skip)]
+    pub squash_merged: Option<bool>,
     /// Whether this branch is an orphan (no common ancestor with default branch).
     /// Orphan branches have independent history and can't compute meaningful ahead/behind counts.
     #[serde(skip)]
     pub is_orphan: Option<bool>,
+    /// Whether this item is what ahead/behind and branch diffs are measured
+    /// against, so those columns stay empty: the main worktree by default, or
+    /// the `--against` branch.
+    #[serde(skip)]
+    pub is_compare_base: bool,
+    /// Hidden by `wt hide`; only listed with `wt list --hidden`
+    #[serde(skip)]
+    pub hidden: bool,
+    /// Recorded stack parent (`wt parent`), which ahead/behind and branch
+    /// diffs are measured against unless `--against` is given
+    #[serde(skip)]
+    pub parent: Option<String>,
+    /// Tasks the `wt list --timeout` deadline cut short; their cells show `~`
+    #[serde(skip)]
+    pub incomplete: BTreeSet<TaskKind>,
 
     // TODO: Same concern as counts/branch_diff above - should upstream fields always be present?
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
@@ -224,6 +282,12 @@ pub struct ListData {
     /// layout (e.g., the picker at a different width) should use this set.
     #[cfg_attr(windows, allow(dead_code))] // Used only by select module (unix-only)
     pub skip_tasks: std::collections::HashSet<super::super::collect::TaskKind>,
+    /// Ref ahead/behind and branch diffs were computed against, when not the
+    /// default branch (`--against` or `list.compare-ref`).
+    pub compare_ref: Option<String>,
+    /// The rendered table, when collection captured it instead of printing
+    /// it (`--output`).
+    pub captured: Option<String>,
 }
 
 impl ListItem {
@@ -235,11 +299,18 @@ impl ListItem {
             commit: None,
             counts: None,
             branch_diff: None,
+            unmerged_commits: None,
+            conflicted_files: None,
             committed_trees_match: None,
             has_file_changes: None,
             would_merge_add: None,
             is_ancestor: None,
+            squash_merged: None,
             is_orphan: None,
+            is_compare_base: false,
+            hidden: false,
+            parent: None,
+            incomplete: BTreeSet::new(),
             upstream: None,
             pr_status: None,
             url: None,
@@ -251,12 +322,48 @@ impl ListItem {
         }
     }
 
+    /// Whether the `--timeout` deadline cut short any task feeding `column`.
+    pub fn is_incomplete(&self, column: ColumnKind) -> bool {
+        column
+            .fed_by()
+            .iter()
+            .any(|kind| self.incomplete.contains(kind))
+    }
+
     pub fn branch_name(&self) -> &str {
         self.branch.as_deref().unwrap_or("(detached)")
     }
 
-    pub fn is_main(&self) -> bool {
-        matches!(&self.kind, ItemKind::Worktree(data) if data.is_main)
+    /// Label for the Branch column: the branch name, or `⌂ <tag>` for detached
+    /// worktrees checked out at a tag. `None` for other detached worktrees.
+    /// Branches deleted under their worktree end in `(deleted)`, stacked
+    /// branches in `(on <parent>)`, and hidden branches in [`HIDDEN_MARKER`].
+    pub fn branch_label(&self) -> Option<Cow<'_, str>> {
+        if let Some(branch) = self.branch.as_deref() {
+            let mut label = Cow::Borrowed(branch);
+            if self.is_branch_deleted() {
+                label = Cow::Owned(format!("{label} (deleted)"));
+            }
+            if let Some(parent) = &self.parent {
+                label = Cow::Owned(format!("{label} (on {parent})"));
+            }
+            if self.hidden {
+                label = Cow::Owned(format!("{label} {HIDDEN_MARKER}"));
+            }
+            return Some(label);
+        }
+        self.worktree_data()
+            .and_then(|data| data.tag.as_deref())
+            .map(|tag| Cow::Owned(format!("⌂ {tag}")))
+    }
+
+    /// Whether this worktree's branch was deleted out from under it.
+    pub fn is_branch_deleted(&self) -> bool {
+        self.worktree_data().is_some_and(|data| data.branch_deleted)
+    }
+
+    pub fn is_compare_base(&self) -> bool {
+        self.is_compare_base
     }
 
     pub fn head(&self) -> &str {
@@ -310,7 +417,8 @@ impl ListItem {
     pub(crate) fn is_potentially_removable(&self) -> Option<bool> {
         // Use already-computed status_symbols if available
         let main_state = self.status_symbols.as_ref()?.main_state;
-        // SameCommit excluded: has uncommitted work that would be lost
+        // SameCommit excluded: has uncommitted work that would be lost.
+        // SquashMerged excluded: it's a heuristic, not proof of integration.
         Some(matches!(
             main_state,
             MainState::Empty | MainState::Integrated(_)
@@ -383,8 +491,7 @@ impl ListItem {
 
         // 4. Commits ahead/behind main (priority 4)
         if let Some(counts) = self.counts
-            && let Some(formatted) =
-                ColumnKind::AheadBehind.format_diff_plain(counts.ahead, counts.behind)
+            && let Some(formatted) = format_commits_plain(&counts)
         {
             segments.push(StatuslineSegment::from_column(
                 formatted,
@@ -579,9 +686,10 @@ impl ListItem {
 
     /// Check if branch content is integrated into the default branch (safe to delete).
     ///
-    /// Returns `Some(MainState)` only for truly integrated states:
+    /// Returns `Some(MainState)` only for integrated (or likely integrated) states:
     /// - `Empty` = same commit as default branch with clean working tree
     /// - `Integrated(...)` = content in default branch via different history
+    /// - `SquashMerged` = default branch has the branch's patches (heuristic)
     ///
     /// Does NOT detect `SameCommit` (same commit with dirty working tree) -
     /// that's handled separately in the caller since it's not an integration state.
@@ -615,10 +723,13 @@ impl ListItem {
         };
         let reason = check_integration(&signals);
 
-        // Convert to MainState, with SameCommit becoming Empty for display
+        // Convert to MainState, with SameCommit becoming Empty for display.
+        // Squash-merge detection is a heuristic, so it only applies when no
+        // integration check matched.
         match reason {
             Some(IntegrationReason::SameCommit) => Some(MainState::Empty),
             Some(other) => Some(MainState::Integrated(other)),
+            None if self.squash_merged == Some(true) => Some(MainState::SquashMerged),
             None => None,
         }
     }
@@ -638,6 +749,35 @@ mod tests {
         assert_eq!(item.branch_name(), "(detached)");
     }
 
+    #[test]
+    fn test_list_item_branch_label() {
+        let item = ListItem::new_branch("abc123".to_string(), "feature".to_string());
+        assert_eq!(item.branch_label().as_deref(), Some("feature"));
+
+        let mut item = ListItem::new_branch("abc123".to_string(), "feature".to_string());
+        item.branch = None;
+        item.kind = ItemKind::Worktree(Box::new(WorktreeData {
+            detached: true,
+            ..Default::default()
+        }));
+        assert_eq!(item.branch_label(), None);
+
+        if let ItemKind::Worktree(data) = &mut item.kind {
+            data.tag = Some("v2.3.1".to_string());
+        }
+        assert_eq!(item.branch_label().as_deref(), Some("⌂ v2.3.1"));
+
+        let mut item = ListItem::new_branch("abc123".to_string(), "gh-pages".to_string());
+        item.hidden = true;
+        assert_eq!(item.branch_label().as_deref(), Some("gh-pages ⊘"));
+
+        item.kind = ItemKind::Worktree(Box::new(WorktreeData {
+            branch_deleted: true,
+            ..Default::default()
+        }));
+        assert_eq!(item.branch_label().as_deref(), Some("gh-pages (deleted) ⊘"));
+    }
+
     #[test]
     fn test_list_item_head() {
         let item = ListItem::new_branch("abc123def".to_string(), "feature".to_string());
@@ -655,6 +795,7 @@ mod tests {
         item.counts = Some(AheadBehind {
             ahead: 5,
             behind: 3,
+            ..Default::default()
         });
         let counts = item.counts.unwrap();
         assert_eq!(counts.ahead, 5);
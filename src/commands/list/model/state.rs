@@ -17,7 +17,7 @@ use worktrunk::git::IntegrationReason;
 /// | Ahead     | `⇡`    - has unpushed commits   |
 /// | Behind    | `⇣`    - missing remote commits |
 /// | Diverged  | `⇅`    - both ahead and behind  |
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::EnumIter)]
 pub enum Divergence {
     /// No remote tracking branch configured
     #[default]
@@ -75,7 +75,7 @@ impl Divergence {
 /// - For branches (without worktree): shows / to distinguish from worktrees
 ///
 /// Priority order for worktrees: BranchWorktreeMismatch > Prunable > Locked
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr, strum::EnumIter)]
 pub enum WorktreeState {
     #[strum(serialize = "")]
     /// Normal worktree (path matches template, not locked or prunable)
@@ -120,17 +120,18 @@ impl serde::Serialize for WorktreeState {
 /// Priority order determines which symbol is shown:
 /// 1. IsMain (^) - this IS the main worktree
 /// 2. Orphan (∅) - no common ancestor with default branch
-/// 3. WouldConflict (✗) - merge-tree simulation shows conflicts
-/// 4. Empty (_) - same commit as default branch AND clean working tree (safe to delete)
-/// 5. SameCommit (–) - same commit as default branch with uncommitted changes
-/// 6. Integrated (⊂) - content is in default branch via different history
-/// 7. Diverged (↕) - both ahead and behind default branch
-/// 8. Ahead (↑) - has commits default branch doesn't have
-/// 9. Behind (↓) - missing commits from default branch
+/// 3. SquashMerged (≈) - default branch has the branch's patches (conflicts are moot)
+/// 4. WouldConflict (✗) - merge-tree simulation shows conflicts
+/// 5. Empty (_) - same commit as default branch AND clean working tree (safe to delete)
+/// 6. SameCommit (–) - same commit as default branch with uncommitted changes
+/// 7. Integrated (⊂) - content is in default branch via different history
+/// 8. Diverged (↕) - both ahead and behind default branch
+/// 9. Ahead (↑) - has commits default branch doesn't have
+/// 10. Behind (↓) - missing commits from default branch
 ///
 /// The `Integrated` variant carries an [`IntegrationReason`] explaining how the
 /// content was integrated (ancestor, trees match, no added changes, or merge adds nothing).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr, strum::EnumIter)]
 #[strum(serialize_all = "snake_case")]
 pub enum MainState {
     /// Normal working branch (up-to-date with default branch, no special state)
@@ -139,6 +140,10 @@ pub enum MainState {
     None,
     /// This IS the main worktree
     IsMain,
+    /// No common ancestor with default branch (orphan branch)
+    Orphan,
+    /// Default branch has the branch's patches but not its commits (likely squash-merged)
+    SquashMerged,
     /// Merge-tree conflicts with default branch (simulated via git merge-tree)
     WouldConflict,
     /// Branch HEAD is same commit as default branch AND working tree is clean (safe to delete)
@@ -148,8 +153,6 @@ pub enum MainState {
     /// Content is integrated into default branch via different history
     #[strum(serialize = "integrated")]
     Integrated(IntegrationReason),
-    /// No common ancestor with default branch (orphan branch)
-    Orphan,
     /// Both ahead and behind default branch
     Diverged,
     /// Has commits default branch doesn't have
@@ -168,6 +171,7 @@ impl std::fmt::Display for MainState {
             Self::Empty => write!(f, "_"),
             Self::SameCommit => write!(f, "–"), // en-dash U+2013
             Self::Integrated(_) => write!(f, "⊂"),
+            Self::SquashMerged => write!(f, "≈"),
             Self::Orphan => write!(f, "∅"), // U+2205 empty set
             Self::Diverged => write!(f, "↕"),
             Self::Ahead => write!(f, "↑"),
@@ -207,12 +211,16 @@ impl MainState {
 
     /// Compute from divergence counts, integration state, and same-commit-dirty flag.
     ///
-    /// Priority: IsMain > Orphan > WouldConflict > integration > SameCommit > Diverged > Ahead > Behind
+    /// Priority: IsMain > Orphan > SquashMerged > WouldConflict > integration > SameCommit >
+    /// Diverged > Ahead > Behind
     ///
     /// Orphan takes priority over WouldConflict because:
     /// - Orphan is a fundamental property (no common ancestor)
     /// - Merge conflicts for orphan branches are expected but not actionable normally
     /// - Users should understand "this is an orphan branch" rather than "this would conflict"
+    ///
+    /// SquashMerged does too: once the default branch has re-edited a squash-merged
+    /// change, merging the branch again conflicts, but the branch is done anyway.
     pub fn from_integration_and_counts(
         is_main: bool,
         would_conflict: bool,
@@ -226,6 +234,8 @@ impl MainState {
             Self::IsMain
         } else if is_orphan {
             Self::Orphan
+        } else if integration == Some(Self::SquashMerged) {
+            Self::SquashMerged
         } else if would_conflict {
             Self::WouldConflict
         } else if let Some(state) = integration {
@@ -258,7 +268,7 @@ impl serde::Serialize for MainState {
 /// These take priority over all other states in the Worktree column.
 ///
 /// Priority: Conflicts (✘) > Rebase (⤴) > Merge (⤵)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr, strum::EnumIter)]
 #[strum(serialize_all = "snake_case")]
 pub enum OperationState {
     /// No operation in progress
@@ -294,7 +304,10 @@ impl OperationState {
         use color_print::cformat;
         match self {
             Self::None => None,
-            Self::Conflicts => Some(cformat!("<red>{self}</>")),
+            Self::Conflicts => {
+                let style = worktrunk::styling::theme().conflicts;
+                Some(format!("{style}{self}{style:#}"))
+            }
             Self::Rebase | Self::Merge => Some(cformat!("<yellow>{self}</>")),
         }
     }
@@ -425,6 +438,7 @@ mod tests {
             format!("{}", MainState::Integrated(IntegrationReason::Ancestor)),
             "⊂"
         );
+        assert_eq!(format!("{}", MainState::SquashMerged), "≈");
         assert_eq!(format!("{}", MainState::Orphan), "∅"); // empty set
         assert_eq!(format!("{}", MainState::Diverged), "↕");
         assert_eq!(format!("{}", MainState::Ahead), "↑");
@@ -470,6 +484,7 @@ mod tests {
             MainState::Integrated(IntegrationReason::TreesMatch).as_json_str(),
             Some("integrated")
         );
+        assert_eq!(MainState::SquashMerged.as_json_str(), Some("squash_merged"));
         assert_eq!(MainState::Diverged.as_json_str(), Some("diverged"));
         assert_eq!(MainState::Ahead.as_json_str(), Some("ahead"));
         assert_eq!(MainState::Behind.as_json_str(), Some("behind"));
@@ -497,6 +512,7 @@ mod tests {
         assert_eq!(MainState::WouldConflict.integration_reason(), None);
         assert_eq!(MainState::Empty.integration_reason(), None);
         assert_eq!(MainState::SameCommit.integration_reason(), None);
+        assert_eq!(MainState::SquashMerged.integration_reason(), None);
         assert_eq!(MainState::Diverged.integration_reason(), None);
         assert_eq!(MainState::Ahead.integration_reason(), None);
         assert_eq!(MainState::Behind.integration_reason(), None);
@@ -568,6 +584,20 @@ mod tests {
             MainState::Integrated(IntegrationReason::Ancestor)
         ));
 
+        // SquashMerged takes priority over WouldConflict (re-edits conflict)
+        assert!(matches!(
+            MainState::from_integration_and_counts(
+                false,
+                true,
+                Some(MainState::SquashMerged),
+                false,
+                false,
+                1,
+                2
+            ),
+            MainState::SquashMerged
+        ));
+
         // SameCommit (via is_same_commit_dirty flag, NOT integration)
         assert!(matches!(
             MainState::from_integration_and_counts(false, false, None, true, false, 0, 0),
@@ -621,7 +651,7 @@ mod tests {
     fn test_operation_state_styled() {
         use insta::assert_snapshot;
         assert!(OperationState::None.styled().is_none());
-        assert_snapshot!(OperationState::Conflicts.styled().unwrap(), @"[31m✘[39m");
+        assert_snapshot!(OperationState::Conflicts.styled().unwrap(), @"[1m[31m✘[0m");
         assert_snapshot!(OperationState::Rebase.styled().unwrap(), @"[33m⤴[39m");
         assert_snapshot!(OperationState::Merge.styled().unwrap(), @"[33m⤵[39m");
     }
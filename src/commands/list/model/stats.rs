@@ -9,21 +9,36 @@ use worktrunk::git::LineDiff;
 #[derive(serde::Serialize, Clone, Default, Debug)]
 pub struct CommitDetails {
     pub timestamp: i64,
+    /// The subject as git reports it; JSON carries it untouched
     pub commit_message: String,
 }
 
+impl CommitDetails {
+    /// The subject flattened to one line for tables and `--long`.
+    pub fn display_message(&self) -> String {
+        crate::display::sanitize_for_display(&self.commit_message)
+    }
+}
+
 /// Ahead/behind counts relative to a base branch.
 #[derive(serde::Serialize, Default, Copy, Clone, Debug)]
 pub struct AheadBehind {
     pub ahead: usize,
     pub behind: usize,
+    /// Counts stop at a shallow clone's boundary, so the true values may be higher
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub lower_bound: bool,
 }
 
 /// Line diff totals for a branch compared to the integration target.
-#[derive(serde::Serialize, Default, Copy, Clone, Debug)]
+#[derive(serde::Serialize, Default, Clone, Debug)]
 pub struct BranchDiffTotals {
     #[serde(rename = "branch_diff")]
     pub diff: LineDiff,
+    /// Commit the diff was taken from: the merge-base, or the default branch
+    /// tip with `--branch-diff-base=head`. None when there's nothing to diff.
+    #[serde(rename = "branch_diff_base", skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
 }
 
 /// Upstream tracking information for a branch.
@@ -14,9 +14,22 @@ use crossterm::{
     terminal::{Clear, ClearType},
 };
 use std::io::{IsTerminal, Write, stdout};
+use worktrunk::styling::TerminalGuard;
 
 use crate::display::truncate_visible;
 
+/// What to do when the finished table has more rows than fit the terminal.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Print every row and let the terminal scroll
+    #[default]
+    Scroll,
+    /// Pipe the finished table through this pager command
+    Page(String),
+    /// Print only the first N rows, then a line counting the rest
+    Truncate(usize),
+}
+
 /// Progressive table that updates rows in-place using crossterm cursor control.
 ///
 /// The table structure is:
@@ -43,6 +56,10 @@ pub struct ProgressiveTable {
     dirty: Vec<usize>,
     /// Whether the skeleton was printed (only true in TTY mode after render_skeleton)
     rendered: bool,
+    /// How the final table handles rows beyond the terminal height
+    overflow: Overflow,
+    /// Shows the cursor again if rendering panics mid-update
+    _terminal_guard: TerminalGuard,
 }
 
 impl ProgressiveTable {
@@ -55,11 +72,13 @@ impl ProgressiveTable {
     /// * `skeletons` - Initial content for each data row (skeleton with known data)
     /// * `initial_footer` - Initial footer message
     /// * `max_width` - Maximum content width (for truncation)
+    /// * `overflow` - What the final render does with rows that don't fit
     pub fn new(
         header: String,
         skeletons: Vec<String>,
         initial_footer: String,
         max_width: usize,
+        overflow: Overflow,
     ) -> Self {
         // Only check terminal height when stdout is a TTY. terminal_size() falls
         // back to stderr/stdin, so it can return Some even for piped stdout.
@@ -68,7 +87,14 @@ impl ProgressiveTable {
         } else {
             None
         };
-        Self::new_with_height(header, skeletons, initial_footer, max_width, term_height)
+        Self::new_with_height(
+            header,
+            skeletons,
+            initial_footer,
+            max_width,
+            term_height,
+            overflow,
+        )
     }
 
     fn new_with_height(
@@ -77,6 +103,7 @@ impl ProgressiveTable {
         initial_footer: String,
         max_width: usize,
         terminal_height: Option<usize>,
+        overflow: Overflow,
     ) -> Self {
         let is_tty = stdout().is_terminal();
         let total_row_count = skeletons.len();
@@ -84,9 +111,12 @@ impl ProgressiveTable {
         // Limit visible rows to fit in terminal: header + rows + spacer + footer = rows + 3
         // Reserve one extra line for the cursor position after printing.
         // Only limit when we have height info — None means non-TTY or unknown.
-        let visible_row_count = terminal_height
+        let mut visible_row_count = terminal_height
             .map(|h| total_row_count.min(h.saturating_sub(4)))
             .unwrap_or(total_row_count);
+        if let Overflow::Truncate(max_rows) = overflow {
+            visible_row_count = visible_row_count.min(max_rows);
+        }
 
         // Build initial lines: header + visible rows + spacer + footer
         let mut lines = Vec::with_capacity(visible_row_count + 3);
@@ -110,6 +140,8 @@ impl ProgressiveTable {
             is_tty,
             dirty: Vec::new(),
             rendered: false,
+            overflow,
+            _terminal_guard: TerminalGuard::new(),
         }
     }
 
@@ -253,10 +285,17 @@ impl ProgressiveTable {
     /// the `MoveUp`-into-scrollback problem.
     pub fn finalize(
         &mut self,
-        final_rows: Vec<String>,
+        mut final_rows: Vec<String>,
         final_footer: String,
     ) -> std::io::Result<()> {
-        if self.row_count < self.total_row_count {
+        let hidden = match self.overflow {
+            Overflow::Truncate(max_rows) if final_rows.len() > max_rows => {
+                final_rows.truncate(max_rows);
+                self.total_row_count - max_rows
+            }
+            _ => 0,
+        };
+        if self.row_count < final_rows.len() || hidden > 0 {
             // Overflow: erase skeleton, print complete table (scrolls naturally)
             debug_assert!(
                 self.rendered,
@@ -266,16 +305,29 @@ impl ProgressiveTable {
             stdout.execute(MoveUp(self.lines.len() as u16))?;
             stdout.execute(MoveToColumn(0))?;
             stdout.execute(Clear(ClearType::FromCursorDown))?;
-            writeln!(stdout, "{}", self.lines[0])?; // header (unchanged)
-            for row in &final_rows {
-                writeln!(stdout, "{}", truncate_visible(row, self.max_width))?;
+
+            let mut lines = vec![self.lines[0].clone()]; // header (unchanged)
+            lines.extend(
+                final_rows
+                    .iter()
+                    .map(|row| truncate_visible(row, self.max_width)),
+            );
+            if hidden > 0 {
+                let dim = anstyle::Style::new().dimmed();
+                lines.push(format!("{dim}…and {hidden} more (use --no-pager){dim:#}"));
             }
-            writeln!(stdout)?;
-            writeln!(
-                stdout,
-                "{}",
-                truncate_visible(&final_footer, self.max_width)
-            )?;
+            lines.push(String::new());
+            lines.push(truncate_visible(&final_footer, self.max_width));
+            let mut text = lines.join("\n");
+            text.push('\n');
+
+            if let Overflow::Page(pager) = &self.overflow
+                && self.row_count < final_rows.len()
+                && crate::pager::page_to_stdout(&text, pager)?
+            {
+                return Ok(());
+            }
+            write!(stdout, "{text}")?;
             stdout.flush()
         } else {
             // Normal: update rows in-place + footer
@@ -303,8 +355,13 @@ mod tests {
         let skeletons = vec!["row0".to_string(), "row1".to_string()];
         let footer = "loading".to_string();
 
-        let mut table =
-            ProgressiveTable::new(header.clone(), skeletons.clone(), footer.clone(), 80);
+        let mut table = ProgressiveTable::new(
+            header.clone(),
+            skeletons.clone(),
+            footer.clone(),
+            80,
+            Overflow::Scroll,
+        );
 
         // header + 2 rows + spacer + footer
         assert_eq!(table.lines.len(), 5);
@@ -337,7 +394,8 @@ mod tests {
         let skeletons = vec!["short".to_string()];
         let footer = "loading...".to_string();
 
-        let table = ProgressiveTable::new(long_header.clone(), skeletons, footer, 20);
+        let table =
+            ProgressiveTable::new(long_header.clone(), skeletons, footer, 20, Overflow::Scroll);
 
         // Header should be truncated (shorter than original)
         assert!(
@@ -354,7 +412,8 @@ mod tests {
         let skeletons = vec!["row0".to_string()];
         let footer = "loading".to_string();
 
-        let mut table = ProgressiveTable::new(header, skeletons, footer.clone(), 80);
+        let mut table =
+            ProgressiveTable::new(header, skeletons, footer.clone(), 80, Overflow::Scroll);
 
         // First footer should match
         assert_eq!(table.lines.last().unwrap(), &footer);
@@ -371,6 +430,7 @@ mod tests {
             vec!["row".to_string()],
             "footer".to_string(),
             80,
+            Overflow::Scroll,
         );
 
         // In test environment, stdout is typically not a TTY
@@ -384,6 +444,7 @@ mod tests {
             vec!["a".to_string(), "b".to_string(), "c".to_string()],
             "f".to_string(),
             80,
+            Overflow::Scroll,
         );
 
         assert_eq!(table.row_count, 3);
@@ -398,6 +459,7 @@ mod tests {
             vec!["row0".to_string(), "row1".to_string()],
             "footer".to_string(),
             80,
+            Overflow::Scroll,
         );
 
         // Out-of-bounds update returns false
@@ -415,6 +477,7 @@ mod tests {
             vec!["row".to_string()],
             "loading...".to_string(),
             80,
+            Overflow::Scroll,
         );
 
         // Without render_skeleton(), finalize updates footer but doesn't print
@@ -434,6 +497,7 @@ mod tests {
             vec!["row0".to_string(), "row1".to_string()],
             "footer".to_string(),
             80,
+            Overflow::Scroll,
         );
 
         // Initially no dirty lines
@@ -461,6 +525,7 @@ mod tests {
             vec!["row0".to_string(), "row1".to_string()],
             "footer".to_string(),
             80,
+            Overflow::Scroll,
         );
 
         // Simulate TTY render state (in tests, is_tty is false so render_skeleton is a no-op,
@@ -486,6 +551,7 @@ mod tests {
             "loading".into(),
             80,
             Some(8),
+            Overflow::Scroll,
         );
 
         assert_eq!(table.row_count, 4);
@@ -510,6 +576,7 @@ mod tests {
             "loading".into(),
             80,
             Some(20),
+            Overflow::Scroll,
         );
 
         assert_eq!(table.row_count, 3);
@@ -527,6 +594,7 @@ mod tests {
             "loading".into(),
             80,
             Some(9),
+            Overflow::Scroll,
         );
 
         assert_eq!(table.row_count, 5);
@@ -543,6 +611,7 @@ mod tests {
             "loading".into(),
             80,
             Some(8),
+            Overflow::Scroll,
         );
 
         assert_eq!(table.row_count, 4);
@@ -559,6 +628,7 @@ mod tests {
             "loading".into(),
             80,
             Some(8),
+            Overflow::Scroll,
         );
 
         // Can update visible rows (0..4)
@@ -580,6 +650,7 @@ mod tests {
             "loading".into(),
             80,
             Some(3),
+            Overflow::Scroll,
         );
 
         assert_eq!(table.row_count, 0);
@@ -599,10 +670,30 @@ mod tests {
             "loading".into(),
             80,
             None,
+            Overflow::Scroll,
         );
 
         assert_eq!(table.row_count, 10);
         assert_eq!(table.total_row_count, 10);
         assert_eq!(table.row_count, table.total_row_count);
     }
+
+    #[test]
+    fn truncate_caps_visible_rows() {
+        // --max-rows 3 with room for 6: the skeleton only shows 3
+        let skeletons: Vec<String> = (0..10).map(|i| format!("row{i}")).collect();
+        let table = ProgressiveTable::new_with_height(
+            "header".into(),
+            skeletons,
+            "loading".into(),
+            80,
+            Some(10),
+            Overflow::Truncate(3),
+        );
+
+        assert_eq!(table.row_count, 3);
+        assert_eq!(table.total_row_count, 10);
+        // header + 3 rows + spacer + footer
+        assert_eq!(table.lines.len(), 6);
+    }
 }
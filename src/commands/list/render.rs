@@ -1,9 +1,18 @@
-use crate::display::{format_relative_time_short, shorten_path, truncate_to_width};
+use crate::display::{
+    ellipsize_path_middle, format_relative_time_short, shorten_path, truncate_to_width,
+};
 use anstyle::Style;
+use std::borrow::Cow;
+use std::ops::Range;
 use std::path::Path;
+use std::sync::LazyLock;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{Stream, StyledLine, hyperlink_stdout, supports_hyperlinks};
+use worktrunk::styling::{
+    DELETION, Stream, StyledLine, StyledString, hyperlink_stdout, supports_hyperlinks, theme,
+    visual_width,
+};
 
+use super::ci_status::CiStatus;
 use super::collect::parse_port_from_url;
 use super::columns::{ColumnKind, DiffVariant};
 use super::layout::{ColumnFormat, ColumnLayout, DiffColumnConfig, LayoutConfig};
@@ -60,7 +69,8 @@ impl DiffColumnConfig {
     }
 
     /// Render a subcolumn value with symbol and padding to fixed width
-    /// Numbers are right-aligned on the ones column (e.g., " +2", "+53")
+    /// The symbol stays at the left edge and numbers are right-aligned on the
+    /// ones column (e.g., "+  2" above "+ 53"), so magnitudes line up vertically
     /// For compact notation (C/K suffix), renders bold (e.g., bold "+6C", bold "+5K")
     fn render_subcolumn(
         segment: &mut StyledLine,
@@ -77,19 +87,15 @@ impl DiffColumnConfig {
             (value.to_string(), false)
         };
         let content_len = 1 + value_str.width(); // symbol + display width
-        let padding_needed = width.saturating_sub(content_len);
+        // Padding goes between symbol and number to right-align the digits
+        let padding = " ".repeat(width.saturating_sub(content_len));
+        let content = format!("{symbol}{padding}{value_str}");
 
-        // Add left padding for right-alignment
-        if padding_needed > 0 {
-            segment.push_raw(" ".repeat(padding_needed));
-        }
-
-        // Add styled content - bold entire value if using compact notation (C/K suffix)
-        // to emphasize approximation
+        // Bold entire value if using compact notation (C/K suffix) to emphasize approximation
         if is_compact {
-            segment.push_styled(format!("{}{}", symbol, value_str), style.bold());
+            segment.push_styled(content, style.bold());
         } else {
-            segment.push_styled(format!("{}{}", symbol, value_str), style);
+            segment.push_styled(content, style);
         }
     }
 
@@ -148,7 +154,7 @@ impl DiffColumnConfig {
                 symbols.negative,
                 negative,
                 negative_width,
-                self.display.negative_style,
+                self.display.negative_style_for(negative),
                 negative_overflow,
                 self.display.variant,
             );
@@ -161,6 +167,16 @@ impl DiffColumnConfig {
     }
 }
 
+/// Check every rendered row against the layout (always on in unit tests).
+///
+/// Integration tests set it too, so each `wt list` snapshot doubles as an
+/// alignment check.
+const ASSERT_LAYOUT_ENV: &str = "WORKTRUNK_ASSERT_LAYOUT";
+
+static ASSERT_LAYOUT: LazyLock<bool> = LazyLock::new(|| {
+    cfg!(test) || std::env::var_os(ASSERT_LAYOUT_ENV).is_some_and(|value| value == "1")
+});
+
 impl LayoutConfig {
     fn render_line<F>(&self, mut render_cell: F) -> StyledLine
     where
@@ -172,9 +188,12 @@ impl LayoutConfig {
         }
 
         let last_index = self.columns.len() - 1;
+        // Segments each column's cell occupies, for the alignment check
+        let mut cells = Vec::with_capacity(self.columns.len());
 
         for (index, column) in self.columns.iter().enumerate() {
             line.pad_to(column.start);
+            let first_segment = line.segments.len();
             let cell = render_cell(column);
             let cell_width = cell.width();
 
@@ -190,6 +209,7 @@ impl LayoutConfig {
             }
 
             line.extend(cell);
+            cells.push(first_segment..line.segments.len());
 
             // Pad to end of column (unless it's the last column)
             if index != last_index {
@@ -200,9 +220,43 @@ impl LayoutConfig {
         let final_width = line.width();
         log::debug!("Rendered line width: {}", final_width);
 
+        if *ASSERT_LAYOUT {
+            self.assert_aligned(&line, &cells);
+        }
+
         line
     }
 
+    /// Panic unless every cell starts at its column's `start` and fits its
+    /// `width`.
+    ///
+    /// Measures the rendered, ANSI-stripped text rather than summing segment
+    /// widths, so it also catches characters whose width depends on their
+    /// neighbours (variation selectors, joiners) and widths taken from styled
+    /// strings.
+    fn assert_aligned(&self, line: &StyledLine, cells: &[Range<usize>]) {
+        let measure = |segments: &[StyledString]| {
+            visual_width(
+                &segments
+                    .iter()
+                    .map(StyledString::render)
+                    .collect::<String>(),
+            )
+        };
+        for (column, cell) in self.columns.iter().zip(cells) {
+            let start = measure(&line.segments[..cell.start]);
+            let width = measure(&line.segments[cell.clone()]);
+            assert!(
+                start == column.start && width <= column.width,
+                "{:?} cell starts at {start} and is {width} wide; layout has it at {} with width {}\nrow: {:?}",
+                column.kind,
+                column.start,
+                column.width,
+                line.plain_text(),
+            );
+        }
+    }
+
     pub fn format_header_line(&self) -> String {
         self.render_header_line().render()
     }
@@ -231,6 +285,38 @@ impl LayoutConfig {
         })
     }
 
+    /// Render the header for unaligned output: column names separated by tabs.
+    pub fn format_unaligned_header(&self) -> String {
+        let style = Style::new().bold();
+        self.columns
+            .iter()
+            .map(|column| format!("{style}{}{style:#}", column.header))
+            .collect::<Vec<_>>()
+            .join("\t")
+    }
+
+    /// Render a list item for unaligned output: each cell stripped of its
+    /// padding, separated by tabs.
+    pub fn format_unaligned_line(&self, item: &ListItem) -> String {
+        self.columns
+            .iter()
+            .map(|column| {
+                column
+                    .render_cell(
+                        item,
+                        &self.status_position_mask,
+                        &self.main_worktree_path,
+                        self.max_message_len,
+                        self.max_summary_len,
+                    )
+                    .render()
+                    .trim()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\t")
+    }
+
     pub fn format_list_item_line(&self, item: &ListItem) -> String {
         self.render_list_item_line(item).render()
     }
@@ -253,7 +339,9 @@ impl LayoutConfig {
     /// Used for both worktrees and branch-only items; branch-only rows render an empty path
     /// and a blank gutter placeholder.
     pub fn render_skeleton_row(&self, item: &ListItem) -> StyledLine {
-        let branch = item.branch_name();
+        let branch = item
+            .branch_label()
+            .unwrap_or(Cow::Borrowed(item.branch_name()));
         let wt_data = item.worktree_data();
         let shortened_path = item
             .worktree_path()
@@ -279,12 +367,12 @@ impl LayoutConfig {
                 }
                 ColumnKind::Branch => {
                     // Show actual branch name (no dim - start normal, gray out later if removable)
-                    cell.push_raw(branch.to_string());
+                    cell.push_raw(truncate_to_width(&branch, col.width));
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Path => {
                     // Show actual path (no dim - start normal, gray out later if removable)
-                    cell.push_raw(&shortened_path);
+                    cell.push_raw(ellipsize_path_middle(&shortened_path, col.width));
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Commit => {
@@ -348,6 +436,10 @@ impl ColumnLayout {
         max_message_len: usize,
         max_summary_len: usize,
     ) -> StyledLine {
+        if item.is_incomplete(self.kind) {
+            return self.placeholder_cell("~");
+        }
+
         // Compute derived values inline (avoids separate context struct)
         let worktree_data = item.worktree_data();
         let text_style = item.should_dim().then(|| Style::new().dimmed());
@@ -371,8 +463,8 @@ impl ColumnLayout {
                 cell
             }
             ColumnKind::Branch => {
-                let text = item.branch.as_deref().unwrap_or("-");
-                self.render_text_cell(text, text_style)
+                let text = item.branch_label();
+                self.render_text_cell(text.as_deref().unwrap_or("-"), text_style)
             }
             ColumnKind::Status => {
                 let Some(ref status_symbols) = item.status_symbols else {
@@ -392,17 +484,21 @@ impl ColumnLayout {
                 self.render_diff_cell(diff.added, diff.deleted)
             }
             ColumnKind::AheadBehind => {
-                if item.is_main() {
+                // A deleted branch has nothing to compare; its tasks never run
+                if item.is_compare_base() || item.is_branch_deleted() {
                     return StyledLine::new();
                 }
                 match item.counts {
                     Some(counts) if counts.ahead == 0 && counts.behind == 0 => StyledLine::new(),
+                    Some(counts) if counts.lower_bound => {
+                        mark_lower_bound(self.render_diff_cell(counts.ahead, counts.behind))
+                    }
                     Some(counts) => self.render_diff_cell(counts.ahead, counts.behind),
                     None => self.placeholder_cell("⋯"), // Not loaded yet
                 }
             }
             ColumnKind::BranchDiff => {
-                if item.is_main() {
+                if item.is_compare_base() || item.is_branch_deleted() {
                     return StyledLine::new();
                 }
                 match item.branch_diff() {
@@ -410,12 +506,28 @@ impl ColumnLayout {
                     None => self.placeholder_cell("…"), // Task was skipped
                 }
             }
+            ColumnKind::Unmerged => {
+                if item.is_compare_base() || item.is_branch_deleted() {
+                    return StyledLine::new();
+                }
+                match item.unmerged_commits {
+                    Some(0) => StyledLine::new(),
+                    Some(count) => {
+                        let mut cell = StyledLine::new();
+                        let text = count.to_string();
+                        cell.push_raw(" ".repeat(self.width.saturating_sub(text.width())));
+                        cell.push_styled(text, theme().ahead);
+                        cell
+                    }
+                    None => self.placeholder_cell("…"), // Task was skipped
+                }
+            }
             ColumnKind::Path => {
                 let Some(data) = worktree_data else {
                     return StyledLine::new();
                 };
                 let path_str = shorten_path(&data.path, main_worktree_path);
-                self.render_text_cell(&path_str, text_style)
+                self.render_text_cell(&ellipsize_path_middle(&path_str, self.width), text_style)
             }
             ColumnKind::Upstream => {
                 let upstream = item.upstream();
@@ -436,6 +548,10 @@ impl ColumnLayout {
                 self.render_diff_cell(active.ahead, active.behind)
             }
             ColumnKind::Time => {
+                // Unborn branch: no commit to date (empty rather than a placeholder)
+                if item.head() == worktrunk::git::NULL_OID {
+                    return StyledLine::new();
+                }
                 let Some(ref commit) = item.commit else {
                     return self.placeholder_cell("⋯");
                 };
@@ -464,7 +580,22 @@ impl ColumnLayout {
                 }
                 cell.truncate_to_width(self.width)
             }
+            ColumnKind::Activity => {
+                // Last recorded command: red if it failed, dim otherwise
+                let Some(entry) = worktree_data.and_then(|data| data.activity.last()) else {
+                    return StyledLine::new();
+                };
+                let command = entry.cmd.lines().next().unwrap_or_default();
+                let style = match entry.exit {
+                    Some(code) if code != 0 => DELETION,
+                    _ => Style::new().dimmed(),
+                };
+                self.render_text_cell(command, Some(style))
+            }
             ColumnKind::CiStatus => {
+                if item.is_branch_deleted() {
+                    return StyledLine::new();
+                }
                 // Check display field first for pending indicators during progressive rendering
                 // (works for both worktrees and branches)
                 if let Some(ref ci_display) = item.display.ci_status_display {
@@ -516,11 +647,21 @@ impl ColumnLayout {
                 }
             }
             ColumnKind::Message => {
+                if item.is_branch_deleted() {
+                    // The Branch column already says so; there's no commit to describe
+                    return StyledLine::new();
+                }
+                if item.head() == worktrunk::git::NULL_OID {
+                    let mut cell = StyledLine::new();
+                    let msg = truncate_to_width("(no commits yet)", max_message_len);
+                    cell.push_styled(msg, Style::new().dimmed());
+                    return cell;
+                }
                 let Some(ref commit) = item.commit else {
                     return self.placeholder_cell("⋯");
                 };
                 let mut cell = StyledLine::new();
-                let msg = truncate_to_width(&commit.commit_message, max_message_len);
+                let msg = truncate_to_width(&commit.display_message(), max_message_len);
                 cell.push_styled(msg, Style::new().dimmed());
                 cell
             }
@@ -528,6 +669,24 @@ impl ColumnLayout {
     }
 }
 
+/// Turn the last padding space of a right-aligned diff cell into `≥`, marking
+/// ahead/behind counts that stop at a shallow clone's boundary.
+///
+/// Cells without leading padding are returned unchanged; JSON and `--long`
+/// still carry the marker.
+fn mark_lower_bound(mut cell: StyledLine) -> StyledLine {
+    let Some(first) = cell.segments.first_mut() else {
+        return cell;
+    };
+    if first.style.is_some() || first.text.is_empty() || first.text.contains(|c: char| c != ' ') {
+        return cell;
+    }
+    first.text.pop();
+    cell.segments
+        .insert(1, StyledString::styled("≥", Style::new().dimmed()));
+    cell
+}
+
 /// Format URL cell with optional hyperlink.
 ///
 /// When the terminal supports OSC 8 hyperlinks, shows just the port (e.g., `:3000`)
@@ -543,6 +702,42 @@ fn format_url_cell(url: &str) -> String {
     url.to_string()
 }
 
+/// Checks named on a `--ci-details` line before the rest are counted
+const MAX_CI_DETAIL_CHECKS: usize = 5;
+
+/// Format the `--ci-details` line printed under a row whose CI failed or is
+/// still running, e.g. `  ✗ build-linux  ✗ lint  ● e2e`.
+///
+/// Printed on its own line after the row, so it plays no part in column
+/// widths. Names are dimmed; only the state symbols keep their CI colors.
+pub(super) fn format_ci_details_line(item: &ListItem) -> Option<String> {
+    let Some(Some(pr_status)) = &item.pr_status else {
+        return None;
+    };
+    if pr_status.checks.is_empty() {
+        return None;
+    }
+    let dim = Style::new().dimmed();
+    let mut parts: Vec<String> = pr_status
+        .checks
+        .iter()
+        .take(MAX_CI_DETAIL_CHECKS)
+        .map(|check| {
+            let symbol = match check.status {
+                CiStatus::Failed => "✗",
+                _ => "●",
+            };
+            let style = Style::new().fg_color(Some(check.status.color().into()));
+            format!("{style}{symbol}{style:#} {dim}{}{dim:#}", check.name)
+        })
+        .collect();
+    let hidden = pr_status.checks.len().saturating_sub(MAX_CI_DETAIL_CHECKS);
+    if hidden > 0 {
+        parts.push(format!("{dim}+{hidden} more{dim:#}"));
+    }
+    Some(format!("  {}", parts.join("  ")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -591,17 +786,10 @@ mod tests {
             "All aligned outputs should have same width"
         );
 
-        // Verify right-alignment: smaller numbers have leading spaces
-        assert!(
-            clean2.starts_with(' '),
-            "54 should have leading space: '{}'",
-            clean2
-        );
-        assert!(
-            clean3.starts_with(' '),
-            "9 should have leading spaces: '{}'",
-            clean3
-        );
+        // Verify right-alignment: smaller numbers pad between sign and digits
+        assert_eq!(clean1, "+310 -112");
+        assert_eq!(clean2, "+ 54 - 63");
+        assert_eq!(clean3, "+  9 -  3");
     }
 
     #[test]
@@ -629,8 +817,8 @@ mod tests {
         let del_only = config.format_aligned(0, 5);
         let clean_del = del_only.ansi_strip().into_owned();
         assert!(
-            clean_del.contains("-5"),
-            "Should contain -5: '{}'",
+            clean_del.contains("-  5"),
+            "Should contain -  5: '{}'",
             clean_del
         );
 
@@ -1063,7 +1251,7 @@ mod tests {
         use super::super::columns::DiffVariant;
 
         // Test that numbers are right-aligned on the ones column
-        // When we have 2-digit allocation but use 1-digit values, they should have leading space
+        // When we have 2-digit allocation but use 1-digit values, the gap goes after the sign
         let total = 8; // 3 (added) + 1 (separator) + 3 (deleted) + 1 (leading padding)
 
         // Test case 1: (53, 7) - large added, small deleted
@@ -1084,7 +1272,7 @@ mod tests {
         );
         let rendered1 = result1.render();
         let clean1 = rendered1.ansi_strip().into_owned();
-        assert_eq!(clean1, " +53  -7");
+        assert_eq!(clean1, " +53 - 7");
 
         // Test case 2: (33, 23) - both medium
         let result2 = format_diff_like_column(
@@ -1124,13 +1312,13 @@ mod tests {
         );
         let rendered3 = result3.render();
         let clean3 = rendered3.ansi_strip().into_owned();
-        assert_eq!(clean3, "  +2  -2");
+        assert_eq!(clean3, " + 2 - 2");
 
         // Verify vertical alignment: the ones digits should be in the same column
         // The ones digit should be at position 3 for all cases (with 2-digit allocation)
-        // ' +53  -7' -> position 3 is '3'
+        // ' +53 - 7' -> position 3 is '3'
         // ' +33 -23' -> position 3 is '3' (second '3', the ones digit)
-        // '  +2  -2' -> position 3 is '2'
+        // ' + 2 - 2' -> position 3 is '2'
         let ones_pos = 3;
         assert_eq!(
             clean1.chars().nth(ones_pos).unwrap(),
@@ -1178,7 +1366,7 @@ mod tests {
         insta::assert_snapshot!(result.render(), @"[32m+999[0m [31m-999[0m");
 
         // Case 2: Positive overflow (1000 exceeds 3 digits)
-        // Should show: "+1K -500" (positive with K suffix, negative normal)
+        // Should show: "+ 1K -500" (positive with K suffix, negative normal)
         let overflow_result = format_diff_like_column(
             1000,
             500,
@@ -1195,10 +1383,10 @@ mod tests {
             },
         );
         assert_eq!(overflow_result.width(), total);
-        insta::assert_snapshot!(overflow_result.render(), @" [1m[32m+1K[0m [31m-500[0m");
+        insta::assert_snapshot!(overflow_result.render(), @"[1m[32m+ 1K[0m [31m-500[0m");
 
         // Case 3: Negative overflow
-        // Should show: "+500 -1K" (positive normal, negative with K suffix)
+        // Should show: "+500 - 1K" (positive normal, negative with K suffix)
         let overflow_result2 = format_diff_like_column(
             500,
             1000,
@@ -1215,7 +1403,7 @@ mod tests {
             },
         );
         assert_eq!(overflow_result2.width(), total);
-        insta::assert_snapshot!(overflow_result2.render(), @"[32m+500[0m  [1m[31m-1K[0m");
+        insta::assert_snapshot!(overflow_result2.render(), @"[32m+500[0m [1m[31m- 1K[0m");
 
         // Case 4: Extreme overflow (>= 10K values show ∞ to avoid false precision)
         let extreme_overflow = format_diff_like_column(
@@ -1238,7 +1426,7 @@ mod tests {
             total,
             "100K overflow should fit in allocated width"
         );
-        insta::assert_snapshot!(extreme_overflow.render(), @"  [1m[32m+∞[0m   [1m[31m-∞[0m");
+        insta::assert_snapshot!(extreme_overflow.render(), @"[1m[32m+  ∞[0m [1m[31m-  ∞[0m");
 
         // Test overflow with Arrows variant (↑ and ↓)
         let arrow_total = 7;
@@ -1284,6 +1472,37 @@ mod tests {
         insta::assert_snapshot!(arrow_overflow2.render(), @"[32m↑50[0m [1m[31m↓1K[0m");
     }
 
+    #[test]
+    fn test_behind_severity_colors_keep_alignment() {
+        use super::super::columns::ColumnKind;
+
+        let config = DiffColumnConfig {
+            positive_digits: 2,
+            negative_digits: 2,
+            total_width: 7,
+            display: ColumnKind::AheadBehind.diff_display_config().unwrap(),
+        };
+        let low = format_diff_like_column(3, 9, config);
+        let warn = format_diff_like_column(3, 10, config);
+        let critical = format_diff_like_column(3, 50, config);
+
+        insta::assert_snapshot!(low.render(), @"[32m↑ 3[0m [2m[31m↓ 9[0m");
+        insta::assert_snapshot!(warn.render(), @"[32m↑ 3[0m [33m↓10[0m");
+        insta::assert_snapshot!(critical.render(), @"[32m↑ 3[0m [31m↓50[0m");
+
+        // Severity only changes escape codes, never the visible layout
+        for line in [&low, &warn, &critical] {
+            assert_eq!(line.width(), 7);
+            assert_eq!(line.render().ansi_strip().width(), 7);
+        }
+
+        // Line diffs and upstream arrows don't escalate
+        let working = ColumnKind::WorkingDiff.diff_display_config().unwrap();
+        assert_eq!(working.negative_style_for(500), DELETION);
+        let upstream = ColumnKind::Upstream.diff_display_config().unwrap();
+        assert_eq!(upstream.negative_style_for(500), DELETION.dimmed());
+    }
+
     #[test]
     fn test_summary_column_rendering() {
         use super::super::layout::ColumnLayout;
@@ -1292,7 +1511,7 @@ mod tests {
 
         let summary_col = ColumnLayout {
             kind: ColumnKind::Summary,
-            header: "Summary",
+            header: "Summary".to_string(),
             start: 0,
             width: 40,
             format: ColumnFormat::Text,
@@ -1317,4 +1536,96 @@ mod tests {
         let cell = summary_col.render_cell(&item, &mask, &main_path, 50, 40);
         insta::assert_snapshot!(cell.render(), @"Add user authentication");
     }
+
+    /// Next value in `0..bound` from a xorshift generator: repeatable without a
+    /// dependency.
+    fn next_random(state: &mut u64, bound: usize) -> usize {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        (*state % bound as u64) as usize
+    }
+
+    /// Random text built from pieces whose width is easy to get wrong.
+    fn random_text(state: &mut u64, max_pieces: usize) -> String {
+        const PIECES: &[&str] = &[
+            "a",
+            "fix-",
+            "/",
+            " ",
+            "é",
+            "e\u{301}", // e + combining acute
+            "日本",
+            "ｗｉｄｅ",
+            "☕",
+            "⚠",
+            "⚠\u{FE0F}", // text symbol promoted to emoji width
+            "❤\u{FE0F}",
+            "1\u{FE0F}\u{20E3}",      // keycap
+            "👨\u{200D}👩\u{200D}👧", // joined family
+            "🇺🇸",
+            "\u{200B}", // zero-width space
+        ];
+        let count = 1 + next_random(state, max_pieces);
+        (0..count)
+            .map(|_| PIECES[next_random(state, PIECES.len())])
+            .collect()
+    }
+
+    /// Fuzz-ish check: random unicode branch names, paths, and messages at
+    /// widths from cramped to roomy, in both layout modes. The alignment check
+    /// in `render_line` (always on in tests) does the asserting.
+    #[test]
+    fn test_random_unicode_rows_stay_aligned() {
+        use super::super::layout::calculate_layout_with_width;
+        use super::super::model::{CommitDetails, ItemKind, WorktreeData};
+        use std::collections::HashSet;
+        use std::path::PathBuf;
+        use worktrunk::config::{ListConfig, ListLayout};
+
+        let mut state = 0x2545_F491_4F6C_DD1D;
+        let main_path = PathBuf::from("/repo");
+        for round in 0..40 {
+            let items: Vec<ListItem> = (0..4)
+                .map(|i| {
+                    let branch = random_text(&mut state, 12);
+                    let mut item = ListItem::new_branch("abc12345".into(), branch);
+                    item.commit = Some(CommitDetails {
+                        timestamp: 0,
+                        commit_message: random_text(&mut state, 30),
+                    });
+                    if i % 2 == 0 {
+                        item.kind = ItemKind::Worktree(Box::new(WorktreeData {
+                            path: main_path.join(random_text(&mut state, 8)),
+                            branch_worktree_mismatch: true,
+                            ..Default::default()
+                        }));
+                    }
+                    item
+                })
+                .collect();
+
+            for layout in [ListLayout::Drop, ListLayout::Shrink] {
+                let list_config = ListConfig {
+                    layout: Some(layout),
+                    ..Default::default()
+                };
+                let width = 40 + round * 5;
+                let config = calculate_layout_with_width(
+                    &items,
+                    &HashSet::new(),
+                    width,
+                    &main_path,
+                    None,
+                    &list_config,
+                    None,
+                );
+                config.render_header_line();
+                for item in &items {
+                    config.render_skeleton_row(item);
+                    config.render_list_item_line(item);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,92 @@
+//! Tab-separated output for `wt list --format=tsv`.
+//!
+//! A header row of fixed machine names, then one row per item, for importing
+//! into spreadsheets. Every value is read out of the item's JSON (see
+//! [`JsonItem`]), so TSV and JSON can't disagree on what a branch's numbers
+//! are. Cells are plain text with no width logic: tabs and newlines inside a
+//! value become spaces, and absent values are empty.
+
+use serde_json::Value;
+
+use super::json_output::JsonItem;
+
+/// Header name, and where the value sits in the item's JSON.
+const COLUMNS: &[(&str, &str)] = &[
+    ("branch", "/branch"),
+    ("path", "/path"),
+    ("ahead", "/main/ahead"),
+    ("behind", "/main/behind"),
+    ("working_added", "/working_tree/diff/added"),
+    ("working_deleted", "/working_tree/diff/deleted"),
+    ("age_seconds", "/commit/timestamp"),
+    ("state", "/main_state"),
+    ("ci", "/ci/status"),
+    ("commit", "/commit/short_sha"),
+    ("message", "/commit/message"),
+];
+
+/// Render `items` as TSV, ending in a newline. Ages are measured from `now`.
+pub(super) fn format_tsv(items: &[JsonItem], now: i64) -> serde_json::Result<String> {
+    let header: Vec<&str> = COLUMNS.iter().map(|(name, _)| *name).collect();
+    let mut out = header.join("\t");
+    out.push('\n');
+
+    for item in items {
+        let json = serde_json::to_value(item)?;
+        let cells: Vec<String> = COLUMNS
+            .iter()
+            .map(|&(name, pointer)| match (name, json.pointer(pointer)) {
+                ("age_seconds", Some(Value::Number(timestamp))) => timestamp
+                    .as_i64()
+                    .map(|timestamp| (now - timestamp).max(0).to_string())
+                    .unwrap_or_default(),
+                (_, None | Some(Value::Null)) => String::new(),
+                (_, Some(Value::String(text))) => text.replace(['\t', '\n', '\r'], " "),
+                (_, Some(value)) => value.to_string(),
+            })
+            .collect();
+        out.push_str(&cells.join("\t"));
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::model::ListItem;
+
+    #[test]
+    fn test_format_tsv() {
+        let mut item = ListItem::new_branch("abc1234def".into(), "feature".into());
+        item.commit = Some(crate::commands::list::model::CommitDetails {
+            timestamp: 1_000,
+            commit_message: "Fix\tthe thing\r\nproperly".into(),
+        });
+        let items = [JsonItem::from_list_item(&item)];
+
+        let tsv = format_tsv(&items, 4_600).unwrap();
+        let rows: Vec<Vec<&str>> = tsv.lines().map(|line| line.split('\t').collect()).collect();
+        let header: Vec<&str> = COLUMNS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(rows[0], header);
+        // A branch without a worktree: no path, diff, or counts
+        assert_eq!(
+            rows[1],
+            [
+                "feature",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "3600",
+                "",
+                "",
+                "abc1234",
+                "Fix the thing  properly"
+            ]
+        );
+        assert_eq!(rows.len(), 2);
+    }
+}
@@ -1,8 +1,12 @@
 use anyhow::Context;
+use color_print::cformat;
+use shell_escape::escape;
 use worktrunk::HookType;
 use worktrunk::config::{Approvals, UserConfig};
-use worktrunk::git::Repository;
-use worktrunk::styling::{eprintln, info_message};
+use worktrunk::git::{GitError, Repository, WorkingTree};
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, success_message, warning_message,
+};
 
 use super::command_approval::approve_command_batch;
 use super::command_executor::CommandContext;
@@ -11,6 +15,7 @@ use super::context::CommandEnv;
 use super::hooks::{HookFailureStrategy, execute_hook};
 use super::project_config::{ApprovableCommand, collect_commands_for_hooks};
 use super::repository_ext::RepositoryCliExt;
+use super::restack::{find_stacked_branches, restack_branches, warn_stacked};
 use super::worktree::{
     BranchDeletionMode, MergeOperations, RemoveResult, get_path_mismatch, handle_push,
 };
@@ -21,6 +26,8 @@ use super::worktree::{
 /// (project-specific merged with global) is used. If that's also None, defaults apply.
 pub struct MergeOptions<'a> {
     pub target: Option<&'a str>,
+    /// Merge this branch's worktree rather than the current one (`--branch`)
+    pub branch: Option<&'a str>,
     /// CLI override for squash. None = use effective config default.
     pub squash: Option<bool>,
     /// CLI override for commit. None = use effective config default.
@@ -34,6 +41,118 @@ pub struct MergeOptions<'a> {
     pub yes: bool,
     /// CLI override for stage mode. None = use effective config default.
     pub stage: Option<super::commit::StageMode>,
+    /// Rebase branches stacked on this one onto the target afterwards
+    pub restack: bool,
+    /// Tag the merged commit
+    pub tag: Option<MergeTag<'a>>,
+}
+
+/// An annotated tag to create on the target branch once merged (`--tag`)
+pub struct MergeTag<'a> {
+    pub name: &'a str,
+    /// Defaults to the tag name
+    pub message: Option<&'a str>,
+    pub sign: bool,
+    /// Push to the primary remote afterwards
+    pub push: bool,
+}
+
+/// Refuse a tag name git won't take, or one already in use, before the merge
+/// changes anything.
+fn validate_tag(repo: &Repository, name: &str) -> anyhow::Result<()> {
+    let refname = format!("refs/tags/{name}");
+    if !repo.run_command_check(&["check-ref-format", "--allow-onelevel", &refname])? {
+        return Err(GitError::InvalidTagName {
+            tag: name.to_string(),
+        }
+        .into());
+    }
+    if repo.run_command_check(&["rev-parse", "--verify", "--quiet", &refname])? {
+        return Err(GitError::TagAlreadyExists {
+            tag: name.to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Tag the tip of `target_branch`, then push the tag if asked.
+///
+/// Runs after the merge has landed, so failures are warnings: the merge
+/// stays, and the hint gives the `git` command that finishes the job.
+fn create_tag(wt: &WorkingTree<'_>, tag: &MergeTag<'_>, target_branch: &str) -> anyhow::Result<()> {
+    let commit = wt
+        .run_command(&["rev-parse", &format!("refs/heads/{target_branch}")])?
+        .trim()
+        .to_string();
+    let message = tag.message.unwrap_or(tag.name);
+    let mut args = vec!["tag", if tag.sign { "--sign" } else { "--annotate" }];
+    args.extend(["--message", message, tag.name, &commit]);
+
+    let output = wt.run_command_output(&args)?;
+    if !output.status.success() {
+        report_tag_failure(
+            cformat!("Merged, but couldn't create tag <bold>{}</>", tag.name),
+            &output.stderr,
+            &args,
+        );
+        return Ok(());
+    }
+
+    let short = &commit[..commit.len().min(7)];
+    let signed = if tag.sign { "signed " } else { "" };
+    if !tag.push {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Created {signed}tag <bold>{}</> @ <dim>{short}</>",
+                tag.name
+            ))
+        );
+        return Ok(());
+    }
+
+    let remote = wt.repo().primary_remote()?;
+    let refspec = format!("refs/tags/{}", tag.name);
+    let push_args = ["push", remote.as_str(), refspec.as_str()];
+    let output = wt.run_network_command_output(&push_args)?;
+    if !output.status.success() {
+        report_tag_failure(
+            cformat!(
+                "Created {signed}tag <bold>{}</> @ <dim>{short}</>, but couldn't push it to <bold>{remote}</>",
+                tag.name
+            ),
+            &output.stderr,
+            &push_args,
+        );
+        return Ok(());
+    }
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Created {signed}tag <bold>{}</> @ <dim>{short}</> and pushed it to <bold>{remote}</>",
+            tag.name
+        ))
+    );
+    Ok(())
+}
+
+/// Warn with git's own explanation, and the command to rerun by hand.
+fn report_tag_failure(message: String, stderr: &[u8], args: &[&str]) {
+    eprintln!("{}", warning_message(message));
+    let stderr = String::from_utf8_lossy(stderr);
+    if !stderr.trim().is_empty() {
+        eprintln!("{}", format_with_gutter(stderr.trim_end(), None));
+    }
+    let command = std::iter::once("git")
+        .chain(args.iter().copied())
+        .map(|arg| escape(arg.into()).into_owned())
+        .collect::<Vec<_>>()
+        .join(" ");
+    eprintln!(
+        "{}",
+        hint_message(cformat!("To finish by hand, run <underline>{command}</>"))
+    );
 }
 
 /// Collect all commands that will be executed during merge.
@@ -79,6 +198,7 @@ fn collect_merge_commands(
 pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let MergeOptions {
         target,
+        branch,
         squash: squash_opt,
         commit: commit_opt,
         rebase: rebase_opt,
@@ -86,6 +206,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         verify: verify_opt,
         yes,
         stage,
+        restack,
+        tag,
     } = opts;
 
     // Load config once, run LLM setup prompt if committing, then reuse config
@@ -95,7 +217,24 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         let _ = crate::output::prompt_commit_generation(&mut config);
     }
 
-    let env = CommandEnv::for_action("merge", config)?;
+    // The worktree the shell is in, which only changes if it's the one removed
+    let invoked = Repository::current()?;
+    let invoked_in = invoked.current_worktree().root()?;
+    let repo = match branch {
+        // Work in that branch's worktree, as if run there with -C
+        Some(branch) => {
+            let worktree =
+                invoked
+                    .worktree_for_branch(branch)?
+                    .ok_or_else(|| GitError::WorktreeNotFound {
+                        branch: branch.to_string(),
+                    })?;
+            Repository::at(worktree)?
+        }
+        None => invoked,
+    };
+
+    let env = CommandEnv::for_action_in(repo, "merge", config)?;
     let repo = &env.repo;
     let config = &env.config;
     // Merge requires being on a branch (can't merge from detached HEAD)
@@ -130,6 +269,9 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
 
     // Get and validate target branch (must be a branch since we're updating it)
     let target_branch = repo.require_target_branch(target)?;
+    if let Some(tag) = &tag {
+        validate_tag(repo, tag.name)?;
+    }
     // Worktree for target is optional: if present we use it for safety checks and as destination.
     let target_worktree_path = repo.worktree_for_branch(&target_branch)?;
 
@@ -138,6 +280,13 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let on_target = current_branch == target_branch;
     let remove_effective = remove && !on_target && !in_main;
 
+    // Branches stacked on this one contain its commits as they are now, before
+    // squash or rebase rewrites them
+    let original_head = current_wt
+        .run_command(&["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+
     // Collect and approve all commands upfront for batch permission request
     let (all_commands, project_id) =
         collect_merge_commands(repo, commit, verify, remove_effective, squash_enabled)?;
@@ -179,6 +328,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let squashed = if squash_enabled {
         matches!(
             super::step_commands::handle_squash(
+                repo,
                 Some(&target_branch),
                 yes,
                 verify,
@@ -194,7 +344,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let rebased = if rebase {
         // Auto-rebase onto target
         matches!(
-            super::step_commands::handle_rebase(Some(&target_branch))?,
+            super::step_commands::handle_rebase(repo, Some(&target_branch))?,
             super::step_commands::RebaseResult::Rebased
         )
     } else {
@@ -221,6 +371,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
 
     // Fast-forward push to target branch with commit/squash/rebase info for consolidated message
     handle_push(
+        repo,
         Some(&target_branch),
         "Merged to",
         Some(MergeOperations {
@@ -230,6 +381,10 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         }),
     )?;
 
+    if let Some(tag) = &tag {
+        create_tag(&current_wt, tag, &target_branch)?;
+    }
+
     // Destination: prefer the target branch's worktree; fall back to home path.
     let destination_path = match target_worktree_path {
         Some(path) => path,
@@ -237,6 +392,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     };
 
     // Finish worktree unless --no-remove was specified
+    let mut changed_directory = false;
     if remove_effective {
         // STEP 1: Check for uncommitted changes before attempting cleanup
         // This prevents showing "Cleaning up worktree..." before failing
@@ -244,6 +400,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
 
         // STEP 2: Remove worktree via shared remove output handler so final message matches wt remove
         let worktree_root = current_wt.root()?;
+        changed_directory = worktree_root == invoked_in;
         // After a successful merge, get integration reason
         let (_, integration_reason) = repo.integration_reason(&current_branch, &target_branch)?;
         // Compute expected_path for path mismatch detection
@@ -256,7 +413,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         let remove_result = RemoveResult::RemovedWorktree {
             main_path: destination_path.clone(),
             worktree_path: worktree_root,
-            changed_directory: true,
+            changed_directory,
             branch_name: Some(current_branch.clone()),
             deletion_mode: BranchDeletionMode::SafeDelete,
             target_branch: Some(target_branch.clone()),
@@ -266,6 +423,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             force_worktree: false,
             expected_path,
             removed_commit,
+            remote_branch: None,
         };
         // Run hooks during merge removal (pass through verify flag)
         // Approval was handled at the gate (collect_merge_commands)
@@ -287,7 +445,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         // This runs after cleanup so the context is clear to the user
         let ctx = CommandContext::new(repo, config, Some(&current_branch), &destination_path, yes);
         // Show path when user's shell won't be in the destination directory where hooks run.
-        let display_path = if remove_effective && !in_main && !on_target {
+        let display_path = if changed_directory {
             // Worktree removed, user will cd to destination
             crate::output::post_hook_display_path(&destination_path)
         } else {
@@ -305,5 +463,17 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         )?;
     }
 
+    if !on_target {
+        // The worktree we started in may be gone; work from the destination
+        let repo = Repository::at(&destination_path)?;
+        let finished = [(current_branch, original_head)];
+        let stacked = find_stacked_branches(&repo, &finished, &target_branch)?;
+        if restack {
+            restack_branches(&repo, &stacked, &target_branch)?;
+        } else if !stacked.is_empty() {
+            warn_stacked(&stacked, &target_branch);
+        }
+    }
+
     Ok(())
 }
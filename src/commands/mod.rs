@@ -1,50 +1,82 @@
 mod alias;
+mod archive;
+pub(crate) mod batch;
 pub(crate) mod branch_deletion;
+mod branches;
+mod clone;
 pub(crate) mod command_approval;
 pub(crate) mod command_executor;
 pub(crate) mod commit;
 pub(crate) mod config;
 pub(crate) mod configure_shell;
 pub(crate) mod context;
+mod diff;
+mod events;
 mod for_each;
 mod handle_switch;
+mod hide;
 mod hook_commands;
 mod hook_filter;
 pub(crate) mod hooks;
 pub(crate) mod init;
+pub(crate) mod json_schema;
 pub(crate) mod list;
 pub(crate) mod merge;
+mod optimize;
+mod parent;
 pub(crate) mod process;
 pub(crate) mod project_config;
 mod relocate;
+mod repair;
+mod report;
 pub(crate) mod repository_ext;
+mod restack;
 #[cfg(unix)]
 pub(crate) mod select;
+mod stats;
 pub(crate) mod statusline;
 pub(crate) mod step_commands;
+mod table;
+mod which;
 pub(crate) mod worktree;
 
 pub(crate) use alias::{AliasOptions, step_alias};
+pub(crate) use archive::{handle_archive, handle_archive_list, handle_archive_restore};
+pub(crate) use branches::handle_branches_clean;
+pub(crate) use clone::handle_clone;
 pub(crate) use config::{
-    handle_config_create, handle_config_show, handle_config_update, handle_hints_clear,
-    handle_hints_get, handle_logs_get, handle_state_clear, handle_state_clear_all,
-    handle_state_get, handle_state_set, handle_state_show,
+    handle_config_create, handle_config_migrate, handle_config_schema, handle_config_show,
+    handle_config_update, handle_config_validate, handle_hints_clear, handle_hints_get,
+    handle_logs_get, handle_state_clear, handle_state_clear_all, handle_state_get,
+    handle_state_set, handle_state_show,
 };
 pub(crate) use configure_shell::{
     handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
 };
+pub(crate) use diff::handle_diff;
+pub(crate) use events::handle_events;
 pub(crate) use for_each::step_for_each;
 pub(crate) use handle_switch::{SwitchOptions, handle_switch};
+pub(crate) use hide::{handle_hide, handle_unhide};
 pub(crate) use hook_commands::{add_approvals, clear_approvals, handle_hook_show, run_hook};
 pub(crate) use init::{handle_completions, handle_init};
-pub(crate) use list::handle_list;
-pub(crate) use merge::{MergeOptions, handle_merge};
+pub(crate) use list::legend::handle_legend;
+pub(crate) use list::{ListOptions, handle_list};
+pub(crate) use merge::{MergeOptions, MergeTag, handle_merge};
+pub(crate) use optimize::{PerfCheck, nudge_if_due, step_optimize};
+pub(crate) use parent::{handle_parent_clear, handle_parent_get, handle_parent_set};
+pub(crate) use repair::handle_repair_default_branch;
+#[cfg(unix)]
+pub(crate) use report::handle_report;
 #[cfg(unix)]
 pub(crate) use select::handle_select;
+pub(crate) use stats::handle_stats;
 pub(crate) use step_commands::{
     PromoteResult, RebaseResult, SquashResult, handle_promote, handle_rebase, handle_squash,
-    step_commit, step_copy_ignored, step_diff, step_prune, step_relocate, step_show_squash_prompt,
+    step_commit, step_copy_ignored, step_diff, step_graduate, step_prune, step_relocate,
+    step_show_squash_prompt,
 };
+pub(crate) use which::handle_which;
 pub(crate) use worktree::{
     OperationMode, handle_remove, handle_remove_current, is_worktree_at_expected_path,
     resolve_worktree_arg, worktree_display_name,
@@ -68,6 +100,20 @@ pub(crate) fn format_command_label(command_type: &str, name: Option<&str>) -> St
     }
 }
 
+/// Find the closest match for `input` among `candidates` using Jaro similarity.
+///
+/// Returns `Some(match)` if a candidate is sufficiently similar (threshold 0.7),
+/// `None` otherwise. Uses `jaro` (not `jaro_winkler`) with the same threshold
+/// as clap — see clap GH #4660 for why.
+pub(crate) fn find_closest_match<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|c| (*c, strsim::jaro(input, c)))
+        .filter(|(_, score)| *score > 0.7)
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(name, _)| name)
+}
+
 /// Show detailed diffstat for a given commit range.
 ///
 /// Displays the diff statistics (file changes, insertions, deletions) in a gutter format.
@@ -109,4 +155,18 @@ mod tests {
         assert_snapshot!(format_command_label("post-start", Some("build")), @"Running post-start [1mbuild[22m");
         assert_snapshot!(format_command_label("pre-commit", None), @"Running pre-commit");
     }
+
+    #[test]
+    fn test_find_closest_match() {
+        assert_eq!(
+            find_closest_match("deplyo", &["deploy", "hello"]),
+            Some("deploy")
+        );
+        assert_eq!(
+            find_closest_match("comit", &["commit", "squash", "push", "rebase"]),
+            Some("commit")
+        );
+        assert_eq!(find_closest_match("zzz", &["deploy", "hello"]), None);
+        assert_eq!(find_closest_match("deploy", &[]), None);
+    }
 }
@@ -0,0 +1,243 @@
+//! Repository performance checks (`wt step optimize`).
+//!
+//! `wt list` walks history for every branch — ahead/behind counts, integration
+//! checks — which is far faster when git has a commit-graph file, and
+//! `git maintenance` keeps that file current as the repo grows. Both write to
+//! the repository (and maintenance to the user's scheduler), so nothing is
+//! changed without an explicit yes. The checks only stat a file and read git
+//! config and pack headers, so they're cheap enough to run before a nudge.
+
+use std::io::{self, IsTerminal};
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, hint_message, info_message, progress_message,
+    success_message, warning_message,
+};
+use worktrunk::utils::get_now;
+
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
+
+/// Object count from which a repo counts as large.
+const LARGE_REPO_OBJECTS: u64 = 100_000;
+
+/// Minimum time between two nudges in the same repo.
+const NUDGE_INTERVAL_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Hint name; the value is the Unix time of the last nudge.
+const NUDGE_HINT: &str = "optimize";
+
+/// What a repo has in place for fast history walks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PerfCheck {
+    pub has_commit_graph: bool,
+    pub maintenance: bool,
+    /// Loose plus packed objects, from `git count-objects -v`
+    pub objects: u64,
+}
+
+impl PerfCheck {
+    pub(crate) fn detect(repo: &Repository) -> anyhow::Result<Self> {
+        let info = repo.git_common_dir().join("objects").join("info");
+        let has_commit_graph = info.join("commit-graph").exists()
+            || info
+                .join("commit-graphs")
+                .join("commit-graph-chain")
+                .exists();
+        // `git maintenance register` sets a strategy in the repo's config
+        let maintenance = repo
+            .run_command(&["config", "--get", "maintenance.strategy"])
+            .is_ok_and(|strategy| !matches!(strategy.trim(), "" | "none"));
+        let objects = parse_object_count(&repo.run_command(&["count-objects", "-v"])?);
+        Ok(Self {
+            has_commit_graph,
+            maintenance,
+            objects,
+        })
+    }
+
+    fn is_large(&self) -> bool {
+        let threshold = std::env::var("WORKTRUNK_TEST_LARGE_REPO_OBJECTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(LARGE_REPO_OBJECTS);
+        self.objects >= threshold
+    }
+
+    /// Large, and missing the commit-graph or maintenance.
+    pub(crate) fn needs_attention(&self) -> bool {
+        self.is_large() && (!self.has_commit_graph || !self.maintenance)
+    }
+
+    /// "no commit-graph, maintenance off"
+    pub(crate) fn describe_missing(&self) -> String {
+        let mut missing = Vec::new();
+        if !self.has_commit_graph {
+            missing.push("no commit-graph");
+        }
+        if !self.maintenance {
+            missing.push("maintenance off");
+        }
+        missing.join(", ")
+    }
+}
+
+/// Sum of the `count` (loose) and `in-pack` lines.
+fn parse_object_count(output: &str) -> u64 {
+    output
+        .lines()
+        .filter_map(|line| line.split_once(": "))
+        .filter(|(key, _)| matches!(*key, "count" | "in-pack"))
+        .filter_map(|(_, value)| value.trim().parse::<u64>().ok())
+        .sum()
+}
+
+/// Ask before making a change; `?` shows the git command that would run.
+/// Non-interactive sessions need `--yes`.
+fn confirm(question: &str, command: &str) -> anyhow::Result<bool> {
+    let response = prompt_yes_no_preview(question, || {
+        eprintln!("{}", format_bash_with_gutter(command));
+    })?;
+    Ok(response == PromptResponse::Accepted)
+}
+
+/// Handle `wt step optimize`.
+pub fn step_optimize(yes: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let check = PerfCheck::detect(&repo)?;
+
+    if check.has_commit_graph && check.maintenance {
+        eprintln!(
+            "{}",
+            success_message("Commit-graph present and maintenance enabled")
+        );
+        return Ok(());
+    }
+    if !check.is_large() {
+        eprintln!(
+            "{}",
+            info_message(format!(
+                "Repo has {} objects ({}); optimizing matters from about {LARGE_REPO_OBJECTS}",
+                check.objects,
+                check.describe_missing()
+            ))
+        );
+    }
+    if !yes && !io::stdin().is_terminal() {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Not changing anything ({}; not interactive). To apply, use <underline>--yes</>",
+                check.describe_missing()
+            ))
+        );
+        return Ok(());
+    }
+
+    if !check.has_commit_graph {
+        eprintln!(
+            "{}",
+            info_message(
+                "Without a commit-graph, git parses every commit it walks, which slows ahead/behind counts and merge checks in wt list"
+            )
+        );
+        if yes
+            || confirm(
+                "Write a commit-graph?",
+                "git commit-graph write --reachable",
+            )?
+        {
+            eprintln!("{}", progress_message("Writing commit-graph..."));
+            repo.run_command(&["commit-graph", "write", "--reachable"])?;
+            eprintln!("{}", success_message("Wrote commit-graph"));
+        }
+    }
+
+    if !check.maintenance {
+        eprintln!(
+            "{}",
+            info_message(
+                "Background maintenance keeps the commit-graph current and packs objects as the repo grows; it registers this repo with the system scheduler"
+            )
+        );
+        if yes || confirm("Start git maintenance?", "git maintenance start")? {
+            repo.run_command(&["maintenance", "start"])?;
+            eprintln!("{}", success_message("Started git maintenance"));
+        }
+    }
+    Ok(())
+}
+
+/// Suggest `wt step optimize` in a large, unoptimized repo (`performance-hint = true`).
+///
+/// Shown at most once a week per repo; the time is kept under the `optimize`
+/// hint, so `wt config state hints clear optimize` brings it back sooner.
+pub(crate) fn nudge_if_due(repo: &Repository) {
+    if !repo.user_config().performance_hint {
+        return;
+    }
+    let now = get_now();
+    let last = repo
+        .run_command(&["config", "--get", &format!("worktrunk.hints.{NUDGE_HINT}")])
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok());
+    if last.is_some_and(|last| now.saturating_sub(last) < NUDGE_INTERVAL_SECS) {
+        return;
+    }
+    let Ok(check) = PerfCheck::detect(repo) else {
+        return;
+    };
+    if !check.needs_attention() {
+        return;
+    }
+
+    eprintln!(
+        "{}",
+        warning_message(format!(
+            "Large repo ({}) makes wt list slower",
+            check.describe_missing()
+        ))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!("To fix, run <underline>wt step optimize</>"))
+    );
+    if let Err(e) = repo.run_command(&[
+        "config",
+        &format!("worktrunk.hints.{NUDGE_HINT}"),
+        &now.to_string(),
+    ]) {
+        log::debug!("Failed to record optimize hint: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_object_count() {
+        let output = "count: 12\nsize: 48\nin-pack: 250000\npacks: 3\nsize-pack: 9000\nprune-packable: 0\ngarbage: 0\nsize-garbage: 0\n";
+        assert_eq!(parse_object_count(output), 250_012);
+        assert_eq!(parse_object_count(""), 0);
+    }
+
+    #[test]
+    fn test_needs_attention() {
+        let check = |has_commit_graph, maintenance, objects| PerfCheck {
+            has_commit_graph,
+            maintenance,
+            objects,
+        };
+        assert!(check(false, true, LARGE_REPO_OBJECTS).needs_attention());
+        assert!(check(true, false, LARGE_REPO_OBJECTS).needs_attention());
+        assert!(!check(true, true, LARGE_REPO_OBJECTS).needs_attention());
+        // Small repos are fast enough either way
+        assert!(!check(false, false, LARGE_REPO_OBJECTS - 1).needs_attention());
+        assert_eq!(
+            check(false, false, 0).describe_missing(),
+            "no commit-graph, maintenance off"
+        );
+    }
+}
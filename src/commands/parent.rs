@@ -0,0 +1,65 @@
+//! Recording the branch a branch is stacked on (`wt parent`).
+//!
+//! The parent lives in the branch's git config section
+//! (`branch.<branch>.worktrunkParent`). `wt list` compares a branch with its
+//! parent instead of the default branch, and `--restack` rebases branches in
+//! the order their parents give.
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{eprintln, info_message, println, success_message};
+
+/// Handle `wt parent [get]`: print the recorded parent, or nothing.
+pub fn handle_parent_get(branch: Option<String>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(b) => b,
+        None => repo.require_current_branch("get parent of current branch")?,
+    };
+    println!("{}", repo.branch_parent(&branch).unwrap_or_default());
+    Ok(())
+}
+
+/// Handle `wt parent set <parent>`.
+pub fn handle_parent_set(parent: &str, branch: Option<String>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(b) => b,
+        None => repo.require_current_branch("set parent of current branch")?,
+    };
+    for name in [branch.as_str(), parent] {
+        if !repo.branch(name).exists_locally()? {
+            return Err(GitError::BranchNotFound {
+                branch: name.to_string(),
+                show_create_hint: false,
+                closest: None,
+            }
+            .into());
+        }
+    }
+
+    repo.set_branch_parent(&branch, parent)?;
+    eprintln!(
+        "{}",
+        success_message(cformat!("Stacked <bold>{branch}</> on <bold>{parent}</>"))
+    );
+    Ok(())
+}
+
+/// Handle `wt parent clear`.
+///
+/// Doesn't require the branch to exist, matching `wt unhide`.
+pub fn handle_parent_clear(branch: Option<String>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(b) => b,
+        None => repo.require_current_branch("clear parent of current branch")?,
+    };
+    let message = if repo.clear_branch_parent(&branch)? {
+        success_message(cformat!("Cleared parent of <bold>{branch}</>"))
+    } else {
+        info_message(cformat!("<bold>{branch}</> has no recorded parent"))
+    };
+    eprintln!("{message}");
+    Ok(())
+}
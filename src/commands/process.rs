@@ -193,7 +193,8 @@ fn posix_command_separator(command: &str) -> &'static str {
 /// * `command` - Shell command to execute
 /// * `branch` - Branch name for log organization
 /// * `hook_log` - Log specification (determines the log filename)
-/// * `context_json` - Optional JSON context to pipe to command's stdin
+/// * `context_json` - Optional JSON context to pipe to command's stdin, also set as
+///   `WT_HOOK_CONTEXT`
 ///
 /// # Returns
 /// Path to the log file where output is being written
@@ -274,7 +275,11 @@ fn spawn_detached_unix(
     // Detachment via process_group(0): puts the spawned shell in its own process group.
     // When the controlling PTY closes, SIGHUP is sent to the foreground process group.
     // Since our process is in a different group, it doesn't receive the signal.
-    let mut child = Command::new("sh")
+    let mut cmd = Command::new("sh");
+    if let Some(json) = context_json {
+        cmd.env(worktrunk::shell_exec::HOOK_CONTEXT_ENV_VAR, json);
+    }
+    let mut child = cmd
         .arg("-c")
         .arg(&shell_cmd)
         .current_dir(worktree_path)
@@ -350,6 +355,9 @@ fn spawn_detached_windows(
         shell.command(&full_command)
     };
 
+    if let Some(json) = context_json {
+        cmd.env(worktrunk::shell_exec::HOOK_CONTEXT_ENV_VAR, json);
+    }
     cmd.current_dir(worktree_path)
         .stdin(Stdio::null())
         .stdout(Stdio::from(
@@ -8,6 +8,7 @@ use worktrunk::git::HookType;
 pub enum Phase {
     Hook(HookType),
     Alias,
+    OnSwitch,
 }
 
 impl fmt::Display for Phase {
@@ -15,6 +16,7 @@ impl fmt::Display for Phase {
         match self {
             Phase::Hook(hook_type) => write!(f, "{hook_type}"),
             Phase::Alias => write!(f, "alias"),
+            Phase::OnSwitch => write!(f, "on-switch"),
         }
     }
 }
@@ -26,7 +26,7 @@ use std::path::{Path, PathBuf};
 use anyhow::Context;
 use color_print::cformat;
 use worktrunk::config::UserConfig;
-use worktrunk::git::{Repository, WorktreeInfo};
+use worktrunk::git::{AddWorktreeOpts, Repository, WorktreeInfo};
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell_exec::Cmd;
 use worktrunk::styling::{
@@ -34,8 +34,9 @@ use worktrunk::styling::{
     warning_message,
 };
 
+use super::batch::{BatchAction, BatchPolicy, LockedSkips};
 use super::commit::{CommitGenerator, StageMode};
-use super::worktree::{compute_worktree_path, paths_match};
+use super::worktree::{check_worktree_root, compute_worktree_path, paths_match};
 
 // ============================================================================
 // Types representing each stage of the pipeline
@@ -148,6 +149,15 @@ pub fn gather_candidates(
                     expected.canonicalize().unwrap_or_else(|_| expected.clone());
 
                 if actual_canonical != expected_canonical {
+                    if let Err(e) = check_worktree_root(&expected, config) {
+                        eprintln!(
+                            "{}",
+                            warning_message(cformat!("Skipping <bold>{branch}</>:"))
+                        );
+                        eprintln!("{}", e);
+                        template_errors += 1;
+                        continue;
+                    }
                     candidates.push(RelocationCandidate {
                         wt,
                         expected_path: expected,
@@ -196,21 +206,15 @@ pub fn validate_candidates(
 ) -> anyhow::Result<ValidationResult> {
     let mut validated = Vec::new();
     let mut skipped = 0;
+    let lock_policy = BatchPolicy::new(BatchAction::Modify, false);
+    let mut skipped_locked = LockedSkips::default();
 
     for candidate in candidates {
         let branch = candidate.branch();
 
-        // Check locked - always skip (user must unlock manually)
-        if let Some(reason) = &candidate.wt.locked {
-            let reason_text = if reason.is_empty() {
-                String::new()
-            } else {
-                format!(": {reason}")
-            };
-            eprintln!(
-                "{}",
-                warning_message(cformat!("Skipping <bold>{branch}</> (locked{reason_text})"))
-            );
+        // Locked worktrees always stay put (user must unlock manually)
+        if let Some(reason) = lock_policy.locked_skip(&candidate.wt) {
+            skipped_locked.push(branch, reason);
             skipped += 1;
             continue;
         }
@@ -260,6 +264,10 @@ pub fn validate_candidates(
         });
     }
 
+    if let Some(message) = skipped_locked.message(&lock_policy) {
+        eprintln!("{message}");
+    }
+
     Ok(ValidationResult { validated, skipped })
 }
 
@@ -379,7 +387,7 @@ impl RelocationExecutor {
     /// Execute all relocations in dependency order.
     pub fn execute(
         &mut self,
-        repo_path: &Path,
+        repo: &Repository,
         default_branch: &str,
         cwd: Option<&Path>,
     ) -> anyhow::Result<()> {
@@ -395,7 +403,7 @@ impl RelocationExecutor {
 
                 match self.is_target_empty(i) {
                     Some(true) => {
-                        self.move_worktree(i, repo_path, default_branch, cwd)?;
+                        self.move_worktree(i, repo, default_branch, cwd)?;
                         made_progress = true;
                     }
                     Some(false) => {
@@ -420,13 +428,13 @@ impl RelocationExecutor {
             }
 
             // No progress - break a cycle by moving one worktree to temp
-            if !self.break_cycle()? {
+            if !self.break_cycle(repo)? {
                 break; // All done
             }
         }
 
         // Move temp-relocated worktrees to final destinations
-        self.finalize_temp_relocations()?;
+        self.finalize_temp_relocations(repo)?;
 
         // Clean up temp directory if empty
         if self.temp_dir.exists() {
@@ -461,7 +469,7 @@ impl RelocationExecutor {
     fn move_worktree(
         &mut self,
         idx: usize,
-        repo_path: &Path,
+        repo: &Repository,
         default_branch: &str,
         cwd: Option<&Path>,
     ) -> anyhow::Result<()> {
@@ -475,14 +483,9 @@ impl RelocationExecutor {
         let dest_display = format_path_for_display(&dest_path);
 
         if is_main {
-            self.move_main_worktree(idx, repo_path, default_branch)?;
+            self.move_main_worktree(idx, repo, default_branch)?;
         } else {
-            Cmd::new("git")
-                .args(["worktree", "move"])
-                .arg(src_path.to_string_lossy())
-                .arg(dest_path.to_string_lossy())
-                .context(&branch)
-                .run()
+            repo.move_worktree(&src_path, &dest_path)
                 .context("Failed to move worktree")?;
         }
 
@@ -506,9 +509,10 @@ impl RelocationExecutor {
     fn move_main_worktree(
         &mut self,
         idx: usize,
-        repo_path: &Path,
+        repo: &Repository,
         default_branch: &str,
     ) -> anyhow::Result<()> {
+        let repo_path = repo.repo_path()?;
         let candidate = &self.pending[idx];
         let branch = candidate.branch();
 
@@ -523,12 +527,10 @@ impl RelocationExecutor {
             .context("Failed to checkout default branch")?;
 
         // Try to create worktree; if it fails, rollback to original branch
-        let add_result = Cmd::new("git")
-            .args(["worktree", "add"])
-            .arg(candidate.expected_path.to_string_lossy())
-            .arg(branch)
-            .context(branch)
-            .run();
+        let add_result = repo.add_worktree(AddWorktreeOpts {
+            branch: Some(branch),
+            ..AddWorktreeOpts::new(&candidate.expected_path)
+        });
 
         if let Err(e) = add_result {
             // Rollback: checkout the original branch to restore user context
@@ -550,7 +552,7 @@ impl RelocationExecutor {
     /// Break a cycle by moving one worktree to a temp location.
     ///
     /// Returns `true` if a worktree was moved to temp, `false` if no cycles remain.
-    fn break_cycle(&mut self) -> anyhow::Result<bool> {
+    fn break_cycle(&mut self, repo: &Repository) -> anyhow::Result<bool> {
         // Find a non-main worktree to temp-move (git worktree move can't move main)
         let cycle_idx = (0..self.pending.len())
             .filter(|&i| !self.moved.contains(&i) && !self.blocked.contains(&i))
@@ -579,12 +581,7 @@ impl RelocationExecutor {
         let msg = cformat!("Moving <bold>{branch}</> to temporary location...");
         eprintln!("{}", progress_message(msg));
 
-        Cmd::new("git")
-            .args(["worktree", "move"])
-            .arg(candidate.wt.path.to_string_lossy())
-            .arg(temp_path.to_string_lossy())
-            .context(branch)
-            .run()
+        repo.move_worktree(&candidate.wt.path, &temp_path)
             .context("Failed to move worktree to temp")?;
 
         // Update current_locations to reflect the move
@@ -606,7 +603,7 @@ impl RelocationExecutor {
     }
 
     /// Move worktrees from temp locations to their final destinations.
-    fn finalize_temp_relocations(&mut self) -> anyhow::Result<()> {
+    fn finalize_temp_relocations(&mut self, repo: &Repository) -> anyhow::Result<()> {
         for temp in std::mem::take(&mut self.temp_relocated) {
             let candidate = &self.pending[temp.index];
             let branch = candidate.branch();
@@ -614,12 +611,7 @@ impl RelocationExecutor {
             let src_display = format_path_for_display(&temp.original_path);
             let dest_display = format_path_for_display(&candidate.expected_path);
 
-            Cmd::new("git")
-                .args(["worktree", "move"])
-                .arg(temp.temp_path.to_string_lossy())
-                .arg(candidate.expected_path.to_string_lossy())
-                .context(branch)
-                .run()
+            repo.move_worktree(&temp.temp_path, &candidate.expected_path)
                 .context("Failed to move worktree from temp to final location")?;
 
             let msg = cformat!("Relocated <bold>{branch}</>: {src_display} → {dest_display}");
@@ -0,0 +1,195 @@
+//! Repairing repository state that has drifted from the remote (`wt repair`).
+//!
+//! `--default-branch` follows a rename of the remote's default branch, such as
+//! `master` → `main`: it refreshes `<remote>/HEAD`, renames the local branch
+//! when that loses nothing, updates the `worktrunk.default-branch` cache, and
+//! re-points this project's `compare-ref` in user config after a preview.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::state_file::write_atomic;
+use worktrunk::config::{acquire_config_lock, format_migration_diff, get_config_path};
+use worktrunk::git::Repository;
+use worktrunk::styling::{eprintln, info_message, success_message, warning_message};
+
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
+
+/// Handle `wt repair --default-branch`.
+pub fn handle_repair_default_branch(yes: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let remote = repo
+        .primary_remote()
+        .context("No remote to read the default branch from")?;
+    let old = repo
+        .invalid_default_branch_config()
+        .or_else(|| repo.default_branch());
+
+    // Ask the remote, not the local cache, which may predate the rename
+    repo.run_network_command(&["fetch", "--", &remote])
+        .with_context(|| cformat!("Failed to fetch <bold>{remote}</>"))?;
+    repo.run_command(&["remote", "set-head", &remote, "--auto"])
+        .with_context(|| cformat!("Failed to read the default branch of <bold>{remote}</>"))?;
+    let head = repo.run_command(&[
+        "symbolic-ref",
+        "--short",
+        &format!("refs/remotes/{remote}/HEAD"),
+    ])?;
+    let new = head
+        .trim()
+        .strip_prefix(&format!("{remote}/"))
+        .unwrap_or(head.trim())
+        .to_string();
+
+    let Some(old) = old.filter(|old| *old != new) else {
+        repo.set_default_branch(&new)?;
+        eprintln!(
+            "{}",
+            info_message(cformat!("Default branch is already <bold>{new}</>"))
+        );
+        return Ok(());
+    };
+
+    rename_local_branch(&repo, &remote, &old, &new)?;
+
+    repo.set_default_branch(&new)?;
+    let _ = repo.clear_hint("default-branch-renamed");
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Default branch is now <bold>{new}</> (was <bold>{old}</>)"
+        ))
+    );
+
+    repoint_compare_ref(&repo, &remote, &old, &new, yes)
+}
+
+/// Rename the local `old` branch to `new`, unless that would lose commits or
+/// clobber an existing `new`.
+fn rename_local_branch(
+    repo: &Repository,
+    remote: &str,
+    old: &str,
+    new: &str,
+) -> anyhow::Result<()> {
+    if !repo.branch(old).exists_locally()? {
+        return Ok(());
+    }
+    if repo.branch(new).exists_locally()? {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Kept branch <bold>{old}</>: <bold>{new}</> already exists locally"
+            ))
+        );
+        return Ok(());
+    }
+
+    let upstream = format!("{remote}/{new}");
+    let unique = repo.run_command(&[
+        "rev-list",
+        "--count",
+        old,
+        "--not",
+        &format!("refs/remotes/{upstream}"),
+    ])?;
+    let unique: usize = unique.trim().parse().unwrap_or(0);
+    if unique > 0 {
+        let commits = if unique == 1 { "commit" } else { "commits" };
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Kept branch <bold>{old}</>: it has {unique} {commits} not on <bold>{upstream}</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    repo.branch(old).rename(new)?;
+    repo.run_command(&["branch", "--set-upstream-to", &upstream, new])?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Renamed branch <bold>{old}</> to <bold>{new}</>, tracking <bold>{upstream}</>"
+        ))
+    );
+    Ok(())
+}
+
+/// Re-point this project's `list.compare-ref` in user config when it names the
+/// old branch.
+///
+/// Only the `[projects."<id>"]` entry is touched: a top-level value applies to
+/// every repository, where the old name may still be right.
+fn repoint_compare_ref(
+    repo: &Repository,
+    remote: &str,
+    old: &str,
+    new: &str,
+    yes: bool,
+) -> anyhow::Result<()> {
+    let Some(config_path) = get_config_path().filter(|path| path.exists()) else {
+        return Ok(());
+    };
+    let Ok(project_id) = repo.project_identifier() else {
+        return Ok(());
+    };
+    // Held until the write, so a concurrent config change isn't overwritten
+    let _lock = acquire_config_lock(&config_path)?;
+    let content = std::fs::read_to_string(&config_path).context("Failed to read user config")?;
+    let mut doc: toml_edit::DocumentMut = content.parse().context("Failed to parse user config")?;
+
+    let Some(item) = doc
+        .get_mut("projects")
+        .and_then(|projects| projects.get_mut(&project_id))
+        .and_then(|project| project.get_mut("list"))
+        .and_then(|list| list.get_mut("compare-ref"))
+    else {
+        return Ok(());
+    };
+    let replacement = match item.as_str() {
+        Some(value) if value == old => new.to_string(),
+        Some(value) if value == format!("{remote}/{old}") => format!("{remote}/{new}"),
+        _ => return Ok(()),
+    };
+    let decor = item.as_value().map(|value| value.decor().clone());
+    *item = toml_edit::value(replacement);
+    if let (Some(decor), Some(value)) = (decor, item.as_value_mut()) {
+        *value.decor_mut() = decor;
+    }
+
+    let mut new_path = OsString::from(config_path.as_os_str());
+    new_path.push(".new");
+    let new_path = PathBuf::from(new_path);
+    std::fs::write(&new_path, doc.to_string()).context("Failed to write user config preview")?;
+    eprintln!(
+        "{}",
+        info_message(cformat!("User config compares against <bold>{old}</>:"))
+    );
+    if let Some(diff) = format_migration_diff(&config_path, &new_path) {
+        eprintln!("{diff}");
+    }
+
+    let accepted = yes
+        || matches!(
+            prompt_yes_no_preview("Update user config?", || {})?,
+            PromptResponse::Accepted
+        );
+    let result = if accepted {
+        write_atomic(&config_path, doc.to_string()).context("Failed to update user config")
+    } else {
+        Ok(())
+    };
+    let _ = std::fs::remove_file(&new_path);
+    result?;
+
+    let message = if accepted {
+        success_message("Updated user config")
+    } else {
+        info_message("Left user config unchanged")
+    };
+    eprintln!("{message}");
+    Ok(())
+}
@@ -0,0 +1,221 @@
+//! Summary of the local usage journal (`wt report`).
+//!
+//! Aggregates what [`crate::usage_journal`] recorded: per command, how often
+//! it ran, how often it failed, its median duration, and how often each flag
+//! was given. Everything shown is already in the journal, so the output holds
+//! only command and flag names and can be pasted anywhere.
+
+use std::collections::BTreeMap;
+
+use color_print::cformat;
+use serde::Serialize;
+use worktrunk::styling::{hint_message, info_message, println};
+
+use crate::cli::OutputFormat;
+use crate::usage_journal::{self, UsageEntry};
+
+/// One command's aggregate.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub(super) struct CommandUsage {
+    /// Subcommand path, e.g. `step copy-ignored`
+    pub(super) command: String,
+    pub(super) runs: usize,
+    pub(super) failed: usize,
+    pub(super) median_ms: u64,
+    /// Runs that gave each flag
+    pub(super) flags: BTreeMap<String, usize>,
+}
+
+/// JSON output for `wt report --format=json`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub(super) struct Report {
+    /// Unix timestamp of the oldest entry, or `None` when the journal is empty
+    pub(super) since: Option<u64>,
+    pub(super) runs: usize,
+    /// Most-run first
+    pub(super) commands: Vec<CommandUsage>,
+}
+
+impl Report {
+    fn from_entries(entries: &[UsageEntry]) -> Self {
+        let mut durations: BTreeMap<&str, Vec<u64>> = BTreeMap::new();
+        let mut by_command: BTreeMap<&str, CommandUsage> = BTreeMap::new();
+        for entry in entries {
+            let usage = by_command
+                .entry(&entry.command)
+                .or_insert_with(|| CommandUsage {
+                    command: entry.command.clone(),
+                    ..Default::default()
+                });
+            usage.runs += 1;
+            if !entry.ok {
+                usage.failed += 1;
+            }
+            for flag in &entry.flags {
+                *usage.flags.entry(flag.clone()).or_default() += 1;
+            }
+            durations
+                .entry(&entry.command)
+                .or_default()
+                .push(entry.dur_ms);
+        }
+
+        let mut commands: Vec<CommandUsage> = by_command
+            .into_iter()
+            .map(|(command, mut usage)| {
+                let runs = durations
+                    .get_mut(command)
+                    .expect("every command has durations");
+                runs.sort_unstable();
+                usage.median_ms = runs[runs.len() / 2];
+                usage
+            })
+            .collect();
+        // Stable sort keeps ties in name order
+        commands.sort_by(|a, b| b.runs.cmp(&a.runs));
+
+        Self {
+            since: entries.iter().map(|entry| entry.ts).min(),
+            runs: entries.len(),
+            commands,
+        }
+    }
+
+    /// The human summary: a header line, then one row per command.
+    fn render(&self) -> Vec<String> {
+        let since = self
+            .since
+            .and_then(|ts| chrono::DateTime::from_timestamp(ts as i64, 0))
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        let s = if self.runs == 1 { "" } else { "s" };
+        let mut lines = vec![cformat!("<bold>{} run{s} since {since}</>", self.runs)];
+
+        let header = ["Command", "Runs", "Failed", "Median", "Flags"];
+        let rows: Vec<[String; 5]> = self
+            .commands
+            .iter()
+            .map(|usage| {
+                let mut flags: Vec<_> = usage.flags.iter().collect();
+                flags.sort_by(|a, b| b.1.cmp(a.1));
+                let flags = flags
+                    .iter()
+                    .map(|(flag, count)| format!("--{flag} {count}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                [
+                    usage.command.clone(),
+                    usage.runs.to_string(),
+                    usage.failed.to_string(),
+                    format!("{}ms", usage.median_ms),
+                    flags,
+                ]
+            })
+            .collect();
+        let width = |i: usize| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain([header[i].len()])
+                .max()
+                .unwrap_or(0)
+        };
+        let (w0, w1, w2, w3) = (width(0), width(1), width(2), width(3));
+
+        lines.push(String::new());
+        lines.push(cformat!(
+            "<bold>{:w0$}  {:>w1$}  {:>w2$}  {:>w3$}  {}</>",
+            header[0],
+            header[1],
+            header[2],
+            header[3],
+            header[4]
+        ));
+        for [command, runs, failed, median, flags] in &rows {
+            lines.push(
+                format!("{command:w0$}  {runs:>w1$}  {failed:>w2$}  {median:>w3$}  {flags}")
+                    .trim_end()
+                    .to_string(),
+            );
+        }
+        lines
+    }
+}
+
+/// Handle `wt report`.
+pub fn handle_report(format: OutputFormat) -> anyhow::Result<()> {
+    let report = Report::from_entries(&usage_journal::read_entries());
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        OutputFormat::Table | OutputFormat::ClaudeCode => {
+            if report.runs == 0 {
+                eprintln!("{}", info_message("No usage recorded"));
+                eprintln!(
+                    "{}",
+                    hint_message(cformat!(
+                        "To record, set <bright-black>usage-journal = true</> in the user config"
+                    ))
+                );
+                return Ok(());
+            }
+            for line in report.render() {
+                println!("{line}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(command: &str, flags: &[&str], dur_ms: u64, ok: bool) -> UsageEntry {
+        UsageEntry {
+            ts: 1_700_000_000 + dur_ms,
+            command: command.to_string(),
+            flags: flags.iter().map(|f| f.to_string()).collect(),
+            dur_ms,
+            ok,
+        }
+    }
+
+    #[test]
+    fn test_report_aggregates_per_command() {
+        let report = Report::from_entries(&[
+            entry("list", &["format"], 30, true),
+            entry("switch", &["create"], 50, true),
+            entry("list", &[], 10, true),
+            entry("list", &["format", "full"], 20, false),
+        ]);
+        assert_eq!(report.since, Some(1_700_000_010));
+        assert_eq!(report.runs, 4);
+        assert_eq!(
+            report.commands,
+            [
+                CommandUsage {
+                    command: "list".into(),
+                    runs: 3,
+                    failed: 1,
+                    median_ms: 20,
+                    flags: [("format".into(), 2), ("full".into(), 1)].into(),
+                },
+                CommandUsage {
+                    command: "switch".into(),
+                    runs: 1,
+                    failed: 0,
+                    median_ms: 50,
+                    flags: [("create".into(), 1)].into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report_empty() {
+        assert_eq!(Report::from_entries(&[]), Report::default());
+    }
+}
@@ -99,6 +99,7 @@ impl RepositoryCliExt for Repository {
                                 branch_name: branch.to_string(),
                                 deletion_mode,
                                 pruned: true,
+                                remote_branch: None,
                             });
                         }
                         if wt.locked.is_some() {
@@ -115,27 +116,55 @@ impl RepositoryCliExt for Repository {
                     None => {
                         // No worktree found - check if the branch exists locally
                         let branch_handle = self.branch(branch);
-                        if branch_handle.exists_locally()? {
+                        let exists_locally = branch_handle.exists_locally()?;
+
+                        // Tag checkouts (`wt switch <tag>`) are detached worktrees at
+                        // the tag's commit. They own no refs, so removing one only
+                        // removes the worktree.
+                        let tag_worktree = if exists_locally {
+                            None
+                        } else {
+                            self.tag_commit(branch)?.and_then(|commit| {
+                                worktrees
+                                    .iter()
+                                    .find(|wt| wt.detached && wt.head == commit && wt.path.exists())
+                            })
+                        };
+                        if let Some(wt) = tag_worktree {
+                            if wt.locked.is_some() {
+                                return Err(GitError::WorktreeLocked {
+                                    branch: branch.into(),
+                                    path: wt.path.clone(),
+                                    reason: wt.locked.clone(),
+                                }
+                                .into());
+                            }
+                            let is_current = current_path == wt.path;
+                            (wt.path.clone(), None, is_current)
+                        } else if exists_locally {
                             return Ok(RemoveResult::BranchOnly {
                                 branch_name: branch.to_string(),
                                 deletion_mode,
                                 pruned: false,
+                                remote_branch: None,
                             });
-                        }
-                        // Check if branch exists on a remote
-                        let remotes = branch_handle.remotes()?;
-                        if !remotes.is_empty() {
-                            return Err(GitError::RemoteOnlyBranch {
+                        } else {
+                            // Check if branch exists on a remote
+                            let remotes = branch_handle.remotes()?;
+                            if !remotes.is_empty() {
+                                return Err(GitError::RemoteOnlyBranch {
+                                    branch: branch.into(),
+                                    remote: remotes[0].clone(),
+                                }
+                                .into());
+                            }
+                            return Err(GitError::BranchNotFound {
                                 branch: branch.into(),
-                                remote: remotes[0].clone(),
+                                show_create_hint: false,
+                                closest: None,
                             }
                             .into());
                         }
-                        return Err(GitError::BranchNotFound {
-                            branch: branch.into(),
-                            show_create_hint: false,
-                        }
-                        .into());
                     }
                 }
             }
@@ -173,12 +202,62 @@ impl RepositoryCliExt for Repository {
             return Err(GitError::CannotRemoveMainWorktree.into());
         }
 
+        // A worktree whose branch was deleted out from under it has no branch
+        // left to delete. Only its last commit can be lost, so refuse if nothing
+        // else references it (unless -D).
+        let deleted_branch_commit = worktrees
+            .iter()
+            .find(|wt| wt.path == worktree_path)
+            .and_then(|wt| self.deleted_branch_commit(wt));
+        let deletion_mode = match (&deleted_branch_commit, &branch_name) {
+            (Some(commit), Some(branch)) => {
+                if !deletion_mode.is_force() && self.is_commit_unreferenced(commit)? {
+                    return Err(GitError::DeletedBranchUnreferenced {
+                        branch: branch.clone(),
+                        commit: commit.clone(),
+                    }
+                    .into());
+                }
+                BranchDeletionMode::Keep
+            }
+            _ => deletion_mode,
+        };
+
+        // A branch name that isn't valid UTF-8 is only held lossily, and git
+        // would be asked to delete a branch that doesn't exist
+        if !deletion_mode.should_keep()
+            && let Some(branch) = &branch_name
+            && worktrees
+                .iter()
+                .any(|wt| wt.path == worktree_path && wt.branch_raw.is_some())
+        {
+            return Err(GitError::BranchNameNotUtf8 {
+                branch: branch.clone(),
+                path: worktree_path,
+            }
+            .into());
+        }
+
         // Check working tree cleanliness (unless --force, which passes through to git)
         // NOTE: background removal fallback may still add --force later when
         // .gitmodules is detected at execution time (see output::handlers),
         // so this remains a best-effort check with a small TOCTOU window.
         if !force_worktree {
-            target_wt.ensure_clean("remove worktree", branch_name.as_deref(), true)?;
+            match &deleted_branch_commit {
+                // git status would report every file as added
+                Some(commit) if target_wt.is_dirty_against(commit)? => {
+                    return Err(GitError::UncommittedChanges {
+                        action: Some("remove worktree".into()),
+                        branch: branch_name.clone(),
+                        force_hint: true,
+                    }
+                    .into());
+                }
+                Some(_) => {}
+                None => {
+                    target_wt.ensure_clean("remove worktree", branch_name.as_deref(), true)?;
+                }
+            }
         }
 
         // Compute main_path and changed_directory based on whether we're removing current
@@ -216,7 +295,8 @@ impl RepositoryCliExt for Repository {
         let removed_commit = target_wt
             .run_command(&["rev-parse", "HEAD"])
             .ok()
-            .map(|s| s.trim().to_string());
+            .map(|s| s.trim().to_string())
+            .or(deleted_branch_commit);
 
         Ok(RemoveResult::RemovedWorktree {
             main_path,
@@ -229,6 +309,7 @@ impl RepositoryCliExt for Repository {
             force_worktree,
             expected_path,
             removed_commit,
+            remote_branch: None,
         })
     }
 
@@ -0,0 +1,492 @@
+//! Branches stacked on a finished branch (`--restack` for `wt merge` and `wt step prune`).
+//!
+//! A branch is stacked when it was created from another branch that has since
+//! been merged or pruned. After a squash or rebase merge, the stacked branch
+//! still carries the parent's original commits, so its `main↕` counts balloon.
+//! Restacking replays only the branch's own commits onto the target with
+//! `git rebase --onto <target> <old parent head>`.
+//!
+//! Which branch a branch is stacked on comes from its recorded parent
+//! (`wt parent`) when there is one, and otherwise from the nearest branch head
+//! in its history.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use color_print::cformat;
+use worktrunk::git::{AddWorktreeOpts, Repository, WorktrunkError};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, error_message, format_with_gutter, hint_message, info_message, progress_message,
+    success_message, warning_message,
+};
+
+/// A branch to rebase after its parent finished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StackedBranch {
+    pub branch: String,
+    /// Branch it was created from: a finished branch, or another stacked branch
+    pub parent: String,
+    /// Parent's head when the branch was stacked on it; the rebase's upstream
+    pub old_base: String,
+    /// Parent finished, so rebase onto the target rather than onto `parent`
+    pub onto_target: bool,
+}
+
+/// Find local branches stacked on the `finished` branches (name, head).
+///
+/// Returns them parents-first, so restacking in order rebases each branch onto
+/// its parent's new head. Finished branches already in `target` (plain merges)
+/// leave nothing stale, so they have no stacked branches.
+///
+/// Runs two git commands regardless of branch count: `for-each-ref` for the
+/// heads, and one `rev-list --parents` walk of everything not in `target`.
+pub(crate) fn find_stacked_branches(
+    repo: &Repository,
+    finished: &[(String, String)],
+    target: &str,
+) -> anyhow::Result<Vec<StackedBranch>> {
+    if finished.is_empty() {
+        return Ok(Vec::new());
+    }
+    let finished_names: HashSet<&str> = finished.iter().map(|(b, _)| b.as_str()).collect();
+
+    let heads: Vec<(String, String)> = repo
+        .list_local_branches()?
+        .into_iter()
+        .filter(|(branch, _)| !finished_names.contains(branch.as_str()) && branch != target)
+        .collect();
+    if heads.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let exclude = format!("^{target}");
+    let mut args = vec!["rev-list", "--parents"];
+    args.extend(finished.iter().map(|(_, sha)| sha.as_str()));
+    args.extend(heads.iter().map(|(_, sha)| sha.as_str()));
+    args.push(&exclude);
+    args.push("--");
+    let walk = repo.run_command(&args)?;
+
+    let graph: HashMap<&str, Vec<&str>> = walk
+        .lines()
+        .filter_map(|line| {
+            let mut shas = line.split_whitespace();
+            Some((shas.next()?, shas.collect()))
+        })
+        .collect();
+    Ok(stack_order(
+        &graph,
+        &heads,
+        finished,
+        &repo.branch_parents(),
+    ))
+}
+
+/// Nearest stacked-on branch of each candidate in `heads`, resolved to chains
+/// that end at a finished branch, parents first.
+///
+/// `graph` maps each commit not in the target to its parents; commits outside
+/// it are in the target and end the search. A parent recorded in `recorded`
+/// (`wt parent`) wins over the nearest branch head, as long as that parent is
+/// finished or a candidate.
+fn stack_order(
+    graph: &HashMap<&str, Vec<&str>>,
+    heads: &[(String, String)],
+    finished: &[(String, String)],
+    recorded: &HashMap<String, String>,
+) -> Vec<StackedBranch> {
+    let finished_at: HashMap<&str, &str> = finished
+        .iter()
+        .map(|(b, sha)| (sha.as_str(), b.as_str()))
+        .collect();
+    let mut candidates_at: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (branch, sha) in heads {
+        candidates_at
+            .entry(sha.as_str())
+            .or_default()
+            .push(branch.as_str());
+    }
+
+    // Nearest branch head below each candidate, searching breadth-first
+    let mut nearest: HashMap<&str, (&str, &str, bool)> = HashMap::new();
+    for (branch, head) in heads {
+        if let Some((parent, parent_head)) = recorded.get(branch).and_then(|parent| {
+            finished
+                .iter()
+                .chain(heads)
+                .find(|(b, _)| b == parent)
+                .map(|(b, sha)| (b.as_str(), sha.as_str()))
+        }) {
+            // The stack starts where the branch's history meets its parent's
+            let parent_commits: HashSet<&str> = bfs(graph, parent_head).into_iter().collect();
+            if let Some(old_base) = bfs(graph, head)
+                .into_iter()
+                .find(|commit| parent_commits.contains(commit))
+            {
+                let onto_target = finished.iter().any(|(b, _)| b == parent);
+                nearest.insert(branch.as_str(), (parent, old_base, onto_target));
+            }
+            continue;
+        }
+        let mut queue = VecDeque::from([head.as_str()]);
+        let mut seen = HashSet::new();
+        while let Some(commit) = queue.pop_front() {
+            if !graph.contains_key(commit) || !seen.insert(commit) {
+                continue;
+            }
+            if let Some(&parent) = finished_at.get(commit) {
+                nearest.insert(branch.as_str(), (parent, commit, true));
+                break;
+            }
+            // Branches sharing this branch's head aren't its parent
+            if commit != head.as_str()
+                && let Some(&parent) = candidates_at.get(commit).and_then(|b| b.first())
+            {
+                nearest.insert(branch.as_str(), (parent, commit, false));
+                break;
+            }
+            queue.extend(&graph[commit]);
+        }
+    }
+
+    // Keep chains that end at a finished branch; depth orders parents first
+    let depth_of = |branch: &str| {
+        let mut depth = 0;
+        let mut current = branch;
+        loop {
+            match nearest.get(current) {
+                Some((_, _, true)) => return Some(depth),
+                Some(&(parent, _, false)) if depth < heads.len() => {
+                    depth += 1;
+                    current = parent;
+                }
+                _ => return None,
+            }
+        }
+    };
+    let mut stacked: Vec<(usize, StackedBranch)> = heads
+        .iter()
+        .filter_map(|(branch, _)| {
+            let depth = depth_of(branch)?;
+            let (parent, old_base, onto_target) = nearest[branch.as_str()];
+            Some((
+                depth,
+                StackedBranch {
+                    branch: branch.clone(),
+                    parent: parent.to_string(),
+                    old_base: old_base.to_string(),
+                    onto_target,
+                },
+            ))
+        })
+        .collect();
+    stacked.sort_by(|(a_depth, a), (b_depth, b)| {
+        a_depth.cmp(b_depth).then_with(|| a.branch.cmp(&b.branch))
+    });
+    stacked.into_iter().map(|(_, s)| s).collect()
+}
+
+/// Commits in `graph` reachable from `start`, nearest first.
+fn bfs<'a>(graph: &HashMap<&'a str, Vec<&'a str>>, start: &'a str) -> Vec<&'a str> {
+    let mut order = Vec::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(commit) = queue.pop_front() {
+        if let Some(parents) = graph.get(commit)
+            && seen.insert(commit)
+        {
+            order.push(commit);
+            queue.extend(parents);
+        }
+    }
+    order
+}
+
+/// Warn that `stacked` branches now carry stale commits, with the rebase that fixes each.
+pub(crate) fn warn_stacked(stacked: &[StackedBranch], target: &str) {
+    let mut parents: Vec<&str> = stacked
+        .iter()
+        .filter(|s| s.onto_target)
+        .map(|s| s.parent.as_str())
+        .collect();
+    parents.sort_unstable();
+    parents.dedup();
+    let names: Vec<String> = stacked
+        .iter()
+        .map(|s| cformat!("<bold>{}</>", s.branch))
+        .collect();
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Stacked on <bold>{}</>, now stale: {}",
+            parents.join(", "),
+            names.join(", ")
+        ))
+    );
+    for s in stacked.iter().filter(|s| s.onto_target) {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To rebase <bold>{}</>, run <underline>git rebase --onto {target} {} {}</>",
+                s.branch,
+                &s.old_base[..7.min(s.old_base.len())],
+                s.branch
+            ))
+        );
+    }
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "To rebase stacked branches automatically, use <underline>--restack</>"
+        ))
+    );
+}
+
+/// Rebase each stacked branch in order, stopping at the first that fails.
+///
+/// A branch with a worktree is rebased there, and a conflicted rebase is left
+/// in progress for the user to resolve. A branch without one is rebased in a
+/// temporary worktree, which a conflict aborts and removes.
+pub(crate) fn restack_branches(
+    repo: &Repository,
+    stacked: &[StackedBranch],
+    target: &str,
+) -> anyhow::Result<()> {
+    for (i, s) in stacked.iter().enumerate() {
+        let onto = if s.onto_target { target } else { &s.parent };
+        eprintln!(
+            "{}",
+            progress_message(cformat!(
+                "Rebasing <bold>{}</> onto <bold>{onto}</>...",
+                s.branch
+            ))
+        );
+        if let Err(failure) = rebase_stacked(repo, s, onto) {
+            eprintln!("{}", error_message(failure.message));
+            if !failure.git_output.is_empty() {
+                eprintln!("{}", format_with_gutter(&failure.git_output, None));
+            }
+            for hint in failure.hints {
+                eprintln!("{}", hint_message(hint));
+            }
+            let remaining: Vec<&str> = stacked[i + 1..].iter().map(|s| s.branch.as_str()).collect();
+            if !remaining.is_empty() {
+                eprintln!(
+                    "{}",
+                    info_message(format!("Not restacked: {}", remaining.join(", ")))
+                );
+            }
+            return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+        }
+        // The finished parent is gone; the branch now sits on the target
+        if s.onto_target
+            && repo.branch_parent(&s.branch).is_some()
+            && (repo.default_branch().as_deref() == Some(target)
+                || repo.set_branch_parent(&s.branch, target).is_err())
+        {
+            repo.clear_branch_parent(&s.branch)?;
+        }
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Rebased <bold>{}</> onto <bold>{onto}</>",
+                s.branch
+            ))
+        );
+    }
+    Ok(())
+}
+
+/// Why a stacked branch wasn't rebased, and what to do about it.
+struct RestackFailure {
+    message: String,
+    git_output: String,
+    hints: Vec<String>,
+}
+
+impl From<anyhow::Error> for RestackFailure {
+    fn from(err: anyhow::Error) -> Self {
+        Self {
+            message: err.to_string(),
+            git_output: String::new(),
+            hints: Vec::new(),
+        }
+    }
+}
+
+fn rebase_stacked(repo: &Repository, s: &StackedBranch, onto: &str) -> Result<(), RestackFailure> {
+    let branch = &s.branch;
+    let args = ["rebase", "--onto", onto, s.old_base.as_str()];
+
+    if let Some(path) = repo.worktree_for_branch(branch)? {
+        let wt = repo.worktree_at(&path);
+        if wt.is_dirty()? {
+            return Err(RestackFailure {
+                message: cformat!(
+                    "Can't rebase <bold>{branch}</>: uncommitted changes @ {}",
+                    format_path_for_display(&path)
+                ),
+                git_output: String::new(),
+                hints: vec![cformat!(
+                    "Commit or stash changes first, then run <underline>git rebase --onto {onto} {}</>",
+                    s.old_base
+                )],
+            });
+        }
+        return match wt.run_command(&args) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(RestackFailure {
+                message: cformat!(
+                    "Rebase of <bold>{branch}</> onto <bold>{onto}</> stopped @ {}",
+                    format_path_for_display(&path)
+                ),
+                git_output: e.to_string(),
+                hints: vec![
+                    cformat!(
+                        "To continue after resolving conflicts, run <underline>git rebase --continue</>"
+                    ),
+                    cformat!("To abort, run <underline>git rebase --abort</>"),
+                ],
+            }),
+        };
+    }
+
+    // No worktree: check the branch out in a temporary one
+    let temp = tempfile::Builder::new()
+        .prefix("wt-restack-")
+        .tempdir()
+        .map_err(anyhow::Error::from)?;
+    let _ = repo.add_worktree(AddWorktreeOpts {
+        branch: Some(branch.as_str()),
+        ..AddWorktreeOpts::new(temp.path())
+    })?;
+    let wt = repo.worktree_at(temp.path());
+    let result = wt.run_command(&args);
+    if result.is_err() {
+        let _ = wt.run_command(&["rebase", "--abort"]);
+    }
+    repo.remove_worktree(temp.path(), true)?;
+    result.map(|_| ()).map_err(|e| RestackFailure {
+        message: cformat!("Rebase of <bold>{branch}</> onto <bold>{onto}</> has conflicts"),
+        git_output: e.to_string(),
+        hints: vec![cformat!(
+            "To rebase by hand, run <underline>wt switch {branch}</>, then <underline>git rebase --onto {onto} {}</>",
+            s.old_base
+        )],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_stack_order_children_and_grandchildren() {
+        // target ← a1 (base, finished) ← b1 (ui) ← c1 (ui-polish)
+        //                              ← d1 (api)
+        let graph: HashMap<&str, Vec<&str>> = HashMap::from([
+            ("a1", vec!["m"]),
+            ("b1", vec!["a1"]),
+            ("c1", vec!["b1"]),
+            ("d1", vec!["a1"]),
+            ("x1", vec!["m"]),
+        ]);
+        let heads = pairs(&[
+            ("ui-polish", "c1"),
+            ("ui", "b1"),
+            ("api", "d1"),
+            ("unrelated", "x1"),
+            ("main", "m"),
+        ]);
+        let stacked = stack_order(&graph, &heads, &pairs(&[("base", "a1")]), &HashMap::new());
+        let summary: Vec<_> = stacked
+            .iter()
+            .map(|s| {
+                (
+                    s.branch.as_str(),
+                    s.parent.as_str(),
+                    s.old_base.as_str(),
+                    s.onto_target,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("api", "base", "a1", true),
+                ("ui", "base", "a1", true),
+                ("ui-polish", "ui", "b1", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stack_order_merged_parent_leaves_nothing() {
+        // Parent's head is in the target (fast-forward merge): not in the walk
+        let graph: HashMap<&str, Vec<&str>> = HashMap::from([("b1", vec!["a1"])]);
+        let stacked = stack_order(
+            &graph,
+            &pairs(&[("ui", "b1")]),
+            &pairs(&[("base", "a1")]),
+            &HashMap::new(),
+        );
+        assert!(stacked.is_empty());
+    }
+
+    #[test]
+    fn test_stack_order_same_head_as_finished() {
+        let graph: HashMap<&str, Vec<&str>> = HashMap::from([("a1", vec!["m"])]);
+        let stacked = stack_order(
+            &graph,
+            &pairs(&[("copy", "a1")]),
+            &pairs(&[("base", "a1")]),
+            &HashMap::new(),
+        );
+        assert_eq!(stacked.len(), 1);
+        assert!(stacked[0].onto_target);
+    }
+
+    #[test]
+    fn test_stack_order_recorded_parent() {
+        // target ← a1 (base, finished) ← b1 ← b2 (api)
+        //                                  ← c1 (ui, stacked on api at b1)
+        // From the graph alone, ui's nearest head is base, which would carry
+        // api's b1 into ui; the recorded parent keeps ui on api.
+        let graph: HashMap<&str, Vec<&str>> = HashMap::from([
+            ("a1", vec!["m"]),
+            ("b1", vec!["a1"]),
+            ("b2", vec!["b1"]),
+            ("c1", vec!["b1"]),
+        ]);
+        let heads = pairs(&[("ui", "c1"), ("api", "b2")]);
+        let finished = pairs(&[("base", "a1")]);
+
+        let detected = stack_order(&graph, &heads, &finished, &HashMap::new());
+        assert!(detected.iter().all(|s| s.parent == "base"));
+
+        let recorded = HashMap::from([("ui".to_string(), "api".to_string())]);
+        let stacked = stack_order(&graph, &heads, &finished, &recorded);
+        let summary: Vec<_> = stacked
+            .iter()
+            .map(|s| {
+                (
+                    s.branch.as_str(),
+                    s.parent.as_str(),
+                    s.old_base.as_str(),
+                    s.onto_target,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [("api", "base", "a1", true), ("ui", "api", "b1", false)]
+        );
+    }
+}
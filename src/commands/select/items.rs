@@ -89,6 +89,8 @@ pub(super) struct WorktreeSkimItem {
     pub item: Arc<ListItem>,
     /// Shared cache for pre-computed previews (all modes)
     pub preview_cache: PreviewCache,
+    /// Key bindings line shown under the tabs
+    pub controls: Arc<str>,
 }
 
 impl SkimItem for WorktreeSkimItem {
@@ -108,7 +110,7 @@ impl SkimItem for WorktreeSkimItem {
         let mode = PreviewStateData::read_mode();
 
         // Build preview: tabs header + content
-        let mut result = Self::render_preview_tabs(mode);
+        let mut result = Self::render_preview_tabs(mode, &self.controls);
         result.push_str(&self.preview_for_mode(mode, context.width, context.height));
 
         ItemPreview::AnsiText(result)
@@ -119,9 +121,9 @@ impl WorktreeSkimItem {
     /// Render the tab header for the preview window
     ///
     /// Shows all preview modes as tabs, with the current mode bolded
-    /// and unselected modes dimmed. Controls shown below in normal text
-    /// for visual distinction from inactive tabs.
-    pub(super) fn render_preview_tabs(mode: PreviewMode) -> String {
+    /// and unselected modes dimmed. Controls (from `PickerKeys::controls`)
+    /// shown below in normal text for visual distinction from inactive tabs.
+    pub(super) fn render_preview_tabs(mode: PreviewMode, controls: &str) -> String {
         // Full SGR reset (\x1b[0m) to ensure clean state between styled elements.
         // Using anstyle::Reset instead of cformat's </> (\x1b[22m) because some terminals
         // don't properly handle the intensity-only reset after bold text. This matches
@@ -144,9 +146,7 @@ impl WorktreeSkimItem {
         let tab5 = format_tab("5: summary", mode == PreviewMode::Summary);
 
         // Controls use dim yellow to distinguish from dimmed (white) tabs
-        let controls = cformat!(
-            "<dim,yellow>Enter: switch | alt-c: create | alt-r: remove | Esc: cancel | ctrl-u/d: scroll | alt-p: toggle</>"
-        );
+        let controls = cformat!("<dim,yellow>{controls}</>");
 
         // End each tab and controls with full reset to prevent style bleeding
         // into dividers and preview content
@@ -415,6 +415,7 @@ impl WorktreeSkimItem {
 
 #[cfg(test)]
 mod tests {
+    use super::super::keys::PickerKeys;
     use super::*;
     use insta::assert_snapshot;
 
@@ -428,7 +429,8 @@ mod tests {
             ("upstream_diff", PreviewMode::UpstreamDiff),
             ("summary", PreviewMode::Summary),
         ] {
-            assert_snapshot!(name, WorktreeSkimItem::render_preview_tabs(mode));
+            let controls = PickerKeys::default().controls();
+            assert_snapshot!(name, WorktreeSkimItem::render_preview_tabs(mode, &controls));
         }
     }
 
@@ -478,6 +480,7 @@ mod tests {
                 branch_name: "feature".to_string(),
                 item: Arc::clone(&item),
                 preview_cache,
+                controls: Arc::from(""),
             }
         };
 
@@ -489,6 +492,7 @@ mod tests {
                 branch_name: "feature".to_string(),
                 item: Arc::clone(&item),
                 preview_cache,
+                controls: Arc::from(""),
             }
         };
 
@@ -505,7 +509,10 @@ mod tests {
     #[test]
     fn test_render_preview_tabs_ansi_codes() {
         // Test that ANSI escape sequences properly reset to prevent style bleeding
-        let output = WorktreeSkimItem::render_preview_tabs(PreviewMode::WorkingTree);
+        let output = WorktreeSkimItem::render_preview_tabs(
+            PreviewMode::WorkingTree,
+            &PickerKeys::default().controls(),
+        );
 
         let first_line = output.lines().next().unwrap();
         let second_line = output.lines().nth(1).unwrap();
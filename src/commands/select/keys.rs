@@ -0,0 +1,186 @@
+//! Picker key bindings.
+//!
+//! Maps the `[keys]` user config to skim bind specs and to the controls line
+//! shown above the preview.
+
+use worktrunk::config::KeysConfig;
+
+/// Action selected by the user in the picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PickerAction {
+    /// Switch to the selected worktree (Enter key).
+    Switch,
+    /// Create a new worktree from the search query (alt-c).
+    Create,
+    /// Remove the selected worktree (alt-r for "remove").
+    Remove,
+    /// Fetch the selected branch's upstream (alt-f).
+    Fetch,
+    /// Open the selected worktree in the editor (alt-o).
+    Open,
+    /// Copy the selected worktree's path to the clipboard (alt-y for "yank").
+    CopyPath,
+}
+
+impl PickerAction {
+    /// Label passed to skim's `accept(...)` and read back from the final event.
+    fn label(self) -> &'static str {
+        match self {
+            PickerAction::Switch => "switch",
+            PickerAction::Create => "create",
+            PickerAction::Remove => "remove",
+            PickerAction::Fetch => "fetch",
+            PickerAction::Open => "open",
+            PickerAction::CopyPath => "copy-path",
+        }
+    }
+
+    /// Parse an `accept(...)` label; unlabeled accepts (Enter) switch.
+    pub(super) fn from_label(label: Option<&str>) -> Self {
+        [
+            PickerAction::Create,
+            PickerAction::Remove,
+            PickerAction::Fetch,
+            PickerAction::Open,
+            PickerAction::CopyPath,
+        ]
+        .into_iter()
+        .find(|action| Some(action.label()) == label)
+        .unwrap_or(PickerAction::Switch)
+    }
+
+    /// Description in the controls line.
+    fn description(self) -> &'static str {
+        match self {
+            PickerAction::CopyPath => "copy path",
+            action => action.label(),
+        }
+    }
+}
+
+/// Resolved picker key bindings. Disabled actions are absent.
+pub(super) struct PickerKeys {
+    bindings: Vec<(PickerAction, String)>,
+}
+
+impl Default for PickerKeys {
+    fn default() -> Self {
+        Self::from_config(&KeysConfig::default())
+    }
+}
+
+impl PickerKeys {
+    pub(super) fn from_config(keys: &KeysConfig) -> Self {
+        let bindings = [
+            (PickerAction::Switch, keys.switch()),
+            (PickerAction::Create, keys.create()),
+            (PickerAction::Remove, keys.remove()),
+            (PickerAction::Fetch, keys.fetch()),
+            (PickerAction::Open, keys.open()),
+            (PickerAction::CopyPath, keys.copy_path()),
+        ]
+        .into_iter()
+        .filter_map(|(action, key)| key.map(|key| (action, key.to_string())))
+        .collect();
+        Self { bindings }
+    }
+
+    /// Skim `--bind` specs for all enabled actions.
+    pub(super) fn bind_specs(&self) -> Vec<String> {
+        let mut specs = Vec::new();
+        // skim accepts on Enter by default; unbind it when switching moved elsewhere
+        if !self
+            .bindings
+            .iter()
+            .any(|(action, key)| *action == PickerAction::Switch && key == "enter")
+        {
+            specs.push("enter:ignore".to_string());
+        }
+        for (action, key) in &self.bindings {
+            specs.push(match action {
+                PickerAction::Switch => format!("{key}:accept"),
+                action => format!("{key}:accept({})", action.label()),
+            });
+        }
+        specs
+    }
+
+    /// Controls line listing enabled bindings.
+    ///
+    /// Switch, create and remove come first, then cancel, so the line's prefix
+    /// is stable when narrow preview panes truncate it.
+    pub(super) fn controls(&self) -> String {
+        let format_binding = |(action, key): &(PickerAction, String)| {
+            let key = if key == "enter" { "Enter" } else { key };
+            format!("{key}: {}", action.description())
+        };
+        let (primary, secondary): (Vec<_>, Vec<_>) = self.bindings.iter().partition(|(a, _)| {
+            matches!(
+                a,
+                PickerAction::Switch | PickerAction::Create | PickerAction::Remove
+            )
+        });
+        primary
+            .into_iter()
+            .map(format_binding)
+            .chain(std::iter::once("Esc: cancel".to_string()))
+            .chain(secondary.into_iter().map(format_binding))
+            .chain(["ctrl-u/d: scroll".to_string(), "alt-p: toggle".to_string()])
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_bindings() {
+        let keys = PickerKeys::default();
+        assert_eq!(
+            keys.bind_specs(),
+            vec![
+                "enter:accept",
+                "alt-c:accept(create)",
+                "alt-r:accept(remove)",
+                "alt-f:accept(fetch)",
+                "alt-o:accept(open)",
+                "alt-y:accept(copy-path)",
+            ]
+        );
+        assert_eq!(
+            keys.controls(),
+            "Enter: switch | alt-c: create | alt-r: remove | Esc: cancel | alt-f: fetch | alt-o: open | alt-y: copy path | ctrl-u/d: scroll | alt-p: toggle"
+        );
+    }
+
+    #[test]
+    fn test_configured_bindings() {
+        let keys = PickerKeys::from_config(&KeysConfig {
+            switch: Some("ctrl-s".into()),
+            remove: Some("ctrl-x".into()),
+            fetch: Some(String::new()),
+            ..Default::default()
+        });
+        let specs = keys.bind_specs();
+        assert_eq!(specs[0], "enter:ignore");
+        assert!(specs.contains(&"ctrl-s:accept".to_string()));
+        assert!(specs.contains(&"ctrl-x:accept(remove)".to_string()));
+        assert!(!specs.iter().any(|s| s.contains("fetch")));
+
+        let controls = keys.controls();
+        assert!(controls.starts_with("ctrl-s: switch | alt-c: create | ctrl-x: remove"));
+        assert!(!controls.contains("fetch"));
+    }
+
+    #[test]
+    fn test_action_from_label() {
+        assert_eq!(PickerAction::from_label(None), PickerAction::Switch);
+        assert_eq!(
+            PickerAction::from_label(Some("copy-path")),
+            PickerAction::CopyPath
+        );
+        assert_eq!(PickerAction::from_label(Some("fetch")), PickerAction::Fetch);
+    }
+}
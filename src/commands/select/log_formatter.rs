@@ -334,7 +334,7 @@ mod tests {
         let input = format!("* \x01{}\x00abc1234\x1f1699999000\x1f Fix bug", full_hash);
         let stats = stats_for(full_hash, 5, 2);
         let output = format_log_output_with_formatter(&input, &stats, fixed_time_formatter);
-        assert_snapshot!(output, @"* abc1234   [32m+5[0m   [31m-2[0m [2m  1h[0m Fix bug");
+        assert_snapshot!(output, @"* abc1234 [32m+  5[0m [31m-  2[0m [2m  1h[0m Fix bug");
 
         // With graph prefix (same structure, different message)
         let input = format!(
@@ -342,7 +342,7 @@ mod tests {
             full_hash
         );
         let output = format_log_output_with_formatter(&input, &stats, fixed_time_formatter);
-        assert_snapshot!(output, @"* abc1234   [32m+5[0m   [31m-2[0m [2m  1h[0m Commit with graph");
+        assert_snapshot!(output, @"* abc1234 [32m+  5[0m [31m-  2[0m [2m  1h[0m Commit with graph");
     }
 
     #[test]
@@ -354,7 +354,7 @@ mod tests {
         );
         let stats = stats_for(full_hash, 13, 5);
         let output = format_log_output_with_formatter(&input, &stats, fixed_time_formatter);
-        assert_snapshot!(output, @"* abc1234  [32m+13[0m   [31m-5[0m [2m  1h[0m Add feature");
+        assert_snapshot!(output, @"* abc1234 [32m+ 13[0m [31m-  5[0m [2m  1h[0m Add feature");
     }
 
     #[test]
@@ -369,8 +369,8 @@ mod tests {
         let stats = multi_stats(&[(hash1, 5, 2), (hash2, 10, 3)]);
         let output = format_log_output_with_formatter(&input, &stats, fixed_time_formatter);
         assert_snapshot!(output, @"
-        * abc1234   [32m+5[0m   [31m-2[0m [2m  1h[0m First commit
-        * def5678  [32m+10[0m   [31m-3[0m [2m  1h[0m Second commit
+        * abc1234 [32m+  5[0m [31m-  2[0m [2m  1h[0m First commit
+        * def5678 [32m+ 10[0m [31m-  3[0m [2m  1h[0m Second commit
         ");
     }
 
@@ -396,7 +396,7 @@ mod tests {
         assert_snapshot!(output, @r"
         *   abc1234           [2m  1h[0m Merge branch
         |\  
-        | * def5678   [32m+5[0m   [31m-2[0m [2m  1h[0m Feature commit
+        | * def5678 [32m+  5[0m [31m-  2[0m [2m  1h[0m Feature commit
         ");
     }
 
@@ -450,7 +450,7 @@ mod tests {
         );
         let stats = stats_for(full_hash, 0, 50);
         let output = format_log_output_with_formatter(&input, &stats, fixed_time_formatter);
-        assert_snapshot!(output, @"* abc1234       [31m-50[0m [2m  1h[0m Remove old code");
+        assert_snapshot!(output, @"* abc1234      [31m- 50[0m [2m  1h[0m Remove old code");
     }
 
     #[test]
@@ -462,7 +462,7 @@ mod tests {
         );
         let stats = stats_for(full_hash, 1500, 800);
         let output = format_log_output_with_formatter(&input, &stats, fixed_time_formatter);
-        assert_snapshot!(output, @"* abc1234  [1m[32m+1K[0m [31m-800[0m [2m  1h[0m Big refactor");
+        assert_snapshot!(output, @"* abc1234 [1m[32m+ 1K[0m [31m-800[0m [2m  1h[0m Big refactor");
     }
 
     #[test]
@@ -470,12 +470,12 @@ mod tests {
         // Standard case: hash, stats, time, message
         let commit_line = "abc1234\x1f1699999000\x1f Test commit";
         let output = format_commit_line(commit_line, (10, 5), 7, &fixed_time_formatter);
-        assert_snapshot!(output, @"abc1234  [32m+10[0m   [31m-5[0m [2m  1h[0m Test commit");
+        assert_snapshot!(output, @"abc1234 [32m+ 10[0m [31m-  5[0m [2m  1h[0m Test commit");
 
         // With padding: shorter hash padded to target width
         let commit_line = "abc12\x1f1699999000\x1f Short hash";
         let output = format_commit_line(commit_line, (5, 2), 9, &fixed_time_formatter);
-        assert_snapshot!(output, @"abc12       [32m+5[0m   [31m-2[0m [2m  1h[0m Short hash");
+        assert_snapshot!(output, @"abc12     [32m+  5[0m [31m-  2[0m [2m  1h[0m Short hash");
     }
 
     // Tests for process_log_with_dimming
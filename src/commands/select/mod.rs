@@ -3,41 +3,42 @@
 //! A skim-based TUI for selecting and switching between worktrees.
 
 mod items;
+mod keys;
 mod log_formatter;
 mod pager;
 mod preview;
 mod summary;
 
 use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
 use std::sync::Arc;
 
 use anyhow::Context;
+use color_print::cformat;
 use dashmap::DashMap;
 use skim::prelude::*;
 use worktrunk::git::{Repository, current_or_recover};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    TerminalGuard, eprintln, hint_message, progress_message, success_message, warning_message,
+};
 
 use super::handle_switch::{
     approve_switch_hooks, run_pre_switch_hooks, spawn_switch_background_hooks, switch_extra_vars,
 };
 use super::list::collect;
 use super::worktree::{
-    SwitchBranchInfo, SwitchResult, execute_switch, get_path_mismatch, handle_remove, plan_switch,
+    SwitchBranchInfo, SwitchResult, SwitchTargetFlags, check_new_branch_name, execute_switch,
+    get_path_mismatch, handle_remove, plan_switch,
 };
 use crate::output::{handle_remove_output, handle_switch_output};
 
 use items::{HeaderSkimItem, PreviewCache, WorktreeSkimItem};
+use keys::{PickerAction, PickerKeys};
 use preview::{PreviewLayout, PreviewMode, PreviewState};
 
-/// Action selected by the user in the picker.
-enum PickerAction {
-    /// Switch to the selected worktree (Enter key).
-    Switch,
-    /// Create a new worktree from the search query (alt-c).
-    Create,
-    /// Remove the selected worktree (alt-r for "remove").
-    Remove,
-}
-
 pub fn handle_select(
     cli_branches: bool,
     cli_remotes: bool,
@@ -48,22 +49,185 @@ pub fn handle_select(
         anyhow::bail!("Interactive picker requires an interactive terminal");
     }
 
-    let (repo, is_recovered) = current_or_recover()?;
+    // Initialize preview mode state file (auto-cleanup on drop)
+    let state = PreviewState::new();
+
+    // Actions other than switch/create re-open the picker afterwards, with
+    // freshly collected data and the same query.
+    let mut query = String::new();
+    loop {
+        // Re-open the repository each round so removals and fetches are reflected
+        let (repo, is_recovered) = current_or_recover()?;
+        let keys = PickerKeys::from_config(&repo.user_config().keys.clone().unwrap_or_default());
+
+        let Some(out) = run_picker(&repo, &state, &keys, cli_branches, cli_remotes, &query)? else {
+            return Ok(());
+        };
+        if out.is_abort {
+            return Ok(());
+        }
+
+        let action = match &out.final_event {
+            Event::EvActAccept(label) => PickerAction::from_label(label.as_deref()),
+            _ => PickerAction::Switch,
+        };
+        let selected_name = out
+            .selected_items
+            .first()
+            .map(|item| item.output().to_string());
+        query = out.query.clone();
+
+        match action {
+            // Read-only actions, also available with --no-cd
+            PickerAction::Open | PickerAction::CopyPath => {
+                let branch_name = selected_name.context("skim accept has no selection")?;
+                let path = selected_worktree_path(&repo, &branch_name)?;
+                if action == PickerAction::Open {
+                    open_in_editor(&path)?;
+                } else {
+                    copy_to_clipboard(&path)?;
+                }
+            }
+            // --no-cd: just output the selected branch name and exit (read-only, no side effects)
+            _ if !change_dir => {
+                let identifier =
+                    resolve_print_identifier(&action, query.trim().to_string(), selected_name)?;
+                println!("{identifier}");
+                return Ok(());
+            }
+            PickerAction::Fetch => {
+                let branch_name = selected_name.context("skim accept has no selection")?;
+                fetch_upstream(&repo, &branch_name)?;
+            }
+            PickerAction::Remove => {
+                // Get the selected worktree's branch name
+                let branch_name = selected_name.context("skim accept has no selection")?;
+
+                let config = repo.user_config();
+
+                // Safe removal: no force-delete (-D), no force-worktree (-f)
+                let result = handle_remove(
+                    &branch_name,
+                    false, // keep_branch: delete branch (default behavior)
+                    false, // force_delete: no -D
+                    false, // force_worktree: no -f
+                    config,
+                )
+                .context("Failed to remove worktree")?;
+
+                // Execute removal in foreground, no hooks, not quiet
+                handle_remove_output(&result, true, false, false)?;
+            }
+            PickerAction::Create | PickerAction::Switch => {
+                let should_create = matches!(action, PickerAction::Create);
+
+                // Get branch name: from query if creating new, from selected item if switching
+                let identifier = if should_create {
+                    let query = query.trim().to_string();
+                    if query.is_empty() {
+                        anyhow::bail!("Cannot create worktree: no branch name entered");
+                    }
+                    check_new_branch_name(&repo, &query, false)?
+                } else {
+                    // Enter pressed: skim accept always includes a selection (abort handled above)
+                    selected_name.context("skim accept has no selection")?
+                };
+
+                // Load config — reuse recovered repo if we recovered earlier
+                let repo = if is_recovered {
+                    repo.clone()
+                } else {
+                    Repository::current().context("Failed to switch worktree")?
+                };
+                let config = repo.user_config();
+
+                // Run pre-switch hooks before anything else (before branch validation, planning, etc.)
+                // Skip when recovered — the source worktree is gone, nothing to run hooks against.
+                if !is_recovered {
+                    run_pre_switch_hooks(&repo, config, true)?;
+                }
+
+                // Switch to existing worktree or create new one
+                let flags = SwitchTargetFlags {
+                    create: should_create,
+                    ..Default::default()
+                };
+                let plan = plan_switch(&repo, &identifier, flags, false, config)?;
+                let hooks_approved = approve_switch_hooks(&repo, config, &plan, false, true)?;
+                let (result, branch_info) =
+                    execute_switch(&repo, plan, config, false, hooks_approved)?;
 
+                // Compute path mismatch lazily (deferred from plan_switch for existing worktrees)
+                let branch_info = match &result {
+                    SwitchResult::Existing { path } | SwitchResult::AlreadyAt(path) => {
+                        let expected_path =
+                            get_path_mismatch(&repo, &branch_info.branch, path, config);
+                        SwitchBranchInfo {
+                            expected_path,
+                            ..branch_info
+                        }
+                    }
+                    _ => branch_info,
+                };
+
+                // Show success message; emit cd directive if shell integration is active
+                // When recovered from a deleted worktree, fall back to repo_path().
+                let fallback_path = repo.repo_path()?.to_path_buf();
+                let cwd = std::env::current_dir().unwrap_or(fallback_path.clone());
+                let source_root = repo.current_worktree().root().unwrap_or(fallback_path);
+                let hooks_display_path = handle_switch_output(
+                    &result,
+                    &branch_info,
+                    change_dir,
+                    Some(&source_root),
+                    &cwd,
+                )?;
+
+                // Spawn background hooks after success message
+                if hooks_approved {
+                    let extra_vars = switch_extra_vars(&result);
+                    spawn_switch_background_hooks(
+                        &repo,
+                        config,
+                        &result,
+                        &branch_info.branch,
+                        None,
+                        false,
+                        &extra_vars,
+                        hooks_display_path.as_deref(),
+                    )?;
+                }
+
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Collect worktree data and run one round of the skim picker.
+///
+/// Returns `None` when there is nothing to show.
+fn run_picker(
+    repo: &Repository,
+    state: &PreviewState,
+    keys: &PickerKeys,
+    cli_branches: bool,
+    cli_remotes: bool,
+    query: &str,
+) -> anyhow::Result<Option<skim::SkimOutput>> {
     // Merge CLI flags with resolved config
     let config = repo.config();
     let show_branches = cli_branches || config.list.branches();
     let show_remotes = cli_remotes || config.list.remotes();
 
-    // Initialize preview mode state file (auto-cleanup on drop)
-    let state = PreviewState::new();
-
     // Gather list data using simplified collection (buffered mode)
     // Skip expensive operations not needed for select UI
     let skip_tasks: std::collections::HashSet<collect::TaskKind> = [
         collect::TaskKind::BranchDiff,
         collect::TaskKind::CiStatus,
         collect::TaskKind::MergeTreeConflicts,
+        collect::TaskKind::SquashMerged,
+        collect::TaskKind::UnmergedCommits,
     ]
     .into_iter()
     .collect();
@@ -73,7 +237,7 @@ pub fn handle_select(
     let command_timeout = config.switch_picker.picker_command_timeout();
 
     let Some(list_data) = collect::collect(
-        &repo,
+        repo,
         collect::ShowConfig::Resolved {
             show_branches,
             show_remotes,
@@ -85,7 +249,7 @@ pub fn handle_select(
         true,  // skip_expensive_for_stale (faster for repos with many stale branches)
     )?
     else {
-        return Ok(());
+        return Ok(None);
     };
 
     // Use the same layout system as `wt list` for proper column alignment
@@ -103,6 +267,8 @@ pub fn handle_select(
         skim_list_width,
         &list_data.main_worktree_path,
         None, // URL column not shown in select
+        &config.list,
+        None,
     );
 
     // Render header using layout system (need both plain and styled text for skim)
@@ -110,6 +276,9 @@ pub fn handle_select(
     let header_display_text = header_line.render();
     let header_plain_text = header_line.plain_text();
 
+    // Key bindings line, shared by every item's preview header
+    let controls: Arc<str> = Arc::from(keys.controls());
+
     // Create shared cache for all preview modes (pre-computed in background)
     let preview_cache: PreviewCache = Arc::new(DashMap::new());
 
@@ -129,6 +298,7 @@ pub fn handle_select(
 
             let item = Arc::new(item);
             items_for_precompute.push(Arc::clone(&item));
+            let controls = Arc::clone(&controls);
 
             Arc::new(WorktreeSkimItem {
                 display_text,
@@ -136,6 +306,7 @@ pub fn handle_select(
                 branch_name,
                 item,
                 preview_cache: Arc::clone(&preview_cache),
+                controls,
             }) as Arc<dyn SkimItem>
         })
         .collect();
@@ -173,6 +344,7 @@ pub fn handle_select(
         .layout("reverse".to_string())
         .header_lines(1) // Make first line (header) non-selectable
         .multi(false)
+        .query(Some(query.to_string()))
         .no_info(true) // Hide info line (matched/total counter)
         .preview(Some("".to_string())) // Enable preview (empty string means use SkimItem::preview())
         .preview_window(preview_window_spec)
@@ -193,39 +365,41 @@ pub fn handle_select(
             "fg:-1,bg:-1,header:-1,matched:108,current:237,current_bg:251,current_match:108"
                 .to_string(),
         ))
-        .bind(vec![
-            // Mode switching (1/2/3/4/5 keys change preview content)
-            format!(
-                "1:execute-silent(echo 1 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "2:execute-silent(echo 2 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "3:execute-silent(echo 3 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "4:execute-silent(echo 4 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "5:execute-silent(echo 5 > {0})+refresh-preview",
-                state_path_str
-            ),
-            // Create new worktree with query as branch name (alt-c for "create")
-            "alt-c:accept(create)".to_string(),
-            // Remove selected worktree (alt-r for "remove")
-            "alt-r:accept(remove)".to_string(),
-            // Preview toggle (alt-p shows/hides preview)
-            // Note: skim doesn't support change-preview-window like fzf, only toggle
-            "alt-p:toggle-preview".to_string(),
-            // Preview scrolling (half-page based on terminal height)
-            format!("ctrl-u:preview-up({half_page})"),
-            format!("ctrl-d:preview-down({half_page})"),
-        ])
+        .bind(
+            vec![
+                // Mode switching (1/2/3/4/5 keys change preview content)
+                format!(
+                    "1:execute-silent(echo 1 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "2:execute-silent(echo 2 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "3:execute-silent(echo 3 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "4:execute-silent(echo 4 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "5:execute-silent(echo 5 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                // Preview toggle (alt-p shows/hides preview)
+                // Note: skim doesn't support change-preview-window like fzf, only toggle
+                "alt-p:toggle-preview".to_string(),
+                // Preview scrolling (half-page based on terminal height)
+                format!("ctrl-u:preview-up({half_page})"),
+                format!("ctrl-d:preview-down({half_page})"),
+            ]
+            .into_iter()
+            // Action bindings (switch, create, remove, ...) from `[keys]` config
+            .chain(keys.bind_specs())
+            .collect(),
+        )
         // Legend/controls moved to preview window tabs (render_preview_tabs)
         .build()
         .map_err(|e| anyhow::anyhow!("Failed to build skim options: {}", e))?;
@@ -297,138 +471,124 @@ pub fn handle_select(
         }
     }
 
-    // Run skim
-    let output = Skim::run_with(&options, Some(rx));
-
-    // Handle selection
-    if let Some(out) = output
-        && !out.is_abort
-    {
-        // Determine action: create (alt-c), remove (alt-r), or switch (enter)
-        let action = match &out.final_event {
-            Event::EvActAccept(Some(label)) if label == "create" => PickerAction::Create,
-            Event::EvActAccept(Some(label)) if label == "remove" => PickerAction::Remove,
-            _ => PickerAction::Switch,
-        };
-
-        // --no-cd: just output the selected branch name and exit (read-only, no side effects)
-        if !change_dir {
-            let selected_name = out
-                .selected_items
-                .first()
-                .map(|item| item.output().to_string());
-            let query = out.query.trim().to_string();
-            let identifier = resolve_print_identifier(&action, query, selected_name)?;
-            println!("{identifier}");
-            return Ok(());
-        }
-
-        match action {
-            PickerAction::Remove => {
-                // Get the selected worktree's branch name
-                let selected = out
-                    .selected_items
-                    .first()
-                    .expect("skim accept has selection");
-                let branch_name = selected.output().to_string();
-
-                let config = repo.user_config();
-
-                // Safe removal: no force-delete (-D), no force-worktree (-f)
-                let result = handle_remove(
-                    &branch_name,
-                    false, // keep_branch: delete branch (default behavior)
-                    false, // force_delete: no -D
-                    false, // force_worktree: no -f
-                    config,
-                )
-                .context("Failed to remove worktree")?;
-
-                // Execute removal in foreground, no hooks, not quiet
-                handle_remove_output(&result, true, false, false)?;
-            }
-            PickerAction::Create | PickerAction::Switch => {
-                let should_create = matches!(action, PickerAction::Create);
-
-                // Get branch name: from query if creating new, from selected item if switching
-                let identifier = if should_create {
-                    let query = out.query.trim().to_string();
-                    if query.is_empty() {
-                        anyhow::bail!("Cannot create worktree: no branch name entered");
-                    }
-                    query
-                } else {
-                    // Enter pressed: skim accept always includes a selection (abort handled above)
-                    let selected = out
-                        .selected_items
-                        .first()
-                        .expect("skim accept has selection");
-                    selected.output().to_string()
-                };
-
-                // Load config — reuse recovered repo if we recovered earlier
-                let repo = if is_recovered {
-                    repo.clone()
-                } else {
-                    Repository::current().context("Failed to switch worktree")?
-                };
-                let config = repo.user_config();
+    // Run skim; the guard leaves raw mode and shows the cursor if it panics
+    let _terminal_guard = TerminalGuard::new();
+    Ok(Skim::run_with(&options, Some(rx)))
+}
 
-                // Run pre-switch hooks before anything else (before branch validation, planning, etc.)
-                // Skip when recovered — the source worktree is gone, nothing to run hooks against.
-                if !is_recovered {
-                    run_pre_switch_hooks(&repo, config, true)?;
-                }
+/// Worktree path for the branch highlighted in the picker.
+fn selected_worktree_path(repo: &Repository, branch: &str) -> anyhow::Result<PathBuf> {
+    repo.worktree_for_branch(branch)?
+        .with_context(|| cformat!("Branch <bold>{branch}</> has no worktree"))
+}
 
-                // Switch to existing worktree or create new one
-                let plan = plan_switch(&repo, &identifier, should_create, None, false, config)?;
-                let hooks_approved = approve_switch_hooks(&repo, config, &plan, false, true)?;
-                let (result, branch_info) =
-                    execute_switch(&repo, plan, config, false, hooks_approved)?;
+/// Open a worktree in `$VISUAL` or `$EDITOR`.
+fn open_in_editor(path: &Path) -> anyhow::Result<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.trim().is_empty()))
+        .context("Cannot open worktree: neither $VISUAL nor $EDITOR is set")?;
+    let path_str = path.to_string_lossy();
+    let escaped = shell_escape::escape(path_str.as_ref().into());
+    Cmd::shell(format!("{editor} {escaped}"))
+        .current_dir(path)
+        .stdin(Stdio::inherit())
+        .stream()
+}
 
-                // Compute path mismatch lazily (deferred from plan_switch for existing worktrees)
-                let branch_info = match &result {
-                    SwitchResult::Existing { path } | SwitchResult::AlreadyAt(path) => {
-                        let expected_path =
-                            get_path_mismatch(&repo, &branch_info.branch, path, config);
-                        SwitchBranchInfo {
-                            expected_path,
-                            ..branch_info
-                        }
-                    }
-                    _ => branch_info,
-                };
+/// Copy a worktree path to the system clipboard.
+///
+/// Uses the first available clipboard command, so no clipboard library is needed.
+fn copy_to_clipboard(path: &Path) -> anyhow::Result<()> {
+    const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+        ("pbcopy", &[]),
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+        ("clip.exe", &[]),
+    ];
+    let (program, args) = CLIPBOARD_COMMANDS
+        .iter()
+        .find(|(program, _)| which::which(program).is_ok())
+        .context("No clipboard command found (pbcopy, wl-copy, xclip, xsel, or clip.exe)")?;
+    let output = Cmd::new(*program)
+        .args(args.iter().copied())
+        .stdin_bytes(path.to_string_lossy().as_bytes())
+        .run()
+        .with_context(|| format!("Failed to run {program}"))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "{program} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Copied <bold>{}</> to clipboard",
+            format_path_for_display(path)
+        ))
+    );
+    Ok(())
+}
 
-                // Show success message; emit cd directive if shell integration is active
-                // When recovered from a deleted worktree, fall back to repo_path().
-                let fallback_path = repo.repo_path()?.to_path_buf();
-                let cwd = std::env::current_dir().unwrap_or(fallback_path.clone());
-                let source_root = repo.current_worktree().root().unwrap_or(fallback_path);
-                let hooks_display_path = handle_switch_output(
-                    &result,
-                    &branch_info,
-                    change_dir,
-                    Some(&source_root),
-                    &cwd,
-                )?;
+/// Fetch a branch's upstream so the re-opened picker shows current ahead/behind counts.
+///
+/// When the fetch moves the upstream to a commit that doesn't contain its old
+/// value, someone force-pushed; pulling would conflict, so warn and suggest
+/// the rebase that replays only the local commits.
+fn fetch_upstream(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    let tracking = repo.run_command(&[
+        "for-each-ref",
+        "--format=%(upstream:remotename) %(upstream:remoteref) %(upstream:short)",
+        &format!("refs/heads/{branch}"),
+    ])?;
+    let mut fields = tracking.split_whitespace();
+    let Some((remote, remote_ref, upstream)) = fields
+        .next()
+        .zip(fields.next())
+        .zip(fields.next())
+        .map(|((remote, remote_ref), upstream)| (remote, remote_ref, upstream))
+    else {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Branch <bold>{branch}</> has no upstream to fetch"
+            ))
+        );
+        return Ok(());
+    };
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Fetching <bold>{branch}</> from {remote}..."))
+    );
+    let upstream_head = || {
+        repo.run_command(&["rev-parse", "--verify", "-q", &format!("{branch}@{{u}}")])
+            .ok()
+    };
+    let before = upstream_head();
+    repo.run_network_command(&["fetch", "--", remote, remote_ref])
+        .with_context(|| cformat!("Failed to fetch branch <bold>{branch}</> from {remote}"))?;
 
-                // Spawn background hooks after success message
-                if hooks_approved {
-                    let extra_vars = switch_extra_vars(&result);
-                    spawn_switch_background_hooks(
-                        &repo,
-                        config,
-                        &result,
-                        &branch_info.branch,
-                        false,
-                        &extra_vars,
-                        hooks_display_path.as_deref(),
-                    )?;
-                }
-            }
-        }
+    // Only a fetch that moved the ref leaves a reflog entry worth checking
+    if before.is_none() || upstream_head() == before {
+        return Ok(());
+    }
+    if let Some(previous) = repo.branch(branch).upstream_rewritten()? {
+        let previous = &previous[..8.min(previous.len())];
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "<bold>{upstream}</> was force-pushed; pulling into <bold>{branch}</> would conflict"
+            ))
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To move your commits onto it, run <underline>wt switch {branch}</>, then <underline>git rebase --onto {upstream} {previous}</>"
+            ))
+        );
     }
-
     Ok(())
 }
 
@@ -447,10 +607,16 @@ fn resolve_print_identifier(
             }
             Ok(query)
         }
-        PickerAction::Switch => selected_name.context("skim accept has no selection"),
+        // Open and copy-path run in the picker loop and never reach here
+        PickerAction::Switch | PickerAction::Open | PickerAction::CopyPath => {
+            selected_name.context("skim accept has no selection")
+        }
         PickerAction::Remove => {
             anyhow::bail!("--no-cd is read-only and cannot be combined with remove (alt-r)")
         }
+        PickerAction::Fetch => {
+            anyhow::bail!("--no-cd is read-only and cannot be combined with fetch (alt-f)")
+        }
     }
 }
 
@@ -0,0 +1,257 @@
+//! Aggregate numbers across worktrees (`wt stats`).
+//!
+//! Built from the same survey as `wt list`, so every number matches what the
+//! table shows. Age buckets are fixed rather than scaled to the data, so
+//! reports taken months apart can be compared directly.
+
+use std::collections::{BTreeMap, HashSet};
+
+use color_print::cformat;
+use serde::Serialize;
+use worktrunk::git::Repository;
+use worktrunk::styling::println;
+
+use crate::cli::OutputFormat;
+use crate::commands::list::collect::{self, TaskKind};
+use crate::commands::list::model::ListItem;
+
+const DAY: i64 = 24 * 60 * 60;
+const WEEK: i64 = 7 * DAY;
+const MONTH: i64 = 30 * DAY;
+const QUARTER: i64 = 90 * DAY;
+
+/// Time since a worktree's last commit, in fixed buckets.
+///
+/// Upper bounds are exclusive: a commit exactly 7 days old is in `under_month`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub(super) struct AgeBuckets {
+    /// Less than 1 day
+    pub(super) under_day: usize,
+    /// 1 to 7 days
+    pub(super) under_week: usize,
+    /// 7 to 30 days
+    pub(super) under_month: usize,
+    /// 30 to 90 days
+    pub(super) under_quarter: usize,
+    /// 90 days or more
+    pub(super) older: usize,
+}
+
+impl AgeBuckets {
+    fn add(&mut self, age_secs: i64) {
+        let bucket = match age_secs {
+            ..DAY => &mut self.under_day,
+            DAY..WEEK => &mut self.under_week,
+            WEEK..MONTH => &mut self.under_month,
+            MONTH..QUARTER => &mut self.under_quarter,
+            _ => &mut self.older,
+        };
+        *bucket += 1;
+    }
+}
+
+/// Uncommitted changes to tracked files, summed across worktrees.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub(super) struct Uncommitted {
+    /// Worktrees with any added or deleted lines
+    pub(super) worktrees: usize,
+    pub(super) added: usize,
+    pub(super) deleted: usize,
+}
+
+/// Commits the default branch has that worktrees lack.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub(super) struct Behind {
+    /// Worktrees behind by at least one commit
+    pub(super) worktrees: usize,
+    /// Sum across those worktrees
+    pub(super) commits: usize,
+    /// Furthest any one worktree is behind
+    pub(super) max: usize,
+}
+
+/// JSON output for `wt stats --format=json`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub(super) struct Stats {
+    pub(super) worktrees: usize,
+    pub(super) age: AgeBuckets,
+    pub(super) uncommitted: Uncommitted,
+    pub(super) behind: Behind,
+    /// Worktrees per `main_state`, as in `wt list --format=json`; worktrees
+    /// with no state are left out
+    pub(super) states: BTreeMap<&'static str, usize>,
+}
+
+impl Stats {
+    /// Aggregate the worktree rows of a survey, measuring ages from `now`.
+    fn from_items(items: &[ListItem], now: i64) -> Self {
+        let mut stats = Self::default();
+        for item in items {
+            let Some(data) = item.worktree_data() else {
+                continue;
+            };
+            stats.worktrees += 1;
+
+            if let Some(commit) = &item.commit {
+                stats.age.add(now - commit.timestamp);
+            }
+
+            if let Some(diff) = data.working_tree_diff
+                && diff.added + diff.deleted > 0
+            {
+                stats.uncommitted.worktrees += 1;
+                stats.uncommitted.added += diff.added;
+                stats.uncommitted.deleted += diff.deleted;
+            }
+
+            if let Some(counts) = item.counts
+                && counts.behind > 0
+            {
+                stats.behind.worktrees += 1;
+                stats.behind.commits += counts.behind;
+                stats.behind.max = stats.behind.max.max(counts.behind);
+            }
+
+            if let Some(state) = item
+                .status_symbols
+                .as_ref()
+                .and_then(|symbols| symbols.main_state.as_json_str())
+            {
+                *stats.states.entry(state).or_default() += 1;
+            }
+        }
+        stats
+    }
+
+    /// The human summary: one labeled line per measure.
+    fn render(&self, default_branch: &str) -> Vec<String> {
+        let AgeBuckets {
+            under_day,
+            under_week,
+            under_month,
+            under_quarter,
+            older,
+        } = self.age;
+        let worktrees = |n: usize| {
+            let s = if n == 1 { "" } else { "s" };
+            format!("{n} worktree{s}")
+        };
+        let states = if self.states.is_empty() {
+            "none".to_string()
+        } else {
+            self.states
+                .iter()
+                .map(|(state, count)| format!("{state} {count}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let rows = [
+            ("Worktrees", self.worktrees.to_string()),
+            (
+                "Age",
+                format!(
+                    "{under_day} under a day, {under_week} under a week, {under_month} under a month, {under_quarter} under 3 months, {older} older"
+                ),
+            ),
+            (
+                "Uncommitted",
+                format!(
+                    "+{} -{} in {}",
+                    self.uncommitted.added,
+                    self.uncommitted.deleted,
+                    worktrees(self.uncommitted.worktrees)
+                ),
+            ),
+            (
+                "Behind",
+                format!(
+                    "{} behind {default_branch}, {} commits in total, {} at most",
+                    worktrees(self.behind.worktrees),
+                    self.behind.commits,
+                    self.behind.max
+                ),
+            ),
+            ("States", states),
+        ];
+        let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+        rows.iter()
+            .map(|(label, value)| cformat!("<bold>{label:width$}</>  {value}"))
+            .collect()
+    }
+}
+
+/// Handle `wt stats`.
+pub fn handle_stats(format: OutputFormat) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+
+    // What `wt list` computes without --full, minus anything that doesn't feed
+    // a number here. No command timeout: a slow row should count, not vanish.
+    let skip_tasks: HashSet<TaskKind> = [
+        TaskKind::BranchDiff,
+        TaskKind::UnmergedCommits,
+        TaskKind::CiStatus,
+        TaskKind::WorkingTreeConflicts,
+        TaskKind::SummaryGenerate,
+        TaskKind::UrlStatus,
+        TaskKind::Activity,
+    ]
+    .into_iter()
+    .collect();
+    let items = collect::collect(
+        &repo,
+        collect::ShowConfig::Resolved {
+            show_branches: false,
+            show_remotes: false,
+            skip_tasks,
+            command_timeout: None,
+        },
+        false, // show_progress
+        false, // render_table
+        false, // skip_expensive_for_stale
+    )?
+    .map(|data| data.items)
+    .unwrap_or_default();
+
+    let stats = Stats::from_items(&items, worktrunk::utils::get_now() as i64);
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+        }
+        OutputFormat::Table | OutputFormat::ClaudeCode => {
+            let default_branch = repo
+                .default_branch()
+                .unwrap_or_else(|| "the default branch".to_string());
+            for line in stats.render(&default_branch) {
+                println!("{line}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_age_bucket_boundaries() {
+        let mut age = AgeBuckets::default();
+        for secs in [0, DAY - 1, DAY, WEEK, MONTH - 1, MONTH, QUARTER] {
+            age.add(secs);
+        }
+        // A commit dated in the future (clock skew) counts as fresh
+        age.add(-DAY);
+        assert_eq!(
+            age,
+            AgeBuckets {
+                under_day: 3,
+                under_week: 1,
+                under_month: 2,
+                under_quarter: 1,
+                older: 1,
+            }
+        );
+    }
+}
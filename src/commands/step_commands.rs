@@ -13,7 +13,7 @@
 //! - `step_prune` - Remove worktrees merged into the default branch
 
 use std::fs;
-use std::io::ErrorKind;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -22,6 +22,7 @@ use color_print::cformat;
 use ignore::gitignore::GitignoreBuilder;
 use worktrunk::HookType;
 use worktrunk::config::UserConfig;
+use worktrunk::fs_ops::{copy_dir_recursive, move_entry, remove_if_exists};
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
@@ -29,13 +30,17 @@ use worktrunk::styling::{
     verbosity, warning_message,
 };
 
+use super::batch::{BatchAction, BatchPolicy, LockedSkips};
 use super::command_approval::approve_hooks;
 use super::commit::{CommitGenerator, CommitOptions, StageMode};
 use super::context::CommandEnv;
 use super::hooks::{HookCommandSpec, HookFailureStrategy, run_hook_with_filter};
+use super::list::ci_status::{CiBranchName, PrStatus};
 use super::repository_ext::{RemoveTarget, RepositoryCliExt};
-use super::worktree::BranchDeletionMode;
+use super::restack::{find_stacked_branches, restack_branches, warn_stacked};
+use super::worktree::{BranchDeletionMode, find_cwd_users};
 use crate::output::handle_remove_output;
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
 use worktrunk::shell_exec::Cmd;
 
 /// Handle `wt step commit` command
@@ -53,7 +58,7 @@ pub fn step_commit(
         let config = UserConfig::load().context("Failed to load config")?;
         let project_id = repo.project_identifier().ok();
         let commit_config = config.commit_generation(project_id.as_deref());
-        let prompt = crate::llm::build_commit_prompt(&commit_config)?;
+        let prompt = crate::llm::build_commit_prompt(&repo.current_worktree(), &commit_config)?;
         println!("{}", prompt);
         return Ok(());
     }
@@ -115,6 +120,7 @@ pub enum SquashResult {
 /// * `verify` - If true, run pre-commit hooks (false when --no-verify flag is passed)
 /// * `stage` - CLI-provided stage mode. If None, uses the effective config default.
 pub fn handle_squash(
+    repo: &Repository,
     target: Option<&str>,
     yes: bool,
     verify: bool,
@@ -125,7 +131,7 @@ pub fn handle_squash(
     // One-time LLM setup prompt (errors logged internally; don't block commit)
     let _ = crate::output::prompt_commit_generation(&mut config);
 
-    let env = CommandEnv::for_action("squash", config)?;
+    let env = CommandEnv::for_action_in(repo.clone(), "squash", config)?;
     let repo = &env.repo;
     // Squash requires being on a branch (can't squash in detached HEAD)
     let current_branch = env.require_branch("squash")?.to_string();
@@ -303,6 +309,7 @@ pub fn handle_squash(
         .unwrap_or("repo");
 
     let commit_message = crate::llm::generate_squash_message(
+        &wt,
         &integration_target,
         &merge_base,
         &subjects,
@@ -388,6 +395,7 @@ pub fn step_show_squash_prompt(target: Option<&str>) -> anyhow::Result<()> {
         .unwrap_or("repo");
 
     let prompt = crate::llm::build_squash_prompt(
+        &wt,
         &integration_target,
         &merge_base,
         &subjects,
@@ -408,9 +416,7 @@ pub enum RebaseResult {
 }
 
 /// Handle shared rebase workflow (used by `wt step rebase` and `wt merge`)
-pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
-    let repo = Repository::current()?;
-
+pub fn handle_rebase(repo: &Repository, target: Option<&str>) -> anyhow::Result<RebaseResult> {
     // Get and validate target ref (any commit-ish for rebase)
     let integration_target = repo.require_target_ref(target)?;
 
@@ -762,14 +768,6 @@ pub fn step_copy_ignored(
     Ok(())
 }
 
-/// Remove a file, ignoring "not found" errors.
-fn remove_if_exists(path: &Path) -> anyhow::Result<()> {
-    if let Err(e) = fs::remove_file(path) {
-        anyhow::ensure!(e.kind() == ErrorKind::NotFound, e);
-    }
-    Ok(())
-}
-
 /// List ignored entries using git ls-files
 ///
 /// Uses `git ls-files --ignored --exclude-standard -o --directory` which:
@@ -810,115 +808,6 @@ fn list_ignored_entries(
     Ok(entries)
 }
 
-/// Copy a directory recursively using reflink (COW).
-///
-/// Uses file-by-file copying with per-file reflink on all platforms. This spreads
-/// I/O operations over time rather than issuing them in a single burst.
-///
-/// ## Why not use atomic directory cloning on macOS?
-///
-/// macOS/APFS supports `clonefile()` on directories, which clones an entire tree
-/// atomically. However, Apple explicitly discourages this in the man page:
-///
-/// > "Cloning directories with these functions is strongly discouraged.
-/// > Use copyfile(3) to clone directories instead."
-/// > — clonefile(2) man page
-///
-/// In practice, atomic `clonefile()` on a Rust `target/` directory (~236K files)
-/// saturates disk I/O at ~45K ops/sec, blocking interactive processes like shell
-/// startup for several seconds. The per-file approach spreads operations over
-/// time, keeping the system responsive even though total copy time is longer.
-///
-/// Apple recommends `copyfile()` with `COPYFILE_CLONE` for directories, which
-/// internally walks the tree and clones per-file — equivalent to what we do here.
-fn copy_dir_recursive(src: &Path, dest: &Path, force: bool) -> anyhow::Result<()> {
-    copy_dir_recursive_fallback(src, dest, force)
-}
-
-/// File-by-file recursive copy with reflink per file.
-///
-/// Used as fallback when atomic directory clone isn't available or fails.
-fn copy_dir_recursive_fallback(src: &Path, dest: &Path, force: bool) -> anyhow::Result<()> {
-    fs::create_dir_all(dest).with_context(|| format!("creating directory {}", dest.display()))?;
-
-    for entry in fs::read_dir(src)? {
-        let entry = entry?;
-        let file_type = entry.file_type()?;
-        let src_path = entry.path();
-        let dest_path = dest.join(entry.file_name());
-
-        if file_type.is_symlink() {
-            // Copy symlink (preserves the link, doesn't follow it)
-            if force {
-                remove_if_exists(&dest_path)?;
-            }
-            // Use symlink_metadata to detect broken symlinks (exists() follows symlinks
-            // and returns false for broken ones, causing EEXIST on the next symlink call)
-            if dest_path.symlink_metadata().is_err() {
-                let target = fs::read_link(&src_path)
-                    .with_context(|| format!("reading symlink {}", src_path.display()))?;
-                create_symlink(&target, &src_path, &dest_path)?;
-            }
-        } else if file_type.is_dir() {
-            copy_dir_recursive_fallback(&src_path, &dest_path, force)?;
-        } else if !file_type.is_file() {
-            // Skip non-regular files (sockets, FIFOs, etc.)
-            log::debug!("skipping non-regular file: {}", src_path.display());
-        } else {
-            if force {
-                remove_if_exists(&dest_path)?;
-            }
-            // Skip existing files for idempotent hook usage
-            match reflink_copy::reflink_or_copy(&src_path, &dest_path) {
-                Ok(_) => {}
-                Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
-                Err(e) => {
-                    return Err(
-                        anyhow::Error::from(e).context(format!("copying {}", src_path.display()))
-                    );
-                }
-            }
-        }
-    }
-
-    Ok(())
-}
-
-/// Move a file or directory, falling back to copy+delete on cross-device errors.
-fn move_entry(src: &Path, dest: &Path, is_dir: bool) -> anyhow::Result<()> {
-    // Ensure parent directory exists
-    if let Some(parent) = dest.parent() {
-        fs::create_dir_all(parent)
-            .context(format!("creating parent directory for {}", dest.display()))?;
-    }
-
-    match fs::rename(src, dest) {
-        Ok(()) => Ok(()),
-        Err(e) if e.kind() == ErrorKind::CrossesDevices => copy_and_remove(src, dest, is_dir),
-        Err(e) => Err(anyhow::Error::from(e).context(format!(
-            "moving {} to {}",
-            src.display(),
-            dest.display()
-        ))),
-    }
-}
-
-/// Copy then delete — fallback when `rename` fails with EXDEV (cross-device).
-fn copy_and_remove(src: &Path, dest: &Path, is_dir: bool) -> anyhow::Result<()> {
-    if is_dir {
-        copy_dir_recursive(src, dest, true)?;
-        fs::remove_dir_all(src).context(format!("removing source directory {}", src.display()))?;
-    } else {
-        reflink_copy::reflink_or_copy(src, dest).context(format!(
-            "copying {} to {}",
-            src.display(),
-            dest.display()
-        ))?;
-        fs::remove_file(src).context(format!("removing source file {}", src.display()))?;
-    }
-    Ok(())
-}
-
 const PROMOTE_STAGING_DIR: &str = "wt-promote-staging";
 
 /// Move gitignored files from both worktrees into a staging directory.
@@ -1258,45 +1147,31 @@ pub fn handle_promote(branch: Option<&str>) -> anyhow::Result<PromoteResult> {
     Ok(PromoteResult::Promoted)
 }
 
-/// Create a symlink, handling platform differences.
-///
-/// On Windows, distinguishes between file and directory symlinks by checking the
-/// source path's metadata (the target may be relative or broken, so we use the
-/// source to determine the type).
-fn create_symlink(target: &Path, src_path: &Path, dest_path: &Path) -> anyhow::Result<()> {
-    #[cfg(unix)]
-    {
-        let _ = src_path; // Used on Windows to determine symlink type
-        std::os::unix::fs::symlink(target, dest_path)
-            .with_context(|| format!("creating symlink {}", dest_path.display()))?;
-    }
-    #[cfg(windows)]
-    {
-        let is_dir = src_path.metadata().map(|m| m.is_dir()).unwrap_or(false);
-        if is_dir {
-            std::os::windows::fs::symlink_dir(target, dest_path)
-                .with_context(|| format!("creating symlink {}", dest_path.display()))?;
-        } else {
-            std::os::windows::fs::symlink_file(target, dest_path)
-                .with_context(|| format!("creating symlink {}", dest_path.display()))?;
-        }
-    }
-    #[cfg(not(any(unix, windows)))]
-    {
-        let _ = (target, src_path, dest_path);
-        anyhow::bail!("symlink creation not supported on this platform");
-    }
-    Ok(())
-}
-
 /// Remove worktrees and branches integrated into the default branch.
 ///
-/// Handles four cases: live worktrees with branches (removed + branch deleted),
-/// detached HEAD worktrees (directory removed, no branch to delete), stale worktree
-/// entries (pruned + branch deleted), and orphan branches without worktrees (deleted).
-/// Skips the main/primary worktree, locked worktrees, and worktrees younger than
-/// `min_age`. Removes the current worktree last to trigger cd to primary.
-pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) -> anyhow::Result<()> {
+/// Handles five cases: live worktrees with branches (removed + branch deleted),
+/// detached HEAD worktrees (directory removed, no branch to delete), worktrees
+/// whose branch was deleted under them (directory removed unless that would lose
+/// their last commit), stale worktree entries (pruned + branch deleted), and
+/// orphan branches without worktrees (deleted).
+/// Skips the main/primary worktree, locked worktrees (see [`BatchPolicy`]),
+/// worktrees younger than `min_age`, and branches hidden by `wt hide` (unless
+/// `include_hidden`).
+/// Removes the current worktree last to trigger cd to primary.
+///
+/// With `squash_aware`, branches that git doesn't see as integrated still count
+/// when the target already has their patches (see
+/// [`Repository::is_squash_merged`]) or their GitHub PR merged at the local head
+/// (see [`pr_merged_at`]).
+pub fn step_prune(
+    dry_run: bool,
+    yes: bool,
+    min_age: &str,
+    squash_aware: bool,
+    foreground: bool,
+    restack: bool,
+    include_hidden: bool,
+) -> anyhow::Result<()> {
     let min_age_duration =
         humantime::parse_duration(min_age).context("Invalid --min-age duration")?;
 
@@ -1316,6 +1191,11 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
     let now_secs = worktrunk::utils::get_now();
 
     let default_branch = repo.default_branch();
+    let hidden_branches = if include_hidden {
+        std::collections::HashSet::new()
+    } else {
+        repo.hidden_branches()
+    };
 
     // Gather candidates: integrated worktrees + integrated branch-only refs
     struct Candidate {
@@ -1327,6 +1207,9 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
         path: Option<PathBuf>,
         /// Current worktree, other worktree, or branch-only (no worktree)
         kind: CandidateKind,
+        /// Integrated only per squash-merge detection (matching patches or a
+        /// merged PR), so git's merged check would refuse to delete the branch
+        squash_merged: bool,
     }
     enum CandidateKind {
         Current,
@@ -1401,10 +1284,25 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
         approved
     };
 
+    // Heads before removal, to find branches stacked on the pruned ones
+    let branch_heads: std::collections::HashMap<String, String> = if dry_run {
+        std::collections::HashMap::new()
+    } else {
+        repo.list_local_branches()?.into_iter().collect()
+    };
+    // Removing the current worktree invalidates `repo` for later git commands
+    let home_path = repo.home_path()?;
+
     let mut candidates: Vec<Candidate> = Vec::new(); // dry-run collects here
     let mut removed: Vec<Candidate> = Vec::new(); // non-dry-run tracks removals
     let mut deferred_current: Option<Candidate> = None; // current worktree removed last
     let mut skipped_young: Vec<String> = Vec::new();
+    let mut skipped_hidden: Vec<String> = Vec::new();
+    let mut skipped_unreferenced: Vec<String> = Vec::new();
+    let mut skipped_squash: Vec<String> = Vec::new();
+    let mut skipped_in_use: Vec<String> = Vec::new();
+    let lock_policy = BatchPolicy::new(BatchAction::Modify, false);
+    let mut skipped_locked = LockedSkips::default();
     // Track branches seen via worktree entries so we don't double-count.
     // Pre-seed with the default branch to prevent it from being pruned
     // (it's trivially "integrated" into itself).
@@ -1438,12 +1336,12 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
                 ),
             },
         };
-        let plan = match repo.prepare_worktree_removal(
-            target,
-            BranchDeletionMode::SafeDelete,
-            false,
-            config,
-        ) {
+        let deletion_mode = if candidate.squash_merged {
+            BranchDeletionMode::ForceDelete
+        } else {
+            BranchDeletionMode::SafeDelete
+        };
+        let plan = match repo.prepare_worktree_removal(target, deletion_mode, false, config) {
             Ok(plan) => plan,
             Err(_) => {
                 // prepare_worktree_removal is the gate: if the worktree can't
@@ -1455,17 +1353,45 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
         Ok(true)
     }
 
+    /// Ask before force-deleting a branch git doesn't see as merged.
+    ///
+    /// Only squash-merge detection vouches for these, so each needs a yes
+    /// (or `--yes`); without a terminal to ask, they're skipped.
+    fn confirm_squash_delete(
+        candidate: &Candidate,
+        repo: &Repository,
+        target: &str,
+        yes: bool,
+    ) -> anyhow::Result<bool> {
+        if !candidate.squash_merged || yes {
+            return Ok(true);
+        }
+        let Some(branch) = &candidate.branch else {
+            return Ok(true);
+        };
+        if !std::io::stdin().is_terminal() {
+            return Ok(false);
+        }
+        let response = prompt_yes_no_preview(
+            &cformat!(
+                "Force-delete <bold>{branch}</>? Its commits aren't in {target}, only their changes"
+            ),
+            || {
+                let range = format!("{target}..refs/heads/{branch}");
+                if let Ok(log) = repo.run_command(&["log", "--oneline", "--no-decorate", &range]) {
+                    eprintln!("{}", format_with_gutter(log.trim_end(), None));
+                }
+            },
+        )?;
+        Ok(response == PromptResponse::Accepted)
+    }
+
     for wt in &worktrees {
         // Track branches so the orphan scan doesn't re-discover them
         if let Some(branch) = &wt.branch {
             seen_branches.insert(branch.clone());
         }
 
-        // Skip locked worktrees
-        if wt.locked.is_some() {
-            continue;
-        }
-
         // Never prune the default branch
         if let Some(branch) = &wt.branch
             && default_branch.as_deref() == Some(branch.as_str())
@@ -1479,11 +1405,20 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
                 let (effective_target, reason) =
                     repo.integration_reason(branch, &integration_target)?;
                 if let Some(reason) = reason {
+                    if let Some(lock_reason) = lock_policy.locked_skip(wt) {
+                        skipped_locked.push(branch.clone(), lock_reason);
+                        continue;
+                    }
+                    if hidden_branches.contains(branch) {
+                        skipped_hidden.push(branch.clone());
+                        continue;
+                    }
                     let candidate = Candidate {
                         label: branch.clone(),
                         branch: Some(branch.clone()),
                         path: None,
                         kind: CandidateKind::BranchOnly,
+                        squash_merged: false,
                     };
                     if dry_run {
                         eprintln!(
@@ -1511,27 +1446,65 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
             continue;
         }
 
-        // For integration check: use branch name, or commit SHA for detached
-        let integration_ref = match &wt.branch {
-            Some(b) if !wt.detached => b.as_str(),
-            _ => &wt.head,
-        };
-
-        // Check integration first — only apply age guard to integrated worktrees
-        let (effective_target, reason) =
-            repo.integration_reason(integration_ref, &integration_target)?;
-        let Some(reason) = reason else {
-            continue;
-        };
-
         let label = wt
             .branch
             .clone()
             .unwrap_or_else(|| format!("(detached {})", &wt.head[..7.min(wt.head.len())]));
 
+        // A worktree whose branch was deleted out from under it has nothing to
+        // integrate. It's removable unless it holds the only reference to its
+        // last commit. Unborn branches have no commits to check and are kept.
+        let branch_deleted = !wt.has_commits();
+        let (description, squash_merged) = if branch_deleted {
+            match repo.deleted_branch_commit(wt) {
+                Some(commit) if repo.is_commit_unreferenced(&commit)? => {
+                    skipped_unreferenced.push(label);
+                    continue;
+                }
+                Some(_) => ("branch deleted".to_string(), false),
+                None => continue,
+            }
+        } else {
+            // For integration check: use branch name, or commit SHA for detached
+            let integration_ref = match &wt.branch {
+                Some(b) if !wt.detached => b.as_str(),
+                _ => &wt.head,
+            };
+
+            // Check integration first — only apply age guard to integrated worktrees
+            let (effective_target, reason) =
+                repo.integration_reason(integration_ref, &integration_target)?;
+            let squash_description = match wt.branch.as_deref() {
+                Some(branch) if reason.is_none() && squash_aware && !wt.detached => {
+                    squash_merge_description(&repo, branch, &wt.head, &effective_target)
+                }
+                _ => None,
+            };
+            let squash_merged = squash_description.is_some();
+            let description = match (reason, squash_description) {
+                (Some(reason), _) => format!("{} {effective_target}", reason.description()),
+                (None, Some(description)) => description,
+                (None, None) => continue,
+            };
+            (description, squash_merged)
+        };
+
+        // Locked worktrees stay put until unlocked, even when integrated
+        if let Some(lock_reason) = lock_policy.locked_skip(wt) {
+            skipped_locked.push(label, lock_reason);
+            continue;
+        }
+
+        if let Some(branch) = &wt.branch
+            && hidden_branches.contains(branch)
+        {
+            skipped_hidden.push(label);
+            continue;
+        }
+
         // Check age: skip recently-created worktrees that look "merged" because
         // they were just created from the default branch
-        if min_age_duration > Duration::ZERO {
+        if min_age_duration > Duration::ZERO && !branch_deleted {
             let git_dir = wt_tree.git_dir()?;
             let metadata = fs::metadata(&git_dir).context("Failed to read worktree git dir")?;
             let created = metadata.created().or_else(|_| {
@@ -1552,7 +1525,12 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
         let wt_path = dunce::canonicalize(&wt.path).unwrap_or(wt.path.clone());
         let is_current = wt_path == current_root;
         let candidate = Candidate {
-            branch: if wt.detached { None } else { wt.branch.clone() },
+            // No branch left to delete when it was deleted under the worktree
+            branch: if wt.detached || branch_deleted {
+                None
+            } else {
+                wt.branch.clone()
+            },
             label,
             path: Some(wt.path.clone()),
             kind: if is_current {
@@ -1560,18 +1538,19 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
             } else {
                 CandidateKind::Other
             },
+            squash_merged,
         };
         if dry_run {
             eprintln!(
                 "{}",
-                info_message(cformat!(
-                    "<bold>{}</> — {} {}",
-                    candidate.label,
-                    reason.description(),
-                    effective_target
-                ))
+                info_message(cformat!("<bold>{}</> — {description}", candidate.label))
             );
             candidates.push(candidate);
+        } else if !confirm_squash_delete(&candidate, &repo, &integration_target, yes)? {
+            skipped_squash.push(candidate.label);
+        } else if config.check_cwd_users && !find_cwd_users(&wt.path).is_empty() {
+            // Nobody to ask mid-batch; other processes keep their directory
+            skipped_in_use.push(candidate.label);
         } else if is_current {
             deferred_current = Some(candidate);
         } else if try_remove(&candidate, &repo, &config, foreground, run_hooks)? {
@@ -1585,7 +1564,25 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
             continue;
         }
         let (effective_target, reason) = repo.integration_reason(&branch, &integration_target)?;
-        if let Some(reason) = reason {
+        let squash_description = if reason.is_none() && squash_aware {
+            repo.run_command(&["rev-parse", &format!("refs/heads/{branch}")])
+                .ok()
+                .and_then(|head| {
+                    squash_merge_description(&repo, &branch, head.trim(), &effective_target)
+                })
+        } else {
+            None
+        };
+        let squash_merged = squash_description.is_some();
+        let description = match reason {
+            Some(reason) => Some(format!("{} {effective_target}", reason.description())),
+            None => squash_description,
+        };
+        if let Some(description) = description {
+            if hidden_branches.contains(&branch) {
+                skipped_hidden.push(branch);
+                continue;
+            }
             // Apply min-age guard: check reflog creation timestamp
             if min_age_duration > Duration::ZERO {
                 let ref_name = format!("refs/heads/{branch}");
@@ -1611,18 +1608,19 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
                 branch: Some(branch),
                 path: None,
                 kind: CandidateKind::BranchOnly,
+                squash_merged,
             };
             if dry_run {
                 eprintln!(
                     "{}",
                     info_message(cformat!(
-                        "<bold>{}</> (branch only) — {} {}",
-                        candidate.label,
-                        reason.description(),
-                        effective_target
+                        "<bold>{}</> (branch only) — {description}",
+                        candidate.label
                     ))
                 );
                 candidates.push(candidate);
+            } else if !confirm_squash_delete(&candidate, &repo, &integration_target, yes)? {
+                skipped_squash.push(candidate.label);
             } else if try_remove(&candidate, &repo, &config, foreground, run_hooks)? {
                 removed.push(candidate);
             }
@@ -1637,10 +1635,55 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
             info_message(format!("Skipped {names} (younger than {min_age})"))
         );
     }
+    if !skipped_hidden.is_empty() {
+        let names = skipped_hidden.join(", ");
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Skipped {names} (hidden; to include, run with <bold>--include-hidden</>)"
+            ))
+        );
+    }
+    if !skipped_unreferenced.is_empty() {
+        let names = skipped_unreferenced.join(", ");
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Skipped {names} (branch deleted, last commit on no other branch; to remove, run <bold>wt remove -D</>)"
+            ))
+        );
+    }
+    if !skipped_squash.is_empty() {
+        let names = skipped_squash.join(", ");
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Skipped {names} (squash-merged, not merged per git; to remove, confirm or run with <bold>--yes</>)"
+            ))
+        );
+    }
+    if !skipped_in_use.is_empty() {
+        let names = skipped_in_use.join(", ");
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Skipped {names} (in use by other processes; to remove, run <bold>wt remove</>)"
+            ))
+        );
+    }
+    if let Some(message) = skipped_locked.message(&lock_policy) {
+        eprintln!("{message}");
+    }
+    let skipped_any = !skipped_young.is_empty()
+        || !skipped_hidden.is_empty()
+        || !skipped_unreferenced.is_empty()
+        || !skipped_squash.is_empty()
+        || !skipped_in_use.is_empty()
+        || !skipped_locked.is_empty();
 
     if dry_run {
         if candidates.is_empty() {
-            if skipped_young.is_empty() {
+            if !skipped_any {
                 eprintln!("{}", info_message("No merged worktrees to remove"));
             }
             return Ok(());
@@ -1663,7 +1706,7 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
     }
 
     if removed.is_empty() {
-        if skipped_young.is_empty() {
+        if !skipped_any {
             eprintln!("{}", info_message("No merged worktrees to remove"));
         }
     } else {
@@ -1673,6 +1716,152 @@ pub fn step_prune(dry_run: bool, yes: bool, min_age: &str, foreground: bool) ->
         );
     }
 
+    let finished: Vec<(String, String)> = removed
+        .iter()
+        .filter_map(|c| {
+            let branch = c.branch.as_ref()?;
+            Some((branch.clone(), branch_heads.get(branch)?.clone()))
+        })
+        .collect();
+    if !finished.is_empty() {
+        let repo = Repository::at(&home_path)?;
+        let stacked = find_stacked_branches(&repo, &finished, &integration_target)?;
+        if restack {
+            restack_branches(&repo, &stacked, &integration_target)?;
+        } else if !stacked.is_empty() {
+            warn_stacked(&stacked, &integration_target);
+        }
+    }
+
+    Ok(())
+}
+
+/// Describe why `branch` counts as squash-merged into `target`, if it does.
+///
+/// Checks the local patches first (no network), then falls back to the forge.
+/// Errors from the local check (including timeouts) just mean "not detected".
+pub(crate) fn squash_merge_description(
+    repo: &Repository,
+    branch: &str,
+    head: &str,
+    target: &str,
+) -> Option<String> {
+    if repo.is_squash_merged(head, target).unwrap_or(false) {
+        Some(format!("squash-merged into {target}"))
+    } else if pr_merged_at(repo, branch, head) {
+        Some("PR merged".to_string())
+    } else {
+        None
+    }
+}
+
+/// Whether `branch` has a merged GitHub PR whose head is `head`.
+///
+/// Squash merges leave no ancestry for `integration_reason` to find, so the
+/// forge is the only witness. A PR head that differs from `head` means local
+/// commits were never pushed, so the branch isn't safe to delete. Without `gh`
+/// (or forge access), this is always false.
+fn pr_merged_at(repo: &Repository, branch: &str, head: &str) -> bool {
+    let ci_branch = CiBranchName::from_branch_ref(branch, false, repo);
+    PrStatus::detect(repo, &ci_branch, head).is_some_and(|pr| pr.merged && !pr.is_stale)
+}
+
+/// Handle `wt step graduate`: put the current detached worktree on a new branch.
+///
+/// Creates `branch` at `HEAD` with `git switch -c` (uncommitted changes carry
+/// over), then moves the worktree to the templated path for `branch` unless
+/// `no_move` is set, it's the main worktree, or the destination already exists.
+/// With `push`, pushes to the primary remote and sets the upstream. Emits a cd
+/// directive when the worktree moved. The name is checked against
+/// `[branch-name-rules]` unless `no_name_rules` is set.
+pub fn step_graduate(
+    branch: &str,
+    no_move: bool,
+    push: bool,
+    fix_name: bool,
+    no_name_rules: bool,
+) -> anyhow::Result<()> {
+    use super::worktree::{check_new_branch_name, check_worktree_root, compute_worktree_path};
+    use worktrunk::git::GitError;
+
+    let repo = Repository::current()?;
+    let config = UserConfig::load()?;
+    let wt = repo.current_worktree();
+
+    if let Some(current) = wt.branch()? {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Worktree is already on branch <bold>{current}</>; to rename it, run <underline>git branch -m {current} {branch}</>"
+            ),
+        }
+        .into());
+    }
+    let checked_branch = if no_name_rules {
+        branch.to_string()
+    } else {
+        check_new_branch_name(&repo, branch, fix_name)?
+    };
+    let branch = checked_branch.as_str();
+    if repo.branch(branch).exists_locally()? {
+        return Err(GitError::BranchAlreadyExists {
+            branch: branch.to_string(),
+        }
+        .into());
+    }
+
+    let root = wt.root()?;
+    wt.run_command(&["switch", "--create", branch])
+        .context("Failed to create branch")?;
+    let short_sha = wt.run_command(&["rev-parse", "--short", "HEAD"])?;
+    let mut summary = vec![format!("created at {}", short_sha.trim())];
+
+    // Push before moving: git commands run from the current directory
+    if push {
+        let remote = repo.primary_remote()?;
+        wt.run_network_command(&["push", "--set-upstream", &remote, branch])
+            .with_context(|| format!("Failed to push {branch} to {remote}"))?;
+        summary.push(cformat!("tracking <bold>{remote}/{branch}</>"));
+    }
+
+    // Move to the templated path if it's free
+    if !no_move && wt.is_linked()? {
+        let dest = compute_worktree_path(&repo, branch, &config)?;
+        let dest_display = format_path_for_display(&dest);
+        if dunce::canonicalize(&dest).is_ok_and(|dest| dest == root) {
+            // Already where the template puts it
+        } else if dest.exists() {
+            eprintln!(
+                "{}",
+                warning_message(format!(
+                    "Not moving worktree; {dest_display} already exists"
+                ))
+            );
+        } else {
+            check_worktree_root(&dest, &config)?;
+            // Resolve the cwd before the move renames it out from under us
+            let cwd = std::env::current_dir()
+                .ok()
+                .map(|cwd| dunce::canonicalize(&cwd).unwrap_or(cwd));
+            repo.move_worktree(&root, &dest)
+                .context("Failed to move worktree")?;
+            summary.push(format!("moved to {dest_display}"));
+
+            // Take the shell along if it's inside the moved worktree
+            if let Some(cwd) = cwd
+                && let Ok(relative) = cwd.strip_prefix(&root)
+            {
+                crate::output::change_directory(dest.join(relative))?;
+            }
+        }
+    }
+
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Graduated to <bold>{branch}</>: {}",
+            summary.join(", ")
+        ))
+    );
     Ok(())
 }
 
@@ -1740,7 +1929,7 @@ pub fn step_relocate(
     // Phase 3 & 4: Create executor (classifies targets) and execute relocations
     let mut executor = RelocationExecutor::new(&repo, validated, clobber)?;
     let cwd = std::env::current_dir().ok();
-    executor.execute(&repo_path, &default_branch, cwd.as_deref())?;
+    executor.execute(&repo, &default_branch, cwd.as_deref())?;
 
     // Show summary
     let total_skipped = skipped + executor.skipped;
@@ -1748,87 +1937,3 @@ pub fn step_relocate(
 
     Ok(())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_remove_if_exists_nonexistent() {
-        // NotFound is silently ignored
-        assert!(remove_if_exists(Path::new("/nonexistent/file")).is_ok());
-    }
-
-    #[test]
-    fn test_remove_if_exists_not_a_file() {
-        // Trying to remove a directory with remove_file produces a non-NotFound error
-        let dir = std::env::temp_dir();
-        assert!(remove_if_exists(&dir).is_err());
-    }
-
-    #[test]
-    fn test_move_entry_file() {
-        let tmp = tempfile::tempdir().unwrap();
-        let src = tmp.path().join("source.txt");
-        let dest = tmp.path().join("subdir/dest.txt");
-
-        fs::write(&src, "content").unwrap();
-        move_entry(&src, &dest, false).unwrap();
-
-        assert!(!src.exists());
-        assert_eq!(fs::read_to_string(&dest).unwrap(), "content");
-    }
-
-    #[test]
-    fn test_move_entry_directory() {
-        let tmp = tempfile::tempdir().unwrap();
-        let src = tmp.path().join("srcdir");
-        let dest = tmp.path().join("nested/destdir");
-
-        fs::create_dir_all(src.join("inner")).unwrap();
-        fs::write(src.join("inner/file.txt"), "nested").unwrap();
-        fs::write(src.join("root.txt"), "root").unwrap();
-
-        move_entry(&src, &dest, true).unwrap();
-
-        assert!(!src.exists());
-        assert_eq!(
-            fs::read_to_string(dest.join("inner/file.txt")).unwrap(),
-            "nested"
-        );
-        assert_eq!(fs::read_to_string(dest.join("root.txt")).unwrap(), "root");
-    }
-
-    #[test]
-    fn test_copy_and_remove_file() {
-        let tmp = tempfile::tempdir().unwrap();
-        let src = tmp.path().join("source.txt");
-        let dest = tmp.path().join("dest.txt");
-
-        fs::write(&src, "content").unwrap();
-        copy_and_remove(&src, &dest, false).unwrap();
-
-        assert!(!src.exists());
-        assert_eq!(fs::read_to_string(&dest).unwrap(), "content");
-    }
-
-    #[test]
-    fn test_copy_and_remove_directory() {
-        let tmp = tempfile::tempdir().unwrap();
-        let src = tmp.path().join("srcdir");
-        let dest = tmp.path().join("destdir");
-
-        fs::create_dir_all(src.join("sub")).unwrap();
-        fs::write(src.join("sub/file.txt"), "nested").unwrap();
-        fs::write(src.join("root.txt"), "root").unwrap();
-
-        copy_and_remove(&src, &dest, true).unwrap();
-
-        assert!(!src.exists());
-        assert_eq!(
-            fs::read_to_string(dest.join("sub/file.txt")).unwrap(),
-            "nested"
-        );
-        assert_eq!(fs::read_to_string(dest.join("root.txt")).unwrap(), "root");
-    }
-}
@@ -0,0 +1,96 @@
+//! Aligned text tables for command listings (`wt branches clean`,
+//! `wt archive list`).
+
+use color_print::cformat;
+use unicode_width::UnicodeWidthStr;
+
+/// How a column's cells are styled. Headers are always bold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CellStyle {
+    Plain,
+    Bold,
+    Dim,
+}
+
+/// Render `rows` under a header line, each column padded to its widest cell
+/// and separated by two spaces.
+///
+/// Trailing empty cells are dropped and the last cell of a line isn't padded,
+/// so no line ends in whitespace.
+pub(crate) fn render_table<const N: usize>(
+    headers: [&str; N],
+    styles: [CellStyle; N],
+    rows: &[[String; N]],
+) -> Vec<String> {
+    let widths: [usize; N] = std::array::from_fn(|col| {
+        rows.iter()
+            .map(|row| row[col].width())
+            .chain([headers[col].width()])
+            .max()
+            .unwrap_or(0)
+    });
+    let pad = |s: &str, w: usize| format!("{s}{}", " ".repeat(w.saturating_sub(s.width())));
+
+    let header = headers
+        .iter()
+        .enumerate()
+        .map(|(col, h)| {
+            if col + 1 == N {
+                h.to_string()
+            } else {
+                pad(h, widths[col])
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("  ");
+    let mut lines = vec![cformat!("<bold>{header}</>")];
+    lines.extend(rows.iter().map(|row| {
+        let len = row
+            .iter()
+            .rposition(|cell| !cell.is_empty())
+            .map_or(0, |i| i + 1);
+        row[..len]
+            .iter()
+            .enumerate()
+            .map(|(col, cell)| {
+                let text = if col + 1 == len {
+                    cell.clone()
+                } else {
+                    pad(cell, widths[col])
+                };
+                match styles[col] {
+                    CellStyle::Plain => text,
+                    CellStyle::Bold => cformat!("<bold>{text}</>"),
+                    CellStyle::Dim => cformat!("<dim>{text}</>"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table_drops_trailing_empty_cells() {
+        let lines = render_table(
+            ["A", "B", "C"],
+            [CellStyle::Bold, CellStyle::Plain, CellStyle::Dim],
+            &[
+                ["one".into(), "x".into(), "tail".into()],
+                ["three".into(), "longer".into(), String::new()],
+            ],
+        );
+        let lines: Vec<String> = lines
+            .iter()
+            .map(|l| ansi_str::AnsiStr::ansi_strip(l.as_str()).into_owned())
+            .collect();
+        assert_eq!(
+            lines,
+            ["A      B       C", "one    x       tail", "three  longer"]
+        );
+    }
+}
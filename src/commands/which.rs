@@ -0,0 +1,124 @@
+//! Owning worktree of a path (`wt which`).
+//!
+//! Answers "which worktree does this file live in?" for scripts and editor
+//! plugins. One `git worktree list --porcelain` plus path prefix matching —
+//! no per-worktree subprocesses. Both sides are canonicalized, so symlinked
+//! paths match, and the deepest root wins, so nested worktrees resolve to the
+//! inner one. Paths inside the shared git directory belong to the primary
+//! worktree.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use dunce::canonicalize;
+use worktrunk::git::{GitError, Repository, WorktreeInfo, base_path};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::println;
+
+use crate::cli::OutputFormat;
+
+/// JSON output for `wt which --format=json`.
+#[derive(serde::Serialize)]
+pub(super) struct WhichOutput<'a> {
+    pub(super) branch: Option<&'a str>,
+    pub(super) path: &'a Path,
+    /// Main worktree, or for bare repositories the default branch's
+    pub(super) is_primary: bool,
+    /// The path is inside the shared git directory rather than a worktree
+    pub(super) in_git_dir: bool,
+}
+
+/// Handle `wt which [path]`.
+pub fn handle_which(path: Option<&Path>, format: OutputFormat) -> anyhow::Result<()> {
+    let target = base_path().join(path.unwrap_or(Path::new(".")));
+    let target = canonicalize(&target)
+        .with_context(|| format!("Cannot resolve {}", format_path_for_display(&target)))?;
+    let dir = if target.is_dir() {
+        target.as_path()
+    } else {
+        target.parent().unwrap_or(&target)
+    };
+    let repo = Repository::at(dir)?;
+
+    let worktrees = repo.list_worktrees()?;
+    let primary = if repo.is_bare()? {
+        let default_branch = repo.default_branch();
+        worktrees
+            .iter()
+            .position(|wt| wt.branch.is_some() && wt.branch == default_branch)
+    } else {
+        (!worktrees.is_empty()).then_some(0)
+    };
+
+    let in_git_dir = target.starts_with(repo.git_common_dir());
+    let owner = if in_git_dir {
+        primary
+    } else {
+        let roots: Vec<Option<PathBuf>> = worktrees
+            .iter()
+            .map(|wt| canonicalize(&wt.path).ok())
+            .collect();
+        owning_root(&target, &roots)
+    };
+    let Some(index) = owner else {
+        return Err(GitError::PathNotInWorktree { path: target }.into());
+    };
+    let worktree: &WorktreeInfo = &worktrees[index];
+
+    match format {
+        OutputFormat::Json => {
+            let output = WhichOutput {
+                branch: worktree.branch.as_deref(),
+                path: &worktree.path,
+                is_primary: primary == Some(index),
+                in_git_dir,
+            };
+            let json =
+                serde_json::to_string_pretty(&output).context("Failed to serialize to JSON")?;
+            println!("{json}");
+        }
+        OutputFormat::Table | OutputFormat::ClaudeCode => {
+            let branch = worktree.branch.as_deref().unwrap_or("-");
+            println!("{branch} {}", worktree.path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Index of the deepest root containing `target`.
+///
+/// Roots that couldn't be resolved (worktrees whose directory is gone) are
+/// `None` and never match.
+fn owning_root(target: &Path, roots: &[Option<PathBuf>]) -> Option<usize> {
+    roots
+        .iter()
+        .enumerate()
+        .filter_map(|(index, root)| Some((index, root.as_deref()?)))
+        .filter(|(_, root)| target.starts_with(root))
+        .max_by_key(|(_, root)| root.components().count())
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owning_root() {
+        let roots = vec![
+            Some(PathBuf::from("/work/repo")),
+            Some(PathBuf::from("/work/repo/nested")),
+            Some(PathBuf::from("/work/repo.feature")),
+            None,
+        ];
+        let owner = |path: &str| owning_root(Path::new(path), &roots);
+
+        assert_eq!(owner("/work/repo/src/lib.rs"), Some(0));
+        assert_eq!(owner("/work/repo/nested/src"), Some(1));
+        assert_eq!(owner("/work/repo/nested"), Some(1));
+        // Component-wise: `repo.feature` isn't inside `repo`
+        assert_eq!(owner("/work/repo.feature/a"), Some(2));
+        assert_eq!(owner("/work/elsewhere"), None);
+    }
+}
@@ -0,0 +1,264 @@
+//! Detect other processes sitting inside a worktree (`check-cwd-users = true`).
+//!
+//! Removing a directory that a shell or dev server has as its working directory
+//! leaves that process pointing at a deleted path. Before removal we look for
+//! such processes — via `/proc` on Linux and `lsof` on macOS — and ask before
+//! going ahead. Detection is best-effort: anything that fails or runs out of
+//! time just reports fewer processes.
+
+use std::io::{self, IsTerminal};
+use std::path::Path;
+
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, format_with_gutter, warning_message};
+
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
+
+/// Time allowed for scanning processes.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+const BUDGET: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// A process whose working directory is inside the worktree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CwdUser {
+    pub pid: u32,
+    pub name: String,
+}
+
+/// "2 processes are using this directory: node (1234), zsh (5678)"
+fn describe(users: &[CwdUser]) -> String {
+    let list = users
+        .iter()
+        .map(|u| format!("{} ({})", u.name, u.pid))
+        .collect::<Vec<_>>()
+        .join(", ");
+    match users.len() {
+        1 => format!("1 process is using this directory: {list}"),
+        n => format!("{n} processes are using this directory: {list}"),
+    }
+}
+
+/// Ask before removing a worktree that other processes are sitting in.
+///
+/// Proceeds silently when nothing is found. Non-interactive sessions can't be
+/// asked, so the removal fails and `--force` is the way through. Only `wt
+/// remove` asks; `wt step prune` skips such worktrees instead.
+pub(crate) fn confirm_no_cwd_users(worktree_path: &Path) -> anyhow::Result<()> {
+    let users = find_cwd_users(worktree_path);
+    if users.is_empty() {
+        return Ok(());
+    }
+
+    let path = format_path_for_display(worktree_path);
+    if !io::stdin().is_terminal() {
+        anyhow::bail!(
+            "Not removing {path}: {}. To remove it anyway, use --force",
+            describe(&users)
+        );
+    }
+
+    eprintln!(
+        "{}",
+        warning_message(format!("{path}: {}", describe(&users)))
+    );
+    let response = prompt_yes_no_preview("Remove it anyway?", || {
+        let list = users
+            .iter()
+            .map(|u| format!("{:>7}  {}", u.pid, u.name))
+            .collect::<Vec<_>>()
+            .join("\n");
+        eprintln!("{}", format_with_gutter(&list, None));
+    })?;
+    if response != PromptResponse::Accepted {
+        anyhow::bail!("Not removing {path}");
+    }
+    Ok(())
+}
+
+/// Processes other than this one and its parent shell whose working directory
+/// is `dir` or below it.
+pub(crate) fn find_cwd_users(dir: &Path) -> Vec<CwdUser> {
+    let Ok(dir) = dunce::canonicalize(dir) else {
+        return Vec::new();
+    };
+    let mut users = scan(&dir);
+    users.retain(|u| !is_self_or_ancestor(u.pid));
+    users.sort_by_key(|u| u.pid);
+    users
+}
+
+#[cfg(target_os = "linux")]
+fn scan(dir: &Path) -> Vec<CwdUser> {
+    let deadline = std::time::Instant::now() + BUDGET;
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .take_while(|_| std::time::Instant::now() < deadline)
+        .filter_map(|entry| {
+            let pid: u32 = entry.file_name().to_str()?.parse().ok()?;
+            // Other users' processes can't be read; they're skipped
+            let cwd = std::fs::read_link(entry.path().join("cwd")).ok()?;
+            if !cwd.starts_with(dir) {
+                return None;
+            }
+            let name = std::fs::read_to_string(entry.path().join("comm")).ok()?;
+            Some(CwdUser {
+                pid,
+                name: name.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn scan(dir: &Path) -> Vec<CwdUser> {
+    use worktrunk::shell_exec::Cmd;
+
+    // -F output: one field per line, `p<pid>` then `c<command>` then `n<path>`
+    let Ok(output) = Cmd::new("lsof")
+        .args(["-n", "-P", "-d", "cwd", "-F", "pcn"])
+        .timeout(BUDGET)
+        .run()
+    else {
+        return Vec::new();
+    };
+    parse_lsof(&String::from_utf8_lossy(&output.stdout), dir)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn scan(_dir: &Path) -> Vec<CwdUser> {
+    Vec::new()
+}
+
+/// Parse `lsof -F pcn` output, keeping processes whose cwd is under `dir`.
+#[cfg(any(target_os = "macos", test))]
+fn parse_lsof(output: &str, dir: &Path) -> Vec<CwdUser> {
+    let mut users = Vec::new();
+    let (mut pid, mut name) = (None, String::new());
+    for line in output.lines() {
+        let (field, value) = line.split_at_checked(1).unwrap_or(("", ""));
+        match field {
+            "p" => pid = value.parse().ok(),
+            "c" => name = value.to_string(),
+            "n" if Path::new(value).starts_with(dir) => {
+                if let Some(pid) = pid {
+                    users.push(CwdUser {
+                        pid,
+                        name: name.clone(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    users
+}
+
+/// Whether `pid` is this process or one of its ancestors.
+///
+/// The shell that ran `wt remove` sits in the worktree being removed, and shell
+/// integration moves it out afterwards, so it never counts. On Linux the whole
+/// chain is walked (shell wrappers may add a level); elsewhere only the parent.
+#[cfg(unix)]
+fn is_self_or_ancestor(pid: u32) -> bool {
+    if pid == std::process::id() || pid == std::os::unix::process::parent_id() {
+        return true;
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let mut current = std::os::unix::process::parent_id();
+        while current > 1 {
+            let Some(parent) = linux_parent_pid(current) else {
+                break;
+            };
+            if parent == pid {
+                return true;
+            }
+            current = parent;
+        }
+    }
+    false
+}
+
+#[cfg(not(unix))]
+fn is_self_or_ancestor(pid: u32) -> bool {
+    pid == std::process::id()
+}
+
+/// Parent pid from `/proc/<pid>/stat`, whose second field (the command) may
+/// itself contain spaces and parentheses.
+#[cfg(target_os = "linux")]
+fn linux_parent_pid(pid: u32) -> Option<u32> {
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    let after_comm = &stat[stat.rfind(')')? + 1..];
+    after_comm.split_whitespace().nth(1)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let users = [
+            CwdUser {
+                pid: 1234,
+                name: "node".into(),
+            },
+            CwdUser {
+                pid: 5678,
+                name: "zsh".into(),
+            },
+        ];
+        assert_eq!(
+            describe(&users),
+            "2 processes are using this directory: node (1234), zsh (5678)"
+        );
+        assert_eq!(
+            describe(&users[..1]),
+            "1 process is using this directory: node (1234)"
+        );
+    }
+
+    #[test]
+    fn test_parse_lsof() {
+        let output =
+            "p100\ncbash\nn/repo.feature\np200\ncnode\nn/repo.feature/web\np300\ncvim\nn/repo\n";
+        assert_eq!(
+            parse_lsof(output, Path::new("/repo.feature")),
+            [
+                CwdUser {
+                    pid: 100,
+                    name: "bash".into()
+                },
+                CwdUser {
+                    pid: 200,
+                    name: "node".into()
+                },
+            ]
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_finds_child_in_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut child = std::process::Command::new("sleep")
+            .arg("10")
+            .current_dir(dir.path())
+            .spawn()
+            .unwrap();
+        let users = find_cwd_users(dir.path());
+        child.kill().unwrap();
+        child.wait().unwrap();
+        assert!(
+            users
+                .iter()
+                .any(|u| u.pid == child.id() && u.name == "sleep")
+        );
+        // This process isn't in the directory, and wouldn't count if it were
+        assert!(users.iter().all(|u| u.pid != std::process::id()));
+    }
+}
@@ -9,7 +9,8 @@ use worktrunk::path::to_posix_path;
 
 use crate::commands::command_executor::CommandContext;
 use crate::commands::hooks::{
-    HookCommandSpec, HookFailureStrategy, SourcedCommand, execute_hook, prepare_hook_commands,
+    HookCommandSpec, HookFailureStrategy, HookRunSummary, SourcedCommand, execute_hook,
+    prepare_hook_commands,
 };
 
 impl<'a> CommandContext<'a> {
@@ -20,7 +21,10 @@ impl<'a> CommandContext<'a> {
     /// won't cd to the new worktree, so they need to know where hooks ran).
     ///
     /// `extra_vars`: Additional template variables (e.g., `base`, `base_worktree_path`).
-    pub fn execute_post_create_commands(&self, extra_vars: &[(&str, &str)]) -> anyhow::Result<()> {
+    pub fn execute_post_create_commands(
+        &self,
+        extra_vars: &[(&str, &str)],
+    ) -> anyhow::Result<HookRunSummary> {
         execute_hook(
             self,
             HookType::PostCreate,
@@ -50,7 +54,7 @@ impl<'a> CommandContext<'a> {
         removed_commit: Option<&str>,
         display_path: Option<&Path>,
     ) -> anyhow::Result<Vec<SourcedCommand>> {
-        let project_config = self.repo.load_project_config()?;
+        let project_config = self.project_config()?;
 
         // Template variables should reflect the removed worktree, not where we run from.
         // The removed worktree path no longer exists, but hooks may need to reference it
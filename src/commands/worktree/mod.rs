@@ -78,6 +78,7 @@
 //!
 //! The shell wrapper is generated by `wt config shell init <shell>` from templates in `templates/`.
 
+mod cwd_users;
 mod hooks;
 mod push;
 mod remove;
@@ -86,15 +87,16 @@ mod switch;
 mod types;
 
 // Re-export public types and functions
+pub(crate) use cwd_users::find_cwd_users;
 pub use push::handle_push;
 pub use remove::{handle_remove, handle_remove_current};
 pub(crate) use resolve::paths_match;
 pub use resolve::{
-    compute_worktree_path, get_path_mismatch, is_worktree_at_expected_path, resolve_worktree_arg,
-    worktree_display_name,
+    check_new_branch_name, check_worktree_root, compute_worktree_path, get_path_mismatch,
+    is_worktree_at_expected_path, resolve_worktree_arg, worktree_display_name,
 };
 pub use switch::{execute_switch, plan_switch};
 pub use types::{
-    BranchDeletionMode, MergeOperations, OperationMode, RemoveResult, SwitchBranchInfo, SwitchPlan,
-    SwitchResult,
+    BranchDeletionMode, MergeOperations, OperationMode, RemoveResult, SharedCheckout,
+    SwitchBranchInfo, SwitchFact, SwitchPlan, SwitchResult, SwitchTargetFlags,
 };
@@ -21,12 +21,11 @@ use crate::commands::repository_ext::RepositoryCliExt;
 /// fast-forward. The stash is restored afterward and we bail out early if any file
 /// overlaps with the push range.
 pub fn handle_push(
+    repo: &Repository,
     target: Option<&str>,
     verb: &str,
     operations: Option<MergeOperations>,
 ) -> anyhow::Result<()> {
-    let repo = Repository::current()?;
-
     // Get and validate target branch (must be a branch since we're updating it)
     let target_branch = repo.require_target_branch(target)?;
 
@@ -130,7 +129,7 @@ pub fn handle_push(
         eprintln!("{}", format_with_gutter(&log_output, None));
 
         // Show diff statistics
-        crate::commands::show_diffstat(&repo, &format!("{}..HEAD", target_branch))?;
+        crate::commands::show_diffstat(repo, &format!("{}..HEAD", target_branch))?;
     }
 
     // Get git common dir for the push
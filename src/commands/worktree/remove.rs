@@ -3,6 +3,7 @@
 use worktrunk::config::UserConfig;
 use worktrunk::git::Repository;
 
+use super::cwd_users::confirm_no_cwd_users;
 use super::types::{BranchDeletionMode, RemoveResult};
 use crate::commands::repository_ext::{RemoveTarget, RepositoryCliExt};
 
@@ -17,12 +18,14 @@ pub fn handle_remove(
     let repo = Repository::current()?;
 
     // Progress message is shown in handle_removed_worktree_output() after pre-remove hooks run
-    repo.prepare_worktree_removal(
+    let result = repo.prepare_worktree_removal(
         RemoveTarget::Branch(worktree_name),
         BranchDeletionMode::from_flags(keep_branch, force_delete),
         force_worktree,
         config,
-    )
+    )?;
+    confirm_removal_with_cwd_users(&result, config)?;
+    Ok(result)
 }
 
 /// Handle removing the current worktree (supports detached HEAD state).
@@ -38,10 +41,29 @@ pub fn handle_remove_current(
     let repo = Repository::current()?;
 
     // Progress message is shown in handle_removed_worktree_output() after pre-remove hooks run
-    repo.prepare_worktree_removal(
+    let result = repo.prepare_worktree_removal(
         RemoveTarget::Current,
         BranchDeletionMode::from_flags(keep_branch, force_delete),
         force_worktree,
         config,
-    )
+    )?;
+    confirm_removal_with_cwd_users(&result, config)?;
+    Ok(result)
+}
+
+/// Ask before pulling the directory out from under other processes (opt-in).
+fn confirm_removal_with_cwd_users(
+    result: &RemoveResult,
+    config: &UserConfig,
+) -> anyhow::Result<()> {
+    if let RemoveResult::RemovedWorktree {
+        worktree_path,
+        force_worktree: false,
+        ..
+    } = result
+        && config.check_cwd_users
+    {
+        confirm_no_cwd_users(worktree_path)?;
+    }
+    Ok(())
 }
@@ -2,7 +2,7 @@
 //!
 //! Functions for resolving worktree arguments and computing expected paths.
 
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use color_print::cformat;
 use dunce::canonicalize;
@@ -10,6 +10,7 @@ use normalize_path::NormalizePath;
 use worktrunk::config::UserConfig;
 use worktrunk::git::{GitError, Repository, ResolvedWorktree};
 use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, info_message};
 
 use super::types::OperationMode;
 
@@ -72,13 +73,25 @@ pub fn resolve_worktree_arg(
 /// Compute the expected worktree path for a branch name.
 ///
 /// For the default branch, returns the repo root (main worktree location).
-/// For other branches, applies the `worktree-path` template from config.
+/// For other branches, applies the `worktree-path` template from the matching
+/// worktree profile, or else from user config.
 ///
 /// Uses cached values from Repository for `default_branch` and `is_bare`.
 pub fn compute_worktree_path(
     repo: &Repository,
     branch: &str,
     config: &UserConfig,
+) -> anyhow::Result<PathBuf> {
+    compute_profile_worktree_path(repo, branch, config, None)
+}
+
+/// [`compute_worktree_path`] with the worktree profile `profile` forced over
+/// pattern matching (`wt switch --profile`).
+pub fn compute_profile_worktree_path(
+    repo: &Repository,
+    branch: &str,
+    config: &UserConfig,
+    profile: Option<&str>,
 ) -> anyhow::Result<PathBuf> {
     let repo_root = repo.repo_path()?;
     let default_branch = repo.default_branch().unwrap_or_default();
@@ -106,12 +119,109 @@ pub fn compute_worktree_path(
             )
         })?;
 
+    // A matching worktree profile's template replaces the user's
+    let profile_template = repo
+        .load_project_config()?
+        .and_then(|c| c.profile_for(Some(branch), profile)?.worktree_path.clone());
     let project = repo.project_identifier().ok();
-    let expanded_path = config.format_path(repo_name, branch, repo, project.as_deref())?;
+    let expanded_path = match profile_template {
+        Some(template) => {
+            config.format_path_template(&template, repo_name, branch, repo, project.as_deref())?
+        }
+        None => config.format_path(repo_name, branch, repo, project.as_deref())?,
+    };
 
     Ok(repo_root.join(expanded_path).normalize())
 }
 
+/// Check that a worktree about to be created or moved to `path` is inside one
+/// of the user's `allowed-worktree-roots`.
+///
+/// Both sides are resolved through symlinks (the nonexistent tail of `path`
+/// lexically), so neither a symlinked root nor a `../..` escape fools the
+/// comparison. No roots configured means any path is allowed.
+pub fn check_worktree_root(path: &Path, config: &UserConfig) -> anyhow::Result<()> {
+    let roots = config.allowed_worktree_roots();
+    if roots.is_empty() {
+        return Ok(());
+    }
+    let resolved = resolve_physical(path);
+    if roots
+        .iter()
+        .any(|root| resolved.starts_with(resolve_physical(root)))
+    {
+        return Ok(());
+    }
+    Err(GitError::WorktreePathNotAllowed {
+        path: resolved,
+        roots,
+    }
+    .into())
+}
+
+/// Resolve `path` to an absolute path through symlinks, component by component.
+///
+/// `..` steps out of the directory a symlink points to, as the filesystem
+/// does, rather than cancelling the symlink's own name. Components that don't
+/// exist yet are appended as they are.
+fn resolve_physical(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut resolved = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            component => {
+                resolved.push(component);
+                if let Ok(canonical) = canonicalize(&resolved) {
+                    resolved = canonical;
+                }
+            }
+        }
+    }
+    resolved
+}
+
+/// Check a name for a new branch against the project's `[branch-name-rules]`.
+///
+/// Returns the name to create: `branch` itself when it passes (or there are no
+/// rules), the suggested fix when it doesn't and `fix_name` is set.
+pub fn check_new_branch_name(
+    repo: &Repository,
+    branch: &str,
+    fix_name: bool,
+) -> anyhow::Result<String> {
+    let Some(rules) = repo
+        .load_project_config()?
+        .and_then(|config| config.branch_name_rules)
+    else {
+        return Ok(branch.to_string());
+    };
+    let Some(violation) = rules.check(branch)? else {
+        return Ok(branch.to_string());
+    };
+    match violation.suggestion {
+        Some(fixed) if fix_name => {
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "Using <bold>{fixed}</> for <bold>{branch}</>, to satisfy <bold>{}</>",
+                    violation.rule
+                ))
+            );
+            Ok(fixed)
+        }
+        suggestion => Err(GitError::BranchNameRejected {
+            branch: branch.to_string(),
+            rule: violation.rule,
+            suggestion,
+        }
+        .into()),
+    }
+}
+
 /// Check if a worktree is at its expected path based on config template.
 ///
 /// Returns true if the worktree's actual path matches what `compute_worktree_path`
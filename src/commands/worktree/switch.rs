@@ -2,24 +2,32 @@
 //!
 //! Functions for planning and executing worktree switches.
 
+use std::collections::HashMap;
+use std::io::{self, IsTerminal};
 use std::path::Path;
 
 use anyhow::Context;
 use color_print::cformat;
 use dunce::canonicalize;
-use worktrunk::config::UserConfig;
+use worktrunk::HookType;
+use worktrunk::config::{UserConfig, expand_template};
 use worktrunk::git::remote_ref::{
     self, GitHubProvider, GitLabProvider, RemoteRefInfo, RemoteRefProvider,
 };
-use worktrunk::git::{GitError, RefContext, RefType, Repository};
+use worktrunk::git::{AddWorktreeOpts, GitError, RefContext, RefType, Repository, WorktrunkError};
 use worktrunk::styling::{
     eprintln, format_with_gutter, hint_message, info_message, progress_message, suggest_command,
     warning_message,
 };
 
-use super::resolve::{compute_clobber_backup, compute_worktree_path};
-use super::types::{CreationMethod, SwitchBranchInfo, SwitchPlan, SwitchResult};
-use crate::commands::command_executor::CommandContext;
+use super::resolve::{check_worktree_root, compute_clobber_backup, compute_profile_worktree_path};
+use super::types::{
+    CreationMethod, SharedCheckout, SwitchBranchInfo, SwitchFact, SwitchPlan, SwitchResult,
+    SwitchTargetFlags,
+};
+use crate::commands::command_executor::{CommandContext, build_hook_context};
+use crate::commands::find_closest_match;
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
 
 /// Result of resolving the switch target.
 struct ResolvedTarget {
@@ -126,6 +134,7 @@ fn resolve_fork_ref(
                 method: CreationMethod::Regular {
                     create_branch: false,
                     base_branch: None,
+                    orphan: false,
                 },
             });
         }
@@ -148,6 +157,7 @@ fn resolve_fork_ref(
                         method: CreationMethod::Regular {
                             create_branch: false,
                             base_branch: None,
+                            orphan: false,
                         },
                     });
                 }
@@ -315,7 +325,7 @@ fn resolve_same_repo_ref(
     // the configured fetch refspec (e.g., single-branch clones, bare repos).
     let refspec = format!("+refs/heads/{branch}:refs/remotes/{remote}/{branch}");
     // Use -- to prevent branch names starting with - from being interpreted as flags
-    repo.run_command(&["fetch", "--", &remote, &refspec])
+    repo.run_network_command(&["fetch", "--", &remote, &refspec])
         .with_context(|| cformat!("Failed to fetch branch <bold>{}</> from {}", branch, remote))?;
 
     Ok(ResolvedTarget {
@@ -323,20 +333,28 @@ fn resolve_same_repo_ref(
         method: CreationMethod::Regular {
             create_branch: false,
             base_branch: None,
+            orphan: false,
         },
     })
 }
 
-/// Resolve the switch target, handling pr:/mr: syntax and --create/--base flags.
+/// Resolve the switch target, handling pr:/mr: syntax and --create/--base/--orphan flags.
 ///
 /// This is the first phase of planning: determine what branch we're switching to
 /// and how we'll create the worktree. May involve network calls for PR/MR resolution.
 fn resolve_switch_target(
     repo: &Repository,
     branch: &str,
-    create: bool,
-    base: Option<&str>,
+    flags: SwitchTargetFlags<'_>,
 ) -> anyhow::Result<ResolvedTarget> {
+    let SwitchTargetFlags {
+        create,
+        base,
+        orphan,
+        branch_from_tag,
+        ..
+    } = flags;
+
     // Handle pr:<number> syntax
     if let Some(suffix) = branch.strip_prefix("pr:")
         && let Ok(number) = suffix.parse::<u32>()
@@ -362,6 +380,37 @@ fn resolve_switch_target(
         resolved_branch = local_name;
     }
 
+    // Tags (e.g., release checkouts side by side). A branch of the same name
+    // wins, matching `git switch`.
+    if !create && repo.tag_commit(&resolved_branch)?.is_some() {
+        if repo.branch(&resolved_branch).exists()? {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Both a branch and a tag named <bold>{resolved_branch}</> exist; using the branch"
+                ))
+            );
+        } else if branch_from_tag {
+            let inspect_branch = format!("inspect/{resolved_branch}");
+            let create_branch = !repo.branch(&inspect_branch).exists_locally()?;
+            return Ok(ResolvedTarget {
+                branch: inspect_branch,
+                method: CreationMethod::Regular {
+                    create_branch,
+                    base_branch: create_branch.then_some(resolved_branch),
+                    orphan: false,
+                },
+            });
+        } else {
+            return Ok(ResolvedTarget {
+                branch: resolved_branch.clone(),
+                method: CreationMethod::Tag {
+                    tag: resolved_branch,
+                },
+            });
+        }
+    }
+
     // Resolve and validate base (only when --create is set)
     let resolved_base = if let Some(base_str) = base {
         if !create {
@@ -415,6 +464,18 @@ fn resolve_switch_target(
         }
     }
 
+    // Orphan branches start with no history, so there's no base to resolve
+    if orphan {
+        return Ok(ResolvedTarget {
+            branch: resolved_branch,
+            method: CreationMethod::Regular {
+                create_branch: true,
+                base_branch: None,
+                orphan: true,
+            },
+        });
+    }
+
     // Compute base branch for creation
     let base_branch = if create {
         resolved_base.or_else(|| {
@@ -441,15 +502,71 @@ fn resolve_switch_target(
         None
     };
 
+    // Without a base, `git worktree add -b` starts from HEAD, which fails with
+    // "not a valid object name" when HEAD is unborn (freshly `git init`-ed repo)
+    if create && base_branch.is_none() && !repo.ref_exists("HEAD")? {
+        return Err(GitError::NoCommitsYet {
+            branch: resolved_branch,
+        }
+        .into());
+    }
+
     Ok(ResolvedTarget {
         branch: resolved_branch,
         method: CreationMethod::Regular {
             create_branch: create,
             base_branch,
+            orphan: false,
         },
     })
 }
 
+/// Resolve a switch argument that names a worktree directory instead of a branch.
+///
+/// An existing directory (absolute or relative path) registered as a worktree
+/// matches first; otherwise a worktree directory basename matches when it's
+/// unique. Returns the matched worktree's branch. Detached worktrees have no
+/// branch to switch to, so they never match.
+fn resolve_worktree_dir(repo: &Repository, arg: &str) -> anyhow::Result<Option<String>> {
+    let worktrees = repo.list_worktrees()?;
+    let on_branch: Vec<_> = worktrees
+        .iter()
+        .filter_map(|wt| wt.branch.as_deref().map(|branch| (wt, branch)))
+        .collect();
+
+    if let Ok(dir) = canonicalize(arg)
+        && dir.is_dir()
+        && let Some((_, branch)) = on_branch
+            .iter()
+            .find(|(wt, _)| canonicalize(&wt.path).is_ok_and(|p| p == dir))
+    {
+        return Ok(Some(branch.to_string()));
+    }
+
+    let mut by_name = on_branch.iter().filter(|(wt, _)| wt.dir_name() == arg);
+    Ok(match (by_name.next(), by_name.next()) {
+        (Some((_, branch)), None) => Some(branch.to_string()),
+        _ => None,
+    })
+}
+
+/// Find the closest local branch or worktree directory name to a missing target.
+fn closest_switch_target(repo: &Repository, arg: &str) -> anyhow::Result<Option<String>> {
+    let branches = repo.all_branches()?;
+    let worktrees = repo.list_worktrees()?;
+    let candidates: Vec<&str> = branches
+        .iter()
+        .map(String::as_str)
+        .chain(
+            worktrees
+                .iter()
+                .filter(|wt| wt.branch.is_some())
+                .map(|wt| wt.dir_name()),
+        )
+        .collect();
+    Ok(find_closest_match(arg, &candidates).map(str::to_owned))
+}
+
 /// Validate that we can create a worktree at the given path.
 ///
 /// Checks:
@@ -476,6 +593,7 @@ fn validate_worktree_creation(
         return Err(GitError::BranchNotFound {
             branch: branch.to_string(),
             show_create_hint: true,
+            closest: closest_switch_target(repo, branch)?,
         }
         .into());
     }
@@ -508,6 +626,45 @@ fn validate_worktree_creation(
     compute_clobber_backup(path, branch, clobber, is_create)
 }
 
+/// Refuse a worktree path outside `allowed-worktree-roots`.
+///
+/// With `--allow-any-path`, a yes at the prompt lets it through; without a
+/// terminal to ask, the path is refused all the same.
+fn check_allowed_path(
+    path: &Path,
+    config: &UserConfig,
+    allow_any_path: bool,
+) -> anyhow::Result<()> {
+    let Err(err) = check_worktree_root(path, config) else {
+        return Ok(());
+    };
+    if !allow_any_path || !io::stdin().is_terminal() {
+        return Err(err);
+    }
+    let path_display = worktrunk::path::format_path_for_display(path);
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "<bold>{path_display}</> is outside allowed-worktree-roots"
+        ))
+    );
+    let roots = config
+        .allowed_worktree_roots()
+        .iter()
+        .map(|root| worktrunk::path::format_path_for_display(root))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let response = prompt_yes_no_preview("Create the worktree there anyway?", || {
+        eprintln!("{}", info_message("Allowed worktree roots:"));
+        eprintln!("{}", format_with_gutter(&roots, None));
+    })?;
+    if response == PromptResponse::Accepted {
+        Ok(())
+    } else {
+        Err(err)
+    }
+}
+
 /// Set up a local branch for a fork PR or MR.
 ///
 /// Creates the branch from FETCH_HEAD, configures tracking (remote, merge ref,
@@ -557,21 +714,126 @@ fn setup_fork_branch(
     }
 
     // Create worktree (delayed streaming: silent if fast, shows progress if slow)
-    // Use -- to prevent branch names starting with - from being interpreted as flags
-    let worktree_path_str = worktree_path.to_string_lossy();
-    let git_args = ["worktree", "add", "--", worktree_path_str.as_ref(), branch];
-    repo.run_command_delayed_stream(
-        &git_args,
-        Repository::SLOW_OPERATION_DELAY_MS,
-        Some(
-            progress_message(cformat!("Creating worktree for <bold>{}</>...", branch)).to_string(),
-        ),
-    )
-    .map_err(|e| worktree_creation_error(&e, branch.to_string(), None))?;
+    let progress =
+        progress_message(cformat!("Creating worktree for <bold>{}</>...", branch)).to_string();
+    let _ = repo
+        .add_worktree(AddWorktreeOpts {
+            branch: Some(branch),
+            progress: Some(&progress),
+            ..AddWorktreeOpts::new(worktree_path)
+        })
+        .map_err(|e| worktree_creation_error(e, branch.to_string(), None))?;
 
     Ok(())
 }
 
+/// Changes in a partial worktree beyond what the interrupted checkout left
+/// out, one `git status --porcelain` line each.
+///
+/// Files the checkout never wrote show as deleted and don't count; edited and
+/// untracked files do. A directory git never registered had nothing checked
+/// out, so everything in it counts.
+fn partial_worktree_work(repo: &Repository, path: &Path) -> anyhow::Result<Vec<String>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    if repo.worktree_at_path(path)?.is_none() {
+        return Ok(std::fs::read_dir(path)?
+            .flatten()
+            .map(|entry| format!("?? {}", entry.file_name().to_string_lossy()))
+            .collect());
+    }
+    let status =
+        repo.worktree_at(path)
+            .run_command(&["status", "--porcelain", "--untracked-files=all"])?;
+    Ok(status
+        .lines()
+        .filter(|line| !line.starts_with(" D"))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Offer to start over on a worktree whose creation never finished.
+///
+/// A `wt` killed mid-checkout leaves the worktree registered but incomplete.
+/// With consent (`--clobber`, or a yes at the prompt) the partial worktree is
+/// removed and this returns `true`, so the switch creates it afresh. Work done
+/// in it since always needs a yes at the prompt; without a terminal it's kept.
+fn recover_interrupted_creation(
+    repo: &Repository,
+    branch: &str,
+    path: &Path,
+    clobber: bool,
+) -> anyhow::Result<bool> {
+    let same = |p: &Path| canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    if !repo
+        .interrupted_creations()
+        .iter()
+        .any(|p| same(p) == same(path))
+    {
+        return Ok(false);
+    }
+
+    let path_display = worktrunk::path::format_path_for_display(path);
+    let work = partial_worktree_work(repo, path)?;
+    if !clobber || !work.is_empty() {
+        let interrupted = || GitError::WorktreeCreationInterrupted {
+            branch: branch.to_string(),
+            path: path.to_path_buf(),
+        };
+        if !io::stdin().is_terminal() {
+            if work.is_empty() {
+                return Err(interrupted().into());
+            }
+            anyhow::bail!(cformat!(
+                "Not removing the partial worktree at <bold>{path_display}</>: {} changed since its creation stopped",
+                files_noun(work.len())
+            ));
+        }
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Creating the worktree for <bold>{branch}</> at <bold>{path_display}</> didn't finish"
+            ))
+        );
+        if !work.is_empty() {
+            eprintln!(
+                "{}",
+                warning_message(format!(
+                    "{} changed since; removing it discards them",
+                    files_noun(work.len())
+                ))
+            );
+        }
+        let response = prompt_yes_no_preview("Remove it and create it again?", || {
+            if !work.is_empty() {
+                eprintln!("{}", format_with_gutter(&work.join("\n"), None));
+            }
+        })?;
+        if response != PromptResponse::Accepted {
+            return Err(interrupted().into());
+        }
+    }
+
+    repo.discard_partial_worktree(path)?;
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Removed the partial worktree at <bold>{path_display}</>"
+        ))
+    );
+    Ok(true)
+}
+
+/// "1 file" / "3 files"
+fn files_noun(count: usize) -> String {
+    if count == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{count} files")
+    }
+}
+
 /// Validate and plan a switch operation.
 ///
 /// This performs all validation upfront, returning a `SwitchPlan` that can be
@@ -583,40 +845,102 @@ fn setup_fork_branch(
 pub fn plan_switch(
     repo: &Repository,
     branch: &str,
-    create: bool,
-    base: Option<&str>,
+    flags: SwitchTargetFlags<'_>,
     clobber: bool,
     config: &UserConfig,
 ) -> anyhow::Result<SwitchPlan> {
     // Record current branch for `wt switch -` support
     let new_previous = repo.current_worktree().branch().ok().flatten();
 
+    // Phase 0: Accept a worktree path or directory name in place of a branch.
+    // A branch of the same name wins, matching how branches beat tags.
+    let dir_branch = if flags.create || matches!(branch, "@" | "-" | "^") {
+        None
+    } else {
+        resolve_worktree_dir(repo, branch)?
+    };
+    let branch = match dir_branch.as_deref() {
+        Some(dir_branch) if dir_branch != branch => {
+            if repo.branch(branch).exists()? {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "Both a branch and a worktree directory named <bold>{branch}</> exist; using the branch"
+                    ))
+                );
+                branch
+            } else {
+                dir_branch
+            }
+        }
+        _ => branch,
+    };
+
     // Phase 1: Resolve target (handles pr:, validates --create/--base, may do network)
-    let target = resolve_switch_target(repo, branch, create, base)?;
+    let target = resolve_switch_target(repo, branch, flags)?;
 
     // Phase 2: Check if worktree already exists for this branch (fast path)
     // This avoids computing the worktree path template (~7 git commands) for existing switches.
-    match repo.worktree_for_branch(&target.branch)? {
-        Some(existing_path) if existing_path.exists() => {
+    // An explicit `--new-path` asks for another worktree, which git only allows
+    // detached or forced, so say so before git refuses.
+    let new_path = flags.new_path.map(std::path::absolute).transpose()?;
+    let mut existing = repo.worktree_for_branch(&target.branch)?;
+    if let Some(path) = &existing
+        && new_path.is_none()
+        && recover_interrupted_creation(repo, &target.branch, path, clobber)?
+    {
+        existing = None;
+    }
+    match (existing, &new_path) {
+        (Some(existing_path), Some(new_path)) => {
+            if flags.shared_checkout.is_none() {
+                return Err(GitError::BranchCheckedOutElsewhere {
+                    branch: target.branch,
+                    path: existing_path,
+                    new_path: new_path.clone(),
+                }
+                .into());
+            }
+        }
+        (Some(existing_path), None) if existing_path.exists() => {
             return Ok(SwitchPlan::Existing {
                 path: canonicalize(&existing_path).unwrap_or(existing_path),
                 branch: target.branch,
                 new_previous,
+                profile: flags.profile.map(str::to_owned),
             });
         }
-        Some(_) => {
+        (Some(_), None) => {
             return Err(GitError::WorktreeMissing {
                 branch: target.branch,
             }
             .into());
         }
-        None => {}
+        (None, _) => {}
     }
 
     // Phase 3: Compute expected path (only needed for create)
-    let expected_path = compute_worktree_path(repo, &target.branch, config)?;
+    let expected_path = match new_path {
+        Some(path) => path,
+        None => compute_profile_worktree_path(repo, &target.branch, config, flags.profile)?,
+    };
+
+    // Tag checkouts are detached, so the branch lookup above can't find them.
+    // A detached worktree at the expected path is the one created earlier.
+    if matches!(target.method, CreationMethod::Tag { .. })
+        && let Some((existing_path, None)) = repo.worktree_at_path(&expected_path)?
+        && existing_path.exists()
+    {
+        return Ok(SwitchPlan::Existing {
+            path: canonicalize(&existing_path).unwrap_or(existing_path),
+            branch: target.branch,
+            new_previous,
+            profile: flags.profile.map(str::to_owned),
+        });
+    }
 
     // Phase 4: Validate we can create at this path
+    check_allowed_path(&expected_path, config, flags.allow_any_path)?;
     let clobber_backup = validate_worktree_creation(
         repo,
         &target.branch,
@@ -625,13 +949,27 @@ pub fn plan_switch(
         &target.method,
     )?;
 
+    // Refuse to grow past `[switch] max-worktrees`
+    let project = repo.project_identifier().ok();
+    if let Some(max) = config
+        .switch(project.as_deref())
+        .and_then(|s| s.max_worktrees)
+    {
+        let count = repo.list_worktrees()?.len();
+        if count >= max {
+            return Err(GitError::WorktreeLimitReached { count, max }.into());
+        }
+    }
+
     // Phase 5: Return the plan
     Ok(SwitchPlan::Create {
         branch: target.branch,
         worktree_path: expected_path,
         method: target.method,
         clobber_backup,
+        shared_checkout: flags.shared_checkout,
         new_previous,
+        profile: flags.profile.map(str::to_owned),
     })
 }
 
@@ -654,6 +992,7 @@ pub fn execute_switch(
             path,
             branch,
             new_previous,
+            ..
         } => {
             let current_dir = std::env::current_dir()
                 .ok()
@@ -692,7 +1031,9 @@ pub fn execute_switch(
             worktree_path,
             method,
             clobber_backup,
+            shared_checkout,
             new_previous,
+            profile,
         } => {
             // Handle --clobber backup if needed (shared for all creation methods)
             if let Some(backup_path) = &clobber_backup {
@@ -710,66 +1051,61 @@ pub fn execute_switch(
                 })?;
             }
 
+            let mut facts = Vec::new();
+
             // Execute based on creation method
             let (created_branch, base_branch, from_remote) = match &method {
                 CreationMethod::Regular {
                     create_branch,
                     base_branch,
+                    orphan,
                 } => {
                     // Check if local branch exists BEFORE git worktree add (for DWIM detection)
                     let branch_handle = repo.branch(&branch);
                     let local_branch_existed =
                         !create_branch && branch_handle.exists_locally().unwrap_or(false);
 
-                    // Build git worktree add command
-                    let worktree_path_str = worktree_path.to_string_lossy();
-                    let mut args = vec!["worktree", "add", worktree_path_str.as_ref()];
-
                     // For DWIM fallback: when the branch doesn't exist locally,
                     // git worktree add relies on DWIM to auto-create it from a
                     // remote tracking branch. DWIM fails in repos without configured
                     // fetch refspecs (bare repos, single-branch clones). Explicitly
                     // create from the tracking ref in that case.
                     let tracking_ref;
+                    let progress =
+                        progress_message(cformat!("Creating worktree for <bold>{}</>...", branch))
+                            .to_string();
+                    let mut opts = AddWorktreeOpts {
+                        branch: Some(&branch),
+                        orphan: *orphan,
+                        create_branch: *create_branch,
+                        detach: shared_checkout == Some(SharedCheckout::Detach),
+                        force: shared_checkout == Some(SharedCheckout::Force),
+                        progress: Some(&progress),
+                        ..AddWorktreeOpts::new(&worktree_path)
+                    };
 
                     if *create_branch {
-                        args.push("-b");
-                        args.push(&branch);
-                        if let Some(base) = base_branch {
-                            args.push(base);
-                        }
-                    } else if !local_branch_existed {
+                        opts.base = base_branch.as_deref();
+                    } else if !*orphan && !local_branch_existed {
                         // Explicit -b when there's exactly one remote tracking ref.
                         // Git's DWIM relies on the fetch refspec including this branch,
                         // which may not hold in single-branch clones or bare repos.
+                        // Multiple or zero remotes: let git's DWIM handle (or error)
                         let remotes = branch_handle.remotes().unwrap_or_default();
                         if remotes.len() == 1 {
                             tracking_ref = format!("{}/{}", remotes[0], branch);
-                            args.extend(["-b", &branch, tracking_ref.as_str()]);
-                        } else {
-                            // Multiple or zero remotes: let git's DWIM handle (or error)
-                            args.push(&branch);
+                            opts.create_branch = true;
+                            opts.base = Some(&tracking_ref);
                         }
-                    } else {
-                        args.push(&branch);
                     }
 
                     // Delayed streaming: silent if fast, shows progress if slow
-                    let progress_msg = Some(
-                        progress_message(cformat!("Creating worktree for <bold>{}</>...", branch))
-                            .to_string(),
-                    );
-                    if let Err(e) = repo.run_command_delayed_stream(
-                        &args,
-                        Repository::SLOW_OPERATION_DELAY_MS,
-                        progress_msg,
-                    ) {
+                    if let Err(e) = repo.add_worktree(opts) {
                         return Err(worktree_creation_error(
-                            &e,
+                            e,
                             branch.clone(),
                             base_branch.clone(),
-                        )
-                        .into());
+                        ));
                     }
 
                     // Safety: unset unsafe upstream when creating a new branch from a remote
@@ -783,6 +1119,23 @@ pub fn execute_switch(
                     {
                         // Unset the upstream to prevent accidental pushes
                         branch_handle.unset_upstream()?;
+                        facts.push(SwitchFact::UpstreamUnset { base: base.clone() });
+                    }
+
+                    // A new branch based on another local branch is stacked on it;
+                    // record that so `wt list` and `--restack` follow the stack
+                    if *create_branch
+                        && let Some(base) = base_branch
+                        && repo.default_branch().as_deref() != Some(base.as_str())
+                        && repo.branch(base).exists_locally().unwrap_or(false)
+                    {
+                        match repo.set_branch_parent(&branch, base) {
+                            Ok(()) => facts.push(SwitchFact::ParentRecorded {
+                                parent: base.clone(),
+                            }),
+                            // Only possible with leftover config from a deleted branch
+                            Err(e) => log::debug!("Not recording parent of {branch}: {e}"),
+                        }
                     }
 
                     // Report tracking info when the branch was auto-created from a remote
@@ -795,6 +1148,24 @@ pub fn execute_switch(
                     (*create_branch, base_branch.clone(), from_remote)
                 }
 
+                CreationMethod::Tag { tag } => {
+                    let tag_ref = format!("refs/tags/{tag}");
+                    let progress =
+                        progress_message(cformat!("Creating worktree for tag <bold>{tag}</>..."))
+                            .to_string();
+                    let opts = AddWorktreeOpts {
+                        base: Some(&tag_ref),
+                        detach: true,
+                        progress: Some(&progress),
+                        ..AddWorktreeOpts::new(&worktree_path)
+                    };
+                    if let Err(e) = repo.add_worktree(opts) {
+                        return Err(worktree_creation_error(e, branch.clone(), None));
+                    }
+
+                    (false, None, None)
+                }
+
                 CreationMethod::ForkRef {
                     ref_type,
                     number,
@@ -807,7 +1178,7 @@ pub fn execute_switch(
 
                     // Fetch the ref (remote was resolved during planning)
                     // Use -- to prevent refs starting with - from being interpreted as flags
-                    repo.run_command(&["fetch", "--", remote, ref_path])
+                    repo.run_network_command(&["fetch", "--", remote, ref_path])
                         .with_context(|| format!("Failed to fetch {} from {}", label, remote))?;
 
                     // Execute branch creation and configuration with cleanup on failure.
@@ -827,12 +1198,9 @@ pub fn execute_switch(
                         return Err(e);
                     }
 
-                    // Show push configuration or warning about prefixed branch
+                    // Report push configuration, or warn about the prefixed branch
                     if let Some(url) = fork_push_url {
-                        eprintln!(
-                            "{}",
-                            info_message(cformat!("Push configured to fork: <underline>{url}</>"))
-                        );
+                        facts.push(SwitchFact::PushToFork { url: url.clone() });
                     } else {
                         // Prefixed branch name due to conflict - push won't work
                         eprintln!(
@@ -853,6 +1221,20 @@ pub fn execute_switch(
                 }
             };
 
+            // Bring back the branch's scratch directory before hooks run
+            if !matches!(method, CreationMethod::Tag { .. })
+                && let Some(dir) = restore_scratch(repo, config, &worktree_path, &branch)
+            {
+                facts.push(SwitchFact::ScratchRestored { dir });
+            }
+
+            // Before the identity check, which `[git-config]` can affect
+            facts.extend(apply_git_config(repo, config, &branch, &worktree_path)?);
+
+            // An includeIf keyed on location can give the new worktree another
+            // identity; catch it before hooks (or the user) commit
+            facts.extend(check_identity(repo, &worktree_path)?);
+
             // Compute base worktree path for hooks and result
             let base_worktree_path = base_branch
                 .as_ref()
@@ -861,9 +1243,10 @@ pub fn execute_switch(
 
             // Execute post-create commands
             if run_hooks {
-                let ctx = CommandContext::new(repo, config, Some(&branch), &worktree_path, force);
+                let ctx = CommandContext::new(repo, config, Some(&branch), &worktree_path, force)
+                    .with_profile(profile.as_deref());
 
-                match &method {
+                let summary = match &method {
                     CreationMethod::Regular { base_branch, .. } => {
                         let extra_vars: Vec<(&str, &str)> = [
                             base_branch.as_ref().map(|b| ("base", b.as_str())),
@@ -874,8 +1257,9 @@ pub fn execute_switch(
                         .into_iter()
                         .flatten()
                         .collect();
-                        ctx.execute_post_create_commands(&extra_vars)?;
+                        ctx.execute_post_create_commands(&extra_vars)?
                     }
+                    CreationMethod::Tag { .. } => ctx.execute_post_create_commands(&[])?,
                     CreationMethod::ForkRef {
                         ref_type,
                         number,
@@ -889,8 +1273,15 @@ pub fn execute_switch(
                         };
                         let extra_vars: Vec<(&str, &str)> =
                             vec![(num_key, &num_str), (url_key, ref_url)];
-                        ctx.execute_post_create_commands(&extra_vars)?;
+                        ctx.execute_post_create_commands(&extra_vars)?
                     }
+                };
+                if summary.ran > 0 {
+                    facts.push(SwitchFact::HooksRan {
+                        hook_type: HookType::PostCreate,
+                        ran: summary.ran,
+                        failed: summary.failed,
+                    });
                 }
             }
 
@@ -904,6 +1295,7 @@ pub fn execute_switch(
                     base_branch,
                     base_worktree_path,
                     from_remote,
+                    facts,
                 },
                 SwitchBranchInfo {
                     branch,
@@ -914,18 +1306,155 @@ pub fn execute_switch(
     }
 }
 
-/// Resolve the deferred path mismatch for existing worktree switches.
+/// Set the project's `[git-config]` entries in the new worktree's own config,
+/// turning on `extensions.worktreeConfig` first if needed.
+///
+/// Values expand with the hook variables plus `worktree_index`, the worktree's
+/// position in `git worktree list`. Removing the worktree removes its config,
+/// so nothing needs undoing later.
+fn apply_git_config(
+    repo: &Repository,
+    config: &UserConfig,
+    branch: &str,
+    worktree_path: &Path,
+) -> anyhow::Result<Vec<SwitchFact>> {
+    let Some(entries) = repo
+        .load_project_config()?
+        .and_then(|c| c.git_config)
+        .filter(|entries| !entries.is_empty())
+    else {
+        return Ok(Vec::new());
+    };
+
+    let canonical = canonicalize(worktree_path).ok();
+    let index = repo
+        .list_worktrees()?
+        .iter()
+        .position(|wt| canonicalize(&wt.path).ok() == canonical)
+        .unwrap_or_default()
+        .to_string();
+    let ctx = CommandContext::new(repo, config, Some(branch), worktree_path, false);
+    let context = build_hook_context(&ctx, &[("worktree_index", &index)])?;
+    let vars: HashMap<&str, &str> = context
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+
+    let mut facts = Vec::new();
+    if !repo.worktree_config_enabled() {
+        repo.enable_worktree_config()
+            .context("Failed to enable extensions.worktreeConfig")?;
+        facts.push(SwitchFact::WorktreeConfigEnabled);
+    }
+    let worktree = repo.worktree_at(worktree_path);
+    for (key, template) in &entries {
+        let value = expand_template(template, &vars, false, repo, &format!("git-config {key}"))?;
+        worktree
+            .set_worktree_config(key, &value)
+            .with_context(|| format!("Failed to set {key} from [git-config]"))?;
+    }
+    facts.push(SwitchFact::GitConfigSet {
+        keys: entries.into_keys().collect(),
+    });
+    Ok(facts)
+}
+
+/// Compare the new worktree's `user.name` and `user.email` with the primary
+/// worktree's, and enforce `[identity] require-email-pattern`.
 ///
+/// Returns a fact for each key that differs. A rejected email is an error,
+/// so nothing runs in the worktree until it's fixed.
+fn check_identity(repo: &Repository, worktree_path: &Path) -> anyhow::Result<Vec<SwitchFact>> {
+    let worktree = repo.worktree_at(worktree_path);
+    let email = worktree.config_with_origin("user.email")?;
+
+    if let Some(rules) = repo.load_project_config()?.and_then(|c| c.identity)
+        && !rules.allows(email.as_ref().map(|(value, _)| value.as_str()))?
+    {
+        let (email, origin) = email.unzip();
+        return Err(GitError::IdentityRejected {
+            path: worktree_path.to_path_buf(),
+            email,
+            origin: origin.map(|origin| display_config_origin(&origin)),
+            pattern: rules.require_email_pattern.unwrap_or_default(),
+        }
+        .into());
+    }
+
+    let primary = repo.worktree_at(repo.home_path()?);
+    let mut facts = Vec::new();
+    for (key, resolved) in [
+        ("user.name", worktree.config_with_origin("user.name")?),
+        ("user.email", email),
+    ] {
+        let (value, origin) = resolved.unzip();
+        let primary_value = primary.config_with_origin(key)?.map(|(value, _)| value);
+        if value != primary_value {
+            facts.push(SwitchFact::IdentityDiffers {
+                key,
+                value,
+                origin: origin.map(|origin| display_config_origin(&origin)),
+                primary_value,
+            });
+        }
+    }
+    Ok(facts)
+}
+
+/// `file:/home/me/.gitconfig` as `~/.gitconfig`; other origins as git prints them.
+fn display_config_origin(origin: &str) -> String {
+    match origin.strip_prefix("file:") {
+        Some(path) => worktrunk::path::format_path_for_display(Path::new(path)),
+        None => origin.to_string(),
+    }
+}
+
+/// Exclude the scratch directory from git and move the one preserved for
+/// `branch` into the new worktree, per `[scratch]`. Returns the directory name
+/// when one was restored.
+///
+/// The worktree already exists, so failures warn rather than abort.
+fn restore_scratch(
+    repo: &Repository,
+    config: &UserConfig,
+    worktree_path: &Path,
+    branch: &str,
+) -> Option<String> {
+    let scratch = config.scratch.as_ref().filter(|s| s.enabled())?;
+    let dir = scratch.dir();
+    let restored = worktrunk::scratch::ensure_excluded(repo.git_common_dir(), dir).and_then(|()| {
+        worktrunk::scratch::restore(repo.git_common_dir(), worktree_path, dir, branch)
+    });
+    match restored {
+        Ok(true) => Some(dir.to_string()),
+        Ok(false) => None,
+        Err(err) => {
+            eprintln!(
+                "{}",
+                warning_message(cformat!("Failed to restore <bold>{dir}/</>: {err:#}"))
+            );
+            None
+        }
+    }
+}
+
+/// Wrap a failed `git worktree add` with the branch being created.
+///
+/// An interruption passes through unchanged, keeping its exit code.
 fn worktree_creation_error(
-    err: &anyhow::Error,
+    err: anyhow::Error,
     branch: String,
     base_branch: Option<String>,
-) -> GitError {
-    let (output, command) = Repository::extract_failed_command(err);
+) -> anyhow::Error {
+    if err.downcast_ref::<WorktrunkError>().is_some() {
+        return err;
+    }
+    let (output, command) = Repository::extract_failed_command(&err);
     GitError::WorktreeCreationFailed {
         branch,
         base_branch,
         error: output,
         command,
     }
+    .into()
 }
@@ -4,7 +4,10 @@
 
 use std::path::{Path, PathBuf};
 
-use worktrunk::git::RefType;
+use worktrunk::HookType;
+use worktrunk::git::{RefType, Repository};
+
+use crate::commands::branch_deletion::RemoteBranch;
 
 /// Flags indicating which merge operations occurred
 #[derive(Debug, Clone, Copy)]
@@ -31,6 +34,43 @@ pub enum SwitchResult {
         base_worktree_path: Option<String>,
         /// Remote tracking branch if auto-created from remote (e.g., "origin/feature")
         from_remote: Option<String>,
+        /// What else the creation did, reported beneath the headline
+        facts: Vec<SwitchFact>,
+    },
+}
+
+/// Something a switch did besides landing in the worktree.
+///
+/// Collected while the switch runs and rendered as dim lines under the
+/// success message. Facts that didn't happen are simply absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwitchFact {
+    /// Upstream removed because the new branch was based on a remote-tracking branch
+    UpstreamUnset { base: String },
+    /// The `--base` branch was recorded as the new branch's stack parent
+    ParentRecorded { parent: String },
+    /// Pushes from the new branch go to a fork
+    PushToFork { url: String },
+    /// Blocking hook commands ran; failures were reported as they happened
+    HooksRan {
+        hook_type: HookType,
+        ran: usize,
+        failed: usize,
+    },
+    /// The scratch directory kept from the branch's last worktree was moved in
+    ScratchRestored { dir: String },
+    /// `extensions.worktreeConfig` was turned on for `[git-config]`
+    WorktreeConfigEnabled,
+    /// `[git-config]` keys were set in this worktree's own config
+    GitConfigSet { keys: Vec<String> },
+    /// Git resolves `key` differently here than in the primary worktree,
+    /// usually through an `includeIf` matching one location but not the other
+    IdentityDiffers {
+        key: &'static str,
+        value: Option<String>,
+        /// Where `value` is set, from `git config --show-origin`
+        origin: Option<String>,
+        primary_value: Option<String>,
     },
 }
 
@@ -63,6 +103,16 @@ pub enum CreationMethod {
         create_branch: bool,
         /// Base branch for creation (resolved, validated to exist)
         base_branch: Option<String>,
+        /// True if creating an empty branch with `--orphan` (--orphan flag)
+        orphan: bool,
+    },
+    /// Tag checkout: `git worktree add --detach` at the tag's commit.
+    ///
+    /// These inspection worktrees have no branch of their own, so removing
+    /// them never deletes a ref.
+    Tag {
+        /// The tag name (e.g., "v2.3.1")
+        tag: String,
     },
     /// Fork PR/MR: fetch from refs/pull/N/head or refs/merge-requests/N/head,
     /// create branch, configure pushRemote.
@@ -86,6 +136,36 @@ pub enum CreationMethod {
     },
 }
 
+/// How `--new-path` checks out a branch that another worktree already has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedCheckout {
+    /// Detached HEAD at the branch's commit (--detach)
+    Detach,
+    /// A second checkout of the branch itself (--force)
+    Force,
+}
+
+/// Flags controlling how `plan_switch()` resolves and creates the target.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwitchTargetFlags<'a> {
+    /// Create a new branch (--create)
+    pub create: bool,
+    /// Base for the new branch (--base)
+    pub base: Option<&'a str>,
+    /// Create an empty branch with no history (--orphan)
+    pub orphan: bool,
+    /// Check out tags on an `inspect/<tag>` branch rather than detached (--branch-from-tag)
+    pub branch_from_tag: bool,
+    /// Create the worktree here instead of at the template path (--new-path)
+    pub new_path: Option<&'a Path>,
+    /// Allow `new_path` to check out a branch that has a worktree elsewhere
+    pub shared_checkout: Option<SharedCheckout>,
+    /// Allow a path outside `allowed-worktree-roots`, after confirmation (--allow-any-path)
+    pub allow_any_path: bool,
+    /// Worktree profile forced over pattern matching (--profile)
+    pub profile: Option<&'a str>,
+}
+
 /// Validated plan for a switch operation.
 ///
 /// Created by `plan_switch()`, consumed by `execute_switch()`.
@@ -99,6 +179,8 @@ pub enum SwitchPlan {
         branch: String,
         /// Branch to record as "previous" for `wt switch -`
         new_previous: Option<String>,
+        /// Worktree profile forced with `--profile`
+        profile: Option<String>,
     },
     /// Need to create a new worktree
     Create {
@@ -108,8 +190,12 @@ pub enum SwitchPlan {
         method: CreationMethod,
         /// If path exists and --clobber, this is the backup path to move it to
         clobber_backup: Option<PathBuf>,
+        /// `--detach` or `--force` for a branch checked out elsewhere
+        shared_checkout: Option<SharedCheckout>,
         /// Branch to record as "previous" for `wt switch -`
         new_previous: Option<String>,
+        /// Worktree profile forced with `--profile`
+        profile: Option<String>,
     },
 }
 
@@ -129,6 +215,15 @@ impl SwitchPlan {
         }
     }
 
+    /// The worktree profile forced with `--profile`, if any.
+    pub fn profile(&self) -> Option<&str> {
+        match self {
+            SwitchPlan::Existing { profile, .. } | SwitchPlan::Create { profile, .. } => {
+                profile.as_deref()
+            }
+        }
+    }
+
     /// Returns true if this plan will create a new worktree.
     pub fn is_create(&self) -> bool {
         matches!(self, SwitchPlan::Create { .. })
@@ -199,6 +294,8 @@ pub enum RemoveResult {
         /// Used for post-remove hook template variables so they reference the
         /// removed worktree's state, not the execution context.
         removed_commit: Option<String>,
+        /// Upstream to delete on its remote once the branch is deleted (`--delete-remote`).
+        remote_branch: Option<RemoteBranch>,
     },
     /// Branch exists but has no worktree - attempt branch deletion only.
     ///
@@ -209,9 +306,55 @@ pub enum RemoveResult {
         deletion_mode: BranchDeletionMode,
         /// True if the worktree was pruned before returning this result.
         pruned: bool,
+        /// Upstream to delete on its remote once the branch is deleted (`--delete-remote`).
+        remote_branch: Option<RemoteBranch>,
     },
 }
 
+impl RemoveResult {
+    /// Also delete the branch's upstream on its remote (`--delete-remote`).
+    ///
+    /// Does nothing for detached worktrees, kept branches, and branches without
+    /// an upstream.
+    pub fn add_remote_branch(&mut self, repo: &Repository) -> anyhow::Result<()> {
+        let (branch_name, deletion_mode, remote_branch) = match self {
+            Self::RemovedWorktree {
+                branch_name: Some(branch_name),
+                deletion_mode,
+                remote_branch,
+                ..
+            }
+            | Self::BranchOnly {
+                branch_name,
+                deletion_mode,
+                remote_branch,
+                ..
+            } => (branch_name, deletion_mode, remote_branch),
+            Self::RemovedWorktree { .. } => return Ok(()),
+        };
+        if !deletion_mode.should_keep() {
+            *remote_branch = RemoteBranch::of(repo, branch_name)?;
+        }
+        Ok(())
+    }
+
+    /// The upstream that will be deleted on its remote, if any.
+    pub fn remote_branch(&self) -> Option<&RemoteBranch> {
+        match self {
+            Self::RemovedWorktree { remote_branch, .. }
+            | Self::BranchOnly { remote_branch, .. } => remote_branch.as_ref(),
+        }
+    }
+
+    /// Keep the upstream after all (the user declined to delete it).
+    pub fn keep_remote_branch(&mut self) {
+        match self {
+            Self::RemovedWorktree { remote_branch, .. }
+            | Self::BranchOnly { remote_branch, .. } => *remote_branch = None,
+        }
+    }
+}
+
 /// Operation mode for worktree resolution - determines which checks are performed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OperationMode {
@@ -250,6 +393,7 @@ mod tests {
             base_branch: Some("main".to_string()),
             base_worktree_path: Some("/test/main".to_string()),
             from_remote: None,
+            facts: Vec::new(),
         };
         assert_eq!(result.path(), &path);
     }
@@ -263,6 +407,7 @@ mod tests {
             base_branch: None,
             base_worktree_path: None,
             from_remote: Some("origin/feature".to_string()),
+            facts: Vec::new(),
         };
         assert_eq!(result.path(), &path);
     }
@@ -320,6 +465,7 @@ mod tests {
             force_worktree: false,
             expected_path: None,
             removed_commit: Some("abc1234567890".to_string()),
+            remote_branch: None,
         };
         match result {
             RemoveResult::RemovedWorktree {
@@ -333,6 +479,7 @@ mod tests {
                 force_worktree,
                 expected_path,
                 removed_commit,
+                remote_branch,
             } => {
                 assert_eq!(main_path.to_str().unwrap(), "/main");
                 assert_eq!(worktree_path.to_str().unwrap(), "/worktree");
@@ -345,6 +492,7 @@ mod tests {
                 assert!(!force_worktree);
                 assert!(expected_path.is_none());
                 assert_eq!(removed_commit.as_deref(), Some("abc1234567890"));
+                assert!(remote_branch.is_none());
             }
             _ => panic!("Expected RemovedWorktree variant"),
         }
@@ -356,12 +504,14 @@ mod tests {
             branch_name: "stale-branch".to_string(),
             deletion_mode: BranchDeletionMode::Keep,
             pruned: false,
+            remote_branch: None,
         };
         match result {
             RemoveResult::BranchOnly {
                 branch_name,
                 deletion_mode,
                 pruned,
+                ..
             } => {
                 assert_eq!(branch_name, "stale-branch");
                 assert!(deletion_mode.should_keep());
@@ -378,12 +528,14 @@ mod tests {
             branch_name: "pruned-branch".to_string(),
             deletion_mode: BranchDeletionMode::SafeDelete,
             pruned: true,
+            remote_branch: None,
         };
         match result {
             RemoveResult::BranchOnly {
                 branch_name,
                 deletion_mode,
                 pruned,
+                ..
             } => {
                 assert_eq!(branch_name, "pruned-branch");
                 assert!(!deletion_mode.should_keep());
@@ -406,6 +558,7 @@ mod tests {
             force_worktree: true,
             expected_path: None,
             removed_commit: None, // Detached HEAD may not have meaningful commit
+            remote_branch: None,
         };
         match result {
             RemoveResult::RemovedWorktree {
@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 use std::ffi::{OsStr, OsString};
 use std::io::Write;
+use std::path::Path;
 
 use clap::Command;
 use clap_complete::engine::{ArgValueCompleter, CompletionCandidate, ValueCompleter};
@@ -11,6 +12,9 @@ use crate::display::format_relative_time_short;
 use worktrunk::config::{ProjectConfig, UserConfig};
 use worktrunk::git::{BranchCategory, HookType, Repository};
 
+/// Hidden subcommand for dynamic completion, handled before clap parses arguments.
+const COMPLETE_SUBCOMMAND: &str = "__complete";
+
 /// Handle shell-initiated completion requests via `COMPLETE=$SHELL wt`
 pub(crate) fn maybe_handle_env_completion() -> bool {
     let Some(shell_name) = std::env::var_os("COMPLETE") else {
@@ -45,6 +49,47 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
         return true;
     }
 
+    write_completions(&shell_name.to_string_lossy(), args, current_dir.as_deref());
+
+    CONTEXT.with(|ctx| ctx.borrow_mut().take());
+    true
+}
+
+/// Handle `wt __complete <shell> -- <words>...`
+///
+/// A hidden entry point speaking the same protocol as `COMPLETE=$SHELL wt -- <words>`,
+/// for callers that can't set environment variables. `<words>` starts with the binary
+/// name; the word under the cursor is `_CLAP_COMPLETE_INDEX`, or the last word.
+pub(crate) fn maybe_handle_complete_subcommand() -> bool {
+    let mut argv = std::env::args_os().skip(1);
+    if argv.next().as_deref() != Some(OsStr::new(COMPLETE_SUBCOMMAND)) {
+        return false;
+    }
+
+    let Some(shell_name) = argv.next() else {
+        return true;
+    };
+    let mut words: Vec<OsString> = argv.collect();
+    if words.first().is_some_and(|w| w == "--") {
+        words.remove(0);
+    }
+    if words.is_empty() {
+        return true;
+    }
+
+    CONTEXT.with(|ctx| {
+        *ctx.borrow_mut() = Some(CompletionContext {
+            args: words.clone(),
+        })
+    });
+    let current_dir = std::env::current_dir().ok();
+    write_completions(&shell_name.to_string_lossy(), words, current_dir.as_deref());
+    CONTEXT.with(|ctx| ctx.borrow_mut().take());
+    true
+}
+
+/// Complete `args` (binary name first) and write candidates in `shell_name`'s format.
+fn write_completions(shell_name: &str, args: Vec<OsString>, current_dir: Option<&Path>) {
     // Generate completions with filtering
     let mut cmd = completion_command();
     cmd.build();
@@ -63,18 +108,11 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
     let current_word = args.get(index).map(|s| s.to_string_lossy());
     let include_long_flags = current_word.as_deref() == Some("-");
 
-    let completions = match clap_complete::engine::complete(
-        &mut cmd,
-        args.clone(),
-        index,
-        current_dir.as_deref(),
-    ) {
-        Ok(c) => c,
-        Err(_) => {
-            CONTEXT.with(|ctx| ctx.borrow_mut().take());
-            return true;
-        }
-    };
+    let completions =
+        match clap_complete::engine::complete(&mut cmd, args.clone(), index, current_dir) {
+            Ok(c) => c,
+            Err(_) => return,
+        };
 
     // If single dash, also get completions for "--" and merge
     let completions = if include_long_flags {
@@ -85,12 +123,9 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
         }
         let mut cmd2 = completion_command();
         cmd2.build();
-        if let Ok(long_completions) = clap_complete::engine::complete(
-            &mut cmd2,
-            args_with_double_dash,
-            index,
-            current_dir.as_deref(),
-        ) {
+        if let Ok(long_completions) =
+            clap_complete::engine::complete(&mut cmd2, args_with_double_dash, index, current_dir)
+        {
             // Add long flags that aren't already present (avoid duplicates)
             for candidate in long_completions {
                 let value = candidate.get_value();
@@ -105,13 +140,12 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
     };
 
     // Write completions in the appropriate format for the shell
-    let shell_name = shell_name.to_string_lossy();
     let ifs = std::env::var("_CLAP_IFS").ok();
     let separator = ifs.as_deref().unwrap_or("\n");
 
     // Shell-specific separator between value and description
     // zsh uses ":", fish/nushell use "\t", bash doesn't support descriptions
-    let help_sep = match shell_name.as_ref() {
+    let help_sep = match shell_name {
         "zsh" => Some(":"),
         "fish" | "nu" => Some("\t"),
         _ => None,
@@ -132,9 +166,6 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
             }
         }
     }
-
-    CONTEXT.with(|ctx| ctx.borrow_mut().take());
-    true
 }
 
 /// Branch completion without additional context filtering (e.g., --base, merge target).
@@ -367,8 +398,27 @@ thread_local! {
 }
 
 fn completion_command() -> Command {
-    let cmd = cli::build_command();
-    hide_non_positional_options_for_completion(cmd)
+    let cmd = hide_non_positional_options_for_completion(cli::build_command());
+    add_alias_subcommands(cmd)
+}
+
+/// Offer `[alias]` names alongside the built-in subcommands.
+fn add_alias_subcommands(mut cmd: Command) -> Command {
+    let Ok(config) = UserConfig::load() else {
+        return cmd;
+    };
+    let builtins = cli::builtin_subcommands();
+    for (name, expansion) in config.alias {
+        if builtins.contains(&name) {
+            continue;
+        }
+        cmd = cmd.subcommand(
+            Command::new(name)
+                .about(format!("Alias for `wt {expansion}`"))
+                .disable_help_flag(true),
+        );
+    }
+    cmd
 }
 
 /// Hide non-positional options so they're filtered out when positional/subcommand
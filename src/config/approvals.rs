@@ -187,7 +187,7 @@ impl Approvals {
             output
         };
 
-        std::fs::write(path, output)
+        super::state_file::write_atomic(path, output)
             .map_err(|e| ConfigError::Message(format!("Failed to write approvals file: {e}")))?;
 
         Ok(())
@@ -67,6 +67,14 @@ const DEPRECATED_VARS: &[(&str, &str)] = &[
     ("main_worktree_path", "primary_worktree_path"),
 ];
 
+/// Replacement for a deprecated template variable name, if it is one.
+pub fn deprecated_var_replacement(name: &str) -> Option<&'static str> {
+    DEPRECATED_VARS
+        .iter()
+        .find(|&&(old, _)| old == name)
+        .map(|&(_, new)| new)
+}
+
 /// Top-level section keys that are deprecated and handled separately.
 /// Callers should filter these out before calling `warn_unknown_fields` to avoid duplicate warnings.
 pub const DEPRECATED_SECTION_KEYS: &[&str] = &["commit-generation", "select"];
@@ -568,7 +576,7 @@ pub fn migrate_select_to_switch_picker(content: &str) -> String {
 ///
 /// Returns `Some(path)` if approvals.toml was created, `None` if it already
 /// existed or there was nothing to copy.
-fn copy_approved_commands_to_approvals_file(config_path: &Path) -> Option<PathBuf> {
+pub(super) fn copy_approved_commands_to_approvals_file(config_path: &Path) -> Option<PathBuf> {
     let approvals_path = config_path.with_file_name("approvals.toml");
     if approvals_path.exists() {
         return None; // Already authoritative, don't overwrite
@@ -659,7 +667,7 @@ impl DeprecationInfo {
     }
 }
 
-fn migration_path(path: &Path) -> PathBuf {
+pub(super) fn migration_path(path: &Path) -> PathBuf {
     // config.toml -> config.toml.new
     // config -> config.new
     match path.extension() {
@@ -834,8 +842,27 @@ pub fn write_migration_file(
     repo: Option<&crate::git::Repository>,
 ) -> Option<PathBuf> {
     let new_path = migration_path(path);
+    let new_content = apply_deprecation_fixes(content, deprecations);
+
+    if let Err(e) = std::fs::write(&new_path, &new_content) {
+        // Log write failure but don't block config loading
+        log::warn!("Could not write migration file: {}", e);
+        return None;
+    }
 
-    // Apply all migrations to generate new content
+    // Mark hint as shown for project config
+    if let Some(repo) = repo {
+        let _ = repo.mark_hint_shown(HINT_DEPRECATED_CONFIG);
+    }
+
+    Some(new_path)
+}
+
+/// Apply all fixes for the detected deprecations, returning the new content.
+///
+/// Pure function; `approved_commands` removal relies on the caller having copied
+/// them to approvals.toml first.
+pub(super) fn apply_deprecation_fixes(content: &str, deprecations: &Deprecations) -> String {
     let mut new_content = content.to_string();
     if !deprecations.vars.is_empty() {
         new_content = replace_deprecated_vars(&new_content);
@@ -849,19 +876,7 @@ pub fn write_migration_file(
     if deprecations.select {
         new_content = migrate_select_to_switch_picker(&new_content);
     }
-
-    if let Err(e) = std::fs::write(&new_path, &new_content) {
-        // Log write failure but don't block config loading
-        log::warn!("Could not write migration file: {}", e);
-        return None;
-    }
-
-    // Mark hint as shown for project config
-    if let Some(repo) = repo {
-        let _ = repo.mark_hint_shown(HINT_DEPRECATED_CONFIG);
-    }
-
-    Some(new_path)
+    new_content
 }
 
 /// Format the diff between original and migrated config files as a string
@@ -1052,6 +1067,17 @@ pub fn warn_unknown_fields<C: WorktrunkConfig>(
                     "{label} has key <bold>{key}</> which belongs in {other_location} (will be ignored)"
                 ))
             );
+        } else if super::is_unknown_hook_name(key) {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "{label} has unknown hook <bold>{key}</> (will be ignored)"
+                ))
+            );
+            eprintln!(
+                "{}",
+                hint_message(format!("Hooks are {}", super::hook_type_names()))
+            );
         } else {
             eprintln!(
                 "{}",
@@ -46,6 +46,7 @@ pub const TEMPLATE_VARS: &[&str] = &[
     "target",    // Added by merge/rebase hooks via extra_vars
     "base",      // Added by creation hooks via extra_vars
     "base_worktree_path", // Added by creation hooks via extra_vars
+    "worktree_index", // Added by [git-config] expansion
 ];
 
 /// Deprecated template variable aliases (still valid for backward compatibility).
@@ -323,6 +324,31 @@ pub fn expand_template(
     shell_escape: bool,
     repo: &Repository,
     name: &str,
+) -> Result<String, TemplateExpandError> {
+    render_template(template, vars, shell_escape, false, repo, name)
+}
+
+/// Expand a `worktree-path` template, optionally lowercasing `sanitize` output.
+///
+/// Lowercasing keeps `Feature/Login` and `feature/login` from mapping to
+/// directories that collide on case-insensitive filesystems.
+pub fn expand_path_template(
+    template: &str,
+    vars: &HashMap<&str, &str>,
+    lowercase_slugs: bool,
+    repo: &Repository,
+    name: &str,
+) -> Result<String, TemplateExpandError> {
+    render_template(template, vars, false, lowercase_slugs, repo, name)
+}
+
+fn render_template(
+    template: &str,
+    vars: &HashMap<&str, &str>,
+    shell_escape: bool,
+    lowercase_slugs: bool,
+    repo: &Repository,
+    name: &str,
 ) -> Result<String, TemplateExpandError> {
     // Build context map with raw values (shell escaping is applied at output time via formatter)
     let mut context = HashMap::new();
@@ -358,8 +384,13 @@ pub fn expand_template(
     }
 
     // Register custom filters
-    env.add_filter("sanitize", |value: Value| -> String {
-        sanitize_branch_name(value.as_str().unwrap_or_default())
+    env.add_filter("sanitize", move |value: Value| -> String {
+        let slug = sanitize_branch_name(value.as_str().unwrap_or_default());
+        if lowercase_slugs {
+            slug.to_lowercase()
+        } else {
+            slug
+        }
     });
     env.add_filter("sanitize_db", |value: Value| -> String {
         sanitize_db(value.as_str().unwrap_or_default())
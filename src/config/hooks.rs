@@ -5,6 +5,21 @@ use crate::git::HookType;
 
 use super::commands::CommandConfig;
 
+/// Whether an unknown config key was meant as a hook: it reads like one
+/// (`pre-…` or `post-…`) but names no hook type.
+pub fn is_unknown_hook_name(key: &str) -> bool {
+    (key.starts_with("pre-") || key.starts_with("post-")) && key.parse::<HookType>().is_err()
+}
+
+/// Hook type names in lifecycle order, comma-separated, for messages.
+pub fn hook_type_names() -> String {
+    use strum::IntoEnumIterator;
+    HookType::iter()
+        .map(|hook| hook.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Shared hook configuration for user and project configs.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
 pub struct HooksConfig {
@@ -91,6 +106,24 @@ impl HooksConfig {
             HookType::PostRemove => self.post_remove.as_ref(),
         }
     }
+
+    /// Hooks with each type set in `overrides` replacing this config's entry.
+    pub fn overridden_by(&self, overrides: &Self) -> Self {
+        let pick = |base: &Option<CommandConfig>, overlay: &Option<CommandConfig>| {
+            overlay.clone().or_else(|| base.clone())
+        };
+        Self {
+            pre_switch: pick(&self.pre_switch, &overrides.pre_switch),
+            post_create: pick(&self.post_create, &overrides.post_create),
+            post_start: pick(&self.post_start, &overrides.post_start),
+            post_switch: pick(&self.post_switch, &overrides.post_switch),
+            pre_commit: pick(&self.pre_commit, &overrides.pre_commit),
+            pre_merge: pick(&self.pre_merge, &overrides.pre_merge),
+            post_merge: pick(&self.post_merge, &overrides.post_merge),
+            pre_remove: pick(&self.pre_remove, &overrides.pre_remove),
+            post_remove: pick(&self.post_remove, &overrides.post_remove),
+        }
+    }
 }
 
 use super::user::Merge;
@@ -19,9 +19,11 @@ mod deprecation;
 mod expansion;
 mod hooks;
 mod project;
+pub mod state_file;
 #[cfg(test)]
 mod test;
 mod user;
+mod version;
 
 /// Trait for worktrunk config types (user and project config).
 ///
@@ -76,6 +78,7 @@ pub use commands::{Command, CommandConfig};
 pub use deprecation::DeprecationInfo;
 pub use deprecation::Deprecations;
 pub use deprecation::check_and_migrate;
+pub use deprecation::deprecated_var_replacement;
 pub use deprecation::detect_deprecations;
 pub use deprecation::format_brief_warning;
 pub use deprecation::format_deprecation_details;
@@ -88,18 +91,22 @@ pub use expansion::{
     DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS, TemplateExpandError, expand_template,
     redact_credentials, sanitize_branch_name, sanitize_db, short_hash,
 };
-pub use hooks::HooksConfig;
+pub use hooks::{HooksConfig, hook_type_names, is_unknown_hook_name};
 pub use project::{
-    ProjectCiConfig, ProjectConfig, ProjectListConfig,
+    BranchNameRules, IdentityRules, NameCase, NameRuleViolation, OnSwitchEntry, ProjectCiConfig,
+    ProjectConfig, ProjectListConfig, WorktreeProfile,
     find_unknown_keys as find_unknown_project_keys,
 };
 pub use user::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, OverridableConfig,
-    ResolvedConfig, SelectConfig, StageMode, SwitchConfig, SwitchPickerConfig, UserConfig,
-    UserProjectOverrides, default_config_path, default_system_config_path,
+    ActivityConfig, ActivityOnRemove, CommitConfig, CommitGenerationConfig, HintsConfig,
+    IntegrationsConfig, KeysConfig, ListConfig, ListDensity, ListLayout, MergeConfig,
+    OverridableConfig, ResolvedConfig, ScratchConfig, SelectConfig, SlugLowercase, StageMode,
+    SwitchConfig, SwitchPickerConfig, ThemeBase, ThemeConfig, UserConfig, UserProjectOverrides,
+    acquire_config_lock, default_config_path, default_system_config_path,
     find_unknown_keys as find_unknown_user_keys, get_config_path, get_system_config_path,
     set_config_path,
 };
+pub use version::{CONFIG_VERSION, MigrateResult, config_version, migrate_file};
 
 #[cfg(test)]
 mod tests {
@@ -298,6 +305,87 @@ mod tests {
         );
     }
 
+    fn slug_config(slug_lowercase: SlugLowercase) -> UserConfig {
+        UserConfig {
+            configs: OverridableConfig {
+                worktree_path: Some("{{ main_worktree }}.{{ branch | sanitize }}".to_string()),
+                slug_lowercase: Some(slug_lowercase),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_format_worktree_path_slug_lowercase() {
+        let test = test_repo();
+        let format = |mode| {
+            slug_config(mode)
+                .format_path("myproject", "Feature/Login", &test.repo, None)
+                .unwrap()
+        };
+        assert_eq!(format(SlugLowercase::Never), "myproject.Feature-Login");
+        assert_eq!(format(SlugLowercase::Always), "myproject.feature-login");
+    }
+
+    #[test]
+    fn test_format_worktree_path_slug_lowercase_leaves_raw_branch() {
+        let test = test_repo();
+        // Only `sanitize` output is lowercased
+        let mut config = slug_config(SlugLowercase::Always);
+        config.configs.worktree_path = Some("{{ main_worktree }}.{{ branch }}".to_string());
+        assert_eq!(
+            config
+                .format_path("myproject", "Feature/Login", &test.repo, None)
+                .unwrap(),
+            "myproject.Feature/Login"
+        );
+    }
+
+    #[test]
+    #[cfg(any(target_os = "macos", windows))]
+    fn test_format_worktree_path_slug_lowercase_auto_insensitive() {
+        let test = test_repo();
+        assert_eq!(
+            slug_config(SlugLowercase::Auto)
+                .format_path("myproject", "Feature/Login", &test.repo, None)
+                .unwrap(),
+            "myproject.feature-login"
+        );
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_format_worktree_path_slug_lowercase_auto_sensitive() {
+        let test = test_repo();
+        assert_eq!(
+            slug_config(SlugLowercase::Auto)
+                .format_path("myproject", "Feature/Login", &test.repo, None)
+                .unwrap(),
+            "myproject.Feature-Login"
+        );
+    }
+
+    #[test]
+    fn test_slug_lowercase_project_override() {
+        let toml = r#"
+slug-lowercase = "auto"
+
+[projects."github.com/user/repo"]
+slug-lowercase = "never"
+"#;
+        let config: UserConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.slug_lowercase(None), SlugLowercase::Auto);
+        assert_eq!(
+            config.slug_lowercase(Some("github.com/user/repo")),
+            SlugLowercase::Never
+        );
+        assert_eq!(
+            UserConfig::default().slug_lowercase(None),
+            SlugLowercase::Never
+        );
+    }
+
     #[test]
     fn test_command_config_single() {
         let toml = r#"post-create = "npm install""#;
@@ -746,6 +834,16 @@ test = "cargo test"
         );
     }
 
+    #[test]
+    fn test_is_unknown_hook_name() {
+        assert!(is_unknown_hook_name("pre-sync"));
+        assert!(is_unknown_hook_name("post-merge-command"));
+        assert!(!is_unknown_hook_name("pre-merge"));
+        assert!(!is_unknown_hook_name("post-remove"));
+        assert!(!is_unknown_hook_name("worktree-path"));
+        assert!(hook_type_names().starts_with("pre-switch, post-create, post-start"));
+    }
+
     #[test]
     fn test_user_config_key_in_project_config_is_detected() {
         // commit-generation is a user-config-only key
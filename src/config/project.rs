@@ -38,13 +38,15 @@ pub struct ProjectListConfig {
 /// Project-level CI configuration.
 ///
 /// Override CI platform detection when URL-based detection fails (e.g., GitHub
-/// Enterprise or self-hosted GitLab with custom domains).
+/// Enterprise or self-hosted GitLab with custom domains), and choose whether
+/// the default branch's row shows CI.
 ///
 /// # Example
 ///
 /// ```toml
 /// [ci]
 /// platform = "github"  # or "gitlab"
+/// include-default-branch = false
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
 pub struct ProjectCiConfig {
@@ -53,6 +55,268 @@ pub struct ProjectCiConfig {
     /// Values: "github" or "gitlab"
     #[serde(default)]
     pub platform: Option<String>,
+
+    /// Show the default branch's CI, from the checks on its head commit.
+    ///
+    /// Default: true
+    #[serde(
+        default,
+        rename = "include-default-branch",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub include_default_branch: Option<bool>,
+}
+
+/// A command offered after `wt switch` lands on a matching branch.
+///
+/// Entries are checked in order; every match is offered. An explicit `--execute`
+/// takes precedence, and `--no-exec` skips them for one invocation.
+///
+/// # Example
+///
+/// ```toml
+/// [[on-switch]]
+/// pattern = "feature/*"
+/// run = "npm run dev"
+///
+/// [[on-switch]]
+/// pattern = "rust/*"
+/// run = "cargo watch -x check"
+/// auto = true
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct OnSwitchEntry {
+    /// Branch glob. `*` matches any run of characters (including `/`), `?` matches one.
+    pub pattern: String,
+
+    /// Command template, expanded with the same variables as `--execute`.
+    pub run: String,
+
+    /// Run without asking. The command still needs project-command approval once.
+    #[serde(default)]
+    pub auto: bool,
+}
+
+/// Per-branch overrides for creating and working in a worktree.
+///
+/// The first profile whose `pattern` matches the branch applies; `wt switch
+/// --profile <name>` picks one by name instead. Unset fields fall back to the
+/// top-level settings.
+///
+/// # Example
+///
+/// ```toml
+/// [[worktree-profiles]]
+/// name = "hotfix"
+/// pattern = "hotfix/*"
+/// worktree-path = "/scratch/{{ repo }}.{{ branch | sanitize }}"
+/// post-create = {}
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct WorktreeProfile {
+    /// Profile name, used by `wt switch --profile`.
+    pub name: String,
+
+    /// Branch glob, with the same syntax as `[[on-switch]]` patterns.
+    /// Profiles without a pattern only apply when selected by name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Worktree path template, replacing the user's `worktree-path`.
+    #[serde(
+        default,
+        rename = "worktree-path",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub worktree_path: Option<String>,
+
+    /// Hooks replacing the top-level project hook of the same type.
+    /// An empty table (`post-create = {}`) skips that hook.
+    #[serde(flatten, default)]
+    pub hooks: HooksConfig,
+}
+
+/// Naming rules for new branches, checked by `wt switch --create` and
+/// `wt step graduate` before anything is created.
+///
+/// # Example
+///
+/// ```toml
+/// [branch-name-rules]
+/// allow = ["^(feature|fix|chore)/"]
+/// deny = ["(?i)wip"]
+/// max-length = 50
+/// case = "lower"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct BranchNameRules {
+    /// Regexes a name must match at least one of. Empty allows any name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+
+    /// Regexes a name must not match.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+
+    /// Longest allowed name, in characters.
+    #[serde(
+        default,
+        rename = "max-length",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_length: Option<usize>,
+
+    /// Letter case names must use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case: Option<NameCase>,
+}
+
+/// Letter case rule for [`BranchNameRules`].
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NameCase {
+    /// No uppercase letters
+    Lower,
+}
+
+/// A branch name that breaks one of the [`BranchNameRules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameRuleViolation {
+    /// The broken rule as written in config, e.g. `max-length = 50`
+    pub rule: String,
+    /// A fixed-up name that passes every rule, when there is one
+    pub suggestion: Option<String>,
+}
+
+impl BranchNameRules {
+    /// Check `name`, reporting the first rule it breaks.
+    ///
+    /// Rules are checked in order: case, length, deny, allow. An invalid regex
+    /// is an error rather than a violation.
+    pub fn check(&self, name: &str) -> anyhow::Result<Option<NameRuleViolation>> {
+        let Some(rule) = self.broken_rule(name)? else {
+            return Ok(None);
+        };
+        let fixed = self.fix(name);
+        let suggestion = (fixed != name && self.broken_rule(&fixed)?.is_none()).then_some(fixed);
+        Ok(Some(NameRuleViolation { rule, suggestion }))
+    }
+
+    fn broken_rule(&self, name: &str) -> anyhow::Result<Option<String>> {
+        if self.case == Some(NameCase::Lower) && name.chars().any(char::is_uppercase) {
+            return Ok(Some(r#"case = "lower""#.to_string()));
+        }
+        if let Some(max) = self.max_length
+            && name.chars().count() > max
+        {
+            return Ok(Some(format!("max-length = {max}")));
+        }
+        for pattern in &self.deny {
+            if compile_rule(pattern)?.is_match(name) {
+                return Ok(Some(format!("deny = [{pattern:?}]")));
+            }
+        }
+        if self.allow.is_empty() {
+            return Ok(None);
+        }
+        for pattern in &self.allow {
+            if compile_rule(pattern)?.is_match(name) {
+                return Ok(None);
+            }
+        }
+        let allow: Vec<String> = self.allow.iter().map(|p| format!("{p:?}")).collect();
+        Ok(Some(format!("allow = [{}]", allow.join(", "))))
+    }
+
+    /// The mechanical fixes: whitespace runs become dashes, letters follow
+    /// the case rule, and the name is cut to the length limit.
+    fn fix(&self, name: &str) -> String {
+        let mut fixed = name.split_whitespace().collect::<Vec<_>>().join("-");
+        if self.case == Some(NameCase::Lower) {
+            fixed = fixed.to_lowercase();
+        }
+        if let Some(max) = self.max_length {
+            fixed = fixed.chars().take(max).collect();
+            fixed.truncate(fixed.trim_end_matches(['-', '/', '.']).len());
+        }
+        fixed
+    }
+}
+
+fn compile_rule(pattern: &str) -> anyhow::Result<regex::Regex> {
+    regex::Regex::new(pattern)
+        .map_err(|e| anyhow::anyhow!("Invalid regex {pattern:?} in [branch-name-rules]: {e}"))
+}
+
+/// Git identity required in new worktrees, checked right after `wt switch`
+/// creates one and before its hooks run.
+///
+/// # Example
+///
+/// ```toml
+/// [identity]
+/// require-email-pattern = "@example\\.com$"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct IdentityRules {
+    /// Regex the `user.email` resolved in a new worktree must match.
+    #[serde(
+        default,
+        rename = "require-email-pattern",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub require_email_pattern: Option<String>,
+}
+
+impl IdentityRules {
+    /// Whether `email` passes the rules. A missing email fails any pattern.
+    pub fn allows(&self, email: Option<&str>) -> anyhow::Result<bool> {
+        let Some(pattern) = &self.require_email_pattern else {
+            return Ok(true);
+        };
+        let regex = regex::Regex::new(pattern).map_err(|e| {
+            anyhow::anyhow!("Invalid regex {pattern:?} in [identity] require-email-pattern: {e}")
+        })?;
+        Ok(email.is_some_and(|email| regex.is_match(email)))
+    }
+}
+
+impl OnSwitchEntry {
+    /// Whether `branch` matches this entry's pattern.
+    pub fn matches(&self, branch: &str) -> bool {
+        glob_match(&self.pattern, branch)
+    }
+}
+
+/// Match `text` against a glob supporting `*` (any run, including empty) and `?` (one char).
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it was tried at, for backtracking
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 impl ProjectListConfig {
@@ -67,6 +331,59 @@ impl ProjectConfig {
     pub fn ci_platform(&self) -> Option<&str> {
         self.ci.as_ref().and_then(|ci| ci.platform.as_deref())
     }
+
+    /// Whether the default branch's row shows CI (default true).
+    pub fn ci_include_default_branch(&self) -> bool {
+        self.ci
+            .as_ref()
+            .and_then(|ci| ci.include_default_branch)
+            .unwrap_or(true)
+    }
+
+    /// The worktree profile that applies to `branch`.
+    ///
+    /// A profile forced by name (`wt switch --profile`) wins; otherwise the
+    /// first profile whose pattern matches.
+    pub fn profile_for(
+        &self,
+        branch: Option<&str>,
+        forced: Option<&str>,
+    ) -> Option<&WorktreeProfile> {
+        if let Some(name) = forced {
+            return self.profile_named(name);
+        }
+        let branch = branch?;
+        self.worktree_profiles.iter().find(|profile| {
+            profile
+                .pattern
+                .as_deref()
+                .is_some_and(|pattern| glob_match(pattern, branch))
+        })
+    }
+
+    /// The worktree profile called `name`, if any.
+    pub fn profile_named(&self, name: &str) -> Option<&WorktreeProfile> {
+        self.worktree_profiles
+            .iter()
+            .find(|profile| profile.name == name)
+    }
+
+    /// This config with the profile for `branch` (or the `forced` one)
+    /// applied to its hooks.
+    pub fn for_branch(&self, branch: Option<&str>, forced: Option<&str>) -> ProjectConfig {
+        let mut config = self.clone();
+        if let Some(profile) = self.profile_for(branch, forced) {
+            config.hooks = self.hooks.overridden_by(&profile.hooks);
+        }
+        config
+    }
+
+    /// `[[on-switch]]` entries whose pattern matches `branch`, in config order.
+    pub fn on_switch_for<'a>(&'a self, branch: &'a str) -> impl Iterator<Item = &'a OnSwitchEntry> {
+        self.on_switch
+            .iter()
+            .filter(move |entry| entry.matches(branch))
+    }
 }
 
 /// Project-specific configuration with hooks.
@@ -99,6 +416,10 @@ impl ProjectConfig {
 /// - `{{ branch | hash_port }}` - Hash string to deterministic port (10000-19999)
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
 pub struct ProjectConfig {
+    /// Config format version (see `wt config migrate`). Absent means version 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+
     /// Project hooks (same keys as user hooks, flattened at top level)
     #[serde(flatten, default)]
     pub hooks: HooksConfig,
@@ -123,10 +444,80 @@ pub struct ProjectConfig {
     /// ```
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub aliases: Option<BTreeMap<String, String>>,
+
+    /// Commands offered after `wt switch` lands on a matching branch.
+    ///
+    /// ```toml
+    /// [[on-switch]]
+    /// pattern = "feature/*"
+    /// run = "npm run dev"
+    /// auto = true
+    /// ```
+    #[serde(default, rename = "on-switch", skip_serializing_if = "Vec::is_empty")]
+    pub on_switch: Vec<OnSwitchEntry>,
+
+    /// Per-branch overrides for worktree location and hooks.
+    ///
+    /// ```toml
+    /// [[worktree-profiles]]
+    /// name = "hotfix"
+    /// pattern = "hotfix/*"
+    /// worktree-path = "/scratch/{{ repo }}.{{ branch | sanitize }}"
+    /// post-create = {}
+    /// ```
+    #[serde(
+        default,
+        rename = "worktree-profiles",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub worktree_profiles: Vec<WorktreeProfile>,
+
+    /// Naming rules for new branches.
+    ///
+    /// ```toml
+    /// [branch-name-rules]
+    /// allow = ["^(feature|fix|chore)/"]
+    /// case = "lower"
+    /// ```
+    #[serde(
+        default,
+        rename = "branch-name-rules",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub branch_name_rules: Option<BranchNameRules>,
+
+    /// Git identity required in new worktrees.
+    ///
+    /// ```toml
+    /// [identity]
+    /// require-email-pattern = "@example\\.com$"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<IdentityRules>,
+
+    /// Git config set in each new worktree only, with `git config --worktree`.
+    ///
+    /// Keys are quoted so TOML doesn't read the dots as nested tables. Values
+    /// are templates, with the hook variables plus `{{ worktree_index }}`.
+    ///
+    /// ```toml
+    /// [git-config]
+    /// "pull.rebase" = "true"
+    /// "core.untrackedCache" = "true"
+    /// ```
+    #[serde(
+        default,
+        rename = "git-config",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub git_config: Option<BTreeMap<String, String>>,
 }
 
 impl ProjectConfig {
-    /// Load project configuration from .config/wt.toml in the repository root
+    /// Load project configuration from .config/wt.toml
+    ///
+    /// Uses the current worktree's file, falling back to the primary worktree's
+    /// (see [`Repository::project_config_path`](crate::git::Repository::project_config_path)).
     ///
     /// Set `write_hints` to true for normal usage. Set to false during completion
     /// to avoid side effects (writing git config hints).
@@ -134,11 +525,9 @@ impl ProjectConfig {
         repo: &crate::git::Repository,
         write_hints: bool,
     ) -> Result<Option<Self>, ConfigError> {
-        let repo_root = repo
-            .current_worktree()
-            .root()
+        let config_path = repo
+            .project_config_path()
             .map_err(|e| ConfigError::Message(format!("Failed to get worktree root: {}", e)))?;
-        let config_path = repo_root.join(".config").join("wt.toml");
 
         if !config_path.exists() {
             return Ok(None);
@@ -175,6 +564,8 @@ impl ProjectConfig {
             );
         }
 
+        // Older config versions load through their upgraded form
+        let contents = super::version::upgrade_in_memory(&contents, "Project config")?;
         let config: ProjectConfig = toml::from_str(&contents)
             .map_err(|e| ConfigError::Message(format!("Failed to parse TOML: {}", e)))?;
 
@@ -242,6 +633,110 @@ pre-remove = "echo bye"
         assert!(config.hooks.pre_remove.is_some());
     }
 
+    #[test]
+    fn test_branch_name_rules() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+[branch-name-rules]
+allow = ["^(feature|fix|chore)/"]
+deny = ["(?i)wip"]
+max-length = 20
+case = "lower"
+"#,
+        )
+        .unwrap();
+        let rules = config.branch_name_rules.unwrap();
+        let check = |name| rules.check(name).unwrap();
+
+        assert_eq!(check("feature/login"), None);
+        assert_eq!(
+            check("Feature/Login"),
+            Some(NameRuleViolation {
+                rule: r#"case = "lower""#.to_string(),
+                suggestion: Some("feature/login".to_string()),
+            })
+        );
+        assert_eq!(
+            check("fix/a very long description"),
+            Some(NameRuleViolation {
+                rule: "max-length = 20".to_string(),
+                suggestion: Some("fix/a-very-long-desc".to_string()),
+            })
+        );
+        // No mechanical fix for a missing prefix or a denied word
+        assert_eq!(
+            check("login"),
+            Some(NameRuleViolation {
+                rule: r#"allow = ["^(feature|fix|chore)/"]"#.to_string(),
+                suggestion: None,
+            })
+        );
+        assert_eq!(check("chore/wip").unwrap().rule, r#"deny = ["(?i)wip"]"#);
+
+        let invalid = BranchNameRules {
+            deny: vec!["(".to_string()],
+            ..Default::default()
+        };
+        assert!(invalid.check("main").is_err());
+    }
+
+    #[test]
+    fn test_worktree_profile_precedence() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+post-create = "npm install"
+pre-merge = "cargo test"
+
+[[worktree-profiles]]
+name = "manual"
+worktree-path = "/manual/{{ branch }}"
+
+[[worktree-profiles]]
+name = "hotfix"
+pattern = "hotfix/*"
+worktree-path = "/scratch/{{ branch | sanitize }}"
+post-create = {}
+
+[[worktree-profiles]]
+name = "any"
+pattern = "*"
+"#,
+        )
+        .unwrap();
+
+        // First matching pattern wins; profiles without a pattern never match
+        let name = |branch| {
+            config
+                .profile_for(Some(branch), None)
+                .map(|p| p.name.as_str())
+        };
+        assert_eq!(name("hotfix/x"), Some("hotfix"));
+        assert_eq!(name("feature"), Some("any"));
+        assert_eq!(config.profile_for(None, None), None);
+        // A forced profile wins over pattern matching
+        assert_eq!(
+            config
+                .profile_for(Some("hotfix/x"), Some("manual"))
+                .map(|p| p.name.as_str()),
+            Some("manual")
+        );
+        assert_eq!(
+            config
+                .profile_named("manual")
+                .unwrap()
+                .worktree_path
+                .as_deref(),
+            Some("/manual/{{ branch }}")
+        );
+
+        // Profile hooks replace the matching top-level hook; others are kept
+        let hotfix = config.for_branch(Some("hotfix/x"), None);
+        assert!(hotfix.hooks.post_create.unwrap().commands().is_empty());
+        assert!(hotfix.hooks.pre_merge.is_some());
+        let feature = config.for_branch(Some("feature"), None);
+        assert_eq!(feature.hooks.post_create, config.hooks.post_create);
+    }
+
     // ============================================================================
     // ListConfig Tests
     // ============================================================================
@@ -302,6 +797,16 @@ platform = "gitlab"
         assert_eq!(ci.platform.as_deref(), Some("gitlab"));
     }
 
+    #[test]
+    fn test_deserialize_ci_include_default_branch() {
+        let config: ProjectConfig =
+            toml::from_str("[ci]\ninclude-default-branch = false\n").unwrap();
+        assert!(!config.ci_include_default_branch());
+
+        let config: ProjectConfig = toml::from_str("[ci]\nplatform = \"github\"\n").unwrap();
+        assert!(config.ci_include_default_branch());
+    }
+
     #[test]
     fn test_deserialize_ci_empty() {
         let contents = r#"
@@ -319,6 +824,84 @@ platform = "gitlab"
         assert!(config.platform.is_none());
     }
 
+    #[test]
+    fn test_deserialize_on_switch() {
+        let contents = r#"
+[[on-switch]]
+pattern = "feature/*"
+run = "npm run dev"
+
+[[on-switch]]
+pattern = "rust/*"
+run = "cargo watch"
+auto = true
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert_eq!(config.on_switch.len(), 2);
+        assert!(!config.on_switch[0].auto);
+        assert!(config.on_switch[1].auto);
+        assert!(find_unknown_keys(contents).is_empty());
+
+        let runs: Vec<_> = config
+            .on_switch_for("feature/login")
+            .map(|e| e.run.as_str())
+            .collect();
+        assert_eq!(runs, ["npm run dev"]);
+        assert_eq!(config.on_switch_for("main").count(), 0);
+    }
+
+    #[test]
+    fn test_identity_rules() {
+        let contents = r#"
+[identity]
+require-email-pattern = "@example\\.com$"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert!(find_unknown_keys(contents).is_empty());
+        let rules = config.identity.unwrap();
+        assert!(rules.allows(Some("dev@example.com")).unwrap());
+        assert!(!rules.allows(Some("dev@example.org")).unwrap());
+        assert!(!rules.allows(None).unwrap());
+
+        assert!(IdentityRules::default().allows(None).unwrap());
+        let invalid = IdentityRules {
+            require_email_pattern: Some("(".into()),
+        };
+        assert!(invalid.allows(Some("dev@example.com")).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_git_config() {
+        let contents = r#"
+[git-config]
+"pull.rebase" = "true"
+"diff.lockb.textconv" = "bun {{ branch }}"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert!(find_unknown_keys(contents).is_empty());
+        let git_config = config.git_config.unwrap();
+        assert_eq!(git_config["pull.rebase"], "true");
+        assert_eq!(git_config["diff.lockb.textconv"], "bun {{ branch }}");
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("feature/*", "feature/login"));
+        assert!(glob_match("feature/*", "feature/a/b"));
+        assert!(glob_match("feature/*", "feature/"));
+        assert!(!glob_match("feature/*", "feature"));
+        assert!(!glob_match("feature/*", "hotfix/login"));
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("*-wip", "login-wip"));
+        assert!(glob_match("rel?ase", "release"));
+        assert!(!glob_match("rel?ase", "relase"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("a*b*c", "axxbyy"));
+        assert!(glob_match("main", "main"));
+        assert!(!glob_match("main", "main2"));
+        assert!(glob_match("功能/*", "功能/登录"));
+    }
+
     // ============================================================================
     // find_unknown_keys Tests
     // ============================================================================
@@ -390,4 +973,29 @@ post-create = "npm install"
         let cloned = config.clone();
         assert_eq!(config, cloned);
     }
+
+    #[test]
+    fn test_config_serialization_roundtrip() {
+        let roundtrip = |config: &ProjectConfig| {
+            let serialized = toml::to_string(config).unwrap();
+            let reloaded: ProjectConfig = toml::from_str(&serialized).unwrap();
+            assert_eq!(&reloaded, config, "{serialized}");
+        };
+
+        roundtrip(&ProjectConfig::default());
+        roundtrip(
+            &toml::from_str(
+                r#"
+post-create = "npm install"
+
+[post-start]
+server = "npm run dev"
+
+[list]
+url = "http://localhost:{{ branch | hash_port }}"
+"#,
+            )
+            .unwrap(),
+        );
+    }
 }
@@ -0,0 +1,273 @@
+//! Shared persistence for small config and state files.
+//!
+//! Concurrent `wt` invocations write the same files: approvals, the update
+//! check state, activity logs, caches. Every write here is atomic (a temporary
+//! file in the same directory, then a rename), so readers never see half a
+//! file. Read-modify-write cycles additionally hold an exclusive advisory lock
+//! on a `.lock` file beside the data, so two processes can't both read the old
+//! contents and each drop the other's change.
+//!
+//! # Versioned state
+//!
+//! Types implementing [`StateFile`] are stored as
+//! `{"version": N, "state": ...}`. A file with another version, or from before
+//! versioning, reads as empty and is replaced on the next update. A file that
+//! isn't valid JSON at all is renamed to `<name>.corrupt` with a warning, and
+//! the update starts fresh.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use color_print::cformat;
+use fs2::FileExt;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::path::format_path_for_display;
+use crate::styling::{eprintln, warning_message};
+
+/// A serde type persisted as a versioned JSON state file.
+pub trait StateFile: Serialize + DeserializeOwned + Default {
+    /// Bump when the stored shape changes incompatibly; older files are dropped.
+    const VERSION: u32;
+}
+
+#[derive(Serialize)]
+struct Envelope<'a, T> {
+    version: u32,
+    state: &'a T,
+}
+
+#[derive(Deserialize)]
+struct RawEnvelope {
+    version: u32,
+    state: serde_json::Value,
+}
+
+/// Exclusive lock on a state file, released on drop.
+#[derive(Debug)]
+pub struct StateLock {
+    _file: File,
+}
+
+/// The lock file guarding `path`: the same name with `.lock` appended.
+pub fn lock_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".lock");
+    PathBuf::from(name)
+}
+
+/// Block until this process holds the exclusive lock at `lock_path`.
+pub fn lock(lock_path: &Path) -> io::Result<StateLock> {
+    if let Some(parent) = lock_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(lock_path)?;
+    file.lock_exclusive()?;
+    Ok(StateLock { _file: file })
+}
+
+/// Replace `path` with `contents` in one step, creating parent directories.
+///
+/// Writes a temporary file beside `path` and renames it over the target, so a
+/// concurrent reader sees either the old contents or the new, never a mix. A
+/// symlinked `path` (a config kept in a dotfiles repo) is written through to
+/// its target rather than replaced by a regular file.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let resolved;
+    let path = if path.is_symlink() {
+        resolved = fs::canonicalize(path)?;
+        resolved.as_path()
+    } else {
+        path
+    };
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(parent)?;
+    let mut temp = tempfile::NamedTempFile::new_in(parent)?;
+    temp.write_all(contents.as_ref())?;
+    temp.as_file().sync_data()?;
+    temp.persist(path).map_err(|e| e.error)?;
+    Ok(())
+}
+
+/// Read a versioned state file. Missing, unreadable, corrupt and other-version
+/// files all read as `None`; only [`update`] repairs them.
+pub fn read<T: StateFile>(path: &Path) -> Option<T> {
+    let content = fs::read_to_string(path).ok()?;
+    match parse(&content) {
+        Parsed::Current(state) => Some(state),
+        Parsed::Stale | Parsed::Corrupt(_) => None,
+    }
+}
+
+/// Read-modify-write a versioned state file under its lock.
+///
+/// `mutate` sees the current state (the default when the file is missing,
+/// stale or corrupt), and the result is written back atomically.
+pub fn update<T: StateFile, R>(path: &Path, mutate: impl FnOnce(&mut T) -> R) -> io::Result<R> {
+    let _lock = lock(&lock_path(path))?;
+    let mut state = match fs::read_to_string(path) {
+        Ok(content) => match parse(&content) {
+            Parsed::Current(state) => state,
+            Parsed::Stale => T::default(),
+            Parsed::Corrupt(err) => {
+                quarantine(path, &err)?;
+                T::default()
+            }
+        },
+        Err(e) if e.kind() == io::ErrorKind::NotFound => T::default(),
+        Err(e) => return Err(e),
+    };
+    let result = mutate(&mut state);
+    let json = serde_json::to_string(&Envelope {
+        version: T::VERSION,
+        state: &state,
+    })
+    .map_err(io::Error::other)?;
+    write_atomic(path, json)?;
+    Ok(result)
+}
+
+enum Parsed<T> {
+    Current(T),
+    /// Valid JSON from another version, or from before versioning
+    Stale,
+    Corrupt(String),
+}
+
+fn parse<T: StateFile>(content: &str) -> Parsed<T> {
+    let value: serde_json::Value = match serde_json::from_str(content) {
+        Ok(value) => value,
+        Err(e) => return Parsed::Corrupt(e.to_string()),
+    };
+    match serde_json::from_value::<RawEnvelope>(value) {
+        Ok(envelope) if envelope.version == T::VERSION => {
+            match serde_json::from_value(envelope.state) {
+                Ok(state) => Parsed::Current(state),
+                Err(e) => Parsed::Corrupt(e.to_string()),
+            }
+        }
+        _ => Parsed::Stale,
+    }
+}
+
+/// Move a corrupt file aside as `<name>.corrupt`, replacing an older one.
+fn quarantine(path: &Path, err: &str) -> io::Result<()> {
+    let mut aside = path.as_os_str().to_owned();
+    aside.push(".corrupt");
+    let aside = PathBuf::from(aside);
+    fs::rename(path, &aside)?;
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Moved unreadable state file to <bold>{}</> and started fresh ({err})",
+            format_path_for_display(&aside)
+        ))
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Counter {
+        count: u32,
+    }
+
+    impl StateFile for Counter {
+        const VERSION: u32 = 2;
+    }
+
+    #[test]
+    fn test_update_and_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/counter.json");
+        assert_eq!(read::<Counter>(&path), None);
+
+        let before = update(&path, |c: &mut Counter| {
+            c.count += 1;
+            c.count - 1
+        })
+        .unwrap();
+        assert_eq!(before, 0);
+        assert_eq!(read::<Counter>(&path), Some(Counter { count: 1 }));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            r#"{"version":2,"state":{"count":1}}"#
+        );
+    }
+
+    #[test]
+    fn test_stale_and_corrupt_files_start_fresh() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("counter.json");
+
+        // Another version (or an unversioned file) is dropped quietly
+        fs::write(&path, r#"{"version":1,"state":{"count":7}}"#).unwrap();
+        assert_eq!(read::<Counter>(&path), None);
+        update(&path, |c: &mut Counter| c.count += 1).unwrap();
+        assert_eq!(read::<Counter>(&path), Some(Counter { count: 1 }));
+
+        // Garbage is kept aside for inspection
+        fs::write(&path, "{\"version\":2,\"sta").unwrap();
+        update(&path, |c: &mut Counter| c.count += 1).unwrap();
+        assert_eq!(read::<Counter>(&path), Some(Counter { count: 1 }));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("counter.json.corrupt")).unwrap(),
+            "{\"version\":2,\"sta"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_atomic_keeps_symlink() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("dotfiles/config.toml");
+        let link = dir.path().join("config.toml");
+        fs::create_dir(dir.path().join("dotfiles")).unwrap();
+        fs::write(&target, "old").unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        write_atomic(&link, "new").unwrap();
+        assert!(link.is_symlink());
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new");
+    }
+
+    /// Many threads incrementing one counter: with the lock held across the
+    /// read and the write, no increment is lost.
+    #[test]
+    fn test_concurrent_updates_lose_nothing() {
+        const THREADS: u32 = 8;
+        const ROUNDS: u32 = 25;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("counter.json");
+
+        std::thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| {
+                    for _ in 0..ROUNDS {
+                        update(&path, |c: &mut Counter| c.count += 1).unwrap();
+                    }
+                });
+            }
+        });
+
+        assert_eq!(
+            read::<Counter>(&path),
+            Some(Counter {
+                count: THREADS * ROUNDS
+            })
+        );
+    }
+}
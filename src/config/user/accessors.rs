@@ -6,12 +6,13 @@
 use std::collections::{BTreeMap, HashMap};
 
 use crate::config::HooksConfig;
-use crate::config::expansion::expand_template;
+use crate::config::expansion::expand_path_template;
 
 use super::UserConfig;
 use super::merge::{Merge, merge_optional};
 use super::sections::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, SelectConfig, SwitchPickerConfig,
+    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, SelectConfig, SlugLowercase,
+    SwitchConfig, SwitchPickerConfig,
 };
 
 /// Default worktree path template
@@ -44,6 +45,25 @@ impl UserConfig {
             .unwrap_or_else(|| self.worktree_path())
     }
 
+    /// Returns `allowed-worktree-roots` with `~` expanded; empty when unrestricted.
+    pub fn allowed_worktree_roots(&self) -> Vec<std::path::PathBuf> {
+        self.allowed_worktree_roots
+            .iter()
+            .map(|root| std::path::PathBuf::from(shellexpand::tilde(root).as_ref()))
+            .collect()
+    }
+
+    /// Returns when `sanitize` lowercases branch names in worktree paths.
+    ///
+    /// Project-specific setting takes precedence over the global one.
+    pub fn slug_lowercase(&self, project: Option<&str>) -> SlugLowercase {
+        project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|p| p.overrides.slug_lowercase)
+            .or(self.configs.slug_lowercase)
+            .unwrap_or_default()
+    }
+
     /// Returns the commit generation config for a specific project.
     ///
     /// Merges project-specific settings with global settings, where project
@@ -112,6 +132,17 @@ impl UserConfig {
         merge_optional(self.configs.merge.as_ref(), project_config)
     }
 
+    /// Returns the switch config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn switch(&self, project: Option<&str>) -> Option<SwitchConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.switch.as_ref());
+        merge_optional(self.configs.switch.as_ref(), project_config)
+    }
+
     /// Returns the select config for a specific project (deprecated path).
     ///
     /// Merges project-specific settings with global settings, where project
@@ -226,16 +257,49 @@ impl UserConfig {
             Some(p) => self.worktree_path_for_project(p),
             None => self.worktree_path(),
         };
+        self.format_path_template(&template, main_worktree, branch, repo, project)
+    }
+
+    /// Format a worktree path from an explicit template, e.g. a worktree profile's
+    /// `worktree-path`. See [`format_path`](Self::format_path) for the arguments.
+    ///
+    /// With `slug-lowercase = "auto"`, the filesystem where the path lands is
+    /// probed, and the template is expanded again with lowercased slugs if it
+    /// ignores case.
+    pub fn format_path_template(
+        &self,
+        template: &str,
+        main_worktree: &str,
+        branch: &str,
+        repo: &crate::git::Repository,
+        project: Option<&str>,
+    ) -> anyhow::Result<String> {
         // Use native path format (not POSIX) since this is used for filesystem operations
-        let repo_path = repo.repo_path()?.to_string_lossy().to_string();
+        let repo_path = repo.repo_path()?.to_path_buf();
+        let repo_path_str = repo_path.to_string_lossy().to_string();
         let mut vars = HashMap::new();
         vars.insert("main_worktree", main_worktree);
         vars.insert("repo", main_worktree);
         vars.insert("branch", branch);
-        vars.insert("repo_path", repo_path.as_str());
-        Ok(
-            expand_template(&template, &vars, false, repo, "worktree-path")
-                .map(|p| shellexpand::tilde(&p).into_owned())?,
-        )
+        vars.insert("repo_path", repo_path_str.as_str());
+        let expand = |lowercase_slugs| -> anyhow::Result<String> {
+            Ok(
+                expand_path_template(template, &vars, lowercase_slugs, repo, "worktree-path")
+                    .map(|p| shellexpand::tilde(&p).into_owned())?,
+            )
+        };
+
+        match self.slug_lowercase(project) {
+            SlugLowercase::Never => expand(false),
+            SlugLowercase::Always => expand(true),
+            SlugLowercase::Auto => {
+                let path = expand(false)?;
+                if crate::path::is_case_insensitive_fs(&repo_path.join(&path)) {
+                    expand(true)
+                } else {
+                    Ok(path)
+                }
+            }
+        }
     }
 }
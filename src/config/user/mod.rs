@@ -13,12 +13,15 @@ mod sections;
 #[cfg(test)]
 mod tests;
 
-use config::{Case, Config, ConfigError, File};
+use std::borrow::Cow;
+
+use config::{Case, Config, ConfigError, File, FileFormat};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // Re-export public types
 pub use merge::Merge;
+pub use mutation::acquire_config_lock;
 pub use path::{
     default_config_path, default_system_config_path, get_config_path, get_system_config_path,
     set_config_path,
@@ -26,8 +29,10 @@ pub use path::{
 pub use resolved::ResolvedConfig;
 pub use schema::{find_unknown_keys, valid_user_config_keys};
 pub use sections::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, OverridableConfig, SelectConfig,
-    StageMode, SwitchConfig, SwitchPickerConfig, UserProjectOverrides,
+    ActivityConfig, ActivityOnRemove, CommitConfig, CommitGenerationConfig, HintsConfig,
+    IntegrationsConfig, KeysConfig, ListConfig, ListDensity, ListLayout, MergeConfig,
+    OverridableConfig, ScratchConfig, SelectConfig, SlugLowercase, StageMode, SwitchConfig,
+    SwitchPickerConfig, ThemeBase, ThemeConfig, UserProjectOverrides,
 };
 
 /// User-level configuration for worktree path formatting and LLM integration.
@@ -71,6 +76,10 @@ pub use sections::{
 /// `__` separator for nested fields (e.g., `WORKTRUNK_COMMIT__GENERATION__COMMAND`).
 #[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 pub struct UserConfig {
+    /// Config format version (see `wt config migrate`). Absent means version 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+
     /// **DEPRECATED**: Use `[commit.generation]` instead.
     ///
     /// This field is kept for backward compatibility. When both are set,
@@ -91,6 +100,46 @@ pub struct UserConfig {
     #[serde(flatten, default)]
     pub configs: OverridableConfig,
 
+    /// Key bindings for the interactive picker
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keys: Option<KeysConfig>,
+
+    /// Severity colors and thresholds for `wt list`, or just the built-in
+    /// theme: `theme = "dark"`, `"light"` or `"auto"`
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "sections::deserialize_theme"
+    )]
+    #[schemars(with = "Option<sections::ThemeConfigToml>")]
+    pub theme: Option<ThemeConfig>,
+
+    /// Per-worktree log of hook and `--execute` commands (off by default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity: Option<ActivityConfig>,
+
+    /// Per-worktree scratch directory kept across `wt remove` (off by default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scratch: Option<ScratchConfig>,
+
+    /// Tools told when worktrees are created and removed (zoxide, custom commands)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<IntegrationsConfig>,
+
+    /// Hints and banners silenced by name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hints: Option<HintsConfig>,
+
+    /// Default flags per subcommand, e.g. `list = "--branches --full"`.
+    /// Inserted before command-line arguments, which take precedence.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub defaults: std::collections::BTreeMap<String, String>,
+
+    /// Command aliases, e.g. `sw = "switch --create"`.
+    /// Expanded before parsing; built-in commands take precedence.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub alias: std::collections::BTreeMap<String, String>,
+
     /// Skip the first-run shell integration prompt
     #[serde(
         default,
@@ -106,6 +155,47 @@ pub struct UserConfig {
         skip_serializing_if = "std::ops::Not::not"
     )]
     pub skip_commit_generation_prompt: bool,
+
+    /// Check for a newer release at most once a day and mention it on stderr
+    #[serde(
+        default,
+        rename = "update-check",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub update_check: bool,
+
+    /// Directories that new and moved worktrees must be inside, e.g. `["~/worktrees"]`.
+    /// Empty (the default) allows any path.
+    #[serde(
+        default,
+        rename = "allowed-worktree-roots",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub allowed_worktree_roots: Vec<String>,
+
+    /// Before removing a worktree, ask if other processes have their working directory in it
+    #[serde(
+        default,
+        rename = "check-cwd-users",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub check_cwd_users: bool,
+
+    /// Suggest `wt step optimize` at most once a week in large repos without a commit-graph or maintenance
+    #[serde(
+        default,
+        rename = "performance-hint",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub performance_hint: bool,
+
+    /// Record command names, flag names and durations locally for `wt report`
+    #[serde(
+        default,
+        rename = "usage-journal",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub usage_journal: bool,
 }
 
 impl UserConfig {
@@ -117,6 +207,20 @@ impl UserConfig {
     /// 3. User config file (personal preferences)
     /// 4. Environment variables (WORKTRUNK_*)
     pub fn load() -> Result<Self, ConfigError> {
+        Self::load_impl(true)
+    }
+
+    /// Load configuration like [`UserConfig::load`], without warning about
+    /// deprecated settings, unknown fields, or a missing `--config` file.
+    ///
+    /// For callers that report config problems themselves, such as
+    /// `wt config show`, or that load the config on behalf of commands which
+    /// may never read it.
+    pub fn load_quiet() -> Result<Self, ConfigError> {
+        Self::load_impl(false)
+    }
+
+    fn load_impl(warn: bool) -> Result<Self, ConfigError> {
         // Note: worktree-path has no default set here - it's handled by the getter
         // which returns the default when None. This allows us to distinguish
         // "user explicitly set this" from "using default".
@@ -124,7 +228,7 @@ impl UserConfig {
 
         // Add system config if it exists (lowest priority file source)
         if let Some(system_path) = path::get_system_config_path() {
-            if let Ok(content) = std::fs::read_to_string(&system_path) {
+            if warn && let Ok(content) = std::fs::read_to_string(&system_path) {
                 // Warn about unknown fields in system config
                 let unknown_keys: std::collections::HashMap<_, _> = find_unknown_keys(&content)
                     .into_iter()
@@ -150,34 +254,48 @@ impl UserConfig {
             // User config always gets migration file (it's global, not worktree-specific)
             // Use show_brief_warning=true to emit a brief pointer to `wt config show`
             // Warning is deduplicated per-process via WARNED_DEPRECATED_PATHS.
+            let mut upgraded = None;
             if let Ok(content) = std::fs::read_to_string(config_path) {
-                let _ = super::deprecation::check_and_migrate(
-                    config_path,
-                    &content,
-                    true,
-                    "User config",
-                    None,
-                    true, // show_brief_warning
-                );
+                if warn {
+                    let _ = super::deprecation::check_and_migrate(
+                        config_path,
+                        &content,
+                        true,
+                        "User config",
+                        None,
+                        true, // show_brief_warning
+                    );
 
-                // Warn about unknown fields in the config file
-                // (must check file content directly, not config.unknown, because
-                // config.unknown includes env vars which shouldn't trigger warnings)
-                let unknown_keys: std::collections::HashMap<_, _> = find_unknown_keys(&content)
-                    .into_iter()
-                    .filter(|(k, _)| {
-                        !super::deprecation::DEPRECATED_SECTION_KEYS.contains(&k.as_str())
-                    })
-                    .collect();
-                super::deprecation::warn_unknown_fields::<UserConfig>(
-                    config_path,
-                    &unknown_keys,
-                    "User config",
-                );
+                    // Warn about unknown fields in the config file
+                    // (must check file content directly, not config.unknown, because
+                    // config.unknown includes env vars which shouldn't trigger warnings)
+                    let unknown_keys: std::collections::HashMap<_, _> = find_unknown_keys(&content)
+                        .into_iter()
+                        .filter(|(k, _)| {
+                            !super::deprecation::DEPRECATED_SECTION_KEYS.contains(&k.as_str())
+                        })
+                        .collect();
+                    super::deprecation::warn_unknown_fields::<UserConfig>(
+                        config_path,
+                        &unknown_keys,
+                        "User config",
+                    );
+                }
+
+                // Older config versions load through their upgraded form
+                if let Cow::Owned(content) =
+                    super::version::upgrade_in_memory(&content, "User config")?
+                {
+                    upgraded = Some(content);
+                }
             }
 
-            builder = builder.add_source(File::from(config_path.clone()));
+            builder = match upgraded {
+                Some(content) => builder.add_source(File::from_str(&content, FileFormat::Toml)),
+                None => builder.add_source(File::from(config_path.clone())),
+            };
         } else if let Some(config_path) = config_path.as_ref()
+            && warn
             && path::is_config_path_explicit()
         {
             // Warn if user explicitly specified a config path that doesn't exist
@@ -4,8 +4,8 @@
 //! using file locking to prevent race conditions between concurrent processes.
 
 use config::ConfigError;
-use fs2::FileExt;
 
+use crate::config::state_file::{self, StateLock};
 use crate::path::format_path_for_display;
 
 use super::UserConfig;
@@ -17,29 +17,9 @@ use super::sections::CommitGenerationConfig;
 ///
 /// Uses a `.lock` file alongside the config file to coordinate between processes.
 /// The lock is released when the returned guard is dropped.
-pub(crate) fn acquire_config_lock(
-    config_path: &std::path::Path,
-) -> Result<std::fs::File, ConfigError> {
-    let lock_path = config_path.with_extension("toml.lock");
-
-    // Create parent directory if needed
-    if let Some(parent) = lock_path.parent() {
-        std::fs::create_dir_all(parent)
-            .map_err(|e| ConfigError::Message(format!("Failed to create config directory: {e}")))?;
-    }
-
-    let file = std::fs::OpenOptions::new()
-        .read(true)
-        .write(true)
-        .create(true)
-        .truncate(false)
-        .open(&lock_path)
-        .map_err(|e| ConfigError::Message(format!("Failed to open lock file: {e}")))?;
-
-    file.lock_exclusive()
-        .map_err(|e| ConfigError::Message(format!("Failed to acquire config lock: {e}")))?;
-
-    Ok(file)
+pub fn acquire_config_lock(config_path: &std::path::Path) -> Result<StateLock, ConfigError> {
+    state_file::lock(&config_path.with_extension("toml.lock"))
+        .map_err(|e| ConfigError::Message(format!("Failed to acquire config lock: {e}")))
 }
 
 impl UserConfig {
@@ -174,4 +154,29 @@ impl UserConfig {
             true
         })
     }
+
+    /// Set `worktree-path` for one project and save.
+    ///
+    /// Sets `[projects."<project>"] worktree-path = ...` in the user config.
+    /// Acquires lock, reloads from disk, sets the template, and saves.
+    /// Pass `None` for default config path, or `Some(path)` for testing.
+    pub fn set_project_worktree_path(
+        &mut self,
+        project: &str,
+        template: String,
+        config_path: Option<&std::path::Path>,
+    ) -> Result<(), ConfigError> {
+        self.with_locked_mutation(config_path, |config| {
+            let overrides = &mut config
+                .projects
+                .entry(project.to_string())
+                .or_default()
+                .overrides;
+            if overrides.worktree_path.as_ref() == Some(&template) {
+                return false;
+            }
+            overrides.worktree_path = Some(template);
+            true
+        })
+    }
 }
@@ -8,7 +8,7 @@ use serde::Serialize;
 
 use super::UserConfig;
 use super::path::get_config_path;
-use super::sections::CommitGenerationConfig;
+use super::sections::{CommitGenerationConfig, SlugLowercase};
 
 impl UserConfig {
     /// Save the current configuration to the default config file location
@@ -118,6 +118,18 @@ impl UserConfig {
                     table.remove("worktree-path");
                 }
 
+                // slug-lowercase (only if set)
+                if let Some(mode) = project_config.overrides.slug_lowercase {
+                    let mode = match mode {
+                        SlugLowercase::Never => "never",
+                        SlugLowercase::Always => "always",
+                        SlugLowercase::Auto => "auto",
+                    };
+                    projects[project_id]["slug-lowercase"] = toml_edit::value(mode);
+                } else if let Some(table) = projects[project_id].as_table_mut() {
+                    table.remove("slug-lowercase");
+                }
+
                 // Per-project nested config sections
                 Self::serialize_project_config_section(
                     projects,
@@ -277,7 +289,7 @@ impl UserConfig {
             doc.to_string()
         };
 
-        std::fs::write(config_path, toml_string)
+        crate::config::state_file::write_atomic(config_path, toml_string)
             .map_err(|e| ConfigError::Message(format!("Failed to write config file: {}", e)))?;
 
         Ok(())
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 
 use super::merge::Merge;
 use crate::config::HooksConfig;
+use crate::styling::{Background, Theme, parse_style};
 
 /// What to stage before committing
 #[derive(
@@ -141,6 +142,70 @@ pub struct ListConfig {
     /// (useful to override a global setting). Disabled when --full is used.
     #[serde(rename = "timeout-ms", skip_serializing_if = "Option::is_none")]
     pub timeout_ms: Option<u64>,
+
+    /// Maximum width of the Path column. Longer paths are ellipsized in the
+    /// middle, keeping the final component. Set to 0 to disable the cap.
+    #[serde(rename = "max-path-width", skip_serializing_if = "Option::is_none")]
+    pub max_path_width: Option<usize>,
+
+    /// Ref to compute ahead/behind and branch diffs against, instead of the
+    /// default branch (same as `--against`)
+    #[serde(rename = "compare-ref", skip_serializing_if = "Option::is_none")]
+    pub compare_ref: Option<String>,
+
+    /// What gives way when columns don't fit at their natural widths
+    /// Values: "drop", "shrink"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout: Option<ListLayout>,
+
+    /// Space between columns: "compact" (1), "normal" (2), or "wide" (3)
+    /// (same as `--density`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub density: Option<ListDensity>,
+
+    /// Pager for tables taller than the terminal. Defaults to the git pager
+    /// (`GIT_PAGER`, `core.pager`, `PAGER`, then `less`); "cat" or "" prints
+    /// every row instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pager: Option<String>,
+}
+
+/// How `wt list` fits its columns into a narrow terminal
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListLayout {
+    /// Show each column at its natural width, dropping the lowest-priority
+    /// columns that don't fit
+    #[default]
+    Drop,
+    /// Truncate text columns toward their minimum widths before dropping any
+    Shrink,
+}
+
+/// How much space `wt list` leaves between columns
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListDensity {
+    /// One-cell gaps and a narrower Message column, for narrow terminals
+    Compact,
+    /// Two-cell gaps
+    #[default]
+    Normal,
+    /// Three-cell gaps
+    Wide,
+}
+
+impl ListDensity {
+    /// Cells between adjacent columns
+    pub fn gap(self) -> usize {
+        match self {
+            Self::Compact => 1,
+            Self::Normal => 2,
+            Self::Wide => 3,
+        }
+    }
 }
 
 impl ListConfig {
@@ -168,6 +233,34 @@ impl ListConfig {
     pub fn timeout_ms(&self) -> Option<u64> {
         self.timeout_ms
     }
+
+    /// Maximum Path column width (default: 40), `None` when disabled with 0
+    pub fn max_path_width(&self) -> Option<usize> {
+        match self.max_path_width.unwrap_or(40) {
+            0 => None,
+            width => Some(width),
+        }
+    }
+
+    /// Ref to compare against instead of the default branch (default: None)
+    pub fn compare_ref(&self) -> Option<&str> {
+        self.compare_ref.as_deref()
+    }
+
+    /// How columns fit a narrow terminal (default: drop)
+    pub fn layout(&self) -> ListLayout {
+        self.layout.unwrap_or_default()
+    }
+
+    /// Space between columns (default: normal)
+    pub fn density(&self) -> ListDensity {
+        self.density.unwrap_or_default()
+    }
+
+    /// Pager for tall tables (default: None, meaning the git pager)
+    pub fn pager(&self) -> Option<&str> {
+        self.pager.as_deref()
+    }
 }
 
 impl Merge for ListConfig {
@@ -178,6 +271,14 @@ impl Merge for ListConfig {
             remotes: other.remotes.or(self.remotes),
             summary: other.summary.or(self.summary),
             timeout_ms: other.timeout_ms.or(self.timeout_ms),
+            max_path_width: other.max_path_width.or(self.max_path_width),
+            compare_ref: other
+                .compare_ref
+                .clone()
+                .or_else(|| self.compare_ref.clone()),
+            layout: other.layout.or(self.layout),
+            density: other.density.or(self.density),
+            pager: other.pager.clone().or_else(|| self.pager.clone()),
         }
     }
 }
@@ -368,6 +469,22 @@ pub struct SwitchConfig {
     /// Picker settings for the interactive selector
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub picker: Option<SwitchPickerConfig>,
+
+    /// Refuse to create worktrees once this many exist
+    #[serde(
+        default,
+        rename = "max-worktrees",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_worktrees: Option<usize>,
+
+    /// Suggest cleaning up after creating a worktree once this many exist
+    #[serde(
+        default,
+        rename = "warn-worktrees",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub warn_worktrees: Option<usize>,
 }
 
 impl Merge for SwitchConfig {
@@ -379,8 +496,374 @@ impl Merge for SwitchConfig {
                 (None, Some(o)) => Some(o.clone()),
                 (Some(s), Some(o)) => Some(s.merge_with(o)),
             },
+            max_worktrees: other.max_worktrees.or(self.max_worktrees),
+            warn_worktrees: other.warn_worktrees.or(self.warn_worktrees),
+        }
+    }
+}
+
+/// Key bindings for the interactive picker (`[keys]`).
+///
+/// Values use skim key syntax (`enter`, `alt-r`, `ctrl-o`). An empty string
+/// disables the action and hides it from the controls line. Plain letters
+/// would shadow typing in the search box, so defaults use `alt-`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct KeysConfig {
+    /// Switch to the highlighted worktree (default: `enter`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch: Option<String>,
+
+    /// Create a worktree named by the search query (default: `alt-c`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create: Option<String>,
+
+    /// Remove the highlighted worktree (default: `alt-r`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<String>,
+
+    /// Fetch the highlighted branch's upstream (default: `alt-f`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetch: Option<String>,
+
+    /// Open the highlighted worktree in `$VISUAL`/`$EDITOR` (default: `alt-o`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open: Option<String>,
+
+    /// Copy the highlighted worktree's path to the clipboard (default: `alt-y`)
+    #[serde(rename = "copy-path", skip_serializing_if = "Option::is_none")]
+    pub copy_path: Option<String>,
+}
+
+impl KeysConfig {
+    /// Key for switching (default: `enter`), `None` if disabled
+    pub fn switch(&self) -> Option<&str> {
+        key_or_default(&self.switch, "enter")
+    }
+
+    /// Key for creating (default: `alt-c`), `None` if disabled
+    pub fn create(&self) -> Option<&str> {
+        key_or_default(&self.create, "alt-c")
+    }
+
+    /// Key for removing (default: `alt-r`), `None` if disabled
+    pub fn remove(&self) -> Option<&str> {
+        key_or_default(&self.remove, "alt-r")
+    }
+
+    /// Key for fetching (default: `alt-f`), `None` if disabled
+    pub fn fetch(&self) -> Option<&str> {
+        key_or_default(&self.fetch, "alt-f")
+    }
+
+    /// Key for opening in the editor (default: `alt-o`), `None` if disabled
+    pub fn open(&self) -> Option<&str> {
+        key_or_default(&self.open, "alt-o")
+    }
+
+    /// Key for copying the path (default: `alt-y`), `None` if disabled
+    pub fn copy_path(&self) -> Option<&str> {
+        key_or_default(&self.copy_path, "alt-y")
+    }
+}
+
+/// Configured key, the default when unset, or `None` when set to `""`.
+fn key_or_default<'a>(key: &'a Option<String>, default: &'static str) -> Option<&'a str> {
+    match key.as_deref() {
+        Some("") => None,
+        Some(key) => Some(key),
+        None => Some(default),
+    }
+}
+
+/// Colors for severity-styled numbers in `wt list`
+///
+/// Values are style specs: an ANSI color name (`red`, `bright-blue`), a
+/// 256-color index (`208`) or `#rrggbb`, plus optional `bold`, `dim`,
+/// `italic`, `underline`. Unset keys come from the built-in dark or light
+/// theme, picked from the terminal background unless `base` says otherwise.
+/// `theme = "light"` is shorthand for setting only `base`.
+///
+/// ```toml
+/// [theme]
+/// base = "light"
+/// behind-warn = "208"
+/// behind-warn-at = 5
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct ThemeConfig {
+    /// Built-in theme for unset keys (default: `auto`, from the terminal background)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<ThemeBase>,
+
+    /// Commits ahead of the default branch (default: `green`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ahead: Option<String>,
+
+    /// Commits behind, below `behind-warn-at` (default: `red dim`; light: `red`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behind: Option<String>,
+
+    /// Commits behind, from `behind-warn-at` (default: `yellow`; light: `magenta`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behind_warn: Option<String>,
+
+    /// Commits behind, from `behind-critical-at` (default: `red`; light: `red bold`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behind_critical: Option<String>,
+
+    /// Behind count where `behind-warn` starts (default: 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behind_warn_at: Option<usize>,
+
+    /// Behind count where `behind-critical` starts (default: 50)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behind_critical_at: Option<usize>,
+
+    /// Added lines in diff columns (default: `green`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added: Option<String>,
+
+    /// Deleted lines in diff columns (default: `red`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<String>,
+
+    /// Conflict markers (default: `red bold`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflicts: Option<String>,
+}
+
+/// Built-in theme selected by `[theme] base`
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeBase {
+    /// Detect from the terminal background
+    #[default]
+    Auto,
+    Dark,
+    Light,
+}
+
+impl ThemeConfig {
+    /// The background named by `base`, or `None` to detect it.
+    pub fn background(&self) -> Option<Background> {
+        match self.base.unwrap_or_default() {
+            ThemeBase::Auto => None,
+            ThemeBase::Dark => Some(Background::Dark),
+            ThemeBase::Light => Some(Background::Light),
         }
     }
+
+    /// Resolve into a [`Theme`], filling unset keys from the built-in theme
+    /// for `background`.
+    ///
+    /// Errors name the offending key so the caller can point at it.
+    pub fn resolve(&self, background: Background) -> Result<Theme, String> {
+        let defaults = Theme::for_background(background);
+        let style = |key: &str, spec: &Option<String>, default| match spec {
+            Some(spec) => parse_style(spec).map_err(|e| format!("theme.{key}: {e}")),
+            None => Ok(default),
+        };
+        Ok(Theme {
+            ahead: style("ahead", &self.ahead, defaults.ahead)?,
+            behind: style("behind", &self.behind, defaults.behind)?,
+            behind_warn: style("behind-warn", &self.behind_warn, defaults.behind_warn)?,
+            behind_critical: style(
+                "behind-critical",
+                &self.behind_critical,
+                defaults.behind_critical,
+            )?,
+            added: style("added", &self.added, defaults.added)?,
+            deleted: style("deleted", &self.deleted, defaults.deleted)?,
+            conflicts: style("conflicts", &self.conflicts, defaults.conflicts)?,
+            behind_warn_at: self.behind_warn_at.unwrap_or(defaults.behind_warn_at),
+            behind_critical_at: self
+                .behind_critical_at
+                .unwrap_or(defaults.behind_critical_at),
+        })
+    }
+}
+
+/// The two TOML forms of `theme`: `theme = "light"` or a `[theme]` table.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub(super) enum ThemeConfigToml {
+    Base(ThemeBase),
+    Table(Box<ThemeConfig>),
+}
+
+/// Deserialize `theme`, expanding the string shorthand to a table with only `base`.
+pub(super) fn deserialize_theme<'de, D>(deserializer: D) -> Result<Option<ThemeConfig>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(
+        Option::<ThemeConfigToml>::deserialize(deserializer)?.map(|toml| match toml {
+            ThemeConfigToml::Base(base) => ThemeConfig {
+                base: Some(base),
+                ..ThemeConfig::default()
+            },
+            ThemeConfigToml::Table(config) => *config,
+        }),
+    )
+}
+
+/// Per-worktree log of hook and `--execute` commands
+///
+/// Off by default. Entries stay local, under the repository's `wt-logs`
+/// directory, and show in `wt list --long` and the `Activity` column.
+///
+/// ```toml
+/// [activity]
+/// enabled = true
+/// size = 20
+/// on-remove = "archive"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct ActivityConfig {
+    /// Record commands (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Entries kept per worktree (default: 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+
+    /// What happens to a worktree's log when it's removed (default: `delete`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_remove: Option<ActivityOnRemove>,
+}
+
+/// What `wt remove` does with a worktree's activity log
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ActivityOnRemove {
+    /// Delete the log
+    #[default]
+    Delete,
+    /// Move the log to `wt-logs/activity/archive/`
+    Archive,
+}
+
+impl ActivityConfig {
+    /// Whether commands are recorded
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+
+    /// Entries kept per worktree
+    pub fn size(&self) -> usize {
+        self.size.unwrap_or(10)
+    }
+
+    /// What happens to the log on removal
+    pub fn on_remove(&self) -> ActivityOnRemove {
+        self.on_remove.unwrap_or_default()
+    }
+}
+
+/// Per-worktree scratch directory that survives `wt remove`
+///
+/// Off by default. The directory is kept out of git through
+/// `.git/info/exclude`, moved aside when its worktree is removed, and moved
+/// back when `wt switch` recreates a worktree for the same branch.
+///
+/// ```toml
+/// [scratch]
+/// enabled = true
+/// dir = ".wt-scratch"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct ScratchConfig {
+    /// Keep scratch directories across removal (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Directory name in each worktree (default: `.wt-scratch`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+}
+
+impl ScratchConfig {
+    /// Whether scratch directories are kept
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+
+    /// Directory name in each worktree
+    pub fn dir(&self) -> &str {
+        self.dir.as_deref().unwrap_or(crate::scratch::DEFAULT_DIR)
+    }
+}
+
+/// Other tools told about worktree paths
+///
+/// ```toml
+/// [integrations]
+/// zoxide = true
+/// on-path-created = "my-index add {{ worktree_path }}"
+/// on-path-removed = "my-index remove {{ worktree_path }}"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct IntegrationsConfig {
+    /// Add worktrees to zoxide on switch and remove them on `wt remove`
+    /// (default: false). Skipped when `zoxide` isn't on PATH.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zoxide: Option<bool>,
+
+    /// Command run after `wt switch` creates a worktree. Template variables:
+    /// `{{ worktree_path }}`, `{{ branch }}`, `{{ repo }}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_path_created: Option<String>,
+
+    /// Command run after `wt remove` removes a worktree, with the same
+    /// variables as `on-path-created`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_path_removed: Option<String>,
+}
+
+impl IntegrationsConfig {
+    /// Whether to keep zoxide's database in step with worktrees
+    pub fn zoxide(&self) -> bool {
+        self.zoxide.unwrap_or(false)
+    }
+}
+
+/// Hints and banners to never show
+///
+/// ```toml
+/// [hints]
+/// disabled = ["first-run"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct HintsConfig {
+    /// Names of silenced hints (`first-run`: the shell integration banner)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled: Vec<String>,
+}
+
+impl HintsConfig {
+    /// Whether the hint called `name` is silenced
+    pub fn is_disabled(&self, name: &str) -> bool {
+        self.disabled.iter().any(|disabled| disabled == name)
+    }
+}
+
+/// When the `sanitize` filter lowercases branch names in `worktree-path`
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SlugLowercase {
+    /// Keep the branch name's case
+    #[default]
+    Never,
+    /// Always lowercase
+    Always,
+    /// Lowercase when the worktree directory's filesystem ignores case
+    Auto,
 }
 
 /// Settings that can be set globally or per-project.
@@ -410,6 +893,15 @@ pub struct OverridableConfig {
     )]
     pub worktree_path: Option<String>,
 
+    /// Lowercase `sanitize` output in the worktree path template
+    /// (`"never"`, `"always"`, or `"auto"` for case-insensitive filesystems)
+    #[serde(
+        rename = "slug-lowercase",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub slug_lowercase: Option<SlugLowercase>,
+
     /// Configuration for the `wt list` command
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub list: Option<ListConfig>,
@@ -451,6 +943,7 @@ impl OverridableConfig {
     pub fn is_empty(&self) -> bool {
         self.hooks == HooksConfig::default()
             && self.worktree_path.is_none()
+            && self.slug_lowercase.is_none()
             && self.list.is_none()
             && self.commit.is_none()
             && self.merge.is_none()
@@ -470,6 +963,7 @@ impl Merge for OverridableConfig {
                 .worktree_path
                 .clone()
                 .or_else(|| self.worktree_path.clone()),
+            slug_lowercase: other.slug_lowercase.or(self.slug_lowercase),
             list: merge_optional(self.list.as_ref(), other.list.as_ref()),
             commit: merge_optional(self.commit.as_ref(), other.commit.as_ref()),
             merge: merge_optional(self.merge.as_ref(), other.merge.as_ref()),
@@ -310,6 +310,11 @@ fn test_list_config_serde() {
         remotes: None,
         summary: None,
         timeout_ms: Some(500),
+        max_path_width: Some(30),
+        compare_ref: None,
+        layout: Some(ListLayout::Shrink),
+        density: Some(ListDensity::Compact),
+        pager: None,
     };
     let json = serde_json::to_string(&config).unwrap();
     let parsed: ListConfig = serde_json::from_str(&json).unwrap();
@@ -318,6 +323,9 @@ fn test_list_config_serde() {
     assert_eq!(parsed.remotes, None);
     assert_eq!(parsed.summary, None);
     assert_eq!(parsed.timeout_ms, Some(500));
+    assert_eq!(parsed.max_path_width, Some(30));
+    assert_eq!(parsed.layout, Some(ListLayout::Shrink));
+    assert_eq!(parsed.density, Some(ListDensity::Compact));
 }
 
 #[test]
@@ -523,13 +531,23 @@ fn test_merge_list_config() {
         remotes: None,
         summary: Some(true),
         timeout_ms: Some(1000),
+        max_path_width: Some(40),
+        compare_ref: Some("release".to_string()),
+        layout: Some(ListLayout::Shrink),
+        density: Some(ListDensity::Wide),
+        pager: Some("less".to_string()),
     };
     let override_config = ListConfig {
-        full: None,           // Should fall back to base
-        branches: Some(true), // Should override
-        remotes: Some(true),  // Should override (base was None)
-        summary: None,        // Should fall back to base
-        timeout_ms: None,     // Should fall back to base
+        full: None,                          // Should fall back to base
+        branches: Some(true),                // Should override
+        remotes: Some(true),                 // Should override (base was None)
+        summary: None,                       // Should fall back to base
+        timeout_ms: None,                    // Should fall back to base
+        max_path_width: Some(0),             // Should override
+        compare_ref: None,                   // Should fall back to base
+        layout: None,                        // Should fall back to base
+        density: Some(ListDensity::Compact), // Should override
+        pager: Some("cat".to_string()),      // Should override
     };
 
     let merged = base.merge_with(&override_config);
@@ -538,6 +556,11 @@ fn test_merge_list_config() {
     assert_eq!(merged.remotes, Some(true)); // From override
     assert_eq!(merged.summary, Some(true)); // From base
     assert_eq!(merged.timeout_ms, Some(1000)); // From base
+    assert_eq!(merged.max_path_width, Some(0)); // From override
+    assert_eq!(merged.compare_ref.as_deref(), Some("release")); // From base
+    assert_eq!(merged.layout, Some(ListLayout::Shrink)); // From base
+    assert_eq!(merged.density, Some(ListDensity::Compact)); // From override
+    assert_eq!(merged.pager.as_deref(), Some("cat")); // From override
 }
 
 #[test]
@@ -957,6 +980,10 @@ fn test_list_config_accessor_methods_defaults() {
     assert!(!config.branches());
     assert!(!config.remotes());
     assert!(config.timeout_ms().is_none());
+    assert_eq!(config.max_path_width(), Some(40));
+    assert_eq!(config.layout(), ListLayout::Drop);
+    assert_eq!(config.density(), ListDensity::Normal);
+    assert!(config.pager().is_none());
 }
 
 #[test]
@@ -967,12 +994,22 @@ fn test_list_config_accessor_methods_with_values() {
         remotes: Some(false),
         summary: Some(true),
         timeout_ms: Some(5000),
+        max_path_width: Some(0),
+        compare_ref: Some("release/2.4".to_string()),
+        layout: Some(ListLayout::Shrink),
+        density: Some(ListDensity::Wide),
+        pager: Some("less -S".to_string()),
     };
     assert!(config.full());
     assert!(config.branches());
     assert!(!config.remotes());
     assert!(config.summary());
     assert_eq!(config.timeout_ms(), Some(5000));
+    assert_eq!(config.max_path_width(), None);
+    assert_eq!(config.compare_ref(), Some("release/2.4"));
+    assert_eq!(config.layout(), ListLayout::Shrink);
+    assert_eq!(config.density(), ListDensity::Wide);
+    assert_eq!(config.pager(), Some("less -S"));
 }
 
 #[test]
@@ -1094,6 +1131,169 @@ timeout-ms = 300
     assert_eq!(picker.timeout_ms, Some(300));
 }
 
+#[test]
+fn test_keys_config_defaults_and_disabling() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[keys]
+remove = "ctrl-x"
+copy-path = ""
+"#,
+    )
+    .unwrap();
+    let keys = config.keys.unwrap();
+    assert_eq!(keys.switch(), Some("enter"));
+    assert_eq!(keys.create(), Some("alt-c"));
+    assert_eq!(keys.remove(), Some("ctrl-x"));
+    assert_eq!(keys.fetch(), Some("alt-f"));
+    assert_eq!(keys.open(), Some("alt-o"));
+    assert_eq!(keys.copy_path(), None);
+}
+
+#[test]
+fn test_theme_config_resolve() {
+    use crate::styling::{Background, Theme, parse_style};
+
+    let config: UserConfig = toml::from_str(
+        r##"
+[theme]
+behind-warn = "208 bold"
+behind-critical-at = 30
+conflicts = "#ff0000"
+"##,
+    )
+    .unwrap();
+    let config = config.theme.unwrap();
+    assert_eq!(config.background(), None);
+    let theme = config.resolve(Background::Dark).unwrap();
+    let defaults = Theme::default();
+    assert_eq!(theme.behind_warn, parse_style("208 bold").unwrap());
+    assert_eq!(theme.conflicts, parse_style("#ff0000").unwrap());
+    assert_eq!(theme.behind_critical_at, 30);
+    assert_eq!(theme.behind_warn_at, defaults.behind_warn_at);
+    assert_eq!(theme.ahead, defaults.ahead);
+
+    let invalid = ThemeConfig {
+        deleted: Some("crimson".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        invalid.resolve(Background::Dark).unwrap_err(),
+        "theme.deleted: unknown color 'crimson'"
+    );
+}
+
+#[test]
+fn test_theme_config_base() {
+    use crate::config::ThemeBase;
+    use crate::styling::{Background, Theme, parse_style};
+
+    // String shorthand sets only the base
+    let config: UserConfig = toml::from_str(r#"theme = "light""#).unwrap();
+    let theme_config = config.theme.unwrap();
+    assert_eq!(theme_config.base, Some(ThemeBase::Light));
+    assert_eq!(theme_config.background(), Some(Background::Light));
+    assert_eq!(
+        theme_config.resolve(Background::Light).unwrap(),
+        Theme::light()
+    );
+
+    let config: UserConfig = toml::from_str(r#"theme = "auto""#).unwrap();
+    assert_eq!(config.theme.unwrap().background(), None);
+
+    // Overrides apply on top of the chosen base
+    let config: UserConfig = toml::from_str(
+        r#"
+[theme]
+base = "light"
+ahead = "blue"
+"#,
+    )
+    .unwrap();
+    let theme_config = config.theme.unwrap();
+    assert_eq!(theme_config.background(), Some(Background::Light));
+    let theme = theme_config.resolve(Background::Light).unwrap();
+    assert_eq!(theme.ahead, parse_style("blue").unwrap());
+    assert_eq!(theme.behind_warn, Theme::light().behind_warn);
+
+    assert!(toml::from_str::<UserConfig>(r#"theme = "solarized""#).is_err());
+}
+
+#[test]
+fn test_activity_config_defaults() {
+    use crate::config::ActivityOnRemove;
+
+    let config: UserConfig = toml::from_str(
+        r#"
+[activity]
+enabled = true
+on-remove = "archive"
+"#,
+    )
+    .unwrap();
+    let activity = config.activity.unwrap();
+    assert!(activity.enabled());
+    assert_eq!(activity.size(), 10);
+    assert_eq!(activity.on_remove(), ActivityOnRemove::Archive);
+
+    let default = ActivityConfig::default();
+    assert!(!default.enabled());
+    assert_eq!(default.on_remove(), ActivityOnRemove::Delete);
+}
+
+#[test]
+fn test_hints_config_disabled() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[hints]
+disabled = ["first-run"]
+"#,
+    )
+    .unwrap();
+    let hints = config.hints.unwrap();
+    assert!(hints.is_disabled("first-run"));
+    assert!(!hints.is_disabled("other"));
+    assert!(!crate::config::HintsConfig::default().is_disabled("first-run"));
+}
+
+#[test]
+fn test_scratch_config_defaults() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[scratch]
+enabled = true
+"#,
+    )
+    .unwrap();
+    let scratch = config.scratch.unwrap();
+    assert!(scratch.enabled());
+    assert_eq!(scratch.dir(), ".wt-scratch");
+
+    let default = crate::config::ScratchConfig::default();
+    assert!(!default.enabled());
+}
+
+#[test]
+fn test_integrations_config() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[integrations]
+zoxide = true
+on-path-removed = "index remove {{ worktree_path }}"
+"#,
+    )
+    .unwrap();
+    let integrations = config.integrations.unwrap();
+    assert!(integrations.zoxide());
+    assert!(integrations.on_path_created.is_none());
+    assert_eq!(
+        integrations.on_path_removed.as_deref(),
+        Some("index remove {{ worktree_path }}")
+    );
+
+    assert!(!crate::config::IntegrationsConfig::default().zoxide());
+}
+
 #[test]
 fn test_switch_picker_merge() {
     use crate::config::user::{Merge, SwitchPickerConfig};
@@ -1122,12 +1322,14 @@ fn test_switch_config_merge() {
             pager: Some("delta".to_string()),
             timeout_ms: None,
         }),
+        ..Default::default()
     };
     let other = SwitchConfig {
         picker: Some(SwitchPickerConfig {
             pager: None,
             timeout_ms: Some(300),
         }),
+        ..Default::default()
     };
     let merged = base.merge_with(&other);
     assert_eq!(
@@ -1137,7 +1339,7 @@ fn test_switch_config_merge() {
     assert_eq!(merged.picker.as_ref().unwrap().timeout_ms, Some(300));
 
     // Base has picker, other doesn't
-    let other_none = SwitchConfig { picker: None };
+    let other_none = SwitchConfig::default();
     let merged = base.merge_with(&other_none);
     assert_eq!(
         merged.picker.as_ref().unwrap().pager.as_deref(),
@@ -1145,7 +1347,7 @@ fn test_switch_config_merge() {
     );
 
     // Neither has picker
-    let base_none = SwitchConfig { picker: None };
+    let base_none = SwitchConfig::default();
     let merged = base_none.merge_with(&other_none);
     assert!(merged.picker.is_none());
 }
@@ -1185,6 +1387,7 @@ fn test_switch_picker_prefers_new_over_select() {
                     pager: Some("delta".to_string()),
                     timeout_ms: Some(100),
                 }),
+                ..Default::default()
             }),
             select: Some(SelectConfig {
                 pager: Some("bat".to_string()),
@@ -1210,6 +1413,7 @@ fn test_switch_picker_project_override() {
                     pager: Some("delta".to_string()),
                     timeout_ms: Some(200),
                 }),
+                ..Default::default()
             }),
             ..Default::default()
         },
@@ -1225,6 +1429,7 @@ fn test_switch_picker_project_override() {
                         pager: Some("bat".to_string()),
                         timeout_ms: None, // Fall back to global
                     }),
+                    ..Default::default()
                 }),
                 ..Default::default()
             },
@@ -1250,6 +1455,7 @@ fn test_switch_picker_project_fallback_from_select() {
                     pager: Some("delta".to_string()),
                     timeout_ms: Some(300),
                 }),
+                ..Default::default()
             }),
             ..Default::default()
         },
@@ -1298,6 +1504,7 @@ fn test_resolved_config_for_project() {
                     pager: Some("less".to_string()),
                     timeout_ms: Some(300),
                 }),
+                ..Default::default()
             }),
             ..Default::default()
         },
@@ -1929,13 +2136,29 @@ fn test_valid_user_config_keys_all_deserialize() {
     for key in &valid_keys {
         match key.as_str() {
             "projects" => continue, // Skip - table type tested separately
-            "skip-shell-integration-prompt" | "skip-commit-generation-prompt" => {
+            "skip-shell-integration-prompt"
+            | "skip-commit-generation-prompt"
+            | "update-check"
+            | "check-cwd-users"
+            | "performance-hint"
+            | "usage-journal" => {
                 scalar_lines.push(format!("{key} = true"));
             }
             "worktree-path" => {
                 scalar_lines.push(format!("{key} = \"test-value\""));
             }
-            "list" | "commit" | "merge" | "switch" | "select" | "commit-generation" | "aliases" => {
+            "allowed-worktree-roots" => {
+                scalar_lines.push(format!("{key} = [\"~/worktrees\"]"));
+            }
+            "slug-lowercase" => {
+                scalar_lines.push(format!("{key} = \"auto\""));
+            }
+            "version" => {
+                scalar_lines.push(format!("{key} = 1"));
+            }
+            "list" | "commit" | "merge" | "switch" | "select" | "commit-generation" | "aliases"
+            | "alias" | "defaults" | "keys" | "theme" | "activity" | "scratch" | "integrations"
+            | "hints" => {
                 // Table sections with minimal content
                 table_lines.push(format!("[{key}]"));
             }
@@ -2258,3 +2481,44 @@ fn test_reload_projects_from_permission_error() {
         "Expected path in error, got: {err}"
     );
 }
+
+/// Serializing a config and loading it back must not lose settings, so tools
+/// can rewrite `config.toml` through the typed structs.
+#[test]
+fn test_config_serialization_roundtrip() {
+    let toml_roundtrip = |config: &UserConfig| {
+        let serialized = toml::to_string(config).unwrap();
+        let reloaded: UserConfig = toml::from_str(&serialized).unwrap();
+        assert_eq!(toml::to_string(&reloaded).unwrap(), serialized);
+        serialized
+    };
+
+    toml_roundtrip(&UserConfig::default());
+
+    let config: UserConfig = toml::from_str(
+        r#"
+worktree-path = "../{{ repo }}.{{ branch | sanitize }}"
+post-create = "npm install"
+
+[list]
+full = true
+
+[merge]
+squash = false
+
+[commit.generation]
+command = "llm -m haiku"
+
+[keys]
+fetch = ""
+
+[projects."github.com/user/repo"]
+approved-commands = ["npm install"]
+worktree-path = ".worktrees/{{ branch | sanitize }}"
+"#,
+    )
+    .unwrap();
+    let serialized = toml_roundtrip(&config);
+    assert!(serialized.contains("fetch = \"\""), "{serialized}");
+    assert!(serialized.contains("squash = false"), "{serialized}");
+}
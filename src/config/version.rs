@@ -0,0 +1,236 @@
+//! Config format versions and migrations between them
+//!
+//! Both config files accept a top-level `version` key. Files without one are
+//! version 1 — everything written before versioning existed.
+//!
+//! Loading upgrades older files in memory, so renamed settings keep working
+//! until the user runs `wt config migrate` to rewrite the file. Deprecation
+//! warnings for the same renames come from [`check_and_migrate`](super::check_and_migrate).
+//!
+//! Loading a file with a version newer than [`CONFIG_VERSION`] fails rather
+//! than silently ignoring settings this build doesn't understand.
+//!
+//! To add a migration: bump [`CONFIG_VERSION`] and add a [`Migration`] whose
+//! `from` is the previous version. Migrations must be idempotent and preserve
+//! comments (edit via `toml_edit`).
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use config::ConfigError;
+
+use super::deprecation::{
+    Deprecations, apply_deprecation_fixes, copy_approved_commands_to_approvals_file,
+    detect_deprecations, migration_path,
+};
+
+/// Config format version written by this build.
+pub const CONFIG_VERSION: u32 = 2;
+
+/// Key holding the format version in both config files.
+const VERSION_KEY: &str = "version";
+
+/// An upgrade from one config version to the next.
+struct Migration {
+    /// Version this migration upgrades from (to `from + 1`)
+    from: u32,
+    /// Rewrite config content; returns the content unchanged when nothing applies
+    apply: fn(&str) -> String,
+}
+
+/// Migrations in version order.
+const MIGRATIONS: &[Migration] = &[Migration {
+    // v2 renamed `[select]` → `[switch.picker]`, `[commit-generation]` →
+    // `[commit.generation]`, and the `repo_root`/`worktree`/... template variables.
+    // Approved commands stay in memory; only `wt config migrate` moves them.
+    from: 1,
+    apply: |content| {
+        let deprecations = Deprecations {
+            approved_commands: false,
+            ..detect_deprecations(content)
+        };
+        apply_deprecation_fixes(content, &deprecations)
+    },
+}];
+
+/// Read the `version` key from config content.
+///
+/// Returns 1 for files without a version (or that don't parse — the TOML
+/// error surfaces later with better context). Errors if the version is
+/// malformed or newer than this build supports.
+pub fn config_version(content: &str, label: &str) -> Result<u32, ConfigError> {
+    let Ok(table) = content.parse::<toml::Table>() else {
+        return Ok(1);
+    };
+    let Some(value) = table.get(VERSION_KEY) else {
+        return Ok(1);
+    };
+    let version = value
+        .as_integer()
+        .and_then(|v| u32::try_from(v).ok())
+        .filter(|&v| v >= 1)
+        .ok_or_else(|| {
+            ConfigError::Message(format!(
+                "{label}: `version` must be a positive integer, got {value}"
+            ))
+        })?;
+    if version > CONFIG_VERSION {
+        return Err(ConfigError::Message(format!(
+            "{label} uses config version {version}, but this worktrunk supports up to version {CONFIG_VERSION}. Please upgrade worktrunk."
+        )));
+    }
+    Ok(version)
+}
+
+/// Upgrade config content to [`CONFIG_VERSION`] for loading.
+///
+/// Returns `Cow::Borrowed` when the content is current or nothing changed.
+pub fn upgrade_in_memory<'a>(content: &'a str, label: &str) -> Result<Cow<'a, str>, ConfigError> {
+    let version = config_version(content, label)?;
+    let mut upgraded = Cow::Borrowed(content);
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= version) {
+        let next = (migration.apply)(&upgraded);
+        if next != *upgraded {
+            upgraded = Cow::Owned(next);
+        }
+    }
+    Ok(upgraded)
+}
+
+/// Outcome of rewriting a config file with [`migrate_file`].
+#[derive(Debug)]
+pub struct MigrateResult {
+    /// Copy of the original file
+    pub backup_path: PathBuf,
+    /// Version the file was at before migrating
+    pub from_version: u32,
+    /// Where approved commands were copied, if the migration moved them
+    pub approvals_copied_to: Option<PathBuf>,
+}
+
+/// Rewrite a config file at [`CONFIG_VERSION`], keeping a backup of the original.
+///
+/// Comments and formatting are preserved. Returns `Ok(None)` when the file is
+/// already current.
+pub fn migrate_file(path: &Path, label: &str) -> anyhow::Result<Option<MigrateResult>> {
+    let content = std::fs::read_to_string(path)?;
+    let from_version = config_version(&content, label)?;
+
+    // Apply every fix, including moving approved commands out of the file
+    let deprecations = detect_deprecations(&content);
+    let approvals_copied_to = if deprecations.approved_commands {
+        copy_approved_commands_to_approvals_file(path)
+    } else {
+        None
+    };
+    let fixed = apply_deprecation_fixes(&content, &deprecations);
+    let migrated = stamp_version(&fixed)?;
+
+    if migrated == content {
+        return Ok(None);
+    }
+
+    let backup_path = backup_path(path);
+    std::fs::copy(path, &backup_path)?;
+    super::state_file::write_atomic(path, &migrated)?;
+
+    // A pending `.new` file from the deprecation system is now stale
+    let _ = std::fs::remove_file(migration_path(path));
+
+    Ok(Some(MigrateResult {
+        backup_path,
+        from_version,
+        approvals_copied_to,
+    }))
+}
+
+/// Set the top-level `version` key to [`CONFIG_VERSION`], placing it first if new.
+fn stamp_version(content: &str) -> anyhow::Result<String> {
+    let mut doc = content.parse::<toml_edit::DocumentMut>()?;
+    if doc.contains_key(VERSION_KEY) {
+        doc[VERSION_KEY] = toml_edit::value(i64::from(CONFIG_VERSION));
+        return Ok(doc.to_string());
+    }
+    // toml_edit appends new keys after existing ones; prepend so the version
+    // sits above the first table
+    let separator = if content.is_empty() { "" } else { "\n" };
+    Ok(format!(
+        "{VERSION_KEY} = {CONFIG_VERSION}\n{separator}{content}"
+    ))
+}
+
+/// config.toml -> config.toml.bak
+fn backup_path(path: &Path) -> PathBuf {
+    match path.extension() {
+        Some(ext) => path.with_extension(format!("{}.bak", ext.to_string_lossy())),
+        None => path.with_extension("bak"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_version() {
+        assert_eq!(config_version("", "User config").unwrap(), 1);
+        assert_eq!(config_version("version = 2\n", "User config").unwrap(), 2);
+
+        let err = config_version("version = 99\n", "User config").unwrap_err();
+        assert!(
+            err.to_string().contains("Please upgrade worktrunk"),
+            "{err}"
+        );
+
+        assert!(config_version("version = \"2\"\n", "User config").is_err());
+        assert!(config_version("version = 0\n", "User config").is_err());
+    }
+
+    #[test]
+    fn test_upgrade_in_memory_renames_select() {
+        let content = "[select]\npager = \"delta\"\n";
+        let upgraded = upgrade_in_memory(content, "User config").unwrap();
+        assert_eq!(upgraded, "[switch.picker]\npager = \"delta\"\n");
+
+        // Current-version files are loaded as written
+        let current = "version = 2\n\n[select]\npager = \"delta\"\n";
+        assert!(matches!(
+            upgrade_in_memory(current, "User config").unwrap(),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_migrate_file_preserves_comments_and_backs_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let original = "# Picker settings\n[select]\npager = \"delta\" # side-by-side\n";
+        std::fs::write(&path, original).unwrap();
+
+        let result = migrate_file(&path, "User config").unwrap().unwrap();
+        assert_eq!(result.from_version, 1);
+        assert_eq!(
+            std::fs::read_to_string(&result.backup_path).unwrap(),
+            original
+        );
+        insta::assert_snapshot!(std::fs::read_to_string(&path).unwrap(), @r#"
+        version = 2
+
+        # Picker settings
+        [switch.picker]
+        pager = "delta" # side-by-side
+        "#);
+
+        // Migrating again is a no-op
+        assert!(migrate_file(&path, "User config").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_stamp_version_updates_existing_key() {
+        assert_eq!(
+            stamp_version("version = 1\npost-create = \"npm ci\"\n").unwrap(),
+            "version = 2\npost-create = \"npm ci\"\n"
+        );
+        assert_eq!(stamp_version("").unwrap(), "version = 2\n");
+    }
+}
@@ -8,18 +8,22 @@
 
 use std::path::{Component, Path};
 
-use unicode_width::UnicodeWidthChar;
 use worktrunk::path::format_path_for_display;
-use worktrunk::styling::visual_width;
-use worktrunk::utils::get_now;
+use worktrunk::styling::{take_width_from_end, take_width_from_start, visual_width};
+use worktrunk::utils::{Clock, display_clock};
 
 /// Format timestamp as abbreviated relative time (e.g., "2h")
+///
+/// Measured from [`display_clock`], which is fixed under `--deterministic`.
 pub(crate) fn format_relative_time_short(timestamp: i64) -> String {
-    // Cast to i64 for signed arithmetic (handles future timestamps)
-    format_relative_time_impl(timestamp, get_now() as i64)
+    format_relative_time(timestamp, display_clock().as_ref())
 }
 
-fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
+/// Format timestamp as abbreviated relative time, measured from `clock`.
+pub(crate) fn format_relative_time(timestamp: i64, clock: &dyn Clock) -> String {
+    // Cast to i64 for signed arithmetic (handles future timestamps)
+    let now = clock.now() as i64;
+
     const MINUTE: i64 = 60;
     const HOUR: i64 = MINUTE * 60;
     const DAY: i64 = HOUR * 24;
@@ -84,6 +88,19 @@ pub(crate) fn shorten_path(path: &Path, main_worktree_path: &Path) -> String {
     }
 }
 
+/// Flatten text for a single table cell.
+///
+/// Control characters (newlines, tabs, the ESC that starts an escape sequence)
+/// become spaces and whitespace runs collapse to one, so a commit subject can
+/// neither break its row nor restyle the terminal. Truncate the result, not
+/// the original, so the measured width is the rendered width.
+pub(crate) fn sanitize_for_display(text: &str) -> String {
+    text.split(|c: char| c.is_control() || c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Truncate text with ellipsis at exact width limit.
 ///
 /// Truncates at character boundary (mid-word if needed) to fill the allocated
@@ -93,23 +110,38 @@ pub(crate) fn truncate_to_width(text: &str, max_width: usize) -> String {
         return text.to_string();
     }
 
-    // Build up string until we hit the width limit (accounting for "…" = 1 width)
-    let target_width = max_width.saturating_sub(1);
-    let mut current_width = 0;
-    let mut last_idx = 0;
+    // Leave room for the "…" (1 column); cut mid-word if needed
+    let truncated = take_width_from_start(text, max_width.saturating_sub(1)).trim_end();
+    format!("{}…", truncated)
+}
 
-    for (idx, ch) in text.char_indices() {
-        let char_width = ch.width().unwrap_or(0);
-        if current_width + char_width > target_width {
-            break;
-        }
-        current_width += char_width;
-        last_idx = idx + ch.len_utf8();
+/// Ellipsize the middle of a path to fit `max_width`, keeping the final component.
+///
+/// `~/code/app…/feature-login-rework`: the head is cut at display width so
+/// wide characters never split, and the last component stays intact. If the
+/// final component alone doesn't fit, its end is kept (`…login-rework`).
+pub(crate) fn ellipsize_path_middle(path: &str, max_width: usize) -> String {
+    if visual_width(path) <= max_width {
+        return path.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
     }
 
-    // Truncate at exact character boundary (mid-word if needed)
-    let truncated = text[..last_idx].trim_end();
-    format!("{}…", truncated)
+    let tail_start = path
+        .trim_end_matches(std::path::is_separator)
+        .rfind(std::path::is_separator)
+        .unwrap_or(0);
+    let tail = &path[tail_start..];
+    let tail_width = visual_width(tail);
+
+    // Need room for the ellipsis plus at least one head character
+    if tail_width + 2 > max_width {
+        return format!("…{}", take_width_from_end(tail, max_width - 1));
+    }
+
+    let head = take_width_from_start(&path[..tail_start], max_width - 1 - tail_width);
+    format!("{head}…{tail}")
 }
 
 // Re-export from styling for convenience
@@ -120,6 +152,34 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_ellipsize_path_middle() {
+        // Fits: unchanged
+        assert_eq!(
+            ellipsize_path_middle("../repo.feature", 40),
+            "../repo.feature"
+        );
+
+        // Head is cut, final component kept intact
+        let path = "~/code/application/worktrees/feature-login-rework";
+        let result = ellipsize_path_middle(path, 32);
+        assert_eq!(result, "~/code/app…/feature-login-rework");
+        assert_eq!(visual_width(&result), 32);
+
+        // Final component too long on its own: keep its end
+        assert_eq!(
+            ellipsize_path_middle("/tmp/an-extremely-long-worktree-name", 12),
+            "…rktree-name"
+        );
+
+        // Wide characters never split across the budget
+        let result = ellipsize_path_middle("/仓库/工作树/分支名称/feature", 16);
+        assert_eq!(result, "/仓库/…/feature");
+        assert_eq!(visual_width(&result), 15);
+
+        assert_eq!(ellipsize_path_middle("/a/b", 0), "");
+    }
+
     #[test]
     fn test_truncate_normal_case() {
         let text = "Fix bug with parsing and more text here";
@@ -180,6 +240,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sanitize_for_display() {
+        assert_eq!(sanitize_for_display("Fix\nthe  thing"), "Fix the thing");
+        assert_eq!(sanitize_for_display("\tIndented\r\n"), "Indented");
+        // The ESC goes, so what's left is inert text rather than a color change
+        assert_eq!(
+            sanitize_for_display("Make \x1b[31mred\x1b[0m"),
+            "Make [31mred [0m"
+        );
+        assert_eq!(sanitize_for_display("Bell\x07 and del\x7f"), "Bell and del");
+        assert_eq!(sanitize_for_display("日本語 ☕"), "日本語 ☕");
+    }
+
+    #[test]
+    fn test_sanitized_message_truncates_with_ellipsis() {
+        let message = sanitize_for_display("Squashed: first\nsecond\nthird change");
+        let result = truncate_to_width(&message, 20);
+        assert_eq!(result, "Squashed: first sec…");
+    }
+
     #[test]
     fn test_truncate_no_truncation_needed() {
         let text = "Short message";
@@ -200,35 +280,36 @@ mod tests {
     #[test]
     fn test_format_relative_time_short() {
         let now: i64 = 1700000000; // Fixed timestamp for testing
+        let clock = worktrunk::utils::FixedClock(now as u64);
 
         // Just now (< 1 minute)
-        assert_eq!(format_relative_time_impl(now - 30, now), "now");
-        assert_eq!(format_relative_time_impl(now - 59, now), "now");
+        assert_eq!(format_relative_time(now - 30, &clock), "now");
+        assert_eq!(format_relative_time(now - 59, &clock), "now");
 
         // Minutes
-        assert_eq!(format_relative_time_impl(now - 60, now), "1m");
-        assert_eq!(format_relative_time_impl(now - 120, now), "2m");
-        assert_eq!(format_relative_time_impl(now - 3599, now), "59m");
+        assert_eq!(format_relative_time(now - 60, &clock), "1m");
+        assert_eq!(format_relative_time(now - 120, &clock), "2m");
+        assert_eq!(format_relative_time(now - 3599, &clock), "59m");
 
         // Hours
-        assert_eq!(format_relative_time_impl(now - 3600, now), "1h");
-        assert_eq!(format_relative_time_impl(now - 7200, now), "2h");
+        assert_eq!(format_relative_time(now - 3600, &clock), "1h");
+        assert_eq!(format_relative_time(now - 7200, &clock), "2h");
 
         // Days
-        assert_eq!(format_relative_time_impl(now - 86400, now), "1d");
-        assert_eq!(format_relative_time_impl(now - 172800, now), "2d");
+        assert_eq!(format_relative_time(now - 86400, &clock), "1d");
+        assert_eq!(format_relative_time(now - 172800, &clock), "2d");
 
         // Weeks
-        assert_eq!(format_relative_time_impl(now - 604800, now), "1w");
+        assert_eq!(format_relative_time(now - 604800, &clock), "1w");
 
         // Months
-        assert_eq!(format_relative_time_impl(now - 2592000, now), "1mo");
+        assert_eq!(format_relative_time(now - 2592000, &clock), "1mo");
 
         // Years
-        assert_eq!(format_relative_time_impl(now - 31536000, now), "1y");
+        assert_eq!(format_relative_time(now - 31536000, &clock), "1y");
 
         // Future timestamp
-        assert_eq!(format_relative_time_impl(now + 1000, now), "future");
+        assert_eq!(format_relative_time(now + 1000, &clock), "future");
     }
 
     #[test]
@@ -285,7 +366,7 @@ mod tests {
 
     #[test]
     fn test_format_relative_time_short_public() {
-        // Test the public function (uses get_now internally)
+        // Test the public function (uses display_clock internally)
         let result = format_relative_time_short(0);
         // A timestamp of 0 (Unix epoch) should show years ago
         assert!(
@@ -296,11 +377,14 @@ mod tests {
     }
 
     #[test]
-    fn test_get_now() {
-        // get_now should return a reasonable timestamp
-        let now = get_now();
+    fn test_display_clock() {
+        // display_clock should return a reasonable timestamp
+        let now = display_clock().now();
         // Should be after 2020 (1577836800)
-        assert!(now > 1577836800, "get_now() should return current time");
+        assert!(
+            now > 1577836800,
+            "display_clock() should return current time"
+        );
     }
 
     #[test]
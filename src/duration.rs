@@ -0,0 +1,101 @@
+//! Parsing for points in time given on the command line (`wt list --since`
+//! and `--until`).
+//!
+//! A point in time is either a `humantime` duration counted back from now,
+//! such as `90m`, `2w`, or `1h30m`, or an ISO date or date-time.
+
+use anyhow::bail;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
+
+/// Parse a point in time as a Unix timestamp: a duration counts back from
+/// `now`; otherwise an ISO date (`2025-01-31`, midnight local time) or
+/// date-time (`2025-01-31T09:00`, with an optional UTC offset).
+pub fn parse_time_point(input: &str, now: u64) -> anyhow::Result<i64> {
+    let trimmed = input.trim();
+    let now = i64::try_from(now).unwrap_or(i64::MAX);
+
+    if trimmed.starts_with(|c: char| c.is_ascii_digit()) && !trimmed.contains('-') {
+        let ago = humantime::parse_duration(trimmed)?;
+        let ago = i64::try_from(ago.as_secs()).unwrap_or(i64::MAX);
+        return Ok(now.saturating_sub(ago));
+    }
+
+    if let Ok(time) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(time.timestamp());
+    }
+    let local = [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(trimmed, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    });
+    if let Some(time) = local
+        && let Some(time) = Local.from_local_datetime(&time).earliest()
+    {
+        return Ok(time.timestamp());
+    }
+
+    bail!("expected a duration like `7d` or a date like `2025-01-31`, got `{trimmed}`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_point() {
+        let now = 1_735_776_000; // 2025-01-02T00:00:00Z
+        assert_eq!(
+            parse_time_point("7d", now).unwrap(),
+            1_735_776_000 - 7 * 86_400
+        );
+        assert_eq!(parse_time_point("90m", now).unwrap(), 1_735_776_000 - 5400);
+        assert_eq!(parse_time_point("0", now).unwrap(), 1_735_776_000);
+
+        // Explicit offsets don't depend on the local timezone
+        assert_eq!(
+            parse_time_point("2025-01-01T00:00:00Z", now).unwrap(),
+            1_735_689_600
+        );
+        assert_eq!(
+            parse_time_point("2025-01-01T02:00:00+02:00", now).unwrap(),
+            1_735_689_600
+        );
+
+        // Dates and offset-less times are local
+        let midnight = Local
+            .with_ymd_and_hms(2025, 1, 31, 0, 0, 0)
+            .earliest()
+            .unwrap()
+            .timestamp();
+        assert_eq!(parse_time_point("2025-01-31", now).unwrap(), midnight);
+        assert_eq!(
+            parse_time_point("2025-01-31T09:30", now).unwrap(),
+            midnight + 34_200
+        );
+    }
+
+    #[test]
+    fn test_parse_time_point_rejects_garbage() {
+        for input in [
+            "",
+            "yesterday",
+            "2025-13-01",
+            "2025-01-32",
+            "7x",
+            "01/31/2025",
+        ] {
+            assert!(
+                parse_time_point(input, 0).is_err(),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+}
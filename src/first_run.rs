@@ -0,0 +1,75 @@
+//! How many times the first-run shell integration banner has been shown.
+//!
+//! The banner explains what shell integration does and offers to install it.
+//! It appears at most [`BANNER_LIMIT`] times in total, so a user who keeps
+//! dismissing the prompt with Ctrl+C isn't asked forever; after that only the
+//! one-line hint remains. A successful `wt config shell install` silences it
+//! for good. The count lives in `<cache dir>/worktrunk/first-run.json`.
+
+use std::path::PathBuf;
+
+use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
+use serde::{Deserialize, Serialize};
+use worktrunk::config::UserConfig;
+use worktrunk::config::state_file::{self, StateFile};
+
+/// Name under `[hints] disabled` that silences the banner.
+pub(crate) const HINT_NAME: &str = "first-run";
+
+/// Most times the banner is shown.
+const BANNER_LIMIT: u32 = 3;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FirstRunState {
+    /// Times the banner has been shown
+    shown: u32,
+    /// Set once shell integration has been installed
+    silenced: bool,
+}
+
+impl StateFile for FirstRunState {
+    const VERSION: u32 = 1;
+}
+
+fn state_path() -> Option<PathBuf> {
+    let strategy = choose_base_strategy().ok()?;
+    Some(
+        strategy
+            .cache_dir()
+            .join("worktrunk")
+            .join("first-run.json"),
+    )
+}
+
+/// Whether to show the banner now, counting it as shown if so.
+pub(crate) fn claim_banner(config: &UserConfig) -> bool {
+    if config
+        .hints
+        .as_ref()
+        .is_some_and(|hints| hints.is_disabled(HINT_NAME))
+    {
+        return false;
+    }
+    let Some(path) = state_path() else {
+        return false;
+    };
+    state_file::update(&path, |state: &mut FirstRunState| {
+        if state.silenced || state.shown >= BANNER_LIMIT {
+            return false;
+        }
+        state.shown += 1;
+        true
+    })
+    .inspect_err(|e| log::debug!("Failed to update first-run state: {e}"))
+    .unwrap_or(false)
+}
+
+/// Never show the banner again; called once shell integration is installed.
+pub(crate) fn silence() {
+    let Some(path) = state_path() else {
+        return;
+    };
+    if let Err(e) = state_file::update(&path, |state: &mut FirstRunState| state.silenced = true) {
+        log::debug!("Failed to update first-run state: {e}");
+    }
+}
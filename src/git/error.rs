@@ -12,14 +12,17 @@
 use std::borrow::Cow;
 use std::path::PathBuf;
 
+use ansi_str::AnsiStr;
 use color_print::{cformat, cwrite};
+use serde::Serialize;
+use serde_json::{Value, json};
 use shell_escape::escape;
 
 use super::HookType;
 use crate::path::format_path_for_display;
 use crate::styling::{
     ERROR_SYMBOL, HINT_SYMBOL, error_message, format_bash_with_gutter, format_with_gutter,
-    hint_message, info_message, suggest_command,
+    hint_message, info_message, suggest_command, suggest_command_in_dir,
 };
 
 /// Platform-specific reference type (PR vs MR).
@@ -159,7 +162,7 @@ impl SwitchSuggestionCtx {
 ///     println!("Branch {} exists", branch);
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, strum::IntoStaticStr)]
 pub enum GitError {
     // Git state errors
     DetachedHead {
@@ -180,11 +183,32 @@ pub enum GitError {
         /// Show hint about creating the branch. Set to false for remove operations
         /// where suggesting creation doesn't make sense.
         show_create_hint: bool,
+        /// Closest branch or worktree directory name, for likely typos
+        closest: Option<String>,
     },
     /// Reference (branch, tag, commit) not found - used when any commit-ish is accepted
     ReferenceNotFound {
         reference: String,
     },
+    /// --create without a base in a repository whose HEAD has no commits yet
+    NoCommitsYet {
+        branch: String,
+    },
+    /// A new branch name breaks the project's `[branch-name-rules]`
+    BranchNameRejected {
+        branch: String,
+        /// The broken rule as written in config
+        rule: String,
+        /// A fixed-up name that passes, offered via `--fix-name`
+        suggestion: Option<String>,
+    },
+    /// `--tag` name that git won't accept as `refs/tags/<name>`
+    InvalidTagName {
+        tag: String,
+    },
+    TagAlreadyExists {
+        tag: String,
+    },
 
     // Worktree errors
     NotInWorktree {
@@ -194,6 +218,35 @@ pub enum GitError {
     WorktreeMissing {
         branch: String,
     },
+    /// A `wt` creating this worktree died before the checkout finished
+    WorktreeCreationInterrupted {
+        branch: String,
+        path: PathBuf,
+    },
+    WorktreeLimitReached {
+        count: usize,
+        max: usize,
+    },
+    /// A worktree would be created or moved outside `allowed-worktree-roots`
+    WorktreePathNotAllowed {
+        path: PathBuf,
+        roots: Vec<PathBuf>,
+    },
+    /// Recording a stack parent would make a branch its own ancestor
+    StackParentCycle {
+        branch: String,
+        /// Recorded parents from the new parent back round to `branch`
+        chain: Vec<String>,
+    },
+    /// A new worktree's `user.email` fails the project's `[identity]` rules
+    IdentityRejected {
+        path: PathBuf,
+        /// The email git resolves in the new worktree, if any
+        email: Option<String>,
+        /// Where that email is set, from `git config --show-origin`
+        origin: Option<String>,
+        pattern: String,
+    },
     RemoteOnlyBranch {
         branch: String,
         remote: String,
@@ -203,6 +256,13 @@ pub enum GitError {
         path: PathBuf,
         occupant: Option<String>,
     },
+    BranchCheckedOutElsewhere {
+        branch: String,
+        /// Where the branch is checked out
+        path: PathBuf,
+        /// Where `--new-path` asked for another checkout
+        new_path: PathBuf,
+    },
     WorktreePathExists {
         branch: String,
         path: PathBuf,
@@ -223,11 +283,23 @@ pub enum GitError {
         remaining_entries: Option<Vec<String>>,
     },
     CannotRemoveMainWorktree,
+    /// Removing a worktree whose branch was deleted would lose its last commit,
+    /// which no branch or tag contains
+    DeletedBranchUnreferenced {
+        branch: String,
+        commit: String,
+    },
     WorktreeLocked {
         branch: String,
         path: PathBuf,
         reason: Option<String>,
     },
+    /// Deleting a branch whose name isn't valid UTF-8: wt only holds it lossily,
+    /// so git would be asked for a branch that doesn't exist
+    BranchNameNotUtf8 {
+        branch: String,
+        path: PathBuf,
+    },
 
     // Merge/push errors
     ConflictingChanges {
@@ -276,6 +348,10 @@ pub enum GitError {
     WorktreeNotFound {
         branch: String,
     },
+    /// `wt which` path that no registered worktree contains
+    PathNotInWorktree {
+        path: PathBuf,
+    },
     /// --create flag used with pr:/mr: syntax (conflict - branch already exists)
     RefCreateConflict {
         ref_type: RefType,
@@ -401,6 +477,7 @@ impl GitError {
             GitError::BranchNotFound {
                 branch,
                 show_create_hint,
+                closest,
             } => {
                 let list_cmd = suggest_command("list", &[], &["--branches", "--remotes"]);
                 let hint = if *show_create_hint {
@@ -414,12 +491,13 @@ impl GitError {
                 } else {
                     cformat!("To list branches, run <underline>{list_cmd}</>")
                 };
-                write!(
-                    f,
-                    "{}\n{}",
-                    error_message(cformat!("No branch named <bold>{branch}</>")),
-                    hint_message(hint)
-                )
+                let message = match closest {
+                    Some(closest) => {
+                        cformat!("No branch named <bold>{branch}</> — perhaps <bold>{closest}</>?")
+                    }
+                    None => cformat!("No branch named <bold>{branch}</>"),
+                };
+                write!(f, "{}\n{}", error_message(message), hint_message(hint))
             }
 
             GitError::ReferenceNotFound { reference } => {
@@ -432,6 +510,20 @@ impl GitError {
                 )
             }
 
+            GitError::NoCommitsYet { branch } => {
+                let orphan_cmd = suggest_command("switch", &[branch], &["--create", "--orphan"]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot create <bold>{branch}</>: repository has no commits yet"
+                    )),
+                    hint_message(cformat!(
+                        "Make a first commit, or to create an empty branch, run <underline>{orphan_cmd}</>"
+                    ))
+                )
+            }
+
             GitError::NotInWorktree { action } => {
                 let message = match action {
                     Some(action) => cformat!("Cannot {action}: not in a worktree"),
@@ -458,6 +550,151 @@ impl GitError {
                 )
             }
 
+            GitError::WorktreeCreationInterrupted { branch, path } => {
+                let path_display = format_path_for_display(path);
+                let mut switch_cmd = suggest_command("switch", &[branch], &["--clobber"]);
+                if let Some(ctx) = ctx {
+                    switch_cmd = ctx.apply(switch_cmd);
+                }
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Creating the worktree for <bold>{branch}</> at <bold>{path_display}</> didn't finish"
+                    )),
+                    hint_message(cformat!(
+                        "To remove it and create it again, run <underline>{switch_cmd}</>"
+                    ))
+                )
+            }
+
+            GitError::BranchNameRejected {
+                branch,
+                rule,
+                suggestion,
+            } => {
+                let hint = match suggestion {
+                    Some(suggestion) => cformat!(
+                        "To create <bold>{suggestion}</> instead, add <underline>--fix-name</>; to skip the rules, add <underline>--no-name-rules</>"
+                    ),
+                    None => cformat!(
+                        "Rules are under <bold>[branch-name-rules]</> in project config; to skip them, add <underline>--no-name-rules</>"
+                    ),
+                };
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Branch name <bold>{branch}</> breaks the naming rule <bold>{rule}</>"
+                    )),
+                    hint_message(hint)
+                )
+            }
+
+            GitError::InvalidTagName { tag } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!("<bold>{tag}</> is not a valid tag name")),
+                    hint_message(cformat!(
+                        "Check it with <underline>git check-ref-format --allow-onelevel refs/tags/{tag}</>"
+                    ))
+                )
+            }
+
+            GitError::TagAlreadyExists { tag } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!("Tag <bold>{tag}</> already exists")),
+                    hint_message(cformat!(
+                        "To see what it points at, run <underline>git show --no-patch {tag}</>"
+                    ))
+                )
+            }
+
+            GitError::IdentityRejected {
+                path,
+                email,
+                origin,
+                pattern,
+            } => {
+                let found = match (email, origin) {
+                    (Some(email), Some(origin)) => {
+                        cformat!("<bold>{email}</> (set in {origin})")
+                    }
+                    (Some(email), None) => cformat!("<bold>{email}</>"),
+                    (None, _) => "unset".to_string(),
+                };
+                let path = format_path_for_display(path);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "New worktree's user.email is {found}, which doesn't match <bold>require-email-pattern = {pattern:?}</>"
+                    )),
+                    hint_message(cformat!(
+                        "The worktree @ <bold>{path}</> was created without running hooks; set user.email there before committing"
+                    ))
+                )
+            }
+
+            GitError::WorktreeLimitReached { count, max } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Worktree limit reached: <bold>{count}</> worktrees exist (max-worktrees = {max})"
+                    )),
+                    hint_message(cformat!(
+                        "To free up slots, run <underline>wt step prune</> or <underline>wt remove</>"
+                    ))
+                )
+            }
+
+            GitError::WorktreePathNotAllowed { path, roots } => {
+                let path_display = format_path_for_display(path);
+                let roots = roots
+                    .iter()
+                    .map(|root| format_path_for_display(root))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                write!(
+                    f,
+                    "{}\n{}\n{}",
+                    error_message(cformat!(
+                        "Worktree path <bold>{path_display}</> is outside allowed-worktree-roots:"
+                    )),
+                    format_with_gutter(&roots, None),
+                    hint_message(
+                        "To allow it, add a directory containing it to allowed-worktree-roots in user config"
+                    )
+                )
+            }
+
+            GitError::StackParentCycle { branch, chain } => {
+                let parent = chain.first().unwrap_or(branch);
+                let cycle = std::iter::once(branch)
+                    .chain(chain)
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" → ");
+                let message = error_message(cformat!(
+                    "Can't stack <bold>{branch}</> on <bold>{parent}</>: parents would loop ({cycle})"
+                ));
+                // The branch recorded as stacked on `branch` closes the loop
+                match chain.len().checked_sub(2).map(|i| &chain[i]) {
+                    Some(child) => write!(
+                        f,
+                        "{message}\n{}",
+                        hint_message(cformat!(
+                            "To unstack <bold>{child}</>, run <underline>wt parent clear --branch={child}</>"
+                        ))
+                    ),
+                    None => write!(f, "{message}"),
+                }
+            }
+
             GitError::RemoteOnlyBranch { branch, remote } => {
                 let cmd = suggest_command("switch", &[branch], &[]);
                 cwrite!(
@@ -492,6 +729,39 @@ impl GitError {
                 )
             }
 
+            GitError::BranchCheckedOutElsewhere {
+                branch,
+                path,
+                new_path,
+            } => {
+                let path_display = format_path_for_display(path);
+                let new_display = format_path_for_display(new_path);
+                let switch_cmd = suggest_command("switch", &[branch], &[]);
+                let detach_cmd = suggest_command(
+                    "switch",
+                    &[branch],
+                    &["--new-path", &new_display, "--detach"],
+                );
+                let force_cmd = suggest_command(
+                    "switch",
+                    &[branch],
+                    &["--new-path", &new_display, "--force"],
+                );
+                write!(
+                    f,
+                    "{}\n{}\n{}",
+                    error_message(cformat!(
+                        "Cannot check out <bold>{branch}</> at <bold>{new_display}</> — it's already checked out at <bold>{path_display}</>"
+                    )),
+                    hint_message(cformat!(
+                        "Git allows a branch in only one worktree; to switch to it, run <underline>{switch_cmd}</>"
+                    )),
+                    hint_message(cformat!(
+                        "For a detached HEAD at its commit, run <underline>{detach_cmd}</>; to check it out twice anyway, run <underline>{force_cmd}</> (a commit in either worktree leaves the other out of date)"
+                    ))
+                )
+            }
+
             GitError::WorktreePathExists {
                 branch,
                 path,
@@ -590,6 +860,21 @@ impl GitError {
                 )
             }
 
+            GitError::DeletedBranchUnreferenced { branch, commit } => {
+                let short = &commit[..commit.len().min(8)];
+                let cmd = suggest_command("remove", &[branch], &["-D"]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot remove <bold>{branch}</>: its branch was deleted, and commit <bold>{short}</> is on no other branch or tag"
+                    )),
+                    hint_message(cformat!(
+                        "To keep it, run <underline>git branch {branch} {short}</>; to discard it, run <underline>{cmd}</>"
+                    ))
+                )
+            }
+
             GitError::WorktreeLocked {
                 branch,
                 path,
@@ -612,6 +897,20 @@ impl GitError {
                 )
             }
 
+            GitError::BranchNameNotUtf8 { branch, path } => {
+                let keep = suggest_command_in_dir(path, "remove", &[], &["--no-delete-branch"]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot delete <bold>{branch}</>: its name isn't valid UTF-8, so wt can't pass it to git"
+                    )),
+                    hint_message(cformat!(
+                        "To remove only the worktree, run <underline>{keep}</>; to delete the branch too, rename it first with <underline>git branch -m</>"
+                    ))
+                )
+            }
+
             GitError::ConflictingChanges {
                 target_branch,
                 files,
@@ -804,7 +1103,21 @@ impl GitError {
                     "{}\n{}",
                     error_message(cformat!("Branch <bold>{branch}</> has no worktree")),
                     hint_message(cformat!(
-                        "To create a worktree, run <underline>{switch_cmd}</>"
+                        "To create a worktree, run <underline>{switch_cmd}</>; to see existing worktrees, run <underline>wt list</>"
+                    ))
+                )
+            }
+
+            GitError::PathNotInWorktree { path } => {
+                let path_display = format_path_for_display(path);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "<bold>{path_display}</> is not inside any worktree"
+                    )),
+                    hint_message(cformat!(
+                        "To see this repository's worktrees, run <underline>wt list</>"
                     ))
                 )
             }
@@ -902,11 +1215,187 @@ impl std::fmt::Display for GitError {
     }
 }
 
+impl GitError {
+    /// Variant name, used as the `error` field of `--json-errors` output.
+    ///
+    /// `WithSwitchSuggestion` reports the error it wraps.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            GitError::WithSwitchSuggestion { source, .. } => source.kind(),
+            _ => self.into(),
+        }
+    }
+
+    /// Structured fields of the variant, for `--json-errors` output.
+    pub fn details(&self) -> Value {
+        let path = |p: &PathBuf| p.display().to_string();
+        match self {
+            GitError::WithSwitchSuggestion { source, .. } => source.details(),
+            GitError::DetachedHead { action } | GitError::NotInWorktree { action } => {
+                json!({ "action": action })
+            }
+            GitError::UncommittedChanges {
+                action,
+                branch,
+                force_hint: _,
+            } => json!({ "action": action, "branch": branch }),
+            GitError::BranchAlreadyExists { branch }
+            | GitError::NoCommitsYet { branch }
+            | GitError::WorktreeMissing { branch }
+            | GitError::WorktreeNotFound { branch } => json!({ "branch": branch }),
+            GitError::BranchNotFound {
+                branch, closest, ..
+            } => json!({ "branch": branch, "closest": closest }),
+            GitError::ReferenceNotFound { reference } => json!({ "reference": reference }),
+            GitError::BranchNameRejected {
+                branch,
+                rule,
+                suggestion,
+            } => json!({ "branch": branch, "rule": rule, "suggestion": suggestion }),
+            GitError::InvalidTagName { tag } | GitError::TagAlreadyExists { tag } => {
+                json!({ "tag": tag })
+            }
+            GitError::WorktreeLimitReached { count, max } => json!({ "count": count, "max": max }),
+            GitError::WorktreePathNotAllowed { path: p, roots } => {
+                json!({ "path": path(p), "roots": roots.iter().map(path).collect::<Vec<_>>() })
+            }
+            GitError::StackParentCycle { branch, chain } => {
+                json!({ "branch": branch, "chain": chain })
+            }
+            GitError::IdentityRejected {
+                path: p,
+                email,
+                origin,
+                pattern,
+            } => {
+                json!({ "path": path(p), "email": email, "origin": origin, "pattern": pattern })
+            }
+            GitError::RemoteOnlyBranch { branch, remote } => {
+                json!({ "branch": branch, "remote": remote })
+            }
+            GitError::WorktreePathOccupied {
+                branch,
+                path: p,
+                occupant,
+            } => json!({ "branch": branch, "path": path(p), "occupant": occupant }),
+            GitError::BranchCheckedOutElsewhere {
+                branch,
+                path: p,
+                new_path,
+            } => json!({ "branch": branch, "path": path(p), "new_path": path(new_path) }),
+            GitError::WorktreePathExists {
+                branch, path: p, ..
+            }
+            | GitError::WorktreeCreationInterrupted { branch, path: p } => {
+                json!({ "branch": branch, "path": path(p) })
+            }
+            GitError::WorktreeCreationFailed {
+                branch,
+                base_branch,
+                error,
+                command,
+            } => json!({
+                "branch": branch,
+                "base_branch": base_branch,
+                "error": error,
+                "command": command.as_ref().map(|c| &c.command),
+            }),
+            GitError::WorktreeRemovalFailed {
+                branch,
+                path: p,
+                error,
+                remaining_entries,
+            } => json!({
+                "branch": branch,
+                "path": path(p),
+                "error": error,
+                "remaining_entries": remaining_entries,
+            }),
+            GitError::CannotRemoveMainWorktree | GitError::NotInteractive => json!({}),
+            GitError::DeletedBranchUnreferenced { branch, commit } => {
+                json!({ "branch": branch, "commit": commit })
+            }
+            GitError::WorktreeLocked {
+                branch,
+                path: p,
+                reason,
+            } => json!({ "branch": branch, "path": path(p), "reason": reason }),
+            GitError::BranchNameNotUtf8 { branch, path: p } => {
+                json!({ "branch": branch, "path": path(p) })
+            }
+            GitError::ConflictingChanges {
+                target_branch,
+                files,
+                worktree_path,
+            } => json!({
+                "target_branch": target_branch,
+                "files": files,
+                "worktree_path": path(worktree_path),
+            }),
+            GitError::NotFastForward { target_branch, .. }
+            | GitError::NotRebased { target_branch } => {
+                json!({ "target_branch": target_branch })
+            }
+            GitError::RebaseConflict {
+                target_branch,
+                git_output,
+            } => json!({ "target_branch": target_branch, "git_output": git_output }),
+            GitError::PushFailed {
+                target_branch,
+                error,
+            } => json!({ "target_branch": target_branch, "error": error }),
+            GitError::HookCommandNotFound { name, available } => {
+                json!({ "name": name, "available": available })
+            }
+            GitError::ParseError { message } | GitError::Other { message } => {
+                json!({ "message": message })
+            }
+            GitError::WorktreeIncludeParseError { error } => json!({ "error": error }),
+            GitError::LlmCommandFailed {
+                command,
+                error,
+                reproduction_command,
+            } => json!({
+                "command": command,
+                "error": error,
+                "reproduction_command": reproduction_command,
+            }),
+            GitError::ProjectConfigNotFound { config_path } => {
+                json!({ "config_path": path(config_path) })
+            }
+            GitError::PathNotInWorktree { path: not_in } => json!({ "path": path(not_in) }),
+            GitError::RefCreateConflict {
+                ref_type,
+                number,
+                branch,
+            } => json!({ "ref_type": ref_type.name(), "number": number, "branch": branch }),
+            GitError::RefBaseConflict { ref_type, number } => {
+                json!({ "ref_type": ref_type.name(), "number": number })
+            }
+            GitError::BranchTracksDifferentRef {
+                branch,
+                ref_type,
+                number,
+            } => json!({ "branch": branch, "ref_type": ref_type.name(), "number": number }),
+            GitError::NoRemoteForRepo {
+                owner,
+                repo,
+                suggested_url,
+            } => json!({ "owner": owner, "repo": repo, "suggested_url": suggested_url }),
+            GitError::CliApiError {
+                ref_type,
+                message,
+                stderr,
+            } => json!({ "ref_type": ref_type.name(), "message": message, "stderr": stderr }),
+        }
+    }
+}
+
 /// Semantic errors that require special handling in main.rs
 ///
 /// Most errors use anyhow::bail! with formatted messages. This enum is only
 /// for cases that need exit code extraction or special handling.
-#[derive(Debug)]
+#[derive(Debug, strum::IntoStaticStr)]
 pub enum WorktrunkError {
     /// Child process exited with non-zero code (preserves exit code for signals)
     ChildProcessExited { code: i32, message: String },
@@ -964,6 +1453,29 @@ impl std::fmt::Display for WorktrunkError {
 
 impl std::error::Error for WorktrunkError {}
 
+impl WorktrunkError {
+    /// Structured fields of the variant, for `--json-errors` output.
+    fn details(&self) -> Value {
+        match self {
+            WorktrunkError::ChildProcessExited { code, .. } => json!({ "code": code }),
+            WorktrunkError::HookCommandFailed {
+                hook_type,
+                command_name,
+                error,
+                exit_code,
+            } => json!({
+                "hook_type": hook_type.to_string(),
+                "command_name": command_name,
+                "error": error,
+                "exit_code": exit_code,
+            }),
+            WorktrunkError::CommandNotApproved | WorktrunkError::AlreadyDisplayed { .. } => {
+                json!({})
+            }
+        }
+    }
+}
+
 /// Extract exit code from WorktrunkError, if applicable
 pub fn exit_code(err: &anyhow::Error) -> Option<i32> {
     // Check for wrapped HookErrorWithHint first
@@ -1040,6 +1552,100 @@ impl std::error::Error for HookErrorWithHint {
     }
 }
 
+/// The `--json-errors` object for an error.
+///
+/// Fields serialize in declaration order, so every error reads the same way
+/// from left to right. Displays as one line of JSON.
+#[derive(Debug, Serialize)]
+pub struct ErrorJson {
+    pub error: &'static str,
+    pub message: String,
+    pub hints: Vec<String>,
+    pub exit_code: i32,
+    pub details: Value,
+}
+
+impl std::fmt::Display for ErrorJson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+/// Build the `--json-errors` object for an error.
+///
+/// `exit_code` is passed in rather than derived so the JSON always reports the
+/// code the process actually exits with. The message and hints are the plain
+/// text of the error and hint lines of the human rendering; gutter content
+/// (git output, command lines) is left to `details`.
+pub fn error_json(err: &anyhow::Error, exit_code: i32) -> ErrorJson {
+    let (kind, rendered, details) = if let Some(e) = err.downcast_ref::<GitError>() {
+        (e.kind(), e.to_string(), e.details())
+    } else if let Some(e) = err.downcast_ref::<HookErrorWithHint>() {
+        let details = match e.inner.downcast_ref::<WorktrunkError>() {
+            Some(inner) => inner.details(),
+            None => json!({}),
+        };
+        ("HookCommandFailed", e.to_string(), details)
+    } else if let Some(e) = err.downcast_ref::<WorktrunkError>() {
+        (e.into(), e.to_string(), e.details())
+    } else if let Some(e) = err.downcast_ref::<crate::config::TemplateExpandError>() {
+        let details = json!({
+            "source_line": e.source_line,
+            "available_vars": e.available_vars,
+        });
+        ("TemplateExpandError", e.to_string(), details)
+    } else {
+        // Plain anyhow errors have no structure beyond their context chain
+        let causes: Vec<String> = err
+            .chain()
+            .skip(1)
+            .map(|c| c.to_string().ansi_strip().into_owned())
+            .collect();
+        return ErrorJson {
+            error: "Error",
+            message: err.to_string().ansi_strip().into_owned(),
+            hints: Vec::new(),
+            exit_code,
+            details: json!({ "causes": causes }),
+        };
+    };
+
+    let rendered = rendered.ansi_strip();
+    let mut message = Vec::new();
+    let mut hints = Vec::new();
+    for line in rendered.lines() {
+        if let Some(text) = line.strip_prefix("✗ ") {
+            message.push(text.to_string());
+        } else if let Some(text) = line.strip_prefix("↳ ") {
+            hints.push(text.to_string());
+        }
+    }
+    ErrorJson {
+        error: kind,
+        message: message.join("\n"),
+        hints,
+        exit_code,
+        details: strip_ansi_values(details),
+    }
+}
+
+/// Strip ANSI styling from every string in a `details` value; variants like
+/// `Other` carry `cformat!` text.
+fn strip_ansi_values(value: Value) -> Value {
+    match value {
+        Value::String(s) => Value::String(s.ansi_strip().into_owned()),
+        Value::Array(items) => Value::Array(items.into_iter().map(strip_ansi_values).collect()),
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key, strip_ansi_values(value)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
 /// Format an error with header and gutter content
 fn format_error_block(header: impl Into<String>, error: &str) -> String {
     let header = header.into();
@@ -1085,6 +1691,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn snapshot_no_commits_yet() {
+        let err = GitError::NoCommitsYet {
+            branch: "feature".into(),
+        };
+        assert_snapshot!(err.to_string(), @"
+        [31m✗[39m [31mCannot create [1mfeature[22m: repository has no commits yet[39m
+        [2m↳[22m [2mMake a first commit, or to create an empty branch, run [4mwt switch --create --orphan feature[24m[22m
+        ");
+    }
+
     #[test]
     fn snapshot_worktree_error_with_path_and_create() {
         let err = GitError::WorktreePathExists {
@@ -1281,6 +1898,20 @@ mod tests {
         ");
     }
 
+    #[test]
+    fn snapshot_branch_checked_out_elsewhere() {
+        let err = GitError::BranchCheckedOutElsewhere {
+            branch: "feature".into(),
+            path: PathBuf::from("/tmp/repo.feature"),
+            new_path: PathBuf::from("/tmp/review"),
+        };
+        assert_snapshot!(err.to_string(), @"
+        [31m✗[39m [31mCannot check out [1mfeature[22m at [1m/tmp/review[22m — it's already checked out at [1m/tmp/repo.feature[22m[39m
+        [2m↳[22m [2mGit allows a branch in only one worktree; to switch to it, run [4mwt switch feature[24m[22m
+        [2m↳[22m [2mFor a detached HEAD at its commit, run [4mwt switch --new-path /tmp/review --detach feature[24m; to check it out twice anyway, run [4mwt switch --new-path /tmp/review --force feature[24m (a commit in either worktree leaves the other out of date)[22m
+        ");
+    }
+
     #[test]
     fn snapshot_worktree_creation_failed() {
         let err = GitError::WorktreeCreationFailed {
@@ -1322,6 +1953,18 @@ mod tests {
         ");
     }
 
+    #[test]
+    fn snapshot_deleted_branch_unreferenced() {
+        let err = GitError::DeletedBranchUnreferenced {
+            branch: "feature".into(),
+            commit: "1f6148ddfafe9ce28d15b6da038f8cf4859a1c48".into(),
+        };
+        assert_snapshot!(err.to_string(), @"
+        [31m✗[39m [31mCannot remove [1mfeature[22m: its branch was deleted, and commit [1m1f6148dd[22m is on no other branch or tag[39m
+        [2m↳[22m [2mTo keep it, run [4mgit branch feature 1f6148dd[24m; to discard it, run [4mwt remove -D feature[24m[22m
+        ");
+    }
+
     #[test]
     fn snapshot_worktree_locked() {
         let err = GitError::WorktreeLocked {
@@ -1603,6 +2246,7 @@ mod tests {
             source: Box::new(GitError::BranchNotFound {
                 branch: "emails".into(),
                 show_create_hint: true,
+                closest: None,
             }),
             ctx: SwitchSuggestionCtx {
                 extra_flags: vec!["--execute=claude".into()],
@@ -1631,4 +2275,203 @@ mod tests {
         // Errors without switch suggestions should render identically
         assert_eq!(inner.to_string(), wrapped.to_string());
     }
+
+    fn json_snapshot(err: impl Into<anyhow::Error>, code: i32) -> String {
+        serde_json::to_string_pretty(&error_json(&err.into(), code)).unwrap()
+    }
+
+    #[test]
+    fn snapshot_json_branch_not_found() {
+        let err = GitError::BranchNotFound {
+            branch: "fetaure".into(),
+            show_create_hint: true,
+            closest: Some("feature".into()),
+        };
+        assert_snapshot!(json_snapshot(err, 1), @r#"
+        {
+          "error": "BranchNotFound",
+          "message": "No branch named fetaure — perhaps feature?",
+          "hints": [
+            "To create a new branch, run wt switch --create fetaure; to list branches, run wt list --branches --remotes"
+          ],
+          "exit_code": 1,
+          "details": {
+            "branch": "fetaure",
+            "closest": "feature"
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_json_detached_head() {
+        let err = GitError::DetachedHead {
+            action: Some("merge".into()),
+        };
+        assert_snapshot!(json_snapshot(err, 1), @r#"
+        {
+          "error": "DetachedHead",
+          "message": "Cannot merge: not on a branch (detached HEAD)",
+          "hints": [
+            "To switch to a branch, run git switch <branch>"
+          ],
+          "exit_code": 1,
+          "details": {
+            "action": "merge"
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_json_worktree_locked() {
+        let err = GitError::WorktreeLocked {
+            branch: "feature".into(),
+            path: PathBuf::from("/tmp/repo.feature"),
+            reason: Some("Testing lock".into()),
+        };
+        assert_snapshot!(json_snapshot(err, 1), @r#"
+        {
+          "error": "WorktreeLocked",
+          "message": "Cannot remove feature, worktree is locked (Testing lock)",
+          "hints": [
+            "To unlock, run git worktree unlock /tmp/repo.feature"
+          ],
+          "exit_code": 1,
+          "details": {
+            "branch": "feature",
+            "path": "/tmp/repo.feature",
+            "reason": "Testing lock"
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_json_not_rebased() {
+        let err = GitError::NotRebased {
+            target_branch: "main".into(),
+        };
+        assert_snapshot!(json_snapshot(err, 1), @r#"
+        {
+          "error": "NotRebased",
+          "message": "Branch not rebased onto main",
+          "hints": [
+            "To rebase first, run wt step rebase main; or remove --no-rebase"
+          ],
+          "exit_code": 1,
+          "details": {
+            "target_branch": "main"
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_json_with_switch_suggestion() {
+        // The wrapper reports the inner variant, with the enriched hint
+        let err = GitError::WithSwitchSuggestion {
+            source: Box::new(GitError::BranchAlreadyExists {
+                branch: "main".into(),
+            }),
+            ctx: SwitchSuggestionCtx {
+                extra_flags: vec!["--execute=claude".into()],
+                trailing_args: vec![],
+            },
+        };
+        assert_snapshot!(json_snapshot(err, 1), @r#"
+        {
+          "error": "BranchAlreadyExists",
+          "message": "Branch main already exists",
+          "hints": [
+            "To switch to the existing branch, run without --create: wt switch main --execute=claude"
+          ],
+          "exit_code": 1,
+          "details": {
+            "branch": "main"
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_json_hook_command_failed() {
+        let err = add_hook_skip_hint(
+            WorktrunkError::HookCommandFailed {
+                hook_type: HookType::PreMerge,
+                command_name: Some("test".into()),
+                error: "exit status 3".into(),
+                exit_code: Some(3),
+            }
+            .into(),
+        );
+        assert_snapshot!(json_snapshot(err, 3), @r#"
+        {
+          "error": "HookCommandFailed",
+          "message": "pre-merge command failed: test: exit status 3",
+          "hints": [
+            "To skip pre-merge hooks, re-run with --no-verify"
+          ],
+          "exit_code": 3,
+          "details": {
+            "command_name": "test",
+            "error": "exit status 3",
+            "exit_code": 3,
+            "hook_type": "pre-merge"
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_json_anyhow_context() {
+        let err = anyhow::anyhow!("permission denied").context("Failed to read config");
+        assert_snapshot!(json_snapshot(err, 1), @r#"
+        {
+          "error": "Error",
+          "message": "Failed to read config",
+          "hints": [],
+          "exit_code": 1,
+          "details": {
+            "causes": [
+              "permission denied"
+            ]
+          }
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_json_strips_styling() {
+        let err = anyhow::anyhow!(cformat!("No <bold>.git</> here"))
+            .context(cformat!("Directory <bold>./x</> already exists"));
+        assert_snapshot!(json_snapshot(err, 1), @r#"
+        {
+          "error": "Error",
+          "message": "Directory ./x already exists",
+          "hints": [],
+          "exit_code": 1,
+          "details": {
+            "causes": [
+              "No .git here"
+            ]
+          }
+        }
+        "#);
+
+        let err = GitError::Other {
+            message: cformat!("Directory <bold>./x</> already exists"),
+        };
+        assert_snapshot!(json_snapshot(err, 1), @r#"
+        {
+          "error": "Other",
+          "message": "Directory ./x already exists",
+          "hints": [],
+          "exit_code": 1,
+          "details": {
+            "message": "Directory ./x already exists"
+          }
+        }
+        "#);
+    }
 }
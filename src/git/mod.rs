@@ -37,6 +37,8 @@ pub const NULL_OID: &str = "0000000000000000000000000000000000000000";
 pub(crate) use diff::DiffStats;
 pub use diff::{LineDiff, parse_numstat_line};
 pub use error::{
+    // The `--json-errors` object
+    ErrorJson,
     // Structured command failure info
     FailedCommand,
     // Typed error enum (Display produces styled output)
@@ -51,11 +53,15 @@ pub use error::{
     WorktrunkError,
     // Error inspection functions
     add_hook_skip_hint,
+    error_json,
     exit_code,
 };
 pub use parse::{parse_porcelain_z, parse_untracked_files};
 pub use recover::{current_or_recover, cwd_removed_hint};
-pub use repository::{Branch, Repository, ResolvedWorktree, WorkingTree, set_base_path};
+pub use repository::{
+    AddWorktreeOpts, Branch, IncompleteHistory, Repository, ResolvedWorktree, WorkingTree,
+    base_path, set_base_path,
+};
 pub use url::GitRemoteUrl;
 pub use url::{parse_owner_repo, parse_remote_owner};
 /// Why branch content is considered integrated into the target branch.
@@ -73,7 +79,7 @@ pub use url::{parse_owner_repo, parse_remote_owner};
 /// 3. [`NoAddedChanges`](Self::NoAddedChanges) - three-dot diff (~50-100ms)
 /// 4. [`TreesMatch`](Self::TreesMatch) - tree SHA comparison (~100-300ms)
 /// 5. [`MergeAddsNothing`](Self::MergeAddsNothing) - merge simulation (~500ms-2s)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::IntoStaticStr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, strum::IntoStaticStr)]
 #[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub enum IntegrationReason {
@@ -87,6 +93,9 @@ pub enum IntegrationReason {
     /// Branch HEAD is an ancestor of target (target has moved past this branch).
     ///
     /// Symbol in `wt list`: `⊂`
+    ///
+    /// The default only so `MainState::Integrated` can be enumerated.
+    #[default]
     Ancestor,
 
     /// Three-dot diff (`main...branch`) shows no files.
@@ -433,7 +442,11 @@ impl From<&WorktreeInfo> for BranchRef {
 pub struct WorktreeInfo {
     pub path: PathBuf,
     pub head: String,
+    /// Branch name, lossily decoded if git reported non-UTF-8 bytes.
     pub branch: Option<String>,
+    /// The branch name's raw bytes, set only when lossy decoding changed them.
+    #[serde(skip)]
+    pub branch_raw: Option<Vec<u8>>,
     pub bare: bool,
     pub detached: bool,
     pub locked: Option<String>,
@@ -629,6 +642,7 @@ mod tests {
             path: PathBuf::from("/repos/myrepo.feature"),
             head: "abc123".into(),
             branch: Some("feature".into()),
+            branch_raw: None,
             bare: false,
             detached: false,
             locked: None,
@@ -657,6 +671,7 @@ mod tests {
             path: PathBuf::from("/repo.feature"),
             head: "abc123".into(),
             branch: Some("feature".into()),
+            branch_raw: None,
             bare: false,
             detached: false,
             locked: None,
@@ -703,6 +718,7 @@ mod tests {
             path: PathBuf::from("/repo.detached"),
             head: "def456".into(),
             branch: None, // Detached HEAD
+            branch_raw: None,
             bare: false,
             detached: true,
             locked: None,
@@ -1,15 +1,36 @@
 //! Git output parsing functions
 
+use std::borrow::Cow;
 use std::path::PathBuf;
 
 use super::{GitError, WorktreeInfo, finalize_worktree};
 
 impl WorktreeInfo {
-    pub(crate) fn parse_porcelain_list(output: &str) -> anyhow::Result<Vec<Self>> {
+    /// Parse `git worktree list --porcelain` output without assuming UTF-8.
+    ///
+    /// Paths keep their raw bytes so they still name the directory on disk.
+    /// Branch names are decoded lossily for display, with the raw bytes kept in
+    /// `branch_raw` when decoding replaced anything.
+    ///
+    /// The format is treated as a contract across git versions, so parsing
+    /// never fails as a whole:
+    /// - every attribute other than `worktree` is optional: `head` defaults to
+    ///   empty, `branch`/`locked`/`prunable` to `None`, and `bare`/`detached`
+    ///   to `false`
+    /// - unknown attributes are skipped (logged at debug level), so attributes
+    ///   added by newer git don't break older worktrunk
+    /// - CRLF line endings and a missing final newline are accepted
+    /// - a malformed entry (`worktree` without a path, `HEAD` or `branch`
+    ///   without a value) is dropped, and parsing resumes at the next entry
+    ///
+    /// Hand-written samples of each output shape live in
+    /// `tests/fixtures/worktree-porcelain`.
+    pub(crate) fn parse_porcelain_list_bytes(output: &[u8]) -> Vec<Self> {
         let mut worktrees = Vec::new();
         let mut current: Option<WorktreeInfo> = None;
 
-        for line in output.lines() {
+        for line in output.split(|&b| b == b'\n') {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
             if line.is_empty() {
                 if let Some(wt) = current.take() {
                     worktrees.push(finalize_worktree(wt));
@@ -17,69 +38,73 @@ impl WorktreeInfo {
                 continue;
             }
 
-            let (key, value) = match line.split_once(' ') {
-                Some((k, v)) => (k, Some(v)),
+            let (key, value) = match line.iter().position(|&b| b == b' ') {
+                Some(i) => (&line[..i], Some(&line[i + 1..])),
                 None => (line, None),
             };
 
-            match key {
-                "worktree" => {
-                    let Some(path) = value else {
-                        return Err(GitError::ParseError {
-                            message: "worktree line missing path".into(),
-                        }
-                        .into());
-                    };
-                    current = Some(WorktreeInfo {
-                        path: PathBuf::from(path),
-                        head: String::new(),
-                        branch: None,
-                        bare: false,
-                        detached: false,
-                        locked: None,
-                        prunable: None,
-                    });
+            if key == b"worktree" {
+                // A new entry without a separating blank line still starts afresh
+                if let Some(wt) = current.take() {
+                    worktrees.push(finalize_worktree(wt));
+                }
+                match value.filter(|path| !path.is_empty()) {
+                    Some(path) => current = Some(WorktreeInfo::at(path_from_bytes(path))),
+                    None => log::debug!("Skipping worktree entry: worktree line missing path"),
+                }
+                continue;
+            }
+
+            // Attributes of a dropped entry, or before the first entry
+            let Some(wt) = current.as_mut() else {
+                continue;
+            };
+
+            let malformed = match (key, value) {
+                (b"HEAD", Some(sha)) if !sha.is_empty() => {
+                    wt.head = String::from_utf8_lossy(sha).into_owned();
+                    None
+                }
+                (b"HEAD", _) => Some("HEAD line missing SHA"),
+                (b"branch", Some(branch_ref)) if !branch_ref.is_empty() => {
+                    // Strip refs/heads/ prefix if present
+                    let raw = branch_ref
+                        .strip_prefix(b"refs/heads/")
+                        .unwrap_or(branch_ref);
+                    let (branch, lossy) = decode_lossy(raw);
+                    wt.branch = Some(branch);
+                    wt.branch_raw = lossy.then(|| raw.to_vec());
+                    None
+                }
+                (b"branch", _) => Some("branch line missing ref"),
+                (b"bare", _) => {
+                    wt.bare = true;
+                    None
+                }
+                (b"detached", _) => {
+                    wt.detached = true;
+                    None
+                }
+                (b"locked", value) => {
+                    wt.locked = Some(decode_lossy(value.unwrap_or_default()).0);
+                    None
                 }
-                key => match (key, current.as_mut()) {
-                    ("HEAD", Some(wt)) => {
-                        let Some(sha) = value else {
-                            return Err(GitError::ParseError {
-                                message: "HEAD line missing SHA".into(),
-                            }
-                            .into());
-                        };
-                        wt.head = sha.to_string();
-                    }
-                    ("branch", Some(wt)) => {
-                        // Strip refs/heads/ prefix if present
-                        let Some(branch_ref) = value else {
-                            return Err(GitError::ParseError {
-                                message: "branch line missing ref".into(),
-                            }
-                            .into());
-                        };
-                        let branch = branch_ref
-                            .strip_prefix("refs/heads/")
-                            .unwrap_or(branch_ref)
-                            .to_string();
-                        wt.branch = Some(branch);
-                    }
-                    ("bare", Some(wt)) => {
-                        wt.bare = true;
-                    }
-                    ("detached", Some(wt)) => {
-                        wt.detached = true;
-                    }
-                    ("locked", Some(wt)) => {
-                        wt.locked = Some(value.unwrap_or_default().to_string());
-                    }
-                    ("prunable", Some(wt)) => {
-                        wt.prunable = Some(value.unwrap_or_default().to_string());
-                    }
-                    _ => {
-                        // Ignore unknown attributes or attributes before first worktree
-                    }
-                },
+                (b"prunable", value) => {
+                    wt.prunable = Some(decode_lossy(value.unwrap_or_default()).0);
+                    None
+                }
+                (key, _) => {
+                    log::debug!(
+                        "Ignoring unknown worktree attribute: {}",
+                        String::from_utf8_lossy(key)
+                    );
+                    None
+                }
+            };
+
+            if let Some(reason) = malformed {
+                log::debug!("Skipping worktree entry {}: {reason}", wt.path.display());
+                current = None;
             }
         }
 
@@ -88,7 +113,47 @@ impl WorktreeInfo {
             worktrees.push(finalize_worktree(wt));
         }
 
-        Ok(worktrees)
+        worktrees
+    }
+
+    /// An entry with every optional attribute at its default.
+    fn at(path: PathBuf) -> Self {
+        WorktreeInfo {
+            path,
+            head: String::new(),
+            branch: None,
+            branch_raw: None,
+            bare: false,
+            detached: false,
+            locked: None,
+            prunable: None,
+        }
+    }
+}
+
+/// Decode bytes for display, replacing invalid UTF-8 with U+FFFD.
+///
+/// Returns whether anything was replaced, so callers can keep the raw bytes.
+pub(crate) fn decode_lossy(bytes: &[u8]) -> (String, bool) {
+    match String::from_utf8_lossy(bytes) {
+        Cow::Borrowed(s) => (s.to_string(), false),
+        Cow::Owned(s) => (s, true),
+    }
+}
+
+/// Build a path from git's raw output bytes.
+///
+/// On Unix the bytes are the path, so non-UTF-8 names round-trip. Elsewhere
+/// git reports UTF-8, and anything else is decoded lossily.
+fn path_from_bytes(bytes: &[u8]) -> PathBuf {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        PathBuf::from(std::ffi::OsStr::from_bytes(bytes))
+    }
+    #[cfg(not(unix))]
+    {
+        PathBuf::from(String::from_utf8_lossy(bytes).into_owned())
     }
 }
 
@@ -297,7 +362,7 @@ mod tests {
     #[test]
     fn test_parse_porcelain_list_single_worktree() {
         let output = "worktree /path/to/repo\nHEAD abc123\nbranch refs/heads/main\n\n";
-        let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
         let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
         assert_eq!(wt.path.to_str().unwrap(), "/path/to/repo");
         assert_eq!(wt.head, "abc123");
@@ -307,7 +372,7 @@ mod tests {
     #[test]
     fn test_parse_porcelain_list_multiple_worktrees() {
         let output = "worktree /path/main\nHEAD aaa\nbranch refs/heads/main\n\nworktree /path/feature\nHEAD bbb\nbranch refs/heads/feature\n\n";
-        let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
         let [main_wt, feature_wt]: [WorktreeInfo; 2] = worktrees.try_into().unwrap();
         assert_eq!(main_wt.branch, Some("main".to_string()));
         assert_eq!(feature_wt.branch, Some("feature".to_string()));
@@ -316,7 +381,7 @@ mod tests {
     #[test]
     fn test_parse_porcelain_list_bare_repo() {
         let output = "worktree /path/to/repo.git\nHEAD abc123\nbare\n\n";
-        let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
         let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
         assert!(wt.bare);
     }
@@ -324,7 +389,7 @@ mod tests {
     #[test]
     fn test_parse_porcelain_list_detached() {
         let output = "worktree /path/to/repo\nHEAD abc123\ndetached\n\n";
-        let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
         let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
         assert!(wt.detached);
         assert!(wt.branch.is_none());
@@ -333,7 +398,7 @@ mod tests {
     #[test]
     fn test_parse_porcelain_list_locked() {
         let output = "worktree /path/to/repo\nHEAD abc123\nbranch refs/heads/main\nlocked reason for lock\n\n";
-        let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
         let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
         assert_eq!(wt.locked, Some("reason for lock".to_string()));
     }
@@ -341,16 +406,14 @@ mod tests {
     #[test]
     fn test_parse_porcelain_list_prunable() {
         let output = "worktree /path/to/repo\nHEAD abc123\nbranch refs/heads/main\nprunable gitdir file missing\n\n";
-        let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
         let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
         assert_eq!(wt.prunable, Some("gitdir file missing".to_string()));
     }
 
     #[test]
     fn test_parse_porcelain_list_empty() {
-        let result = WorktreeInfo::parse_porcelain_list("");
-        assert!(result.is_ok());
-        let worktrees = result.unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(b"");
         assert!(worktrees.is_empty());
     }
 
@@ -358,31 +421,31 @@ mod tests {
     fn test_parse_porcelain_list_no_trailing_blank() {
         // Git output may not always end with a blank line
         let output = "worktree /path/to/repo\nHEAD abc123\nbranch refs/heads/main";
-        let result = WorktreeInfo::parse_porcelain_list(output);
-        assert!(result.is_ok());
-        let worktrees = result.unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
         assert_eq!(worktrees.len(), 1);
     }
 
     #[test]
     fn test_parse_porcelain_list_missing_worktree_path() {
-        let output = "worktree\nHEAD abc123\n\n";
-        let result = WorktreeInfo::parse_porcelain_list(output);
-        assert!(result.is_err());
+        // The entry is dropped; the next one still parses
+        let output = "worktree\nHEAD abc123\n\nworktree /path/b\nHEAD def456\n\n";
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
+        let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
+        assert_eq!(wt.path.to_str().unwrap(), "/path/b");
     }
 
     #[test]
     fn test_parse_porcelain_list_missing_head_sha() {
         let output = "worktree /path\nHEAD\n\n";
-        let result = WorktreeInfo::parse_porcelain_list(output);
-        assert!(result.is_err());
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
+        assert!(worktrees.is_empty());
     }
 
     #[test]
     fn test_parse_porcelain_list_branch_without_refs_prefix() {
         // This can happen in some edge cases
         let output = "worktree /path/to/repo\nHEAD abc123\nbranch main\n\n";
-        let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
         let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
         // Should use the branch name as-is when no refs/heads/ prefix
         assert_eq!(wt.branch, Some("main".to_string()));
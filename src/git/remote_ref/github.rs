@@ -91,6 +91,7 @@ fn fetch_pr_info(pr_number: u32, repo: &Repository) -> anyhow::Result<RemoteRefI
 
     let output = match Cmd::new("gh")
         .args(["api", &api_path, "--hostname", &hostname])
+        .network()
         .current_dir(repo_root)
         .env("GH_PROMPT_DISABLED", "1")
         .run()
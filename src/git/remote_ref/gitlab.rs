@@ -91,6 +91,7 @@ fn fetch_mr_info(mr_number: u32, repo_root: &Path) -> anyhow::Result<RemoteRefIn
 
     let output = match Cmd::new("glab")
         .args(["api", &api_path])
+        .network()
         .current_dir(repo_root)
         .env("GLAB_NO_PROMPT", "1")
         .run()
@@ -266,6 +267,7 @@ fn fetch_project_urls(
 
     let output = Cmd::new("glab")
         .args(["api", &api_path])
+        .network()
         .current_dir(repo_root)
         .env("GLAB_NO_PROMPT", "1")
         .run()?;
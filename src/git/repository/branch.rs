@@ -109,6 +109,34 @@ impl<'a> Branch<'a> {
         }
     }
 
+    /// Check whether the last update to this branch's upstream rewrote it.
+    ///
+    /// Reads the remote-tracking ref's reflog: when its previous value isn't an
+    /// ancestor of the current one, the upstream was force-pushed, and the
+    /// previous value is returned. Only meaningful right after a fetch that moved
+    /// the ref — otherwise `@{1}` describes some older update.
+    pub fn upstream_rewritten(&self) -> anyhow::Result<Option<String>> {
+        let Ok(tracking) = self.repo.run_command(&[
+            "rev-parse",
+            "--symbolic-full-name",
+            &format!("{}@{{u}}", self.name),
+        ]) else {
+            return Ok(None);
+        };
+        let tracking = tracking.trim();
+        let Ok(previous) =
+            self.repo
+                .run_command(&["rev-parse", "--verify", "-q", &format!("{tracking}@{{1}}")])
+        else {
+            return Ok(None);
+        };
+        let previous = previous.trim().to_string();
+        if self.repo.is_ancestor(&previous, tracking)? {
+            return Ok(None);
+        }
+        Ok(Some(previous))
+    }
+
     /// Unset the upstream tracking branch for this branch.
     ///
     /// This removes the tracking relationship, preventing accidental pushes
@@ -119,6 +147,16 @@ impl<'a> Branch<'a> {
         Ok(())
     }
 
+    /// Rename this branch with `git branch -m`.
+    ///
+    /// Worktrees on the branch follow it, so cached current branches are
+    /// dropped.
+    pub fn rename(&self, new: &str) -> anyhow::Result<()> {
+        self.repo.run_command(&["branch", "-m", &self.name, new])?;
+        self.repo.cache.current_branches.clear();
+        Ok(())
+    }
+
     /// Get the remote where this branch would be pushed.
     ///
     /// Uses [`@{push}` syntax][1] which resolves through:
@@ -1,7 +1,8 @@
-//! Branch-related operations for Repository.
+//! Branch- and tag-related operations for Repository.
 //!
 //! For single-branch operations, see [`super::Branch`].
-//! This module contains multi-branch operations (listing, filtering, etc.).
+//! This module contains multi-branch operations (listing, filtering, etc.)
+//! and tag lookups.
 
 use std::collections::{HashMap, HashSet};
 
@@ -24,6 +25,76 @@ impl Repository {
             .is_ok())
     }
 
+    /// Resolve a tag (`refs/tags/{name}`) to the commit it points at.
+    ///
+    /// Annotated tags are peeled to their commit. Returns `None` if no such tag exists.
+    pub fn tag_commit(&self, name: &str) -> anyhow::Result<Option<String>> {
+        let output = self.run_command_output(&[
+            "rev-parse",
+            "--verify",
+            "-q",
+            &format!("refs/tags/{name}^{{commit}}"),
+        ])?;
+        // With -q, exit code 1 means the tag doesn't exist
+        if output.status.code() == Some(1) {
+            return Ok(None);
+        }
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to resolve tag {name}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(Some(
+            String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        ))
+    }
+
+    /// Map commit SHAs to the tags pointing at them.
+    ///
+    /// Annotated tags are peeled to their commit. When several tags point at the
+    /// same commit, the last one in refname order wins.
+    pub fn tags_by_commit(&self) -> anyhow::Result<HashMap<String, String>> {
+        let output = self.run_command(&[
+            "for-each-ref",
+            "--format=%(objectname) %(*objectname) %(refname:lstrip=2)",
+            "refs/tags/",
+        ])?;
+
+        Ok(output
+            .lines()
+            .filter_map(|line| {
+                let (sha, rest) = line.split_once(' ')?;
+                let (peeled, tag) = rest.split_once(' ')?;
+                // %(*objectname) is empty for lightweight tags
+                let commit = if peeled.is_empty() { sha } else { peeled };
+                Some((commit.to_string(), tag.to_string()))
+            })
+            .collect())
+    }
+
+    /// Check whether `commit` would be lost if nothing but a reflog pointed at it.
+    ///
+    /// True when the commit still exists and no branch, remote-tracking branch,
+    /// or tag contains it. A commit that has already been garbage-collected has
+    /// nothing left to lose.
+    pub fn is_commit_unreferenced(&self, commit: &str) -> anyhow::Result<bool> {
+        if !self.ref_exists(commit)? {
+            return Ok(false);
+        }
+        let containing = self.run_command(&[
+            "for-each-ref",
+            "--count=1",
+            "--format=%(refname)",
+            "--contains",
+            commit,
+            "refs/heads/",
+            "refs/remotes/",
+            "refs/tags/",
+        ])?;
+        Ok(containing.trim().is_empty())
+    }
+
     /// List all local branch names, sorted by most recent commit first.
     pub fn all_branches(&self) -> anyhow::Result<Vec<String>> {
         let stdout = self.run_command(&[
@@ -1,5 +1,8 @@
 //! Git config, hints, marker, and default branch operations for Repository.
 
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
 use anyhow::Context;
 use color_print::cformat;
 
@@ -22,6 +25,29 @@ impl Repository {
         Ok(())
     }
 
+    /// Whether `extensions.worktreeConfig` is on, so each worktree also reads
+    /// its own `config.worktree`.
+    pub fn worktree_config_enabled(&self) -> bool {
+        self.run_command(&["config", "--bool", "extensions.worktreeConfig"])
+            .is_ok_and(|value| value.trim() == "true")
+    }
+
+    /// Turn on `extensions.worktreeConfig` in the shared config.
+    ///
+    /// Git asks that a `core.bare = true` in the shared config move to the
+    /// bare repository's own `config.worktree` first, so linked worktrees
+    /// don't inherit it; this does that move.
+    pub fn enable_worktree_config(&self) -> anyhow::Result<()> {
+        let common = self.worktree_at(self.git_common_dir());
+        let bare = common.run_command(&["config", "--local", "--bool", "core.bare"]);
+        self.set_config("extensions.worktreeConfig", "true")?;
+        if bare.is_ok_and(|value| value.trim() == "true") {
+            common.set_worktree_config("core.bare", "true")?;
+            common.run_command(&["config", "--local", "--unset", "core.bare"])?;
+        }
+        Ok(())
+    }
+
     /// Read a user-defined marker from `worktrunk.state.<branch>.marker` in git config.
     ///
     /// Markers are stored as JSON: `{"marker": "text", "set_at": unix_timestamp}`.
@@ -47,6 +73,99 @@ impl Repository {
         branch.and_then(|branch| self.branch_marker(branch))
     }
 
+    /// Branches hidden from `wt list` by `wt hide`.
+    ///
+    /// Stored as `worktrunk.state.<branch>.hidden = true`, alongside markers.
+    pub fn hidden_branches(&self) -> HashSet<String> {
+        self.run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.hidden$"])
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                // Format: "worktrunk.state.<branch>.hidden true"
+                line.split_whitespace()
+                    .next()?
+                    .strip_prefix("worktrunk.state.")?
+                    .strip_suffix(".hidden")
+                    .map(String::from)
+            })
+            .collect()
+    }
+
+    /// Hide or unhide a branch in `wt list`. Returns whether anything changed.
+    pub fn set_branch_hidden(&self, branch: &str, hidden: bool) -> anyhow::Result<bool> {
+        let config_key = format!("worktrunk.state.{branch}.hidden");
+        let was_hidden = self.run_command(&["config", "--get", &config_key]).is_ok();
+        if was_hidden == hidden {
+            return Ok(false);
+        }
+        if hidden {
+            self.run_command(&["config", &config_key, "true"])?;
+        } else {
+            self.run_command(&["config", "--unset", &config_key])?;
+        }
+        Ok(true)
+    }
+
+    /// Stack parent recorded for `branch` (`branch.<branch>.worktrunkParent`).
+    ///
+    /// Set by `wt switch --create --base <branch>` and `wt parent set`. Lives in
+    /// the branch's own config section, so deleting the branch drops it.
+    pub fn branch_parent(&self, branch: &str) -> Option<String> {
+        self.run_command(&[
+            "config",
+            "--get",
+            &format!("branch.{branch}.worktrunkParent"),
+        ])
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+    }
+
+    /// Every recorded stack parent, keyed by child branch.
+    pub fn branch_parents(&self) -> HashMap<String, String> {
+        self.run_command(&["config", "--get-regexp", r"^branch\..+\.worktrunkparent$"])
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                // Format: "branch.<branch>.worktrunkparent <parent>"
+                let (key, parent) = line.split_once(' ')?;
+                let branch = key
+                    .strip_prefix("branch.")?
+                    .strip_suffix(".worktrunkparent")?;
+                let parent = parent.trim();
+                (!parent.is_empty()).then(|| (branch.to_string(), parent.to_string()))
+            })
+            .collect()
+    }
+
+    /// Record `parent` as the stack parent of `branch`.
+    ///
+    /// Fails with [`GitError::StackParentCycle`] when `branch` is already an
+    /// ancestor of `parent` through recorded parents, or is `parent` itself.
+    pub fn set_branch_parent(&self, branch: &str, parent: &str) -> anyhow::Result<()> {
+        if let Some(chain) = parent_cycle(&self.branch_parents(), branch, parent) {
+            return Err(GitError::StackParentCycle {
+                branch: branch.to_string(),
+                chain,
+            }
+            .into());
+        }
+        self.set_config(&format!("branch.{branch}.worktrunkParent"), parent)
+    }
+
+    /// Forget the stack parent of `branch`. Returns whether one was recorded.
+    pub fn clear_branch_parent(&self, branch: &str) -> anyhow::Result<bool> {
+        if self.branch_parent(branch).is_none() {
+            return Ok(false);
+        }
+        self.run_command(&[
+            "config",
+            "--unset",
+            &format!("branch.{branch}.worktrunkParent"),
+        ])?;
+        Ok(true)
+    }
+
     /// Set the previous branch in worktrunk.history for `wt switch -` support.
     ///
     /// Stores the branch we're switching FROM, so `wt switch -` can return to it.
@@ -209,6 +328,40 @@ impl Repository {
             .and_then(|opt| opt.clone())
     }
 
+    /// Detect an upstream rename of the default branch, such as `master` → `main`.
+    ///
+    /// Returns `(old, new)` when the local default branch tracks a remote branch
+    /// that no longer exists while `<remote>/HEAD` points to a different branch
+    /// that does. Reads local refs only, so it's only as fresh as the last fetch.
+    pub fn renamed_default_branch(&self) -> Option<(String, String)> {
+        let old = self.default_branch()?;
+        let tracking = self
+            .run_command(&[
+                "for-each-ref",
+                "--format=%(upstream:remotename)%00%(upstream:track)",
+                &format!("refs/heads/{old}"),
+            ])
+            .ok()?;
+        let (remote, track) = tracking.trim_end().split_once('\0')?;
+        if remote.is_empty() || track != "[gone]" {
+            return None;
+        }
+
+        let head = self
+            .run_command(&[
+                "symbolic-ref",
+                "--short",
+                &format!("refs/remotes/{remote}/HEAD"),
+            ])
+            .ok()?;
+        let new = head.trim().strip_prefix(&format!("{remote}/"))?;
+        (new != old
+            && self
+                .ref_exists(&format!("refs/remotes/{remote}/{new}"))
+                .unwrap_or(false))
+        .then(|| (old, new.to_string()))
+    }
+
     /// Try to detect default branch from remote.
     fn detect_from_remote(&self) -> Option<String> {
         let remote = self.primary_remote().ok()?;
@@ -254,6 +407,7 @@ impl Repository {
             return Err(GitError::BranchNotFound {
                 branch,
                 show_create_hint: true,
+                closest: None,
             }
             .into());
         }
@@ -346,7 +500,7 @@ impl Repository {
     }
 
     pub(super) fn query_remote_default_branch(&self, remote: &str) -> anyhow::Result<String> {
-        let stdout = self.run_command(&["ls-remote", "--symref", remote, "HEAD"])?;
+        let stdout = self.run_network_command(&["ls-remote", "--symref", remote, "HEAD"])?;
         DefaultBranchName::from_remote(&stdout).map(DefaultBranchName::into_string)
     }
 
@@ -375,6 +529,36 @@ impl Repository {
     // Project config
     // =========================================================================
 
+    /// Path of the project config (`.config/wt.toml`) that applies to this worktree.
+    ///
+    /// The current worktree's copy wins. When it has none, falls back to the
+    /// primary worktree's copy, so worktrees created before the config was
+    /// committed still pick it up. When neither exists, returns the current
+    /// worktree's path (where the file would be created).
+    ///
+    /// The fallback is derived from this repository's common dir, never by
+    /// walking up the filesystem — a submodule never resolves to its
+    /// superproject's config.
+    pub fn project_config_path(&self) -> anyhow::Result<PathBuf> {
+        let local = self
+            .current_worktree()
+            .root()?
+            .join(".config")
+            .join("wt.toml");
+        if local.exists() {
+            return Ok(local);
+        }
+
+        if let Ok(Some(primary)) = self.primary_worktree() {
+            let shared = primary.join(".config").join("wt.toml");
+            if shared.exists() {
+                return Ok(shared);
+            }
+        }
+
+        Ok(local)
+    }
+
     /// Load the project configuration (.config/wt.toml) if it exists.
     ///
     /// Result is cached in the repository's shared cache (same for all clones).
@@ -393,3 +577,55 @@ impl Repository {
             .cloned()
     }
 }
+
+/// The loop that recording `parent` for `branch` would close: the recorded
+/// parents from `parent` onward, ending back at `branch`. `None` when there's
+/// no loop, including when an existing loop doesn't pass through `branch`.
+fn parent_cycle(
+    parents: &HashMap<String, String>,
+    branch: &str,
+    parent: &str,
+) -> Option<Vec<String>> {
+    let mut chain = vec![parent.to_string()];
+    let mut current = parent;
+    while current != branch {
+        current = parents.get(current)?;
+        if chain.iter().any(|seen| seen == current) {
+            return None;
+        }
+        chain.push(current.to_string());
+    }
+    Some(chain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parents(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(child, parent)| (child.to_string(), parent.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parent_cycle() {
+        let stack = parents(&[("ui", "api"), ("api", "base")]);
+        // Extending the stack is fine
+        assert_eq!(parent_cycle(&stack, "polish", "ui"), None);
+        assert_eq!(parent_cycle(&stack, "base", "main"), None);
+        // Stacking the bottom on the top loops
+        assert_eq!(
+            parent_cycle(&stack, "base", "ui"),
+            Some(vec!["ui".into(), "api".into(), "base".into()])
+        );
+        assert_eq!(parent_cycle(&stack, "ui", "ui"), Some(vec!["ui".into()]));
+    }
+
+    #[test]
+    fn test_parent_cycle_ignores_unrelated_loop() {
+        let looped = parents(&[("a", "b"), ("b", "a")]);
+        assert_eq!(parent_cycle(&looped, "c", "a"), None);
+    }
+}
@@ -80,17 +80,36 @@ impl Repository {
     }
 
     /// Get commit timestamp and message in a single git command.
-    pub fn commit_details(&self, commit: &str) -> anyhow::Result<(i64, String)> {
+    ///
+    /// Returns `None` for unborn branches (null OID, or a symbolic ref like
+    /// `HEAD` whose branch has no commits yet), where `git log` has nothing to
+    /// show. Any other failure, such as a ref pointing at a missing object, is
+    /// an error.
+    pub fn commit_details(&self, commit: &str) -> anyhow::Result<Option<(i64, String)>> {
+        if commit == crate::git::NULL_OID {
+            return Ok(None);
+        }
         // Use space separator - timestamps don't contain spaces, and %s (subject)
         // is the first line only (no embedded newlines). Split on first space.
-        let stdout = self.run_command(&["log", "-1", "--format=%ct %s", commit])?;
+        let stdout = match self.run_command(&["log", "-1", "--format=%ct %s", commit]) {
+            Ok(stdout) => stdout,
+            // Only check for an unborn ref after failure, keeping the hot path to one command
+            Err(_) if self.is_unborn_symref(commit) => return Ok(None),
+            Err(e) => return Err(e),
+        };
         // Only strip trailing newline, not spaces (empty subject = "timestamp ")
         let line = stdout.trim_end_matches('\n');
         let (timestamp_str, message) = line
             .split_once(' ')
             .context("Failed to parse commit details")?;
         let timestamp = timestamp_str.parse().context("Failed to parse timestamp")?;
-        Ok((timestamp, message.trim().to_owned()))
+        Ok(Some((timestamp, message.trim().to_owned())))
+    }
+
+    /// Whether `reference` is a symbolic ref whose target branch has no commits.
+    fn is_unborn_symref(&self, reference: &str) -> bool {
+        self.run_command(&["symbolic-ref", "-q", reference])
+            .is_ok_and(|target| !self.ref_exists(target.trim()).unwrap_or(true))
     }
 
     /// Get commit subjects (first line of commit message) from a range.
@@ -293,6 +312,31 @@ impl Repository {
         LineDiff::from_numstat(&stdout)
     }
 
+    /// Get line diff statistics between the tips of two refs.
+    ///
+    /// Diffs `base` directly against `head` without going through the
+    /// merge-base, so commits that landed on `base` after the branch point
+    /// count as (reversed) changes too. Returns the resolved `base` commit
+    /// alongside the stats.
+    pub fn tip_diff_stats(&self, base: &str, head: &str) -> anyhow::Result<(String, LineDiff)> {
+        let _guard = super::super::HEAVY_OPS_SEMAPHORE.acquire();
+
+        let base_sha = self
+            .run_command(&["rev-parse", "--verify", &format!("{base}^{{commit}}")])?
+            .trim()
+            .to_owned();
+        let mut args = vec!["diff", "--numstat", &base_sha, head];
+
+        let sparse_paths = self.sparse_checkout_paths();
+        if !sparse_paths.is_empty() {
+            args.push("--");
+            args.extend(sparse_paths.iter().map(|s| s.as_str()));
+        }
+
+        let stdout = self.run_command(&args)?;
+        Ok((base_sha, LineDiff::from_numstat(&stdout)?))
+    }
+
     /// Get formatted diff stats summary for display.
     ///
     /// Returns a vector of formatted strings like ["3 files", "+45", "-12"].
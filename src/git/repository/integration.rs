@@ -3,10 +3,11 @@
 //! Methods for determining if a branch has been integrated into the target
 //! (same commit, ancestor, trees match, etc.).
 
-use anyhow::Context;
+use anyhow::{Context, bail};
 
 use super::Repository;
 use crate::git::{IntegrationReason, check_integration, compute_integration_lazy};
+use crate::shell_exec::Cmd;
 
 impl Repository {
     /// Resolve a ref, preferring branches over tags when names collide.
@@ -126,6 +127,63 @@ impl Repository {
         Ok(!clean_merge)
     }
 
+    /// List the files that would conflict if head were merged into base.
+    ///
+    /// Like [`has_merge_conflicts`](Self::has_merge_conflicts), but asks
+    /// `git merge-tree --name-only` for the conflicted paths. Returns `None` for
+    /// a clean merge, or when the two share no history. Results are cached per
+    /// (base, head) pair, so worktrees sharing a commit only run the merge once.
+    ///
+    /// Needs git 2.38+ (`--write-tree`); older versions return an error.
+    pub fn merge_conflict_files(
+        &self,
+        base: &str,
+        head: &str,
+    ) -> anyhow::Result<Option<Vec<String>>> {
+        let key = (base.to_string(), head.to_string());
+        if let Some(cached) = self.cache.merge_conflicts.get(&key) {
+            return Ok(cached.clone());
+        }
+
+        let base = self.resolve_preferring_branch(base);
+        let head = self.resolve_preferring_branch(head);
+        // Orphan branches have no merge base to conflict against; `wt list`
+        // marks them as unrelated instead
+        if self.merge_base(&base, &head)?.is_none() {
+            self.cache.merge_conflicts.insert(key, None);
+            return Ok(None);
+        }
+        // Exit codes: 0 = clean, 1 = conflicts, anything else = error (bad ref, old git)
+        let output = self.run_command_output(&[
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            &base,
+            &head,
+        ])?;
+
+        let result = match output.status.code() {
+            Some(0) => None,
+            // First line is the (partial) tree OID, then one conflicted path per line
+            Some(1) => Some(
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .skip(1)
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_owned)
+                    .collect(),
+            ),
+            _ => bail!(
+                "git merge-tree failed for {base} {head}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        };
+
+        self.cache.merge_conflicts.insert(key, result.clone());
+        Ok(result)
+    }
+
     /// Check if merging a branch into target would add anything (not already integrated).
     ///
     /// Uses `git merge-tree` to simulate merging the branch into the target. If the
@@ -166,6 +224,151 @@ impl Repository {
         Ok(merge_tree != target_tree)
     }
 
+    /// Check if a branch looks squash-merged (or cherry-picked) into target.
+    ///
+    /// A heuristic for merges that leave no ancestry behind: true when
+    /// `git cherry` finds an equivalent commit on target for every branch
+    /// commit, or when the patch-id of the branch's cumulative diff matches a
+    /// single commit on target (the squash). Unlike
+    /// [`would_merge_add_to_target`](Self::would_merge_add_to_target), this
+    /// still matches after target has edited the same lines again, so callers
+    /// present it as "probably merged" rather than integrated.
+    ///
+    /// Returns false for branches that are ancestors of target (merged the
+    /// ordinary way) and for orphan branches. Results are cached per
+    /// (branch SHA, target SHA) pair.
+    ///
+    /// The patch-id comparison reads every commit target gained since the
+    /// fork point; [`is_cherry_merged`](Self::is_cherry_merged) is the cheap
+    /// half of this check.
+    pub fn is_squash_merged(&self, branch: &str, target: &str) -> anyhow::Result<bool> {
+        let key = self.resolve_commit_pair(branch, target)?;
+        if let Some(cached) = self.cache.squash_merged.get(&key) {
+            return Ok(*cached);
+        }
+
+        let result = self.compute_squash_merged(&key.0, &key.1)?;
+        self.cache.squash_merged.insert(key, result);
+        Ok(result)
+    }
+
+    /// Check if every branch commit's change is already on target.
+    ///
+    /// The `git cherry` half of [`is_squash_merged`](Self::is_squash_merged):
+    /// catches cherry-picks and rebased copies but not several commits squashed
+    /// into one. Shares its cache with
+    /// [`unmerged_commit_count`](Self::unmerged_commit_count).
+    pub fn is_cherry_merged(&self, branch: &str, target: &str) -> anyhow::Result<bool> {
+        let (branch_sha, target_sha) = self.resolve_commit_pair(branch, target)?;
+        if self.is_ancestor(&branch_sha, &target_sha)? {
+            return Ok(false);
+        }
+        let (unmerged, equivalent) = self.cherry_counts(&branch_sha, &target_sha)?;
+        Ok(unmerged == 0 && equivalent > 0)
+    }
+
+    /// Count the branch commits whose changes target doesn't have yet.
+    ///
+    /// `git cherry` semantics: commits on branch but not target, minus those
+    /// with a patch-equivalent commit on target (cherry-picks, rebased
+    /// copies). Merge commits are skipped, so syncing target into the branch
+    /// doesn't inflate the count the way it inflates "ahead". Shares its
+    /// cache with [`is_squash_merged`](Self::is_squash_merged), keyed per
+    /// (branch SHA, target SHA) pair.
+    pub fn unmerged_commit_count(&self, branch: &str, target: &str) -> anyhow::Result<usize> {
+        let (branch_sha, target_sha) = self.resolve_commit_pair(branch, target)?;
+        let (unmerged, _) = self.cherry_counts(&branch_sha, &target_sha)?;
+        Ok(unmerged)
+    }
+
+    /// Resolve branch and target to commit SHAs in one `rev-parse`.
+    fn resolve_commit_pair(&self, branch: &str, target: &str) -> anyhow::Result<(String, String)> {
+        let branch = self.resolve_preferring_branch(branch);
+        let target = self.resolve_preferring_branch(target);
+        let output = self.run_command(&["rev-parse", &branch, &target])?;
+        let mut shas = output.lines().map(str::trim);
+        let (Some(branch_sha), Some(target_sha)) = (shas.next(), shas.next()) else {
+            bail!("rev-parse returned incomplete output for {branch} {target}");
+        };
+        Ok((branch_sha.to_string(), target_sha.to_string()))
+    }
+
+    /// Run `git cherry target branch` and count its marks: `+` for commits
+    /// target lacks, `-` for commits whose change target already has.
+    fn cherry_counts(&self, branch_sha: &str, target_sha: &str) -> anyhow::Result<(usize, usize)> {
+        let key = (branch_sha.to_string(), target_sha.to_string());
+        if let Some(cached) = self.cache.cherry.get(&key) {
+            return Ok(*cached);
+        }
+
+        let cherry = self.run_command(&["cherry", target_sha, branch_sha])?;
+        let counts = cherry.lines().fold((0, 0), |(unmerged, equivalent), line| {
+            match line.chars().next() {
+                Some('+') => (unmerged + 1, equivalent),
+                Some('-') => (unmerged, equivalent + 1),
+                _ => (unmerged, equivalent),
+            }
+        });
+        self.cache.cherry.insert(key, counts);
+        Ok(counts)
+    }
+
+    fn compute_squash_merged(&self, branch: &str, target: &str) -> anyhow::Result<bool> {
+        if self.is_ancestor(branch, target)? {
+            return Ok(false);
+        }
+        let Some(merge_base) = self.merge_base(target, branch)? else {
+            return Ok(false);
+        };
+
+        // Every branch commit's change already exists on target
+        let (unmerged, equivalent) = self.cherry_counts(branch, target)?;
+        if unmerged == 0 && equivalent > 0 {
+            return Ok(true);
+        }
+
+        // Several commits squashed into one: compare the branch's whole diff
+        // against each commit target gained since the fork point
+        let diff =
+            self.run_command(&["diff", "--no-color", "--no-ext-diff", &merge_base, branch])?;
+        let Some(branch_patch_id) = self.patch_ids(diff)?.into_iter().next() else {
+            // Empty diff: nothing to match
+            return Ok(false);
+        };
+        let log = self.run_command(&[
+            "log",
+            "-p",
+            "--no-merges",
+            "--no-color",
+            "--no-ext-diff",
+            &format!("{merge_base}..{target}"),
+        ])?;
+        Ok(self.patch_ids(log)?.contains(&branch_patch_id))
+    }
+
+    /// Run `git patch-id --stable` over a patch series, one ID per patch.
+    fn patch_ids(&self, patches: String) -> anyhow::Result<Vec<String>> {
+        if patches.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let output = Cmd::new("git")
+            .args(["patch-id", "--stable"])
+            .current_dir(&self.discovery_path)
+            .context(self.logging_context())
+            .stdin_bytes(patches)
+            .run()
+            .context("Failed to execute: git patch-id --stable")?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("git patch-id failed: {}", stderr.trim());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .map(str::to_owned)
+            .collect())
+    }
+
     /// Determine the effective target for integration checks.
     ///
     /// If the upstream of the local target (e.g., `origin/main`) is strictly ahead of
@@ -10,7 +10,7 @@
 //! - `working_tree.rs` - WorkingTree struct and worktree-specific operations
 //! - `branch.rs` - Branch struct and single-branch operations (exists, upstream, remotes)
 //! - `branches.rs` - Multi-branch operations (listing, filtering, completions)
-//! - `worktrees.rs` - Worktree management (list, resolve, remove)
+//! - `worktrees.rs` - Worktree management (list, resolve, add, move, lock, remove)
 //! - `remotes.rs` - Remote and URL operations
 //! - `diff.rs` - Diff, history, and commit operations
 //! - `config.rs` - Git config, hints, markers, and default branch detection
@@ -20,7 +20,7 @@ use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
@@ -51,10 +51,11 @@ mod remotes;
 mod working_tree;
 mod worktrees;
 
-// Re-export WorkingTree and Branch
+// Re-export WorkingTree, Branch, and worktree options
 pub use branch::Branch;
 pub use working_tree::WorkingTree;
 pub(super) use working_tree::path_to_logging_context;
+pub use worktrees::AddWorktreeOpts;
 
 /// Structured error from [`Repository::run_command_delayed_stream`].
 ///
@@ -108,6 +109,8 @@ pub(super) struct RepoCache {
     pub(super) integration_target: OnceCell<Option<String>>,
     /// Primary remote name (None if no remotes configured)
     pub(super) primary_remote: OnceCell<Option<String>>,
+    /// Configured remotes with URLs: (remote_name, url), in config order
+    pub(super) remote_urls: OnceCell<Vec<(String, String)>>,
     /// Primary remote URL (None if no remotes configured or no URL)
     pub(super) primary_remote_url: OnceCell<Option<String>>,
     /// Project identifier derived from remote URL
@@ -122,11 +125,19 @@ pub(super) struct RepoCache {
     pub(super) resolved_config: OnceCell<ResolvedConfig>,
     /// Sparse checkout paths (empty if not a sparse checkout)
     pub(super) sparse_checkout_paths: OnceCell<Vec<String>>,
+    /// Shallow or partial clone detection (None = full history and objects)
+    pub(super) incomplete_history: OnceCell<Option<IncompleteHistory>>,
     /// Merge-base cache: (commit1, commit2) -> merge_base_sha (None = no common ancestor)
     pub(super) merge_base: DashMap<(String, String), Option<String>>,
     /// Batch ahead/behind cache: (base_ref, branch_name) -> (ahead, behind)
     /// Populated by batch_ahead_behind(), used by get_cached_ahead_behind()
     pub(super) ahead_behind: DashMap<(String, String), (usize, usize)>,
+    /// Merge-tree conflict cache: (base, head) -> conflicted paths (None = clean merge)
+    pub(super) merge_conflicts: DashMap<(String, String), Option<Vec<String>>>,
+    /// Squash-merge cache: (branch_sha, target_sha) -> whether target has the branch's patches
+    pub(super) squash_merged: DashMap<(String, String), bool>,
+    /// `git cherry` cache: (branch_sha, target_sha) -> (unmerged, already on target) commit counts
+    pub(super) cherry: DashMap<(String, String), (usize, usize)>,
 
     // ========== Per-worktree values (keyed by path) ==========
     /// Worktree root paths: worktree_path -> canonicalized root
@@ -135,6 +146,18 @@ pub(super) struct RepoCache {
     pub(super) current_branches: DashMap<PathBuf, Option<String>>,
 }
 
+/// Why a clone lacks some history or objects.
+///
+/// Diffs on such clones can trigger on-demand fetches (partial) or stop at the
+/// grafted boundary (shallow), so `wt list` skips line counts by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncompleteHistory {
+    /// `git clone --depth`: commits beyond the boundary are missing
+    Shallow,
+    /// `git clone --filter`: blobs or trees are fetched from a promisor remote on demand
+    Partial,
+}
+
 /// Result of resolving a worktree name.
 ///
 /// Used by `resolve_worktree` to handle different resolution outcomes:
@@ -156,23 +179,38 @@ pub enum ResolvedWorktree {
     },
 }
 
-/// Global base path for repository operations, set by -C flag.
-static BASE_PATH: OnceLock<PathBuf> = OnceLock::new();
+/// Discovered git common dirs: canonical discovery path -> common dir.
+///
+/// Commands call [`Repository::current()`] from several places (command log,
+/// verbose log, output hints), and each call would otherwise spawn
+/// `git rev-parse --git-common-dir` again. Only successful discoveries are
+/// recorded; removing or moving a worktree forgets the paths inside it.
+static DISCOVERED: LazyLock<DashMap<PathBuf, PathBuf>> = LazyLock::new(DashMap::new);
+
+/// Forget discoveries made from inside `path`, after it was removed or moved.
+pub(super) fn forget_discovered(path: &Path) {
+    let path = canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    DISCOVERED.retain(|discovery_path, _| !discovery_path.starts_with(&path));
+}
 
-/// Default base path when -C flag is not provided.
-static DEFAULT_BASE_PATH: LazyLock<PathBuf> = LazyLock::new(|| PathBuf::from("."));
+/// Global base path for repository operations, set by -C flag.
+static BASE_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
 
-/// Initialize the global base path for repository operations.
+/// Set the base path for repository operations.
 ///
-/// This should be called once at program startup from main().
-/// If not called, defaults to "." (current directory).
+/// Called once from main() for `-C`. If never called, defaults to "."
+/// (current directory).
 pub fn set_base_path(path: PathBuf) {
-    BASE_PATH.set(path).ok();
+    *BASE_PATH.write().unwrap_or_else(|e| e.into_inner()) = Some(path);
 }
 
-/// Get the base path for repository operations.
-fn base_path() -> &'static PathBuf {
-    BASE_PATH.get().unwrap_or(&DEFAULT_BASE_PATH)
+/// Get the base path for repository operations: the `-C` directory, or `.`.
+pub fn base_path() -> PathBuf {
+    BASE_PATH
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("."))
 }
 
 /// Repository state for git operations.
@@ -219,7 +257,7 @@ impl Repository {
     /// For worktree-specific operations on paths other than cwd, use
     /// `repo.worktree_at(path)` to get a [`WorkingTree`].
     pub fn current() -> anyhow::Result<Self> {
-        Self::at(base_path().clone())
+        Self::at(base_path())
     }
 
     /// Discover the repository from the specified path.
@@ -285,7 +323,25 @@ impl Repository {
     ///
     /// Always returns a canonicalized absolute path to ensure consistent
     /// comparison with `WorkingTree::git_dir()`.
+    ///
+    /// Memoized per process in [`DISCOVERED`]. A path that no longer resolves
+    /// (e.g., a deleted cwd) skips the memo so git reports the error as before.
     fn resolve_git_common_dir(discovery_path: &Path) -> anyhow::Result<PathBuf> {
+        let Ok(key) = canonicalize(discovery_path) else {
+            return Self::discover_git_common_dir(discovery_path);
+        };
+        if let Some(git_common_dir) = DISCOVERED.get(&key)
+            && git_common_dir.is_dir()
+        {
+            return Ok(git_common_dir.clone());
+        }
+        let git_common_dir = Self::discover_git_common_dir(discovery_path)?;
+        DISCOVERED.insert(key, git_common_dir.clone());
+        Ok(git_common_dir)
+    }
+
+    /// Run `git rev-parse --git-common-dir` from `discovery_path`.
+    fn discover_git_common_dir(discovery_path: &Path) -> anyhow::Result<PathBuf> {
         let output = Cmd::new("git")
             .args(["rev-parse", "--git-common-dir"])
             .current_dir(discovery_path)
@@ -317,11 +373,13 @@ impl Repository {
         &self.discovery_path
     }
 
-    /// Get a worktree view at the current directory.
+    /// Get a worktree view at the path this repository was discovered from:
+    /// the current directory (or `-C` path) for [`Repository::current()`], or
+    /// the path given to [`Repository::at()`].
     ///
     /// This is the primary way to get a [`WorkingTree`] for worktree-specific operations.
     pub fn current_worktree(&self) -> WorkingTree<'_> {
-        self.worktree_at(base_path().clone())
+        self.worktree_at(self.discovery_path.clone())
     }
 
     /// Get a worktree view at a specific path.
@@ -499,6 +557,28 @@ impl Repository {
         })
     }
 
+    /// Whether this is a shallow or partial clone.
+    ///
+    /// Shallow clones have a `shallow` file in the common dir. Partial clones
+    /// set `extensions.partialClone` or mark a remote as a promisor. Shallow
+    /// wins when both apply, since missing commits also affect ahead/behind.
+    pub fn incomplete_history(&self) -> Option<IncompleteHistory> {
+        *self.cache.incomplete_history.get_or_init(|| {
+            if self.git_common_dir.join("shallow").exists() {
+                return Some(IncompleteHistory::Shallow);
+            }
+            let is_partial = self
+                .get_config("extensions.partialclone")
+                .ok()
+                .flatten()
+                .is_some()
+                || self
+                    .run_command(&["config", "--get-regexp", r"^remote\..*\.promisor$"])
+                    .is_ok_and(|out| out.lines().any(|line| line.ends_with(" true")));
+            is_partial.then_some(IncompleteHistory::Partial)
+        })
+    }
+
     /// Check if git's builtin fsmonitor daemon is enabled.
     ///
     /// Returns true only for `core.fsmonitor=true` (the builtin daemon).
@@ -605,7 +685,29 @@ impl Repository {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn run_command(&self, args: &[&str]) -> anyhow::Result<String> {
-        let output = Cmd::new("git")
+        let stdout = self.run_command_bytes(args)?;
+        Ok(String::from_utf8_lossy(&stdout).into_owned())
+    }
+
+    /// Run a git command and return its raw stdout.
+    ///
+    /// Like [`run_command`](Self::run_command), but without decoding, for output
+    /// that may name non-UTF-8 paths or refs.
+    pub fn run_command_bytes(&self, args: &[&str]) -> anyhow::Result<Vec<u8>> {
+        self.run_git(Cmd::new("git"), args)
+    }
+
+    /// Run a git command that talks to a remote (fetch, push, ls-remote).
+    ///
+    /// Like [`run_command`](Self::run_command), but marked
+    /// [`network`](Cmd::network), so `--offline` refuses it.
+    pub fn run_network_command(&self, args: &[&str]) -> anyhow::Result<String> {
+        let stdout = self.run_git(Cmd::new("git").network(), args)?;
+        Ok(String::from_utf8_lossy(&stdout).into_owned())
+    }
+
+    fn run_git(&self, cmd: Cmd, args: &[&str]) -> anyhow::Result<Vec<u8>> {
+        let output = cmd
             .args(args.iter().copied())
             .current_dir(&self.discovery_path)
             .context(self.logging_context())
@@ -627,8 +729,7 @@ impl Repository {
             bail!("{}", error_msg);
         }
 
-        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
-        Ok(stdout)
+        Ok(output.stdout)
     }
 
     /// Run a git command and return whether it succeeded (exit code 0).
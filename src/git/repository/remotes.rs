@@ -31,20 +31,9 @@ impl Repository {
                 }
 
                 // Fall back to first remote with a configured URL
-                // Use git config to find remotes with URLs, filtering out phantom remotes
-                // from global config (e.g., `remote.origin.prunetags=true` without a URL)
-                let output = self
-                    .run_command(&["config", "--get-regexp", r"remote\..+\.url"])
-                    .unwrap_or_default();
-                let first_remote = output.lines().next().and_then(|line| {
-                    // Parse "remote.<name>.url <value>" format
-                    // Use ".url " as delimiter to handle remote names with dots (e.g., "my.remote")
-                    line.strip_prefix("remote.")
-                        .and_then(|s| s.split_once(".url "))
-                        .map(|(name, _)| name)
-                });
-
-                first_remote.map(|s| s.to_string())
+                // (remote_urls() filters out phantom remotes from global config,
+                // e.g., `remote.origin.prunetags=true` without a URL)
+                self.remote_urls().first().map(|(name, _)| name.clone())
             })
             .clone()
             .ok_or_else(|| anyhow::anyhow!("No remotes configured"))
@@ -81,27 +70,18 @@ impl Repository {
         owner: &str,
         repo: &str,
     ) -> Option<String> {
-        // Get all remotes with URLs
-        let output = self
-            .run_command(&["config", "--get-regexp", r"remote\..+\.url"])
-            .ok()?;
-
-        for line in output.lines() {
-            // Parse "remote.<name>.url <value>" format
-            if let Some(rest) = line.strip_prefix("remote.")
-                && let Some((name, url)) = rest.split_once(".url ")
-                && let Some(parsed) = GitRemoteUrl::parse(url)
-                // Case-insensitive comparison (GitHub owner/repo names are case-insensitive)
-                && parsed.owner().eq_ignore_ascii_case(owner)
-                && parsed.repo().eq_ignore_ascii_case(repo)
-                // If host is specified, it must also match (case-insensitive)
-                && host.is_none_or(|h| parsed.host().eq_ignore_ascii_case(h))
-            {
-                return Some(name.to_string());
-            }
-        }
-
-        None
+        self.remote_urls()
+            .iter()
+            .find(|(_, url)| {
+                GitRemoteUrl::parse(url).is_some_and(|parsed| {
+                    // Case-insensitive comparison (GitHub owner/repo names are case-insensitive)
+                    parsed.owner().eq_ignore_ascii_case(owner)
+                        && parsed.repo().eq_ignore_ascii_case(repo)
+                        // If host is specified, it must also match (case-insensitive)
+                        && host.is_none_or(|h| parsed.host().eq_ignore_ascii_case(h))
+                })
+            })
+            .map(|(name, _)| name.clone())
     }
 
     /// Find a remote that points to the same project as the given URL.
@@ -123,20 +103,28 @@ impl Repository {
     /// Returns a list of (remote_name, url) pairs for all remotes with URLs.
     /// Useful for searching across remotes when the specific remote is unknown.
     pub fn all_remote_urls(&self) -> Vec<(String, String)> {
-        let output = match self.run_command(&["config", "--get-regexp", r"remote\..+\.url"]) {
-            Ok(output) => output,
-            Err(_) => return Vec::new(),
-        };
-
-        output
-            .lines()
-            .filter_map(|line| {
-                // Parse "remote.<name>.url <value>" format
-                let rest = line.strip_prefix("remote.")?;
-                let (name, url) = rest.split_once(".url ")?;
-                Some((name.to_string(), url.to_string()))
-            })
-            .collect()
+        self.remote_urls().to_vec()
+    }
+
+    /// Configured remotes with URLs, cached in the shared repo cache.
+    fn remote_urls(&self) -> &[(String, String)] {
+        self.cache.remote_urls.get_or_init(|| {
+            let output = match self.run_command(&["config", "--get-regexp", r"remote\..+\.url"]) {
+                Ok(output) => output,
+                Err(_) => return Vec::new(),
+            };
+
+            output
+                .lines()
+                .filter_map(|line| {
+                    // Parse "remote.<name>.url <value>" format
+                    // Use ".url " as delimiter to handle remote names with dots (e.g., "my.remote")
+                    let rest = line.strip_prefix("remote.")?;
+                    let (name, url) = rest.split_once(".url ")?;
+                    Some((name.to_string(), url.to_string()))
+                })
+                .collect()
+        })
     }
 
     /// Get the URL for the primary remote, if configured.
@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use super::super::{DefaultBranchName, WorktreeInfo, finalize_worktree};
+use super::worktrees::find_worktree_at;
 
 #[test]
 fn test_parse_worktree_list() {
@@ -14,7 +15,7 @@ branch refs/heads/feature
 
 ";
 
-    let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
     let [main_wt, feature_wt]: [WorktreeInfo; 2] = worktrees.try_into().unwrap();
 
     assert_eq!(main_wt.path, PathBuf::from("/path/to/main"));
@@ -28,6 +29,25 @@ branch refs/heads/feature
     assert_eq!(feature_wt.branch, Some("feature".to_string()));
 }
 
+#[test]
+fn test_find_worktree_at_case_sensitivity() {
+    let output = "worktree /repos/app
+HEAD abcd1234
+branch refs/heads/main
+
+worktree /repos/app.Feature-Login
+HEAD efgh5678
+branch refs/heads/Feature/Login
+
+";
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
+    let target = std::path::Path::new("/repos/app.feature-login");
+
+    assert!(find_worktree_at(&worktrees, target, false).is_none());
+    let found = find_worktree_at(&worktrees, target, true).unwrap();
+    assert_eq!(found.branch.as_deref(), Some("Feature/Login"));
+}
+
 #[test]
 fn test_parse_detached_worktree() {
     let output = "worktree /path/to/detached
@@ -36,7 +56,7 @@ detached
 
 ";
 
-    let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
     let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
     assert!(wt.detached);
     assert_eq!(wt.branch, None);
@@ -49,6 +69,7 @@ fn test_finalize_worktree_with_branch() {
         path: PathBuf::from("/path/to/worktree"),
         head: "abcd1234".to_string(),
         branch: Some("feature".to_string()),
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: None,
@@ -66,6 +87,7 @@ fn test_finalize_worktree_detached_with_branch() {
         path: PathBuf::from("/path/to/worktree"),
         head: "abcd1234".to_string(),
         branch: Some("feature".to_string()),
+        branch_raw: None,
         bare: false,
         detached: true,
         locked: None,
@@ -86,6 +108,7 @@ fn test_finalize_worktree_detached_no_branch() {
         path: PathBuf::from("/nonexistent/path"),
         head: "abcd1234".to_string(),
         branch: None,
+        branch_raw: None,
         bare: false,
         detached: true,
         locked: None,
@@ -107,7 +130,7 @@ locked reason for lock
 
 ";
 
-    let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
     let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
     assert_eq!(wt.locked, Some("reason for lock".to_string()));
 }
@@ -120,7 +143,7 @@ bare
 
 ";
 
-    let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
     let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
     assert!(wt.bare);
 }
@@ -309,7 +332,7 @@ locked
 
 ";
 
-    let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
     let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
     // Empty lock reason should still be recorded
     assert_eq!(wt.locked, Some(String::new()));
@@ -324,7 +347,7 @@ prunable gitdir file points to non-existent location
 
 ";
 
-    let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
     let [wt]: [WorktreeInfo; 1] = worktrees.try_into().unwrap();
     assert!(wt.prunable.is_some());
     assert!(wt.prunable.as_ref().unwrap().contains("non-existent"));
@@ -350,7 +373,7 @@ detached
 
 ";
 
-    let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
     let [main_wt, feature_a, feature_b, detached_wt]: [WorktreeInfo; 4] =
         worktrees.try_into().unwrap();
     assert_eq!(main_wt.branch, Some("main".to_string()));
@@ -428,3 +451,302 @@ fn extract_failed_command_from_other_error() {
     assert_eq!(output, "some other error");
     assert!(cmd.is_none());
 }
+
+#[test]
+fn add_worktree_opts_git_args() {
+    use super::AddWorktreeOpts;
+    use std::path::Path;
+
+    let path = Path::new("/tmp/repo.feature");
+    let args = |opts: AddWorktreeOpts<'_>| opts.git_args().unwrap().join(" ");
+
+    assert_eq!(
+        args(AddWorktreeOpts::new(path)),
+        "worktree add -- /tmp/repo.feature"
+    );
+    assert_eq!(
+        args(AddWorktreeOpts {
+            branch: Some("feature"),
+            ..AddWorktreeOpts::new(path)
+        }),
+        "worktree add -- /tmp/repo.feature feature"
+    );
+    assert_eq!(
+        args(AddWorktreeOpts {
+            branch: Some("feature"),
+            create_branch: true,
+            base: Some("origin/main"),
+            track: true,
+            no_checkout: true,
+            ..AddWorktreeOpts::new(path)
+        }),
+        "worktree add --no-checkout --track -b feature -- /tmp/repo.feature origin/main"
+    );
+    assert_eq!(
+        args(AddWorktreeOpts {
+            base: Some("refs/tags/v1.0"),
+            detach: true,
+            ..AddWorktreeOpts::new(path)
+        }),
+        "worktree add --detach -- /tmp/repo.feature refs/tags/v1.0"
+    );
+    assert_eq!(
+        args(AddWorktreeOpts {
+            branch: Some("-dash"),
+            create_branch: true,
+            orphan: true,
+            ..AddWorktreeOpts::new(path)
+        }),
+        "worktree add --orphan -b -dash -- /tmp/repo.feature"
+    );
+    assert_eq!(
+        args(AddWorktreeOpts {
+            branch: Some("feature"),
+            force: true,
+            ..AddWorktreeOpts::new(path)
+        }),
+        "worktree add --force -- /tmp/repo.feature feature"
+    );
+}
+
+#[test]
+fn add_worktree_opts_rejects_conflicts() {
+    use super::AddWorktreeOpts;
+    use std::path::Path;
+
+    let path = Path::new("/tmp/repo.feature");
+    for opts in [
+        AddWorktreeOpts {
+            create_branch: true,
+            ..AddWorktreeOpts::new(path)
+        },
+        AddWorktreeOpts {
+            branch: Some("feature"),
+            create_branch: true,
+            detach: true,
+            ..AddWorktreeOpts::new(path)
+        },
+        AddWorktreeOpts {
+            branch: Some("feature"),
+            orphan: true,
+            base: Some("main"),
+            ..AddWorktreeOpts::new(path)
+        },
+        AddWorktreeOpts {
+            branch: Some("feature"),
+            track: true,
+            ..AddWorktreeOpts::new(path)
+        },
+    ] {
+        assert!(opts.git_args().is_err(), "{opts:?}");
+    }
+}
+
+#[test]
+fn worktree_mutations_in_temp_repo() {
+    use super::{AddWorktreeOpts, Repository};
+    use crate::shell_exec::Cmd;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let base = dunce::canonicalize(tmp.path()).unwrap();
+    let repo_dir = base.join("repo");
+    std::fs::create_dir(&repo_dir).unwrap();
+    for args in [
+        &["init", "--quiet", "-b", "main"][..],
+        &[
+            "-c",
+            "user.name=Test",
+            "-c",
+            "user.email=test@example.com",
+            "commit",
+            "--allow-empty",
+            "-m",
+            "init",
+        ],
+    ] {
+        Cmd::new("git")
+            .args(args.iter().copied())
+            .current_dir(&repo_dir)
+            .run()
+            .unwrap();
+    }
+    let repo = Repository::at(&repo_dir).unwrap();
+
+    // Create a branch in a new worktree
+    let feature_path = base.join("feature");
+    let wt = repo
+        .add_worktree(AddWorktreeOpts {
+            branch: Some("feature"),
+            create_branch: true,
+            base: Some("main"),
+            ..AddWorktreeOpts::new(&feature_path)
+        })
+        .unwrap();
+    assert_eq!(wt.branch().unwrap().as_deref(), Some("feature"));
+
+    // Detached, unpopulated worktree
+    let detached_path = base.join("detached");
+    let _ = repo
+        .add_worktree(AddWorktreeOpts {
+            base: Some("main"),
+            detach: true,
+            no_checkout: true,
+            ..AddWorktreeOpts::new(&detached_path)
+        })
+        .unwrap();
+    let detached = repo
+        .list_worktrees()
+        .unwrap()
+        .into_iter()
+        .find(|wt| wt.path == detached_path)
+        .unwrap();
+    assert!(detached.detached);
+
+    // Move, lock, unlock, remove
+    let moved_path = base.join("moved");
+    repo.move_worktree(&feature_path, &moved_path).unwrap();
+    assert_eq!(
+        repo.worktree_for_branch("feature").unwrap(),
+        Some(moved_path.clone())
+    );
+
+    repo.lock_worktree(&moved_path, Some("in use")).unwrap();
+    let locked = |repo: &Repository| {
+        repo.list_worktrees()
+            .unwrap()
+            .into_iter()
+            .find(|wt| wt.path == moved_path)
+            .unwrap()
+            .locked
+    };
+    assert_eq!(locked(&repo).as_deref(), Some("in use"));
+    repo.unlock_worktree(&moved_path).unwrap();
+    assert_eq!(locked(&repo), None);
+
+    repo.remove_worktree(&moved_path, false).unwrap();
+    assert_eq!(repo.worktree_for_branch("feature").unwrap(), None);
+}
+
+#[test]
+fn contended_lock_from_git_output() {
+    use super::StreamCommandError;
+    use super::worktrees::contended_lock;
+
+    let stream_err = |output: &str| -> anyhow::Error {
+        StreamCommandError {
+            output: output.into(),
+            command: "git worktree add".into(),
+            exit_info: "exit code 128".into(),
+        }
+        .into()
+    };
+
+    assert_eq!(
+        contended_lock(&stream_err(
+            "fatal: Unable to create '/repo/.git/index.lock': File exists.\n\nAnother git process seems to be running in this repository"
+        )),
+        Some(PathBuf::from("/repo/.git/index.lock"))
+    );
+    assert_eq!(
+        contended_lock(&stream_err(
+            "fatal: cannot lock ref 'refs/heads/feature': Unable to create '/repo/.git/refs/heads/feature.lock': File exists."
+        )),
+        Some(PathBuf::from("/repo/.git/refs/heads/feature.lock"))
+    );
+    assert_eq!(
+        contended_lock(&stream_err(
+            "error: could not lock config file /repo/.git/config: File exists"
+        )),
+        Some(PathBuf::from("/repo/.git/config.lock"))
+    );
+    assert_eq!(
+        contended_lock(&stream_err(
+            "fatal: a branch named 'feature' already exists"
+        )),
+        None
+    );
+}
+
+#[test]
+fn concurrent_add_worktree_shares_parent_dirs() {
+    use super::{AddWorktreeOpts, Repository};
+    use crate::shell_exec::Cmd;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let base = dunce::canonicalize(tmp.path()).unwrap();
+    let repo_dir = base.join("repo");
+    std::fs::create_dir(&repo_dir).unwrap();
+    for args in [
+        &["init", "--quiet", "-b", "main"][..],
+        &[
+            "-c",
+            "user.name=Test",
+            "-c",
+            "user.email=test@example.com",
+            "commit",
+            "--allow-empty",
+            "-m",
+            "init",
+        ],
+    ] {
+        Cmd::new("git")
+            .args(args.iter().copied())
+            .current_dir(&repo_dir)
+            .run()
+            .unwrap();
+    }
+
+    // Every worktree lands under the same not-yet-existing parent tree
+    let parent = base.join("worktrees").join("nested");
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let repo_dir = repo_dir.clone();
+            let path = parent.join(format!("feature-{i}"));
+            std::thread::spawn(move || {
+                let repo = Repository::at(&repo_dir).unwrap();
+                let branch = format!("feature-{i}");
+                repo.add_worktree(AddWorktreeOpts {
+                    branch: Some(&branch),
+                    create_branch: true,
+                    base: Some("main"),
+                    ..AddWorktreeOpts::new(&path)
+                })
+                .map(|_| ())
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap().unwrap();
+    }
+
+    let repo = Repository::at(&repo_dir).unwrap();
+    for i in 0..8 {
+        assert_eq!(
+            repo.worktree_for_branch(&format!("feature-{i}")).unwrap(),
+            Some(parent.join(format!("feature-{i}")))
+        );
+    }
+}
+
+#[test]
+fn commit_details_only_forgives_unborn_head() {
+    use super::Repository;
+    use crate::shell_exec::Cmd;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let repo_dir = dunce::canonicalize(tmp.path()).unwrap();
+    Cmd::new("git")
+        .args(["init", "--quiet", "-b", "main"])
+        .current_dir(&repo_dir)
+        .run()
+        .unwrap();
+    let repo = Repository::at(&repo_dir).unwrap();
+
+    assert_eq!(repo.commit_details("HEAD").unwrap(), None);
+    assert_eq!(repo.commit_details(crate::git::NULL_OID).unwrap(), None);
+    assert!(repo.commit_details("no-such-branch").is_err());
+    assert!(
+        repo.commit_details("1111111111111111111111111111111111111111")
+            .is_err()
+    );
+}
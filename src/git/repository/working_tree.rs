@@ -71,7 +71,8 @@ impl<'a> WorkingTree<'a> {
 
     /// Get the path this WorkingTree was created with.
     ///
-    /// This is the path passed to `worktree_at()` or `base_path()` for `current_worktree()`.
+    /// This is the path passed to `worktree_at()`, or the repository's discovery path for
+    /// `current_worktree()`.
     /// For the canonical git-determined root, use [`root()`](Self::root) instead.
     pub fn path(&self) -> &Path {
         &self.path
@@ -79,8 +80,16 @@ impl<'a> WorkingTree<'a> {
 
     /// Run a git command in this worktree and return stdout.
     pub fn run_command(&self, args: &[&str]) -> anyhow::Result<String> {
-        let output = self.run_command_output(args)?;
+        Self::stdout_or_error(self.run_command_output(args)?)
+    }
+
+    /// Run a git command that talks to a remote in this worktree and return
+    /// stdout. Marked [`network`](Cmd::network), so `--offline` refuses it.
+    pub fn run_network_command(&self, args: &[&str]) -> anyhow::Result<String> {
+        Self::stdout_or_error(self.run_network_command_output(args)?)
+    }
 
+    fn stdout_or_error(output: std::process::Output) -> anyhow::Result<String> {
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             let stderr = stderr.replace('\r', "\n");
@@ -102,8 +111,20 @@ impl<'a> WorkingTree<'a> {
     /// Use this when you need to check exit codes directly (e.g., for commands
     /// where non-zero exit is not an error condition).
     pub fn run_command_output(&self, args: &[&str]) -> anyhow::Result<std::process::Output> {
-        Cmd::new("git")
-            .args(args.iter().copied())
+        self.git_output(Cmd::new("git"), args)
+    }
+
+    /// [`run_command_output`](Self::run_command_output) for a git command that
+    /// talks to a remote, so `--offline` refuses it.
+    pub fn run_network_command_output(
+        &self,
+        args: &[&str],
+    ) -> anyhow::Result<std::process::Output> {
+        self.git_output(Cmd::new("git").network(), args)
+    }
+
+    fn git_output(&self, cmd: Cmd, args: &[&str]) -> anyhow::Result<std::process::Output> {
+        cmd.args(args.iter().copied())
             .current_dir(&self.path)
             .context(path_to_logging_context(&self.path))
             .run()
@@ -153,11 +174,145 @@ impl<'a> WorkingTree<'a> {
     /// 2. On large repos (70k+ files), this adds noticeable latency to every clean check
     /// 3. Users who use skip-worktree are power users who understand the implications
     /// 4. A warning wouldn't prevent data loss anyway — it's informational only
+    ///
+    /// [`Self::hidden_changes`] reports those files when explicitly requested.
     pub fn is_dirty(&self) -> anyhow::Result<bool> {
-        let stdout = self.run_command(&["status", "--porcelain"])?;
+        let stdout = self.status_porcelain()?;
         Ok(!stdout.trim().is_empty())
     }
 
+    /// `git status --porcelain` output used for all dirtiness checks.
+    ///
+    /// Ignored files are never reported (`--ignored=no`), and like any status
+    /// output, files marked `--skip-worktree` or `--assume-unchanged` are omitted.
+    /// Uses `--no-optional-locks` so concurrent checks don't contend for the index lock.
+    pub fn status_porcelain(&self) -> anyhow::Result<String> {
+        self.run_command(&[
+            "--no-optional-locks",
+            "status",
+            "--porcelain",
+            "--ignored=no",
+        ])
+    }
+
+    /// Tracked files hidden from status whose contents differ from the index.
+    ///
+    /// Covers files marked with `git update-index --skip-worktree` or
+    /// `--assume-unchanged`. Lists every tracked file, so callers only run this
+    /// on request (`wt list --include-skipped`).
+    ///
+    /// Sparse checkout marks every file outside the checkout as skip-worktree
+    /// and leaves it off disk; those absent files aren't changes.
+    pub fn hidden_changes(&self) -> anyhow::Result<Vec<String>> {
+        let listing = self.run_command(&["ls-files", "-v", "-s", "-z"])?;
+        let sparse = self.is_sparse_checkout()?;
+        // Entries look like `S 100644 <sha> 0\t<path>`; `S` marks skip-worktree,
+        // lowercase tags mark assume-unchanged.
+        let hidden: Vec<(&str, &str, &str)> = listing
+            .split('\0')
+            .filter_map(|entry| {
+                let (meta, path) = entry.split_once('\t')?;
+                let mut fields = meta.split(' ');
+                let tag = fields.next()?;
+                let sha = fields.nth(1)?;
+                let is_hidden = tag == "S" || tag.chars().all(|c| c.is_ascii_lowercase());
+                is_hidden.then_some((tag, sha, path))
+            })
+            .collect();
+        if hidden.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Paths are relative to the directory git ran in
+        let mut changed = Vec::new();
+        let mut present = Vec::new();
+        for (tag, sha, path) in hidden {
+            if self.path.join(path).exists() {
+                present.push((sha, path));
+            } else if !(sparse && tag == "S") {
+                changed.push(path.to_string());
+            }
+        }
+        if !present.is_empty() {
+            let mut args = vec!["hash-object", "--"];
+            args.extend(present.iter().map(|(_, path)| *path));
+            let hashes = self.run_command(&args)?;
+            changed.extend(
+                present
+                    .iter()
+                    .zip(hashes.lines())
+                    .filter(|((sha, _), hash)| sha != hash)
+                    .map(|((_, path), _)| path.to_string()),
+            );
+        }
+        Ok(changed)
+    }
+
+    /// Whether `core.sparseCheckout` is on for this worktree.
+    fn is_sparse_checkout(&self) -> anyhow::Result<bool> {
+        let output =
+            self.run_command_output(&["config", "--type=bool", "--get", "core.sparseCheckout"])?;
+        // Exit code 1 means the key isn't set
+        Ok(output.status.success() && String::from_utf8_lossy(&output.stdout).trim() == "true")
+    }
+
+    /// A git config value as resolved in this worktree, with where it came from.
+    ///
+    /// Resolution includes `includeIf` sections, whose conditions depend on
+    /// the worktree's location. Returns `(value, origin)`, where origin is as
+    /// printed by `git config --show-origin` (`file:/home/me/.gitconfig`), or
+    /// `None` when the key is unset.
+    pub fn config_with_origin(&self, key: &str) -> anyhow::Result<Option<(String, String)>> {
+        let output = self.run_command_output(&["config", "--show-origin", "--get", key])?;
+        // Exit code 1 means the key isn't set
+        if output.status.code() == Some(1) {
+            return Ok(None);
+        }
+        if !output.status.success() {
+            bail!(
+                "Failed to read {key}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let line = stdout.trim_end_matches(['\n', '\r']);
+        Ok(line
+            .split_once('\t')
+            .map(|(origin, value)| (value.to_string(), origin.to_string())))
+    }
+
+    /// Set `key` in this worktree's own `config.worktree`, leaving other
+    /// worktrees untouched.
+    ///
+    /// Needs `extensions.worktreeConfig`; see
+    /// [`Repository::enable_worktree_config`](super::Repository::enable_worktree_config).
+    pub fn set_worktree_config(&self, key: &str, value: &str) -> anyhow::Result<()> {
+        self.run_command(&["config", "--worktree", key, value])?;
+        Ok(())
+    }
+
+    /// The settings in this worktree's own `config.worktree`, in file order.
+    ///
+    /// Empty when the worktree has no such file. Git only reads the file while
+    /// `extensions.worktreeConfig` is on, which this doesn't check.
+    pub fn worktree_config(&self) -> anyhow::Result<Vec<(String, String)>> {
+        let path = self.git_dir()?.join("config.worktree");
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let path = path.to_string_lossy();
+        let stdout = self.run_command(&["config", "--file", &path, "--list", "-z"])?;
+        // Each entry is `key\nvalue\0`; a key without a value has no newline
+        Ok(stdout
+            .split('\0')
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (key, value) = entry.split_once('\n').unwrap_or((entry, ""));
+                (key.to_string(), value.to_string())
+            })
+            .collect())
+    }
+
     /// Get the root directory of this worktree (top-level of the working tree).
     ///
     /// Returns the canonicalized absolute path to the top-level directory.
@@ -202,6 +357,57 @@ impl<'a> WorkingTree<'a> {
         Ok(git_dir.join("rebase-merge").exists() || git_dir.join("rebase-apply").exists())
     }
 
+    /// The commit HEAD last pointed at, according to this worktree's HEAD reflog.
+    ///
+    /// Reads the reflog file directly: git refuses `git reflog HEAD` when HEAD
+    /// doesn't resolve, which is exactly when this is needed. Returns `None`
+    /// when there's no reflog or it never recorded a commit.
+    pub fn reflog_head_commit(&self) -> anyhow::Result<Option<String>> {
+        let log_path = self.git_dir()?.join("logs").join("HEAD");
+        let Ok(log) = std::fs::read_to_string(&log_path) else {
+            return Ok(None);
+        };
+        Ok(log
+            .lines()
+            .rev()
+            .find_map(|line| line.split(' ').nth(1))
+            .filter(|sha| *sha != crate::git::NULL_OID)
+            .map(str::to_string))
+    }
+
+    /// The commit HEAD last pointed at, when this worktree's branch was deleted
+    /// out from under it.
+    ///
+    /// See [`Repository::deleted_branch_commit`], which avoids running git for
+    /// worktrees whose HEAD is already known.
+    pub fn deleted_branch_commit(&self) -> anyhow::Result<Option<String>> {
+        if self
+            .run_command(&["rev-parse", "--verify", "-q", "HEAD"])
+            .is_ok()
+            || self.branch()?.is_none()
+        {
+            return Ok(None);
+        }
+        self.reflog_head_commit()
+    }
+
+    /// Whether the index, working tree, or untracked files differ from `commit`.
+    ///
+    /// [`Self::is_dirty`] for a worktree whose HEAD doesn't resolve, where
+    /// `git status` reports every tracked file as newly added.
+    pub fn is_dirty_against(&self, commit: &str) -> anyhow::Result<bool> {
+        for args in [
+            &["diff", "--quiet", "--cached", commit, "--"][..],
+            &["diff", "--quiet", commit, "--"],
+        ] {
+            if !self.run_command_output(args)?.status.success() {
+                return Ok(true);
+            }
+        }
+        let untracked = self.run_command(&["ls-files", "--others", "--exclude-standard"])?;
+        Ok(!untracked.trim().is_empty())
+    }
+
     /// Check if a merge is in progress.
     pub fn is_merging(&self) -> anyhow::Result<bool> {
         let git_dir = self.git_dir()?;
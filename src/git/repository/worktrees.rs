@@ -1,13 +1,285 @@
 //! Worktree management operations for Repository.
 
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
+use super::{
+    GitError, Repository, ResolvedWorktree, StreamCommandError, WorkingTree, WorktreeInfo,
+    forget_discovered,
+};
+use crate::git::WorktrunkError;
+use crate::path::{format_path_for_display, paths_equal};
+use anyhow::Context;
 use color_print::cformat;
 use dunce::canonicalize;
-use normalize_path::NormalizePath;
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
 
-use super::{GitError, Repository, ResolvedWorktree, WorktreeInfo};
-use crate::path::format_path_for_display;
+/// Options for [`Repository::add_worktree`].
+///
+/// Construct with [`AddWorktreeOpts::new`] and override fields with struct
+/// update syntax.
+#[derive(Debug, Clone, Copy)]
+pub struct AddWorktreeOpts<'a> {
+    /// Directory for the new worktree
+    pub path: &'a Path,
+    /// Branch to check out, or to create with `create_branch`/`orphan`
+    pub branch: Option<&'a str>,
+    /// Create `branch` (`-b`), starting from `base` or HEAD
+    pub create_branch: bool,
+    /// Commit-ish to start from: the new branch's base, or the commit to detach at
+    pub base: Option<&'a str>,
+    /// Check out `base` (or `branch`) with a detached HEAD (`--detach`)
+    pub detach: bool,
+    /// Skip populating the working tree (`--no-checkout`)
+    pub no_checkout: bool,
+    /// Set `base` as the new branch's upstream (`--track`)
+    pub track: bool,
+    /// Create `branch` with no history (`--orphan`)
+    pub orphan: bool,
+    /// Check out `branch` even if another worktree has it (`--force`)
+    pub force: bool,
+    /// Progress message shown if git takes longer than
+    /// [`Repository::SLOW_OPERATION_DELAY_MS`]
+    pub progress: Option<&'a str>,
+}
+
+impl<'a> AddWorktreeOpts<'a> {
+    /// Options for checking out HEAD's commit at `path`, with everything else off.
+    pub fn new(path: &'a Path) -> Self {
+        Self {
+            path,
+            branch: None,
+            create_branch: false,
+            base: None,
+            detach: false,
+            no_checkout: false,
+            track: false,
+            orphan: false,
+            force: false,
+            progress: None,
+        }
+    }
+
+    /// Arguments for `git worktree add`.
+    ///
+    /// Options go before `--` so branch names and paths starting with `-`
+    /// aren't read as flags.
+    pub(super) fn git_args(&self) -> anyhow::Result<Vec<String>> {
+        let invalid = |message: &str| {
+            anyhow::Error::from(GitError::Other {
+                message: message.to_string(),
+            })
+        };
+        let creates = self.create_branch || self.orphan;
+        if creates && self.branch.is_none() {
+            return Err(invalid("Creating a worktree branch requires a branch name"));
+        }
+        if creates && self.detach {
+            return Err(invalid("Cannot both create a branch and detach HEAD"));
+        }
+        if self.orphan && self.base.is_some() {
+            return Err(invalid("Orphan branches have no base"));
+        }
+        if self.track && !self.create_branch {
+            return Err(invalid("--track applies only when creating a branch"));
+        }
+
+        let mut args: Vec<String> = vec!["worktree".into(), "add".into()];
+        if self.detach {
+            args.push("--detach".into());
+        }
+        if self.no_checkout {
+            args.push("--no-checkout".into());
+        }
+        if self.track {
+            args.push("--track".into());
+        }
+        if self.orphan {
+            args.push("--orphan".into());
+        }
+        if self.force {
+            args.push("--force".into());
+        }
+        if let Some(branch) = self.branch.filter(|_| creates) {
+            args.extend(["-b".into(), branch.into()]);
+        }
+        args.push("--".into());
+        args.push(self.path.to_string_lossy().into_owned());
+
+        let commitish = if creates {
+            self.base
+        } else {
+            self.branch.or(self.base)
+        };
+        args.extend(commitish.map(String::from));
+        Ok(args)
+    }
+}
+
+/// Directory under the git common dir holding [`CreationMarker`]s.
+const CREATION_MARKERS_DIR: &str = "wt-creating";
+
+/// Contents of a worktree creation marker.
+#[derive(Debug, Serialize, Deserialize)]
+struct CreationMarker {
+    /// Process running the creation; a live one means it's still in progress
+    pid: u32,
+    path: PathBuf,
+}
+
+/// Whether process `pid` is still running.
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    let Ok(pid) = i32::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 checks existence; EPERM means it exists under another user
+    !matches!(
+        nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid), None),
+        Err(nix::errno::Errno::ESRCH)
+    )
+}
+
+/// Whether process `pid` is still running. Without a cheap check, assume
+/// it isn't: recovery asks before removing anything.
+#[cfg(not(unix))]
+fn process_alive(_pid: u32) -> bool {
+    false
+}
+
+/// Notes Ctrl-C while a worktree is being created, instead of exiting.
+///
+/// Git sees the same SIGINT and cleans up after itself if it's mid-checkout;
+/// this lets `wt` outlive it to remove anything left and report the
+/// interruption. The handler is unregistered on drop.
+struct InterruptGuard {
+    #[cfg(unix)]
+    registration: Option<(
+        signal_hook::SigId,
+        std::sync::Arc<std::sync::atomic::AtomicBool>,
+    )>,
+}
+
+impl InterruptGuard {
+    fn install() -> Self {
+        #[cfg(unix)]
+        {
+            let flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+            let registration =
+                signal_hook::flag::register(signal_hook::consts::SIGINT, flag.clone())
+                    .inspect_err(|e| log::debug!("Failed to install interrupt handler: {e}"))
+                    .ok()
+                    .map(|id| (id, flag));
+            Self { registration }
+        }
+        #[cfg(not(unix))]
+        Self {}
+    }
+
+    fn fired(&self) -> bool {
+        #[cfg(unix)]
+        {
+            self.registration
+                .as_ref()
+                .is_some_and(|(_, flag)| flag.load(std::sync::atomic::Ordering::Relaxed))
+        }
+        #[cfg(not(unix))]
+        false
+    }
+}
+
+impl Drop for InterruptGuard {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some((id, _)) = self.registration.take() {
+            signal_hook::low_level::unregister(id);
+        }
+    }
+}
+
+/// Wait before retrying a git command that lost a race for a lock file.
+const LOCK_RETRY_DELAY: Duration = Duration::from_millis(250);
+
+/// Lock file a failed git command couldn't acquire, if that's why it failed.
+///
+/// Matches git's `Unable to create '<path>.lock': File exists` (index, refs,
+/// packed-refs) and `could not lock config file <path>`.
+pub(super) fn contended_lock(err: &anyhow::Error) -> Option<PathBuf> {
+    let output = err
+        .downcast_ref::<StreamCommandError>()
+        .map_or_else(|| err.to_string(), |e| e.output.clone());
+    output.lines().find_map(|line| {
+        if let Some((_, rest)) = line.split_once("Unable to create '")
+            && let Some((path, _)) = rest.split_once("': File exists")
+        {
+            return Some(PathBuf::from(path));
+        }
+        let (_, rest) = line.split_once("could not lock config file ")?;
+        let path = rest.split_once(':').map_or(rest, |(path, _)| path);
+        Some(PathBuf::from(format!("{path}.lock")))
+    })
+}
+
+/// Find the worktree at `path`, comparing paths case-insensitively when asked.
+pub(super) fn find_worktree_at<'a>(
+    worktrees: &'a [WorktreeInfo],
+    path: &Path,
+    case_insensitive: bool,
+) -> Option<&'a WorktreeInfo> {
+    worktrees
+        .iter()
+        .find(|wt| paths_equal(&wt.path, path, case_insensitive))
+}
+
+/// Replace git's lock error with one naming the process holding the lock.
+fn lock_contention_error(err: anyhow::Error, lock: &Path) -> anyhow::Error {
+    let holder = lock_holder(lock).unwrap_or_else(|| "another git process".to_string());
+    let message = format!(
+        "{holder} holds {}; retry once it finishes",
+        format_path_for_display(lock)
+    );
+    match err.downcast::<StreamCommandError>() {
+        Ok(e) => StreamCommandError {
+            output: message,
+            ..e
+        }
+        .into(),
+        Err(_) => anyhow::anyhow!(message),
+    }
+}
+
+/// Describe the process with `lock` open, e.g. `git (pid 4242)`.
+///
+/// Git keeps lock files open while it holds them, so scanning open file
+/// descriptors finds the holder. Only supported on Linux.
+#[cfg(target_os = "linux")]
+fn lock_holder(lock: &Path) -> Option<String> {
+    let own_pid = std::process::id().to_string();
+    std::fs::read_dir("/proc")
+        .ok()?
+        .flatten()
+        .find_map(|entry| {
+            let pid = entry.file_name().to_string_lossy().into_owned();
+            if pid == own_pid || !pid.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let fds = std::fs::read_dir(entry.path().join("fd")).ok()?;
+            fds.flatten()
+                .any(|fd| std::fs::read_link(fd.path()).is_ok_and(|target| target == lock))
+                .then(|| {
+                    let name = std::fs::read_to_string(entry.path().join("comm"))
+                        .map(|comm| comm.trim().to_string())
+                        .unwrap_or_else(|_| "process".to_string());
+                    format!("{name} (pid {pid})")
+                })
+        })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn lock_holder(_lock: &Path) -> Option<String> {
+    None
+}
 
 impl Repository {
     /// List all worktrees for this repository.
@@ -20,8 +292,8 @@ impl Repository {
     ///
     /// Returns an empty vec for bare repos with no linked worktrees.
     pub fn list_worktrees(&self) -> anyhow::Result<Vec<WorktreeInfo>> {
-        let stdout = self.run_command(&["worktree", "list", "--porcelain"])?;
-        let raw_worktrees = WorktreeInfo::parse_porcelain_list(&stdout)?;
+        let stdout = self.run_command_bytes(&["worktree", "list", "--porcelain"])?;
+        let raw_worktrees = WorktreeInfo::parse_porcelain_list_bytes(&stdout);
         let mut worktrees: Vec<_> = raw_worktrees.into_iter().filter(|wt| !wt.bare).collect();
 
         // Submodule path correction.
@@ -73,6 +345,25 @@ impl Repository {
         }))
     }
 
+    /// For a worktree whose branch was deleted out from under it, the commit
+    /// its HEAD last pointed at.
+    ///
+    /// Deleting a checked-out branch (`git update-ref -d`, or a tool that
+    /// doesn't check worktrees) leaves the worktree on a branch ref that no
+    /// longer exists, which git reports like an unborn branch. The worktree's
+    /// HEAD reflog tells the two apart: an unborn branch never recorded a
+    /// commit. A worktree moved to a new branch with `git switch --orphan` looks
+    /// the same as a deleted one and is reported as deleted too.
+    pub fn deleted_branch_commit(&self, wt: &WorktreeInfo) -> Option<String> {
+        if wt.branch.is_none() || wt.has_commits() || wt.is_prunable() {
+            return None;
+        }
+        self.worktree_at(&wt.path)
+            .reflog_head_commit()
+            .ok()
+            .flatten()
+    }
+
     /// Find the worktree path for a given branch, if one exists.
     pub fn worktree_for_branch(&self, branch: &str) -> anyhow::Result<Option<PathBuf>> {
         let worktrees = self.list_worktrees()?;
@@ -102,18 +393,23 @@ impl Repository {
     ///
     /// Returns `Some((path, branch))` if a worktree exists at the path,
     /// where `branch` is `None` for detached HEAD worktrees.
+    ///
+    /// On case-insensitive filesystems (default macOS and Windows volumes), a
+    /// worktree whose path differs only in case also counts: `app.Feature` and
+    /// `app.feature` are the same directory there.
     pub fn worktree_at_path(
         &self,
         path: &Path,
     ) -> anyhow::Result<Option<(PathBuf, Option<String>)>> {
         let worktrees = self.list_worktrees()?;
-        // Use lexical normalization so comparison works even when path doesn't exist
-        let normalized_path = path.normalize();
+        // Lexical comparison works even when path doesn't exist. Only probe the
+        // filesystem when a match hinges on case.
+        let found = find_worktree_at(&worktrees, path, false).or_else(|| {
+            find_worktree_at(&worktrees, path, true)
+                .filter(|_| crate::path::is_case_insensitive_fs(path))
+        });
 
-        Ok(worktrees
-            .iter()
-            .find(|wt| wt.path.normalize() == normalized_path)
-            .map(|wt| (wt.path.clone(), wt.branch.clone())))
+        Ok(found.map(|wt| (wt.path.clone(), wt.branch.clone())))
     }
 
     /// Prune worktree entries whose directories no longer exist.
@@ -126,6 +422,268 @@ impl Repository {
         Ok(())
     }
 
+    /// Exclusive lock serializing `git worktree add` in this repository, across
+    /// processes. Held until the returned file is dropped.
+    fn lock_worktree_admin(&self) -> anyhow::Result<std::fs::File> {
+        let path = self.git_common_dir().join("wt-worktree-add.lock");
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", format_path_for_display(&path)))?;
+        file.lock_exclusive()
+            .with_context(|| format!("Failed to lock {}", format_path_for_display(&path)))?;
+        Ok(file)
+    }
+
+    /// Create a worktree with `git worktree add`.
+    ///
+    /// Output is silent unless git runs slowly and `opts.progress` is set.
+    /// Returns a handle to the new worktree.
+    ///
+    /// Safe to call concurrently: parent directories are created race-free, adds
+    /// in the same repository take turns, and a command that loses a race for a
+    /// git lock file is retried once. If the lock is still held, the error names
+    /// the process holding it.
+    ///
+    /// Creation is all or nothing. A marker in `.git/wt-creating/` covers the
+    /// checkout; if git is killed or Ctrl-C arrives, the partial worktree and
+    /// any branch created for it are removed before returning. A marker left
+    /// behind means this process itself died mid-checkout, which
+    /// [`interrupted_creations`](Self::interrupted_creations) reports.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::path::Path;
+    /// use worktrunk::git::{AddWorktreeOpts, Repository};
+    ///
+    /// let repo = Repository::current()?;
+    /// let wt = repo.add_worktree(AddWorktreeOpts {
+    ///     branch: Some("feature"),
+    ///     create_branch: true,
+    ///     base: Some("main"),
+    ///     ..AddWorktreeOpts::new(Path::new("../repo.feature"))
+    /// })?;
+    /// assert_eq!(wt.branch()?.as_deref(), Some("feature"));
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn add_worktree(&self, opts: AddWorktreeOpts<'_>) -> anyhow::Result<WorkingTree<'_>> {
+        let args = opts.git_args()?;
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+        // Concurrent creations (several `wt switch --create` at once) share parent
+        // directories. `create_dir_all` tolerates another process creating them first.
+        if let Some(parent) = opts.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", format_path_for_display(parent)))?;
+        }
+
+        // Only a directory git creates is ours to clean up. An existing one is
+        // either empty (git fills it) or makes git refuse before touching it.
+        let fresh = !opts.path.exists();
+        let marker = fresh.then(|| self.mark_creation(opts.path)).flatten();
+        // A branch this call creates goes too if the worktree doesn't survive
+        let new_branch = opts
+            .branch
+            .filter(|_| fresh && opts.create_branch)
+            .filter(|branch| !self.branch(branch).exists_locally().unwrap_or(true));
+
+        // Git reads every worktree's admin directory while adding one, and fails
+        // on one a concurrent add has only half written
+        let _admin_lock = self.lock_worktree_admin()?;
+        let interrupt = InterruptGuard::install();
+        let run = || {
+            self.run_command_delayed_stream(
+                &args,
+                Self::SLOW_OPERATION_DELAY_MS,
+                opts.progress.map(str::to_string),
+            )
+        };
+        // A racing git process can briefly hold a shared lock; retry once
+        let result = run().or_else(|err| {
+            if contended_lock(&err).is_none() {
+                return Err(err);
+            }
+            log::debug!("Worktree creation hit a git lock; retrying");
+            std::thread::sleep(LOCK_RETRY_DELAY);
+            run().map_err(|err| match contended_lock(&err) {
+                Some(lock) => lock_contention_error(err, &lock),
+                None => err,
+            })
+        });
+        let interrupted = interrupt.fired();
+        drop(interrupt);
+
+        let error = if interrupted {
+            WorktrunkError::ChildProcessExited {
+                code: 130,
+                message: format!(
+                    "Interrupted; removed the partial worktree at {}",
+                    format_path_for_display(opts.path)
+                ),
+            }
+            .into()
+        } else {
+            match result {
+                Ok(()) => {
+                    if let Some(marker) = marker {
+                        let _ = std::fs::remove_file(marker);
+                    }
+                    return Ok(self.worktree_at(opts.path));
+                }
+                Err(err) => err,
+            }
+        };
+
+        // Git removes its own partial worktree on an ordinary failure or
+        // Ctrl-C; a directory left behind means git itself was killed.
+        if fresh && (interrupted || opts.path.exists()) {
+            if let Err(e) = self.discard_partial_worktree(opts.path) {
+                log::debug!("Failed to clean up partial worktree: {e:#}");
+            }
+            if let Some(branch) = new_branch
+                && self.branch(branch).exists_locally().unwrap_or(false)
+            {
+                let _ = self.run_command(&["branch", "-D", "--", branch]);
+            }
+        }
+        if let Some(marker) = marker {
+            let _ = std::fs::remove_file(marker);
+        }
+        Err(error)
+    }
+
+    /// Marker for a worktree creation in progress at `path`.
+    fn creation_marker(&self, path: &Path) -> PathBuf {
+        self.git_common_dir()
+            .join(CREATION_MARKERS_DIR)
+            .join(crate::path::sanitize_for_filename(&path.to_string_lossy()))
+    }
+
+    /// Record that this process is creating a worktree at `path`.
+    ///
+    /// Returns the marker, or `None` if it couldn't be written (creation
+    /// proceeds anyway; only recovery after a crash is lost).
+    fn mark_creation(&self, path: &Path) -> Option<PathBuf> {
+        let marker = self.creation_marker(path);
+        let contents = CreationMarker {
+            pid: std::process::id(),
+            path: path.to_path_buf(),
+        };
+        let written = serde_json::to_string(&contents)
+            .map_err(std::io::Error::other)
+            .and_then(|json| crate::config::state_file::write_atomic(&marker, json));
+        match written {
+            Ok(()) => Some(marker),
+            Err(e) => {
+                log::debug!("Failed to write worktree creation marker: {e}");
+                None
+            }
+        }
+    }
+
+    /// Worktrees whose creation by `wt` never finished.
+    ///
+    /// Each path had a `git worktree add` in progress when the `wt` running it
+    /// died (killed, or the machine lost power), so the worktree may be
+    /// registered with a half-populated directory. Creations still running in
+    /// another process aren't included. Cheap when there are none: no git
+    /// commands run.
+    pub fn interrupted_creations(&self) -> Vec<PathBuf> {
+        let Ok(entries) = std::fs::read_dir(self.git_common_dir().join(CREATION_MARKERS_DIR))
+        else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .filter_map(|entry| {
+                let contents = std::fs::read_to_string(entry.path()).ok()?;
+                let marker: CreationMarker = serde_json::from_str(&contents).ok()?;
+                (!process_alive(marker.pid)).then_some(marker.path)
+            })
+            .collect()
+    }
+
+    /// Remove a worktree whose creation didn't finish, and its marker.
+    ///
+    /// Unregisters the worktree if git got as far as registering it, and
+    /// deletes whatever was checked out. Only for paths from
+    /// [`interrupted_creations`](Self::interrupted_creations) or a failed
+    /// [`add_worktree`](Self::add_worktree): the directory goes without any
+    /// check for changes, so callers ask first about work done in it.
+    pub fn discard_partial_worktree(&self, path: &Path) -> anyhow::Result<()> {
+        forget_discovered(path);
+        if self.worktree_at_path(path)?.is_some() {
+            let path_str = path.to_string_lossy();
+            // Twice forced: git may still hold the worktree locked as "initializing"
+            self.run_command(&["worktree", "remove", "--force", "--force", "--", &path_str])?;
+        }
+        if path.exists() {
+            std::fs::remove_dir_all(path)
+                .with_context(|| format!("Failed to remove {}", format_path_for_display(path)))?;
+        }
+        let marker = self.creation_marker(path);
+        if marker.exists() {
+            std::fs::remove_file(marker)?;
+        }
+        Ok(())
+    }
+
+    /// Move a linked worktree to a new path with `git worktree move`.
+    ///
+    /// Git can't move the main worktree; callers relocate it by other means.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::path::Path;
+    /// use worktrunk::git::Repository;
+    ///
+    /// let repo = Repository::current()?;
+    /// repo.move_worktree(Path::new("../repo.old"), Path::new("../repo.new"))?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn move_worktree(&self, from: &Path, to: &Path) -> anyhow::Result<()> {
+        forget_discovered(from);
+        self.cache.worktree_roots.clear();
+        self.cache.current_branches.clear();
+        let from = from.to_string_lossy();
+        let to = to.to_string_lossy();
+        self.run_command(&["worktree", "move", "--", &from, &to])?;
+        Ok(())
+    }
+
+    /// Lock a worktree so `git worktree prune`/`remove` leave it alone.
+    ///
+    /// The reason shows in `git worktree list` and in `wt list` metadata.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::path::Path;
+    /// use worktrunk::git::Repository;
+    ///
+    /// let repo = Repository::current()?;
+    /// repo.lock_worktree(Path::new("../repo.usb"), Some("on removable drive"))?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn lock_worktree(&self, path: &Path, reason: Option<&str>) -> anyhow::Result<()> {
+        let path = path.to_string_lossy();
+        let mut args = vec!["worktree", "lock"];
+        if let Some(reason) = reason {
+            args.extend(["--reason", reason]);
+        }
+        args.extend(["--", &path]);
+        self.run_command(&args)?;
+        Ok(())
+    }
+
+    /// Unlock a worktree locked with [`lock_worktree`](Self::lock_worktree).
+    pub fn unlock_worktree(&self, path: &Path) -> anyhow::Result<()> {
+        let path = path.to_string_lossy();
+        self.run_command(&["worktree", "unlock", "--", &path])?;
+        Ok(())
+    }
+
     /// Remove a worktree at the specified path.
     ///
     /// When `force` is true, passes `--force` to `git worktree remove`,
@@ -137,6 +695,10 @@ impl Repository {
     /// adds `--force`, which is safe because the caller has already validated
     /// worktree cleanliness via `ensure_clean()`.
     ///
+    /// Worktrees whose branch was deleted get `--force` for the same reason:
+    /// git sees every tracked file as added, so callers check them with
+    /// `is_dirty_against()` instead.
+    ///
     /// # Why git requires `--force` for submodules
     ///
     /// Git's `--force` flag on `worktree remove` bypasses two unrelated
@@ -156,7 +718,7 @@ impl Repository {
     /// `ensure_clean()` and the actual delete), but for non-submodule
     /// worktrees git's own dirty check acts as an accidental backstop
     /// that we lose here. The window is milliseconds.
-    pub fn remove_worktree(&self, path: &std::path::Path, force: bool) -> anyhow::Result<()> {
+    pub fn remove_worktree(&self, path: &Path, force: bool) -> anyhow::Result<()> {
         let path_str = path.to_str().ok_or_else(|| {
             anyhow::Error::from(GitError::Other {
                 message: format!(
@@ -165,13 +727,13 @@ impl Repository {
                 ),
             })
         })?;
-        let use_force = if force {
-            true
-        } else {
-            self.worktree_at(path).has_initialized_submodules()?
-        };
+        let wt = self.worktree_at(path);
+        let use_force =
+            force || wt.deleted_branch_commit()?.is_some() || wt.has_initialized_submodules()?;
         if use_force && !force {
-            log::debug!("Using --force for worktree removal due to initialized submodules");
+            log::debug!(
+                "Using --force for worktree removal due to initialized submodules or a deleted branch"
+            );
         }
         let mut args = vec!["worktree", "remove"];
         if use_force {
@@ -179,6 +741,7 @@ impl Repository {
         }
         args.push(path_str);
 
+        forget_discovered(path);
         self.run_command(&args)?;
         Ok(())
     }
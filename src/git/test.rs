@@ -9,7 +9,7 @@ use rstest::rstest;
 
 /// Helper to parse a single worktree from porcelain output
 fn parse_single(input: &str) -> WorktreeInfo {
-    let list = WorktreeInfo::parse_porcelain_list(input).expect("parse ok");
+    let list = WorktreeInfo::parse_porcelain_list_bytes(input.as_bytes());
     assert_eq!(list.len(), 1);
     list.into_iter().next().unwrap()
 }
@@ -20,10 +20,7 @@ fn test_parse_worktree_list_no_trailing_blank_line() {
     // the last worktree might not be added
     // Looking at the code (lines 1128-1130), this should be handled correctly
     let output = "worktree /path/to/repo1\nHEAD abc123\nbranch refs/heads/main\n\nworktree /path/to/repo2\nHEAD def456\nbranch refs/heads/dev";
-    let result = WorktreeInfo::parse_porcelain_list(output);
-
-    assert!(result.is_ok());
-    let worktrees = result.unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
 
     // Should have 2 worktrees - code handles this with "if let Some(wt) = current" at end
     assert_eq!(
@@ -36,7 +33,7 @@ fn test_parse_worktree_list_no_trailing_blank_line() {
 #[test]
 fn test_parse_worktree_list_multiple_worktrees() {
     let output = "worktree /path/to/main\nHEAD abc123\nbranch refs/heads/main\n\nworktree /path/to/feature\nHEAD def456\nbranch refs/heads/feature\ndetached\n\n";
-    let worktrees = WorktreeInfo::parse_porcelain_list(output).unwrap();
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
     let [main_wt, feature_wt]: [WorktreeInfo; 2] = worktrees.try_into().unwrap();
 
     assert_eq!(main_wt.branch, Some("main".to_string()));
@@ -47,22 +44,29 @@ fn test_parse_worktree_list_multiple_worktrees() {
 }
 
 #[rstest]
-#[case::missing_path("worktree\nHEAD abc123\n\n", "missing path")]
-#[case::head_missing_sha(
-    "worktree /path/to/repo\nHEAD\nbranch refs/heads/main\n\n",
-    "missing SHA"
-)]
-#[case::branch_missing_ref("worktree /path/to/repo\nHEAD abc123\nbranch\n\n", "missing ref")]
-fn test_parse_worktree_list_error_cases(#[case] input: &str, #[case] expected_message: &str) {
-    let result = WorktreeInfo::parse_porcelain_list(input);
-
-    assert!(result.is_err(), "Parsing should fail");
-    let err = result.unwrap_err();
-    let msg = err.to_string();
-    assert!(
-        msg.contains(expected_message),
-        "Error should mention {expected_message}, got: {msg}"
+#[case::missing_path("worktree\nHEAD abc123\n\n")]
+#[case::empty_path("worktree \nHEAD abc123\n\n")]
+#[case::head_missing_sha("worktree /path/to/repo\nHEAD\nbranch refs/heads/main\n\n")]
+#[case::branch_missing_ref("worktree /path/to/repo\nHEAD abc123\nbranch\n\n")]
+fn test_parse_worktree_list_skips_malformed_entry(#[case] malformed: &str) {
+    // A malformed entry is dropped without losing its neighbours
+    let output = format!(
+        "worktree /path/to/main\nHEAD aaa\nbranch refs/heads/main\n\n{malformed}worktree /path/to/next\nHEAD bbb\n\n"
     );
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
+    let paths: Vec<_> = worktrees
+        .iter()
+        .map(|wt| wt.path.to_str().unwrap())
+        .collect();
+    assert_eq!(paths, ["/path/to/main", "/path/to/next"]);
+}
+
+#[test]
+fn test_parse_worktree_list_entries_without_blank_separator() {
+    let output = "worktree /a\nHEAD aaa\nworktree /b\nHEAD bbb\n";
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output.as_bytes());
+    let [a, b]: [WorktreeInfo; 2] = worktrees.try_into().unwrap();
+    assert_eq!((a.head.as_str(), b.head.as_str()), ("aaa", "bbb"));
 }
 
 // Tests for parse_remote_default_branch
@@ -202,7 +206,7 @@ fn test_line_diff_from_numstat(
 
 #[test]
 fn snapshot_parse_worktree_list_empty_output() {
-    let result = WorktreeInfo::parse_porcelain_list("").expect("parse ok");
+    let result = WorktreeInfo::parse_porcelain_list_bytes(b"");
     assert_debug_snapshot!(result, @"[]");
 }
 
@@ -216,6 +220,7 @@ fn snapshot_parse_worktree_list_missing_head() {
         branch: Some(
             "main",
         ),
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: None,
@@ -235,6 +240,7 @@ fn snapshot_parse_worktree_list_locked_with_empty_reason() {
         branch: Some(
             "main",
         ),
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: Some(
@@ -257,6 +263,7 @@ fn snapshot_parse_worktree_list_locked_with_reason() {
         branch: Some(
             "main",
         ),
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: Some(
@@ -278,6 +285,7 @@ fn snapshot_parse_worktree_list_prunable_empty() {
         branch: Some(
             "main",
         ),
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: None,
@@ -298,6 +306,7 @@ fn snapshot_parse_worktree_list_fields_before_worktree() {
         path: "/path/to/repo",
         head: "def456",
         branch: None,
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: None,
@@ -314,6 +323,7 @@ fn snapshot_parse_worktree_list_bare_repository() {
         path: "/path/to/repo",
         head: "",
         branch: None,
+        branch_raw: None,
         bare: true,
         detached: false,
         locked: None,
@@ -330,6 +340,7 @@ fn snapshot_parse_worktree_list_detached_head() {
         path: "/path/to/repo",
         head: "abc123",
         branch: None,
+        branch_raw: None,
         bare: false,
         detached: true,
         locked: None,
@@ -350,6 +361,7 @@ fn snapshot_parse_worktree_list_branch_with_refs_prefix() {
         branch: Some(
             "feature/nested/branch",
         ),
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: None,
@@ -368,6 +380,7 @@ fn snapshot_parse_worktree_list_branch_without_refs_prefix() {
         branch: Some(
             "main",
         ),
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: None,
@@ -384,6 +397,7 @@ fn snapshot_parse_worktree_list_unknown_attributes() {
         path: "/path/to/repo",
         head: "abc123",
         branch: None,
+        branch_raw: None,
         bare: false,
         detached: false,
         locked: None,
@@ -391,3 +405,115 @@ fn snapshot_parse_worktree_list_unknown_attributes() {
     }
     "#);
 }
+
+#[test]
+fn test_parse_worktree_list_non_utf8_branch_and_path() {
+    let output = b"worktree /repos/caf\xe9\nHEAD abc123\nbranch refs/heads/caf\xe9\n\nworktree /repos/main\nHEAD def456\nbranch refs/heads/main\n\n";
+    let worktrees = WorktreeInfo::parse_porcelain_list_bytes(output);
+    let [cafe, main]: [WorktreeInfo; 2] = worktrees.try_into().unwrap();
+
+    // The name is shown lossily, with the original bytes kept alongside
+    assert_eq!(cafe.branch.as_deref(), Some("caf\u{FFFD}"));
+    assert_eq!(cafe.branch_raw.as_deref(), Some(&b"caf\xe9"[..]));
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        assert_eq!(cafe.path.as_os_str().as_bytes(), b"/repos/caf\xe9");
+    }
+
+    assert_eq!(main.branch.as_deref(), Some("main"));
+    assert_eq!(main.branch_raw, None);
+}
+
+/// Read every sample in `tests/fixtures/worktree-porcelain`.
+///
+/// The samples are hand-written in the format `git worktree list --porcelain`
+/// documents, each covering one shape of output (bare, locked with a quoted
+/// reason, prunable, unborn, CRLF, ...) rather than captured from a specific
+/// git version.
+fn porcelain_corpus() -> Vec<(String, Vec<u8>)> {
+    let dir =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/worktree-porcelain");
+    let mut samples: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            (name, std::fs::read(&path).unwrap())
+        })
+        .collect();
+    samples.sort();
+    samples
+}
+
+#[test]
+fn snapshot_parse_worktree_list_corpus() {
+    // One sample per shape of porcelain output; a change to any of these
+    // snapshots is a change to how that shape is understood
+    let corpus = porcelain_corpus();
+    assert!(corpus.len() >= 8, "corpus went missing");
+    for (name, output) in corpus {
+        let worktrees = WorktreeInfo::parse_porcelain_list_bytes(&output);
+        insta::with_settings!({ snapshot_suffix => name.clone() }, {
+            assert_debug_snapshot!("worktree_porcelain", worktrees);
+        });
+    }
+}
+
+#[test]
+fn test_parse_worktree_list_mutations_never_panic() {
+    // Small deterministic fuzzer: damage each corpus sample in random ways
+    // and check the parser neither panics nor invents entries
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move |bound: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % bound.max(1) as u64) as usize
+    };
+    const INSERTS: &[&[u8]] = &[
+        b"\n",
+        b"\r",
+        b"\r\n",
+        b" ",
+        b"\n\n",
+        b"worktree",
+        b"HEAD",
+        b"branch",
+        b"locked",
+        b"\xff\xfe",
+        b"\0",
+    ];
+
+    for (name, sample) in porcelain_corpus() {
+        for round in 0..500 {
+            let mut output = sample.clone();
+            for _ in 0..=next(4) {
+                let at = next(output.len() + 1);
+                match next(4) {
+                    0 => output.truncate(at),
+                    1 if at < output.len() => {
+                        output.remove(at);
+                    }
+                    2 => {
+                        let insert = INSERTS[next(INSERTS.len())];
+                        output.splice(at..at, insert.iter().copied());
+                    }
+                    _ if at < output.len() => output[at] = next(256) as u8,
+                    _ => {}
+                }
+            }
+
+            let worktrees = WorktreeInfo::parse_porcelain_list_bytes(&output);
+            let entries = output
+                .split(|&b| b == b'\n')
+                .filter(|line| line.starts_with(b"worktree"))
+                .count();
+            assert!(
+                worktrees.len() <= entries,
+                "{name} round {round}: {} worktrees from {entries} entries",
+                worktrees.len()
+            );
+        }
+    }
+}
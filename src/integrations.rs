@@ -0,0 +1,122 @@
+//! Telling other tools about worktree paths (`[integrations]`).
+//!
+//! With `zoxide = true`, `wt switch` runs `zoxide add` for the worktree it
+//! lands in and `wt remove` runs `zoxide remove`, so freshly created
+//! worktrees are jump targets straight away. `on-path-created` and
+//! `on-path-removed` run a command template on the same events for any other
+//! tool.
+//!
+//! The worktree operation has already succeeded by the time these run, so
+//! every failure is a warning and never changes the exit code.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Stdio;
+
+use color_print::cformat;
+
+use crate::config::{IntegrationsConfig, expand_template};
+use crate::git::Repository;
+use crate::path::to_posix_path;
+use crate::shell_exec::Cmd;
+use crate::styling::{eprintln, warning_message};
+
+/// What happened to a worktree path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathEvent {
+    /// `wt switch` created the worktree
+    Created,
+    /// `wt switch` moved to an existing worktree
+    Switched,
+    /// `wt remove` removed the worktree
+    Removed,
+}
+
+/// Tell the configured tools about `path`.
+///
+/// Commands run in `dir`, which must exist; for [`PathEvent::Removed`] that's
+/// somewhere other than the removed worktree.
+pub fn notify(
+    repo: &Repository,
+    config: Option<&IntegrationsConfig>,
+    event: PathEvent,
+    path: &Path,
+    branch: &str,
+    dir: &Path,
+) {
+    let Some(config) = config else {
+        return;
+    };
+
+    if config.zoxide() && which::which("zoxide").is_ok() {
+        let action = match event {
+            PathEvent::Created | PathEvent::Switched => "add",
+            PathEvent::Removed => "remove",
+        };
+        run_zoxide(action, path, dir);
+    }
+
+    let (name, template) = match event {
+        PathEvent::Created => ("on-path-created", &config.on_path_created),
+        PathEvent::Removed => ("on-path-removed", &config.on_path_removed),
+        PathEvent::Switched => return,
+    };
+    if let Some(template) = template {
+        run_template(repo, name, template, path, branch, dir);
+    }
+}
+
+fn run_zoxide(action: &str, path: &Path, dir: &Path) {
+    let path = path.to_string_lossy();
+    let result = Cmd::new("zoxide")
+        .args([action, "--", path.as_ref()])
+        .current_dir(dir)
+        .run();
+    let error = match result {
+        Ok(output) if output.status.success() => return,
+        Ok(output) => String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        Err(err) => err.to_string(),
+    };
+    eprintln!(
+        "{}",
+        warning_message(cformat!("<bold>zoxide {action}</> failed: {error}"))
+    );
+}
+
+fn run_template(
+    repo: &Repository,
+    name: &str,
+    template: &str,
+    path: &Path,
+    branch: &str,
+    dir: &Path,
+) {
+    let worktree_path = to_posix_path(&path.to_string_lossy());
+    let repo_name = repo
+        .repo_path()
+        .ok()
+        .and_then(|root| root.file_name())
+        .and_then(|name| name.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+    let vars = HashMap::from([
+        ("worktree_path", worktree_path.as_str()),
+        ("branch", branch),
+        ("repo", repo_name.as_str()),
+    ]);
+
+    let result = expand_template(template, &vars, true, repo, name)
+        .map_err(anyhow::Error::from)
+        .and_then(|command| {
+            Cmd::shell(command)
+                .current_dir(dir)
+                .stdout(Stdio::from(std::io::stderr()))
+                .stream()
+        });
+    if let Err(err) = result {
+        eprintln!(
+            "{}",
+            warning_message(cformat!("<bold>{name}</> command failed: {err}"))
+        );
+    }
+}
@@ -7,14 +7,21 @@
 //! with worktrunk, please [open an issue](https://github.com/max-sixty/worktrunk/issues)
 //! to discuss your use case.
 
+pub mod activity;
 pub mod command_log;
 pub mod config;
+pub mod duration;
+pub mod fs_ops;
 pub mod git;
+pub mod integrations;
 pub mod path;
+pub mod scratch;
 pub mod shell;
 pub mod shell_exec;
 pub mod styling;
 pub mod sync;
+#[cfg(feature = "test-utils")]
+pub mod testing;
 pub mod trace;
 pub mod utils;
 
@@ -5,7 +5,7 @@ use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use worktrunk::config::CommitGenerationConfig;
-use worktrunk::git::Repository;
+use worktrunk::git::WorkingTree;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell_exec::{Cmd, ShellConfig};
 use worktrunk::styling::{eprintln, warning_message};
@@ -308,6 +308,7 @@ pub(crate) fn execute_llm_command(command: &str, prompt: &str) -> anyhow::Result
         .args(&shell.args)
         .arg(command)
         .external("commit.generation")
+        .network()
         .stdin_bytes(prompt)
         .env_remove("CLAUDECODE")
         .run()
@@ -463,13 +464,15 @@ fn build_prompt(
 }
 
 pub(crate) fn generate_commit_message(
+    wt: &WorkingTree<'_>,
     commit_generation_config: &CommitGenerationConfig,
 ) -> anyhow::Result<String> {
-    // Check if commit generation is configured (non-empty command)
-    if commit_generation_config.is_configured() {
+    // Check if commit generation is configured (non-empty command); offline,
+    // fall through to the deterministic message rather than failing
+    if commit_generation_config.is_configured() && !worktrunk::utils::is_offline() {
         let command = commit_generation_config.command.as_ref().unwrap();
         // Commit generation is explicitly configured - fail if it doesn't work
-        return try_generate_commit_message(command, commit_generation_config).map_err(|e| {
+        return try_generate_commit_message(wt, command, commit_generation_config).map_err(|e| {
             worktrunk::git::GitError::LlmCommandFailed {
                 command: command.clone(),
                 error: e.to_string(),
@@ -483,9 +486,8 @@ pub(crate) fn generate_commit_message(
     }
 
     // Fallback: generate a descriptive commit message based on changed files
-    let repo = Repository::current()?;
     // Use -z for NUL-separated output to handle filenames with spaces/newlines
-    let file_list = repo.run_command(&["diff", "--staged", "--name-only", "-z"])?;
+    let file_list = wt.run_command(&["diff", "--staged", "--name-only", "-z"])?;
     let staged_files = file_list
         .split('\0')
         .map(|s| s.trim())
@@ -513,10 +515,11 @@ pub(crate) fn generate_commit_message(
 }
 
 fn try_generate_commit_message(
+    wt: &WorkingTree<'_>,
     command: &str,
     config: &CommitGenerationConfig,
 ) -> anyhow::Result<String> {
-    let prompt = build_commit_prompt(config)?;
+    let prompt = build_commit_prompt(wt, config)?;
     execute_llm_command(command, &prompt)
 }
 
@@ -524,13 +527,14 @@ fn try_generate_commit_message(
 ///
 /// Gathers the staged diff, branch name, repo name, and recent commits, then renders
 /// the prompt template. Used by both normal commit generation and `--show-prompt`.
-pub(crate) fn build_commit_prompt(config: &CommitGenerationConfig) -> anyhow::Result<String> {
-    let repo = Repository::current()?;
-
+pub(crate) fn build_commit_prompt(
+    wt: &WorkingTree<'_>,
+    config: &CommitGenerationConfig,
+) -> anyhow::Result<String> {
     // Get staged diff and diffstat
     // Use -c flags to ensure consistent format regardless of user's git config
     // (diff.noprefix, diff.mnemonicPrefix, etc. could break our parsing)
-    let diff_output = repo.run_command(&[
+    let diff_output = wt.run_command(&[
         "-c",
         "diff.noprefix=false",
         "-c",
@@ -539,13 +543,12 @@ pub(crate) fn build_commit_prompt(config: &CommitGenerationConfig) -> anyhow::Re
         "diff",
         "--staged",
     ])?;
-    let diff_stat = repo.run_command(&["--no-pager", "diff", "--staged", "--stat"])?;
+    let diff_stat = wt.run_command(&["--no-pager", "diff", "--staged", "--stat"])?;
 
     // Prepare diff (may filter if too large)
     let prepared = prepare_diff(diff_output, diff_stat);
 
     // Get current branch and repo root
-    let wt = repo.current_worktree();
     let current_branch = wt.branch()?.unwrap_or_else(|| "HEAD".to_string());
     let repo_root = wt.root()?;
     let repo_name = repo_root
@@ -553,7 +556,7 @@ pub(crate) fn build_commit_prompt(config: &CommitGenerationConfig) -> anyhow::Re
         .and_then(|n| n.to_str())
         .unwrap_or("repo");
 
-    let recent_commits = repo.recent_commit_subjects(None, 5);
+    let recent_commits = wt.repo().recent_commit_subjects(None, 5);
 
     let context = TemplateContext {
         git_diff: &prepared.diff,
@@ -568,6 +571,7 @@ pub(crate) fn build_commit_prompt(config: &CommitGenerationConfig) -> anyhow::Re
 }
 
 pub(crate) fn generate_squash_message(
+    wt: &WorkingTree<'_>,
     target_branch: &str,
     merge_base: &str,
     subjects: &[String],
@@ -575,11 +579,13 @@ pub(crate) fn generate_squash_message(
     repo_name: &str,
     commit_generation_config: &CommitGenerationConfig,
 ) -> anyhow::Result<String> {
-    // Check if commit generation is configured (non-empty command)
-    if commit_generation_config.is_configured() {
+    // Check if commit generation is configured (non-empty command); offline,
+    // fall through to the deterministic message rather than failing
+    if commit_generation_config.is_configured() && !worktrunk::utils::is_offline() {
         let command = commit_generation_config.command.as_ref().unwrap();
 
         let prompt = build_squash_prompt(
+            wt,
             target_branch,
             merge_base,
             subjects,
@@ -616,6 +622,7 @@ pub(crate) fn generate_squash_message(
 /// Gathers the combined diff, commit subjects, branch names, and recent commits, then
 /// renders the prompt template. Used by both normal squash generation and `--show-prompt`.
 pub(crate) fn build_squash_prompt(
+    wt: &WorkingTree<'_>,
     target_branch: &str,
     merge_base: &str,
     subjects: &[String],
@@ -623,11 +630,9 @@ pub(crate) fn build_squash_prompt(
     repo_name: &str,
     config: &CommitGenerationConfig,
 ) -> anyhow::Result<String> {
-    let repo = Repository::current()?;
-
     // Get the combined diff and diffstat for all commits being squashed
     // Use -c flags to ensure consistent format regardless of user's git config
-    let diff_output = repo.run_command(&[
+    let diff_output = wt.run_command(&[
         "-c",
         "diff.noprefix=false",
         "-c",
@@ -637,12 +642,12 @@ pub(crate) fn build_squash_prompt(
         merge_base,
         "HEAD",
     ])?;
-    let diff_stat = repo.run_command(&["--no-pager", "diff", merge_base, "HEAD", "--stat"])?;
+    let diff_stat = wt.run_command(&["--no-pager", "diff", merge_base, "HEAD", "--stat"])?;
 
     // Prepare diff (may filter if too large)
     let prepared = prepare_diff(diff_output, diff_stat);
 
-    let recent_commits = repo.recent_commit_subjects(Some(merge_base), 5);
+    let recent_commits = wt.repo().recent_commit_subjects(Some(merge_base), 5);
     let context = TemplateContext {
         git_diff: &prepared.diff,
         git_diff_stat: &prepared.stat,
@@ -3,17 +3,20 @@ use std::io::Write;
 
 use anyhow::Context;
 use clap::FromArgMatches;
-use clap::error::ErrorKind as ClapErrorKind;
+use clap::error::{ContextKind, ErrorKind as ClapErrorKind};
 use color_print::{ceprintln, cformat};
 use std::process;
-use worktrunk::config::{UserConfig, set_config_path};
+use worktrunk::config::{ListDensity, UserConfig, set_config_path};
 use worktrunk::git::{
-    Repository, ResolvedWorktree, current_or_recover, cwd_removed_hint, exit_code, set_base_path,
+    Repository, ResolvedWorktree, current_or_recover, cwd_removed_hint, error_json, exit_code,
+    set_base_path,
 };
 use worktrunk::styling::{
-    eprintln, error_message, format_with_gutter, hint_message, info_message, warning_message,
+    eprintln, error_message, format_with_gutter, hint_message, info_message, println,
+    warning_message,
 };
 
+use commands::branch_deletion::{RemoteBranch, confirm_remote_deletion};
 use commands::command_approval::approve_hooks;
 use commands::context::CommandEnv;
 use commands::list::progressive::RenderMode;
@@ -24,6 +27,7 @@ mod commands;
 mod completion;
 mod diagnostic;
 mod display;
+mod first_run;
 mod help;
 pub(crate) mod help_pager;
 mod invocation;
@@ -32,6 +36,8 @@ mod md_help;
 mod output;
 mod pager;
 mod summary;
+mod update_check;
+mod usage_journal;
 mod verbose_log;
 
 // Re-export invocation utilities at crate level for use by other modules
@@ -45,21 +51,27 @@ pub(crate) use crate::cli::OutputFormat;
 use commands::handle_select;
 use commands::worktree::handle_push;
 use commands::{
-    MergeOptions, OperationMode, RebaseResult, SquashResult, SwitchOptions, add_approvals,
-    clear_approvals, handle_completions, handle_config_create, handle_config_show,
-    handle_config_update, handle_configure_shell, handle_hints_clear, handle_hints_get,
-    handle_hook_show, handle_init, handle_list, handle_logs_get, handle_merge, handle_promote,
-    handle_rebase, handle_remove, handle_remove_current, handle_show_theme, handle_squash,
+    ListOptions, MergeOptions, MergeTag, OperationMode, RebaseResult, SquashResult, SwitchOptions,
+    add_approvals, clear_approvals, handle_archive, handle_archive_list, handle_archive_restore,
+    handle_branches_clean, handle_clone, handle_completions, handle_config_create,
+    handle_config_migrate, handle_config_schema, handle_config_show, handle_config_update,
+    handle_config_validate, handle_configure_shell, handle_diff, handle_events, handle_hide,
+    handle_hints_clear, handle_hints_get, handle_hook_show, handle_init, handle_legend,
+    handle_list, handle_logs_get, handle_merge, handle_parent_clear, handle_parent_get,
+    handle_parent_set, handle_promote, handle_rebase, handle_remove, handle_remove_current,
+    handle_repair_default_branch, handle_report, handle_show_theme, handle_squash,
     handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
-    handle_state_show, handle_switch, handle_unconfigure_shell, resolve_worktree_arg, run_hook,
-    step_commit, step_copy_ignored, step_diff, step_for_each, step_prune, step_relocate,
+    handle_state_show, handle_stats, handle_switch, handle_unconfigure_shell, handle_unhide,
+    handle_which, resolve_worktree_arg, run_hook, step_commit, step_copy_ignored, step_diff,
+    step_for_each, step_graduate, step_optimize, step_prune, step_relocate,
 };
 use output::handle_remove_output;
 
 use cli::{
-    ApprovalsCommand, CiStatusAction, Cli, Commands, ConfigCommand, ConfigShellCommand,
-    DefaultBranchAction, HintsAction, HookCommand, ListSubcommand, LogsAction, MarkerAction,
-    PreviousBranchAction, StateCommand, StepCommand,
+    ApprovalsCommand, ArchiveCommand, BranchDiffBase, BranchesCommand, CiStatusAction, Cli,
+    Commands, ConfigCommand, ConfigShellCommand, DefaultBranchAction, HintsAction, HookCommand,
+    ListSubcommand, LogsAction, MarkerAction, ParentCommand, PreviousBranchAction, StateCommand,
+    StepCommand,
 };
 use worktrunk::HookType;
 
@@ -70,14 +82,22 @@ fn enhance_and_exit_error(err: clap::Error) -> ! {
     // For unrecognized subcommands, check if they match a nested subcommand
     // e.g., `wt squash` -> suggest `wt step squash`
     if err.kind() == ClapErrorKind::InvalidSubcommand
-        && let Some(unknown) = err.get(clap::error::ContextKind::InvalidSubcommand)
+        && let Some(unknown) = err.get(ContextKind::InvalidSubcommand)
     {
         let cmd = cli::build_command();
         if let Some(suggestion) = cli::suggest_nested_subcommand(&cmd, &unknown.to_string()) {
+            // Clap's own fuzzy tip would name whichever top-level command is
+            // spelled closest (`wt squash` → `stats`), so render without it
+            let mut plain = clap::Error::new(ClapErrorKind::InvalidSubcommand).with_cmd(&cmd);
+            for kind in [ContextKind::InvalidSubcommand, ContextKind::Usage] {
+                if let Some(value) = err.get(kind) {
+                    plain.insert(kind, value.clone());
+                }
+            }
             ceprintln!(
                 "{}
   <yellow>tip:</>  perhaps <cyan,bold>{suggestion}</cyan,bold>?",
-                err.render().ansi()
+                plain.render().ansi()
             );
             process::exit(2);
         }
@@ -248,28 +268,32 @@ fn handle_step_command(action: StepCommand) -> anyhow::Result<()> {
                 commands::step_show_squash_prompt(target.as_deref())
             } else {
                 // Approval is handled inside handle_squash (like step_commit)
-                handle_squash(target.as_deref(), yes, verify, stage).map(|result| match result {
-                    SquashResult::Squashed | SquashResult::NoNetChanges => {}
-                    SquashResult::NoCommitsAhead(branch) => {
-                        eprintln!(
-                            "{}",
-                            info_message(format!(
-                                "Nothing to squash; no commits ahead of {branch}"
-                            ))
-                        );
-                    }
-                    SquashResult::AlreadySingleCommit => {
-                        eprintln!(
-                            "{}",
-                            info_message("Nothing to squash; already a single commit")
-                        );
-                    }
-                })
+                Repository::current()
+                    .and_then(|repo| handle_squash(&repo, target.as_deref(), yes, verify, stage))
+                    .map(|result| match result {
+                        SquashResult::Squashed | SquashResult::NoNetChanges => {}
+                        SquashResult::NoCommitsAhead(branch) => {
+                            eprintln!(
+                                "{}",
+                                info_message(format!(
+                                    "Nothing to squash; no commits ahead of {branch}"
+                                ))
+                            );
+                        }
+                        SquashResult::AlreadySingleCommit => {
+                            eprintln!(
+                                "{}",
+                                info_message("Nothing to squash; already a single commit")
+                            );
+                        }
+                    })
             }
         }
-        StepCommand::Push { target } => handle_push(target.as_deref(), "Pushed to", None),
-        StepCommand::Rebase { target } => {
-            handle_rebase(target.as_deref()).map(|result| match result {
+        StepCommand::Push { target } => Repository::current()
+            .and_then(|repo| handle_push(&repo, target.as_deref(), "Pushed to", None)),
+        StepCommand::Rebase { target } => Repository::current()
+            .and_then(|repo| handle_rebase(&repo, target.as_deref()))
+            .map(|result| match result {
                 RebaseResult::Rebased => (),
                 RebaseResult::UpToDate(branch) => {
                     eprintln!(
@@ -277,8 +301,7 @@ fn handle_step_command(action: StepCommand) -> anyhow::Result<()> {
                         info_message(cformat!("Already up to date with <bold>{branch}</>"))
                     );
                 }
-            })
-        }
+            }),
         StepCommand::Diff { target, extra_args } => step_diff(target.as_deref(), &extra_args),
         StepCommand::CopyIgnored {
             from,
@@ -286,7 +309,10 @@ fn handle_step_command(action: StepCommand) -> anyhow::Result<()> {
             dry_run,
             force,
         } => step_copy_ignored(from.as_deref(), to.as_deref(), dry_run, force),
-        StepCommand::ForEach { args } => step_for_each(args),
+        StepCommand::ForEach {
+            args,
+            include_locked,
+        } => step_for_each(args, include_locked),
         StepCommand::Promote { branch } => {
             handle_promote(branch.as_deref()).map(|result| match result {
                 commands::PromoteResult::Promoted => (),
@@ -300,18 +326,37 @@ fn handle_step_command(action: StepCommand) -> anyhow::Result<()> {
                 }
             })
         }
+        StepCommand::Graduate {
+            branch,
+            no_move,
+            push,
+            fix_name,
+            no_name_rules,
+        } => step_graduate(&branch, no_move, push, fix_name, no_name_rules),
         StepCommand::Prune {
             dry_run,
             yes,
             min_age,
+            squash_aware,
             foreground,
-        } => step_prune(dry_run, yes, &min_age, foreground),
+            restack,
+            include_hidden,
+        } => step_prune(
+            dry_run,
+            yes,
+            &min_age,
+            squash_aware,
+            foreground,
+            restack,
+            include_hidden,
+        ),
         StepCommand::Relocate {
             branches,
             dry_run,
             commit,
             clobber,
         } => step_relocate(branches, dry_run, commit, clobber),
+        StepCommand::Optimize { yes } => step_optimize(yes),
         StepCommand::External(args) => {
             commands::AliasOptions::parse(args).and_then(commands::step_alias)
         }
@@ -421,22 +466,44 @@ fn handle_config_command(action: ConfigCommand) -> anyhow::Result<()> {
     match action {
         ConfigCommand::Shell { action } => handle_config_shell_command(action),
         ConfigCommand::Create { project } => handle_config_create(project),
-        ConfigCommand::Show { full } => handle_config_show(full),
+        ConfigCommand::Show { full, for_branch } => handle_config_show(full, for_branch.as_deref()),
         ConfigCommand::Update { yes } => handle_config_update(yes),
+        ConfigCommand::Migrate => handle_config_migrate(),
+        ConfigCommand::Validate { strict } => handle_config_validate(strict),
+        ConfigCommand::Schema { project } => handle_config_schema(project),
         ConfigCommand::State { action } => handle_state_command(action),
     }
 }
 
-fn handle_list_command(
+struct ListCommandArgs {
     subcommand: Option<ListSubcommand>,
-    format: OutputFormat,
+    format: cli::ListFormat,
     branches: bool,
     remotes: bool,
     full: bool,
-    progressive: bool,
-    no_progressive: bool,
-) -> anyhow::Result<()> {
-    match subcommand {
+    include_skipped: bool,
+    branch_diff_base: BranchDiffBase,
+    full_diffs: bool,
+    summary: bool,
+    against: Option<String>,
+    hidden: bool,
+    since: Option<String>,
+    until: Option<String>,
+    timeout: Option<String>,
+    current: bool,
+    no_align: bool,
+    long: bool,
+    ci_details: bool,
+    ascii: bool,
+    density: Option<ListDensity>,
+    no_pager: bool,
+    max_rows: Option<usize>,
+    output: Option<std::path::PathBuf>,
+    progressive: Option<bool>,
+}
+
+fn handle_list_command(spec: ListCommandArgs) -> anyhow::Result<()> {
+    match spec.subcommand {
         Some(ListSubcommand::Statusline {
             format,
             claude_code,
@@ -452,8 +519,34 @@ fn handle_list_command(
         }
         None => {
             let (repo, _recovered) = current_or_recover()?;
-            let render_mode = RenderMode::detect(flag_pair(progressive, no_progressive));
-            handle_list(repo, format, branches, remotes, full, render_mode)
+            handle_list(
+                repo,
+                ListOptions {
+                    format: spec.format,
+                    branches: spec.branches,
+                    remotes: spec.remotes,
+                    full: spec.full,
+                    include_skipped: spec.include_skipped,
+                    branch_diff_base: spec.branch_diff_base,
+                    full_diffs: spec.full_diffs,
+                    summary: spec.summary,
+                    against: spec.against,
+                    hidden: spec.hidden,
+                    since: spec.since,
+                    until: spec.until,
+                    timeout: spec.timeout,
+                    current: spec.current,
+                    no_align: spec.no_align,
+                    long: spec.long,
+                    ci_details: spec.ci_details,
+                    ascii: spec.ascii,
+                    density: spec.density,
+                    no_pager: spec.no_pager,
+                    max_rows: spec.max_rows,
+                    output: spec.output,
+                    render_mode: RenderMode::detect(spec.progressive),
+                },
+            )
         }
     }
 }
@@ -479,10 +572,21 @@ struct SwitchCommandArgs {
     remotes: bool,
     create: bool,
     base: Option<String>,
-    execute: Option<String>,
+    orphan: bool,
+    fix_name: bool,
+    no_name_rules: bool,
+    branch_from_tag: bool,
+    execute: Vec<String>,
     execute_args: Vec<String>,
+    keep_going: bool,
+    no_exec: bool,
+    profile: Option<String>,
     yes: bool,
     clobber: bool,
+    new_path: Option<std::path::PathBuf>,
+    detach: bool,
+    force: bool,
+    allow_any_path: bool,
     no_cd: bool,
     verify: bool,
 }
@@ -514,10 +618,21 @@ fn handle_switch_command(spec: SwitchCommandArgs) -> anyhow::Result<()> {
                     branch: &branch,
                     create: spec.create,
                     base: spec.base.as_deref(),
-                    execute: spec.execute.as_deref(),
+                    orphan: spec.orphan,
+                    fix_name: spec.fix_name,
+                    no_name_rules: spec.no_name_rules,
+                    branch_from_tag: spec.branch_from_tag,
+                    execute: &spec.execute,
                     execute_args: &spec.execute_args,
+                    keep_going: spec.keep_going,
+                    no_exec: spec.no_exec,
+                    profile: spec.profile.as_deref(),
                     yes: spec.yes,
                     clobber: spec.clobber,
+                    new_path: spec.new_path.as_deref(),
+                    detach: spec.detach,
+                    force: spec.force,
+                    allow_any_path: spec.allow_any_path,
                     change_dir: !spec.no_cd,
                     verify: spec.verify,
                 },
@@ -535,6 +650,30 @@ struct RemoveCommandArgs {
     verify: bool,
     yes: bool,
     force: bool,
+    delete_remote: bool,
+}
+
+/// Look up the upstreams `--delete-remote` would delete, and ask about them
+/// at the gate, before anything is removed.
+fn plan_remote_deletions(
+    repo: &Repository,
+    plans: &mut [&mut RemoveResult],
+    yes: bool,
+) -> anyhow::Result<()> {
+    for plan in plans.iter_mut() {
+        plan.add_remote_branch(repo)?;
+    }
+    let remote_branches: Vec<&RemoteBranch> = plans
+        .iter()
+        .filter_map(|plan| plan.remote_branch())
+        .collect();
+    if remote_branches.is_empty() || yes || confirm_remote_deletion(&remote_branches)? {
+        return Ok(());
+    }
+    for plan in plans.iter_mut() {
+        plan.keep_remote_branch();
+    }
+    Ok(())
 }
 
 fn handle_remove_command(spec: RemoveCommandArgs) -> anyhow::Result<()> {
@@ -548,6 +687,12 @@ fn handle_remove_command(spec: RemoveCommandArgs) -> anyhow::Result<()> {
                 }
                 .into());
             }
+            if !spec.delete_branch && spec.delete_remote {
+                return Err(worktrunk::git::GitError::Other {
+                    message: "Cannot use --delete-remote with --no-delete-branch".into(),
+                }
+                .into());
+            }
 
             let repo = Repository::current().context("Failed to remove worktree")?;
 
@@ -574,7 +719,7 @@ fn handle_remove_command(spec: RemoveCommandArgs) -> anyhow::Result<()> {
 
             if branches.is_empty() {
                 // Single worktree removal: validate FIRST, then approve, then execute
-                let result = handle_remove_current(
+                let mut result = handle_remove_current(
                     !spec.delete_branch,
                     spec.force_delete,
                     spec.force,
@@ -589,6 +734,9 @@ fn handle_remove_command(spec: RemoveCommandArgs) -> anyhow::Result<()> {
 
                 // "Approve at the Gate": approval happens AFTER validation passes
                 let run_hooks = spec.verify && approve_remove(spec.yes)?;
+                if spec.delete_remote {
+                    plan_remote_deletions(&repo, &mut [&mut result], spec.yes)?;
+                }
 
                 handle_remove_output(&result, spec.foreground, run_hooks, false)
             } else {
@@ -705,6 +853,14 @@ fn handle_remove_command(spec: RemoveCommandArgs) -> anyhow::Result<()> {
                 // TODO(pre-remove-context): Approval context uses current worktree,
                 // but hooks execute in each target worktree.
                 let run_hooks = spec.verify && approve_remove(spec.yes)?;
+                if spec.delete_remote {
+                    let mut plans: Vec<&mut RemoveResult> = plans_others
+                        .iter_mut()
+                        .chain(plans_branch_only.iter_mut())
+                        .chain(plan_current.iter_mut())
+                        .collect();
+                    plan_remote_deletions(&repo, &mut plans, spec.yes)?;
+                }
 
                 // Phase 3: Execute all validated plans
                 // Remove other worktrees first
@@ -732,6 +888,25 @@ fn handle_remove_command(spec: RemoveCommandArgs) -> anyhow::Result<()> {
         })
 }
 
+/// Print `[alias]` entries as `name = expansion`, one per line.
+fn print_aliases() {
+    let aliases = UserConfig::load()
+        .map(|config| config.alias)
+        .unwrap_or_default();
+    if aliases.is_empty() {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "No aliases configured; add an <bright-black>[alias]</> section to the user config"
+            ))
+        );
+        return;
+    }
+    for (name, expansion) in &aliases {
+        println!("{name} = {expansion}");
+    }
+}
+
 fn main() {
     // Configure Rayon's global thread pool for mixed I/O workloads.
     // The `wt list` command runs git operations (CPU + disk I/O) and network
@@ -753,7 +928,13 @@ fn main() {
     // Tell crossterm to always emit ANSI sequences
     crossterm::style::force_color_output(true);
 
-    if completion::maybe_handle_env_completion() {
+    output::install_panic_hook();
+
+    if completion::maybe_handle_env_completion() || completion::maybe_handle_complete_subcommand() {
+        return;
+    }
+
+    if update_check::maybe_run_worker() {
         return;
     }
 
@@ -767,10 +948,16 @@ fn main() {
     // Clap doesn't support this natively yet - see https://github.com/clap-rs/clap/issues/3320
     // When available, use built-in setting. Until then, could use try_parse() to intercept
     // MissingRequiredArgument errors and print custom messages with ValueEnum::value_variants().
-    let cmd = cli::build_command();
-    let matches = cmd.try_get_matches().unwrap_or_else(|e| {
+    let args = cli::expand_config_args(std::env::args_os().collect()).unwrap_or_else(|e| {
+        eprintln!("{}", error_message(e.to_string()));
+        process::exit(2);
+    });
+    let mut cmd = cli::build_command();
+    let matches = cmd.try_get_matches_from_mut(args).unwrap_or_else(|e| {
         enhance_and_exit_error(e);
     });
+    let started = std::time::Instant::now();
+    let invocation = usage_journal::Invocation::from_matches(&cmd, &matches);
     let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     // Initialize base path from -C flag if provided
@@ -783,6 +970,16 @@ fn main() {
         set_config_path(path);
     }
 
+    if cli.deterministic {
+        worktrunk::utils::set_deterministic();
+    }
+
+    if cli.offline {
+        worktrunk::utils::set_offline();
+    }
+
+    let json_errors = cli.json_errors;
+
     // Configure logging based on --verbose flag or RUST_LOG env var
     // When -vv is set, also write logs to .git/wt-logs/verbose.log
     if cli.verbose >= 2 {
@@ -866,6 +1063,11 @@ fn main() {
         })
         .init();
 
+    if cli.list_aliases {
+        print_aliases();
+        return;
+    }
+
     let Some(command) = cli.command else {
         // No subcommand provided - print help to stderr (stdout is eval'd by shell wrapper)
         let mut cmd = cli::build_command();
@@ -885,27 +1087,73 @@ fn main() {
             branches,
             remotes,
             full,
+            include_skipped,
+            branch_diff_base,
+            full_diffs,
+            summary,
+            against,
+            hidden,
+            since,
+            until,
+            timeout,
+            current,
+            no_align,
+            long,
+            ci_details,
+            ascii,
+            density,
+            no_pager,
+            max_rows,
+            output,
             progressive,
             no_progressive,
-        } => handle_list_command(
+        } => handle_list_command(ListCommandArgs {
             subcommand,
             format,
             branches,
             remotes,
             full,
-            progressive,
-            no_progressive,
-        ),
+            include_skipped,
+            branch_diff_base,
+            full_diffs,
+            summary,
+            against,
+            hidden,
+            since,
+            until,
+            timeout,
+            current,
+            no_align,
+            long,
+            ci_details,
+            ascii,
+            density,
+            no_pager,
+            max_rows,
+            output,
+            progressive: flag_pair(progressive, no_progressive),
+        }),
         Commands::Switch {
             branch,
             branches,
             remotes,
             create,
             base,
+            orphan,
+            fix_name,
+            no_name_rules,
+            branch_from_tag,
             execute,
             execute_args,
+            keep_going,
+            no_exec,
+            profile,
             yes,
             clobber,
+            new_path,
+            detach,
+            force,
+            allow_any_path,
             no_cd,
             verify,
         } => handle_switch_command(SwitchCommandArgs {
@@ -914,10 +1162,21 @@ fn main() {
             remotes,
             create,
             base,
+            orphan,
+            fix_name,
+            no_name_rules,
+            branch_from_tag,
             execute,
             execute_args,
+            keep_going,
+            no_exec,
+            profile,
             yes,
             clobber,
+            new_path,
+            detach,
+            force,
+            allow_any_path,
             no_cd,
             verify,
         }),
@@ -929,6 +1188,7 @@ fn main() {
             verify,
             yes,
             force,
+            delete_remote,
         } => handle_remove_command(RemoveCommandArgs {
             branches,
             delete_branch,
@@ -937,9 +1197,64 @@ fn main() {
             verify,
             yes,
             force,
+            delete_remote,
         }),
+        Commands::Diff {
+            branch,
+            target,
+            working,
+            tool,
+            format,
+        } => handle_diff(branch.as_deref(), target.as_deref(), working, tool, format),
+        Commands::Events {
+            follow,
+            once: _,
+            interval,
+        } => handle_events(follow, &interval),
+        Commands::Which { path, format } => handle_which(path.as_deref(), format),
+        Commands::Stats { format } => handle_stats(format),
+        Commands::Report { format } => handle_report(format),
+        Commands::Legend => handle_legend(),
+        Commands::Hide { branches } => handle_hide(&branches),
+        Commands::Unhide { branches } => handle_unhide(&branches),
+        Commands::Parent { action, branch } => match action {
+            None => handle_parent_get(branch),
+            Some(ParentCommand::Get { branch }) => handle_parent_get(branch),
+            Some(ParentCommand::Set { parent, branch }) => handle_parent_set(&parent, branch),
+            Some(ParentCommand::Clear { branch }) => handle_parent_clear(branch),
+        },
+        Commands::Repair {
+            default_branch: _,
+            yes,
+        } => handle_repair_default_branch(yes),
+        Commands::Branches {
+            action:
+                BranchesCommand::Clean {
+                    dry_run,
+                    yes,
+                    squash_aware,
+                    delete_remote,
+                },
+        } => handle_branches_clean(dry_run, yes, squash_aware, delete_remote),
+        Commands::Clone {
+            url,
+            dir,
+            bare,
+            init,
+        } => handle_clone(&url, dir, bare, init),
+        Commands::Archive {
+            action,
+            branch,
+            uncommitted,
+            message,
+        } => match action {
+            None => handle_archive(branch, uncommitted, message),
+            Some(ArchiveCommand::List) => handle_archive_list(),
+            Some(ArchiveCommand::Restore { name, force }) => handle_archive_restore(&name, force),
+        },
         Commands::Merge {
             target,
+            branch,
             squash,
             no_squash,
             commit,
@@ -952,11 +1267,17 @@ fn main() {
             no_verify,
             yes,
             stage,
+            restack,
+            tag,
+            tag_message,
+            sign,
+            push_tag,
         } => {
             // Pass CLI flags as options; handle_merge determines effective defaults
             // using per-project config merged with global config
             handle_merge(MergeOptions {
                 target: target.as_deref(),
+                branch: branch.as_deref(),
                 squash: flag_pair(squash, no_squash),
                 commit: flag_pair(commit, no_commit),
                 rebase: flag_pair(rebase, no_rebase),
@@ -964,15 +1285,34 @@ fn main() {
                 verify: flag_pair(verify, no_verify),
                 yes,
                 stage,
+                restack,
+                tag: tag.as_deref().map(|name| MergeTag {
+                    name,
+                    message: tag_message.as_deref(),
+                    sign,
+                    push: push_tag,
+                }),
             })
         }
     };
+    let duration = started.elapsed();
 
     if let Err(e) = result {
+        // Preserve exit code from child processes (especially for signals like SIGINT)
+        let code = exit_code(&e).unwrap_or(1);
+
         // GitError, WorktrunkError, and HookErrorWithHint produce styled output via Display.
         // Some variants (AlreadyDisplayed, CommandNotApproved) have empty Display impls —
         // skip eprintln! for those to avoid phantom blank lines.
-        if let Some(err) = e.downcast_ref::<worktrunk::git::GitError>() {
+        if json_errors {
+            // One JSON object instead of the styled rendering; the exit code it reports
+            // is the one we exit with below. A hook's output can end mid-line (or be
+            // nothing but our reset code), so the JSON starts a line of its own.
+            if output::had_child_output() {
+                eprintln!();
+            }
+            eprintln!("{}", error_json(&e, code));
+        } else if let Some(err) = e.downcast_ref::<worktrunk::git::GitError>() {
             eprintln!("{}", err);
         } else if let Some(err) = e.downcast_ref::<worktrunk::git::WorktrunkError>() {
             let display = err.to_string();
@@ -1018,7 +1358,7 @@ fn main() {
         // worktree was removed (reliable on all platforms), and (2) OS-level detection
         // for cases not covered by the flag (e.g., external worktree removal).
         let cwd_gone = output::was_cwd_removed() || std::env::current_dir().is_err();
-        if cwd_gone {
+        if cwd_gone && !json_errors {
             if let Some(hint) = cwd_removed_hint() {
                 eprintln!("{}", hint_message(hint));
             } else {
@@ -1026,20 +1366,24 @@ fn main() {
             }
         }
 
-        // Preserve exit code from child processes (especially for signals like SIGINT)
-        let code = exit_code(&e).unwrap_or(1);
-
         // Write diagnostic if -vv was used (error case)
         diagnostic::write_if_verbose(verbose_level, &command_line, Some(&e.to_string()));
 
         // Reset ANSI state before exiting
         let _ = output::terminate_output();
+        usage_journal::record(&invocation, duration, false);
         process::exit(code);
     }
 
     // Write diagnostic if -vv was used (success case)
     diagnostic::write_if_verbose(verbose_level, &command_line, None);
 
+    // Daily release check (opt-in), after the command's own output
+    update_check::run_if_due();
+
     // Reset ANSI state before returning to shell (success case)
     let _ = output::terminate_output();
+
+    // Local usage journal (opt-in), once everything is written
+    usage_journal::record(&invocation, duration, true);
 }
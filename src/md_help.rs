@@ -359,6 +359,7 @@ fn colorize_status_symbols(text: &str) -> String {
     let progress = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::Blue)));
     let disabled = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::BrightBlack)));
     let working_tree = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::Cyan)));
+    let merged = Style::new().fg_color(Some(AnsiStyleColor::Ansi(AnsiColor::Magenta)));
 
     // Pattern for dimmed text (from inline `code` rendering)
     // render_inline_formatting wraps backticked text in dimmed style
@@ -414,6 +415,11 @@ fn colorize_status_symbols(text: &str) -> String {
             &format!("{dimmed_bullet} gray"),
             &format!("{disabled}●{disabled:#} gray"),
         )
+        // Merged PR indicator
+        .replace(
+            &format!("{dim}✔{dim:#} purple"),
+            &format!("{merged}✔{merged:#} purple"),
+        )
         // CI error indicator: ⚠ symbol (also rendered dimmed initially)
         .replace(
             &format!("{dim}⚠{dim:#} yellow"),
@@ -429,7 +435,7 @@ fn colorize_status_symbols(text: &str) -> String {
         .replace("● no-ci", &format!("{disabled}●{disabled:#} no-ci"));
 
     // Symbols that should remain dimmed are already dimmed from backtick rendering:
-    // - Main state: _ (same commit), ⊂ (content integrated), ^, ↑, ↓, ↕
+    // - Main state: _ (same commit), ⊂ (content integrated), ≈ (squash-merged), ^, ↑, ↓, ↕
     // - Upstream divergence: |, ⇡, ⇣, ⇅
     // - Worktree state: / (branch without worktree)
 
@@ -24,17 +24,16 @@ use std::os::unix::process::CommandExt;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 
-#[cfg(unix)]
 use color_print::cformat;
 use std::sync::{Mutex, OnceLock};
 
-#[cfg(not(unix))]
 use worktrunk::git::WorktrunkError;
 #[cfg(not(unix))]
 use worktrunk::shell_exec::Cmd;
 use worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR;
 #[cfg(unix)]
 use worktrunk::shell_exec::ShellConfig;
+use worktrunk::styling::{eprintln, warning_message};
 
 // Re-export set_verbosity from the library's styling module.
 // This ensures the binary and library share the same global state.
@@ -67,6 +66,9 @@ struct OutputState {
     /// This explicit flag avoids unreliable CWD detection on Windows where
     /// deleted directories remain accessible to the process that held them.
     cwd_removed: bool,
+    /// Set once a child command has run after our ANSI reset. A child that
+    /// prints nothing leaves stderr mid-line, right after the reset code.
+    child_output: bool,
 }
 
 /// Maps canonical path prefixes to logical (symlink-preserved) prefixes.
@@ -190,6 +192,7 @@ fn get_state() -> &'static Mutex<OutputState> {
             target_dir: None,
             symlink_mapping,
             cwd_removed: false,
+            child_output: false,
         })
     })
 }
@@ -205,6 +208,12 @@ fn has_directive_file() -> bool {
 
 /// Write a directive to the directive file (if set)
 fn write_directive(directive: &str) -> io::Result<()> {
+    write_directive_bytes(directive.as_bytes())
+}
+
+/// Write a directive, which may hold non-UTF-8 path bytes, to the directive
+/// file (if set)
+fn write_directive_bytes(directive: &[u8]) -> io::Result<()> {
     // Copy path out of lock to avoid holding mutex during I/O
     let path = {
         let guard = get_state().lock().expect("OUTPUT_STATE lock poisoned");
@@ -216,10 +225,71 @@ fn write_directive(directive: &str) -> io::Result<()> {
     };
 
     let mut file = OpenOptions::new().append(true).open(&path)?;
-    writeln!(file, "{}", directive)?;
+    file.write_all(directive)?;
+    file.write_all(b"\n")?;
     file.flush()
 }
 
+/// The `cd` directive for `path`, single-quoted for the shell.
+///
+/// Both shell families take single-quoted contents literally, but escape
+/// embedded quotes differently:
+/// - PowerShell: double the quote ('it''s')
+/// - POSIX (bash/zsh/fish): end quote, escaped quote, start quote ('it'\''s')
+///
+/// POSIX shells read the path's bytes as they are, so a non-UTF-8 path on Unix
+/// is written unchanged. `None` when the path can't be expressed for the shell.
+fn cd_directive(path: &Path, powershell: bool) -> Option<Vec<u8>> {
+    let (quote_escape, bytes): (&[u8], Vec<u8>) = if powershell {
+        (b"''", path.to_str()?.as_bytes().to_vec())
+    } else {
+        #[cfg(unix)]
+        let bytes = std::os::unix::ffi::OsStrExt::as_bytes(path.as_os_str()).to_vec();
+        #[cfg(not(unix))]
+        let bytes = path.to_str()?.as_bytes().to_vec();
+        (b"'\\''", bytes)
+    };
+    let mut directive = b"cd '".to_vec();
+    for &byte in &bytes {
+        if byte == b'\'' {
+            directive.extend_from_slice(quote_escape);
+        } else {
+            directive.push(byte);
+        }
+    }
+    directive.push(b'\'');
+    Some(directive)
+}
+
+/// Whether the shell integration sourcing the directives is PowerShell
+fn is_powershell() -> bool {
+    std::env::var("WORKTRUNK_SHELL")
+        .map(|v| v.eq_ignore_ascii_case("powershell"))
+        .unwrap_or(false)
+}
+
+/// An `eval` of `command`, single-quoted so the shell reads it as one word.
+///
+/// Chaining the quoted evals keeps each command whole: a trailing `#` comment
+/// can't swallow the rest of the chain, and the exit status of a multi-line
+/// payload is its own, as with `sh -c`. Quotes are escaped as in [`cd_directive`];
+/// backslashes too leave the quotes, since fish reads `\\` and `\'` inside them.
+fn eval_directive(command: &str, powershell: bool) -> String {
+    if powershell {
+        return format!("Invoke-Expression '{}'", command.replace('\'', "''"));
+    }
+    let mut directive = String::from("eval '");
+    for c in command.chars() {
+        match c {
+            '\'' => directive.push_str(r"'\''"),
+            '\\' => directive.push_str(r"'\\'"),
+            c => directive.push(c),
+        }
+    }
+    directive.push('\'');
+    directive
+}
+
 /// Request directory change (for shell integration)
 ///
 /// If shell integration is active (WORKTRUNK_DIRECTIVE_FILE set), writes `cd` command to the file.
@@ -236,20 +306,20 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
         drop(guard); // Release lock before I/O
 
         let directive_path = to_logical_path(path);
-        let path_str = directive_path.to_string_lossy();
-        // Escape based on shell type. Both shell families use single-quoted strings
-        // where contents are literal, but they escape embedded quotes differently:
-        // - PowerShell: double the quote ('it''s')
-        // - POSIX (bash/zsh/fish): end quote, escaped quote, start quote ('it'\''s')
-        let is_powershell = std::env::var("WORKTRUNK_SHELL")
-            .map(|v| v.eq_ignore_ascii_case("powershell"))
-            .unwrap_or(false);
-        let escaped = if is_powershell {
-            path_str.replace('\'', "''")
-        } else {
-            path_str.replace('\'', "'\\''")
+        let is_powershell = is_powershell();
+        // A lossy path would send the shell somewhere else, so a path the shell
+        // can't be given gets no cd; the caller's messages still show where it is
+        let Some(directive) = cd_directive(&directive_path, is_powershell) else {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Can't change directory to <bold>{}</>: the path isn't valid UTF-8",
+                    directive_path.to_string_lossy()
+                ))
+            );
+            return Ok(());
         };
-        write_directive(&format!("cd '{}'", escaped))?;
+        write_directive_bytes(&directive)?;
     }
 
     Ok(())
@@ -275,10 +345,32 @@ pub fn was_cwd_removed() -> bool {
         .cwd_removed
 }
 
+/// Record that a child command's output went to stderr.
+///
+/// Called by `execute_command_in_worktree`, which writes an ANSI reset with
+/// no newline before the child runs.
+pub fn mark_child_output() {
+    get_state()
+        .lock()
+        .expect("OUTPUT_STATE lock poisoned")
+        .child_output = true;
+}
+
+/// Check whether stderr may be mid-line after a child command's output.
+pub fn had_child_output() -> bool {
+    get_state()
+        .lock()
+        .expect("OUTPUT_STATE lock poisoned")
+        .child_output
+}
+
 /// Request command execution
 ///
 /// In interactive mode (no directive file), executes the command directly (replacing process on Unix).
 /// In shell integration mode, writes the command to the directive file.
+///
+/// Either way the command is passed through verbatim: newlines, quotes and `$` reach
+/// the shell exactly as given, as a single `sh -c` argument or as sourced lines.
 pub fn execute(command: impl Into<String>) -> anyhow::Result<()> {
     let command = command.into();
 
@@ -297,6 +389,73 @@ pub fn execute(command: impl Into<String>) -> anyhow::Result<()> {
     }
 }
 
+/// Request execution of several commands, in order
+///
+/// In shell integration mode, writes the commands as directives to run after
+/// the shell's cd: one `&&` chain of quoted evals, or one line each with
+/// `keep_going` (a lone command is written as is). Otherwise the commands before the last run in the target
+/// directory, stopping at the first failure unless `keep_going` is set, and the
+/// last replaces this process (Unix) so it still gets the terminal.
+///
+/// `exec_dir` is for when the shell doesn't change directory (`--no-cd`): the
+/// commands then run here, in that directory, in either mode.
+pub fn execute_all(
+    commands: &[String],
+    exec_dir: Option<&Path>,
+    keep_going: bool,
+) -> anyhow::Result<()> {
+    let Some((last, rest)) = commands.split_last() else {
+        return Ok(());
+    };
+
+    if exec_dir.is_none() && has_directive_file() {
+        if keep_going || rest.is_empty() {
+            for command in rest {
+                write_directive(command)?;
+            }
+            return execute(last.as_str());
+        }
+        // One `&&` chain, so the shell stops where a direct run would. Every
+        // supported shell reads `&&`; braces would not survive fish.
+        let powershell = is_powershell();
+        let chain: Vec<String> = commands
+            .iter()
+            .map(|command| eval_directive(command, powershell))
+            .collect();
+        return execute(chain.join(" && "));
+    }
+
+    let exec_dir = match exec_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => {
+            let guard = get_state().lock().expect("OUTPUT_STATE lock poisoned");
+            guard
+                .target_dir
+                .clone()
+                .unwrap_or_else(|| PathBuf::from("."))
+        }
+    };
+    for (i, command) in rest.iter().enumerate() {
+        let Err(err) = super::execute_command_in_worktree(&exec_dir, command, None, None) else {
+            continue;
+        };
+        let Some(code) = worktrunk::git::exit_code(&err) else {
+            return Err(err);
+        };
+        let message = cformat!(
+            "Command {} of {} failed with exit code {code}: <bold>{}</>",
+            i + 1,
+            commands.len(),
+            command.lines().next().unwrap_or_default()
+        );
+        if !keep_going {
+            return Err(WorktrunkError::ChildProcessExited { code, message }.into());
+        }
+        eprintln!("{}", warning_message(message));
+    }
+    execute_command(last.clone(), Some(&exec_dir))
+}
+
 /// Execute a command in the given directory (Unix: exec, non-Unix: spawn)
 #[cfg(unix)]
 fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result<()> {
@@ -362,6 +521,19 @@ pub fn terminate_output() -> io::Result<()> {
     stderr.flush()
 }
 
+/// Reset ANSI state before the default panic message, so a crash mid-output
+/// can't leave the shell styled.
+///
+/// Panic messages go to stderr, never to stdout or the directive file the shell
+/// wrapper sources. Install this once, early in `main`.
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = terminate_output();
+        default_hook(info);
+    }));
+}
+
 /// Check if we're in shell integration mode (directive file is set)
 ///
 /// This is useful for handlers that need to know whether shell integration is active.
@@ -523,6 +695,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cd_directive_quoting() {
+        let cd = |path: &str, powershell| {
+            String::from_utf8(cd_directive(Path::new(path), powershell).unwrap()).unwrap()
+        };
+        assert_eq!(cd("/tmp/it's", false), r"cd '/tmp/it'\''s'");
+        assert_eq!(cd("/tmp/it's", true), "cd '/tmp/it''s'");
+    }
+
+    #[test]
+    fn test_eval_directive_quoting() {
+        assert_eq!(
+            eval_directive("npm test # it's", false),
+            r"eval 'npm test # it'\''s'"
+        );
+        assert_eq!(
+            eval_directive(r"printf 'a\n'", false),
+            r"eval 'printf '\''a'\\'n'\'''"
+        );
+        assert_eq!(
+            eval_directive("npm test # it's", true),
+            "Invoke-Expression 'npm test # it''s'"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cd_directive_non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+        let path = Path::new(std::ffi::OsStr::from_bytes(b"/tmp/caf\xe9"));
+        assert_eq!(cd_directive(path, false).unwrap(), b"cd '/tmp/caf\xe9'");
+        assert_eq!(cd_directive(path, true), None);
+    }
+
     #[test]
     fn test_lazy_init_does_not_panic() {
         // Verify lazy initialization doesn't panic.
@@ -4,12 +4,13 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 
+use anyhow::Context;
 use color_print::cformat;
 use worktrunk::shell_exec::Cmd;
 use worktrunk::styling::{eprint, format_bash_with_gutter, stderr};
 
 use crate::commands::branch_deletion::{
-    BranchDeletionOutcome, BranchDeletionResult, delete_branch_if_safe,
+    BranchDeletionOutcome, BranchDeletionResult, RemoteBranch, delete_branch_if_safe,
 };
 use crate::commands::command_executor::CommandContext;
 use crate::commands::hooks::{
@@ -19,16 +20,19 @@ use crate::commands::process::{
     HookLog, InternalOp, build_remove_command, build_remove_command_staged, generate_removing_path,
     spawn_detached,
 };
-use crate::commands::worktree::{BranchDeletionMode, RemoveResult, SwitchBranchInfo, SwitchResult};
-use worktrunk::config::UserConfig;
+use crate::commands::worktree::{
+    BranchDeletionMode, RemoveResult, SwitchBranchInfo, SwitchFact, SwitchResult,
+};
+use worktrunk::config::{ActivityOnRemove, UserConfig};
 use worktrunk::git::GitError;
 use worktrunk::git::IntegrationReason;
 use worktrunk::git::Repository;
 use worktrunk::git::path_dir_name;
+use worktrunk::integrations::PathEvent;
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
-    FormattedMessage, eprintln, error_message, format_with_gutter, hint_message, info_message,
-    progress_message, success_message, suggest_command, warning_message,
+    FormattedMessage, WARNING_SYMBOL, eprintln, error_message, format_with_gutter, hint_message,
+    info_message, progress_message, success_message, suggest_command, warning_message,
 };
 
 use super::shell_integration::{
@@ -93,7 +97,17 @@ fn execute_instant_removal_or_fallback(
             // later (at execution time) when .gitmodules is present. That creates a
             // small check-vs-use window where newly introduced changes could be
             // removed. See remove_worktree() docs for the detailed safety analysis.
-            let force = force_worktree || worktree_path.join(".gitmodules").exists();
+            //
+            // A worktree whose branch was deleted also needs --force; its clean
+            // check ran against its last commit.
+            let force = force_worktree
+                || worktree_path.join(".gitmodules").exists()
+                || repo
+                    .worktree_at(worktree_path)
+                    .deleted_branch_commit()
+                    .ok()
+                    .flatten()
+                    .is_some();
             build_remove_command(worktree_path, branch_to_delete, force)
         }
     }
@@ -165,6 +179,64 @@ fn format_switch_message(
     }
 }
 
+/// Format a switch fact as an indented line beneath the switch message: dim,
+/// or yellow for facts that need attention.
+fn format_switch_fact(fact: &SwitchFact) -> String {
+    let text = match fact {
+        SwitchFact::UpstreamUnset { base } => {
+            format!("No upstream set; base {base} is a remote branch")
+        }
+        SwitchFact::ParentRecorded { parent } => {
+            format!("Stacked on {parent}; wt list compares with it")
+        }
+        SwitchFact::PushToFork { url } => format!("Pushes go to fork {url}"),
+        SwitchFact::ScratchRestored { dir } => format!("Restored {dir}/ from the last worktree"),
+        SwitchFact::WorktreeConfigEnabled => {
+            "Enabled extensions.worktreeConfig so worktrees can carry their own git config"
+                .to_string()
+        }
+        SwitchFact::GitConfigSet { keys } => {
+            format!("Set {} for this worktree only", keys.join(", "))
+        }
+        SwitchFact::HooksRan {
+            hook_type,
+            ran,
+            failed,
+        } => {
+            let noun = if *ran == 1 { "command" } else { "commands" };
+            match failed {
+                0 => format!("Ran {ran} {hook_type} {noun}"),
+                _ => format!("Ran {ran} {hook_type} {noun}, {failed} failed"),
+            }
+        }
+        SwitchFact::IdentityDiffers {
+            key,
+            value,
+            origin,
+            primary_value,
+        } => {
+            let value = value.as_deref().unwrap_or("unset");
+            let primary_value = primary_value.as_deref().unwrap_or("unset");
+            let origin = origin
+                .as_ref()
+                .map(|origin| format!(" (set in {origin})"))
+                .unwrap_or_default();
+            return cformat!(
+                "  {WARNING_SYMBOL} <yellow>{key} is <bold>{value}</> here{origin}, but <bold>{primary_value}</> in the primary worktree</>"
+            );
+        }
+    };
+    cformat!("  <dim>· {text}</>")
+}
+
+/// Format the switch report: the headline message, then one line per fact.
+fn format_switch_report(headline: impl std::fmt::Display, facts: &[SwitchFact]) -> String {
+    std::iter::once(headline.to_string())
+        .chain(facts.iter().map(format_switch_fact))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Format a branch-worktree mismatch warning message.
 ///
 /// Shows when a worktree is at a path that doesn't match the config template.
@@ -510,7 +582,7 @@ pub fn handle_switch_output(
                     eprintln!("{}", warning);
                 }
                 // Show what exists + why cd won't happen
-                // (--execute command display is handled by execute_user_command)
+                // (--execute command display is handled by execute_user_commands)
                 eprintln!(
                     "{}",
                     warning_message(cformat!(
@@ -550,20 +622,19 @@ pub fn handle_switch_output(
             created_branch,
             base_branch,
             from_remote,
+            facts,
             ..
         } => {
-            // Always show success for creation
-            eprintln!(
-                "{}",
-                success_message(format_switch_message(
-                    branch,
-                    &path,
-                    true, // worktree_created
-                    *created_branch,
-                    base_branch.as_deref(),
-                    from_remote.as_deref(),
-                ))
-            );
+            // Always show success for creation, with what else happened beneath it
+            let headline = success_message(format_switch_message(
+                branch,
+                &path,
+                true, // worktree_created
+                *created_branch,
+                base_branch.as_deref(),
+                from_remote.as_deref(),
+            ));
+            eprintln!("{}", format_switch_report(headline, facts));
 
             // Show worktree-path config hint on first --create in this repo,
             // unless user already has a custom worktree-path config
@@ -581,7 +652,7 @@ pub fn handle_switch_output(
             }
 
             // Warn if shell won't cd to the new worktree (but not for --no-cd)
-            // (--execute command display is handled by execute_user_command)
+            // (--execute command display is handled by execute_user_commands)
             if let Some(reason) = shell_warning_reason {
                 // Don't repeat "Created worktree" — success message above already said that
                 eprintln!(
@@ -611,12 +682,19 @@ pub fn handle_switch_output(
     Ok(display_path_for_hooks)
 }
 
-/// Execute the --execute command after hooks have run
+/// Execute the --execute commands after hooks have run
 ///
 /// `display_path` is shown when the user's shell won't be in the worktree directory
-/// (shell integration not active). This helps users understand where the command runs.
-pub fn execute_user_command(command: &str, display_path: Option<&Path>) -> anyhow::Result<()> {
-    // Show what command is being executed (section header + gutter content)
+/// (shell integration not active). This helps users understand where the commands run.
+/// `exec_dir` is the worktree when the shell isn't changing to it (`--no-cd`).
+/// See [`super::execute_all`] for how failures stop the sequence.
+pub fn execute_user_commands(
+    commands: &[String],
+    display_path: Option<&Path>,
+    exec_dir: Option<&Path>,
+    keep_going: bool,
+) -> anyhow::Result<()> {
+    // Show what is being executed (section header + gutter content)
     // Include path when user's shell won't be there (shell integration not active)
     let header = match display_path {
         Some(path) => {
@@ -626,11 +704,9 @@ pub fn execute_user_command(command: &str, display_path: Option<&Path>) -> anyho
         None => "Executing (--execute):".to_string(),
     };
     eprintln!("{}", progress_message(header));
-    eprintln!("{}", format_bash_with_gutter(command));
-
-    super::execute(command)?;
+    eprintln!("{}", format_bash_with_gutter(&commands.join("\n")));
 
-    Ok(())
+    super::execute_all(commands, exec_dir, keep_going)
 }
 
 /// Handle output for a remove operation
@@ -656,6 +732,7 @@ pub fn handle_remove_output(
             force_worktree,
             expected_path,
             removed_commit,
+            remote_branch,
         } => handle_removed_worktree_output(RemovedWorktreeOutputContext {
             main_path,
             worktree_path,
@@ -667,6 +744,7 @@ pub fn handle_remove_output(
             force_worktree: *force_worktree,
             expected_path: expected_path.as_deref(),
             removed_commit: removed_commit.as_deref(),
+            remote_branch: remote_branch.as_ref(),
             foreground,
             verify,
         }),
@@ -674,7 +752,14 @@ pub fn handle_remove_output(
             branch_name,
             deletion_mode,
             pruned,
-        } => handle_branch_only_output(branch_name, *deletion_mode, *pruned, quiet),
+            remote_branch,
+        } => handle_branch_only_output(
+            branch_name,
+            *deletion_mode,
+            *pruned,
+            remote_branch.as_ref(),
+            quiet,
+        ),
     }
 }
 
@@ -686,6 +771,7 @@ fn handle_branch_only_output(
     branch_name: &str,
     deletion_mode: BranchDeletionMode,
     pruned: bool,
+    remote_branch: Option<&RemoteBranch>,
     quiet: bool,
 ) -> anyhow::Result<()> {
     let branch_info = if pruned {
@@ -758,12 +844,124 @@ fn handle_branch_only_output(
                 ))
             );
         }
+        if let Some(remote_branch) = remote_branch {
+            delete_remote_branch(&repo, branch_name, remote_branch, deletion_mode.is_force());
+        }
     }
 
     stderr().flush()?;
     Ok(())
 }
 
+/// Delete a removed branch's upstream on its remote (`--delete-remote`).
+///
+/// Keeps it when the remote has commits the local branch never had, unless the
+/// branch was force-deleted. Failures warn rather than fail the removal, since
+/// the branch itself is already gone.
+fn delete_remote_branch(
+    repo: &Repository,
+    branch_name: &str,
+    remote_branch: &RemoteBranch,
+    force: bool,
+) {
+    let RemoteBranch {
+        remote,
+        name,
+        short,
+        unique_commits,
+    } = remote_branch;
+    if *unique_commits > 0 && !force {
+        let commits = if *unique_commits == 1 {
+            "commit"
+        } else {
+            "commits"
+        };
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Kept <bold>{short}</>: it has {unique_commits} {commits} not on <bold>{branch_name}</>"
+            ))
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To delete it anyway, run <underline>git push {remote} --delete {name}</>"
+            ))
+        );
+        return;
+    }
+    match repo.run_network_command(&["push", remote, "--delete", name]) {
+        Ok(_) => eprintln!(
+            "{}",
+            success_message(cformat!("Deleted remote branch <bold>{short}</>"))
+        ),
+        Err(e) => {
+            eprintln!(
+                "{}",
+                warning_message(cformat!("Failed to delete <bold>{short}</>"))
+            );
+            eprintln!("{}", format_with_gutter(&e.to_string(), None));
+        }
+    }
+}
+
+/// Delete or archive a removed worktree's activity log, per `[activity] on-remove`.
+///
+/// Runs even when activity recording is off, so a log left from when it was on
+/// doesn't outlive its worktree.
+fn retire_activity_log(repo: &Repository, worktree_path: &std::path::Path) {
+    let archive = repo
+        .user_config()
+        .activity
+        .as_ref()
+        .is_some_and(|activity| activity.on_remove() == ActivityOnRemove::Archive);
+    if let Err(err) = worktrunk::activity::retire(&repo.wt_logs_dir(), worktree_path, archive) {
+        log::debug!("Failed to retire activity log: {err}");
+    }
+}
+
+/// Move a removed worktree's scratch directory aside for its branch, per `[scratch]`.
+///
+/// A failed move stops the removal, which would otherwise delete the directory.
+fn preserve_scratch(
+    repo: &Repository,
+    worktree_path: &std::path::Path,
+    branch: &str,
+) -> anyhow::Result<()> {
+    let Some(scratch) = repo.user_config().scratch.as_ref().filter(|s| s.enabled()) else {
+        return Ok(());
+    };
+    let dir = scratch.dir();
+    let kept = worktrunk::scratch::preserve(repo.git_common_dir(), worktree_path, dir, branch)
+        .with_context(|| cformat!("Failed to keep <bold>{dir}/</>; worktree not removed"))?;
+    if kept {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Kept <bold>{dir}/</> for <bold>{branch}</>; it returns when the worktree is recreated"
+            ))
+        );
+    }
+    Ok(())
+}
+
+/// Drop a removed worktree from zoxide and run `on-path-removed`, per `[integrations]`.
+fn notify_path_removed(
+    repo: &Repository,
+    main_path: &std::path::Path,
+    worktree_path: &std::path::Path,
+    branch: &str,
+) {
+    worktrunk::integrations::notify(
+        repo,
+        repo.user_config().integrations.as_ref(),
+        PathEvent::Removed,
+        worktree_path,
+        branch,
+        main_path,
+    );
+}
+
 /// Spawn post-remove and post-switch hooks as a single batch after worktree removal.
 ///
 /// Combines both hook types into one output line for consistency with how
@@ -979,6 +1177,7 @@ impl RemovalDisplayInfo {
     /// Print hints about branch status (why it was kept, how to force delete).
     fn print_hints(
         &self,
+        repo: &Repository,
         branch_name: &str,
         deletion_mode: BranchDeletionMode,
         pre_computed_integration: Option<IntegrationReason>,
@@ -999,6 +1198,16 @@ impl RemovalDisplayInfo {
                         "Branch integrated ({desc} <underline>{target}</>, <dim>{symbol}</>); retained with <underline>--no-delete-branch</>"
                     ))
                 );
+            } else if repo.branch(branch_name).exists_locally()? {
+                // User kept an unmerged branch - show how to delete it later
+                // (a branch deleted out from under its worktree has nothing to keep)
+                let cmd = suggest_command("remove", &[branch_name], &["-D"]);
+                eprintln!(
+                    "{}",
+                    hint_message(cformat!(
+                        "Branch retained with <underline>--no-delete-branch</>; to delete, run <underline>{cmd}</>"
+                    ))
+                );
             }
         } else if self.show_unmerged_hint
             || (!deletion_mode.should_keep() && !self.branch_was_integrated)
@@ -1032,6 +1241,7 @@ struct RemovedWorktreeOutputContext<'a> {
     force_worktree: bool,
     expected_path: Option<&'a Path>,
     removed_commit: Option<&'a str>,
+    remote_branch: Option<&'a RemoteBranch>,
     foreground: bool,
     verify: bool,
 }
@@ -1049,6 +1259,7 @@ fn handle_removed_worktree_output(ctx: RemovedWorktreeOutputContext<'_>) -> anyh
         force_worktree,
         expected_path,
         removed_commit,
+        remote_branch,
         foreground,
         verify,
     } = ctx;
@@ -1085,7 +1296,13 @@ fn handle_removed_worktree_output(ctx: RemovedWorktreeOutputContext<'_>) -> anyh
         )?;
     }
 
-    // Emit cd directive only after pre-remove hooks succeed
+    // Keep the scratch directory before the cd directive: a failure here leaves
+    // the worktree in place, so the shell must stay in it
+    if let Some(branch) = branch_name {
+        preserve_scratch(&repo, worktree_path, branch)?;
+    }
+
+    // Emit cd directive only after pre-remove hooks and scratch succeed
     if changed_directory {
         super::change_directory(main_path)?;
         stderr().flush()?; // Force flush to ensure shell processes the cd
@@ -1094,6 +1311,9 @@ fn handle_removed_worktree_output(ctx: RemovedWorktreeOutputContext<'_>) -> anyh
         super::mark_cwd_removed();
     }
 
+    // Pre-remove hooks were the last commands to run here
+    retire_activity_log(&repo, worktree_path);
+
     // Handle detached HEAD case (no branch known)
     let Some(branch_name) = branch_name else {
         // No branch associated - just remove the worktree
@@ -1152,6 +1372,7 @@ fn handle_removed_worktree_output(ctx: RemovedWorktreeOutputContext<'_>) -> anyh
             )?;
         }
         // Post-remove hooks for detached HEAD use "HEAD" as the branch identifier
+        notify_path_removed(&repo, main_path, worktree_path, "HEAD");
         spawn_hooks_after_remove(
             &repo,
             main_path,
@@ -1208,9 +1429,15 @@ fn handle_removed_worktree_output(ctx: RemovedWorktreeOutputContext<'_>) -> anyh
         )?;
 
         display_info.print_message(branch_name, true)?;
-        display_info.print_hints(branch_name, deletion_mode, pre_computed_integration)?;
+        display_info.print_hints(&repo, branch_name, deletion_mode, pre_computed_integration)?;
+        if let Some(remote_branch) = remote_branch
+            && display_info.branch_deleted()
+        {
+            delete_remote_branch(&repo, branch_name, remote_branch, deletion_mode.is_force());
+        }
         print_switch_message_if_changed(changed_directory, main_path)?;
 
+        notify_path_removed(&repo, main_path, worktree_path, branch_name);
         spawn_hooks_after_remove(
             &repo,
             main_path,
@@ -1240,7 +1467,12 @@ fn handle_removed_worktree_output(ctx: RemovedWorktreeOutputContext<'_>) -> anyh
         );
 
         display_info.print_message(branch_name, false)?;
-        display_info.print_hints(branch_name, deletion_mode, pre_computed_integration)?;
+        display_info.print_hints(&repo, branch_name, deletion_mode, pre_computed_integration)?;
+        if let Some(remote_branch) = remote_branch
+            && display_info.branch_deleted()
+        {
+            delete_remote_branch(&repo, branch_name, remote_branch, deletion_mode.is_force());
+        }
         print_switch_message_if_changed(changed_directory, main_path)?;
 
         // Stop fsmonitor daemon BEFORE rename (must happen while path still exists).
@@ -1266,6 +1498,7 @@ fn handle_removed_worktree_output(ctx: RemovedWorktreeOutputContext<'_>) -> anyh
             None,
         )?;
 
+        notify_path_removed(&repo, main_path, worktree_path, branch_name);
         spawn_hooks_after_remove(
             &repo,
             main_path,
@@ -1286,8 +1519,8 @@ fn handle_removed_worktree_output(ctx: RemovedWorktreeOutputContext<'_>) -> anyh
 /// deterministic output ordering. Per CLAUDE.md guidelines: child process output goes to
 /// stderr, worktrunk output goes to stdout.
 ///
-/// If `stdin_content` is provided, it will be piped to the command's stdin. This is used to pass
-/// hook context as JSON to hook commands.
+/// If `stdin_content` is provided, it will be piped to the command's stdin and set as
+/// `WT_HOOK_CONTEXT`. This is used to pass hook context as JSON to hook commands.
 ///
 /// ## Color Bleeding Prevention
 ///
@@ -1319,6 +1552,7 @@ pub fn execute_command_in_worktree(
     // followed immediately by child process output to stderr (e.g., pre-commit run output).
     eprint!("{}", anstyle::Reset);
     stderr().flush().ok(); // Ignore flush errors - reset is best-effort, command execution should proceed
+    super::mark_child_output();
 
     // Execute with stdout→stderr redirect for deterministic ordering
     let mut cmd = Cmd::shell(command)
@@ -1331,7 +1565,9 @@ pub fn execute_command_in_worktree(
     }
 
     if let Some(content) = stdin_content {
-        cmd = cmd.stdin_bytes(content);
+        cmd = cmd
+            .stdin_bytes(content)
+            .env(worktrunk::shell_exec::HOOK_CONTEXT_ENV_VAR, content);
     }
 
     cmd.stream()?;
@@ -1370,6 +1606,54 @@ mod tests {
         assert_snapshot!(msg, @"Created worktree for [1mfeature[22m @ [1m/tmp/test[22m");
     }
 
+    #[test]
+    fn test_format_switch_report() {
+        let path = PathBuf::from("/tmp/test");
+        let headline = || {
+            success_message(format_switch_message(
+                "feature",
+                &path,
+                true,
+                true,
+                Some("origin/main"),
+                None,
+            ))
+        };
+
+        // Minimal: nothing else happened, so just the headline
+        assert_snapshot!(format_switch_report(headline(), &[]), @"[32m✓[39m [32mCreated branch [1mfeature[22m from [1morigin/main[22m and worktree @ [1m/tmp/test[22m[39m");
+
+        // Maximal: every fact, each on its own line
+        let facts = [
+            SwitchFact::UpstreamUnset {
+                base: "origin/main".to_string(),
+            },
+            SwitchFact::PushToFork {
+                url: "git@github.com:contributor/repo.git".to_string(),
+            },
+            SwitchFact::HooksRan {
+                hook_type: worktrunk::HookType::PostCreate,
+                ran: 3,
+                failed: 1,
+            },
+        ];
+        assert_snapshot!(format_switch_report(headline(), &facts), @r"
+        [32m✓[39m [32mCreated branch [1mfeature[22m from [1morigin/main[22m and worktree @ [1m/tmp/test[22m[39m
+          [2m· No upstream set; base origin/main is a remote branch[22m
+          [2m· Pushes go to fork git@github.com:contributor/repo.git[22m
+          [2m· Ran 3 post-create commands, 1 failed[22m
+        ");
+
+        // A single successful command reads naturally
+        let single = format_switch_fact(&SwitchFact::HooksRan {
+            hook_type: worktrunk::HookType::PostCreate,
+            ran: 1,
+            failed: 0,
+        });
+        assert!(single.contains("Ran 1 post-create command"));
+        assert!(!single.contains("failed"));
+    }
+
     #[test]
     fn test_get_flag_note() {
         // --no-delete-branch flag (text only, no symbol, no suffix)
@@ -13,7 +13,7 @@
 //!
 //! eprintln!("{}", success_message("Operation complete"));
 //! output::change_directory(&path);
-//! output::execute("git pull");
+//! output::execute_all(&["git pull".to_string()], None, false);
 //! ```
 //!
 //! ## Shell Integration
@@ -37,13 +37,13 @@ pub(crate) mod shell_integration;
 
 // Re-export the public API
 pub(crate) use global::{
-    change_directory, execute, is_shell_integration_active, mark_cwd_removed,
-    post_hook_display_path, pre_hook_display_path, set_verbosity, terminate_output,
-    to_logical_path, was_cwd_removed,
+    change_directory, execute_all, had_child_output, install_panic_hook,
+    is_shell_integration_active, mark_child_output, mark_cwd_removed, post_hook_display_path,
+    pre_hook_display_path, set_verbosity, terminate_output, to_logical_path, was_cwd_removed,
 };
 // Re-export output handlers
 pub(crate) use handlers::{
-    execute_command_in_worktree, execute_user_command, handle_remove_output, handle_switch_output,
+    execute_command_in_worktree, execute_user_commands, handle_remove_output, handle_switch_output,
 };
 // Re-export shell integration functions
 pub(crate) use shell_integration::{
@@ -44,7 +44,8 @@
 //! | $SHELL not set | Hint: `To enable automatic cd, run wt config shell install` |
 //! | Current shell already installed | Hint: `Restart shell to activate shell integration` |
 //! | `skip-shell-integration-prompt` / Non-TTY | Hint: `To enable automatic cd, run wt config shell install` |
-//! | TTY | Prompt: `Install shell integration? [y/N/?]` |
+//! | Banner shown 3 times, installed before, or `[hints] disabled = ["first-run"]` | Hint: `To enable automatic cd, run wt config shell install` |
+//! | TTY | Banner explaining shell integration, then prompt: `Install shell integration? [y/N/?]` |
 //!
 //! # Reason Values
 //!
@@ -69,7 +70,7 @@ use worktrunk::styling::{
 };
 
 use crate::commands::configure_shell::{
-    ConfigAction, UninstallScanResult, handle_configure_shell, prompt_for_install,
+    ConfigAction, ConfigureResult, UninstallScanResult, handle_configure_shell, prompt_for_install,
     scan_shell_configs,
 };
 
@@ -373,7 +374,9 @@ pub fn prompt_shell_integration(
         return Ok(false);
     }
 
-    let is_tty = std::io::stdin().is_terminal() && std::io::stderr().is_terminal();
+    let is_tty = std::io::stdin().is_terminal()
+        && std::io::stdout().is_terminal()
+        && std::io::stderr().is_terminal();
 
     // Check the current shell (via $SHELL or PSModulePath fallback)
     // Only prompt if current shell is supported (so they benefit immediately)
@@ -417,14 +420,20 @@ pub fn prompt_shell_integration(
         return Ok(false);
     }
 
-    // Can't or shouldn't prompt - show install hint
-    if config.skip_shell_integration_prompt || !is_tty || skip_prompt {
+    // Can't or shouldn't prompt - show install hint. The banner check goes
+    // last since it counts as a showing.
+    if config.skip_shell_integration_prompt
+        || !is_tty
+        || skip_prompt
+        || !crate::first_run::claim_banner(config)
+    {
         eprintln!("{}", hint_message(shell_integration_hint()));
         return Ok(false);
     }
 
-    // TTY + first time: Show interactive prompt
+    // TTY + first few runs: explain, then prompt
     // Accepting installs for all shells with config files (same as `wt config shell install`)
+    print_first_run_banner(&scan.configured, binary_name);
     let confirmed = prompt_for_install(
         &scan.configured,
         &scan.completion_results,
@@ -449,6 +458,28 @@ pub fn prompt_shell_integration(
     Ok(true)
 }
 
+/// Explain what shell integration does and which files installing it edits.
+fn print_first_run_banner(configured: &[ConfigureResult], binary_name: &str) {
+    let paths: Vec<_> = configured
+        .iter()
+        .filter(|r| !matches!(r.action, ConfigAction::AlreadyExists))
+        .map(|r| format_path_for_display(&r.path))
+        .collect();
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Shell integration lets <bold>{binary_name} switch</>, <bold>merge</> and <bold>remove</> change this shell's directory"
+        ))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "Installing edits {}; <underline>{binary_name} config shell uninstall</> undoes it",
+            paths.join(", ")
+        ))
+    );
+}
+
 /// Print the result of shell integration uninstallation.
 ///
 /// Shows removed extensions/completions, not-found warnings, summary, and restart hint.
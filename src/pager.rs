@@ -1,4 +1,10 @@
+//! Pager detection and spawning, shared by `--help` and `wt list`.
+
+use std::io::Write;
+use std::process::Stdio;
+
 use worktrunk::git::Repository;
+use worktrunk::shell_exec::ShellConfig;
 
 /// Parse a pager value, treating empty strings and "cat" as "no pager".
 pub(crate) fn parse_pager_value(value: &str) -> Option<String> {
@@ -6,10 +12,84 @@ pub(crate) fn parse_pager_value(value: &str) -> Option<String> {
     (!trimmed.is_empty() && trimmed != "cat").then(|| trimmed.to_string())
 }
 
-/// Read `core.pager` from git config, returning None if unset or invalid.
+/// Read `core.pager` from git config, returning None if unset.
 pub(crate) fn git_config_pager() -> Option<String> {
     let repo = Repository::current().ok()?;
-    repo.run_command(&["config", "--get", "core.pager"])
+    repo.run_command(&["config", "--get", "core.pager"]).ok()
+}
+
+/// Detect the pager, following git's pager precedence.
+///
+/// Checks in order: GIT_PAGER → git config core.pager → PAGER → "less".
+/// As in git, the first one set decides, so `GIT_PAGER=cat` turns paging off
+/// rather than deferring to `core.pager`.
+///
+/// On Windows without Git Bash, returns None if only `less` would be selected
+/// (since `less` isn't available without Git for Windows).
+pub(crate) fn detect_pager() -> Option<String> {
+    let shell = ShellConfig::get().ok()?;
+
+    // Check environment variables in git's precedence order
+    let configured = std::env::var("GIT_PAGER")
         .ok()
-        .and_then(|output| parse_pager_value(&output))
+        .or_else(git_config_pager)
+        .or_else(|| std::env::var("PAGER").ok());
+
+    // If user explicitly configured a pager (or turned it off), use that
+    if let Some(value) = configured {
+        return parse_pager_value(&value);
+    }
+
+    // Default to "less" only if we have a POSIX shell (Unix or Git Bash on Windows)
+    // Without Git Bash, less isn't typically available on Windows
+    if shell.is_posix() {
+        Some("less".to_string())
+    } else {
+        log::debug!("No POSIX shell available, skipping pager (less not available)");
+        None
+    }
+}
+
+/// Compute LESS flags by appending our required flags to user's existing LESS setting.
+///
+/// Returns flags suitable for setting LESS env var when spawning less.
+/// Ensures F (quit if one screen), R (colors), X (no termcap init) are always active.
+pub(crate) fn compute_less_flags(user_less: Option<&str>) -> String {
+    format!("{} -FRX", user_less.unwrap_or_default())
+}
+
+/// Pipe `text` through `pager_cmd`, which writes to the terminal on stdout.
+///
+/// Returns `false` without writing anything when the pager can't be started,
+/// so the caller can print `text` itself.
+pub(crate) fn page_to_stdout(text: &str, pager_cmd: &str) -> std::io::Result<bool> {
+    let shell = match ShellConfig::get() {
+        Ok(shell) => shell,
+        Err(e) => {
+            log::debug!("Shell unavailable for pager: {}", e);
+            return Ok(false);
+        }
+    };
+    let less_flags = compute_less_flags(std::env::var("LESS").ok().as_deref());
+    log::debug!("$ {} (pager)", pager_cmd);
+    let mut cmd = shell.command(pager_cmd);
+    // Prevent subprocesses from writing to the directive file
+    cmd.env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR);
+    let mut child = match cmd.stdin(Stdio::piped()).env("LESS", &less_flags).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            log::debug!("Failed to spawn pager '{}': {}", pager_cmd, e);
+            return Ok(false);
+        }
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user quitting the pager early closes the pipe; that's fine
+        if let Err(e) = stdin.write_all(text.as_bytes())
+            && e.kind() != std::io::ErrorKind::BrokenPipe
+        {
+            return Err(e);
+        }
+    }
+    child.wait()?;
+    Ok(true)
 }
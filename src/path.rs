@@ -1,15 +1,17 @@
 use path_slash::PathExt as _;
 use shell_escape::unix::escape;
 use std::borrow::Cow;
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use normalize_path::NormalizePath;
 
 use sanitize_filename::{Options as SanitizeOptions, sanitize_with_options};
 
 use crate::config::short_hash;
 #[cfg(windows)]
 use crate::shell_exec::{Cmd, ShellConfig};
-#[cfg(windows)]
-use std::path::PathBuf;
 
 /// Convert a path to POSIX format for Git Bash compatibility.
 ///
@@ -168,11 +170,116 @@ pub fn sanitize_for_filename(value: &str) -> String {
     result
 }
 
+/// Case-sensitivity probe results, keyed by the directory that was probed.
+static CASE_INSENSITIVE_DIRS: LazyLock<Mutex<HashMap<PathBuf, bool>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Check whether the filesystem holding `path` ignores case in file names.
+///
+/// Probes the nearest existing ancestor of `path` by creating a mixed-case
+/// temporary file and looking it up under its lowercased name. Falls back to a
+/// platform guess (case-insensitive on macOS and Windows) when the directory
+/// can't be written. Results are cached per directory for the process.
+pub fn is_case_insensitive_fs(path: &Path) -> bool {
+    const PLATFORM_GUESS: bool = cfg!(any(target_os = "macos", windows));
+    let Some(dir) = path.ancestors().find(|dir| dir.is_dir()) else {
+        return PLATFORM_GUESS;
+    };
+    let dir = dir.to_path_buf();
+    if let Some(&cached) = CASE_INSENSITIVE_DIRS.lock().unwrap().get(&dir) {
+        return cached;
+    }
+    let insensitive = probe_case_insensitive(&dir).unwrap_or(PLATFORM_GUESS);
+    log::debug!(
+        "Filesystem at {} is case-{}",
+        dir.display(),
+        if insensitive {
+            "insensitive"
+        } else {
+            "sensitive"
+        }
+    );
+    CASE_INSENSITIVE_DIRS
+        .lock()
+        .unwrap()
+        .insert(dir, insensitive);
+    insensitive
+}
+
+fn probe_case_insensitive(dir: &Path) -> Option<bool> {
+    let probe = tempfile::Builder::new()
+        .prefix(".wt-CaseProbe-")
+        .tempfile_in(dir)
+        .ok()?;
+    let name = probe.path().file_name()?.to_str()?.to_lowercase();
+    Some(dir.join(name).symlink_metadata().is_ok())
+}
+
+/// Compare two paths lexically, optionally ignoring case.
+///
+/// Both paths are normalized first (`.` and `..` resolved without touching
+/// the filesystem), so this works for paths that don't exist yet.
+pub fn paths_equal(a: &Path, b: &Path, case_insensitive: bool) -> bool {
+    let (a, b) = (a.normalize(), b.normalize());
+    if !case_insensitive {
+        return a == b;
+    }
+    a.to_string_lossy().to_lowercase() == b.to_string_lossy().to_lowercase()
+}
+
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
 
-    use super::{format_path_for_display, home_dir, sanitize_for_filename, to_posix_path};
+    use super::{
+        format_path_for_display, home_dir, is_case_insensitive_fs, paths_equal,
+        sanitize_for_filename, to_posix_path,
+    };
+
+    #[test]
+    fn paths_equal_respects_case_sensitivity() {
+        let a = Path::new("/repos/app.Feature-Login");
+        let b = Path::new("/repos/app.feature-login");
+        assert!(!paths_equal(a, b, false));
+        assert!(paths_equal(a, b, true));
+        assert!(paths_equal(a, a, false));
+    }
+
+    #[test]
+    fn paths_equal_normalizes_before_comparing() {
+        let a = Path::new("/repos/main/../App.Feature");
+        let b = Path::new("/repos/./app.feature");
+        assert!(paths_equal(a, b, true));
+        assert!(!paths_equal(a, b, false));
+        assert!(!paths_equal(a, Path::new("/repos/app.other"), true));
+    }
+
+    #[test]
+    fn case_probe_uses_nearest_existing_ancestor() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("not").join("yet");
+        assert_eq!(
+            is_case_insensitive_fs(&missing),
+            is_case_insensitive_fs(dir.path())
+        );
+        // The probe file is cleaned up
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    #[cfg(any(target_os = "macos", windows))]
+    fn case_probe_detects_default_insensitive_volumes() {
+        // Default APFS and NTFS volumes ignore case
+        let dir = tempfile::tempdir().unwrap();
+        assert!(is_case_insensitive_fs(dir.path()));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn case_probe_detects_case_sensitive_volumes() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!is_case_insensitive_fs(dir.path()));
+    }
 
     #[test]
     fn shortens_path_under_home() {
@@ -0,0 +1,243 @@
+//! Opt-in scratch directory that outlives its worktree.
+//!
+//! With `[scratch] enabled = true`, each worktree's scratch directory
+//! (`.wt-scratch/` by default) is listed in `.git/info/exclude`, so git never
+//! sees it. `wt remove` moves it to `.git/wt-scratch/<branch>/`, and the next
+//! `wt switch` that creates a worktree for the same branch moves it back.
+//!
+//! # Moving
+//!
+//! A move is a `rename`, which costs the same for any size of directory. A
+//! worktree on another filesystem than the repository falls back to copying
+//! file by file, then deleting the source; a socket or FIFO, which can't be
+//! copied, stops the move and leaves the source in place.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::fs_ops::move_entry;
+use crate::path::sanitize_for_filename;
+use crate::utils::get_now;
+
+/// Default scratch directory name, relative to the worktree root.
+pub const DEFAULT_DIR: &str = ".wt-scratch";
+
+/// Directory under the git common dir holding preserved scratch directories.
+const STORE_DIR: &str = "wt-scratch";
+
+/// Where a branch's scratch directory waits between worktrees, keyed by the
+/// branch so `feature/x` and `feature-x` don't collide.
+pub fn store_path(git_common_dir: &Path, branch: &str) -> PathBuf {
+    git_common_dir
+        .join(STORE_DIR)
+        .join(sanitize_for_filename(branch))
+}
+
+/// Whether a scratch directory is preserved for `branch`.
+pub fn is_preserved(git_common_dir: &Path, branch: &str) -> bool {
+    store_path(git_common_dir, branch).is_dir()
+}
+
+/// Add `/<dir_name>/` to `.git/info/exclude` unless an entry already covers it.
+pub fn ensure_excluded(git_common_dir: &Path, dir_name: &str) -> anyhow::Result<()> {
+    check_dir_name(dir_name)?;
+    let path = git_common_dir.join("info").join("exclude");
+    let existing = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let covered = existing.lines().map(str::trim).any(|line| {
+        let line = line.strip_prefix('/').unwrap_or(line);
+        line.strip_suffix('/').unwrap_or(line) == dir_name
+    });
+    if covered {
+        return Ok(());
+    }
+
+    let mut content = existing;
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(&format!("/{dir_name}/\n"));
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, content)?;
+    Ok(())
+}
+
+/// Move a worktree's scratch directory into the store for `branch`. Returns
+/// whether there was one to move.
+///
+/// A directory already preserved for `branch` is never deleted: it's moved
+/// aside to a timestamped name next to it (see [`set_aside`]), and the new one
+/// takes its place so the next worktree gets the latest.
+pub fn preserve(
+    git_common_dir: &Path,
+    worktree_path: &Path,
+    dir_name: &str,
+    branch: &str,
+) -> anyhow::Result<bool> {
+    check_dir_name(dir_name)?;
+    let source = worktree_path.join(dir_name);
+    if !source.is_dir() {
+        return Ok(false);
+    }
+    let dest = store_path(git_common_dir, branch);
+    if dest.symlink_metadata().is_ok() {
+        set_aside(&dest)?;
+    }
+    move_entry(&source, &dest, true)?;
+    Ok(true)
+}
+
+/// Rename a stored directory to `<name>.<unix-seconds>`, adding a counter when
+/// that name is taken too. Returns the new path.
+fn set_aside(path: &Path) -> anyhow::Result<PathBuf> {
+    let secs = get_now();
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut aside = path.with_file_name(format!("{name}.{secs}"));
+    let mut n = 1;
+    while aside.symlink_metadata().is_ok() {
+        aside = path.with_file_name(format!("{name}.{secs}-{n}"));
+        n += 1;
+    }
+    fs::rename(path, &aside)?;
+    Ok(aside)
+}
+
+/// Move the scratch directory preserved for `branch` into a new worktree.
+///
+/// Returns whether one was restored. A worktree that already has a directory
+/// of that name keeps it, and the preserved one stays in the store.
+pub fn restore(
+    git_common_dir: &Path,
+    worktree_path: &Path,
+    dir_name: &str,
+    branch: &str,
+) -> anyhow::Result<bool> {
+    check_dir_name(dir_name)?;
+    let source = store_path(git_common_dir, branch);
+    let dest = worktree_path.join(dir_name);
+    if !source.is_dir() || dest.symlink_metadata().is_ok() {
+        return Ok(false);
+    }
+    move_entry(&source, &dest, true)?;
+    Ok(true)
+}
+
+/// The scratch directory must be a single path component inside the worktree.
+fn check_dir_name(dir_name: &str) -> anyhow::Result<()> {
+    let valid = !dir_name.is_empty()
+        && dir_name != "."
+        && dir_name != ".."
+        && dir_name != ".git"
+        && !dir_name.contains(['/', '\\']);
+    anyhow::ensure!(
+        valid,
+        "scratch dir `{dir_name}` must be a single directory name"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preserve_and_restore() {
+        let git_dir = tempfile::tempdir().unwrap();
+        let old = tempfile::tempdir().unwrap();
+        let new = tempfile::tempdir().unwrap();
+        fs::create_dir_all(old.path().join(".wt-scratch/deep")).unwrap();
+        fs::write(old.path().join(".wt-scratch/deep/notes.md"), "todo").unwrap();
+
+        assert!(preserve(git_dir.path(), old.path(), ".wt-scratch", "feature/x").unwrap());
+        assert!(!old.path().join(".wt-scratch").exists());
+        assert!(is_preserved(git_dir.path(), "feature/x"));
+        assert!(!is_preserved(git_dir.path(), "feature-x"));
+
+        assert!(restore(git_dir.path(), new.path(), ".wt-scratch", "feature/x").unwrap());
+        let notes = fs::read_to_string(new.path().join(".wt-scratch/deep/notes.md")).unwrap();
+        assert_eq!(notes, "todo");
+        assert!(!is_preserved(git_dir.path(), "feature/x"));
+
+        // Nothing left to restore, and nothing to preserve from an empty worktree
+        assert!(!restore(git_dir.path(), old.path(), ".wt-scratch", "feature/x").unwrap());
+        assert!(!preserve(git_dir.path(), old.path(), ".wt-scratch", "feature/x").unwrap());
+    }
+
+    #[test]
+    fn test_restore_keeps_existing_directory() {
+        let git_dir = tempfile::tempdir().unwrap();
+        let old = tempfile::tempdir().unwrap();
+        let new = tempfile::tempdir().unwrap();
+        fs::create_dir(old.path().join("scratch")).unwrap();
+        fs::create_dir(new.path().join("scratch")).unwrap();
+
+        preserve(git_dir.path(), old.path(), "scratch", "main").unwrap();
+        assert!(!restore(git_dir.path(), new.path(), "scratch", "main").unwrap());
+        assert!(is_preserved(git_dir.path(), "main"));
+    }
+
+    #[test]
+    fn test_preserve_keeps_earlier_store() {
+        let git_dir = tempfile::tempdir().unwrap();
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        fs::create_dir(first.path().join("scratch")).unwrap();
+        fs::write(first.path().join("scratch/a.txt"), "first").unwrap();
+        fs::create_dir(second.path().join("scratch")).unwrap();
+        fs::write(second.path().join("scratch/a.txt"), "second").unwrap();
+
+        preserve(git_dir.path(), first.path(), "scratch", "main").unwrap();
+        preserve(git_dir.path(), second.path(), "scratch", "main").unwrap();
+
+        // The latest waits under the branch name; the earlier one is set aside
+        let store = store_path(git_dir.path(), "main");
+        assert_eq!(fs::read_to_string(store.join("a.txt")).unwrap(), "second");
+        let aside: Vec<_> = fs::read_dir(store.parent().unwrap())
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .filter(|p| *p != store)
+            .collect();
+        assert_eq!(aside.len(), 1);
+        let prefix = format!("{}.", store.file_name().unwrap().to_string_lossy());
+        assert!(
+            aside[0]
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with(&prefix)
+        );
+        assert_eq!(fs::read_to_string(aside[0].join("a.txt")).unwrap(), "first");
+    }
+
+    #[test]
+    fn test_ensure_excluded() {
+        let git_dir = tempfile::tempdir().unwrap();
+        let exclude = git_dir.path().join("info/exclude");
+
+        ensure_excluded(git_dir.path(), ".wt-scratch").unwrap();
+        ensure_excluded(git_dir.path(), ".wt-scratch").unwrap();
+        assert_eq!(fs::read_to_string(&exclude).unwrap(), "/.wt-scratch/\n");
+
+        // An existing entry in another spelling counts, and a missing final
+        // newline is added before appending
+        fs::write(&exclude, "# local\nnotes/").unwrap();
+        ensure_excluded(git_dir.path(), "notes").unwrap();
+        ensure_excluded(git_dir.path(), "tmp").unwrap();
+        assert_eq!(
+            fs::read_to_string(&exclude).unwrap(),
+            "# local\nnotes/\n/tmp/\n"
+        );
+
+        assert!(ensure_excluded(git_dir.path(), "a/b").is_err());
+        assert!(ensure_excluded(git_dir.path(), "..").is_err());
+    }
+}
@@ -12,11 +12,13 @@ use std::io::{ErrorKind, Read, Write};
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicI32, Ordering};
 use std::time::Instant;
 
 use wait_timeout::ChildExt;
 
 use crate::git::{GitError, WorktrunkError};
+use crate::styling::TerminalGuard;
 use crate::sync::Semaphore;
 
 /// Semaphore to limit concurrent command execution.
@@ -195,6 +197,10 @@ fn find_git_bash() -> Option<PathBuf> {
 /// Hooks and other child processes should not be able to write to the directive file.
 pub const DIRECTIVE_FILE_ENV_VAR: &str = "WORKTRUNK_DIRECTIVE_FILE";
 
+/// Environment variable carrying a hook's JSON context, the same JSON piped to
+/// its stdin, for commands whose stdin is already spoken for.
+pub const HOOK_CONTEXT_ENV_VAR: &str = "WT_HOOK_CONTEXT";
+
 // ============================================================================
 // Thread-Local Command Timeout
 // ============================================================================
@@ -209,6 +215,10 @@ thread_local! {
     /// This is used by `wt switch` interactive picker to make the TUI responsive faster on large repos.
     /// The timeout is set per-worker-thread in Rayon's thread pool.
     static COMMAND_TIMEOUT: Cell<Option<Duration>> = const { Cell::new(None) };
+
+    /// Thread-local deadline. Commands run via `run()` on this thread are killed when it
+    /// passes, and refused once it has passed. Used by `wt list --timeout`.
+    static COMMAND_DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
 }
 
 /// Set the command timeout for the current thread.
@@ -222,6 +232,16 @@ pub fn set_command_timeout(timeout: Option<Duration>) {
     COMMAND_TIMEOUT.with(|t| t.set(timeout));
 }
 
+/// Set the command deadline for the current thread.
+///
+/// Like [`set_command_timeout`], but a point in time shared by every command on the
+/// thread: each one gets whatever time remains, whichever is shorter. Once the
+/// deadline passes, `run()` fails with [`std::io::ErrorKind::TimedOut`] without
+/// spawning anything.
+pub fn set_command_deadline(deadline: Option<Instant>) {
+    COMMAND_DEADLINE.with(|d| d.set(deadline));
+}
+
 /// Emit an instant trace event (a milestone marker with no duration).
 ///
 /// Instant events appear as vertical lines in Chrome Trace Format visualization tools
@@ -393,6 +413,8 @@ pub struct Cmd {
     /// When set, log this command to the command log after execution.
     /// The label identifies what triggered the command (e.g., "pre-merge user:lint").
     external_label: Option<String>,
+    /// If true, the command reaches the network and is refused under `--offline`
+    network: bool,
 }
 
 impl Cmd {
@@ -415,6 +437,7 @@ impl Cmd {
             stdin_cfg: None,
             forward_signals: false,
             external_label: None,
+            network: false,
         }
     }
 
@@ -439,6 +462,7 @@ impl Cmd {
             stdin_cfg: None,
             forward_signals: false,
             external_label: None,
+            network: false,
         }
     }
 
@@ -544,6 +568,34 @@ impl Cmd {
         self
     }
 
+    /// Mark this command as reaching the network (forge APIs, LLMs, update
+    /// checks, and git commands that talk to a remote).
+    ///
+    /// Under `--offline`, `.run()` and `.stream()` refuse it without spawning.
+    /// Call sites mark it rather than the argv being guessed at, since the same
+    /// `git fetch` or `git push` may target a URL or a local path or bundle.
+    pub fn network(mut self) -> Self {
+        self.network = true;
+        self
+    }
+
+    /// The single gate between `--offline` and the network: every spawn passes
+    /// through here, so new features can't bypass it.
+    fn refuse_if_offline(&self) -> std::io::Result<()> {
+        if self.network && crate::utils::is_offline() {
+            log::debug!(
+                "Offline: not running {} {}",
+                self.program,
+                self.args.join(" ")
+            );
+            return Err(std::io::Error::other(format!(
+                "{} needs the network, which --offline forbids",
+                self.program
+            )));
+        }
+        Ok(())
+    }
+
     /// Execute the command and return its output.
     ///
     /// Captures stdout/stderr and returns them in `Output`. For interactive
@@ -559,6 +611,7 @@ impl Cmd {
             !self.shell_wrap,
             "Cmd::shell() commands must use .stream(), not .run()"
         );
+        self.refuse_if_offline()?;
 
         // Build command string for logging
         let cmd_str = if self.args.is_empty() {
@@ -600,8 +653,20 @@ impl Cmd {
         // Applied last to ensure it can't be re-added by user-provided envs.
         cmd.env_remove(DIRECTIVE_FILE_ENV_VAR);
 
-        // Determine effective timeout: explicit > thread-local > none
-        let effective_timeout = self.timeout.or_else(|| COMMAND_TIMEOUT.with(|t| t.get()));
+        // Determine effective timeout: explicit > thread-local > none, capped by
+        // the time left before the thread-local deadline
+        let mut effective_timeout = self.timeout.or_else(|| COMMAND_TIMEOUT.with(|t| t.get()));
+        if let Some(deadline) = COMMAND_DEADLINE.with(|d| d.get()) {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                log::debug!("Deadline passed: not running {}", cmd_str);
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "deadline passed",
+                ));
+            }
+            effective_timeout = Some(effective_timeout.map_or(remaining, |t| t.min(remaining)));
+        }
 
         // Execute with or without stdin
         let result = if let Some(stdin_data) = self.stdin_data {
@@ -723,6 +788,7 @@ impl Cmd {
             !self.shell_wrap || self.args.is_empty(),
             "Cmd::shell() cannot use .arg() - include arguments in the shell command string"
         );
+        self.refuse_if_offline()?;
 
         let working_dir = self
             .current_dir
@@ -823,6 +889,13 @@ impl Cmd {
         }
         // stdin handle is dropped here, closing the pipe
 
+        // Restores the cursor if an interrupted child (e.g. a spinner) hid it.
+        // Only signal forwarding, which is Unix-only, marks it dirty.
+        #[cfg(unix)]
+        let mut terminal_guard = TerminalGuard::new();
+        #[cfg(not(unix))]
+        let terminal_guard = TerminalGuard::new();
+
         // Wait for child with optional signal forwarding
         #[cfg(unix)]
         let (status, seen_signal) = if self.forward_signals {
@@ -840,6 +913,8 @@ impl Cmd {
                     for sig in signals.pending() {
                         if seen_signal.is_none() {
                             seen_signal = Some(sig);
+                            INTERRUPTED.store(sig, Ordering::Relaxed);
+                            terminal_guard.dirty();
                             forward_signal_with_escalation(child_pgid, sig);
                         }
                     }
@@ -861,6 +936,7 @@ impl Cmd {
                 message: format!("Failed to wait for command: {}", e),
             })
         })?;
+        drop(terminal_guard);
 
         // Handle signals (Unix only)
         #[cfg(unix)]
@@ -903,6 +979,21 @@ impl Cmd {
 // Signal forwarding helpers (Unix only)
 // ============================================================================
 
+/// First signal forwarded to a streamed child, or 0 if none.
+static INTERRUPTED: AtomicI32 = AtomicI32::new(0);
+
+/// The signal (SIGINT, SIGTERM) that interrupted a streamed command, if any.
+///
+/// Set by [`Cmd::stream`] with `.forward_signals()` when it forwards a signal
+/// to the child. Batch operations check this between commands to stop early
+/// rather than moving on to the next worktree.
+pub fn interrupted() -> Option<i32> {
+    match INTERRUPTED.load(Ordering::Relaxed) {
+        0 => None,
+        sig => Some(sig),
+    }
+}
+
 #[cfg(unix)]
 fn process_group_alive(pgid: i32) -> bool {
     match nix::sys::signal::killpg(nix::unistd::Pid::from_raw(pgid), None) {
@@ -1187,6 +1278,24 @@ mod tests {
         set_command_timeout(None);
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_cmd_deadline_caps_and_refuses() {
+        // The deadline cuts a command short even with a longer timeout
+        set_command_timeout(Some(Duration::from_secs(10)));
+        set_command_deadline(Some(Instant::now() + Duration::from_millis(50)));
+        let result = Cmd::new("sleep").arg("10").run();
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::TimedOut);
+
+        // Once it has passed, nothing is spawned
+        let result = Cmd::new("echo").arg("too late").run();
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::TimedOut);
+
+        set_command_deadline(None);
+        set_command_timeout(None);
+        assert!(Cmd::new("echo").arg("no deadline").run().is_ok());
+    }
+
     // ========================================================================
     // Cmd::stream() tests
     // ========================================================================
@@ -0,0 +1,221 @@
+//! Terminal background detection.
+//!
+//! Picks between the built-in dark and light themes when `[theme]` leaves the
+//! choice on `auto`. `COLORFGBG` is checked first since it costs nothing; failing
+//! that, the terminal is asked for its background color with an OSC 11 query.
+//! The query is followed by a device attributes request (DA1), which every
+//! terminal answers, so terminals without OSC 11 support end the wait early
+//! instead of running out the timeout.
+//!
+//! Detection only runs when stdout is a terminal with colors enabled; anywhere
+//! else the colors are stripped anyway and the dark theme stays.
+
+use std::io::IsTerminal;
+
+use anstream::ColorChoice;
+
+/// Longest wait for the terminal to answer the background query.
+#[cfg(unix)]
+const QUERY_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// Whether the terminal draws on a dark or light background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Background {
+    Dark,
+    Light,
+}
+
+impl Background {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Background::Dark => "dark",
+            Background::Light => "light",
+        }
+    }
+}
+
+/// Where the chosen background came from, for `wt config show`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackgroundSource {
+    /// Set explicitly with `theme = "dark"` or `theme = "light"`
+    Configured,
+    /// The `COLORFGBG` environment variable
+    ColorFgBg,
+    /// The terminal's answer to an OSC 11 query
+    Terminal,
+    /// Nothing to go on; the dark theme
+    Default,
+}
+
+impl BackgroundSource {
+    pub fn describe(self) -> &'static str {
+        match self {
+            BackgroundSource::Configured => "configured",
+            BackgroundSource::ColorFgBg => "from COLORFGBG",
+            BackgroundSource::Terminal => "from terminal background",
+            BackgroundSource::Default => "default",
+        }
+    }
+}
+
+/// Settle on a background: the configured one if any, else detect it.
+pub fn resolve_background(configured: Option<Background>) -> (Background, BackgroundSource) {
+    if let Some(background) = configured {
+        return (background, BackgroundSource::Configured);
+    }
+    detect_background().unwrap_or((Background::Dark, BackgroundSource::Default))
+}
+
+/// Detect the terminal background, or `None` when stdout isn't a colored terminal
+/// or the terminal doesn't say.
+pub fn detect_background() -> Option<(Background, BackgroundSource)> {
+    let stdout = std::io::stdout();
+    if !stdout.is_terminal() || anstream::AutoStream::choice(&stdout) == ColorChoice::Never {
+        return None;
+    }
+    if let Some(background) = std::env::var("COLORFGBG")
+        .ok()
+        .as_deref()
+        .and_then(parse_colorfgbg)
+    {
+        return Some((background, BackgroundSource::ColorFgBg));
+    }
+    query_terminal().map(|background| (background, BackgroundSource::Terminal))
+}
+
+/// Parse `COLORFGBG` (`"15;0"`, or `"0;default;15"` from rxvt): the last field
+/// is the background's ANSI color index. 7 (white) and 9–15 (bright colors
+/// other than bright black) are light.
+fn parse_colorfgbg(value: &str) -> Option<Background> {
+    let index: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    match index {
+        7 | 9..=15 => Some(Background::Light),
+        0..=6 | 8 => Some(Background::Dark),
+        _ => None,
+    }
+}
+
+/// Parse an OSC 11 reply such as `ESC ] 11 ; rgb:fdfd/f6f6/e3e3 BEL`.
+///
+/// Channels have one to four hex digits each. Light means a relative
+/// luminance above one half.
+fn parse_osc11_reply(reply: &str) -> Option<Background> {
+    let start = reply.find("]11;")? + "]11;".len();
+    let spec = reply[start..].strip_prefix("rgb:")?;
+    let end = spec
+        .find(|c: char| !(c.is_ascii_hexdigit() || c == '/'))
+        .unwrap_or(spec.len());
+    let mut channels = spec[..end].split('/').map(|hex| {
+        if hex.is_empty() || hex.len() > 4 {
+            return None;
+        }
+        let max = (1u32 << (4 * hex.len())) - 1;
+        Some(u32::from_str_radix(hex, 16).ok()? as f64 / max as f64)
+    });
+    let (r, g, b) = (channels.next()??, channels.next()??, channels.next()??);
+    let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    Some(if luminance > 0.5 {
+        Background::Light
+    } else {
+        Background::Dark
+    })
+}
+
+/// Ask the terminal for its background color over `/dev/tty`.
+#[cfg(unix)]
+fn query_terminal() -> Option<Background> {
+    use std::io::Write;
+
+    let mut tty = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    // Raw mode keeps the reply from echoing and makes it readable without a newline
+    crossterm::terminal::enable_raw_mode().ok()?;
+    let reply = tty
+        .write_all(b"\x1b]11;?\x07\x1b[c")
+        .and_then(|()| tty.flush())
+        .ok()
+        .and_then(|()| read_reply(&mut tty));
+    let _ = crossterm::terminal::disable_raw_mode();
+    parse_osc11_reply(&reply?)
+}
+
+#[cfg(not(unix))]
+fn query_terminal() -> Option<Background> {
+    None
+}
+
+/// Read until the DA1 reply (`ESC [ ? … c`) arrives or the timeout passes.
+///
+/// The OSC 11 reply, when there is one, comes before it.
+#[cfg(unix)]
+fn read_reply(tty: &mut std::fs::File) -> Option<String> {
+    use std::io::Read;
+    use std::os::fd::AsFd;
+    use std::time::Instant;
+
+    use nix::poll::{PollFd, PollFlags, PollTimeout, poll};
+
+    let deadline = Instant::now() + QUERY_TIMEOUT;
+    let mut reply = Vec::new();
+    let mut chunk = [0u8; 64];
+    loop {
+        let remaining = deadline.checked_duration_since(Instant::now())?;
+        let timeout = PollTimeout::try_from(remaining).ok()?;
+        let mut fds = [PollFd::new(tty.as_fd(), PollFlags::POLLIN)];
+        if poll(&mut fds, timeout).ok()? == 0 {
+            return None;
+        }
+        let n = tty.read(&mut chunk).ok()?;
+        if n == 0 {
+            return None;
+        }
+        reply.extend_from_slice(&chunk[..n]);
+        let text = String::from_utf8_lossy(&reply);
+        if let Some(da1) = text.find("\x1b[?")
+            && text[da1..].contains('c')
+        {
+            return Some(text.into_owned());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_colorfgbg() {
+        assert_eq!(parse_colorfgbg("15;0"), Some(Background::Dark));
+        assert_eq!(parse_colorfgbg("0;15"), Some(Background::Light));
+        assert_eq!(parse_colorfgbg("0;default;15"), Some(Background::Light));
+        assert_eq!(parse_colorfgbg("12;8"), Some(Background::Dark));
+        assert_eq!(parse_colorfgbg("0;7"), Some(Background::Light));
+        assert_eq!(parse_colorfgbg("15;default"), None);
+        assert_eq!(parse_colorfgbg(""), None);
+    }
+
+    #[test]
+    fn test_parse_osc11_reply() {
+        // Solarized light, BEL-terminated, followed by the DA1 reply
+        assert_eq!(
+            parse_osc11_reply("\x1b]11;rgb:fdfd/f6f6/e3e3\x07\x1b[?62;22c"),
+            Some(Background::Light)
+        );
+        // ST-terminated
+        assert_eq!(
+            parse_osc11_reply("\x1b]11;rgb:0000/2b2b/3636\x1b\\"),
+            Some(Background::Dark)
+        );
+        // Two-digit channels
+        assert_eq!(
+            parse_osc11_reply("\x1b]11;rgb:ff/ff/ff\x07"),
+            Some(Background::Light)
+        );
+        // Only the DA1 reply: no OSC 11 support
+        assert_eq!(parse_osc11_reply("\x1b[?1;2c"), None);
+        assert_eq!(parse_osc11_reply("\x1b]11;rgb:ffff/ffff\x07"), None);
+    }
+}
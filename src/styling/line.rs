@@ -34,23 +34,47 @@ fn truncate_visible_with_ellipsis(rendered: &str, max_width: usize, ellipsis: &s
         return out;
     }
 
-    let mut cut_at = 0;
-    let mut width = 0;
-    for (i, ch) in plain_str.char_indices() {
-        let w = UnicodeWidthChar::width(ch).unwrap_or(0);
-        if width + w > budget {
-            break;
-        }
-        width += w;
-        cut_at = i + ch.len_utf8();
-    }
-
+    let cut_at = take_width_from_start(plain_str, budget).len();
     let mut out = rendered.ansi_cut(..cut_at).into_owned();
     out.push_str(ellipsis);
     out.push_str("\u{1b}[0m");
     out
 }
 
+/// Longest prefix of `text` whose display width is at most `width`.
+///
+/// Measures each candidate prefix as a whole rather than summing character
+/// widths: a variation selector or joiner changes the width of the character
+/// before it (`⚠` is 1 column, `⚠\u{FE0F}` is 2), so per-character sums
+/// disagree with [`UnicodeWidthStr::width`] and a cut made that way can
+/// overflow the column it was meant to fit.
+pub fn take_width_from_start(text: &str, width: usize) -> &str {
+    let mut fits = 0;
+    for (idx, ch) in text.char_indices() {
+        let end = idx + ch.len_utf8();
+        if text[..end].width() > width {
+            break;
+        }
+        fits = end;
+    }
+    &text[..fits]
+}
+
+/// Longest suffix of `text` whose display width is at most `width`.
+///
+/// The mirror of [`take_width_from_start`], measured the same way. Doesn't
+/// start with a combining mark or selector cut off from its character.
+pub fn take_width_from_end(text: &str, width: usize) -> &str {
+    let mut fits = text.len();
+    for (idx, _) in text.char_indices().rev() {
+        if text[idx..].width() > width {
+            break;
+        }
+        fits = idx;
+    }
+    text[fits..].trim_start_matches(|ch: char| ch.width() == Some(0))
+}
+
 /// A piece of text with an optional style
 #[derive(Clone, Debug)]
 pub struct StyledString {
@@ -226,6 +250,25 @@ mod tests {
         assert!(visible_width(&out) <= 1);
     }
 
+    /// Prefixes and suffixes are measured whole, so a variation selector
+    /// widening the character before it counts.
+    #[test]
+    fn test_take_width_measures_sequences() {
+        // "⚠" alone is 1 column, with VS16 it's 2
+        assert_eq!(take_width_from_start("a⚠\u{FE0F}b", 2), "a⚠");
+        assert_eq!(take_width_from_start("a⚠\u{FE0F}b", 3), "a⚠\u{FE0F}");
+        assert_eq!(take_width_from_end("a⚠\u{FE0F}b", 2), "b");
+        assert_eq!(take_width_from_end("a⚠\u{FE0F}b", 3), "⚠\u{FE0F}b");
+
+        // Wide characters never split
+        assert_eq!(take_width_from_start("日本語", 3), "日");
+        assert_eq!(take_width_from_end("日本語", 3), "語");
+        assert_eq!(take_width_from_start("abc", 0), "");
+
+        let out = truncate_visible("\u{1b}[31mwarn ⚠\u{FE0F}\u{1b}[0m", 6);
+        assert_eq!(UnicodeWidthStr::width(out.ansi_strip().as_ref()), 6);
+    }
+
     /// StyledLine composition: push, extend, render, plain_text all produce correct output.
     #[test]
     fn test_styled_line_composition() {
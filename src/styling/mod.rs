@@ -13,12 +13,15 @@
 //! This separation allows piping (`wt list | grep foo`) without status messages interfering.
 //! Use `println!` for primary output, `eprintln!` for status messages.
 
+mod background;
 mod constants;
 mod format;
 mod highlighting;
 mod hyperlink;
 mod line;
 mod suggest;
+mod terminal;
+mod theme;
 
 use ansi_str::AnsiStr;
 use unicode_width::UnicodeWidthStr;
@@ -30,14 +33,21 @@ pub use anstream::{eprint, eprintln, print, println, stderr, stdout};
 pub use anstyle::Style as AnstyleStyle;
 
 // Re-export our public types
+pub use background::{Background, BackgroundSource, detect_background, resolve_background};
 pub use constants::*;
 #[cfg(all(test, feature = "syntax-highlighting"))]
 pub(crate) use format::format_bash_with_gutter_at_width;
 pub use format::{GUTTER_OVERHEAD, format_bash_with_gutter, format_with_gutter, wrap_styled_text};
 pub use highlighting::format_toml;
 pub use hyperlink::{Stream, hyperlink_stdout, strip_osc8_hyperlinks, supports_hyperlinks};
-pub use line::{StyledLine, StyledString, truncate_visible};
+pub use line::{
+    StyledLine, StyledString, take_width_from_end, take_width_from_start, truncate_visible,
+};
 pub use suggest::{suggest_command, suggest_command_in_dir};
+pub use terminal::{TerminalGuard, restore_terminal};
+pub use theme::{
+    DEFAULT_BEHIND_CRITICAL, DEFAULT_BEHIND_WARN, Theme, parse_style, set_theme, theme,
+};
 
 // ============================================================================
 // Verbosity
@@ -65,6 +75,9 @@ pub fn verbosity() -> u8 {
     VERBOSITY.load(Ordering::Relaxed)
 }
 
+/// Terminal width used by `--deterministic` when `COLUMNS` isn't set.
+pub const DETERMINISTIC_TERMINAL_WIDTH: usize = 120;
+
 /// Get terminal width, or `usize::MAX` if detection fails.
 ///
 /// Prefers direct terminal size detection over COLUMNS environment variable,
@@ -75,7 +88,18 @@ pub fn verbosity() -> u8 {
 /// When detection fails (piped context, no TTY), returns `usize::MAX` rather than
 /// an arbitrary default. Callers that need width-based formatting will produce
 /// full output, letting the consumer handle truncation.
+///
+/// Under `--deterministic`, returns `COLUMNS` if set, otherwise
+/// [`DETERMINISTIC_TERMINAL_WIDTH`].
 pub fn get_terminal_width() -> usize {
+    // --deterministic: output must not depend on the terminal it ran in
+    if crate::utils::is_deterministic() {
+        return std::env::var("COLUMNS")
+            .ok()
+            .and_then(|cols| cols.parse().ok())
+            .unwrap_or(DETERMINISTIC_TERMINAL_WIDTH);
+    }
+
     // Prefer direct terminal detection (more accurate than COLUMNS which may be stale/wrong)
     // Check stderr first (status messages), then stdout (table output)
     if let Some((terminal_size::Width(w), _)) =
@@ -0,0 +1,48 @@
+//! Restoring terminal state after interrupted or panicking work.
+
+use std::io::{IsTerminal, Write};
+
+use crossterm::{ExecutableCommand, cursor::Show, terminal};
+
+/// Restores the terminal when dropped during a panic or after [`TerminalGuard::dirty`].
+///
+/// Child processes killed mid-run (npm spinners, progress bars) and the
+/// interactive picker can leave the cursor hidden or raw mode enabled. Holding
+/// a guard across that work shows the cursor and leaves raw mode on the way
+/// out, including while unwinding. A clean exit touches nothing, so normal
+/// output carries no extra escape sequences.
+#[derive(Debug, Default)]
+pub struct TerminalGuard {
+    dirty: bool,
+}
+
+impl TerminalGuard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark the terminal as possibly left in a bad state, so drop restores it.
+    pub fn dirty(&mut self) {
+        self.dirty = true;
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        if self.dirty || std::thread::panicking() {
+            restore_terminal();
+        }
+    }
+}
+
+/// Show the cursor and leave raw mode, if attached to a terminal.
+pub fn restore_terminal() {
+    if terminal::is_raw_mode_enabled().unwrap_or(false) {
+        let _ = terminal::disable_raw_mode();
+    }
+    let mut stderr = std::io::stderr();
+    if stderr.is_terminal() {
+        let _ = stderr.execute(Show);
+        let _ = stderr.flush();
+    }
+}
@@ -0,0 +1,233 @@
+//! Severity colors for numeric table cells.
+//!
+//! `wt list` colors ahead/behind counts, diff line counts and conflict markers
+//! by severity. The styles live in a process-wide [`Theme`], installed once from
+//! the `[theme]` user config section and read at render time. Unset keys come
+//! from the built-in dark or light theme, chosen by the terminal background. Width calculations
+//! always happen on the plain text, so swapping colors never changes alignment;
+//! anstream strips the codes entirely when color is disabled (`NO_COLOR`, pipes).
+
+use std::sync::OnceLock;
+
+use anstyle::{AnsiColor, Color, Effects, RgbColor, Style};
+
+use super::background::Background;
+use super::constants::{ADDITION, DELETION};
+
+/// Default behind count at which the behind style escalates to `behind-warn`
+pub const DEFAULT_BEHIND_WARN: usize = 10;
+
+/// Default behind count at which the behind style escalates to `behind-critical`
+pub const DEFAULT_BEHIND_CRITICAL: usize = 50;
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Styles for each severity level, plus the behind-count thresholds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// Commits ahead of the default branch
+    pub ahead: Style,
+    /// Commits behind, below the warn threshold
+    pub behind: Style,
+    /// Commits behind, at or above `behind_warn_at`
+    pub behind_warn: Style,
+    /// Commits behind, at or above `behind_critical_at`
+    pub behind_critical: Style,
+    /// Added lines in diff columns
+    pub added: Style,
+    /// Deleted lines in diff columns
+    pub deleted: Style,
+    /// Conflict markers
+    pub conflicts: Style,
+    pub behind_warn_at: usize,
+    pub behind_critical_at: usize,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// Built-in theme for dark backgrounds.
+    pub fn dark() -> Self {
+        Self {
+            ahead: ADDITION,
+            behind: DELETION.dimmed(),
+            behind_warn: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Yellow))),
+            behind_critical: DELETION,
+            added: ADDITION,
+            deleted: DELETION,
+            conflicts: DELETION.bold(),
+            behind_warn_at: DEFAULT_BEHIND_WARN,
+            behind_critical_at: DEFAULT_BEHIND_CRITICAL,
+        }
+    }
+
+    /// Built-in theme for light backgrounds.
+    ///
+    /// Dimmed text and yellow all but vanish on light backgrounds, so the
+    /// behind levels escalate through plain red, magenta and bold red instead.
+    pub fn light() -> Self {
+        Self {
+            behind: DELETION,
+            behind_warn: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Magenta))),
+            behind_critical: DELETION.bold(),
+            ..Self::dark()
+        }
+    }
+
+    /// The built-in theme for `background`.
+    pub fn for_background(background: Background) -> Self {
+        match background {
+            Background::Dark => Self::dark(),
+            Background::Light => Self::light(),
+        }
+    }
+
+    /// Style for a behind count, escalating as it crosses each threshold.
+    pub fn behind_style(&self, behind: usize) -> Style {
+        self.escalated_behind(behind).unwrap_or(self.behind)
+    }
+
+    /// The warn or critical style once a behind count reaches its threshold.
+    ///
+    /// `None` below `behind_warn_at`, so callers can keep their own base style.
+    pub fn escalated_behind(&self, behind: usize) -> Option<Style> {
+        if behind >= self.behind_critical_at {
+            Some(self.behind_critical)
+        } else if behind >= self.behind_warn_at {
+            Some(self.behind_warn)
+        } else {
+            None
+        }
+    }
+}
+
+/// Install the global theme.
+///
+/// Call this once, before rendering. Later calls are ignored.
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// The global theme, or the defaults if none was installed.
+pub fn theme() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+/// Parse a style spec such as `"red"`, `"yellow bold"`, `"208"` or `"#ff8800 dim"`.
+///
+/// Tokens are whitespace-separated: at most one color (ANSI name, 256-color
+/// index, or `#rrggbb`) plus any of `bold`, `dim`, `italic`, `underline`.
+pub fn parse_style(spec: &str) -> Result<Style, String> {
+    let mut style = Style::new();
+    let mut has_color = false;
+    for token in spec.split_whitespace() {
+        let effect = match token {
+            "bold" => Some(Effects::BOLD),
+            "dim" => Some(Effects::DIMMED),
+            "italic" => Some(Effects::ITALIC),
+            "underline" => Some(Effects::UNDERLINE),
+            _ => None,
+        };
+        if let Some(effect) = effect {
+            style = style.effects(style.get_effects() | effect);
+            continue;
+        }
+        if has_color {
+            return Err(format!("'{spec}' has more than one color"));
+        }
+        let color = parse_color(token).ok_or_else(|| format!("unknown color '{token}'"))?;
+        style = style.fg_color(Some(color));
+        has_color = true;
+    }
+    if !has_color && style.get_effects().is_plain() {
+        return Err("empty style".to_string());
+    }
+    Ok(style)
+}
+
+fn parse_color(token: &str) -> Option<Color> {
+    if let Some(hex) = token.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::Rgb(RgbColor(channel(0)?, channel(2)?, channel(4)?)));
+    }
+    if let Ok(index) = token.parse::<u8>() {
+        return Some(Color::Ansi256(index.into()));
+    }
+    let ansi = match token {
+        "black" => AnsiColor::Black,
+        "red" => AnsiColor::Red,
+        "green" => AnsiColor::Green,
+        "yellow" => AnsiColor::Yellow,
+        "blue" => AnsiColor::Blue,
+        "magenta" => AnsiColor::Magenta,
+        "cyan" => AnsiColor::Cyan,
+        "white" => AnsiColor::White,
+        "bright-black" => AnsiColor::BrightBlack,
+        "bright-red" => AnsiColor::BrightRed,
+        "bright-green" => AnsiColor::BrightGreen,
+        "bright-yellow" => AnsiColor::BrightYellow,
+        "bright-blue" => AnsiColor::BrightBlue,
+        "bright-magenta" => AnsiColor::BrightMagenta,
+        "bright-cyan" => AnsiColor::BrightCyan,
+        "bright-white" => AnsiColor::BrightWhite,
+        _ => return None,
+    };
+    Some(Color::Ansi(ansi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_behind_style_thresholds() {
+        let theme = Theme::default();
+        assert_eq!(theme.behind_style(0), theme.behind);
+        assert_eq!(theme.behind_style(9), theme.behind);
+        assert_eq!(theme.behind_style(10), theme.behind_warn);
+        assert_eq!(theme.behind_style(49), theme.behind_warn);
+        assert_eq!(theme.behind_style(50), theme.behind_critical);
+        assert_eq!(theme.behind_style(5000), theme.behind_critical);
+        assert_eq!(theme.escalated_behind(9), None);
+
+        let custom = Theme {
+            behind_warn_at: 3,
+            behind_critical_at: 5,
+            ..Theme::default()
+        };
+        assert_eq!(custom.behind_style(2), custom.behind);
+        assert_eq!(custom.behind_style(3), custom.behind_warn);
+        assert_eq!(custom.behind_style(5), custom.behind_critical);
+    }
+
+    #[test]
+    fn test_parse_style() {
+        assert_eq!(parse_style("red"), Ok(DELETION));
+        assert_eq!(parse_style("red bold"), Ok(DELETION.bold()));
+        assert_eq!(parse_style("bold red"), Ok(DELETION.bold()));
+        assert_eq!(
+            parse_style("208"),
+            Ok(Style::new().fg_color(Some(Color::Ansi256(208.into()))))
+        );
+        assert_eq!(
+            parse_style("#ff8800 dim"),
+            Ok(Style::new()
+                .fg_color(Some(Color::Rgb(RgbColor(0xff, 0x88, 0x00))))
+                .dimmed())
+        );
+        assert_eq!(parse_style("bold"), Ok(Style::new().bold()));
+
+        assert!(parse_style("").is_err());
+        assert!(parse_style("reddish").is_err());
+        assert!(parse_style("red green").is_err());
+        assert!(parse_style("#ff88").is_err());
+        assert!(parse_style("256").is_err());
+    }
+}
@@ -15,6 +15,7 @@ use std::sync::LazyLock;
 use color_print::cformat;
 use minijinja::Environment;
 use serde::{Deserialize, Serialize};
+use worktrunk::config::state_file;
 use worktrunk::git::Repository;
 use worktrunk::path::sanitize_for_filename;
 use worktrunk::sync::Semaphore;
@@ -85,34 +86,13 @@ pub(crate) fn read_cache(repo: &Repository, branch: &str) -> Option<CachedSummar
 pub(crate) fn write_cache(repo: &Repository, branch: &str, cached: &CachedSummary) {
     let path = cache_file(repo, branch);
 
-    if let Some(parent) = path.parent()
-        && let Err(e) = fs::create_dir_all(parent)
-    {
-        log::debug!("Failed to create summary cache dir for {}: {}", branch, e);
-        return;
-    }
-
     let Ok(json) = serde_json::to_string(cached) else {
         log::debug!("Failed to serialize summary cache for {}", branch);
         return;
     };
 
-    let temp_path = path.with_extension("json.tmp");
-    if let Err(e) = fs::write(&temp_path, &json) {
-        log::debug!(
-            "Failed to write summary cache temp file for {}: {}",
-            branch,
-            e
-        );
-        return;
-    }
-
-    #[cfg(windows)]
-    let _ = fs::remove_file(&path);
-
-    if let Err(e) = fs::rename(&temp_path, &path) {
-        log::debug!("Failed to rename summary cache file for {}: {}", branch, e);
-        let _ = fs::remove_file(&temp_path);
+    if let Err(e) = state_file::write_atomic(&path, json) {
+        log::debug!("Failed to write summary cache for {}: {}", branch, e);
     }
 }
 
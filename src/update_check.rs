@@ -0,0 +1,272 @@
+//! Opt-in check for newer releases.
+//!
+//! With `update-check = true`, the first successful command of the day starts
+//! a detached `wt` that asks the GitHub releases API for the latest version,
+//! so no command waits on the network. A later command mentions a newer
+//! release on stderr, once per result. The time and result of the last check
+//! live in `<cache dir>/worktrunk/update-check.json`, which
+//! `wt config show --full` reports.
+
+use std::io::IsTerminal;
+use std::path::PathBuf;
+
+use color_print::cformat;
+use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
+use serde::{Deserialize, Serialize};
+use worktrunk::config::UserConfig;
+use worktrunk::config::state_file::{self, StateFile};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::utils::{get_now, is_offline};
+
+/// Minimum time between two background checks.
+const CHECK_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+/// Set on the detached `wt` that performs the check, instead of a command.
+const WORKER_ENV_VAR: &str = "WORKTRUNK_UPDATE_CHECK_WORKER";
+
+/// Page linked from the notice.
+const RELEASES_URL: &str = "https://github.com/max-sixty/worktrunk/releases/latest";
+
+/// Outcome of the last background check.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UpdateCheckState {
+    /// Unix timestamp of the check
+    pub checked_at: u64,
+    /// Latest released version, or `None` if the request failed
+    pub latest: Option<String>,
+    /// Whether a command has already mentioned `latest`
+    #[serde(default)]
+    pub announced: bool,
+}
+
+impl StateFile for UpdateCheckState {
+    const VERSION: u32 = 1;
+}
+
+impl UpdateCheckState {
+    /// Whether enough time has passed since this check to run another.
+    fn is_due(&self, now: u64) -> bool {
+        now.saturating_sub(self.checked_at) >= CHECK_INTERVAL_SECS
+    }
+}
+
+/// Path of the state file, in the platform cache directory.
+fn state_path() -> Option<PathBuf> {
+    let strategy = choose_base_strategy().ok()?;
+    Some(
+        strategy
+            .cache_dir()
+            .join("worktrunk")
+            .join("update-check.json"),
+    )
+}
+
+/// Read the outcome of the last background check, if any.
+pub(crate) fn read_state() -> Option<UpdateCheckState> {
+    state_file::read(&state_path()?)
+}
+
+/// Update the state file under its lock, logging rather than surfacing errors.
+fn update_state<R>(mutate: impl FnOnce(&mut UpdateCheckState) -> R) -> Option<R> {
+    state_file::update(&state_path()?, mutate)
+        .inspect_err(|e| log::debug!("Failed to write update check state: {e}"))
+        .ok()
+}
+
+/// Mention a newer release found by an earlier check, then start today's
+/// check in the background if it's enabled and due.
+///
+/// Called once the command has finished and its output is flushed. Nothing
+/// here waits on the network. Skipped under `--offline`, in CI, and when
+/// stdout or stderr isn't a terminal (shell init, statusline and piped output
+/// shouldn't grow an extra line).
+pub(crate) fn run_if_due() {
+    if is_offline() || std::env::var_os("CI").is_some() {
+        return;
+    }
+    if !std::io::stdout().is_terminal() || !std::io::stderr().is_terminal() {
+        return;
+    }
+    let Ok(config) = UserConfig::load() else {
+        return;
+    };
+    if !config.update_check {
+        return;
+    }
+
+    let unannounced = update_state(|state| {
+        let latest = state.latest.clone().filter(|latest| {
+            !state.announced && is_newer_version(latest, env!("CARGO_PKG_VERSION"))
+        });
+        state.announced = true;
+        latest
+    });
+    if let Some(Some(latest)) = unannounced {
+        eprintln!(
+            "{}",
+            cformat!(
+                "<dim>worktrunk {latest} is available (current: {}): {RELEASES_URL}</>",
+                crate::cli::version_str()
+            )
+        );
+    }
+
+    // Claim today's check before starting it, so concurrent commands don't
+    // each fetch
+    let now = get_now();
+    let claimed = update_state(|state| {
+        if !state.is_due(now) {
+            return false;
+        }
+        state.checked_at = now;
+        true
+    });
+    if claimed == Some(true) {
+        spawn_worker();
+    }
+}
+
+/// Start a detached `wt` to run the check, without waiting for it.
+fn spawn_worker() {
+    let Ok(exe) = std::env::current_exe() else {
+        return;
+    };
+    let mut cmd = std::process::Command::new(exe);
+    cmd.env(WORKER_ENV_VAR, "1")
+        .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    // Out of the terminal's process group, so closing it doesn't stop the check
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+    #[cfg(windows)]
+    std::os::windows::process::CommandExt::creation_flags(&mut cmd, 0x00000208);
+    if let Err(e) = cmd.spawn() {
+        log::debug!("Failed to start update check: {e}");
+    }
+}
+
+/// Perform the check if this process is the detached worker.
+///
+/// Returns true when it was, and the process should exit without running a
+/// command.
+pub(crate) fn maybe_run_worker() -> bool {
+    if std::env::var_os(WORKER_ENV_VAR).is_none() {
+        return false;
+    }
+    let latest = fetch_latest_version(10)
+        .inspect_err(|e| log::debug!("Update check failed: {e}"))
+        .ok();
+    update_state(|state| {
+        if state.latest != latest {
+            state.announced = false;
+        }
+        state.latest = latest;
+    });
+    true
+}
+
+/// Fetch the latest release version from GitHub, giving up after `max_time` seconds.
+pub(crate) fn fetch_latest_version(max_time: u32) -> anyhow::Result<String> {
+    // Allow tests to inject a version without network access.
+    // Set to "error" to simulate a fetch failure.
+    if let Ok(version) = std::env::var("WORKTRUNK_TEST_LATEST_VERSION") {
+        if version == "error" {
+            anyhow::bail!("simulated fetch failure");
+        }
+        return Ok(version);
+    }
+
+    let user_agent = format!(
+        "worktrunk/{} (https://worktrunk.dev)",
+        env!("CARGO_PKG_VERSION")
+    );
+    let output = Cmd::new("curl")
+        .args([
+            "--silent",
+            "--fail",
+            "--max-time",
+            &max_time.to_string(),
+            "--header",
+            &format!("User-Agent: {user_agent}"),
+            "https://api.github.com/repos/max-sixty/worktrunk/releases/latest",
+        ])
+        .network()
+        .run()?;
+
+    if !output.status.success() {
+        anyhow::bail!("GitHub API request failed");
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let tag = json["tag_name"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("missing tag_name in response"))?;
+
+    // Strip leading 'v' prefix (e.g., "v0.23.2" -> "0.23.2")
+    Ok(tag.strip_prefix('v').unwrap_or(tag).to_string())
+}
+
+/// Compare two semver version strings (e.g., "0.24.0" > "0.23.2")
+pub(crate) fn is_newer_version(latest: &str, current: &str) -> bool {
+    let parse = |s: &str| -> Option<(u32, u32, u32)> {
+        let mut parts = s.splitn(3, '.');
+        Some((
+            parts.next()?.parse().ok()?,
+            parts.next()?.parse().ok()?,
+            parts.next()?.parse().ok()?,
+        ))
+    };
+    match (parse(latest), parse(current)) {
+        (Some(l), Some(c)) => l > c,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer_version() {
+        // Newer versions
+        assert!(is_newer_version("0.24.0", "0.23.2"));
+        assert!(is_newer_version("1.0.0", "0.99.99"));
+        assert!(is_newer_version("0.23.3", "0.23.2"));
+        assert!(is_newer_version("0.23.2", "0.23.1"));
+
+        // Same version
+        assert!(!is_newer_version("0.23.2", "0.23.2"));
+
+        // Older versions
+        assert!(!is_newer_version("0.23.1", "0.23.2"));
+        assert!(!is_newer_version("0.22.0", "0.23.2"));
+
+        // Invalid input
+        assert!(!is_newer_version("invalid", "0.23.2"));
+        assert!(!is_newer_version("0.23.2", "invalid"));
+    }
+
+    #[test]
+    fn test_update_check_state_without_announced() {
+        // State written before `announced` existed still loads
+        let state: UpdateCheckState =
+            serde_json::from_str(r#"{"checked_at":1,"latest":"0.1.0"}"#).unwrap();
+        assert!(!state.announced);
+    }
+
+    #[test]
+    fn test_update_check_is_due() {
+        let state = UpdateCheckState {
+            checked_at: 1_000_000,
+            latest: Some("0.1.0".into()),
+            announced: false,
+        };
+        assert!(!state.is_due(1_000_000));
+        assert!(!state.is_due(1_000_000 + CHECK_INTERVAL_SECS - 1));
+        assert!(state.is_due(1_000_000 + CHECK_INTERVAL_SECS));
+        // A clock that moved backwards doesn't trigger a check
+        assert!(!state.is_due(0));
+    }
+}
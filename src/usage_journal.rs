@@ -0,0 +1,238 @@
+//! Opt-in local usage journal.
+//!
+//! With `usage-journal = true`, each command appends one line to
+//! `<data dir>/worktrunk/usage.jsonl`: the subcommand, the names of the flags
+//! given, how long it took, and whether it succeeded. Argument values —
+//! branch names, paths, commands — are never recorded, and nothing leaves the
+//! machine. `wt report` summarizes the file.
+//!
+//! The entry is written after the command's output is flushed, as a single
+//! append. Commands that replace the process (`--execute` without shell
+//! integration) end before that point and go unrecorded.
+//!
+//! # Growth control
+//!
+//! Before each write, the file size is checked. If >256KB, the current file is
+//! renamed to `usage.jsonl.old` and a fresh file is started. This bounds
+//! storage to ~512KB worst case, a few thousand commands.
+
+use std::collections::BTreeSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Command};
+use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
+use serde::{Deserialize, Serialize};
+use worktrunk::config::UserConfig;
+use worktrunk::utils::get_now;
+
+/// Maximum journal size before rotation (256KB).
+const MAX_JOURNAL_SIZE: u64 = 262_144;
+
+/// Command name recorded for user-defined `wt step` aliases, whose names are
+/// the user's own and so are left out.
+const ALIAS_COMMAND: &str = "(alias)";
+
+/// One journal line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UsageEntry {
+    /// Unix timestamp when the command finished
+    pub ts: u64,
+    /// Subcommand path, e.g. `step copy-ignored`
+    pub command: String,
+    /// Long names of the flags given on the command line, sorted
+    pub flags: Vec<String>,
+    pub dur_ms: u64,
+    pub ok: bool,
+}
+
+/// What a command line asked for, reduced to names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Invocation {
+    command: String,
+    flags: Vec<String>,
+}
+
+impl Invocation {
+    /// Read the subcommand path and flag names out of parsed arguments.
+    ///
+    /// Only options and switches given explicitly count (defaults and
+    /// environment variables don't), and only by name; positionals are
+    /// skipped entirely.
+    pub(crate) fn from_matches(cmd: &Command, matches: &ArgMatches) -> Self {
+        let mut path = Vec::new();
+        let mut flags = BTreeSet::new();
+        let (mut cmd, mut matches) = (cmd, matches);
+        loop {
+            for arg in cmd.get_arguments().filter(|arg| !arg.is_positional()) {
+                let id = arg.get_id().as_str();
+                if matches.value_source(id) == Some(ValueSource::CommandLine) {
+                    flags.insert(arg.get_long().unwrap_or(id).to_string());
+                }
+            }
+            let Some((name, sub_matches)) = matches.subcommand() else {
+                break;
+            };
+            match cmd.find_subcommand(name) {
+                Some(sub_cmd) => {
+                    path.push(name.to_string());
+                    cmd = sub_cmd;
+                    matches = sub_matches;
+                }
+                None => {
+                    // External subcommand: a `wt step` alias
+                    path.push(ALIAS_COMMAND.to_string());
+                    break;
+                }
+            }
+        }
+        Self {
+            command: path.join(" "),
+            flags: flags.into_iter().collect(),
+        }
+    }
+}
+
+/// Path of the journal, in the platform data directory.
+pub(crate) fn journal_path() -> Option<PathBuf> {
+    let strategy = choose_base_strategy().ok()?;
+    Some(strategy.data_dir().join("worktrunk").join("usage.jsonl"))
+}
+
+/// Append an entry for a finished command if the journal is enabled.
+///
+/// Best-effort: any failure is logged at debug level and otherwise ignored.
+pub(crate) fn record(invocation: &Invocation, duration: Duration, ok: bool) {
+    if invocation.command.is_empty() {
+        return;
+    }
+    // Quiet: the command already reported any config problems
+    let Ok(config) = UserConfig::load_quiet() else {
+        return;
+    };
+    if !config.usage_journal {
+        return;
+    }
+    let Some(path) = journal_path() else {
+        return;
+    };
+    let entry = UsageEntry {
+        ts: get_now(),
+        command: invocation.command.clone(),
+        flags: invocation.flags.clone(),
+        dur_ms: duration.as_millis() as u64,
+        ok,
+    };
+    if let Err(e) = append(&path, &entry) {
+        log::debug!("Failed to write usage journal: {e}");
+    }
+}
+
+fn append(path: &Path, entry: &UsageEntry) -> std::io::Result<()> {
+    if let Ok(metadata) = fs::metadata(path)
+        && metadata.len() > MAX_JOURNAL_SIZE
+    {
+        fs::rename(path, path.with_extension("jsonl.old"))?;
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Single write_all so each JSON line is written atomically
+    let mut buf = serde_json::to_string(entry)?;
+    buf.push('\n');
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(buf.as_bytes())
+}
+
+/// Read every entry, oldest first, including the rotated file.
+///
+/// Lines that don't parse (a torn write, an entry from a newer wt) are
+/// skipped.
+pub(crate) fn read_entries() -> Vec<UsageEntry> {
+    let Some(path) = journal_path() else {
+        return Vec::new();
+    };
+    [path.with_extension("jsonl.old"), path]
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .flat_map(|contents| {
+            contents
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::FromArgMatches;
+
+    use super::*;
+
+    fn invocation(args: &[&str]) -> Invocation {
+        let mut cmd = crate::cli::build_command();
+        let matches = cmd
+            .try_get_matches_from_mut(std::iter::once("wt").chain(args.iter().copied()))
+            .unwrap();
+        // Same arguments must also parse into the typed CLI
+        crate::cli::Cli::from_arg_matches(&matches).unwrap();
+        Invocation::from_matches(&cmd, &matches)
+    }
+
+    #[test]
+    fn test_invocation_records_names_not_values() {
+        let inv = invocation(&["switch", "--create", "secret-branch", "--base", "main"]);
+        assert_eq!(inv.command, "switch");
+        assert_eq!(inv.flags, ["base", "create"]);
+
+        let inv = invocation(&["-C", "/home/me/project", "list", "--format", "json"]);
+        assert_eq!(inv.command, "list");
+        assert_eq!(inv.flags, ["directory", "format"]);
+        let recorded = format!("{inv:?}");
+        assert!(!recorded.contains("secret") && !recorded.contains("/home/me"));
+    }
+
+    #[test]
+    fn test_invocation_nested_and_alias() {
+        let inv = invocation(&["config", "state", "logs", "get"]);
+        assert_eq!(inv.command, "config state logs get");
+        assert!(inv.flags.is_empty());
+
+        let inv = invocation(&["step", "deploy-to-prod", "--env", "staging"]);
+        assert_eq!(inv.command, "step (alias)");
+        assert!(inv.flags.is_empty());
+    }
+
+    #[test]
+    fn test_append_rotates_at_size_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("usage.jsonl");
+        let filler = "x".repeat(MAX_JOURNAL_SIZE as usize + 1);
+        fs::write(&path, &filler).unwrap();
+
+        let entry = UsageEntry {
+            ts: 1,
+            command: "list".into(),
+            flags: vec![],
+            dur_ms: 5,
+            ok: true,
+        };
+        append(&path, &entry).unwrap();
+
+        let old = fs::read_to_string(path.with_extension("jsonl.old")).unwrap();
+        assert_eq!(old, filler);
+        let line = fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            serde_json::from_str::<UsageEntry>(line.trim()).unwrap(),
+            entry
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! General utilities.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Format a Unix timestamp as ISO 8601 string (e.g., "2025-01-01T00:00:00Z").
@@ -50,6 +51,90 @@ pub fn get_now() -> u64 {
         })
 }
 
+/// Source of "now" for rendering relative times.
+///
+/// Display code takes a clock rather than calling [`get_now`] directly, so
+/// `--deterministic` can pin the reference instant and unit tests can assert
+/// exact strings without sleeping.
+pub trait Clock {
+    /// Current Unix timestamp in seconds.
+    fn now(&self) -> u64;
+}
+
+/// The real clock (honors `WORKTRUNK_TEST_EPOCH`, see [`get_now`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        get_now()
+    }
+}
+
+/// A clock stopped at a fixed instant.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Reference instant for `--deterministic` output (2025-01-02T00:00:00Z).
+///
+/// `WORKTRUNK_TEST_EPOCH` takes precedence when set.
+pub const DETERMINISTIC_EPOCH: u64 = 1735776000;
+
+/// Set by the hidden `--deterministic` flag.
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Enable snapshot-stable rendering for this process (`--deterministic`).
+pub fn set_deterministic() {
+    DETERMINISTIC.store(true, Ordering::Relaxed);
+}
+
+/// Whether output should be snapshot-stable.
+///
+/// True with `--deterministic` or `WORKTRUNK_DETERMINISTIC=1`. Relative times
+/// are then measured from a fixed instant, rows with equal timestamps sort by
+/// name, and the terminal width is fixed unless `COLUMNS` is set.
+pub fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+        || std::env::var("WORKTRUNK_DETERMINISTIC").is_ok_and(|v| v == "1" || v == "true")
+}
+
+/// Set by the global `--offline` flag.
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Forbid network access for this process (`--offline`).
+pub fn set_offline() {
+    OFFLINE.store(true, Ordering::Relaxed);
+}
+
+/// Whether network access is forbidden.
+///
+/// True with `--offline` or `WORKTRUNK_OFFLINE=1`. Every command that would
+/// reach the network is refused in [`Cmd`](crate::shell_exec::Cmd), so features
+/// check this only to fall back quietly instead of failing.
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+        || std::env::var("WORKTRUNK_OFFLINE").is_ok_and(|v| v == "1" || v == "true")
+}
+
+/// Clock for rendering relative times: fixed under `--deterministic`, real otherwise.
+pub fn display_clock() -> Box<dyn Clock> {
+    if is_deterministic() {
+        let epoch = std::env::var("WORKTRUNK_TEST_EPOCH")
+            .ok()
+            .and_then(|val| val.parse::<u64>().ok())
+            .unwrap_or(DETERMINISTIC_EPOCH);
+        Box::new(FixedClock(epoch))
+    } else {
+        Box::new(SystemClock)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,6 +155,11 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fixed_clock() {
+        assert_eq!(FixedClock(DETERMINISTIC_EPOCH).now(), DETERMINISTIC_EPOCH);
+    }
+
     #[test]
     fn test_format_timestamp_iso8601_u64_overflow() {
         // Timestamps exceeding i64::MAX are handled by try_from
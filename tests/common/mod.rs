@@ -395,6 +395,7 @@ pub fn open_pty_with_size(rows: u16, cols: u16) -> portable_pty::PtyPair {
 }
 
 use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -407,159 +408,11 @@ pub fn wt_bin() -> PathBuf {
     PathBuf::from(env!("CARGO_BIN_EXE_wt"))
 }
 use tempfile::TempDir;
-use worktrunk::config::sanitize_branch_name;
 use worktrunk::path::to_posix_path;
-
-/// Path to the standard fixture (relative to crate root).
-/// Contains repo/, repo.feature-a/, repo.feature-b/, repo.feature-c/, origin_git/.
-fn standard_fixture_path() -> PathBuf {
-    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/standard")
-}
-
-/// Worktree info returned from fixture copy.
-struct FixtureWorktrees {
-    worktrees: HashMap<String, PathBuf>,
-    remote: PathBuf,
-}
-
-/// Copy the standard fixture to create a new test repo with worktrees and remote.
-///
-/// The fixture contains:
-/// - Main repo on `main` branch with one commit
-/// - Remote (origin) bare repository
-/// - Three feature worktrees (feature-a, feature-b, feature-c) each with one commit
-///
-/// Pure Rust recursive copy - 2.5x faster than spawning cp/robocopy.
-/// Benchmarked at 21ms vs 53ms per fixture copy on macOS.
-fn copy_standard_fixture(dest: &Path) -> FixtureWorktrees {
-    fn copy_dir_recursive(src: &Path, dest: &Path) {
-        std::fs::create_dir_all(dest).unwrap();
-        for entry in std::fs::read_dir(src).unwrap() {
-            let entry = entry.unwrap();
-            let file_type = entry.file_type().unwrap();
-            let src_path = entry.path();
-            let dest_path = dest.join(entry.file_name());
-            if file_type.is_dir() {
-                copy_dir_recursive(&src_path, &dest_path);
-            } else if file_type.is_file() {
-                std::fs::copy(&src_path, &dest_path).unwrap();
-            }
-            // Skip symlinks, sockets, etc (shouldn't be in fixture)
-        }
-    }
-
-    let fixture = standard_fixture_path();
-    copy_dir_recursive(&fixture, dest);
-
-    // Verify essential directories exist after copy
-    let essential = ["repo/_git", "origin_git", "repo.feature-a/_git"];
-    for path in essential {
-        let full_path = dest.join(path);
-        assert!(
-            full_path.exists(),
-            "Essential fixture path missing after copy: {:?}",
-            full_path
-        );
-    }
-
-    // Rename _git to .git in all locations
-    let renames = [
-        ("repo/_git", "repo/.git"),
-        ("origin_git", "origin.git"),
-        ("repo.feature-a/_git", "repo.feature-a/.git"),
-        ("repo.feature-b/_git", "repo.feature-b/.git"),
-        ("repo.feature-c/_git", "repo.feature-c/.git"),
-    ];
-    for (from, to) in renames {
-        let from_path = dest.join(from);
-        let to_path = dest.join(to);
-        if from_path.exists() {
-            std::fs::rename(&from_path, &to_path).unwrap_or_else(|e| {
-                panic!("Failed to rename {:?} to {:?}: {}", from_path, to_path, e)
-            });
-        }
-    }
-
-    // Verify origin.git is a valid bare repository
-    let origin_git = dest.join("origin.git");
-    assert!(
-        origin_git.join("HEAD").exists(),
-        "origin.git is not a valid git repository (missing HEAD): {:?}",
-        origin_git
-    );
-
-    // Canonicalize dest for worktrees map (on macOS /var -> /private/var)
-    let canonical_dest = canonicalize(dest).unwrap();
-
-    // Fix gitdir files - fixture uses _git which we rename to .git
-    // Paths are relative so no absolute path replacement needed
-    for wt in ["feature-a", "feature-b", "feature-c"] {
-        let gitdir_path = dest.join(format!("repo.{wt}/.git"));
-        if gitdir_path.exists() {
-            let content = std::fs::read_to_string(&gitdir_path).unwrap();
-            let fixed = content.replace("_git", ".git");
-            std::fs::write(&gitdir_path, fixed).unwrap();
-        }
-
-        // Fix main repo's worktree gitdir reference
-        let main_gitdir = dest.join(format!("repo/.git/worktrees/repo.{wt}/gitdir"));
-        if main_gitdir.exists() {
-            let content = std::fs::read_to_string(&main_gitdir).unwrap();
-            let fixed = content.replace("_git", ".git");
-            std::fs::write(&main_gitdir, fixed).unwrap();
-        }
-    }
-
-    // Fix remote URL in config (origin_git -> origin.git)
-    let config_path = dest.join("repo/.git/config");
-    if config_path.exists() {
-        let content = std::fs::read_to_string(&config_path).unwrap();
-        let fixed = content.replace("origin_git", "origin.git");
-        std::fs::write(&config_path, fixed).unwrap();
-    }
-
-    // Build worktrees map using canonical paths
-    let mut worktrees = HashMap::new();
-    for wt in ["feature-a", "feature-b", "feature-c"] {
-        worktrees.insert(wt.to_string(), canonical_dest.join(format!("repo.{wt}")));
-    }
-
-    let remote = canonical_dest.join("origin.git");
-
-    FixtureWorktrees { worktrees, remote }
-}
-
-/// Write a gitconfig file for tests.
-fn write_test_gitconfig(path: &Path) {
-    std::fs::write(
-        path,
-        "[user]\n\tname = Test User\n\temail = test@example.com\n\
-         [advice]\n\tmergeConflict = false\n\tresolveConflict = false\n\
-         [init]\n\tdefaultBranch = main\n\
-         [commit]\n\tgpgsign = false\n\
-         [rerere]\n\tenabled = true\n",
-    )
-    .unwrap();
-}
-
-/// Canonicalize a path without Windows verbatim prefix (`\\?\`).
-///
-/// On Windows, `std::fs::canonicalize()` returns verbatim paths like `\\?\C:\...`
-/// which git cannot handle. The `dunce` crate strips this prefix when safe.
-/// On Unix, this is equivalent to `std::fs::canonicalize()`.
-pub fn canonicalize(path: &Path) -> std::io::Result<PathBuf> {
-    dunce::canonicalize(path)
-}
-
-/// Time constants for `commit_with_age()` - use as `5 * MINUTE`, `2 * HOUR`, etc.
-pub const MINUTE: i64 = 60;
-pub const HOUR: i64 = 60 * MINUTE;
-pub const DAY: i64 = 24 * HOUR;
-pub const WEEK: i64 = 7 * DAY;
-
-/// The epoch used for deterministic timestamps in tests (2025-01-01T00:00:00Z).
-/// Use this when creating test data with timestamps (cache entries, etc.).
-pub const TEST_EPOCH: u64 = 1735776000;
+pub use worktrunk::testing::{
+    DAY, HOUR, MINUTE, NULL_DEVICE, TEST_EPOCH, WEEK, canonicalize, check_git_status,
+    clean_cli_env, configure_git_cmd, set_temp_home_env,
+};
 
 /// Default timeout for background hook/command completion.
 /// Generous to avoid flakiness under CI load; exponential backoff means fast tests when things work.
@@ -585,6 +438,8 @@ const STATIC_TEST_ENV_VARS: &[(&str, &str)] = &[
     // Disable delayed streaming for deterministic output across platforms.
     // Without this, slow CI triggers progress messages that don't appear on faster systems.
     ("WORKTRUNK_TEST_DELAYED_STREAM_MS", "-1"),
+    // Panic on any `wt list` row whose cells drift from the column layout
+    ("WORKTRUNK_ASSERT_LAYOUT", "1"),
 ];
 
 // NOTE: TERM is intentionally NOT in STATIC_TEST_ENV_VARS because:
@@ -592,13 +447,6 @@ const STATIC_TEST_ENV_VARS: &[(&str, &str)] = &[
 // - PTY tests (especially skim-based select tests) need a TERM with valid terminfo
 // - macOS CI doesn't have alacritty terminfo, causing skim to fail
 
-/// Null device path, platform-appropriate.
-/// Use this for GIT_CONFIG_SYSTEM to disable system config in tests.
-#[cfg(windows)]
-const NULL_DEVICE: &str = "NUL";
-#[cfg(not(windows))]
-const NULL_DEVICE: &str = "/dev/null";
-
 /// Create a `wt` CLI command with standardized test environment settings.
 ///
 /// The command has the following guarantees:
@@ -678,33 +526,11 @@ pub fn configure_completion_invocation_for_shell(cmd: &mut Command, words: &[&st
 /// - This function enables RUST_LOG=warn; PTY tests don't (too noisy in combined output)
 /// - This function clears host GIT_*/WORKTRUNK_* vars; PTY tests start with clean env
 pub fn configure_cli_command(cmd: &mut Command) {
-    for (key, _) in std::env::vars() {
-        if key.starts_with("GIT_") || key.starts_with("WORKTRUNK_") {
-            cmd.env_remove(&key);
-        }
-    }
-    // Prevent host environment from disabling ANSI in snapshots.
-    // NO_COLOR can override CLICOLOR_FORCE in downstream output handling.
-    cmd.env_remove("NO_COLOR");
-    // Set to non-existent path to prevent loading user's real config.
+    // Clear host GIT_*/WORKTRUNK_* vars and point config at non-existent paths.
     // Tests that need config should use TestRepo::configure_wt_cmd() which overrides this.
-    // Note: env_remove above may cause insta-cmd to capture empty values in snapshots,
+    // Note: env_remove may cause insta-cmd to capture empty values in snapshots,
     // but correctness (isolating from host WORKTRUNK_* vars) trumps snapshot aesthetics.
-    cmd.env("WORKTRUNK_CONFIG_PATH", "/nonexistent/test/config.toml");
-    cmd.env(
-        "WORKTRUNK_SYSTEM_CONFIG_PATH",
-        "/etc/xdg/worktrunk/config.toml",
-    );
-    cmd.env(
-        "WORKTRUNK_APPROVALS_PATH",
-        "/nonexistent/test/approvals.toml",
-    );
-    // Remove $SHELL to avoid platform-dependent diagnostic output (macOS has /bin/zsh,
-    // Linux has /bin/bash). Tests that need SHELL should set it explicitly.
-    cmd.env_remove("SHELL");
-    // Remove PSModulePath to prevent false PowerShell detection on CI environments
-    // where PowerShell Core is installed but not being used.
-    cmd.env_remove("PSModulePath");
+    clean_cli_env(cmd);
     // Disable auto PowerShell detection (tests that need it should set to "1")
     cmd.env("WORKTRUNK_TEST_POWERSHELL_ENV", "0");
     // Disable auto nushell detection (tests that need it should set to "1")
@@ -742,28 +568,6 @@ pub fn configure_cli_command(cmd: &mut Command) {
     }
 }
 
-/// Configure a git command with isolated environment for testing.
-///
-/// Sets environment variables for:
-/// - Isolated git config (using provided path or /dev/null)
-/// - Deterministic commit timestamps
-/// - Consistent locale settings
-/// - No terminal prompts
-///
-/// # Arguments
-/// * `cmd` - The git Command to configure
-/// * `git_config_path` - Path to git config file (use `/dev/null` or `NULL_DEVICE` for none)
-pub fn configure_git_cmd(cmd: &mut Command, git_config_path: &Path) {
-    cmd.env("GIT_CONFIG_GLOBAL", git_config_path);
-    cmd.env("GIT_CONFIG_SYSTEM", NULL_DEVICE);
-    cmd.env("GIT_AUTHOR_DATE", "2025-01-01T00:00:00Z");
-    cmd.env("GIT_COMMITTER_DATE", "2025-01-01T00:00:00Z");
-    cmd.env("LC_ALL", "C");
-    cmd.env("LANG", "C");
-    cmd.env("WORKTRUNK_TEST_EPOCH", TEST_EPOCH.to_string());
-    cmd.env("GIT_TERMINAL_PROMPT", "0");
-}
-
 /// Shared interface for test repository fixtures.
 ///
 /// Provides `configure_git_cmd()`, `git_command()`, and `run_git_in()` with consistent
@@ -977,26 +781,6 @@ pub fn shell_command(
     cmd
 }
 
-/// Set home environment variables for commands that rely on isolated temp homes.
-///
-/// Sets both Unix (`HOME`, `XDG_CONFIG_HOME`) and Windows (`USERPROFILE`) variables
-/// so the `home` crate can find the temp home directory on all platforms.
-///
-/// Canonicalizes the path on macOS to handle `/var` → `/private/var` symlinks.
-/// This ensures `format_path_for_display()` can correctly convert paths to `~/...`.
-pub fn set_temp_home_env(cmd: &mut Command, home: &Path) {
-    // Canonicalize to resolve macOS symlinks (/var -> /private/var)
-    // This ensures paths match when format_path_for_display() compares against HOME
-    let home = canonicalize(home).unwrap_or_else(|_| home.to_path_buf());
-    cmd.env("HOME", &home);
-    cmd.env("XDG_CONFIG_HOME", home.join(".config"));
-    // Windows: the `home` crate uses USERPROFILE for home_dir()
-    cmd.env("USERPROFILE", &home);
-    // Windows: etcetera uses APPDATA for config_dir() (AppData\Roaming)
-    // Map it to .config to match Unix XDG_CONFIG_HOME behavior
-    cmd.env("APPDATA", home.join(".config"));
-}
-
 /// Override `WORKTRUNK_CONFIG_PATH` to point to the XDG-derived user config path
 /// under `home`. Use this after `set_temp_home_env` in tests that write user
 /// config at the XDG path and need `config create`/`config show` to find it.
@@ -1008,37 +792,13 @@ pub fn set_xdg_config_path(cmd: &mut Command, home: &Path) {
     );
 }
 
-/// Check that a git command succeeded, panicking with diagnostics if not.
+/// Integration-test fixture: a [`worktrunk::testing::TestRepo`] plus what
+/// running the `wt` binary needs — mock gh/glab commands and snapshot filters.
 ///
-/// Use this after `git_command().output()` to ensure the command succeeded.
-///
-/// # Example
-/// ```ignore
-/// let output = repo.git_command().args(["add", "."]).current_dir(&dir).output().unwrap();
-/// check_git_status(&output, "add");
-/// ```
-pub fn check_git_status(output: &std::process::Output, cmd_desc: &str) {
-    if !output.status.success() {
-        panic!(
-            "git {} failed:\nstdout: {}\nstderr: {}",
-            cmd_desc,
-            String::from_utf8_lossy(&output.stdout),
-            String::from_utf8_lossy(&output.stderr)
-        );
-    }
-}
-
+/// Derefs to the library fixture for git helpers (`commit`, `add_worktree`,
+/// `setup_remote`, ...).
 pub struct TestRepo {
-    temp_dir: TempDir, // Must keep to ensure cleanup on drop
-    root: PathBuf,
-    pub worktrees: HashMap<String, PathBuf>,
-    remote: Option<PathBuf>, // Path to bare remote repo if created
-    /// Isolated config file for this test (prevents pollution of user's config)
-    test_config_path: PathBuf,
-    /// Isolated approvals file for this test (prevents pollution of user's approvals)
-    test_approvals_path: PathBuf,
-    /// Git config file with test settings (advice disabled, etc.)
-    git_config_path: PathBuf,
+    inner: worktrunk::testing::TestRepo,
     /// Path to mock bin directory for gh/glab commands
     mock_bin_path: Option<PathBuf>,
     /// Whether Claude CLI should be treated as installed
@@ -1047,48 +807,35 @@ pub struct TestRepo {
     _snapshot_guard: insta::internals::SettingsBindDropGuard,
 }
 
-impl TestRepo {
-    /// Create a new test repository with isolated git environment.
-    ///
-    /// The repo includes:
-    /// - Main branch with one initial commit
-    /// - Remote (origin) bare repository
-    /// - Three feature worktrees (feature-a, feature-b, feature-c) each with one commit
-    ///
-    /// Uses a pre-created fixture for fast initialization - copies the fixture
-    /// from `tests/fixtures/standard/` instead of running git commands.
-    ///
-    /// Also sets up mock gh/glab commands that appear authenticated to prevent
-    /// CI status hints from appearing in test output.
-    pub fn new() -> Self {
-        let temp_dir = TempDir::new().unwrap();
-
-        // Copy from standard fixture (includes worktrees and remote)
-        let fixture = copy_standard_fixture(temp_dir.path());
+impl Deref for TestRepo {
+    type Target = worktrunk::testing::TestRepo;
 
-        // Canonicalize to resolve symlinks (important on macOS where /var is symlink to /private/var)
-        let root = canonicalize(&temp_dir.path().join("repo")).unwrap();
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
 
-        // Create isolated config path for this test
-        let test_config_path = temp_dir.path().join("test-config.toml");
-        let test_approvals_path = temp_dir.path().join("test-approvals.toml");
-        let git_config_path = temp_dir.path().join("test-gitconfig");
+impl DerefMut for TestRepo {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
 
-        // Write gitconfig for tests
-        write_test_gitconfig(&git_config_path);
+impl TestRepo {
+    /// Create a new test repository from the standard fixture.
+    ///
+    /// See [`worktrunk::testing::TestRepo::new`]. Also sets up mock gh/glab
+    /// commands that appear authenticated to prevent CI status hints from
+    /// appearing in test output.
+    pub fn new() -> Self {
+        let inner = worktrunk::testing::TestRepo::new();
 
         // Bind full snapshot settings (including ANSI cleanup) for all tests
         let snapshot_guard =
-            setup_snapshot_settings_for_paths(&root, &fixture.worktrees).bind_to_scope();
+            setup_snapshot_settings_for_paths(inner.root_path(), &inner.worktrees).bind_to_scope();
 
         let mut repo = Self {
-            temp_dir,
-            root,
-            worktrees: fixture.worktrees,
-            remote: Some(fixture.remote),
-            test_config_path,
-            test_approvals_path,
-            git_config_path,
+            inner,
             mock_bin_path: None,
             claude_installed: false,
             _snapshot_guard: snapshot_guard,
@@ -1105,53 +852,37 @@ impl TestRepo {
     /// Use this for tests that specifically need to test behavior in an
     /// uninitialized repo. Most tests should use `new()` instead.
     pub fn empty() -> Self {
-        let temp_dir = TempDir::new().unwrap();
-        let root = temp_dir.path().join("repo");
-        std::fs::create_dir(&root).unwrap();
-        let root = canonicalize(&root).unwrap();
-
-        let test_config_path = temp_dir.path().join("test-config.toml");
-        let test_approvals_path = temp_dir.path().join("test-approvals.toml");
-        let git_config_path = temp_dir.path().join("test-gitconfig");
+        let inner = worktrunk::testing::TestRepo::empty();
 
-        // Write gitconfig
-        std::fs::write(
-            &git_config_path,
-            "[user]\n\tname = Test User\n\temail = test@example.com\n\
-             [advice]\n\tmergeConflict = false\n\tresolveConflict = false\n\
-             [init]\n\tdefaultBranch = main\n",
-        )
-        .unwrap();
-
-        // Set up snapshot settings before creating the repo (worktrees empty initially)
-        let worktrees = HashMap::new();
-        let snapshot_guard = setup_snapshot_settings_for_paths(&root, &worktrees).bind_to_scope();
+        // Worktrees are empty initially
+        let snapshot_guard =
+            setup_snapshot_settings_for_paths(inner.root_path(), &inner.worktrees).bind_to_scope();
 
-        let repo = Self {
-            temp_dir,
-            root,
-            worktrees,
-            remote: None,
-            test_config_path,
-            test_approvals_path,
-            git_config_path,
+        Self {
+            inner,
             mock_bin_path: None,
             claude_installed: false,
             _snapshot_guard: snapshot_guard,
-        };
-
-        // Run git init (can't avoid this for empty repos)
-        repo.run_git(&["init", "-q"]);
-
-        repo
+        }
     }
 
+    // The next three forward to the library fixture so they take precedence
+    // over the same-named `TestRepoBase` methods.
+
     /// Configure a git command with isolated environment
-    ///
-    /// This sets environment variables only for the specific command,
-    /// ensuring thread-safety and test isolation.
     pub fn configure_git_cmd(&self, cmd: &mut Command) {
-        configure_git_cmd(cmd, &self.git_config_path);
+        self.inner.configure_git_cmd(cmd);
+    }
+
+    /// Create a `git` command pre-configured for this test repo.
+    #[must_use]
+    pub fn git_command(&self) -> Command {
+        self.inner.git_command()
+    }
+
+    /// Run a git command in a specific directory, panicking on failure.
+    pub fn run_git_in(&self, dir: &Path, args: &[&str]) {
+        self.inner.run_git_in(dir, args);
     }
 
     /// Get standard test environment variables as a vector.
@@ -1175,7 +906,7 @@ impl TestRepo {
         vars.extend([
             (
                 "GIT_CONFIG_GLOBAL".to_string(),
-                self.git_config_path.display().to_string(),
+                self.inner.git_config_path().display().to_string(),
             ),
             ("GIT_CONFIG_SYSTEM".to_string(), NULL_DEVICE.to_string()),
             (
@@ -1188,6 +919,8 @@ impl TestRepo {
             ),
             // Prevent git from prompting for credentials when running under a TTY
             ("GIT_TERMINAL_PROMPT".to_string(), "0".to_string()),
+            // Never page under a TTY; a pager would wait for input forever
+            ("GIT_PAGER".to_string(), "cat".to_string()),
             // Use test-specific home directory for isolation
             ("HOME".to_string(), self.home_path().display().to_string()),
             (
@@ -1227,129 +960,15 @@ impl TestRepo {
         .expect("Failed to write .zshrc for test");
     }
 
-    /// Create a `git` command pre-configured for this test repo.
-    ///
-    /// Returns an isolated Command with test-specific git config.
-    /// Chain `.args()` to add arguments.
-    ///
-    /// # Example
-    /// ```ignore
-    /// repo.git_command()
-    ///     .args(["status", "--porcelain"])
-    ///     .output()?;
-    /// ```
-    #[must_use]
-    pub fn git_command(&self) -> Command {
-        let mut cmd = Command::new("git");
-        self.configure_git_cmd(&mut cmd);
-        cmd.current_dir(&self.root);
-        cmd
-    }
-
-    /// Run a git command in the repo root, panicking on failure.
-    ///
-    /// Thin wrapper around `git_command()` that runs the command and checks status.
-    pub fn run_git(&self, args: &[&str]) {
-        let output = self.git_command().args(args).output().unwrap();
-        check_git_status(&output, &args.join(" "));
-    }
-
-    /// Run a git command in a specific directory, panicking on failure.
-    ///
-    /// Thin wrapper around `git_command()` that runs in `dir` and checks status.
-    pub fn run_git_in(&self, dir: &Path, args: &[&str]) {
-        let output = self
-            .git_command()
-            .args(args)
-            .current_dir(dir)
-            .output()
-            .unwrap();
-        check_git_status(&output, &args.join(" "));
-    }
-
-    /// Run a git command and return stdout as a trimmed string.
-    ///
-    /// Thin wrapper around `git_command()` for commands that return output.
-    pub fn git_output(&self, args: &[&str]) -> String {
-        let output = self.git_command().args(args).output().unwrap();
-        check_git_status(&output, &args.join(" "));
-        String::from_utf8_lossy(&output.stdout).trim().to_string()
-    }
-
-    /// Remove fixture worktrees to get a clean state for tests.
-    ///
-    /// The standard fixture includes worktrees for feature-a, feature-b, feature-c.
-    /// Call this method in tests that need a specific worktree state. Also clears
-    /// the worktrees map so `add_worktree` can recreate them if needed.
-    pub fn remove_fixture_worktrees(&mut self) {
-        for branch in &["feature-a", "feature-b", "feature-c"] {
-            let worktree_path = self
-                .root_path()
-                .parent()
-                .unwrap()
-                .join(format!("repo.{}", branch));
-            if worktree_path.exists() {
-                let _ = self
-                    .git_command()
-                    .args([
-                        "worktree",
-                        "remove",
-                        "--force",
-                        worktree_path.to_str().unwrap(),
-                    ])
-                    .output();
-            }
-            // Delete the branch after removing the worktree
-            let _ = self.git_command().args(["branch", "-D", branch]).output();
-            // Remove from worktrees map so add_worktree() can recreate if needed
-            self.worktrees.remove(*branch);
-        }
-    }
-
-    /// Stage all changes in a directory.
-    pub fn stage_all(&self, dir: &Path) {
-        self.run_git_in(dir, &["add", "."]);
-    }
-
-    /// Get the HEAD commit SHA.
-    pub fn head_sha(&self) -> String {
-        let output = self
-            .git_command()
-            .args(["rev-parse", "HEAD"])
-            .output()
-            .unwrap();
-        check_git_status(&output, "rev-parse HEAD");
-        String::from_utf8_lossy(&output.stdout).trim().to_string()
-    }
-
-    /// Get the HEAD commit SHA in a specific directory.
-    pub fn head_sha_in(&self, dir: &Path) -> String {
-        let output = self
-            .git_command()
-            .args(["rev-parse", "HEAD"])
-            .current_dir(dir)
-            .output()
-            .unwrap();
-        check_git_status(&output, "rev-parse HEAD");
-        String::from_utf8_lossy(&output.stdout).trim().to_string()
-    }
-
     /// Configure command for CLI tests with isolated environment.
     ///
-    /// Sets `WORKTRUNK_CONFIG_PATH`, `HOME`, and mock gh/glab commands.
+    /// Sets `WORKTRUNK_CONFIG_PATH`, `HOME` (via `configure_wt_env()`), and mock gh/glab commands.
     ///
     /// **Internal helper** - used by `wt_command()` and `make_snapshot_cmd()`.
     /// Tests should use `repo.wt_command()` instead of calling this directly.
     pub fn configure_wt_cmd(&self, cmd: &mut Command) {
         configure_cli_command(cmd);
-        self.configure_git_cmd(cmd);
-        cmd.env("WORKTRUNK_CONFIG_PATH", &self.test_config_path);
-        cmd.env(
-            "WORKTRUNK_SYSTEM_CONFIG_PATH",
-            "/etc/xdg/worktrunk/config.toml",
-        );
-        cmd.env("WORKTRUNK_APPROVALS_PATH", &self.test_approvals_path);
-        set_temp_home_env(cmd, self.home_path());
+        self.configure_wt_env(cmd);
         self.configure_mock_commands(cmd);
     }
 
@@ -1373,24 +992,6 @@ impl TestRepo {
         cmd
     }
 
-    /// Get the isolated HOME directory for this test.
-    ///
-    /// This is the temp directory containing the repo and can be used to set up
-    /// user config files before running commands:
-    /// - `.zshrc`, `.bashrc` - shell integration config
-    /// - `.config/worktrunk/config.toml` - user config (note: overridden by WORKTRUNK_CONFIG_PATH)
-    ///
-    /// The directory structure is:
-    /// ```text
-    /// home_path()/
-    /// ├── repo/              # The git repository (root_path())
-    /// ├── test-config.toml   # WORKTRUNK_CONFIG_PATH target
-    /// └── test-gitconfig     # GIT_CONFIG_GLOBAL target
-    /// ```
-    pub fn home_path(&self) -> &Path {
-        self.temp_dir.path()
-    }
-
     /// Prepare a `wt` command configured for shell completions within this repo.
     pub fn completion_cmd(&self, words: &[&str]) -> Command {
         self.completion_cmd_for_shell(words, "bash")
@@ -1405,438 +1006,6 @@ impl TestRepo {
         cmd
     }
 
-    /// Get the root path of the repository
-    pub fn root_path(&self) -> &Path {
-        &self.root
-    }
-
-    /// Get the path to the bare remote repository, if created.
-    pub fn remote_path(&self) -> Option<&Path> {
-        self.remote.as_deref()
-    }
-
-    /// Get the project identifier (canonical path) for this test repo.
-    ///
-    /// Returns the full canonical path of the repository. The standard fixture uses a local
-    /// path remote (`../origin_git`) which doesn't parse as a proper git URL, causing
-    /// worktrunk to fall back to the full canonical path.
-    ///
-    /// Use with TOML literal strings (single quotes) to avoid backslash escaping:
-    /// ```ignore
-    /// format!(r#"[projects.'{}']"#, repo.project_id())
-    /// ```
-    pub fn project_id(&self) -> String {
-        dunce::canonicalize(&self.root)
-            .unwrap_or_else(|_| self.root.clone())
-            .to_str()
-            .unwrap_or("")
-            .to_string()
-    }
-
-    /// Get the path to the isolated test config file
-    ///
-    /// This config path is automatically set via WORKTRUNK_CONFIG_PATH when using
-    /// `configure_wt_cmd()`, ensuring tests don't pollute the user's real config.
-    pub fn test_config_path(&self) -> &Path {
-        &self.test_config_path
-    }
-
-    /// Get the path to the isolated test approvals file
-    ///
-    /// This approvals path is automatically set via WORKTRUNK_APPROVALS_PATH when using
-    /// `configure_wt_cmd()`, ensuring tests don't pollute the user's real approvals.
-    pub fn test_approvals_path(&self) -> &Path {
-        &self.test_approvals_path
-    }
-
-    /// Write project-specific config (`.config/wt.toml`) under the repo root.
-    pub fn write_project_config(&self, contents: &str) {
-        let config_dir = self.root_path().join(".config");
-        std::fs::create_dir_all(&config_dir).unwrap();
-        std::fs::write(config_dir.join("wt.toml"), contents).unwrap();
-    }
-
-    /// Overwrite the isolated WORKTRUNK_CONFIG_PATH used during tests.
-    ///
-    /// Automatically prepends `skip-commit-generation-prompt = true` to prevent
-    /// interactive prompts from appearing in test output.
-    pub fn write_test_config(&self, contents: &str) {
-        let full_contents = format!("skip-commit-generation-prompt = true\n{}", contents);
-        std::fs::write(&self.test_config_path, full_contents).unwrap();
-    }
-
-    /// Write approved commands to the isolated WORKTRUNK_APPROVALS_PATH.
-    pub fn write_test_approvals(&self, contents: &str) {
-        std::fs::write(&self.test_approvals_path, contents).unwrap();
-    }
-
-    /// Get the path to a named worktree
-    pub fn worktree_path(&self, name: &str) -> &Path {
-        self.worktrees
-            .get(name)
-            .unwrap_or_else(|| panic!("Worktree '{}' not found", name))
-    }
-
-    /// Create a commit with the given message
-    pub fn commit(&self, message: &str) {
-        // Create a file to ensure there's something to commit
-        let file_path = self.root.join("file.txt");
-        std::fs::write(&file_path, message).unwrap();
-
-        self.git_command().args(["add", "."]).output().unwrap();
-
-        self.git_command()
-            .args(["commit", "-m", message])
-            .output()
-            .unwrap();
-    }
-
-    /// Create a commit with a custom message (useful for testing malicious messages)
-    pub fn commit_with_message(&self, message: &str) {
-        // Create file with message-derived name for deterministic commits
-        // Use first 16 chars of message (sanitized) as filename
-        let sanitized: String = message
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '-')
-            .take(16)
-            .collect();
-        let file_path = self.root.join(format!("file-{}.txt", sanitized));
-        std::fs::write(&file_path, message).unwrap();
-
-        self.git_command().args(["add", "."]).output().unwrap();
-
-        self.git_command()
-            .args(["commit", "-m", message])
-            .output()
-            .unwrap();
-    }
-
-    /// Create a commit with a specific age relative to TEST_EPOCH
-    ///
-    /// This allows creating commits that display specific relative ages
-    /// in the Age column (e.g., "10m", "1h", "1d").
-    ///
-    /// # Arguments
-    /// * `message` - The commit message
-    /// * `age_seconds` - How many seconds ago the commit should appear
-    ///
-    /// # Example
-    /// ```ignore
-    /// repo.commit_with_age("Initial commit", 86400);  // Shows "1d"
-    /// repo.commit_with_age("Fix bug", 3600);          // Shows "1h"
-    /// repo.commit_with_age("Add feature", 600);       // Shows "10m"
-    /// ```
-    pub fn commit_with_age(&self, message: &str, age_seconds: i64) {
-        let commit_time = TEST_EPOCH as i64 - age_seconds;
-        // Use ISO 8601 format for consistent behavior across git versions
-        let timestamp = unix_to_iso8601(commit_time);
-
-        // Use file.txt like commit() does - allows multiple commits to the same file
-        let file_path = self.root.join("file.txt");
-        std::fs::write(&file_path, message).unwrap();
-
-        self.git_command().args(["add", "."]).output().unwrap();
-
-        // Create commit with custom timestamp
-        self.git_command()
-            .env("GIT_AUTHOR_DATE", &timestamp)
-            .env("GIT_COMMITTER_DATE", &timestamp)
-            .args(["commit", "-m", message])
-            .output()
-            .unwrap();
-    }
-
-    /// Commit already-staged changes with a specific age
-    ///
-    /// This does NOT create or modify any files - it only commits staged changes.
-    /// Use this when you've already staged specific files and want clean diffs
-    /// (no spurious file.txt changes).
-    ///
-    /// # Example
-    /// ```ignore
-    /// std::fs::write(wt.join("feature.rs"), "...").unwrap();
-    /// run_git(&repo, &["add", "feature.rs"], &wt);
-    /// repo.commit_staged_with_age("Add feature", 2 * HOUR, &wt);
-    /// ```
-    pub fn commit_staged_with_age(&self, message: &str, age_seconds: i64, dir: &Path) {
-        let commit_time = TEST_EPOCH as i64 - age_seconds;
-        let timestamp = unix_to_iso8601(commit_time);
-
-        self.git_command()
-            .env("GIT_AUTHOR_DATE", &timestamp)
-            .env("GIT_COMMITTER_DATE", &timestamp)
-            .args(["commit", "-m", message])
-            .current_dir(dir)
-            .output()
-            .unwrap();
-    }
-
-    /// Add a worktree with the given name and branch
-    ///
-    /// The worktree path follows the default template format: `repo.{branch}`
-    /// (sanitized, with slashes replaced by dashes).
-    ///
-    /// If the worktree already exists (from the standard fixture), returns its path
-    /// without creating a new one.
-    pub fn add_worktree(&mut self, branch: &str) -> PathBuf {
-        // If worktree already exists (from fixture), just return its path
-        if let Some(path) = self.worktrees.get(branch) {
-            return path.clone();
-        }
-
-        let safe_branch = sanitize_branch_name(branch);
-        // Use default template path format: ../{{ repo }}.{{ branch }}
-        // From {temp_dir}/repo, this resolves to {temp_dir}/repo.{branch}
-        let worktree_path = self.temp_dir.path().join(format!("repo.{}", safe_branch));
-        let worktree_str = worktree_path.to_str().unwrap();
-
-        self.run_git(&["worktree", "add", "-b", branch, worktree_str]);
-
-        // Canonicalize worktree path to match what git returns
-        let canonical_path = canonicalize(&worktree_path).unwrap();
-        // Use branch as key (consistent with path generation)
-        self.worktrees
-            .insert(branch.to_string(), canonical_path.clone());
-        canonical_path
-    }
-
-    /// Creates a worktree at a custom path (for testing nested worktrees).
-    ///
-    /// Unlike `add_worktree`, this places the worktree at the specified path
-    /// rather than using the default sibling layout.
-    pub fn add_worktree_at_path(&mut self, branch: &str, path: &Path) -> PathBuf {
-        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
-        let path_str = path.to_str().unwrap();
-        self.run_git(&["worktree", "add", "-b", branch, path_str]);
-
-        let canonical_path = canonicalize(path).unwrap();
-        self.worktrees
-            .insert(branch.to_string(), canonical_path.clone());
-        canonical_path
-    }
-
-    /// Creates a worktree for the default branch (required for merge operations)
-    ///
-    /// This is a convenience method that creates a worktree for the default branch
-    /// in the standard location expected by merge tests. Returns the path to the
-    /// created worktree.
-    ///
-    /// If the primary worktree is currently on "main", this method detaches HEAD
-    /// first so the worktree can be created.
-    pub fn add_main_worktree(&self) -> PathBuf {
-        // If primary is on main, detach HEAD first so we can create a worktree for it
-        if self.current_branch() == "main" {
-            self.detach_head();
-        }
-
-        let main_wt = self.root_path().parent().unwrap().join("repo.main-wt");
-        let main_wt_str = main_wt.to_str().unwrap();
-        self.run_git(&["worktree", "add", main_wt_str, "main"]);
-        main_wt
-    }
-
-    /// Creates a worktree with a file and commits it.
-    ///
-    /// This is a convenience method that combines the common pattern of:
-    /// 1. Creating a worktree for a new branch
-    /// 2. Writing a file to it
-    /// 3. Staging and committing the file
-    ///
-    /// # Example
-    /// ```ignore
-    /// let feature_wt = repo.add_worktree_with_commit(
-    ///     "feature",
-    ///     "feature.txt",
-    ///     "feature content",
-    ///     "Add feature file",
-    /// );
-    /// ```
-    pub fn add_worktree_with_commit(
-        &mut self,
-        branch: &str,
-        filename: &str,
-        content: &str,
-        message: &str,
-    ) -> PathBuf {
-        let worktree_path = self.add_worktree(branch);
-        std::fs::write(worktree_path.join(filename), content).unwrap();
-        self.run_git_in(&worktree_path, &["add", filename]);
-        self.run_git_in(&worktree_path, &["commit", "-m", message]);
-        worktree_path
-    }
-
-    /// Shorthand: adds a "feature" worktree with a canonical commit.
-    ///
-    /// Equivalent to:
-    /// ```ignore
-    /// repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature file")
-    /// ```
-    ///
-    /// Returns the path to the feature worktree.
-    pub fn add_feature(&mut self) -> PathBuf {
-        self.add_worktree_with_commit(
-            "feature",
-            "feature.txt",
-            "feature content",
-            "Add feature file",
-        )
-    }
-
-    /// Adds a commit to an existing worktree.
-    ///
-    /// This writes a file, stages it, and commits it in the specified worktree.
-    /// Useful for tests that need multiple commits in the same worktree.
-    ///
-    /// # Arguments
-    /// * `worktree_path` - Path to the existing worktree
-    /// * `filename` - Name of the file to create/modify
-    /// * `content` - Content to write to the file
-    /// * `message` - Commit message
-    ///
-    /// # Example
-    /// ```ignore
-    /// let feature_wt = repo.add_worktree("feature");
-    /// repo.commit_in_worktree(&feature_wt, "file1.txt", "content 1", "feat: add file 1");
-    /// repo.commit_in_worktree(&feature_wt, "file2.txt", "content 2", "feat: add file 2");
-    /// ```
-    pub fn commit_in_worktree(
-        &self,
-        worktree_path: &Path,
-        filename: &str,
-        content: &str,
-        message: &str,
-    ) {
-        std::fs::write(worktree_path.join(filename), content).unwrap();
-        self.run_git_in(worktree_path, &["add", filename]);
-        self.run_git_in(worktree_path, &["commit", "-m", message]);
-    }
-
-    /// Creates a branch without a worktree.
-    ///
-    /// This creates a local branch pointing to HEAD without checking it out.
-    /// Useful for testing branch listing without creating worktrees.
-    pub fn create_branch(&self, branch_name: &str) {
-        self.run_git(&["branch", branch_name]);
-    }
-
-    /// Pushes a branch to origin.
-    ///
-    /// Creates a remote tracking branch on origin. Requires `setup_remote()`
-    /// to have been called first.
-    pub fn push_branch(&self, branch_name: &str) {
-        self.run_git(&["push", "origin", branch_name]);
-    }
-
-    /// Detach HEAD in the main repository
-    pub fn detach_head(&self) {
-        self.detach_head_at(&self.root);
-    }
-
-    /// Detach HEAD in a specific worktree
-    pub fn detach_head_in_worktree(&self, name: &str) {
-        let worktree_path = self.worktree_path(name);
-        self.detach_head_at(worktree_path);
-    }
-
-    fn detach_head_at(&self, path: &Path) {
-        let sha = self.head_sha_in(path);
-        self.run_git_in(path, &["checkout", "--detach", &sha]);
-    }
-
-    /// Lock a worktree with an optional reason
-    pub fn lock_worktree(&self, name: &str, reason: Option<&str>) {
-        let worktree_path = self.worktree_path(name);
-        let worktree_str = worktree_path.to_str().unwrap();
-
-        match reason {
-            Some(r) => self.run_git(&["worktree", "lock", "--reason", r, worktree_str]),
-            None => self.run_git(&["worktree", "lock", worktree_str]),
-        }
-    }
-
-    /// Create a bare remote repository and set it as origin
-    ///
-    /// This creates a bare git repository in the temp directory and configures
-    /// it as the 'origin' remote. The remote will have the same default branch
-    /// as the local repository (main).
-    pub fn setup_remote(&mut self, default_branch: &str) {
-        self.setup_custom_remote("origin", default_branch);
-    }
-
-    /// Create a bare remote repository with a custom name
-    ///
-    /// This creates a bare git repository in the temp directory and configures
-    /// it with the specified remote name. The remote will have the same default
-    /// branch as the local repository.
-    ///
-    /// If the remote already exists (from fixture), this is a no-op.
-    pub fn setup_custom_remote(&mut self, remote_name: &str, default_branch: &str) {
-        // If origin remote already exists (from fixture), just ensure HEAD is set
-        if remote_name == "origin" && self.remote.is_some() {
-            // Set origin/HEAD (fixture may not have this set)
-            self.run_git(&["remote", "set-head", "origin", default_branch]);
-            return;
-        }
-
-        // Create bare remote repository
-        let remote_path = self.temp_dir.path().join(format!("{}.git", remote_name));
-        if remote_path.exists() {
-            // Remote directory already exists, just use it
-            self.remote = Some(canonicalize(&remote_path).unwrap());
-            return;
-        }
-        std::fs::create_dir(&remote_path).unwrap();
-
-        self.run_git_in(
-            &remote_path,
-            &["init", "--bare", "--initial-branch", default_branch],
-        );
-
-        // Canonicalize remote path
-        let remote_path = canonicalize(&remote_path).unwrap();
-        let remote_path_str = remote_path.to_str().unwrap();
-
-        // Add as remote, push, and set HEAD
-        self.run_git(&["remote", "add", remote_name, remote_path_str]);
-        self.run_git(&["push", "-u", remote_name, default_branch]);
-        self.run_git(&["remote", "set-head", remote_name, default_branch]);
-
-        self.remote = Some(remote_path);
-    }
-
-    /// Clear the local origin/HEAD reference
-    ///
-    /// This forces git to not have a cached default branch, useful for testing
-    /// the fallback path that queries the remote.
-    pub fn clear_origin_head(&self) {
-        self.run_git(&["remote", "set-head", "origin", "--delete"]);
-    }
-
-    /// Check if origin/HEAD is set
-    pub fn has_origin_head(&self) -> bool {
-        self.git_command()
-            .args(["rev-parse", "--abbrev-ref", "origin/HEAD"])
-            .output()
-            .unwrap()
-            .status
-            .success()
-    }
-
-    /// Switch the primary worktree to a different branch
-    ///
-    /// Creates a new branch and switches to it in the primary worktree.
-    /// This is useful for testing scenarios where the primary worktree is not on the default branch.
-    pub fn switch_primary_to(&self, branch: &str) {
-        self.run_git(&["switch", "-c", branch]);
-    }
-
-    /// Get the current branch of the primary worktree
-    ///
-    /// Returns the name of the current branch, or panics if HEAD is detached.
-    pub fn current_branch(&self) -> String {
-        self.git_output(&["branch", "--show-current"])
-    }
-
     /// Setup mock `gh` and `glab` commands that return immediately without network calls
     ///
     /// Creates a mock bin directory with fake gh/glab scripts. After calling this,
@@ -1864,7 +1033,7 @@ impl TestRepo {
     pub fn setup_mock_ci_tools_unauthenticated(&mut self) {
         use crate::common::mock_commands::{MockConfig, MockResponse};
 
-        let mock_bin = self.temp_dir.path().join("mock-bin");
+        let mock_bin = self.home_path().join("mock-bin");
         std::fs::create_dir_all(&mock_bin).unwrap();
 
         // gh: installed but not authenticated
@@ -1932,7 +1101,7 @@ impl TestRepo {
     pub fn setup_mock_gh_with_ci_data(&mut self, pr_json: &str, run_json: &str) {
         use crate::common::mock_commands::{MockConfig, MockResponse};
 
-        let mock_bin = self.temp_dir.path().join("mock-bin");
+        let mock_bin = self.home_path().join("mock-bin");
         std::fs::create_dir_all(&mock_bin).unwrap();
 
         // Write JSON data files
@@ -1975,7 +1144,7 @@ impl TestRepo {
     pub fn setup_mock_glab_with_ci_data(&mut self, mr_json: &str, project_id: Option<u64>) {
         use crate::common::mock_commands::{MockConfig, MockResponse};
 
-        let mock_bin = self.temp_dir.path().join("mock-bin");
+        let mock_bin = self.home_path().join("mock-bin");
         std::fs::create_dir_all(&mock_bin).unwrap();
 
         // Parse the MR JSON to create separate list and view responses
@@ -2040,7 +1209,7 @@ impl TestRepo {
     pub fn setup_mock_glab_with_failing_mr_view(&mut self, mr_json: &str, project_id: Option<u64>) {
         use crate::common::mock_commands::{MockConfig, MockResponse};
 
-        let mock_bin = self.temp_dir.path().join("mock-bin");
+        let mock_bin = self.home_path().join("mock-bin");
         std::fs::create_dir_all(&mock_bin).unwrap();
 
         std::fs::write(mock_bin.join("mr_list_data.json"), mr_json).unwrap();
@@ -2076,7 +1245,7 @@ impl TestRepo {
     pub fn setup_mock_glab_with_ci_rate_limit(&mut self, project_id: Option<u64>) {
         use crate::common::mock_commands::{MockConfig, MockResponse};
 
-        let mock_bin = self.temp_dir.path().join("mock-bin");
+        let mock_bin = self.home_path().join("mock-bin");
         std::fs::create_dir_all(&mock_bin).unwrap();
 
         let project_id_response = match project_id {
@@ -2158,7 +1327,7 @@ impl TestRepo {
 
 impl TestRepoBase for TestRepo {
     fn git_config_path(&self) -> &Path {
-        &self.git_config_path
+        self.inner.git_config_path()
     }
 }
 
@@ -2317,6 +1486,8 @@ pub fn add_standard_env_redactions(settings: &mut insta::Settings) {
     settings.add_redaction(".env.XDG_CONFIG_HOME", "[TEST_CONFIG_HOME]");
     // Windows: etcetera uses APPDATA for config_dir()
     settings.add_redaction(".env.APPDATA", "[TEST_CONFIG_HOME]");
+    // Data directory for archives and the usage journal
+    settings.add_redaction(".env.XDG_DATA_HOME", "[TEST_DATA_HOME]");
     settings.add_redaction(".env.PATH", "[PATH]");
     // Mock commands directory (temp path for mock gh/glab binaries)
     settings.add_redaction(".env.MOCK_CONFIG_DIR", "[MOCK_CONFIG_DIR]");
@@ -3261,79 +2432,11 @@ pub fn wait_for(description: &str, mut check: impl FnMut() -> bool) {
     panic!("Condition not met within {:?}: {}", BG_TIMEOUT, description);
 }
 
-/// Convert Unix timestamp to ISO 8601 format for consistent git date handling
-///
-/// Git interprets `@timestamp` format inconsistently across versions and platforms.
-/// Using ISO 8601 format ensures deterministic commit SHAs across all environments.
-fn unix_to_iso8601(timestamp: i64) -> String {
-    // Calculate date components from Unix timestamp
-    let days_since_epoch = timestamp / 86400;
-    let seconds_in_day = timestamp % 86400;
-
-    let hours = seconds_in_day / 3600;
-    let minutes = (seconds_in_day % 3600) / 60;
-    let seconds = seconds_in_day % 60;
-
-    // Calculate year, month, day from days since Unix epoch (1970-01-01)
-    // Simplified algorithm: account for leap years
-    let mut year = 1970i64;
-    let mut remaining_days = days_since_epoch;
-
-    loop {
-        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
-        if remaining_days < days_in_year {
-            break;
-        }
-        remaining_days -= days_in_year;
-        year += 1;
-    }
-
-    let days_in_months: [i64; 12] = if is_leap_year(year) {
-        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    };
-
-    let mut month = 1;
-    for &days in &days_in_months {
-        if remaining_days < days {
-            break;
-        }
-        remaining_days -= days;
-        month += 1;
-    }
-
-    let day = remaining_days + 1; // Days are 1-indexed
-
-    format!(
-        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-        year, month, day, hours, minutes, seconds
-    )
-}
-
-fn is_leap_year(year: i64) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::rstest;
 
-    #[test]
-    fn test_unix_to_iso8601() {
-        // 2025-01-01T00:00:00Z
-        assert_eq!(unix_to_iso8601(1735689600), "2025-01-01T00:00:00Z");
-        // 2025-01-02T00:00:00Z (TEST_EPOCH)
-        assert_eq!(unix_to_iso8601(1735776000), "2025-01-02T00:00:00Z");
-        // 2024-12-31T00:00:00Z (one day before 2025-01-01)
-        assert_eq!(unix_to_iso8601(1735603200), "2024-12-31T00:00:00Z");
-        // Unix epoch
-        assert_eq!(unix_to_iso8601(0), "1970-01-01T00:00:00Z");
-        // Leap year: 2024-02-29
-        assert_eq!(unix_to_iso8601(1709164800), "2024-02-29T00:00:00Z");
-    }
-
     #[rstest]
     fn test_commit_with_age(repo: TestRepo) {
         // TestRepo::new() already includes one initial commit from fixture
@@ -3354,6 +2457,50 @@ mod tests {
         assert_eq!(log.lines().count(), 5);
     }
 
+    #[rstest]
+    fn test_conflict_and_rebase_helpers(mut repo: TestRepo) {
+        let conflict = repo.create_conflict("conflicting", "shared.txt");
+        let output = repo
+            .git_command()
+            .args(["merge-tree", "--write-tree", "main", "conflicting"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "branches should conflict");
+        assert!(conflict.join("shared.txt").exists());
+
+        let rebasing = repo.start_conflicting_rebase("rebasing", "other.txt");
+        let rebase_dir = repo
+            .git_command()
+            .args(["rev-parse", "--git-path", "rebase-merge"])
+            .current_dir(&rebasing)
+            .output()
+            .unwrap();
+        let rebase_dir = rebasing.join(String::from_utf8_lossy(&rebase_dir.stdout).trim());
+        assert!(rebase_dir.exists(), "rebase should be in progress");
+    }
+
+    #[rstest]
+    fn test_stash_and_divergence_helpers(repo: TestRepo) {
+        let feature = repo.worktree_path("feature-a").to_path_buf();
+        repo.create_stash(&feature, "WIP on feature-a");
+        let stashes = repo
+            .git_command()
+            .args(["stash", "list"])
+            .current_dir(&feature)
+            .output()
+            .unwrap();
+        assert!(String::from_utf8_lossy(&stashes.stdout).contains("WIP on feature-a"));
+
+        repo.diverge_from_remote(&feature);
+        let counts = repo
+            .git_command()
+            .args(["rev-list", "--left-right", "--count", "HEAD...@{upstream}"])
+            .current_dir(&feature)
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&counts.stdout).trim(), "1\t1");
+    }
+
     #[test]
     fn test_validate_ansi_codes_no_leak() {
         // Good - no nesting
@@ -347,6 +347,41 @@ fn test_concurrent_approve_preserves_all_approvals() {
     );
 }
 
+/// Many threads approving at once, each from its own stale in-memory copy: the
+/// lock around each read-modify-write keeps every approval.
+#[test]
+fn test_parallel_approvals_keep_every_command() {
+    let temp_dir = TempDir::new().unwrap();
+    let approvals_path = temp_dir.path().join("approvals.toml");
+
+    std::thread::scope(|scope| {
+        for thread in 0..8 {
+            let approvals_path = &approvals_path;
+            scope.spawn(move || {
+                for i in 0..5 {
+                    Approvals::default()
+                        .approve_command(
+                            "github.com/user/repo".to_string(),
+                            format!("make task-{thread}-{i}"),
+                            Some(approvals_path),
+                        )
+                        .unwrap();
+                }
+            });
+        }
+    });
+
+    let toml_content = fs::read_to_string(&approvals_path).unwrap();
+    for thread in 0..8 {
+        for i in 0..5 {
+            assert!(
+                toml_content.contains(&format!("make task-{thread}-{i}")),
+                "make task-{thread}-{i} was lost. Saved content:\n{toml_content}"
+            );
+        }
+    }
+}
+
 ///
 /// This tests a race condition where two instances (simulating separate processes)
 /// both revoke commands. Without proper merging, the second save would restore
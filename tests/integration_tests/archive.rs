@@ -0,0 +1,117 @@
+//! Integration tests for `wt archive`
+
+use crate::common::{TestRepo, TestRepoBase, make_snapshot_cmd, repo};
+use rstest::rstest;
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use tempfile::TempDir;
+
+fn run_archive(repo: &TestRepo, data_home: &Path, args: &[&str], cwd: Option<&Path>) -> Output {
+    make_snapshot_cmd(repo, "archive", args, cwd)
+        .env("XDG_DATA_HOME", data_home)
+        .output()
+        .unwrap()
+}
+
+/// Path of the worktree that has `branch` checked out.
+fn worktree_path(repo: &TestRepo, branch: &str) -> PathBuf {
+    let list = repo.git_output(&["worktree", "list", "--porcelain"]);
+    let branch_line = format!("branch refs/heads/{branch}");
+    list.split("\n\n")
+        .find(|block| block.lines().any(|line| line == branch_line))
+        .and_then(|block| block.lines().next()?.strip_prefix("worktree "))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| panic!("no worktree for {branch}:\n{list}"))
+}
+
+/// Archiving removes the worktree and branch; restoring brings back both,
+/// including the uncommitted changes.
+#[rstest]
+fn test_archive_and_restore(mut repo: TestRepo) {
+    let data_home = TempDir::new().unwrap();
+    let wt = repo.add_worktree("feature");
+    repo.commit_in(&wt, "Add feature");
+    std::fs::write(wt.join("notes.txt"), "wip").unwrap();
+
+    let output = run_archive(&repo, data_home.path(), &["feature"], None);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("--uncommitted"), "{stderr}");
+
+    let output = run_archive(
+        &repo,
+        data_home.path(),
+        &["feature", "--uncommitted", "-m", "Paused"],
+        None,
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("feature-20250102-000000"), "{stderr}");
+    assert!(!wt.exists());
+    let branches = repo.git_output(&["branch", "--list", "feature"]);
+    assert!(branches.is_empty(), "{branches}");
+
+    let output = run_archive(&repo, data_home.path(), &["list"], None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("feature-20250102-000000"), "{stdout}");
+    assert!(stdout.contains("Paused (+ uncommitted)"), "{stdout}");
+
+    let output = run_archive(
+        &repo,
+        data_home.path(),
+        &["restore", "feature-20250102-000000"],
+        None,
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Restored"), "{stderr}");
+    let restored = worktree_path(&repo, "feature");
+    assert_eq!(
+        std::fs::read_to_string(restored.join("notes.txt")).unwrap(),
+        "wip"
+    );
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s", "feature"]),
+        "Add feature"
+    );
+
+    // Restoring deletes the archive
+    let output = run_archive(&repo, data_home.path(), &["list"], None);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No archives"));
+}
+
+/// An existing branch of the same name is left alone without `--force`.
+#[rstest]
+fn test_archive_restore_existing_branch(mut repo: TestRepo) {
+    let data_home = TempDir::new().unwrap();
+    let wt = repo.add_worktree("feature");
+    repo.commit_in(&wt, "Add feature");
+
+    let output = run_archive(&repo, data_home.path(), &[], Some(&wt));
+    assert!(output.status.success());
+
+    repo.run_git(&["branch", "feature"]);
+    let output = run_archive(
+        &repo,
+        data_home.path(),
+        &["restore", "feature-20250102-000000"],
+        None,
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("--force"), "{stderr}");
+
+    let output = run_archive(
+        &repo,
+        data_home.path(),
+        &["restore", "feature-20250102-000000", "--force"],
+        None,
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s", "feature"]),
+        "Add feature"
+    );
+}
@@ -0,0 +1,116 @@
+//! Integration tests for `wt branches clean`
+
+use crate::common::{TestRepo, repo, repo_with_remote};
+use rstest::rstest;
+use std::fs;
+
+/// `merged` points at main; `unmerged` has a commit main lacks. Neither has a worktree.
+fn setup_branches(repo: &mut TestRepo) {
+    repo.run_git(&["branch", "merged"]);
+    let path = repo.add_worktree("unmerged");
+    fs::write(path.join("new.txt"), "new\n").unwrap();
+    repo.run_git_in(&path, &["add", "new.txt"]);
+    repo.run_git_in(&path, &["commit", "-m", "Unmerged work"]);
+    repo.run_git(&["worktree", "remove", path.to_str().unwrap()]);
+}
+
+fn local_branches(repo: &TestRepo) -> String {
+    let output = repo
+        .git_command()
+        .args(["branch", "--format=%(refname:short)"])
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[rstest]
+fn test_branches_clean_dry_run_lists_merged_only(mut repo: TestRepo) {
+    setup_branches(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["branches", "clean", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = ansi_str::AnsiStr::ansi_strip(stdout.as_ref());
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "{stdout}");
+    assert!(lines[0].starts_with("Branch"), "{stdout}");
+    assert!(lines[1].starts_with("merged "), "{stdout}");
+    assert!(lines[1].contains("same commit as main"), "{stdout}");
+
+    // Nothing deleted
+    assert!(local_branches(&repo).lines().any(|b| b == "merged"));
+}
+
+#[rstest]
+fn test_branches_clean_deletes_with_yes(mut repo: TestRepo) {
+    setup_branches(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["branches", "clean", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let branches = local_branches(&repo);
+    assert!(!branches.lines().any(|b| b == "merged"), "{branches}");
+    assert!(branches.lines().any(|b| b == "unmerged"), "{branches}");
+    assert!(branches.lines().any(|b| b == "main"), "{branches}");
+}
+
+#[rstest]
+fn test_branches_clean_needs_yes_when_not_interactive(mut repo: TestRepo) {
+    setup_branches(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["branches", "clean"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not interactive"), "{stderr}");
+    assert!(local_branches(&repo).lines().any(|b| b == "merged"));
+}
+
+/// `--delete-remote` deletes a cleaned branch's remote branch, but never the
+/// default branch a local branch happens to track.
+#[rstest]
+fn test_branches_clean_delete_remote_keeps_default_branch(
+    #[from(repo_with_remote)] repo: TestRepo,
+) {
+    repo.run_git(&["branch", "done"]);
+    repo.run_git(&["push", "-u", "origin", "done"]);
+    repo.run_git(&["branch", "--track", "from-main", "origin/main"]);
+
+    let output = repo
+        .wt_command()
+        .args(["branches", "clean", "--yes", "--delete-remote"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Keeping remote origin/main (default branch)"),
+        "{stderr}"
+    );
+
+    let branches = local_branches(&repo);
+    assert!(!branches.lines().any(|b| b == "done"), "{branches}");
+    assert!(!branches.lines().any(|b| b == "from-main"), "{branches}");
+    let remote = repo
+        .git_command()
+        .args(["ls-remote", "--heads", "origin"])
+        .output()
+        .unwrap();
+    let remote = String::from_utf8_lossy(&remote.stdout);
+    assert!(remote.contains("refs/heads/main"), "{remote}");
+    assert!(!remote.contains("refs/heads/done"), "{remote}");
+}
@@ -762,3 +762,211 @@ fn test_list_full_with_gitlab_ci_rate_limit(mut repo: TestRepo) {
         assert_cmd_snapshot!("gitlab_ci_rate_limit", cmd);
     });
 }
+
+/// A merged PR (with no open one) reports `merged` in JSON, even though git
+/// still sees the branch as ahead of main.
+#[rstest]
+fn test_list_full_with_merged_pr(mut repo: TestRepo) {
+    let head_sha = setup_github_repo_with_feature(&mut repo);
+
+    let pr_json = format!(
+        r#"[{{
+        "headRefOid": "{}",
+        "mergeStateStatus": "UNKNOWN",
+        "statusCheckRollup": [],
+        "url": "https://github.com/test-owner/test-repo/pull/1",
+        "headRepositoryOwner": {{"login": "test-owner"}},
+        "state": "MERGED"
+    }}]"#,
+        head_sha
+    );
+    repo.setup_mock_gh_with_ci_data(&pr_json, "[]");
+
+    let mut cmd = make_snapshot_cmd(&repo, "list", &["--full", "--format=json"], None);
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|i| i["branch"] == "feature").unwrap();
+    assert_eq!(feature["ci"]["merged"], true);
+    assert_eq!(feature["ci"]["stale"], false);
+}
+
+/// The default branch reports the checks on its head commit rather than any PR
+/// whose head happens to be named `main`, unless `ci.include-default-branch = false`.
+#[rstest]
+fn test_list_full_default_branch_uses_commit_checks(mut repo: TestRepo) {
+    use crate::common::mock_commands::{MockConfig, MockResponse};
+
+    let head_sha = setup_github_repo_with_feature(&mut repo);
+    let pr_json = format!(
+        r#"[{{
+        "headRefOid": "{}",
+        "mergeStateStatus": "CLEAN",
+        "statusCheckRollup": [{{"status": "COMPLETED", "conclusion": "SUCCESS"}}],
+        "url": "https://github.com/test-owner/test-repo/pull/1",
+        "headRepositoryOwner": {{"login": "test-owner"}}
+    }}]"#,
+        head_sha
+    );
+    repo.setup_mock_gh_with_ci_data(&pr_json, "[]");
+    let mock_bin = repo.home_path().join("mock-bin");
+    MockConfig::new("gh")
+        .version("gh version 2.0.0 (mock)")
+        .command("auth", MockResponse::exit(0))
+        .command("pr", MockResponse::file("pr_data.json"))
+        .command(
+            "api",
+            MockResponse::output(r#"[{"status": "completed", "conclusion": "failure"}]"#),
+        )
+        .write(&mock_bin);
+
+    let list_ci = |repo: &TestRepo| {
+        let mut cmd = make_snapshot_cmd(repo, "list", &["--full", "--format=json"], None);
+        repo.configure_mock_commands(&mut cmd);
+        let output = cmd.output().unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+        let ci = |branch: &str| {
+            json.iter()
+                .find(|i| i["branch"] == branch)
+                .unwrap()
+                .get("ci")
+                .cloned()
+        };
+        (ci("main"), ci("feature"))
+    };
+
+    let (main, feature) = list_ci(&repo);
+    let main = main.expect("main should have CI status");
+    assert_eq!(main["source"], "branch");
+    assert_eq!(main["status"], "failed");
+    let feature = feature.expect("feature should have CI status");
+    assert_eq!(feature["source"], "pr");
+    assert_eq!(feature["status"], "passed");
+
+    repo.write_project_config("[ci]\ninclude-default-branch = false\n");
+    repo.wt_command()
+        .args(["config", "state", "ci-status", "clear", "--all"])
+        .output()
+        .unwrap();
+    let (main, feature) = list_ci(&repo);
+    assert!(main.is_none(), "{main:?}");
+    assert!(feature.is_some());
+}
+
+/// `--ci-details` names failing and pending checks under their row, from the
+/// same `statusCheckRollup` the status came from; JSON carries them too.
+#[rstest]
+fn test_list_ci_details(mut repo: TestRepo) {
+    let head_sha = setup_github_repo_with_feature(&mut repo);
+    let pr_json = format!(
+        r#"[{{
+        "headRefOid": "{}",
+        "mergeStateStatus": "BLOCKED",
+        "statusCheckRollup": [
+            {{"name": "build-linux", "status": "COMPLETED", "conclusion": "SUCCESS"}},
+            {{"name": "e2e", "status": "IN_PROGRESS", "conclusion": null}},
+            {{"name": "lint", "status": "COMPLETED", "conclusion": "FAILURE"}}
+        ],
+        "url": "https://github.com/test-owner/test-repo/pull/1",
+        "headRepositoryOwner": {{"login": "test-owner"}}
+    }}]"#,
+        head_sha
+    );
+    repo.setup_mock_gh_with_ci_data(&pr_json, "[]");
+
+    let run = |args: &[&str]| {
+        let mut cmd = make_snapshot_cmd(&repo, "list", args, None);
+        repo.configure_mock_commands(&mut cmd);
+        let output = cmd.output().unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        output.stdout
+    };
+
+    // No --full needed: the flag fetches CI itself
+    let table = ansi_str::AnsiStr::ansi_strip(&*String::from_utf8_lossy(&run(&["--ci-details"])))
+        .into_owned();
+    let lines: Vec<&str> = table.lines().collect();
+    let row = lines
+        .iter()
+        .position(|l| l.contains("feature"))
+        .unwrap_or_else(|| panic!("{table}"));
+    assert_eq!(lines[row + 1], "  ✗ lint  ● e2e", "{table}");
+    assert!(!table.contains("build-linux"), "{table}");
+
+    let json: Vec<serde_json::Value> =
+        serde_json::from_slice(&run(&["--full", "--format=json"])).unwrap();
+    let feature = json.iter().find(|i| i["branch"] == "feature").unwrap();
+    assert_eq!(
+        feature["ci"]["checks"],
+        serde_json::json!([
+            {"name": "lint", "status": "failed"},
+            {"name": "e2e", "status": "running"},
+        ])
+    );
+}
+
+/// `--offline` never spawns a network client: gh, glab and curl are replaced
+/// by scripts that record any call, and `wt list --full` must leave no record.
+#[rstest]
+#[cfg(unix)]
+fn test_list_full_offline_makes_no_network_calls(mut repo: TestRepo) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let head_sha = setup_github_repo_with_feature(&mut repo);
+    repo.setup_mock_gh_with_ci_data(
+        &format!(r#"[{{"headRefOid": "{head_sha}", "statusCheckRollup": []}}]"#),
+        "[]",
+    );
+    let mock_bin = repo.home_path().join("mock-bin");
+    let calls = repo.home_path().join("network-calls");
+    for tool in ["gh", "glab", "curl"] {
+        let path = mock_bin.join(tool);
+        let _ = std::fs::remove_file(&path);
+        std::fs::write(
+            &path,
+            format!(
+                "#!/bin/sh\necho \"{tool} $*\" >> '{}'\nexit 1\n",
+                calls.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    let mut cmd = make_snapshot_cmd(&repo, "list", &["--full", "--offline"], None);
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let table = String::from_utf8_lossy(&output.stdout);
+    assert!(!table.contains("CI"), "CI column should be hidden: {table}");
+    assert!(
+        !calls.exists(),
+        "network commands ran offline:\n{}",
+        std::fs::read_to_string(&calls).unwrap_or_default()
+    );
+
+    // The env var works the same way
+    let mut cmd = make_snapshot_cmd(&repo, "list", &["--full"], None);
+    repo.configure_mock_commands(&mut cmd);
+    cmd.env("WORKTRUNK_OFFLINE", "1");
+    assert!(cmd.output().unwrap().status.success());
+    assert!(!calls.exists());
+}
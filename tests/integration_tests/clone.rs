@@ -0,0 +1,181 @@
+//! Integration tests for `wt clone`
+
+use crate::common::{TestRepo, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::process::Command;
+
+/// `wt clone` run from the test home, cloning the fixture's `origin.git`.
+fn clone_cmd(repo: &TestRepo, args: &[&str]) -> Command {
+    let mut cmd = repo.wt_command();
+    cmd.current_dir(repo.home_path())
+        .args(["clone", "origin.git"])
+        .args(args);
+    cmd
+}
+
+fn stderr(output: &std::process::Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    ansi_str::AnsiStr::ansi_strip(&*stderr).into_owned()
+}
+
+fn git_stdout(repo: &TestRepo, dir: &std::path::Path, args: &[&str]) -> String {
+    let output = repo
+        .git_command()
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[rstest]
+fn test_clone(repo: TestRepo) {
+    let output = clone_cmd(&repo, &["app"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let app = repo.home_path().join("app");
+    assert_eq!(
+        git_stdout(&repo, &app, &["branch", "--show-current"]),
+        "main"
+    );
+    assert!(!app.join(".config/wt.toml").exists());
+    assert!(stderr(&output).contains("wt switch --create"));
+}
+
+#[rstest]
+fn test_clone_bare(repo: TestRepo) {
+    let origin = repo.home_path().join("origin.git");
+    repo.run_git_in(&origin, &["branch", "extra", "main"]);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!("clone_bare", clone_cmd(&repo, &["--bare", "--init", "app"]));
+    });
+
+    let app = repo.home_path().join("app");
+    let main = app.join("main");
+    assert_eq!(
+        git_stdout(&repo, &app.join(".git"), &["config", "core.bare"]),
+        "true"
+    );
+    assert_eq!(
+        git_stdout(&repo, &main, &["branch", "--show-current"]),
+        "main"
+    );
+    assert_eq!(
+        git_stdout(
+            &repo,
+            &main,
+            &["rev-parse", "--abbrev-ref", "main@{upstream}"]
+        ),
+        "origin/main"
+    );
+    assert_eq!(
+        git_stdout(&repo, &main, &["symbolic-ref", "refs/remotes/origin/HEAD"]),
+        "refs/remotes/origin/main"
+    );
+    assert!(main.join(".config/wt.toml").exists());
+
+    // Only the default branch stays local; the rest live under `origin/`
+    assert_eq!(
+        git_stdout(
+            &repo,
+            &main,
+            &["for-each-ref", "--format=%(refname)", "refs/heads/"]
+        ),
+        "refs/heads/main"
+    );
+    assert_eq!(
+        git_stdout(&repo, &main, &["rev-parse", "--verify", "origin/extra"]),
+        git_stdout(&repo, &main, &["rev-parse", "main"])
+    );
+
+    // Without a terminal, the layout's worktree-path is only suggested
+    let config = std::fs::read_to_string(repo.test_config_path()).unwrap_or_default();
+    assert!(!config.contains("[projects"), "{config}");
+
+    // Once saved, new worktrees land beside `.git`, too
+    repo.write_test_config(r#"worktree-path = "../{{ branch | sanitize }}""#);
+    let output = repo
+        .wt_command()
+        .current_dir(&main)
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        git_stdout(&repo, &app.join("feature"), &["branch", "--show-current"]),
+        "feature"
+    );
+}
+
+#[rstest]
+fn test_clone_bare_keeps_custom_worktree_path(repo: TestRepo) {
+    repo.write_test_config(r#"worktree-path = "{{ repo_path }}/../wt/{{ branch }}""#);
+
+    let output = clone_cmd(&repo, &["--bare", "app"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let main = repo.home_path().join("app/wt/main");
+    assert_eq!(
+        git_stdout(&repo, &main, &["branch", "--show-current"]),
+        "main"
+    );
+    let config = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert!(!config.contains("[projects"), "{config}");
+}
+
+/// `--bare` refuses a default-branch worktree outside `allowed-worktree-roots`.
+#[rstest]
+fn test_clone_bare_outside_allowed_roots(repo: TestRepo) {
+    let allowed = repo.home_path().join("allowed");
+    std::fs::create_dir(&allowed).unwrap();
+    repo.write_test_config(&format!(
+        "allowed-worktree-roots = [{:?}]",
+        allowed.display().to_string()
+    ));
+
+    let output = clone_cmd(&repo, &["--bare", "app"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("outside allowed-worktree-roots"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(!repo.home_path().join("app/main").exists());
+    let config = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert!(!config.contains("[projects"), "{config}");
+}
+
+#[rstest]
+fn test_clone_refuses_non_empty_directory(repo: TestRepo) {
+    let app = repo.home_path().join("app");
+    std::fs::create_dir(&app).unwrap();
+    std::fs::write(app.join("notes.txt"), "keep me").unwrap();
+
+    let output = clone_cmd(&repo, &["app"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("already exists and is not empty"),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(
+        std::fs::read_to_string(app.join("notes.txt")).unwrap(),
+        "keep me"
+    );
+}
+
+/// `--offline` refuses the clone before git runs, even from a local path.
+#[rstest]
+fn test_clone_offline(repo: TestRepo) {
+    let output = clone_cmd(&repo, &["--offline", "app"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("--offline forbids"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(!repo.home_path().join("app").exists());
+}
@@ -593,13 +593,15 @@ fn test_complete_step_subcommands(repo: TestRepo) {
     );
     assert!(subcommands.contains(&"diff"), "Missing diff");
     assert!(subcommands.contains(&"for-each"), "Missing for-each");
+    assert!(subcommands.contains(&"graduate"), "Missing graduate");
+    assert!(subcommands.contains(&"optimize"), "Missing optimize");
     assert!(subcommands.contains(&"promote"), "Missing promote");
     assert!(subcommands.contains(&"prune"), "Missing prune");
     assert!(subcommands.contains(&"relocate"), "Missing relocate");
     assert_eq!(
         subcommands.len(),
-        10,
-        "Should have exactly 10 step subcommands"
+        12,
+        "Should have exactly 12 step subcommands"
     );
 }
 
@@ -1259,3 +1261,34 @@ fn test_complete_switch_shows_all_remotes_for_ambiguous_branch(mut repo: TestRep
         "Should show both remotes for ambiguous branch: {stdout}"
     );
 }
+
+/// `wt __complete <shell> -- <words>` speaks the same protocol as `COMPLETE=<shell> wt -- <words>`.
+#[rstest]
+fn test_complete_subcommand_matches_env_protocol(repo: TestRepo) {
+    repo.commit("initial");
+    repo.run_git(&["branch", "feature/new"]);
+
+    for words in [&["wt", "switch", ""][..], &["wt", "list", "--"][..]] {
+        let env_output = repo
+            .completion_cmd_for_shell(words, "fish")
+            .output()
+            .unwrap();
+        assert!(env_output.status.success());
+
+        let mut cmd = repo.wt_command();
+        cmd.args(["__complete", "fish", "--"]).args(words);
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&env_output.stdout),
+            "__complete diverged from COMPLETE=fish for {words:?}"
+        );
+    }
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["__complete", "fish", "--", "wt", "switch", "feat"]);
+    let output = cmd.output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).contains("feature/new"));
+}
@@ -0,0 +1,55 @@
+//! Integration tests for versioned config migration (`wt config migrate`)
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use std::fs;
+
+#[rstest]
+fn test_config_migrate_rewrites_renamed_settings(repo: TestRepo) {
+    repo.write_project_config(
+        "# Install dependencies\npost-create = \"cd {{ repo_root }} && npm install\"\n",
+    );
+    let config_path = repo.root_path().join(".config").join("wt.toml");
+
+    let output = repo
+        .wt_command()
+        .args(["config", "migrate"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Project config migrated from version 1 to 2"),
+        "{stderr}"
+    );
+
+    assert_eq!(
+        fs::read_to_string(&config_path).unwrap(),
+        "version = 2\n\n# Install dependencies\npost-create = \"cd {{ repo_path }} && npm install\"\n"
+    );
+    let backup = fs::read_to_string(config_path.with_extension("toml.bak")).unwrap();
+    assert!(backup.contains("{{ repo_root }}"));
+
+    // A second run has nothing to do
+    let output = repo
+        .wt_command()
+        .args(["config", "migrate"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Project config is up to date"), "{stderr}");
+}
+
+#[rstest]
+fn test_config_newer_version_asks_for_upgrade(repo: TestRepo) {
+    repo.write_project_config("version = 99\npost-create = \"npm install\"\n");
+
+    let output = repo
+        .wt_command()
+        .args(["config", "validate"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Please upgrade worktrunk"), "{stderr}");
+}
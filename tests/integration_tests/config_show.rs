@@ -1260,6 +1260,57 @@ fn test_config_show_full_update_available(mut repo: TestRepo, temp_home: TempDir
     });
 }
 
+/// With `update-check = true`, diagnostics report the last background check.
+/// `etcetera` only reads `XDG_CACHE_HOME` off Windows.
+#[cfg(not(windows))]
+#[rstest]
+fn test_config_show_full_last_update_check(mut repo: TestRepo, temp_home: TempDir) {
+    repo.setup_mock_ci_tools_unauthenticated();
+
+    let global_config_dir = temp_home.path().join(".config").join("worktrunk");
+    fs::create_dir_all(&global_config_dir).unwrap();
+    fs::write(
+        global_config_dir.join("config.toml"),
+        "update-check = true\n",
+    )
+    .unwrap();
+    let cache_home = temp_home.path().join(".cache");
+
+    let run = || {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        repo.configure_mock_commands(&mut cmd);
+        cmd.env("WORKTRUNK_TEST_LATEST_VERSION", env!("CARGO_PKG_VERSION"));
+        cmd.env("XDG_CACHE_HOME", &cache_home);
+        cmd.args(["config", "show", "--full"])
+            .current_dir(repo.root_path());
+        set_temp_home_env(&mut cmd, temp_home.path());
+        set_xdg_config_path(&mut cmd, temp_home.path());
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        ansi_str::AnsiStr::ansi_strip(&*String::from_utf8_lossy(&output.stderr)).into_owned()
+    };
+
+    assert!(run().contains("Update check enabled; not run yet"));
+
+    // One hour before WORKTRUNK_TEST_EPOCH
+    let state_dir = cache_home.join("worktrunk");
+    fs::create_dir_all(&state_dir).unwrap();
+    fs::write(
+        state_dir.join("update-check.json"),
+        r#"{"version":1,"state":{"checked_at":1735772400,"latest":"99.0.0"}}"#,
+    )
+    .unwrap();
+    assert!(run().contains("Last update check 1h ago: found 99.0.0"));
+
+    fs::write(
+        state_dir.join("update-check.json"),
+        r#"{"version":1,"state":{"checked_at":1735772400,"latest":null}}"#,
+    )
+    .unwrap();
+    assert!(run().contains("Last update check 1h ago: request failed"));
+}
+
 #[rstest]
 fn test_config_show_full_version_check_unavailable(mut repo: TestRepo, temp_home: TempDir) {
     repo.setup_mock_ci_tools_unauthenticated();
@@ -2660,3 +2711,45 @@ fn test_explicit_config_path_not_found_shows_warning(repo: TestRepo) {
         assert_cmd_snapshot!(cmd);
     });
 }
+
+/// A worktree created before the project config existed falls back to the
+/// primary worktree's `.config/wt.toml`; its own copy wins once it has one.
+#[rstest]
+fn test_config_show_project_config_from_primary_worktree(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.write_project_config("post-create = \"echo from-primary\"\n");
+
+    let show = || {
+        let output = repo
+            .wt_command()
+            .args(["config", "show"])
+            .current_dir(&feature)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        )
+    };
+
+    let fallback = show();
+    assert!(fallback.contains("from-primary"), "{fallback}");
+    assert!(
+        fallback.contains("using the primary worktree's"),
+        "{fallback}"
+    );
+
+    fs::create_dir_all(feature.join(".config")).unwrap();
+    fs::write(
+        feature.join(".config").join("wt.toml"),
+        "post-create = \"echo from-feature\"\n",
+    )
+    .unwrap();
+
+    let local = show();
+    assert!(local.contains("from-feature"), "{local}");
+    assert!(!local.contains("from-primary"), "{local}");
+    assert!(!local.contains("using the primary worktree's"), "{local}");
+}
@@ -0,0 +1,94 @@
+//! Integration tests for `wt config validate` and `wt config schema`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn run_config(repo: &TestRepo, args: &[&str]) -> std::process::Output {
+    repo.wt_command().arg("config").args(args).output().unwrap()
+}
+
+#[rstest]
+fn test_config_validate_valid(repo: TestRepo) {
+    repo.write_test_config("worktree-path = \"../{{ repo }}.{{ branch }}\"\n");
+    repo.write_project_config("post-create = \"npm install\"\n");
+
+    let output = run_config(&repo, &["validate", "--strict"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("User config is valid"), "{stderr}");
+    assert!(stderr.contains("Project config is valid"), "{stderr}");
+}
+
+#[rstest]
+fn test_config_validate_unknown_keys_fail_only_when_strict(repo: TestRepo) {
+    repo.write_project_config("post-create = \"npm install\"\nnot-a-key = true\n");
+
+    let output = run_config(&repo, &["validate"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("not-a-key"), "{stderr}");
+
+    let output = run_config(&repo, &["validate", "--strict"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("1 unknown key"), "{stderr}");
+}
+
+#[rstest]
+fn test_config_validate_invalid_toml(repo: TestRepo) {
+    repo.write_project_config("post-create = [\n");
+
+    let output = run_config(&repo, &["validate"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Project config is invalid"), "{stderr}");
+}
+
+#[rstest]
+fn test_config_schema(repo: TestRepo) {
+    let properties = |args: &[&str]| {
+        let output = run_config(&repo, args);
+        assert!(output.status.success());
+        let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        schema["properties"].as_object().unwrap().clone()
+    };
+
+    let user = properties(&["schema"]);
+    assert!(user.contains_key("worktree-path"));
+    assert!(user.contains_key("projects"));
+
+    let project = properties(&["schema", "--project"]);
+    assert!(project.contains_key("post-create"));
+    assert!(!project.contains_key("projects"));
+}
+
+#[rstest]
+fn test_config_validate_alias_shadows_builtin(repo: TestRepo) {
+    repo.write_test_config("[alias]\nsw = \"switch --create\"\nlist = \"list --full\"\n");
+
+    let output = run_config(&repo, &["validate"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Alias list shadows"), "{stderr}");
+    assert!(!stderr.contains("Alias sw"), "{stderr}");
+}
+
+#[rstest]
+fn test_config_validate_unknown_hook(repo: TestRepo) {
+    repo.write_project_config("pre-sync = \"git fetch\"\nnot-a-key = true\n");
+
+    let output = run_config(&repo, &["validate"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("Project config has unknown hook pre-sync"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("Hooks are pre-switch, post-create"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("not-a-key"), "{stderr}");
+}
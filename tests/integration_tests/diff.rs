@@ -0,0 +1,97 @@
+//! Integration tests for `wt diff`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use std::fs;
+
+/// Create a feature worktree with one committed file ahead of main
+fn setup_feature_with_commit(repo: &mut TestRepo) -> std::path::PathBuf {
+    let feature_path = repo.add_worktree("feature");
+    fs::write(feature_path.join("feature.txt"), "one\ntwo\nthree\n").unwrap();
+    repo.run_git_in(&feature_path, &["add", "feature.txt"]);
+    repo.run_git_in(&feature_path, &["commit", "-m", "Add feature file"]);
+    feature_path
+}
+
+fn diff_json(repo: &TestRepo, args: &[&str]) -> serde_json::Value {
+    let output = repo
+        .wt_command()
+        .arg("diff")
+        .args(args)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[rstest]
+fn test_diff_json_reports_per_file_counts(mut repo: TestRepo) {
+    setup_feature_with_commit(&mut repo);
+
+    let json = diff_json(&repo, &["feature"]);
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(json["target"], "main");
+    assert_eq!(json["working"], false);
+    assert_eq!(
+        json["files"],
+        serde_json::json!([{ "path": "feature.txt", "added": 3, "deleted": 0 }])
+    );
+}
+
+#[rstest]
+fn test_diff_working_includes_uncommitted_changes(mut repo: TestRepo) {
+    let feature_path = setup_feature_with_commit(&mut repo);
+    fs::write(feature_path.join("feature.txt"), "one\nthree\nfour\nfive\n").unwrap();
+
+    // Committed only: the edit is invisible
+    let json = diff_json(&repo, &["feature"]);
+    assert_eq!(json["files"][0]["added"], 3);
+
+    // Against the worktree directory: the edit counts
+    let json = diff_json(&repo, &["feature", "--working"]);
+    assert_eq!(json["working"], true);
+    assert_eq!(
+        json["files"],
+        serde_json::json!([{ "path": "feature.txt", "added": 4, "deleted": 0 }])
+    );
+}
+
+#[rstest]
+fn test_diff_table_aligns_counts(mut repo: TestRepo) {
+    let feature_path = setup_feature_with_commit(&mut repo);
+    fs::write(feature_path.join("big.txt"), "x\n".repeat(120)).unwrap();
+    repo.run_git_in(&feature_path, &["add", "big.txt"]);
+    repo.run_git_in(&feature_path, &["commit", "-m", "Add big file"]);
+
+    let output = repo
+        .wt_command()
+        .args(["diff", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = ansi_str::AnsiStr::ansi_strip(stdout.as_ref());
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "{stdout}");
+    assert!(lines[0].starts_with("+120"), "{stdout}");
+    assert!(lines[1].starts_with("+  3"), "{stdout}");
+    // Paths start in the same column
+    assert_eq!(lines[0].find("big.txt"), lines[1].find("feature.txt"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("2 files, +123 -0"), "{stderr}");
+}
+
+#[rstest]
+fn test_diff_unknown_branch_errors(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["diff", "no-such-branch"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
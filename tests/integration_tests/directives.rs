@@ -336,6 +336,96 @@ fn test_switch_no_cd_execute_runs_in_target_worktree(#[from(repo_with_remote)] r
     });
 }
 
+/// `--execute` payload with newlines, both quote styles, `$` and non-ASCII text.
+const TRICKY_EXECUTE_PAYLOAD: &str =
+    "printf '%s\\n' \"it's quoted\"\necho 'cost: $5'\necho \"naïve ✓ 日本\"";
+
+#[rstest]
+fn test_switch_execute_payload_written_verbatim(#[from(repo_with_remote)] repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    cmd.args([
+        "switch",
+        "--create",
+        "tricky-exec",
+        "--execute",
+        TRICKY_EXECUTE_PAYLOAD,
+    ])
+    .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    // The payload is the last directive, byte-for-byte, so the wrapper sources it as typed
+    let directives = fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directives.ends_with(&format!("\n{TRICKY_EXECUTE_PAYLOAD}\n")),
+        "Execute payload was altered in the directive file: {directives:?}"
+    );
+}
+
+#[cfg(unix)]
+#[rstest]
+fn test_switch_execute_payload_runs_without_directive_file(repo: TestRepo) {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args([
+        "switch",
+        "--create",
+        "tricky-exec",
+        "--execute",
+        TRICKY_EXECUTE_PAYLOAD,
+    ])
+    .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    // Passed to `sh -c` as a single argument: every line runs, nothing is re-split
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "it's quoted\ncost: $5\nnaïve ✓ 日本\n"
+    );
+}
+
+/// In shell integration mode, failures must not leak anything the wrapper would act on:
+/// errors and hints go to stderr, stdout stays empty, and no directive is written.
+#[rstest]
+#[case::switch_missing_branch(&["switch", "nonexistent-branch"])]
+#[case::switch_execute_missing_branch(&["switch", "--execute", "echo hi", "nonexistent-branch"])]
+#[case::remove_missing_branch(&["remove", "nonexistent"])]
+#[case::invalid_shell(&["config", "shell", "init", "not-a-shell"])]
+#[case::unknown_flag(&["list", "--no-such-flag"])]
+fn test_failures_write_no_directives(
+    #[from(repo_with_remote)] repo: TestRepo,
+    #[case] args: &[&str],
+) {
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    cmd.args(args).current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+
+    assert!(!output.status.success(), "{args:?} should fail");
+    assert!(
+        !output.stderr.is_empty(),
+        "{args:?} should explain the failure on stderr"
+    );
+    assert!(
+        output.stdout.is_empty(),
+        "{args:?} wrote to stdout in shell integration mode: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let directives = fs::read_to_string(&directive_path).unwrap_or_default();
+    assert!(
+        directives.is_empty(),
+        "{args:?} wrote directives despite failing: {directives}"
+    );
+}
+
 // ============================================================================
 // Non-Directive Mode Tests (no WORKTRUNK_DIRECTIVE_FILE)
 // ============================================================================
@@ -0,0 +1,57 @@
+//! Integration tests for `wt events`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use std::fs;
+
+// Git reports Windows paths with forward slashes, unlike `Path::display`
+#[cfg(unix)]
+#[rstest]
+fn test_events_once_prints_current_state(mut repo: TestRepo) {
+    repo.remove_fixture_worktrees();
+    let feature_path = repo.add_worktree("feature");
+    fs::write(feature_path.join("scratch.txt"), "wip\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["events", "--once"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<_> = stdout.lines().collect();
+
+    let main_path = repo.root_path().display().to_string();
+    let feature_path = feature_path.display().to_string();
+    assert_eq!(lines.len(), 4, "{stdout}");
+    assert!(
+        lines.contains(&format!("worktree-created main {main_path}").as_str()),
+        "{stdout}"
+    );
+    assert!(
+        lines.contains(&format!("branch-clean main {main_path}").as_str()),
+        "{stdout}"
+    );
+    assert!(
+        lines.contains(&format!("worktree-created feature {feature_path}").as_str()),
+        "{stdout}"
+    );
+    assert!(
+        lines.contains(&format!("branch-dirty feature {feature_path}").as_str()),
+        "{stdout}"
+    );
+}
+
+#[rstest]
+fn test_events_follow_conflicts_with_once(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["events", "--follow", "--once"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
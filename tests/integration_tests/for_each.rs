@@ -1,6 +1,6 @@
 //! Integration tests for `wt step for-each`
 
-use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use crate::common::{TestRepo, make_snapshot_cmd, repo, wait_for_file_content};
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
 
@@ -44,6 +44,40 @@ fn test_for_each_no_args_error(repo: TestRepo) {
     assert_cmd_snapshot!(make_snapshot_cmd(&repo, "step", &["for-each"], None));
 }
 
+#[rstest]
+fn test_for_each_skips_locked(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.add_worktree("locked");
+    repo.lock_worktree("locked", Some("on external drive"));
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["for-each", "--", "git", "branch", "--show-current"],
+        None,
+    ));
+}
+
+#[rstest]
+fn test_for_each_include_locked(mut repo: TestRepo) {
+    repo.add_worktree("locked");
+    repo.lock_worktree("locked", None);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &[
+            "for-each",
+            "--include-locked",
+            "--",
+            "git",
+            "branch",
+            "--show-current"
+        ],
+        None,
+    ));
+}
+
 #[rstest]
 fn test_for_each_with_detached_head(mut repo: TestRepo) {
     repo.add_worktree("detached-test");
@@ -118,3 +152,68 @@ fn test_for_each_skips_prunable_worktrees(mut repo: TestRepo) {
         None,
     ));
 }
+
+/// Ctrl-C stops the batch: the running command's whole process tree is killed,
+/// later worktrees are skipped, and wt exits 130 after a partial summary.
+///
+/// The background `sleep` ignores SIGINT (as `&` jobs do in a non-interactive
+/// shell), so this also covers escalation to SIGTERM.
+#[rstest]
+#[cfg(unix)]
+fn test_for_each_sigint_kills_children(mut repo: TestRepo) {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+    use std::io::Read;
+    use std::os::unix::process::CommandExt;
+    use std::process::Stdio;
+
+    repo.add_worktree("feature");
+    let pid_file = repo.root_path().parent().unwrap().join("child.pid");
+    let command = format!("sleep 30 & echo $! > {}; wait", pid_file.display());
+
+    // Spawn wt in its own process group (so SIGINT to that group doesn't kill the test)
+    let mut cmd = crate::common::wt_command();
+    cmd.current_dir(repo.root_path());
+    cmd.args(["step", "for-each", "--", &command]);
+    cmd.stdout(Stdio::null());
+    cmd.stderr(Stdio::piped());
+    cmd.process_group(0);
+    let mut child = cmd.spawn().expect("failed to spawn wt step for-each");
+
+    wait_for_file_content(&pid_file);
+    let sleep_pid: i32 = std::fs::read_to_string(&pid_file)
+        .unwrap()
+        .trim()
+        .parse()
+        .unwrap();
+
+    // Real Ctrl-C signals the foreground process group, which is wt's
+    kill(Pid::from_raw(-(child.id() as i32)), Signal::SIGINT).expect("failed to send SIGINT");
+
+    let mut stderr = String::new();
+    child
+        .stderr
+        .take()
+        .unwrap()
+        .read_to_string(&mut stderr)
+        .unwrap();
+    let status = child.wait().expect("failed to wait for wt");
+    assert_eq!(status.code(), Some(130), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Interrupted in") && stderr.contains("after 0 of"),
+        "expected a partial summary; got: {stderr}"
+    );
+    assert_eq!(
+        stderr.matches("Running in").count(),
+        1,
+        "later worktrees should be skipped; got: {stderr}"
+    );
+
+    // The orphaned sleep is gone (a zombie awaiting reaping counts as gone)
+    let state = std::fs::read_to_string(format!("/proc/{sleep_pid}/stat")).ok();
+    let running = match state {
+        Some(stat) => stat.split_whitespace().nth(2) != Some("Z"),
+        None => kill(Pid::from_raw(sleep_pid), None).is_ok(),
+    };
+    assert!(!running, "child sleep {sleep_pid} outlived wt");
+}
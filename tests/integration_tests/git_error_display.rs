@@ -109,6 +109,7 @@ fn branch_not_found() {
     let err = GitError::BranchNotFound {
         branch: "nonexistent".into(),
         show_create_hint: true,
+        closest: None,
     };
 
     assert_snapshot!("branch_not_found", err.to_string());
@@ -119,6 +120,7 @@ fn branch_not_found_no_create_hint() {
     let err = GitError::BranchNotFound {
         branch: "nonexistent".into(),
         show_create_hint: false,
+        closest: None,
     };
 
     assert_snapshot!("branch_not_found_no_create_hint", err.to_string());
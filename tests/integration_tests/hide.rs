@@ -0,0 +1,126 @@
+//! Integration tests for `wt hide` / `wt unhide`
+
+use crate::common::{TestRepo, list_snapshots, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+fn wt(repo: &TestRepo, args: &[&str]) -> std::process::Output {
+    repo.wt_command().args(args).output().unwrap()
+}
+
+fn stderr(output: &std::process::Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    ansi_str::AnsiStr::ansi_strip(&*stderr).into_owned()
+}
+
+#[rstest]
+fn test_hide_list(mut repo: TestRepo) {
+    repo.add_worktree("gh-pages");
+    repo.add_worktree("feature");
+    assert!(wt(&repo, &["hide", "gh-pages"]).status.success());
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+    // Left out, and counted in the footer
+    assert_cmd_snapshot!(
+        "hide_list_default",
+        list_snapshots::command(&repo, repo.root_path())
+    );
+    // Listed with a marker
+    assert_cmd_snapshot!("hide_list_hidden", {
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.arg("--hidden");
+        cmd
+    });
+}
+
+#[rstest]
+fn test_hide_json(mut repo: TestRepo) {
+    repo.add_worktree("gh-pages");
+    assert!(wt(&repo, &["hide", "gh-pages"]).status.success());
+
+    let output = wt(&repo, &["list", "--hidden", "--format=json"]);
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let hidden: Vec<_> = items
+        .iter()
+        .filter(|item| item["is_hidden"] == true)
+        .map(|item| item["branch"].as_str().unwrap())
+        .collect();
+    assert_eq!(hidden, ["gh-pages"]);
+    // The branch field stays the plain name
+    assert!(items.iter().all(|item| item["branch"] != "gh-pages ⊘"));
+}
+
+#[rstest]
+fn test_hide_unhide_messages(mut repo: TestRepo) {
+    repo.add_worktree("gh-pages");
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "hide", &["gh-pages"], None));
+    assert_cmd_snapshot!(
+        "hide_already_hidden",
+        make_snapshot_cmd(&repo, "hide", &["gh-pages"], None)
+    );
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "unhide", &["gh-pages"], None));
+    assert_cmd_snapshot!(
+        "unhide_not_hidden",
+        make_snapshot_cmd(&repo, "unhide", &["gh-pages"], None)
+    );
+}
+
+#[rstest]
+fn test_hide_unknown_branch(repo: TestRepo) {
+    let output = wt(&repo, &["hide", "no-such-branch"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("no-such-branch"),
+        "{}",
+        stderr(&output)
+    );
+    let config = repo
+        .git_command()
+        .args(["config", "--get-regexp", "hidden"])
+        .output()
+        .unwrap();
+    assert!(config.stdout.is_empty());
+}
+
+#[rstest]
+fn test_hide_keeps_remove_safety_checks(mut repo: TestRepo) {
+    let path = repo.add_worktree("gh-pages");
+    std::fs::write(path.join("dirty.txt"), "uncommitted\n").unwrap();
+    assert!(wt(&repo, &["hide", "gh-pages"]).status.success());
+
+    // Naming a hidden worktree explicitly still refuses to drop changes
+    let output = wt(&repo, &["remove", "gh-pages", "--foreground"]);
+    assert!(!output.status.success());
+    assert!(path.exists());
+}
+
+#[rstest]
+fn test_prune_skips_hidden(mut repo: TestRepo) {
+    // At the default branch's commit, so integrated
+    repo.add_worktree("gh-pages");
+    assert!(wt(&repo, &["hide", "gh-pages"]).status.success());
+
+    let output = wt(&repo, &["step", "prune", "--dry-run", "--min-age=0s"]);
+    let text = stderr(&output);
+    assert!(output.status.success(), "{text}");
+    assert!(text.contains("Skipped gh-pages (hidden"), "{text}");
+    assert!(!text.contains("would be removed"), "{text}");
+
+    let output = wt(
+        &repo,
+        &[
+            "step",
+            "prune",
+            "--dry-run",
+            "--min-age=0s",
+            "--include-hidden",
+        ],
+    );
+    let text = stderr(&output);
+    assert!(text.contains("gh-pages —"), "{text}");
+    assert!(text.contains("would be removed"), "{text}");
+}
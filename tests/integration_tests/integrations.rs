@@ -0,0 +1,93 @@
+//! Integration tests for `[integrations]`: zoxide and path commands
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn stderr(output: &std::process::Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    ansi_str::AnsiStr::ansi_strip(&*stderr).into_owned()
+}
+
+/// A `zoxide` that appends its arguments to `zoxide.log`, first on PATH.
+#[cfg(unix)]
+fn fake_zoxide(repo: &TestRepo) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let bin = repo.home_path().join("zoxide-bin");
+    std::fs::create_dir_all(&bin).unwrap();
+    let script = bin.join("zoxide");
+    let log = bin.join("zoxide.log");
+    std::fs::write(
+        &script,
+        format!("#!/bin/sh\necho \"$@\" >> '{}'\n", log.display()),
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+    bin
+}
+
+#[cfg(unix)]
+fn wt_with_path(repo: &TestRepo, bin: &std::path::Path, args: &[&str]) -> std::process::Output {
+    let path = format!("{}:{}", bin.display(), std::env::var("PATH").unwrap());
+    repo.wt_command()
+        .args(args)
+        .env("PATH", path)
+        .output()
+        .unwrap()
+}
+
+#[cfg(unix)]
+#[rstest]
+fn test_integrations_register_worktree_paths(repo: TestRepo) {
+    let bin = fake_zoxide(&repo);
+    let events = bin.join("events.log");
+    repo.write_test_config(&format!(
+        r#"[integrations]
+zoxide = true
+on-path-created = "echo created {{{{ branch }}}} >> '{events}'"
+on-path-removed = "echo removed {{{{ branch }}}} >> '{events}'"
+"#,
+        events = events.display()
+    ));
+
+    for args in [
+        &["switch", "--create", "feature"][..],
+        &["switch", "feature"],
+        &["remove", "--foreground", "feature"],
+    ] {
+        let output = wt_with_path(&repo, &bin, args);
+        assert!(output.status.success(), "{}", stderr(&output));
+    }
+
+    let zoxide = std::fs::read_to_string(bin.join("zoxide.log")).unwrap();
+    let calls: Vec<_> = zoxide.lines().collect();
+    assert_eq!(calls.len(), 3, "{zoxide}");
+    assert!(calls[0].starts_with("add -- ") && calls[0].ends_with("repo.feature"));
+    assert_eq!(calls[1], calls[0]);
+    assert!(calls[2].starts_with("remove -- ") && calls[2].ends_with("repo.feature"));
+
+    // Path commands fire on creation and removal, not on plain switches
+    let events = std::fs::read_to_string(&events).unwrap();
+    assert_eq!(events, "created feature\nremoved feature\n");
+}
+
+#[rstest]
+fn test_integrations_failure_only_warns(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[integrations]
+zoxide = true
+on-path-created = "exit 3"
+"#,
+    );
+
+    // zoxide isn't installed here, so it's skipped without a word
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    let text = stderr(&output);
+    assert!(text.contains("on-path-created command failed"), "{text}");
+    assert!(!text.contains("zoxide"), "{text}");
+}
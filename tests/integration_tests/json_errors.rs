@@ -0,0 +1,133 @@
+//! Integration tests for `--json-errors`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::process::Output;
+
+/// The JSON object on the last line of stderr.
+fn error_json(output: &Output) -> serde_json::Value {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let last = stderr.lines().last().unwrap_or_default();
+    serde_json::from_str(last).unwrap_or_else(|e| panic!("{e}: {stderr}"))
+}
+
+#[rstest]
+fn test_json_errors_branch_not_found(repo: TestRepo) {
+    let output = make_snapshot_cmd(&repo, "switch", &["nonexistent", "--json-errors"], None)
+        .output()
+        .unwrap();
+    let json = error_json(&output);
+
+    assert_eq!(json["error"], "BranchNotFound");
+    assert!(
+        json["hints"][0]
+            .as_str()
+            .unwrap()
+            .contains("wt switch --create")
+    );
+    assert_eq!(json["details"]["branch"], "nonexistent");
+    assert_eq!(json["exit_code"], output.status.code().unwrap());
+
+    // Fields come in a fixed order
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr
+            .lines()
+            .last()
+            .unwrap()
+            .starts_with(r#"{"error":"BranchNotFound","message":"#),
+        "{stderr}"
+    );
+}
+
+/// A failing hook's exit code reaches both the JSON and the process.
+#[rstest]
+fn test_json_errors_hook_exit_code(mut repo: TestRepo) {
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("wt.toml"), r#"pre-merge = "exit 3""#).unwrap();
+    repo.commit("Add config");
+    let feature_wt = repo.add_feature();
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--json-errors"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    let json = error_json(&output);
+
+    assert_eq!(json["error"], "HookCommandFailed");
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(json["exit_code"], 3);
+}
+
+/// A styled plain-anyhow error reaches the JSON as plain text.
+#[rstest]
+fn test_json_errors_strips_styling(repo: TestRepo) {
+    let app = repo.home_path().join("app");
+    fs::create_dir(&app).unwrap();
+    fs::write(app.join("notes.txt"), "keep me").unwrap();
+
+    let mut cmd = repo.wt_command();
+    cmd.current_dir(repo.home_path())
+        .args(["--json-errors", "clone", "origin.git", "app"]);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!("json_errors_strips_styling", cmd);
+    });
+}
+
+/// Success output is unaffected by the flag.
+#[rstest]
+fn test_json_errors_success_unchanged(repo: TestRepo) {
+    let output = make_snapshot_cmd(&repo, "list", &["--json-errors"], None)
+        .output()
+        .unwrap();
+    let plain = make_snapshot_cmd(&repo, "list", &[], None)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(output.stdout, plain.stdout);
+    assert_eq!(output.stderr, plain.stderr);
+}
+
+/// Pinning the current schema version leaves output unchanged; any other
+/// version fails before the command runs, naming the supported ones.
+#[rstest]
+fn test_schema_version(repo: TestRepo) {
+    let pinned = make_snapshot_cmd(
+        &repo,
+        "list",
+        &["--format=json", "--schema-version=1"],
+        None,
+    )
+    .output()
+    .unwrap();
+    let plain = make_snapshot_cmd(&repo, "list", &["--format=json"], None)
+        .output()
+        .unwrap();
+    assert!(pinned.status.success());
+    assert_eq!(pinned.stdout, plain.stdout);
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "list",
+        &["--format=json", "--schema-version=2"],
+        None,
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    assert!(
+        stderr.contains("schema version 2 is not supported by this wt; supported: 1"),
+        "{stderr}"
+    );
+}
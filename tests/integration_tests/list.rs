@@ -192,6 +192,33 @@ fn test_list_unicode_commit_message(mut repo: TestRepo) {
     assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
 }
 
+/// Control characters in a subject (tabs, CR, the ESC of an escape sequence)
+/// become single spaces in the table; JSON keeps the subject as git has it.
+#[rstest]
+fn test_list_commit_message_control_characters(mut repo: TestRepo) {
+    let message = "Squash:\tfirst \x1b[31mred\x1b[0m\rsecond\nwrapped line";
+    let feature_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_path, "file.txt", "x", message);
+
+    assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .arg("--format=json")
+        .output()
+        .unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(
+        feature["commit"]["message"],
+        "Squash:\tfirst \x1b[31mred\x1b[0m\rsecond wrapped line"
+    );
+}
+
 #[rstest]
 fn test_list_many_worktrees_with_varied_stats(mut repo: TestRepo) {
     // Create multiple worktrees with different characteristics
@@ -2708,8 +2735,12 @@ fn test_list_shows_warning_on_git_error(mut repo: TestRepo) {
     let git_dir = repo.root_path().join(".git");
     let ref_path = git_dir.join("refs/heads/feature");
 
-    // Write an invalid SHA that doesn't exist in the repo
-    std::fs::write(&ref_path, "0000000000000000000000000000000000000000\n").unwrap();
+    // Write an invalid SHA that doesn't exist in the repo (not the null OID,
+    // which reads as a branch deleted under its worktree). Every task that
+    // reads the commit fails and is listed. The row still shows the SHA from
+    // the ref, and commit details fall back to their defaults, so the age is
+    // measured from the Unix epoch (55y).
+    std::fs::write(&ref_path, "1111111111111111111111111111111111111111\n").unwrap();
 
     assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
 }
@@ -2966,3 +2997,908 @@ fn test_list_empty_repo_json() {
     assert_eq!(item["commit"]["sha"], "");
     assert_eq!(item["commit"]["short_sha"], "");
 }
+
+/// Skip-worktree edits are hidden from `git status`, so they don't make a
+/// worktree dirty unless `--include-skipped` is passed.
+#[rstest]
+fn test_list_skip_worktree_edits(mut repo: TestRepo) {
+    let feature_path =
+        repo.add_worktree_with_commit("feature", "config.local", "default", "Add config");
+    std::fs::write(feature_path.join("config.local"), "local override").unwrap();
+    repo.run_git_in(
+        &feature_path,
+        &["update-index", "--skip-worktree", "config.local"],
+    );
+
+    let modified = |include_skipped: bool| {
+        let mut cmd = repo.wt_command();
+        cmd.current_dir(repo.root_path())
+            .args(["list", "--format=json"]);
+        if include_skipped {
+            cmd.arg("--include-skipped");
+        }
+        let output = cmd.output().unwrap();
+        let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+        let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+        feature["working_tree"]["modified"].clone()
+    };
+
+    assert_eq!(
+        modified(false),
+        false,
+        "skip-worktree edits should be hidden"
+    );
+    assert_eq!(
+        modified(true),
+        true,
+        "--include-skipped should surface them"
+    );
+}
+
+/// Files outside a sparse checkout carry the skip-worktree bit but aren't on
+/// disk; `--include-skipped` doesn't count them as edits.
+#[rstest]
+fn test_list_include_skipped_ignores_sparse_checkout(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    std::fs::create_dir(feature_path.join("docs")).unwrap();
+    repo.commit_in_worktree(&feature_path, "docs/guide.md", "guide", "Add guide");
+    repo.run_git_in(
+        &feature_path,
+        &["sparse-checkout", "set", "--no-cone", "/*", "!/docs/"],
+    );
+    assert!(!feature_path.join("docs/guide.md").exists());
+
+    let output = repo
+        .wt_command()
+        .current_dir(repo.root_path())
+        .args(["list", "--format=json", "--include-skipped"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+    assert_eq!(feature["working_tree"]["modified"], false);
+}
+
+/// `--deterministic` measures ages from a fixed instant instead of the clock.
+#[rstest]
+fn test_list_deterministic_ages(repo: TestRepo) {
+    let list_output = |configure: &dyn Fn(&mut std::process::Command)| {
+        let mut cmd = repo.wt_command();
+        cmd.env_remove("WORKTRUNK_TEST_EPOCH").arg("list");
+        configure(&mut cmd);
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        ansi_str::AnsiStr::ansi_strip(&*String::from_utf8_lossy(&output.stdout)).into_owned()
+    };
+
+    // Fixture commits are dated 16 hours before the reference instant
+    let flag = list_output(&|cmd| {
+        cmd.arg("--deterministic");
+    });
+    assert!(flag.contains(" 16h "), "{flag}");
+
+    let env = list_output(&|cmd| {
+        cmd.env("WORKTRUNK_DETERMINISTIC", "1");
+    });
+    assert_eq!(flag, env);
+}
+
+#[rstest]
+fn test_list_ellipsizes_divergent_worktree_paths(mut repo: TestRepo) {
+    // A worktree outside the sibling layout forces the Path column and a long relative path
+    let elsewhere = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join("unrelated-root/with/several/levels/of/nesting/feature-login-rework");
+    repo.add_worktree_at_path("login", &elsewhere);
+
+    let list_output = |repo: &TestRepo| {
+        let output = repo.wt_command().arg("list").output().unwrap();
+        assert!(output.status.success());
+        ansi_str::AnsiStr::ansi_strip(&*String::from_utf8_lossy(&output.stdout)).into_owned()
+    };
+
+    let capped = list_output(&repo);
+    let row = capped.lines().find(|l| l.contains("login")).unwrap();
+    let tail = format!("…{}feature-login-rework", std::path::MAIN_SEPARATOR);
+    assert!(row.contains(&tail), "{capped}");
+    assert!(!row.contains("nesting"), "{capped}");
+
+    // JSON keeps the full path
+    let json = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json = String::from_utf8_lossy(&json.stdout);
+    assert!(json.contains("nesting"), "{json}");
+
+    // 0 disables the cap
+    repo.write_test_config("[list]\nmax-path-width = 0\n");
+    let uncapped = list_output(&repo);
+    let row = uncapped.lines().find(|l| l.contains("login")).unwrap();
+    assert!(row.contains("nesting"), "{uncapped}");
+    assert!(!row.contains('…'), "{uncapped}");
+}
+
+#[rstest]
+fn test_list_format_tsv(repo: TestRepo) {
+    // A branch without a worktree, and uncommitted lines in one worktree
+    repo.run_git(&["branch", "standalone"]);
+    std::fs::write(
+        repo.worktree_path("feature-a").join("new.txt"),
+        "one\ntwo\n",
+    )
+    .unwrap();
+    repo.run_git_in(repo.worktree_path("feature-a"), &["add", "new.txt"]);
+
+    let mut cmd = list_snapshots::command(&repo, repo.root_path());
+    cmd.args(["--format=tsv", "--branches"]);
+    assert_cmd_snapshot!(cmd);
+}
+
+/// A deadline that has already passed leaves every gathered cell as `~`; the
+/// command still succeeds and names what's missing on stderr.
+#[rstest]
+fn test_list_timeout_marks_incomplete(repo: TestRepo) {
+    let mut cmd = list_snapshots::command(&repo, repo.root_path());
+    cmd.args(["--timeout", "0"]);
+    assert_cmd_snapshot!(cmd);
+
+    let mut cmd = list_snapshots::command(&repo, repo.root_path());
+    cmd.args(["--timeout", "0", "--format=json"]);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    for item in json.as_array().unwrap() {
+        let incomplete = item["incomplete"].as_array().unwrap();
+        assert!(incomplete.contains(&"commit".into()), "{item}");
+        assert!(incomplete.contains(&"working_tree".into()), "{item}");
+    }
+
+    // A deadline that's never reached changes nothing
+    let json_with = |args: &[&str]| {
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.arg("--format=json").args(args);
+        serde_json::from_slice::<serde_json::Value>(&cmd.output().unwrap().stdout).unwrap()
+    };
+    assert_eq!(json_with(&["--timeout", "1h"]), json_with(&[]));
+}
+
+/// At width 100, a long branch name and a divergent path don't both fit at full
+/// width: `layout = "drop"` hides Path, `layout = "shrink"` truncates both.
+#[rstest]
+fn test_list_layout_drop_vs_shrink(mut repo: TestRepo) {
+    let elsewhere = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join("checkouts/billing-export");
+    repo.add_worktree_at_path("billing-export-to-parquet-for-finance", &elsewhere);
+
+    for mode in ["drop", "shrink"] {
+        repo.write_test_config(&format!("[list]\nlayout = \"{mode}\"\n"));
+        assert_cmd_snapshot!(
+            format!("list_layout_{mode}"),
+            list_snapshots::command_with_width(&repo, 100)
+        );
+    }
+}
+
+/// At width 100, `--density compact` closes the gaps between columns enough to
+/// fit columns that normal density hides.
+#[rstest]
+fn test_list_density_compact(mut repo: TestRepo) {
+    let elsewhere = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join("checkouts/billing-export");
+    repo.add_worktree_at_path("billing-export-to-parquet-for-finance", &elsewhere);
+
+    for density in ["normal", "compact"] {
+        assert_cmd_snapshot!(format!("list_density_{density}"), {
+            let mut cmd = list_snapshots::command_with_width(&repo, 100);
+            cmd.args(["--density", density]);
+            cmd
+        });
+    }
+}
+
+/// `main…±` diffs from the merge-base by default; `--branch-diff-base=head`
+/// diffs against the default branch tip, which also counts main's new commits.
+#[rstest]
+fn test_list_branch_diff_base(mut repo: TestRepo) {
+    repo.add_feature();
+    let merge_base = repo.head_sha();
+
+    // Main advances past the branch point
+    std::fs::write(repo.root_path().join("main.txt"), "one\ntwo\n").unwrap();
+    repo.run_git(&["add", "main.txt"]);
+    repo.run_git(&["commit", "-m", "Advance main"]);
+    let main_tip = repo.head_sha();
+
+    let feature_main = |extra: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--full", "--format=json"])
+            .args(extra)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+        json.into_iter()
+            .find(|item| item["branch"] == "feature")
+            .unwrap()["main"]
+            .clone()
+    };
+
+    let main = feature_main(&[]);
+    assert_eq!(main["diff"]["added"], 1, "{main}");
+    assert_eq!(main["diff"]["deleted"], 0, "{main}");
+    assert_eq!(main["diff_base"], merge_base.as_str(), "{main}");
+    assert_eq!(main["behind"], 1, "{main}");
+
+    // The raw tip diff also shows main.txt as deleted; ahead/behind are unchanged
+    let main = feature_main(&["--branch-diff-base", "head"]);
+    assert_eq!(main["diff"]["added"], 1, "{main}");
+    assert_eq!(main["diff"]["deleted"], 2, "{main}");
+    assert_eq!(main["diff_base"], main_tip.as_str(), "{main}");
+    assert_eq!(main["behind"], 1, "{main}");
+}
+
+/// JSON reports how many files would conflict, and omits the count for clean merges.
+#[rstest]
+fn test_list_json_conflicted_files(mut repo: TestRepo) {
+    for file in ["a.txt", "b.txt", "c.txt"] {
+        std::fs::write(repo.root_path().join(file), "original\n").unwrap();
+    }
+    repo.commit("Add shared files");
+
+    let feature = repo.add_worktree("feature");
+    repo.add_worktree("clean");
+    for file in ["a.txt", "b.txt"] {
+        std::fs::write(feature.join(file), "feature\n").unwrap();
+    }
+    repo.run_git_in(&feature, &["commit", "-am", "Feature edits"]);
+
+    // Main edits the same two files differently; c.txt stays untouched
+    for file in ["a.txt", "b.txt"] {
+        std::fs::write(repo.root_path().join(file), "main\n").unwrap();
+    }
+    repo.commit("Main edits");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let main_of =
+        |branch: &str| json.iter().find(|item| item["branch"] == branch).unwrap()["main"].clone();
+
+    let feature_main = main_of("feature");
+    assert_eq!(feature_main["conflicted_files"], 2, "{feature_main}");
+    let clean_main = main_of("clean");
+    assert!(clean_main.get("conflicted_files").is_none(), "{clean_main}");
+}
+
+/// `--summary` adds a behind count to the footer and wraps JSON output in an
+/// object with totals.
+#[rstest]
+fn test_list_summary(mut repo: TestRepo) {
+    repo.remove_fixture_worktrees();
+    let behind = repo.add_worktree("behind");
+    repo.commit("Advance main");
+    repo.add_worktree_with_commit("ahead", "ahead.txt", "content", "Ahead of main");
+    std::fs::write(behind.join("untracked.txt"), "x").unwrap();
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--format=json", "--summary"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["items"].as_array().unwrap().len(), 3);
+    let summary = &json["summary"];
+    assert_eq!(summary["worktrees"], 3);
+    assert_eq!(summary["dirty"], 1);
+    assert_eq!(summary["ahead"], 1);
+    assert_eq!(summary["behind"], 1);
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .arg("--summary")
+        .output()
+        .unwrap();
+    let footer = String::from_utf8_lossy(&output.stdout);
+    assert!(footer.contains("1 behind"), "{footer}");
+}
+
+/// `--against` counts commits and diffs against another ref, names it in the
+/// headers, and leaves that branch's own row uncompared.
+#[rstest]
+fn test_list_against(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.run_git(&["checkout", "-q", "-b", "release"]);
+    repo.commit("Release fix");
+    repo.run_git(&["checkout", "-q", "main"]);
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--format=json", "--branches", "--against", "release"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let item = |branch: &str| json.iter().find(|i| i["branch"] == branch).unwrap();
+    assert_eq!(item("feature")["main"]["behind"], 1);
+    assert_eq!(item("feature")["main"]["against"], "release");
+    // The main worktree is compared; the release branch is not
+    assert_eq!(item("main")["main"]["behind"], 1);
+    assert!(item("release").get("main").is_none());
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--against", "release"])
+        .output()
+        .unwrap();
+    let table = String::from_utf8_lossy(&output.stdout);
+    assert!(table.contains("release↕"), "{table}");
+
+    // Unknown refs fail before rendering anything
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--against", "nope"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No branch, tag, or commit named"),
+        "{stderr}"
+    );
+}
+
+/// `--current` lists only the worktree the command runs from, and emits a
+/// single JSON object rather than an array.
+#[rstest]
+fn test_list_current(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    repo.add_worktree("other");
+
+    let output = list_snapshots::command(&repo, &feature_path)
+        .args(["--current", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json.is_object(), "{json}");
+    assert_eq!(json["branch"], "feature");
+
+    let output = list_snapshots::command(&repo, &feature_path)
+        .arg("--current")
+        .output()
+        .unwrap();
+    let table = String::from_utf8_lossy(&output.stdout);
+    assert!(table.contains("feature"), "{table}");
+    assert!(!table.contains("other"), "{table}");
+}
+
+/// `--no-align` streams tab-separated rows, and can't be combined with the
+/// progressive table.
+#[rstest]
+fn test_list_no_align(mut repo: TestRepo) {
+    repo.remove_fixture_worktrees();
+    repo.add_worktree("feature");
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .arg("--no-align")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(
+        lines[0].contains("Branch") && lines[0].contains('\t'),
+        "{stdout}"
+    );
+    let rows: Vec<&&str> = lines[1..]
+        .iter()
+        .take_while(|line| !line.is_empty())
+        .collect();
+    assert_eq!(rows.len(), 2, "{stdout}");
+    assert!(rows.iter().all(|row| row.contains('\t')), "{stdout}");
+    assert!(rows.iter().any(|row| row.contains("feature")), "{stdout}");
+    assert!(stdout.contains("Showing 2 worktrees"), "{stdout}");
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--no-align", "--progressive"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+/// `--long` prints one labeled block per worktree without truncating values,
+/// and rejects JSON output.
+#[rstest]
+fn test_list_long(mut repo: TestRepo) {
+    repo.remove_fixture_worktrees();
+    let feature_path = repo.add_worktree("feature");
+    let message = format!("Long subject {}", "that keeps going ".repeat(12));
+    repo.commit_in_worktree(&feature_path, "long.txt", "content", message.trim());
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .arg("--long")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = ansi_str::AnsiStr::ansi_strip(&*stdout);
+    let blocks: Vec<&str> = stdout.split("\n\n").collect();
+    assert_eq!(blocks.len(), 2, "{stdout}");
+    assert!(stdout.contains(message.trim()), "{stdout}");
+
+    let feature = blocks
+        .iter()
+        .find(|block| block.contains("feature"))
+        .unwrap_or_else(|| panic!("{stdout}"));
+    assert!(feature.contains("Path:"), "{feature}");
+    assert!(feature.contains("Main ↕:"), "{feature}");
+    // Values start in the same column within a block
+    let value_columns: Vec<usize> = feature
+        .lines()
+        .map(|line| {
+            let (label, rest) = line.split_once(':').unwrap();
+            label.chars().count() + 1 + rest.len() - rest.trim_start().len()
+        })
+        .collect();
+    assert!(value_columns.windows(2).all(|w| w[0] == w[1]), "{feature}");
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--long", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+/// A branch squash-merged into main, which main has since edited over, shows
+/// as `squash_merged` rather than integrated. Matching the squash commit reads
+/// main's history since the fork, so it waits for `--full`.
+#[rstest]
+fn test_list_squash_merged(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_path, "file.txt", "changed", "Change file");
+    repo.commit_in_worktree(&feature_path, "extra.txt", "extra", "Add extra file");
+
+    std::fs::write(repo.root_path().join("file.txt"), "changed").unwrap();
+    repo.run_git(&["add", "file.txt"]);
+    repo.commit_in_worktree(repo.root_path(), "extra.txt", "extra", "Feature (#1)");
+    repo.commit("reverted");
+
+    let feature_state = |args: &[&str]| {
+        let output = list_snapshots::command(&repo, repo.root_path())
+            .arg("--format=json")
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let feature = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["branch"] == "feature")
+            .unwrap()
+            .clone();
+        assert!(feature.get("integration_reason").is_none(), "{feature}");
+        feature["main_state"].clone()
+    };
+    assert_ne!(feature_state(&[]), "squash_merged");
+    assert_eq!(feature_state(&["--full"]), "squash_merged");
+}
+
+/// `--full` JSON counts unmerged commits by change: merge commits and patches
+/// main already cherry-picked don't count, though they stay in `ahead`.
+#[rstest]
+fn test_list_json_unmerged_commits(mut repo: TestRepo) {
+    let picked = repo.add_worktree("picked");
+    repo.commit_in_worktree(&picked, "picked.txt", "picked", "Picked change");
+    repo.commit_in_worktree(&picked, "pending.txt", "pending", "Pending change");
+    let synced = repo.add_worktree("synced");
+    repo.commit_in_worktree(&synced, "synced.txt", "synced", "Synced change");
+
+    // Main moves on and cherry-picks one commit; the other branch merges main in
+    repo.commit("Main work");
+    repo.run_git(&["cherry-pick", "picked~1"]);
+    repo.run_git_in(&synced, &["merge", "--no-edit", "main"]);
+
+    let list = |args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--format=json"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        serde_json::from_slice::<Vec<serde_json::Value>>(&output.stdout).unwrap()
+    };
+    let main_of = |json: &[serde_json::Value], branch: &str| {
+        json.iter().find(|item| item["branch"] == branch).unwrap()["main"].clone()
+    };
+
+    let json = list(&["--full"]);
+    let picked_main = main_of(&json, "picked");
+    assert_eq!(picked_main["ahead"], 2, "{picked_main}");
+    assert_eq!(picked_main["unmerged"], 1, "{picked_main}");
+    let synced_main = main_of(&json, "synced");
+    assert_eq!(synced_main["ahead"], 2, "{synced_main}");
+    assert_eq!(synced_main["unmerged"], 1, "{synced_main}");
+
+    // Only computed with --full
+    let json = list(&[]);
+    let picked_main = main_of(&json, "picked");
+    assert!(picked_main.get("unmerged").is_none(), "{picked_main}");
+}
+
+/// A shallow clone skips line diffs with a one-time note, and ahead/behind
+/// past the clone boundary come back as lower bounds.
+#[rstest]
+fn test_list_shallow_clone(repo: TestRepo) {
+    repo.run_git(&["checkout", "-b", "feature"]);
+    repo.commit("Feature commit");
+    repo.run_git(&["checkout", "main"]);
+    repo.commit("Main commit 1");
+    repo.commit("Main commit 2");
+
+    let clone = repo.home_path().join("shallow");
+    let source = format!("file://{}", repo.root_path().to_slash_lossy());
+    repo.run_git(&[
+        "clone",
+        "--depth=1",
+        "--no-single-branch",
+        &source,
+        clone.to_str().unwrap(),
+    ]);
+    repo.run_git_in(&clone, &["branch", "feature", "origin/feature"]);
+
+    let list = |extra: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--branches", "--format=json"])
+            .args(extra)
+            .current_dir(&clone)
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        assert!(output.status.success(), "{stderr}");
+        let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let feature = items
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["branch"] == "feature")
+            .cloned()
+            .unwrap();
+        (feature, stderr)
+    };
+
+    let (feature, stderr) = list(&["--full"]);
+    assert!(stderr.contains("--full-diffs"), "{stderr}");
+    assert_eq!(feature["main"]["lower_bound"], true);
+    assert_eq!(feature["main"]["ahead"], 1);
+    assert!(feature["main"].get("diff").is_none(), "{feature}");
+
+    // The note is shown once per repository
+    let (_, stderr) = list(&["--full"]);
+    assert!(!stderr.contains("--full-diffs"), "{stderr}");
+
+    let (feature, _) = list(&["--full", "--full-diffs"]);
+    assert!(feature["main"].get("diff").is_some(), "{feature}");
+}
+
+/// Count the pipes that delimit cells, skipping `\|` escapes.
+fn markdown_cell_delimiters(row: &str) -> usize {
+    let mut count = 0;
+    let mut escaped = false;
+    for c in row.chars() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '|' if !escaped => count += 1,
+            _ => escaped = false,
+        }
+        if c != '\\' {
+            escaped = false;
+        }
+    }
+    count
+}
+
+/// `--format=markdown` renders a table with the same number of cells on every
+/// row, escaping pipes and backticks in commit messages.
+#[rstest]
+fn test_list_markdown(mut repo: TestRepo) {
+    repo.remove_fixture_worktrees();
+    let feature = repo.add_worktree("feature/日本語");
+    repo.commit_in_worktree(
+        &feature,
+        "file.txt",
+        "content",
+        "Pipe | and `backticks` in ünïcode",
+    );
+    std::fs::write(feature.join("file.txt"), "changed\nlines\n").unwrap();
+
+    for (name, args) in [
+        ("list_markdown", &["--format=markdown"][..]),
+        ("list_markdown_ascii", &["--format=markdown", "--ascii"]),
+    ] {
+        let output = list_snapshots::command(&repo, repo.root_path())
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let rows: Vec<&str> = stdout.lines().collect();
+        assert_eq!(rows.len(), 4, "{stdout}");
+        let delimiters = markdown_cell_delimiters(rows[0]);
+        for row in &rows {
+            assert!(row.starts_with("| ") && row.ends_with(" |"), "{row}");
+            assert_eq!(markdown_cell_delimiters(row), delimiters, "{stdout}");
+        }
+        assert!(
+            stdout.contains("Pipe \\| and \\`backticks\\` in ünïcode"),
+            "{stdout}"
+        );
+        // Without the usual filters, which turn backslashes into slashes
+        insta::with_settings!({ filters => Vec::<(&str, &str)>::new() }, {
+            insta::assert_snapshot!(name, stdout);
+        });
+    }
+
+    // Symbols spelled out only make sense for Markdown
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .arg("--ascii")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+/// Names of leftover `--output` temp files in `dir`.
+fn output_temp_files(dir: &std::path::Path) -> Vec<String> {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name.starts_with(".wt-list-"))
+        .collect()
+}
+
+#[rstest]
+fn test_list_output_file(repo: TestRepo) {
+    let out_dir = tempfile::tempdir().unwrap();
+    let target = out_dir.path().join("status.txt");
+
+    for format in ["table", "json", "markdown"] {
+        let direct = list_snapshots::command(&repo, repo.root_path())
+            .args(["--format", format])
+            .output()
+            .unwrap();
+        let output = list_snapshots::command(&repo, repo.root_path())
+            .args(["--format", format, "--output"])
+            .arg(&target)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(output.stdout.is_empty(), "{format}: nothing on stdout");
+
+        // The same text as stdout would get, without colors
+        let written = std::fs::read_to_string(&target).unwrap();
+        let expected =
+            ansi_str::AnsiStr::ansi_strip(&*String::from_utf8_lossy(&direct.stdout)).into_owned();
+        assert_eq!(written, expected, "{format}");
+    }
+    assert!(output_temp_files(out_dir.path()).is_empty());
+}
+
+#[cfg(unix)]
+#[rstest]
+fn test_list_output_file_keeps_permissions(repo: TestRepo) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let out_dir = tempfile::tempdir().unwrap();
+    let target = out_dir.path().join("status.json");
+    std::fs::write(&target, "stale").unwrap();
+    std::fs::set_permissions(&target, std::fs::Permissions::from_mode(0o640)).unwrap();
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--format=json", "--output"])
+        .arg(&target)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let metadata = std::fs::metadata(&target).unwrap();
+    assert_eq!(metadata.permissions().mode() & 0o777, 0o640);
+    assert!(std::fs::read_to_string(&target).unwrap().starts_with('['));
+}
+
+/// A target that can't be renamed over (here, a directory) fails with the
+/// path in the error and leaves no temp file behind.
+#[rstest]
+fn test_list_output_file_rename_fails(repo: TestRepo) {
+    let out_dir = tempfile::tempdir().unwrap();
+    let target = out_dir.path().join("status");
+    std::fs::create_dir(&target).unwrap();
+    std::fs::write(target.join("keep.txt"), "").unwrap();
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--format=json", "--output"])
+        .arg(&target)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Failed to write"), "{stderr}");
+    assert!(stderr.contains("status"), "{stderr}");
+    assert!(output_temp_files(out_dir.path()).is_empty());
+    assert!(target.join("keep.txt").exists());
+}
+
+#[cfg(unix)]
+#[rstest]
+fn test_list_output_file_unwritable_dir(repo: TestRepo) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let out_dir = tempfile::tempdir().unwrap();
+    let locked = out_dir.path().join("locked");
+    std::fs::create_dir(&locked).unwrap();
+    std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o555)).unwrap();
+
+    // Skip if running as root
+    if std::fs::write(locked.join("probe"), "").is_ok() {
+        eprintln!("Skipping - running with elevated privileges");
+        return;
+    }
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--format=json", "--output"])
+        .arg(locked.join("status.json"))
+        .output()
+        .unwrap();
+    std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("status.json"), "{stderr}");
+    assert!(std::fs::read_dir(&locked).unwrap().next().is_none());
+}
+
+/// Set up `main` 30 days old, a `feature` worktree committed to an hour ago,
+/// and a long-named branch without a worktree at main's commit.
+fn setup_time_filter_repo(repo: &mut TestRepo) {
+    repo.commit_with_age("Old work", 30 * DAY);
+    let feature = repo.add_worktree("feature");
+    std::fs::write(feature.join("recent.txt"), "recent\n").unwrap();
+    repo.run_git_in(&feature, &["add", "recent.txt"]);
+    repo.commit_staged_with_age("Recent work", HOUR, &feature);
+    repo.run_git(&["branch", "stale-branch-with-a-very-long-name"]);
+}
+
+fn listed_branches(repo: &TestRepo, args: &[&str]) -> Vec<String> {
+    let output = list_snapshots::command(repo, repo.root_path())
+        .args(["--branches", "--format=json"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    items
+        .iter()
+        .map(|item| item["branch"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[rstest]
+fn test_list_since_until(mut repo: TestRepo) {
+    setup_time_filter_repo(&mut repo);
+
+    // The fixture's feature-a..c worktrees are a day old
+    assert_eq!(
+        listed_branches(&repo, &["--since", "7d"]),
+        ["feature", "feature-a", "feature-b", "feature-c"]
+    );
+    assert_eq!(listed_branches(&repo, &["--since", "90m"]), ["feature"]);
+    assert_eq!(
+        listed_branches(&repo, &["--until", "1w"]),
+        ["main", "stale-branch-with-a-very-long-name"]
+    );
+    // Both bounds compose; the test epoch is 2025-01-02
+    assert_eq!(
+        listed_branches(&repo, &["--since", "2024-11-01", "--until", "2024-12-31"]),
+        ["main", "stale-branch-with-a-very-long-name"]
+    );
+    assert!(listed_branches(&repo, &["--since", "30m"]).is_empty());
+}
+
+#[rstest]
+fn test_list_since_skips_filtered_widths(mut repo: TestRepo) {
+    setup_time_filter_repo(&mut repo);
+
+    // The long branch name is filtered out, so it doesn't widen the Branch column
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.args(["--branches", "--since", "2h"]);
+        cmd
+    });
+}
+
+#[rstest]
+fn test_list_since_invalid(repo: TestRepo) {
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .args(["--since", "lately"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(stderr.contains("Invalid --since value"), "{stderr}");
+    assert!(stderr.contains("expected a duration like `7d`"), "{stderr}");
+}
+
+/// A worktree whose branch was deleted out from under it is listed as
+/// `(deleted)`, with no comparison against the default branch.
+#[rstest]
+fn test_list_deleted_branch_worktree(mut repo: TestRepo) {
+    repo.remove_fixture_worktrees();
+    repo.add_worktree_with_commit("gone", "gone.txt", "content", "Work on gone");
+    repo.delete_branch_under_worktree("gone");
+
+    assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
+
+    let output = list_snapshots::command(&repo, repo.root_path())
+        .arg("--format=json")
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let gone = json.iter().find(|item| item["branch"] == "gone").unwrap();
+    assert_eq!(gone["worktree"]["state"], "branch_deleted");
+    let main = json.iter().find(|item| item["branch"] == "main").unwrap();
+    assert!(main["worktree"].get("state").is_none());
+}
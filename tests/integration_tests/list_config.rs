@@ -473,3 +473,91 @@ timeout-ms = 1
         stderr
     );
 }
+
+/// `[defaults]` flags apply on every run, and flags on the command line win.
+#[rstest]
+fn test_list_config_defaults(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config("[defaults]\nlist = \"--branches --format json\"\n");
+
+    let list = |args: &[&str]| {
+        let output = repo.wt_command().arg("list").args(args).output().unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    // Both defaults apply: JSON output that includes the worktree-less branch
+    let json: Vec<serde_json::Value> = serde_json::from_str(&list(&[])).unwrap();
+    assert!(json.iter().any(|item| item["branch"] == "feature"));
+
+    // Repeating a boolean default is accepted; an explicit value replaces the default
+    let table = list(&["--branches", "--format", "table"]);
+    assert!(
+        serde_json::from_str::<serde_json::Value>(&table).is_err(),
+        "{table}"
+    );
+    assert!(table.contains("feature"), "{table}");
+
+    // `wt config show` lists the effective defaults
+    let output = repo.wt_command().args(["config", "show"]).output().unwrap();
+    let shown = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let shown = ansi_str::AnsiStr::ansi_strip(&*shown).into_owned();
+    assert!(
+        shown.contains("wt list --branches --format json"),
+        "{shown}"
+    );
+}
+
+/// A bad default flag is reported against its config key.
+#[rstest]
+fn test_list_config_defaults_invalid_flag(repo: TestRepo) {
+    repo.write_test_config("[defaults]\nlist = \"--bogus\"\n");
+
+    let output = repo.wt_command().arg("list").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("defaults.list"), "{stderr}");
+    assert!(stderr.contains("--bogus"), "{stderr}");
+}
+
+/// `[alias]` entries expand before parsing, with trailing arguments appended.
+#[rstest]
+fn test_list_config_alias(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    repo.write_test_config("[alias]\nls = \"list --format json\"\nla = \"ls --branches\"\n");
+
+    let output = repo.wt_command().args(["la", "--full"]).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json.iter().any(|item| item["branch"] == "feature"));
+
+    let output = repo.wt_command().arg("--list-aliases").output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "la = ls --branches\nls = list --format json\n"
+    );
+}
+
+/// Alias cycles fail with the chain that looped.
+#[rstest]
+fn test_list_config_alias_cycle(repo: TestRepo) {
+    repo.write_test_config("[alias]\na = \"b --full\"\nb = \"a\"\n");
+
+    let output = repo.wt_command().arg("a").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Alias cycle: a → b → a"), "{stderr}");
+}
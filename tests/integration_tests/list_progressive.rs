@@ -134,6 +134,65 @@ fn test_list_progressive_overflow(mut repo: TestRepo) {
     );
 }
 
+/// A table taller than the terminal goes through `list.pager`; `--no-pager`
+/// prints it directly.
+#[rstest]
+fn test_list_progressive_overflow_pager(mut repo: TestRepo) {
+    for i in 1..=10 {
+        repo.add_worktree(&format!("paged-{:02}", i));
+    }
+    let marker = repo.root_path().parent().unwrap().join("pager-ran");
+    repo.write_test_config(&format!(
+        "[list]\npager = \"touch '{}' && cat\"\n",
+        marker.display()
+    ));
+
+    let mut opts = ProgressiveCaptureOptions::with_byte_interval(500);
+    opts.terminal_size = (10, 150);
+    let output = capture_progressive_output(&repo, "list", &["--no-pager"], opts);
+    assert_eq!(output.exit_code, 0);
+    assert!(!marker.exists(), "--no-pager should skip the pager");
+
+    let mut opts = ProgressiveCaptureOptions::with_byte_interval(500);
+    opts.terminal_size = (10, 150);
+    let output = capture_progressive_output(&repo, "list", &[], opts);
+    assert_eq!(output.exit_code, 0);
+    assert!(marker.exists(), "Overflowing table should be paged");
+    // `cat` as the pager still shows the end of the table
+    assert!(
+        output.final_output().contains("paged-10"),
+        "Final output:\n{}",
+        output.final_output()
+    );
+}
+
+/// `--max-rows` keeps the first rows and counts the rest, without a pager.
+#[rstest]
+fn test_list_progressive_max_rows(repo: TestRepo) {
+    let output = capture_progressive_output(
+        &repo,
+        "list",
+        &["--max-rows", "2"],
+        ProgressiveCaptureOptions::with_byte_interval(500),
+    );
+
+    assert_eq!(output.exit_code, 0);
+    let final_text = output.final_output();
+    // main and the fixture's three worktrees: 2 shown, 2 counted
+    assert!(
+        final_text.contains("…and 2 more (use --no-pager)"),
+        "Final output:\n{final_text}"
+    );
+    assert!(
+        !final_text.contains("feature-c"),
+        "Final output:\n{final_text}"
+    );
+    assert!(
+        final_text.contains("Showing"),
+        "Final output:\n{final_text}"
+    );
+}
+
 /// Tests progressive rendering with no worktrees (fast path).
 #[rstest]
 fn test_list_progressive_fast_command(repo: TestRepo) {
@@ -2089,3 +2089,198 @@ fn test_merge_squash_ignored_with_no_commit(repo_with_multi_commit_feature: Test
         Some(feature_wt)
     ));
 }
+
+/// Squash-merging a branch leaves branches created from it carrying its old
+/// commits: merge warns about them, and `--restack` rebases them onto the target.
+#[rstest]
+#[case::warn(false)]
+#[case::restack(true)]
+fn test_merge_stacked_branch(mut repo_with_multi_commit_feature: TestRepo, #[case] restack: bool) {
+    let repo = &mut repo_with_multi_commit_feature;
+    let feature_wt = repo.worktrees["feature"].clone();
+    let ui_wt = repo.add_worktree("feature-ui");
+    repo.run_git_in(&ui_wt, &["reset", "--hard", "feature"]);
+    repo.commit_in_worktree(&ui_wt, "ui.txt", "ui", "feat: add ui");
+
+    let mut args = vec!["main", "--yes"];
+    if restack {
+        args.push("--restack");
+    }
+    let output = make_snapshot_cmd(repo, "merge", &args, Some(&feature_wt))
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("feature-ui"), "{stderr}");
+    assert_eq!(stderr.contains("--restack"), !restack, "{stderr}");
+
+    // Restacked, only the branch's own commit is ahead of main
+    let ahead = repo.git_output(&["rev-list", "--count", "main..feature-ui"]);
+    assert_eq!(ahead, if restack { "1" } else { "3" });
+}
+
+/// A recorded parent decides what a branch restacks onto: `feature-ui` stays
+/// on `feature-api` even though `feature-api` moved on after it branched.
+#[rstest]
+fn test_merge_restack_recorded_parent(mut repo_with_multi_commit_feature: TestRepo) {
+    let repo = &mut repo_with_multi_commit_feature;
+    let feature_wt = repo.worktrees["feature"].clone();
+    let api_wt = repo.add_worktree("feature-api");
+    repo.run_git_in(&api_wt, &["reset", "--hard", "feature"]);
+    repo.commit_in_worktree(&api_wt, "api.txt", "api", "feat: add api");
+    let ui_wt = repo.add_worktree("feature-ui");
+    repo.run_git_in(&ui_wt, &["reset", "--hard", "feature-api"]);
+    repo.commit_in_worktree(&ui_wt, "ui.txt", "ui", "feat: add ui");
+    repo.commit_in_worktree(&api_wt, "api2.txt", "api 2", "feat: extend api");
+    let output = repo
+        .wt_command()
+        .args(["parent", "set", "feature-api", "--branch=feature-ui"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = make_snapshot_cmd(
+        repo,
+        "merge",
+        &["main", "--yes", "--restack"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    let ancestor = repo
+        .git_command()
+        .args(["merge-base", "--is-ancestor", "feature-api", "feature-ui"])
+        .status()
+        .unwrap();
+    assert!(
+        ancestor.success(),
+        "feature-ui not on feature-api:\n{stderr}"
+    );
+    let ahead = repo.git_output(&["rev-list", "--count", "feature-api..feature-ui"]);
+    assert_eq!(ahead, "1");
+    assert_eq!(
+        repo.git_output(&["config", "branch.feature-ui.worktrunkParent"]),
+        "feature-api"
+    );
+}
+
+#[rstest]
+fn test_merge_tag(merge_scenario: (TestRepo, PathBuf)) {
+    let (mut repo, feature_wt) = merge_scenario;
+    repo.setup_remote("main");
+
+    snapshot_merge_with_env(
+        "merge_tag",
+        &repo,
+        &[
+            "main",
+            "--tag",
+            "v1.0",
+            "--tag-message",
+            "Release 1.0",
+            "--push-tag",
+        ],
+        Some(&feature_wt),
+        &[],
+    );
+
+    // An annotated tag on the merged commit, also on the remote
+    assert_eq!(repo.git_output(&["cat-file", "-t", "v1.0"]), "tag");
+    assert_eq!(
+        repo.git_output(&["rev-parse", "v1.0^{commit}"]),
+        repo.git_output(&["rev-parse", "main"])
+    );
+    assert_eq!(
+        repo.git_output(&["tag", "-l", "--format=%(contents:subject)", "v1.0"]),
+        "Release 1.0"
+    );
+    let remote_tags = repo.git_output(&["ls-remote", "--tags", "origin"]);
+    assert!(remote_tags.contains("refs/tags/v1.0"), "{remote_tags}");
+}
+
+#[rstest]
+fn test_merge_tag_invalid_name(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    snapshot_merge_with_env(
+        "merge_tag_invalid_name",
+        &repo,
+        &["main", "--tag", "v1..0"],
+        Some(&feature_wt),
+        &[],
+    );
+
+    // Rejected before anything merged
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+    assert!(feature_wt.exists());
+}
+
+#[rstest]
+fn test_merge_tag_already_exists(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    repo.run_git(&["tag", "v1.0"]);
+
+    snapshot_merge_with_env(
+        "merge_tag_already_exists",
+        &repo,
+        &["main", "--tag", "v1.0"],
+        Some(&feature_wt),
+        &[],
+    );
+    assert!(feature_wt.exists());
+}
+
+/// Signing without a working GPG leaves the merge in place and says what to
+/// run once signing works.
+#[rstest]
+fn test_merge_tag_sign_fails(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    repo.run_git(&["config", "gpg.program", "false"]);
+    let feature_head = repo.git_output(&["rev-parse", "feature"]);
+
+    snapshot_merge_with_env(
+        "merge_tag_sign_fails",
+        &repo,
+        &["main", "--tag", "v1.0", "--sign"],
+        Some(&feature_wt),
+        &[],
+    );
+
+    assert!(repo.git_output(&["tag", "-l", "v1.0"]).is_empty());
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), feature_head);
+}
+
+/// `--branch` merges another worktree's branch from where the user stands;
+/// there's no directory change since the removed worktree isn't the current one.
+#[rstest]
+fn test_merge_branch_from_elsewhere(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, _feature_wt) = merge_scenario;
+    let feature_head = repo.git_output(&["rev-parse", "feature"]);
+
+    snapshot_merge_with_env(
+        "merge_branch_from_elsewhere",
+        &repo,
+        &["--branch", "feature"],
+        None,
+        &[],
+    );
+
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), feature_head);
+}
+
+#[rstest]
+fn test_merge_branch_without_worktree(repo: TestRepo) {
+    repo.run_git(&["branch", "no-worktree"]);
+
+    snapshot_merge_with_env(
+        "merge_branch_without_worktree",
+        &repo,
+        &["--branch", "no-worktree"],
+        None,
+        &[],
+    );
+}
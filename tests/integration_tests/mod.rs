@@ -11,39 +11,53 @@ pub mod approval_pty;
 pub mod approval_save;
 pub mod approval_ui;
 pub mod approvals;
+pub mod archive;
 pub mod bare_repository;
+pub mod branches;
 pub mod cache_sharing;
 pub mod ci_status;
+pub mod clone;
 pub mod column_alignment_verification;
 pub mod completion;
 pub mod completion_validation;
 pub mod config_init;
+pub mod config_migrate;
 pub mod config_show;
 pub mod config_show_theme;
 pub mod config_state;
 pub mod config_update_pty;
+pub mod config_validate;
 pub mod configure_shell;
 pub mod default_branch;
 pub mod diagnostic;
+pub mod diff;
 pub mod directives;
 pub mod doc_templates;
 pub mod e2e_shell;
 pub mod e2e_shell_post_start;
+pub mod events;
 pub mod for_each;
 pub mod git_error_display;
 pub mod help;
+pub mod hide;
 pub mod hook_show;
 pub mod init;
+pub mod integrations;
+pub mod json_errors;
+pub mod legend;
 pub mod list;
 pub mod list_column_alignment;
 pub mod list_config;
 pub mod list_progressive;
 pub mod merge;
 pub mod output_system_guard;
+pub mod parent;
 pub mod post_start_commands;
 pub mod push;
 pub mod readme_sync;
 pub mod remove;
+pub mod repair;
+pub mod report;
 pub mod repository;
 pub mod security;
 pub mod select_config;
@@ -53,13 +67,17 @@ pub mod shell_powershell;
 pub mod shell_wrapper;
 pub mod snapshot_formatting_guard;
 pub mod spacing_edge_cases;
+pub mod stats;
 pub mod statusline;
 pub mod step_alias;
 pub mod step_copy_ignored;
 pub mod step_diff;
+pub mod step_graduate;
+pub mod step_optimize;
 pub mod step_promote;
 pub mod step_prune;
 pub mod step_relocate;
 pub mod switch;
 pub mod switch_picker;
 pub mod user_hooks;
+pub mod which;
@@ -34,10 +34,28 @@ const STDOUT_ALLOWED_PATHS: &[&str] = &[
     "config/state.rs",
     // Hint list output
     "config/hints.rs",
+    // JSON Schema output for wt config schema
+    "config/schema.rs",
     // LLM prompt output for wt step commit --show-prompt
     "step_commands.rs",
     // --no-cd flag: branch name output for scripting
     "select/mod.rs",
+    // Diff stats and JSON output for wt diff
+    "diff.rs",
+    // Table of branches to delete for wt branches clean
+    "branches.rs",
+    // Archive list output for wt archive list
+    "archive.rs",
+    // Owning worktree (line or JSON) for wt which
+    "which.rs",
+    // Aggregate numbers (human or JSON) for wt stats
+    "stats.rs",
+    // Usage summary (human or JSON) for wt report
+    "report.rs",
+    // Symbol legend for wt legend
+    "list/legend.rs",
+    // Recorded parent branch for wt parent get
+    "parent.rs",
 ];
 
 /// Substrings that indicate the line is a special case (e.g., in a comment or test reference)
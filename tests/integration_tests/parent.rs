@@ -0,0 +1,155 @@
+//! Integration tests for `wt parent` and stacked branches in `wt list`
+
+use crate::common::{TestRepo, list_snapshots, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+fn wt(repo: &TestRepo, args: &[&str]) -> std::process::Output {
+    repo.wt_command().args(args).output().unwrap()
+}
+
+fn stdout(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// `api` with one commit on main, and `ui` with one commit on `api`.
+fn stacked(repo: &mut TestRepo) {
+    let api = repo.add_worktree("api");
+    repo.commit_in_worktree(&api, "api.txt", "api", "Add api");
+    let ui = repo.add_worktree("ui");
+    repo.run_git_in(&ui, &["reset", "--hard", "api"]);
+    repo.commit_in_worktree(&ui, "ui.txt", "ui", "Add ui");
+}
+
+#[rstest]
+fn test_parent_set_get_clear(mut repo: TestRepo) {
+    stacked(&mut repo);
+    assert_eq!(stdout(&wt(&repo, &["parent", "--branch=ui"])), "");
+
+    assert!(
+        wt(&repo, &["parent", "set", "api", "--branch=ui"])
+            .status
+            .success()
+    );
+    assert_eq!(stdout(&wt(&repo, &["parent", "--branch=ui"])), "api");
+    assert_eq!(
+        repo.git_output(&["config", "branch.ui.worktrunkParent"]),
+        "api"
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "parent",
+        &["clear", "--branch=ui"],
+        None
+    ));
+    assert_cmd_snapshot!(
+        "parent_clear_none",
+        make_snapshot_cmd(&repo, "parent", &["clear", "--branch=ui"], None)
+    );
+}
+
+#[rstest]
+fn test_parent_cycle_rejected(mut repo: TestRepo) {
+    stacked(&mut repo);
+    repo.run_git(&["branch", "polish", "ui"]);
+    assert!(
+        wt(&repo, &["parent", "set", "api", "--branch=ui"])
+            .status
+            .success()
+    );
+    assert!(
+        wt(&repo, &["parent", "set", "ui", "--branch=polish"])
+            .status
+            .success()
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "parent",
+        &["set", "polish", "--branch=api"],
+        None
+    ));
+    assert_eq!(stdout(&wt(&repo, &["parent", "--branch=api"])), "");
+}
+
+#[rstest]
+fn test_parent_unknown_branch(repo: TestRepo) {
+    let output = wt(&repo, &["parent", "set", "no-such-branch", "--branch=main"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no-such-branch"));
+}
+
+#[rstest]
+fn test_switch_base_records_parent(mut repo: TestRepo) {
+    stacked(&mut repo);
+    let output = wt(&repo, &["switch", "--create", "polish", "--base", "ui"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(stdout(&wt(&repo, &["parent", "--branch=polish"])), "ui");
+
+    // The default branch as base isn't a stack
+    let output = wt(&repo, &["switch", "--create", "other", "--base", "main"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&wt(&repo, &["parent", "--branch=other"])), "");
+}
+
+#[rstest]
+fn test_list_stacked_branch(mut repo: TestRepo) {
+    stacked(&mut repo);
+    assert!(
+        wt(&repo, &["parent", "set", "api", "--branch=ui"])
+            .status
+            .success()
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+    // ui is one commit ahead of api rather than two ahead of main
+    assert_cmd_snapshot!(
+        "parent_list",
+        list_snapshots::command(&repo, repo.root_path())
+    );
+}
+
+#[rstest]
+fn test_list_stacked_branch_json(mut repo: TestRepo) {
+    stacked(&mut repo);
+    assert!(
+        wt(&repo, &["parent", "set", "api", "--branch=ui"])
+            .status
+            .success()
+    );
+
+    let output = wt(&repo, &["list", "--format=json"]);
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let item = |branch: &str| {
+        items
+            .iter()
+            .find(|item| item["branch"] == branch)
+            .unwrap()
+            .clone()
+    };
+    let ui = item("ui");
+    assert_eq!(ui["parent"], "api");
+    assert_eq!(ui["main"]["against"], "api");
+    assert_eq!(ui["main"]["ahead"], 1);
+    let api = item("api");
+    assert!(api.get("parent").is_none());
+    assert_eq!(api["main"]["ahead"], 1);
+
+    // --against applies to every row, stacked or not
+    let output = wt(&repo, &["list", "--format=json", "--against=main"]);
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let ui = items.iter().find(|item| item["branch"] == "ui").unwrap();
+    assert_eq!(ui["parent"], "api");
+    assert_eq!(ui["main"]["against"], "main");
+    assert_eq!(ui["main"]["ahead"], 2);
+}
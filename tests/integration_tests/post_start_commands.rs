@@ -721,6 +721,52 @@ approved-commands = ["cat > context.json"]
     );
 }
 
+#[rstest]
+fn test_hook_context_env_var(repo: TestRepo) {
+    use crate::common::wt_command;
+
+    // Blocking and background hooks both get the JSON context in WT_HOOK_CONTEXT
+    repo.write_project_config(
+        r#"post-create = "printenv WT_HOOK_CONTEXT > create.json"
+post-start = "printenv WT_HOOK_CONTEXT > start.json"
+"#,
+    );
+
+    repo.commit("Add config");
+
+    repo.write_test_approvals(
+        r#"[projects."../origin"]
+approved-commands = ["printenv WT_HOOK_CONTEXT > create.json", "printenv WT_HOOK_CONTEXT > start.json"]
+"#,
+    );
+
+    let temp_home = TempDir::new().unwrap();
+    let mut cmd = wt_command();
+    cmd.args(["switch", "--create", "env-json"])
+        .current_dir(repo.root_path())
+        .env("WORKTRUNK_CONFIG_PATH", repo.test_config_path())
+        .env("WORKTRUNK_APPROVALS_PATH", repo.test_approvals_path());
+    set_temp_home_env(&mut cmd, temp_home.path());
+    let output = cmd.output().expect("failed to run wt switch");
+
+    assert!(
+        output.status.success(),
+        "wt switch should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.env-json");
+    let contents = fs::read_to_string(worktree_path.join("create.json")).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&contents)
+        .unwrap_or_else(|e| panic!("Should be valid JSON: {}\nContents: {}", e, contents));
+    assert_eq!(json["branch"].as_str(), Some("env-json"));
+    assert_eq!(json["hook_type"].as_str(), Some("post-create"));
+
+    let json = wait_for_valid_json(&worktree_path.join("start.json"));
+    assert_eq!(json["branch"].as_str(), Some("env-json"));
+    assert_eq!(json["hook_type"].as_str(), Some("post-start"));
+}
+
 // ============================================================================
 // Post-Start Command Tests (parallel, background)
 // ============================================================================
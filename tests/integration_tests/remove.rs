@@ -1,6 +1,6 @@
 use crate::common::{
     BareRepoTest, TestRepo, TestRepoBase, configure_directive_file, directive_file,
-    make_snapshot_cmd, repo, repo_with_remote, setup_snapshot_settings,
+    make_snapshot_cmd, repo, repo_with_remote, resolve_git_common_dir, setup_snapshot_settings,
     setup_temp_snapshot_settings, wt_command,
 };
 use insta_cmd::assert_cmd_snapshot;
@@ -88,6 +88,106 @@ fn test_remove_locked_worktree(mut repo: TestRepo) {
     ));
 }
 
+/// Removing a branch whose name isn't valid UTF-8 refuses rather than asking
+/// git to delete the lossy name; `--no-delete-branch` removes just the worktree.
+#[cfg(unix)]
+#[rstest]
+fn test_remove_non_utf8_branch(repo: TestRepo) {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let branch = OsStr::from_bytes(b"caf\xe9");
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.cafe");
+    let output = repo
+        .git_command()
+        .args([
+            OsStr::new("worktree"),
+            "add".as_ref(),
+            "-b".as_ref(),
+            branch,
+        ])
+        .arg(&worktree_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let branch_exists = || {
+        let refs = repo
+            .git_command()
+            .args(["for-each-ref", "--format=%(refname)", "refs/heads/"])
+            .output()
+            .unwrap()
+            .stdout;
+        refs.split(|&b| b == b'\n')
+            .any(|line| line == b"refs/heads/caf\xe9")
+    };
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--foreground"],
+        Some(&worktree_path)
+    ));
+    assert!(worktree_path.exists());
+    assert!(branch_exists());
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--foreground", "--no-delete-branch"],
+        Some(&worktree_path),
+    )
+    .output()
+    .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(!worktree_path.exists());
+    assert!(branch_exists());
+}
+
+/// A worktree whose branch was deleted is removed without touching branches,
+/// as long as its last commit is still on another branch.
+#[rstest]
+fn test_remove_deleted_branch_worktree(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("gone");
+    repo.delete_branch_under_worktree("gone");
+
+    // Uncommitted work is still protected, judged against the last commit
+    std::fs::write(worktree_path.join("scratch.txt"), "notes").unwrap();
+    let output = make_snapshot_cmd(&repo, "remove", &["gone"], None)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("uncommitted changes"));
+    std::fs::remove_file(worktree_path.join("scratch.txt")).unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--foreground", "gone"],
+        None
+    ));
+    assert!(!worktree_path.exists());
+}
+
+/// Refuses when the deleted branch's last commit is on no other branch,
+/// unless `-D` is given.
+#[rstest]
+fn test_remove_deleted_branch_worktree_unreferenced(mut repo: TestRepo) {
+    let worktree_path =
+        repo.add_worktree_with_commit("gone", "gone.txt", "content", "Work on gone");
+    repo.delete_branch_under_worktree("gone");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "remove", &["gone"], None));
+    assert!(worktree_path.exists());
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--foreground", "-D", "gone"],
+        None
+    ));
+    assert!(!worktree_path.exists());
+}
+
 #[rstest]
 fn test_remove_locked_worktree_no_reason(mut repo: TestRepo) {
     // Create a worktree and lock it without a reason
@@ -631,7 +731,7 @@ fn test_remove_foreground_no_delete_branch(mut repo: TestRepo) {
 }
 
 /// Tests foreground removal with --no-delete-branch on an unmerged branch.
-/// No hint needed since the flag had no effect (branch wouldn't be deleted anyway).
+/// The hint shows how to delete the retained branch later.
 #[rstest]
 fn test_remove_foreground_no_delete_branch_unmerged(mut repo: TestRepo) {
     // Create a worktree with an unmerged commit
@@ -657,9 +757,6 @@ fn test_remove_foreground_no_delete_branch_unmerged(mut repo: TestRepo) {
         .unwrap();
 
     // Remove with both --foreground and --no-delete-branch
-    // No hint because:
-    // - Branch is unmerged (wouldn't be deleted anyway)
-    // - --no-delete-branch had no effect
     assert_cmd_snapshot!(make_snapshot_cmd(
         &repo,
         "remove",
@@ -2450,6 +2547,125 @@ fn test_remove_foreground_with_submodules(mut repo: TestRepo) {
     );
 }
 
+/// `check-cwd-users = true`: a process sitting in the worktree blocks a
+/// non-interactive removal; `--force` skips the check.
+#[cfg(target_os = "linux")]
+#[rstest]
+fn test_remove_blocked_by_cwd_user(mut repo: TestRepo) {
+    repo.write_test_config("check-cwd-users = true\n");
+    let worktree_path = repo.add_worktree("feature-busy");
+    let mut sleeper = std::process::Command::new("sleep")
+        .arg("30")
+        .current_dir(&worktree_path)
+        .spawn()
+        .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature-busy"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains(&format!(
+            "1 process is using this directory: sleep ({})",
+            sleeper.id()
+        )),
+        "{stderr}"
+    );
+    assert!(worktree_path.exists());
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "--force", "feature-busy"])
+        .output()
+        .unwrap();
+    sleeper.kill().unwrap();
+    sleeper.wait().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree_path.exists());
+}
+
+/// With `[scratch] enabled = true`, the scratch directory survives `wt remove`
+/// and returns when `wt switch` recreates the branch's worktree.
+#[rstest]
+fn test_remove_keeps_scratch_for_next_worktree(mut repo: TestRepo) {
+    repo.write_test_config(
+        r#"[scratch]
+enabled = true
+"#,
+    );
+    let feature_path = repo.add_worktree("feature-scratch");
+    std::fs::create_dir_all(feature_path.join(".wt-scratch/logs")).unwrap();
+    std::fs::write(feature_path.join(".wt-scratch/logs/run.txt"), "kept").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args([
+            "remove",
+            "--foreground",
+            "--force",
+            "--no-delete-branch",
+            "feature-scratch",
+        ])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Kept"), "{stderr}");
+    assert!(!feature_path.exists());
+
+    let git_common_dir = resolve_git_common_dir(repo.root_path());
+    // Stored under the sanitized branch name, which carries a hash suffix
+    let stored: Vec<_> = std::fs::read_dir(git_common_dir.join("wt-scratch"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(stored.len(), 1);
+    let stored = &stored[0];
+    assert!(stored.join("logs/run.txt").exists());
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--long", "--branches"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let block = stdout
+        .split("\n\n")
+        .find(|block| block.contains("feature-scratch"))
+        .unwrap_or_else(|| panic!("{stdout}"));
+    assert!(block.contains("Scratch:"), "{block}");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--no-cd", "feature-scratch"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Restored"), "{stderr}");
+    let restored = feature_path.join(".wt-scratch/logs/run.txt");
+    assert_eq!(std::fs::read_to_string(restored).unwrap(), "kept");
+    assert!(!stored.exists());
+
+    let exclude = std::fs::read_to_string(git_common_dir.join("info/exclude")).unwrap();
+    assert!(exclude.contains("/.wt-scratch/"), "{exclude}");
+    // Excluded, so the new worktree is clean
+    let status = repo
+        .git_command()
+        .args(["status", "--porcelain"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&status.stdout), "");
+}
+
 /// Restore write permissions recursively so TempDir cleanup succeeds.
 #[cfg(unix)]
 fn restore_dir_permissions(dir: &std::path::Path) {
@@ -2465,3 +2681,108 @@ fn restore_dir_permissions(dir: &std::path::Path) {
         }
     }
 }
+
+/// `--delete-remote` deletes the upstream along with the merged branch.
+#[rstest]
+fn test_remove_delete_remote(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.add_worktree("feature-pushed");
+    repo.run_git(&["push", "-u", "origin", "feature-pushed"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--foreground", "--yes", "--delete-remote", "feature-pushed"],
+        None
+    ));
+
+    let output = repo
+        .git_command()
+        .args(["ls-remote", "--heads", "origin", "feature-pushed"])
+        .output()
+        .unwrap();
+    assert!(output.stdout.is_empty());
+}
+
+/// `--delete-remote` keeps an upstream with commits the local branch lacks.
+#[rstest]
+fn test_remove_delete_remote_keeps_unpulled(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.add_worktree("feature-unpulled");
+    repo.run_git(&["push", "-u", "origin", "feature-unpulled"]);
+    // Someone else pushed a commit; fetched but not pulled
+    repo.run_git(&["commit", "--allow-empty", "-m", "Remote-only work"]);
+    repo.run_git(&["push", "origin", "HEAD:feature-unpulled"]);
+    repo.run_git(&["reset", "--hard", "HEAD~1"]);
+    repo.run_git(&["fetch", "origin"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &[
+            "--foreground",
+            "--yes",
+            "--delete-remote",
+            "feature-unpulled"
+        ],
+        None
+    ));
+
+    let output = repo
+        .git_command()
+        .args(["ls-remote", "--heads", "origin", "feature-unpulled"])
+        .output()
+        .unwrap();
+    assert!(!output.stdout.is_empty());
+}
+
+/// Without a terminal or `--yes`, `--delete-remote` leaves the upstream alone.
+#[rstest]
+fn test_remove_delete_remote_not_interactive(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.add_worktree("feature-asked");
+    repo.run_git(&["push", "-u", "origin", "feature-asked"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--foreground", "--delete-remote", "feature-asked"],
+        None
+    ));
+}
+
+/// `--delete-remote` never deletes the remote's default branch, even when a
+/// branch tracks it.
+#[rstest]
+fn test_remove_delete_remote_skips_default_branch(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.add_worktree("feature-tracks-main");
+    repo.run_git(&[
+        "branch",
+        "--set-upstream-to",
+        "origin/main",
+        "feature-tracks-main",
+    ]);
+
+    let output = repo
+        .wt_command()
+        .args([
+            "remove",
+            "--foreground",
+            "--yes",
+            "--delete-remote",
+            "feature-tracks-main",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("origin/main"), "{stderr}");
+
+    let output = repo
+        .git_command()
+        .args(["ls-remote", "--heads", "origin", "main"])
+        .output()
+        .unwrap();
+    assert!(!output.stdout.is_empty());
+}
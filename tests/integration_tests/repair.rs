@@ -0,0 +1,126 @@
+//! Integration tests for `wt repair`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo_with_remote, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use worktrunk::git::Repository;
+
+fn wt(repo: &TestRepo, args: &[&str]) -> std::process::Output {
+    repo.wt_command().args(args).output().unwrap()
+}
+
+fn stderr(output: &std::process::Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    ansi_str::AnsiStr::ansi_strip(&*stderr).into_owned()
+}
+
+fn git_stdout(repo: &TestRepo, args: &[&str]) -> String {
+    let output = repo.git_command().args(args).output().unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// Rename the remote's `main` to `trunk` and fetch, as after an upstream rename.
+///
+/// Any earlier `wt` command would have cached `main` as the default branch.
+fn rename_remote_default(repo: &TestRepo) {
+    repo.run_git(&["config", "worktrunk.default-branch", "main"]);
+    let remote = repo.remote_path().unwrap();
+    repo.run_git_in(remote, &["branch", "-m", "main", "trunk"]);
+    repo.run_git(&["fetch", "--prune", "origin"]);
+    repo.run_git(&["remote", "set-head", "origin", "trunk"]);
+}
+
+#[rstest]
+fn test_list_warns_once_about_renamed_default(#[from(repo_with_remote)] repo: TestRepo) {
+    rename_remote_default(&repo);
+
+    let first = stderr(&wt(&repo, &["list"]));
+    assert!(
+        first.contains("The remote renamed main to trunk"),
+        "{first}"
+    );
+    assert!(first.contains("wt repair --default-branch"), "{first}");
+
+    let second = stderr(&wt(&repo, &["list"]));
+    assert!(!second.contains("renamed"), "{second}");
+}
+
+#[rstest]
+fn test_repair_default_branch(#[from(repo_with_remote)] repo: TestRepo) {
+    rename_remote_default(&repo);
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+    assert_cmd_snapshot!(
+        "repair_default_branch",
+        make_snapshot_cmd(&repo, "repair", &["--default-branch"], None)
+    );
+
+    assert_eq!(git_stdout(&repo, &["branch", "--show-current"]), "trunk");
+    assert_eq!(
+        git_stdout(&repo, &["rev-parse", "--abbrev-ref", "trunk@{upstream}"]),
+        "origin/trunk"
+    );
+    assert_eq!(
+        git_stdout(&repo, &["config", "--get", "worktrunk.default-branch"]),
+        "trunk"
+    );
+    // Comparisons follow the new name straight away
+    let repository = Repository::at(repo.root_path()).unwrap();
+    assert_eq!(repository.default_branch().as_deref(), Some("trunk"));
+    assert!(repository.renamed_default_branch().is_none());
+}
+
+#[rstest]
+fn test_repair_keeps_branch_with_unique_commits(#[from(repo_with_remote)] repo: TestRepo) {
+    rename_remote_default(&repo);
+    repo.commit("Local only");
+
+    let output = wt(&repo, &["repair", "--default-branch"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let text = stderr(&output);
+    assert!(
+        text.contains("Kept branch main: it has 1 commit not on origin/trunk"),
+        "{text}"
+    );
+    assert_eq!(git_stdout(&repo, &["branch", "--show-current"]), "main");
+    // The cache still moves, so comparisons use the remote's branch
+    assert_eq!(
+        git_stdout(&repo, &["config", "--get", "worktrunk.default-branch"]),
+        "trunk"
+    );
+}
+
+#[rstest]
+fn test_repair_repoints_project_compare_ref(#[from(repo_with_remote)] repo: TestRepo) {
+    let project_id = Repository::at(repo.root_path())
+        .unwrap()
+        .project_identifier()
+        .unwrap();
+    repo.write_test_config(&format!(
+        "[list]\ncompare-ref = \"main\"\n\n[projects.\"{project_id}\".list]\ncompare-ref = \"origin/main\"  # shared base\n"
+    ));
+    rename_remote_default(&repo);
+
+    let output = wt(&repo, &["repair", "--default-branch", "--yes"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Updated user config"));
+
+    let config = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    // The project's value follows the rename; the global one is left alone
+    assert!(
+        config.contains("compare-ref = \"origin/trunk\"  # shared base"),
+        "{config}"
+    );
+    assert!(
+        config.contains("[list]\ncompare-ref = \"main\""),
+        "{config}"
+    );
+}
+
+#[rstest]
+fn test_repair_default_branch_unchanged(#[from(repo_with_remote)] repo: TestRepo) {
+    let output = wt(&repo, &["repair", "--default-branch"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Default branch is already main"));
+}
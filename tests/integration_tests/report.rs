@@ -0,0 +1,70 @@
+//! Integration tests for the usage journal and `wt report`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The data directory, inside the test home so snapshots stay stable.
+fn data_home(repo: &TestRepo) -> PathBuf {
+    repo.home_path().join(".local").join("share")
+}
+
+fn wt(repo: &TestRepo, subcommand: &str, args: &[&str]) -> Command {
+    let mut cmd = make_snapshot_cmd(repo, subcommand, args, None);
+    cmd.env("XDG_DATA_HOME", data_home(repo));
+    cmd
+}
+
+fn journal(repo: &TestRepo) -> Option<String> {
+    fs::read_to_string(data_home(repo).join("worktrunk").join("usage.jsonl")).ok()
+}
+
+/// With nothing recorded, the report explains how to start recording.
+#[rstest]
+fn test_report_empty(repo: TestRepo) {
+    assert_cmd_snapshot!(wt(&repo, "report", &[]));
+}
+
+/// Recording is off unless the user config turns it on.
+#[rstest]
+fn test_usage_journal_off_by_default(repo: TestRepo) {
+    let output = wt(&repo, "list", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(journal(&repo), None);
+}
+
+/// Entries hold command and flag names only; the report aggregates them.
+#[rstest]
+fn test_usage_journal_records_and_reports(repo: TestRepo) {
+    repo.write_test_config("usage-journal = true\n");
+    for _ in 0..2 {
+        let output = wt(&repo, "list", &["--format=json"]).output().unwrap();
+        assert!(output.status.success());
+    }
+    let output = wt(&repo, "switch", &["secret-branch"]).output().unwrap();
+    assert!(!output.status.success());
+
+    let journal = journal(&repo).unwrap();
+    assert_eq!(journal.lines().count(), 3);
+    assert!(!journal.contains("secret"), "{journal}");
+    let last: serde_json::Value = serde_json::from_str(journal.lines().last().unwrap()).unwrap();
+    assert_eq!(last["command"], "switch");
+    assert_eq!(last["flags"], serde_json::json!([]));
+    assert_eq!(last["ok"], false);
+
+    let output = wt(&repo, "report", &["--format=json"]).output().unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["runs"], 3);
+    let list = &report["commands"][0];
+    assert_eq!(list["command"], "list");
+    assert_eq!(list["runs"], 2);
+    assert_eq!(list["failed"], 0);
+    assert_eq!(list["flags"], serde_json::json!({ "format": 2 }));
+    let switch = &report["commands"][1];
+    assert_eq!(switch["command"], "switch");
+    assert_eq!(switch["failed"], 1);
+}
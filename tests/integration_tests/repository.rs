@@ -919,3 +919,41 @@ fn test_branch_diff_stats_scoped_to_sparse_checkout() {
     assert_eq!(full_stats.added, 4, "full: inside/ + outside/ additions");
     assert_eq!(full_stats.deleted, 2, "full: inside/ + outside/ deletions");
 }
+
+/// Test that a force-pushed upstream is reported with its previous value, and
+/// a fast-forward isn't.
+#[test]
+fn test_upstream_rewritten() {
+    let mut repo = TestRepo::new();
+    repo.setup_remote("main");
+
+    repo.run_git(&["checkout", "-b", "feature"]);
+    fs::write(repo.root_path().join("feature.txt"), "content").unwrap();
+    repo.run_git(&["add", "feature.txt"]);
+    repo.run_git(&["commit", "-m", "Feature"]);
+    repo.run_git(&["push", "-u", "origin", "feature"]);
+    let pushed_sha = repo.git_output(&["rev-parse", "HEAD"]);
+
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let branch = repository.branch("feature");
+
+    // First update of the tracking ref: nothing to compare against
+    assert_eq!(branch.upstream_rewritten().unwrap(), None);
+
+    // Rewrite the remote branch so it no longer contains the pushed commit
+    repo.run_git(&["push", "--force", "origin", "main:feature"]);
+    assert_eq!(branch.upstream_rewritten().unwrap(), Some(pushed_sha));
+
+    // Moving forward again is an ordinary update
+    repo.run_git(&["push", "origin", "feature"]);
+    assert_eq!(branch.upstream_rewritten().unwrap(), None);
+
+    // No upstream at all
+    assert_eq!(
+        repository
+            .branch("no-such-branch")
+            .upstream_rewritten()
+            .unwrap(),
+        None
+    );
+}
@@ -332,6 +332,11 @@ mod pty_tests {
             "Should not install when declined: {bashrc_content}"
         );
 
+        // The banner counts as shown once
+        let first_run =
+            fs::read_to_string(temp_home.path().join(".cache/worktrunk/first-run.json")).unwrap();
+        assert!(first_run.contains(r#""shown":1"#), "{first_run}");
+
         // Snapshot the output (filters applied via settings)
         prompt_pty_settings(&repo, temp_home.path()).bind(|| {
             assert_snapshot!("prompt_decline", &output);
@@ -389,6 +394,11 @@ mod pty_tests {
             "Should install when accepted: {bashrc_content}"
         );
 
+        // Installing silences the first-run banner for good
+        let first_run =
+            fs::read_to_string(temp_home.path().join(".cache/worktrunk/first-run.json")).unwrap();
+        assert!(first_run.contains(r#""silenced":true"#), "{first_run}");
+
         // Snapshot the output (filters applied via settings)
         prompt_pty_settings(&repo, temp_home.path()).bind(|| {
             assert_snapshot!("prompt_accept", &output);
@@ -451,6 +461,68 @@ mod pty_tests {
         });
     }
 
+    /// Run `wt switch --create feature` in a PTY with an empty `.bashrc`, and
+    /// return its output.
+    fn switch_without_integration(repo: &TestRepo, temp_home: &Path) -> String {
+        fs::write(temp_home.join(".bashrc"), "# empty bashrc\n").unwrap();
+        let mut env_vars = repo.test_env_vars();
+        env_vars.retain(|(k, _)| k != "WORKTRUNK_DIRECTIVE_FILE");
+        env_vars.push(("SHELL".to_string(), "/bin/bash".to_string()));
+
+        let cmd = build_pty_command(
+            wt_bin().to_str().unwrap(),
+            &["switch", "--create", "feature"],
+            repo.root_path(),
+            &env_vars,
+            Some(temp_home),
+        );
+        let (output, exit_code) = exec_cmd_in_pty(cmd, "");
+        assert_eq!(exit_code, 0, "{output}");
+        output
+    }
+
+    /// Test: Banner already shown the maximum number of times → hint only
+    #[rstest]
+    fn test_banner_limit_shows_hint(repo: TestRepo) {
+        let temp_home = TempDir::new().unwrap();
+        let state_dir = temp_home.path().join(".cache/worktrunk");
+        fs::create_dir_all(&state_dir).unwrap();
+        fs::write(
+            state_dir.join("first-run.json"),
+            r#"{"version":1,"state":{"shown":3,"silenced":false}}"#,
+        )
+        .unwrap();
+
+        let output = switch_without_integration(&repo, temp_home.path());
+        assert!(
+            !output.contains("Install shell integration"),
+            "Should not prompt after the banner limit: {output}"
+        );
+        assert!(
+            output.contains("wt config shell install"),
+            "Should show install hint: {output}"
+        );
+    }
+
+    /// Test: `[hints] disabled = ["first-run"]` → hint only, nothing counted
+    #[rstest]
+    fn test_hints_disabled_skips_banner(repo: TestRepo) {
+        repo.write_test_config("[hints]\ndisabled = [\"first-run\"]\n");
+        let temp_home = TempDir::new().unwrap();
+
+        let output = switch_without_integration(&repo, temp_home.path());
+        assert!(
+            !output.contains("Install shell integration"),
+            "Should not prompt when the banner is disabled: {output}"
+        );
+        assert!(
+            !temp_home
+                .path()
+                .join(".cache/worktrunk/first-run.json")
+                .exists()
+        );
+    }
+
     /// Test: Second switch after first prompt → no prompt
     #[rstest]
     fn test_no_prompt_after_first_prompt(repo: TestRepo) {
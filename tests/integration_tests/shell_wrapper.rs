@@ -1053,6 +1053,147 @@ mod unix_tests {
         );
     }
 
+    /// Repeated `--execute` commands stop at the first failure, as without the
+    /// wrapper; `--keep-going` runs the rest
+    #[rstest]
+    #[case("bash")]
+    #[case("zsh")]
+    #[case("fish")]
+    #[case("nu")]
+    fn test_wrapper_execute_stops_at_failure(#[case] shell: &str, repo: TestRepo) {
+        // Quotes split the echoed words, so a match comes from execution.
+        // `false` rather than `exit`, which would end the sourcing shell.
+        let after = "echo \"after\" \"failure\"";
+
+        let output = exec_through_wrapper(
+            shell,
+            &repo,
+            "switch",
+            &["--create", "stop-exec", "-x", "false", "-x", after, "--yes"],
+        );
+        output.assert_no_directive_leaks();
+        assert!(
+            !output.combined.contains("after failure"),
+            "{shell}: ran a command after the failure:\n{}",
+            output.combined
+        );
+
+        let output = exec_through_wrapper(
+            shell,
+            &repo,
+            "switch",
+            &[
+                "stop-exec",
+                "-x",
+                "false",
+                "-x",
+                after,
+                "--keep-going",
+                "--yes",
+            ],
+        );
+        output.assert_no_directive_leaks();
+        output.assert_success();
+        assert!(
+            output.combined.contains("after failure"),
+            "{shell}: --keep-going skipped a command:\n{}",
+            output.combined
+        );
+    }
+
+    /// A trailing comment in one `--execute` command doesn't swallow the next
+    #[rstest]
+    #[case("bash")]
+    #[case("zsh")]
+    #[case("fish")]
+    #[case("nu")]
+    fn test_wrapper_execute_trailing_comment(#[case] shell: &str, repo: TestRepo) {
+        let output = exec_through_wrapper(
+            shell,
+            &repo,
+            "switch",
+            &[
+                "--create",
+                "comment-exec",
+                "-x",
+                "echo \"first\" \"command\" # run",
+                "-x",
+                "echo \"second\" \"command\"",
+                "--yes",
+            ],
+        );
+        output.assert_no_directive_leaks();
+        output.assert_success();
+        for line in ["first command", "second command"] {
+            assert!(
+                output.combined.contains(line),
+                "{shell}: missing {line:?} in output:\n{}",
+                output.combined
+            );
+        }
+    }
+
+    /// A multi-line `--execute` payload whose last line fails stops the chain
+    /// as a whole, after running its earlier lines
+    #[rstest]
+    #[case("bash")]
+    #[case("zsh")]
+    #[case("fish")]
+    fn test_wrapper_execute_multiline_failure(#[case] shell: &str, repo: TestRepo) {
+        let output = exec_through_wrapper(
+            shell,
+            &repo,
+            "switch",
+            &[
+                "--create",
+                "multiline-failure",
+                "-x",
+                "echo \"payload\" \"start\"\nfalse",
+                "-x",
+                "echo \"after\" \"failure\"",
+                "--yes",
+            ],
+        );
+        output.assert_no_directive_leaks();
+        assert!(
+            output.combined.contains("payload start"),
+            "{shell}: skipped the payload's first line:\n{}",
+            output.combined
+        );
+        assert!(
+            !output.combined.contains("after failure"),
+            "{shell}: ran a command after the failing payload:\n{}",
+            output.combined
+        );
+    }
+
+    /// Multi-line `--execute` payloads with quotes, `$` and unicode reach the shell intact
+    #[rstest]
+    #[case("bash")]
+    #[case("zsh")]
+    #[case("fish")]
+    fn test_wrapper_execute_multiline_payload(#[case] shell: &str, repo: TestRepo) {
+        // Quotes split each echoed line, so matches come from execution, not the command echo
+        let payload = "echo \"it's\" line-1\necho 'costs' '$5'\necho \"naïve\" ✓";
+
+        let output = exec_through_wrapper(
+            shell,
+            &repo,
+            "switch",
+            &["--create", "multiline-exec", "--execute", payload, "--yes"],
+        );
+
+        output.assert_no_directive_leaks();
+        output.assert_success();
+        for line in ["it's line-1", "costs $5", "naïve ✓"] {
+            assert!(
+                output.combined.contains(line),
+                "{shell}: missing {line:?} in output:\n{}",
+                output.combined
+            );
+        }
+    }
+
     /// Test switch --create with post-create (blocking) and post-start (background)
     /// Note: bash and fish disabled due to flaky PTY buffering race conditions
     ///
@@ -0,0 +1,41 @@
+//! Integration tests for `wt stats`
+
+use crate::common::{DAY, HOUR, TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+
+/// A stale worktree the default branch has moved past, and a recent one with
+/// uncommitted edits.
+fn setup(repo: &mut TestRepo) {
+    let stale = repo.add_worktree("stale");
+    fs::write(stale.join("stale.txt"), "old\n").unwrap();
+    repo.run_git_in(&stale, &["add", "stale.txt"]);
+    repo.commit_staged_with_age("Old work", 100 * DAY, &stale);
+    repo.commit_with_age("Main moves on", 2 * HOUR);
+    repo.commit_with_age("Main moves further", HOUR);
+
+    let active = repo.add_worktree("active");
+    fs::write(active.join("active.txt"), "a\nb\n").unwrap();
+    repo.run_git_in(&active, &["add", "active.txt"]);
+    repo.commit_staged_with_age("Recent work", 3 * DAY, &active);
+    fs::write(active.join("active.txt"), "a\nc\nd\n").unwrap();
+}
+
+#[rstest]
+fn test_stats(mut repo: TestRepo) {
+    setup(&mut repo);
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "stats", &[], None));
+}
+
+#[rstest]
+fn test_stats_json(mut repo: TestRepo) {
+    setup(&mut repo);
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "stats", &["--format=json"], None));
+}
+
+/// Nothing uncommitted and nothing behind still prints every line.
+#[rstest]
+fn test_stats_no_changes(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "stats", &[], None));
+}
@@ -0,0 +1,141 @@
+//! Integration tests for `wt step graduate`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, repo_with_remote};
+use rstest::rstest;
+
+/// Add a worktree detached at HEAD, at a path the template wouldn't pick.
+fn add_detached_worktree(repo: &TestRepo) -> std::path::PathBuf {
+    let path = repo.root_path().parent().unwrap().join("scratch");
+    repo.run_git(&["worktree", "add", "--detach", path.to_str().unwrap()]);
+    path
+}
+
+/// Graduating creates the branch, attaches the worktree and moves it to the
+/// templated path.
+#[rstest]
+fn test_graduate_detached_worktree(repo: TestRepo) {
+    let scratch = add_detached_worktree(&repo);
+
+    let output = make_snapshot_cmd(&repo, "step", &["graduate", "fix"], Some(&scratch))
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Graduated to fix"), "{stderr}");
+    assert!(stderr.contains("moved to"), "{stderr}");
+
+    let expected = repo.root_path().parent().unwrap().join("repo.fix");
+    assert!(expected.exists(), "worktree should move to {expected:?}");
+    assert!(!scratch.exists(), "old path should be gone");
+    assert_eq!(
+        repo.git_output(&["-C", expected.to_str().unwrap(), "branch", "--show-current"]),
+        "fix"
+    );
+}
+
+/// `--no-move` leaves the worktree where it is.
+#[rstest]
+fn test_graduate_no_move(repo: TestRepo) {
+    let scratch = add_detached_worktree(&repo);
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "step",
+        &["graduate", "fix", "--no-move"],
+        Some(&scratch),
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(!stderr.contains("moved to"), "{stderr}");
+    assert_eq!(
+        repo.git_output(&["-C", scratch.to_str().unwrap(), "branch", "--show-current"]),
+        "fix"
+    );
+}
+
+/// `--push` pushes the new branch and sets its upstream.
+#[rstest]
+fn test_graduate_push(repo_with_remote: TestRepo) {
+    let repo = repo_with_remote;
+    let scratch = add_detached_worktree(&repo);
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "step",
+        &["graduate", "fix", "--no-move", "--push"],
+        Some(&scratch),
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("tracking origin/fix"), "{stderr}");
+    assert_eq!(
+        repo.git_output(&["rev-parse", "--abbrev-ref", "fix@{upstream}"]),
+        "origin/fix"
+    );
+}
+
+/// A worktree already on a branch points at `git branch -m` instead.
+#[rstest]
+fn test_graduate_on_branch(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+
+    let output = make_snapshot_cmd(&repo, "step", &["graduate", "fix"], Some(&feature))
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("git branch -m feature fix"), "{stderr}");
+}
+
+/// An existing branch name is refused.
+#[rstest]
+fn test_graduate_existing_branch(repo: TestRepo) {
+    let scratch = add_detached_worktree(&repo);
+    repo.create_branch("fix");
+
+    let output = make_snapshot_cmd(&repo, "step", &["graduate", "fix"], Some(&scratch))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(scratch.exists());
+}
+
+/// Graduation checks `[branch-name-rules]` before creating the branch.
+#[rstest]
+fn test_graduate_branch_name_rules(repo: TestRepo) {
+    repo.write_project_config("[branch-name-rules]\ncase = \"lower\"\n");
+    let scratch = add_detached_worktree(&repo);
+    let graduate = |args: &[&str]| {
+        make_snapshot_cmd(&repo, "step", args, Some(&scratch))
+            .output()
+            .unwrap()
+    };
+
+    let output = graduate(&["graduate", "Fix", "--no-move"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains(r#"breaks the naming rule case = "lower""#),
+        "{stderr}"
+    );
+    assert_eq!(
+        repo.git_output(&["-C", scratch.to_str().unwrap(), "branch", "--show-current"]),
+        ""
+    );
+
+    let output = graduate(&["graduate", "Fix", "--no-move", "--fix-name"]);
+    assert!(output.status.success());
+    assert_eq!(
+        repo.git_output(&["-C", scratch.to_str().unwrap(), "branch", "--show-current"]),
+        "fix"
+    );
+}
@@ -0,0 +1,70 @@
+//! Integration tests for `wt step optimize` and the `performance-hint` nudge
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use rstest::rstest;
+
+fn commit_graph_exists(repo: &TestRepo) -> bool {
+    let info = repo.root_path().join(".git/objects/info");
+    info.join("commit-graph").exists() || info.join("commit-graphs/commit-graph-chain").exists()
+}
+
+/// `--yes` writes the missing commit-graph. Maintenance is already configured,
+/// so the test doesn't touch the system scheduler.
+#[rstest]
+fn test_optimize_writes_commit_graph(repo: TestRepo) {
+    repo.run_git(&["config", "maintenance.strategy", "incremental"]);
+
+    let output = make_snapshot_cmd(&repo, "step", &["optimize", "--yes"], None)
+        .env("WORKTRUNK_TEST_LARGE_REPO_OBJECTS", "0")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Wrote commit-graph"), "{stderr}");
+    assert!(!stderr.contains("maintenance"), "{stderr}");
+    assert!(commit_graph_exists(&repo));
+}
+
+/// Without a terminal or `--yes`, nothing changes.
+#[rstest]
+fn test_optimize_not_interactive(repo: TestRepo) {
+    let before = commit_graph_exists(&repo);
+
+    let output = make_snapshot_cmd(&repo, "step", &["optimize"], None)
+        .env("WORKTRUNK_TEST_LARGE_REPO_OBJECTS", "0")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("--yes"), "{stderr}");
+    assert_eq!(commit_graph_exists(&repo), before);
+    assert!(
+        repo.git_command()
+            .args(["config", "--get", "maintenance.strategy"])
+            .output()
+            .unwrap()
+            .stdout
+            .is_empty()
+    );
+}
+
+/// `performance-hint = true` suggests `wt step optimize` after `wt list`, then
+/// stays quiet for a week.
+#[rstest]
+fn test_list_performance_hint_once_a_week(repo: TestRepo) {
+    repo.write_test_config("performance-hint = true\n");
+
+    let list = || {
+        let output = make_snapshot_cmd(&repo, "list", &[], None)
+            .env("WORKTRUNK_TEST_LARGE_REPO_OBJECTS", "0")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    };
+
+    let first = list();
+    assert!(first.contains("wt step optimize"), "{first}");
+    let second = list();
+    assert!(!second.contains("wt step optimize"), "{second}");
+}
@@ -74,6 +74,36 @@ fn test_prune_removes_merged(mut repo: TestRepo) {
     assert!(!worktree_path.exists(), "Merged worktree should be removed");
 }
 
+/// `check-cwd-users = true`: prune leaves a merged worktree another process is
+/// sitting in, and says so.
+#[cfg(target_os = "linux")]
+#[rstest]
+fn test_prune_skips_worktree_in_use(mut repo: TestRepo) {
+    repo.commit("initial");
+    repo.write_test_config("check-cwd-users = true\n");
+    let worktree_path = repo.add_worktree("busy-branch");
+    let mut sleeper = std::process::Command::new("sleep")
+        .arg("30")
+        .current_dir(&worktree_path)
+        .spawn()
+        .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "prune", "--yes", "--min-age=0s", "--foreground"])
+        .output()
+        .unwrap();
+    sleeper.kill().unwrap();
+    sleeper.wait().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Skipped busy-branch (in use by other processes"),
+        "{stderr}"
+    );
+    assert!(worktree_path.exists());
+}
+
 /// Prune skips worktrees with unique commits (not merged)
 #[rstest]
 fn test_prune_skips_unmerged(mut repo: TestRepo) {
@@ -494,3 +524,151 @@ fn test_prune_stale_plus_young(mut repo: TestRepo) {
         None
     ));
 }
+
+/// `--squash-aware` removes a worktree whose PR merged even though git can't
+/// see the merge (squash merge); without the flag it's left alone.
+#[rstest]
+fn test_prune_squash_aware_merged_pr(mut repo: TestRepo) {
+    repo.commit("initial");
+    repo.run_git(&[
+        "remote",
+        "set-url",
+        "origin",
+        "https://github.com/test-owner/test-repo.git",
+    ]);
+    let wt_path = repo.add_worktree_with_commit("feature", "f.txt", "content", "feature commit");
+    // @{push} needs tracking config and the remote-tracking ref
+    repo.run_git(&["config", "branch.feature.remote", "origin"]);
+    repo.run_git(&["config", "branch.feature.merge", "refs/heads/feature"]);
+    repo.run_git(&["update-ref", "refs/remotes/origin/feature", "feature"]);
+    let head = repo.git_output(&["rev-parse", "feature"]);
+    repo.setup_mock_gh_with_ci_data(
+        &format!(
+            r#"[{{
+            "headRefOid": "{head}",
+            "url": "https://github.com/test-owner/test-repo/pull/1",
+            "headRepositoryOwner": {{"login": "test-owner"}},
+            "state": "MERGED"
+        }}]"#
+        ),
+        "[]",
+    );
+
+    let mut cmd = make_snapshot_cmd(&repo, "step", &["prune", "--dry-run", "--min-age=0s"], None);
+    repo.configure_mock_commands(&mut cmd);
+    let stderr = String::from_utf8_lossy(&cmd.output().unwrap().stderr).into_owned();
+    assert!(stderr.contains("No merged worktrees to remove"), "{stderr}");
+
+    let mut cmd = make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--squash-aware", "--yes", "--min-age=0s"],
+        None,
+    );
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!wt_path.exists(), "Merged-PR worktree should be removed");
+}
+
+/// `--squash-aware` also removes a branch whose patches landed on main as one
+/// squash commit, even after main edited the same file again; git's checks
+/// (and `wt remove`) still see it as unmerged.
+#[rstest]
+fn test_prune_squash_aware_local_patches(mut repo: TestRepo) {
+    repo.commit("initial");
+    let wt_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&wt_path, "file.txt", "changed", "Change file");
+    repo.commit_in_worktree(&wt_path, "extra.txt", "extra", "Add extra file");
+
+    // Squash both commits onto main, then revert part of it
+    std::fs::write(repo.root_path().join("file.txt"), "changed").unwrap();
+    repo.run_git(&["add", "file.txt"]);
+    repo.commit_in_worktree(repo.root_path(), "extra.txt", "extra", "Feature (#1)");
+    repo.commit("initial");
+
+    let output = make_snapshot_cmd(&repo, "step", &["prune", "--dry-run", "--min-age=0s"], None)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No merged worktrees to remove"), "{stderr}");
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--squash-aware", "--dry-run", "--min-age=0s"],
+        None,
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("squash-merged into main"), "{stderr}");
+
+    // git doesn't see the branch merged, so without a terminal to confirm the
+    // force-delete (and no --yes), it stays
+    let output = make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--squash-aware", "--min-age=0s"],
+        None,
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Skipped feature (squash-merged"),
+        "{stderr}"
+    );
+    assert!(
+        wt_path.exists(),
+        "Unconfirmed squash-merged worktree should stay"
+    );
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--squash-aware", "--yes", "--min-age=0s"],
+        None,
+    )
+    .output()
+    .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        !wt_path.exists(),
+        "Squash-merged worktree should be removed"
+    );
+}
+
+/// Worktrees whose branch was deleted are pruned when their last commit is on
+/// another branch, and skipped when removing them would lose it.
+#[rstest]
+fn test_prune_deleted_branch_worktrees(mut repo: TestRepo) {
+    repo.commit("initial");
+
+    let safe_path = repo.add_worktree("gone-safe");
+    let unique_path =
+        repo.add_worktree_with_commit("gone-unique", "u.txt", "content", "unique commit");
+    repo.delete_branch_under_worktree("gone-safe");
+    repo.delete_branch_under_worktree("gone-unique");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--yes", "--min-age=0s", "--foreground"],
+        None
+    ));
+
+    assert!(
+        !safe_path.exists(),
+        "Deleted-branch worktree should be removed"
+    );
+    assert!(unique_path.exists(), "Unreferenced commit should be kept");
+}
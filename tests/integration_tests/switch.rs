@@ -514,6 +514,117 @@ fn test_switch_execute_multiline(repo: TestRepo) {
     );
 }
 
+/// Repeated `-x` run in order; the first failure stops the rest.
+#[rstest]
+fn test_switch_execute_multiple_stops_on_failure(repo: TestRepo) {
+    snapshot_switch(
+        "switch_execute_multiple_stops_on_failure",
+        &repo,
+        &[
+            "--create",
+            "multi-fail",
+            "-x",
+            "echo first",
+            "-x",
+            "exit 3",
+            "-x",
+            "echo never",
+        ],
+    );
+}
+
+/// `--keep-going` warns about a failed `-x` command and runs the rest.
+#[rstest]
+fn test_switch_execute_multiple_keep_going(repo: TestRepo) {
+    snapshot_switch(
+        "switch_execute_multiple_keep_going",
+        &repo,
+        &[
+            "--create",
+            "multi-keep",
+            "--keep-going",
+            "-x",
+            "exit 3",
+            "-x",
+            "echo second",
+        ],
+    );
+}
+
+/// With shell integration, the `-x` commands become one `&&` chain of evals, in order,
+/// and trailing args go to the last one.
+#[rstest]
+fn test_switch_internal_with_multiple_execute(repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args([
+            "switch",
+            "--create",
+            "multi-internal",
+            "-x",
+            "echo one",
+            "-x",
+            "echo two",
+            "--",
+            "with arg",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let directives = fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directives.ends_with(concat!(
+            r"eval 'echo one' && eval 'echo two '\''with arg'\'''",
+            "\n"
+        )),
+        "{directives}"
+    );
+}
+
+/// `--no-cd -x` runs the commands in the target worktree, with or without
+/// shell integration, and leaves the shell where it is.
+#[rstest]
+#[case::direct(false)]
+#[case::shell_integration(true)]
+fn test_switch_no_cd_execute_runs_in_worktree(repo: TestRepo, #[case] integration: bool) {
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = repo.wt_command();
+    if integration {
+        configure_directive_file(&mut cmd, &directive_path);
+    }
+    let output = cmd
+        .args([
+            "switch",
+            "--create",
+            "no-cd-exec",
+            "--no-cd",
+            "-x",
+            "touch first-marker",
+            "-x",
+            "touch last-marker",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let worktree = repo.root_path().parent().unwrap().join("repo.no-cd-exec");
+    for marker in ["first-marker", "last-marker"] {
+        assert!(worktree.join(marker).exists(), "{marker} not in worktree");
+        assert!(!repo.root_path().join(marker).exists(), "{marker} in cwd");
+    }
+    let directives = fs::read_to_string(&directive_path).unwrap_or_default();
+    assert!(!directives.contains("marker"), "{directives}");
+}
+
 // Execute template expansion tests
 #[rstest]
 fn test_switch_execute_template_branch(repo: TestRepo) {
@@ -1476,6 +1587,52 @@ fn test_switch_create_no_hint_with_custom_worktree_path(repo: TestRepo) {
     );
 }
 
+#[rstest]
+fn test_switch_create_slug_lowercase(repo: TestRepo) {
+    repo.write_test_config(
+        "worktree-path = \".worktrees/{{ branch | sanitize }}\"\nslug-lowercase = \"always\"\n",
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "Feature/Login"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(
+        worktrees.contains(".worktrees/feature-login"),
+        "expected lowercased worktree directory:\n{worktrees}"
+    );
+    assert!(worktrees.contains("branch refs/heads/Feature/Login"));
+}
+
+/// On case-insensitive filesystems, paths differing only in case are the same
+/// directory, so the second branch's path is occupied by the first.
+#[rstest]
+#[cfg(any(target_os = "macos", windows))]
+fn test_switch_error_path_occupied_case_insensitive(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "Feature-Login"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature/login"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Feature-Login"),
+        "expected the occupying branch in the error:\n{stderr}"
+    );
+}
+
 // ============================================================================
 // PR Syntax Tests (pr:<number>)
 // ============================================================================
@@ -3350,3 +3507,962 @@ fn test_switch_base_without_create_warns_not_errors(repo: TestRepo) {
         &["base-test", "--base", "-"],
     );
 }
+
+/// `--create` in a freshly `git init`-ed repo has nothing to branch from, so
+/// it should explain that a first commit is needed rather than surfacing
+/// git's "not a valid object name: 'HEAD'".
+#[test]
+fn test_switch_create_in_empty_repo_errors() {
+    let repo = TestRepo::empty();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "other"])
+        .output()
+        .unwrap();
+
+    assert!(
+        !output.status.success(),
+        "switch should fail without commits"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("repository has no commits yet"),
+        "should explain that a first commit is needed: {stderr}"
+    );
+    assert!(
+        stderr.contains("--orphan"),
+        "should suggest --orphan: {stderr}"
+    );
+    assert!(
+        !stderr.contains("not a valid object name"),
+        "should not surface raw git error: {stderr}"
+    );
+}
+
+/// `--create --orphan` creates an empty branch, which works without commits.
+#[test]
+fn test_switch_create_orphan_in_empty_repo() {
+    let repo = TestRepo::empty();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "--orphan", "other", "--yes"])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "orphan switch should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(
+        worktrees.contains("branch refs/heads/other"),
+        "orphan worktree should be registered: {worktrees}"
+    );
+}
+
+/// Switching to a tag creates a detached worktree at the tag's commit.
+#[rstest]
+fn test_switch_to_tag_creates_detached_worktree(repo: TestRepo) {
+    repo.run_git(&["tag", "v2.3.1"]);
+    let tag_commit = repo.git_output(&["rev-parse", "v2.3.1^{commit}"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "v2.3.1", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "switch to tag should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    let tag_entry = worktrees
+        .split("\n\n")
+        .find(|entry| entry.contains("v2.3.1"))
+        .unwrap_or_else(|| panic!("tag worktree should be listed: {worktrees}"));
+    assert!(tag_entry.contains("detached"), "{tag_entry}");
+    assert!(tag_entry.contains(tag_commit.trim()), "{tag_entry}");
+
+    // Switching again reuses the existing detached worktree
+    let output = repo
+        .wt_command()
+        .args(["switch", "v2.3.1", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "second switch should reuse the worktree: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The list labels the detached worktree with its tag
+    let output = repo.wt_command().args(["list"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("⌂ v2.3.1"), "{stdout}");
+
+    // Removing by tag name removes the worktree without touching refs
+    let output = repo
+        .wt_command()
+        .args(["remove", "v2.3.1", "--yes", "--foreground"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "remove by tag should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(!worktrees.contains("v2.3.1"), "{worktrees}");
+    assert!(repo.git_output(&["tag", "--list"]).contains("v2.3.1"));
+}
+
+/// `--branch-from-tag` creates an `inspect/<tag>` branch instead of detaching.
+#[rstest]
+fn test_switch_to_tag_with_branch_from_tag(repo: TestRepo) {
+    repo.run_git(&["tag", "v2.3.1"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "v2.3.1", "--branch-from-tag", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "switch should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(
+        worktrees.contains("branch refs/heads/inspect/v2.3.1"),
+        "{worktrees}"
+    );
+}
+
+/// A branch and a tag with the same name: the branch wins, with a warning.
+#[rstest]
+fn test_switch_prefers_branch_over_tag(repo: TestRepo) {
+    repo.run_git(&["branch", "release"]);
+    repo.run_git(&["tag", "release"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "release", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "switch should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Both a branch and a tag named"),
+        "should warn about ambiguity: {stderr}"
+    );
+
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(
+        worktrees.contains("branch refs/heads/release"),
+        "{worktrees}"
+    );
+}
+
+/// `[[on-switch]]` entries matching the branch run after switching, ahead of `-x`;
+/// `--no-exec` skips them.
+#[rstest]
+fn test_switch_on_switch_entries(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+[[on-switch]]
+pattern = "feature/*"
+run = "echo offered {{ branch }}"
+
+[[on-switch]]
+pattern = "feature/*"
+run = "echo auto"
+auto = true
+
+[[on-switch]]
+pattern = "other/*"
+run = "echo unrelated"
+"#,
+    );
+
+    let switch = |args: &[&str]| -> (String, String) {
+        let (directive_path, _guard) = directive_file();
+        let mut cmd = repo.wt_command();
+        configure_directive_file(&mut cmd, &directive_path);
+        let output = cmd.arg("switch").args(args).output().unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        assert!(output.status.success(), "switch {args:?} failed: {stderr}");
+        (fs::read_to_string(&directive_path).unwrap(), stderr)
+    };
+
+    // --yes accepts offered entries and approves auto ones, in config order
+    let (directives, _) = switch(&["--create", "feature/x", "--yes"]);
+    assert!(
+        directives.ends_with("eval 'echo offered feature/x' && eval 'echo auto'\n"),
+        "{directives}"
+    );
+    assert!(!directives.contains("unrelated"), "{directives}");
+
+    // Non-interactive without --yes: offered entries are skipped, approved auto entries run
+    // The fixture's remote is `../origin.git`, which identifies the project
+    repo.write_test_approvals("[projects.'../origin']\napproved-commands = [\"echo auto\"]\n");
+    let (directives, stderr) = switch(&["--create", "feature/y"]);
+    assert!(directives.ends_with("echo auto\n"), "{directives}");
+    assert!(!directives.contains("offered"), "{directives}");
+    assert!(stderr.contains("Skipped on-switch command"), "{stderr}");
+
+    // Config entries run before --execute commands; --no-exec skips only the entries
+    let (directives, _) = switch(&["feature/x", "--yes", "-x", "echo explicit"]);
+    assert!(
+        directives.ends_with(
+            "eval 'echo offered feature/x' && eval 'echo auto' && eval 'echo explicit'\n"
+        ),
+        "{directives}"
+    );
+    let (directives, _) = switch(&["feature/x", "--yes", "--no-exec", "-x", "echo explicit"]);
+    assert!(directives.ends_with("echo explicit\n"), "{directives}");
+    assert!(!directives.contains("offered"), "{directives}");
+    let (directives, _) = switch(&["feature/x", "--yes", "--no-exec"]);
+    assert!(!directives.contains("echo"), "{directives}");
+
+    // Branches matching no pattern run nothing
+    let (directives, _) = switch(&["--create", "main-line", "--yes"]);
+    assert!(!directives.contains("echo"), "{directives}");
+}
+
+/// The first `[[worktree-profiles]]` entry whose pattern matches picks the
+/// worktree path and hooks; `--profile` selects one by name instead.
+#[rstest]
+fn test_switch_worktree_profiles(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+post-create = "touch default-marker"
+
+[[worktree-profiles]]
+name = "hotfix"
+pattern = "hotfix/*"
+worktree-path = "{{ repo_path }}/../scratch/{{ branch | sanitize }}"
+post-create = {}
+
+[[worktree-profiles]]
+name = "catch-all"
+pattern = "*"
+post-create = "touch profile-marker"
+"#,
+    );
+    let parent = repo.root_path().parent().unwrap().to_path_buf();
+    let switch = |args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .arg("switch")
+            .args(args)
+            .arg("--yes")
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    };
+
+    // Earlier profiles win: hotfix/* relocates and skips post-create
+    switch(&["--create", "hotfix/x"]);
+    let hotfix = parent.join("scratch").join("hotfix-x");
+    assert!(hotfix.is_dir());
+    assert!(!hotfix.join("default-marker").exists());
+    assert!(!hotfix.join("profile-marker").exists());
+
+    // Other branches fall through to the catch-all, keeping the default path
+    switch(&["--create", "feature"]);
+    let feature = parent.join("repo.feature");
+    assert!(feature.join("profile-marker").exists());
+    assert!(!feature.join("default-marker").exists());
+
+    // --profile overrides the pattern match
+    switch(&["--create", "other", "--profile", "hotfix"]);
+    assert!(parent.join("scratch").join("other").is_dir());
+
+    // Unknown profile names are rejected
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "x", "--profile", "nope"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No worktree profile named"));
+
+    // config show reports the resolved profile
+    let output = repo
+        .wt_command()
+        .args(["config", "show", "--for-branch", "hotfix/y"])
+        .output()
+        .unwrap();
+    let shown = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let shown = ansi_str::AnsiStr::ansi_strip(&*shown).into_owned();
+    assert!(shown.contains("Profile hotfix applies"), "{shown}");
+    assert!(shown.contains("hotfix-y"), "{shown}");
+}
+
+/// `[switch] max-worktrees` refuses to create past the limit; `warn-worktrees`
+/// adds a cleanup hint once the count reaches it.
+#[rstest]
+fn test_switch_worktree_limits(mut repo: TestRepo) {
+    repo.remove_fixture_worktrees();
+    repo.write_test_config("[switch]\nmax-worktrees = 3\nwarn-worktrees = 2\n");
+    let create = |branch: &str| {
+        repo.wt_command()
+            .args(["switch", "--create", branch, "--yes"])
+            .output()
+            .unwrap()
+    };
+
+    // Main worktree plus one: at the warning threshold
+    let output = create("first");
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("2 worktrees exist"), "{stderr}");
+
+    let output = create("second");
+    assert!(output.status.success());
+
+    // Three exist: the limit is reached
+    let output = create("third");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Worktree limit reached"), "{stderr}");
+    assert!(
+        !repo
+            .root_path()
+            .parent()
+            .unwrap()
+            .join("repo.third")
+            .exists()
+    );
+
+    // Switching to an existing worktree is unaffected
+    let output = repo
+        .wt_command()
+        .args(["switch", "first", "--yes"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+}
+
+/// The switch argument also matches worktree directory names and paths; typos get a suggestion.
+#[rstest]
+fn test_switch_by_worktree_directory(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature-login");
+
+    let switch = |args: &[&str]| -> (String, String, bool) {
+        let (directive_path, _guard) = directive_file();
+        let mut cmd = repo.wt_command();
+        configure_directive_file(&mut cmd, &directive_path);
+        let output = cmd.arg("switch").args(args).output().unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        let directives = fs::read_to_string(&directive_path).unwrap_or_default();
+        (directives, stderr, output.status.success())
+    };
+
+    // Directory basename
+    let (directives, stderr, success) = switch(&["repo.feature-login"]);
+    assert!(success, "{stderr}");
+    assert!(
+        directives.contains(feature_path.to_str().unwrap()),
+        "{directives}"
+    );
+
+    // Relative path, resolved against the working directory
+    let (directives, stderr, success) = switch(&["../repo.feature-login"]);
+    assert!(success, "{stderr}");
+    assert!(
+        directives.contains(feature_path.to_str().unwrap()),
+        "{directives}"
+    );
+
+    // Typo in the directory name
+    let (_, stderr, success) = switch(&["repo.feature-logn"]);
+    assert!(!success);
+    assert!(stderr.contains("perhaps"), "{stderr}");
+    assert!(stderr.contains("repo.feature-login"), "{stderr}");
+}
+
+/// A branch and a worktree directory with the same name: the branch wins, with a warning.
+#[rstest]
+fn test_switch_prefers_branch_over_worktree_directory(repo: TestRepo) {
+    let scratch = repo.root_path().parent().unwrap().join("hotfix");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        "-b",
+        "scratch",
+        scratch.to_str().unwrap(),
+    ]);
+    repo.run_git(&["branch", "hotfix"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "hotfix", "--yes"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "switch should succeed: {stderr}");
+    assert!(
+        stderr.contains("Both a branch and a worktree directory named"),
+        "should warn about ambiguity: {stderr}"
+    );
+
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(
+        worktrees.contains("branch refs/heads/hotfix"),
+        "{worktrees}"
+    );
+}
+
+/// A branch checked out outside the template path: plain switch goes to that worktree.
+#[rstest]
+fn test_switch_branch_checked_out_elsewhere_switches_to_it(repo: TestRepo) {
+    let elsewhere = repo.root_path().parent().unwrap().join("review-spot");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        "-b",
+        "feature",
+        elsewhere.to_str().unwrap(),
+    ]);
+
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd.args(["switch", "feature", "--yes"]).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "switch should succeed: {stderr}");
+
+    let directives = fs::read_to_string(&directive_path).unwrap_or_default();
+    assert!(directives.contains("review-spot"), "{directives}");
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert_eq!(
+        worktrees
+            .lines()
+            .filter(|line| *line == "branch refs/heads/feature")
+            .count(),
+        1
+    );
+}
+
+/// `--new-path` for a branch that has a worktree explains git's rule, then
+/// `--detach` or `--force` create the second worktree.
+#[rstest]
+fn test_switch_new_path_branch_checked_out_elsewhere(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    let parent = repo.root_path().parent().unwrap().to_path_buf();
+    let switch = |args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(["switch", "--yes", "--no-cd"])
+            .args(args)
+            .output()
+            .unwrap();
+        (
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+            output.status.success(),
+        )
+    };
+
+    let second = parent.join("feature-second");
+    let (stderr, success) = switch(&["feature", "--new-path", second.to_str().unwrap()]);
+    assert!(!success);
+    assert!(stderr.contains("already checked out at"), "{stderr}");
+    assert!(stderr.contains("--detach"), "{stderr}");
+    assert!(stderr.contains("--force"), "{stderr}");
+    assert!(!second.exists(), "git should not have been run");
+
+    let detached = parent.join("feature-detached");
+    let (stderr, success) = switch(&[
+        "feature",
+        "--new-path",
+        detached.to_str().unwrap(),
+        "--detach",
+    ]);
+    assert!(success, "{stderr}");
+
+    let forced = parent.join("feature-forced");
+    let (stderr, success) = switch(&["feature", "--new-path", forced.to_str().unwrap(), "--force"]);
+    assert!(success, "{stderr}");
+
+    let head = |path: &Path| {
+        repo.git_command()
+            .current_dir(path)
+            .args(["symbolic-ref", "-q", "HEAD"])
+            .output()
+            .unwrap()
+    };
+    assert!(!head(&detached).status.success(), "should be detached");
+    assert_eq!(
+        String::from_utf8_lossy(&head(&forced).stdout).trim(),
+        "refs/heads/feature"
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&head(&feature_path).stdout).trim(),
+        "refs/heads/feature"
+    );
+}
+
+fn local_branch_exists(repo: &TestRepo, branch: &str) -> bool {
+    repo.git_command()
+        .args([
+            "rev-parse",
+            "--verify",
+            "-q",
+            &format!("refs/heads/{branch}"),
+        ])
+        .output()
+        .unwrap()
+        .status
+        .success()
+}
+
+const BRANCH_NAME_RULES: &str = r#"
+[branch-name-rules]
+allow = ["^(feature|fix|chore)/"]
+case = "lower"
+"#;
+
+/// A name breaking `[branch-name-rules]` is rejected before anything is
+/// created, quoting the rule and offering the fixed-up name.
+#[rstest]
+fn test_switch_create_branch_name_rejected(repo: TestRepo) {
+    repo.write_project_config(BRANCH_NAME_RULES);
+    snapshot_switch(
+        "switch_create_branch_name_rejected",
+        &repo,
+        &["--create", "Feature/Login"],
+    );
+    assert!(!local_branch_exists(&repo, "Feature/Login"));
+
+    // No mechanical fix for a missing prefix
+    snapshot_switch(
+        "switch_create_branch_name_no_fix",
+        &repo,
+        &["--create", "login"],
+    );
+}
+
+/// `--fix-name` creates the suggested name; `--no-name-rules` skips the rules.
+#[rstest]
+fn test_switch_create_branch_name_fix_and_skip(repo: TestRepo) {
+    repo.write_project_config(BRANCH_NAME_RULES);
+    let switch = |args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(["switch", "--create", "--yes"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    };
+
+    switch(&["Feature/Login", "--fix-name"]);
+    assert!(local_branch_exists(&repo, "feature/login"));
+    assert!(!local_branch_exists(&repo, "Feature/Login"));
+
+    switch(&["Scratch", "--no-name-rules"]);
+    assert!(local_branch_exists(&repo, "Scratch"));
+
+    // Existing branches aren't checked: the rules only guard new names
+    switch(&["--no-name-rules", "Other"]);
+    let output = repo
+        .wt_command()
+        .args(["switch", "Other", "--yes"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+}
+
+// Interrupted creation tests
+
+/// Run `script` from a `post-checkout` hook, i.e. inside the `git worktree add`
+/// that `wt` starts. `$PPID` is that git process; its parent is `wt`.
+#[cfg(unix)]
+fn install_post_checkout_hook(repo: &TestRepo, script: &str) {
+    use std::os::unix::fs::PermissionsExt;
+    let hook = repo
+        .root_path()
+        .join(".git")
+        .join("hooks")
+        .join("post-checkout");
+    fs::create_dir_all(hook.parent().unwrap()).unwrap();
+    fs::write(&hook, format!("#!/bin/sh\n{script}\n")).unwrap();
+    fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[cfg(unix)]
+fn interrupted_worktree_path(repo: &TestRepo) -> std::path::PathBuf {
+    let repo_name = repo.root_path().file_name().unwrap().to_str().unwrap();
+    repo.root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}.interrupted"))
+}
+
+/// Git killed mid-creation leaves a registered, half-done worktree; `wt`
+/// removes it and the branch it created.
+#[rstest]
+#[cfg(unix)]
+fn test_switch_create_checkout_killed(repo: TestRepo) {
+    install_post_checkout_hook(&repo, "kill -9 $PPID");
+    snapshot_switch(
+        "switch_create_checkout_killed",
+        &repo,
+        &["--create", "interrupted"],
+    );
+
+    assert!(!interrupted_worktree_path(&repo).exists());
+    assert!(
+        !repo
+            .git_output(&["worktree", "list"])
+            .contains("interrupted")
+    );
+    assert!(!local_branch_exists(&repo, "interrupted"));
+}
+
+/// Ctrl-C during creation removes the new worktree and exits 130.
+#[rstest]
+#[cfg(unix)]
+fn test_switch_create_interrupted_by_ctrl_c(repo: TestRepo) {
+    install_post_checkout_hook(&repo, "kill -INT $(ps -o ppid= -p $PPID)");
+    snapshot_switch(
+        "switch_create_interrupted_by_ctrl_c",
+        &repo,
+        &["--create", "interrupted"],
+    );
+
+    assert!(!interrupted_worktree_path(&repo).exists());
+    assert!(
+        !repo
+            .git_output(&["worktree", "list"])
+            .contains("interrupted")
+    );
+    assert!(!local_branch_exists(&repo, "interrupted"));
+}
+
+/// When `wt` itself dies mid-checkout, the next switch to the branch finds
+/// the unfinished worktree and offers to start over.
+#[rstest]
+#[cfg(unix)]
+fn test_switch_recovers_interrupted_creation(repo: TestRepo) {
+    install_post_checkout_hook(&repo, "kill -9 $(ps -o ppid= -p $PPID) $PPID");
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "interrupted"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    fs::remove_file(repo.root_path().join(".git/hooks/post-checkout")).unwrap();
+    let path = interrupted_worktree_path(&repo);
+    assert!(path.exists(), "partial worktree should be left behind");
+
+    // Not interactive: explain and point at --clobber
+    snapshot_switch(
+        "switch_interrupted_creation_not_interactive",
+        &repo,
+        &["interrupted"],
+    );
+    assert!(path.exists());
+
+    snapshot_switch(
+        "switch_interrupted_creation_clobber",
+        &repo,
+        &["interrupted", "--clobber"],
+    );
+    assert!(path.join(".git").exists());
+    let markers = repo.root_path().join(".git").join("wt-creating");
+    assert_eq!(fs::read_dir(markers).unwrap().count(), 0);
+}
+
+/// Work added to a partial worktree isn't thrown away: `--clobber` without a
+/// terminal refuses to remove it.
+#[rstest]
+#[cfg(unix)]
+fn test_switch_interrupted_creation_keeps_work(repo: TestRepo) {
+    install_post_checkout_hook(&repo, "kill -9 $(ps -o ppid= -p $PPID) $PPID");
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "interrupted"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    fs::remove_file(repo.root_path().join(".git/hooks/post-checkout")).unwrap();
+    let path = interrupted_worktree_path(&repo);
+    fs::write(path.join("notes.txt"), "keep me\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "interrupted", "--clobber"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("1 file changed since its creation stopped"),
+        "{stderr}"
+    );
+    assert_eq!(
+        fs::read_to_string(path.join("notes.txt")).unwrap(),
+        "keep me\n"
+    );
+}
+
+/// A conditional include giving the new worktree another `user.email` is
+/// reported under the switch message, naming the file that set it.
+#[rstest]
+fn test_switch_create_identity_differs(repo: TestRepo) {
+    let include = repo.root_path().join(".git/work-identity.inc");
+    fs::write(&include, "[user]\n\temail = me@work.example\n").unwrap();
+    repo.run_git(&[
+        "config",
+        "includeIf.onbranch:work/**.path",
+        include.to_str().unwrap(),
+    ]);
+
+    snapshot_switch(
+        "switch_create_identity_differs",
+        &repo,
+        &["--create", "work/login"],
+    );
+}
+
+/// `[identity] require-email-pattern` turns a wrong email into an error
+/// before any hook runs in the new worktree.
+#[rstest]
+fn test_switch_create_identity_rejected(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+post-create = "touch hook-ran"
+
+[identity]
+require-email-pattern = "@corp[.]example$"
+"#,
+    );
+    repo.write_test_approvals("[projects.'../origin']\napproved-commands = [\"touch hook-ran\"]\n");
+
+    snapshot_switch(
+        "switch_create_identity_rejected",
+        &repo,
+        &["--create", "feature-identity"],
+    );
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature-identity",
+        repo.root_path().file_name().unwrap().to_str().unwrap()
+    ));
+    assert!(worktree.exists());
+    assert!(!worktree.join("hook-ran").exists());
+}
+
+/// `[git-config]` entries land in the new worktree's own config: git sees
+/// them there, not in the primary or other worktrees, and `wt list --long`
+/// shows them.
+#[rstest]
+fn test_switch_create_git_config(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+[git-config]
+"pull.rebase" = "true"
+"worktrunk.test.slot" = "{{ branch }}-{{ worktree_index }}"
+"#,
+    );
+
+    snapshot_switch(
+        "switch_create_git_config",
+        &repo,
+        &["--create", "feature-cfg"],
+    );
+
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature-cfg",
+        repo.root_path().file_name().unwrap().to_str().unwrap()
+    ));
+    let get = |dir: &Path, key: &str| {
+        let output = repo
+            .git_command()
+            .args(["config", "--get", key])
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+    assert_eq!(get(&worktree, "pull.rebase"), "true");
+    // The primary worktree comes first, then the fixture's three
+    assert_eq!(get(&worktree, "worktrunk.test.slot"), "feature-cfg-4");
+    for other in [repo.root_path(), repo.worktree_path("feature-a")] {
+        assert_eq!(get(other, "pull.rebase"), "");
+        assert_eq!(get(other, "worktrunk.test.slot"), "");
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--long"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = ansi_str::AnsiStr::ansi_strip(&*stdout);
+    let blocks: Vec<&str> = stdout.split("\n\n").collect();
+    let block = blocks
+        .iter()
+        .find(|block| block.contains("feature-cfg"))
+        .unwrap_or_else(|| panic!("{stdout}"));
+    assert!(block.contains("Git config: pull.rebase = true"), "{block}");
+    assert!(blocks.iter().filter(|b| b.contains("Git config")).count() == 1);
+}
+
+/// Repository discovery runs once per process, however many places ask for it.
+#[rstest]
+fn test_switch_create_discovers_repository_once(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature-once"])
+        .env("RUST_LOG", "debug")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let count = |cmd: &str| {
+        stderr
+            .lines()
+            .filter(|line| line.contains("[wt-trace]") && line.contains(cmd))
+            .count()
+    };
+    // Discovery for the command log can run before logging starts, so the
+    // one real call may not show up
+    assert!(
+        count("cmd=\"git rev-parse --git-common-dir\"") <= 1,
+        "{stderr}"
+    );
+    assert_eq!(count("cmd=\"git config --get-regexp"), 1, "{stderr}");
+}
+
+/// `allowed-worktree-roots` refuses paths outside the listed directories,
+/// comparing through symlinks and `..`.
+#[rstest]
+fn test_switch_allowed_worktree_roots(repo: TestRepo) {
+    let base = repo.root_path().parent().unwrap().to_path_buf();
+    let allowed = base.join("allowed");
+    std::fs::create_dir_all(&allowed).unwrap();
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&allowed, base.join("allowed-link")).unwrap();
+    let config = |roots: &str, template: &str| {
+        repo.write_test_config(&format!(
+            "allowed-worktree-roots = [{roots}]\nworktree-path = \"{template}\"\n"
+        ));
+    };
+    let switch = |args: &[&str]| {
+        let output = repo.wt_command().args(args).output().unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        (
+            ansi_str::AnsiStr::ansi_strip(&*stderr).into_owned(),
+            output.status.success(),
+        )
+    };
+    let allowed_str = allowed.display().to_string();
+
+    // The default sibling template lands outside the root: refused before creating anything
+    config(
+        &format!("{allowed_str:?}"),
+        "../repo.{{ branch | sanitize }}",
+    );
+    let (stderr, success) = switch(&["switch", "--create", "outside", "--yes"]);
+    assert!(!success, "{stderr}");
+    assert!(
+        stderr.contains("outside allowed-worktree-roots"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("allowed\n"),
+        "roots should be listed: {stderr}"
+    );
+    assert!(!base.join("repo.outside").exists());
+    let branch = repo
+        .git_command()
+        .args(["rev-parse", "--verify", "--quiet", "refs/heads/outside"])
+        .output()
+        .unwrap();
+    assert!(!branch.status.success(), "branch created despite refusal");
+
+    // Inside the root is fine
+    config(
+        &format!("{allowed_str:?}"),
+        "../allowed/{{ branch | sanitize }}",
+    );
+    let (stderr, success) = switch(&["switch", "--create", "inside", "--yes"]);
+    assert!(success, "{stderr}");
+    assert!(allowed.join("inside").exists());
+
+    // `..` can't climb back out of the root
+    let escape = allowed.join("sub/../../escaped");
+    let (stderr, success) = switch(&[
+        "switch",
+        "--create",
+        "escape",
+        "--new-path",
+        escape.to_str().unwrap(),
+        "--yes",
+    ]);
+    assert!(!success, "{stderr}");
+    assert!(
+        stderr.contains("outside allowed-worktree-roots"),
+        "{stderr}"
+    );
+    assert!(!base.join("escaped").exists());
+
+    // Without a terminal to confirm, --allow-any-path still refuses
+    config(
+        &format!("{allowed_str:?}"),
+        "../repo.{{ branch | sanitize }}",
+    );
+    let (stderr, success) = switch(&["switch", "--create", "anywhere", "--allow-any-path"]);
+    assert!(!success, "{stderr}");
+    assert!(
+        stderr.contains("outside allowed-worktree-roots"),
+        "{stderr}"
+    );
+
+    // A symlinked root matches paths under its target, in either direction
+    #[cfg(unix)]
+    {
+        let link_str = base.join("allowed-link").display().to_string();
+        config(
+            &format!("{link_str:?}"),
+            "../allowed/{{ branch | sanitize }}",
+        );
+        let (stderr, success) = switch(&["switch", "--create", "via-target", "--yes"]);
+        assert!(success, "{stderr}");
+
+        config(
+            &format!("{allowed_str:?}"),
+            "../allowed-link/{{ branch | sanitize }}",
+        );
+        let (stderr, success) = switch(&["switch", "--create", "via-link", "--yes"]);
+        assert!(success, "{stderr}");
+        assert!(allowed.join("via-link").exists());
+    }
+}
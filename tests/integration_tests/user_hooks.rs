@@ -1946,3 +1946,48 @@ fn test_remove_current_worktree_fires_post_switch_hook(mut repo: TestRepo) {
         "Post-switch hook should run when removing current worktree, got: {content}"
     );
 }
+
+/// With `[activity] enabled = true`, hook commands are recorded per worktree
+/// and shown in `wt list --long`; removing the worktree drops its log.
+#[rstest]
+fn test_activity_log_records_hook_commands(mut repo: TestRepo) {
+    repo.write_test_config(
+        r#"[activity]
+enabled = true
+
+[pre-switch]
+check = "echo ACTIVITY_CHECK"
+"#,
+    );
+    let feature_path = repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "pre-switch"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = repo.wt_command().args(["list", "--long"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let feature = stdout
+        .split("\n\n")
+        .find(|block| block.contains("feature"))
+        .unwrap_or_else(|| panic!("{stdout}"));
+    assert!(feature.contains("Activity:"), "{feature}");
+    assert!(feature.contains("echo ACTIVITY_CHECK"), "{feature}");
+
+    let activity_dir = resolve_git_common_dir(repo.root_path()).join("wt-logs/activity");
+    assert_eq!(fs::read_dir(&activity_dir).unwrap().count(), 1);
+
+    repo.wt_command()
+        .args(["remove", "feature", "--foreground", "--yes"])
+        .output()
+        .unwrap();
+    assert_eq!(fs::read_dir(&activity_dir).unwrap().count(), 0);
+}
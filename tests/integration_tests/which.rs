@@ -0,0 +1,93 @@
+//! Integration tests for `wt which`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use std::fs;
+
+fn which(repo: &TestRepo, args: &[&str]) -> serde_json::Value {
+    let output = repo
+        .wt_command()
+        .arg("which")
+        .args(args)
+        .arg("--format=json")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[rstest]
+fn test_which_main_and_linked_worktrees(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    fs::create_dir_all(feature_path.join("src")).unwrap();
+    let feature_path = dunce::canonicalize(&feature_path).unwrap();
+
+    let json = which(&repo, &[]);
+    assert_eq!(json["branch"], "main");
+    assert_eq!(json["is_primary"], true);
+    assert_eq!(json["in_git_dir"], false);
+
+    let json = which(&repo, &[feature_path.join("src").to_str().unwrap()]);
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(json["is_primary"], false);
+
+    // Paths in the shared git directory, including a linked worktree's
+    // admin directory, belong to the primary worktree
+    let json = which(&repo, &[".git/worktrees"]);
+    assert_eq!(json["branch"], "main");
+    assert_eq!(json["in_git_dir"], true);
+}
+
+#[rstest]
+fn test_which_nested_worktree(mut repo: TestRepo) {
+    let nested = repo.root_path().join("nested");
+    repo.add_worktree_at_path("nested", &nested);
+    fs::write(nested.join("file.txt"), "x\n").unwrap();
+
+    let json = which(&repo, &["nested/file.txt"]);
+    assert_eq!(json["branch"], "nested");
+}
+
+#[cfg(unix)]
+#[rstest]
+fn test_which_through_symlink(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    let link = repo.root_path().parent().unwrap().join("feature-link");
+    std::os::unix::fs::symlink(&feature_path, &link).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["which", link.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("feature "), "{stdout}");
+}
+
+#[rstest]
+fn test_which_outside_worktrees_fails(repo: TestRepo) {
+    // A bare repository with no worktrees: its directory is the git dir, and
+    // there's no primary worktree to report
+    let bare = repo.root_path().parent().unwrap().join("bare.git");
+    let status = repo
+        .git_command()
+        .args(["init", "--bare", "-q", bare.to_str().unwrap()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = repo
+        .wt_command()
+        .args(["which", bare.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = ansi_str::AnsiStr::ansi_strip(&*stderr);
+    assert!(stderr.contains("is not inside any worktree"), "{stderr}");
+}